@@ -0,0 +1,18 @@
+use bytes::Bytes;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use tokyo_fix::spec::fix42::fields;
+use tokyo_fix::fix_message::FixMessage;
+
+const LOGON: &[u8] = b"8=FIX.4.2\x019=77\x0135=A\x0134=1\x0149=TEST_CLIENT\x0156=TEST_SERVER\x0152=20250119-16:13:08.931\x0198=0\x01108=30\x01141=Y\x0110=217\x01";
+
+// The per message cost of the hot path, indexing a frame and reading a couple of fields from it.
+fn parse(c: &mut Criterion) {
+    let frame = Bytes::from_static(LOGON);
+    c.bench_function("parse logon", |b| b.iter(|| {
+        let msg = FixMessage::new(black_box(frame.clone()));
+        msg.get_int(&fields::MSG_SEQ_NUM).unwrap() + msg.get_int(&fields::HEART_BT_INT).unwrap()
+    }));
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
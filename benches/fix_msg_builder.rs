@@ -0,0 +1,17 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use tokyo_fix::fix_42::attribute_enums::MsgType;
+use tokyo_fix::spec::fix42::fields;
+use tokyo_fix::fix_msg_builder::{FixMessageBuilder, SessionHeader};
+
+// Building a message once the builder's buffers have grown to fit it.
+fn finish(c: &mut Criterion) {
+    let mut builder = FixMessageBuilder::new(SessionHeader::new("FIX.4.2", "TEST_SERVER", "TEST_CLIENT"));
+    let mut seq_no = 0;
+    c.bench_function("finish heartbeat", |b| b.iter(|| {
+        seq_no += 1;
+        builder.start(MsgType::HeartBeat).add_string_field(fields::TEST_REQ_ID, black_box("test")).finish(seq_no)
+    }));
+}
+
+criterion_group!(benches, finish);
+criterion_main!(benches);
@@ -0,0 +1,513 @@
+// Generates code from the QuickFIX style specs checked in under spec/: a FixTag constant for every
+// field and a table of them all to look tags up in, an enum for every field with enumerated values,
+// and a struct for every message and repeating group that decodes from a FixMessage and encodes
+// through a FixMessageBuilder.
+//
+// Each spec becomes a module of $OUT_DIR/specs.rs named after the file, e.g. spec/FIX42.xml is
+// spec::fix42, so 4.2, 4.4 and 5.0 are generated the same way from whichever specs are present.
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use std::path::Path;
+use std::{env, fs};
+
+fn main() {
+    println!("cargo:rerun-if-changed=spec");
+
+    let mut paths: Vec<_> = fs::read_dir("spec").expect("spec directory")
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "xml"))
+        .collect();
+    paths.sort();
+
+    let mut out = String::new();
+    for path in paths {
+        let module = path.file_stem().unwrap().to_string_lossy().to_lowercase();
+        let xml = fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+        let spec = Spec::parse(&xml).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+        let _ = writeln!(out, "pub mod {} {{", module);
+        spec.write_fields(&mut out);
+        spec.write_enums(&mut out);
+        spec.write_messages(&mut out);
+        let _ = writeln!(out, "}}");
+    }
+
+    let dest = Path::new(&env::var("OUT_DIR").unwrap()).join("specs.rs");
+    fs::write(dest, out).expect("write specs.rs");
+}
+
+struct Field {
+    number : u32,
+    name   : String,
+    kind   : String,
+    values : Vec<(String, String)>,
+}
+
+enum Entry {
+    Field { name: String, required: bool },
+    Group { name: String, entries: Vec<Entry> },
+}
+
+struct Message {
+    name     : String,
+    msg_type : String,
+    entries  : Vec<Entry>,
+}
+
+struct Spec {
+    fields   : Vec<Field>,
+    by_name  : HashMap<String, usize>,
+    messages : Vec<Message>,
+}
+
+impl Spec {
+
+    fn parse(xml: &str) -> Result<Spec, String> {
+        let doc = roxmltree::Document::parse(xml).map_err(|e| e.to_string())?;
+        let root = doc.root_element();
+        let section = |name: &str| root.children().find(|n| n.has_tag_name(name));
+
+        let mut fields = Vec::new();
+        for node in section("fields").iter().flat_map(|s| s.children()).filter(|n| n.has_tag_name("field")) {
+            let number = node.attribute("number").and_then(|n| n.parse().ok())
+                .ok_or_else(|| format!("field without a number: {:?}", node.attribute("name")))?;
+            let values = node.children().filter(|n| n.has_tag_name("value"))
+                .map(|v| (v.attribute("enum").unwrap_or("").to_string(), v.attribute("description").unwrap_or("").to_string()))
+                .collect();
+            fields.push(Field {
+                number,
+                name: node.attribute("name").unwrap_or("").to_string(),
+                kind: node.attribute("type").unwrap_or("STRING").to_string(),
+                values,
+            });
+        }
+        fields.sort_by_key(|field| field.number);
+        let by_name = fields.iter().enumerate().map(|(i, field)| (field.name.clone(), i)).collect();
+
+        let components: HashMap<&str, roxmltree::Node> = section("components").iter()
+            .flat_map(|s| s.children())
+            .filter(|n| n.has_tag_name("component"))
+            .filter_map(|n| n.attribute("name").map(|name| (name, n)))
+            .collect();
+
+        let mut messages = Vec::new();
+        for node in section("messages").iter().flat_map(|s| s.children()).filter(|n| n.has_tag_name("message")) {
+            messages.push(Message {
+                name: node.attribute("name").unwrap_or("").to_string(),
+                msg_type: node.attribute("msgtype").unwrap_or("").to_string(),
+                entries: entries(node, &components, true)?,
+            });
+        }
+
+        let spec = Spec { fields, by_name, messages };
+        for message in &spec.messages {
+            spec.check_names(&message.entries)?;
+        }
+        Ok(spec)
+    }
+
+    fn check_names(&self, entries: &[Entry]) -> Result<(), String> {
+        for entry in entries {
+            let name = match entry {
+                Entry::Field { name, .. } => name,
+                Entry::Group { name, entries, .. } => { self.check_names(entries)?; name },
+            };
+            if !self.by_name.contains_key(name) {
+                return Err(format!("{} isn't defined in <fields>", name));
+            }
+        }
+        Ok(())
+    }
+
+    fn field(&self, name: &str) -> &Field {
+        &self.fields[self.by_name[name]]
+    }
+
+    fn write_fields(&self, out: &mut String) {
+        let _ = writeln!(out, "pub mod fields {{");
+        let _ = writeln!(out, "    use crate::fix_42::value_types::{{FixTag, ValueType}};");
+        for field in &self.fields {
+            let _ = writeln!(out, "    pub const {}: FixTag = FixTag {{ id: \"{}\", datatype: ValueType::{} }};",
+                             screaming(&field.name), field.number, value_type(&field.kind));
+        }
+        // Fields are sorted by number, so find can binary search.
+        let all: Vec<_> = self.fields.iter().map(|field| format!("&{}", screaming(&field.name))).collect();
+        let _ = writeln!(out, "    pub static ALL: &[&FixTag] = &[{}];", all.join(", "));
+        let _ = writeln!(out, "    pub fn find(number: u32) -> Option<&'static FixTag> {{");
+        let _ = writeln!(out, "        ALL.binary_search_by_key(&number, |tag| tag.number()).ok().map(|i| ALL[i])");
+        let _ = writeln!(out, "    }}");
+        // A LENGTH field gives the size of the DATA field named after it, SecureDataLen of SecureData,
+        // SignatureLength of Signature and so on.
+        let data: Vec<_> = self.fields.iter()
+            .filter(|field| field.kind == "LENGTH")
+            .filter_map(|field| {
+                let name = field.name.strip_suffix("Length").or_else(|| field.name.strip_suffix("Len"))?;
+                let data = self.fields.get(*self.by_name.get(name)?)?;
+                (data.kind == "DATA").then(|| format!("{} => Some({})", field.number, data.number))
+            })
+            .collect();
+        let _ = writeln!(out, "    pub fn data_field_of(length: u32) -> Option<u32> {{");
+        let _ = writeln!(out, "        match length {{ {}, _ => None }}", data.join(", "));
+        let _ = writeln!(out, "    }}");
+        let _ = writeln!(out, "}}");
+    }
+
+    fn write_enums(&self, out: &mut String) {
+        let _ = writeln!(out, "pub mod enums {{");
+        let _ = writeln!(out, "    use std::fmt::{{Display, Formatter}};");
+        let _ = writeln!(out, "    use std::str::FromStr;");
+        let _ = writeln!(out, "    use crate::fix_42::attribute_enums::{{FixEnum, UnknownEnumValue}};");
+        for field in self.fields.iter().filter(|field| !field.values.is_empty()) {
+            let variants = variants(field);
+            let name = &field.name;
+            let _ = writeln!(out, "\n    // {} ({})", name, field.number);
+            let _ = writeln!(out, "    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]");
+            let _ = writeln!(out, "    pub enum {} {{", name);
+            for (variant, _) in &variants {
+                let _ = writeln!(out, "        {},", variant);
+            }
+            let _ = writeln!(out, "    }}\n");
+            let _ = writeln!(out, "    impl {} {{", name);
+            let _ = writeln!(out, "        pub const ALL: &'static [{}] = &[{}];", name,
+                             variants.iter().map(|(v, _)| format!("{}::{}", name, v)).collect::<Vec<_>>().join(", "));
+            let _ = writeln!(out, "    }}\n");
+            let _ = writeln!(out, "    impl FixEnum for {} {{", name);
+            let _ = writeln!(out, "        fn value(&self) -> &'static str {{");
+            let _ = writeln!(out, "            match self {{");
+            for (variant, value) in &variants {
+                let _ = writeln!(out, "                {}::{} => {:?},", name, variant, value);
+            }
+            let _ = writeln!(out, "            }}\n        }}");
+            let _ = writeln!(out, "        fn from_bytes(value: &[u8]) -> Option<{}> {{", name);
+            let _ = writeln!(out, "            match value {{");
+            for (variant, value) in &variants {
+                let _ = writeln!(out, "                b{:?} => Some({}::{}),", value, name, variant);
+            }
+            let _ = writeln!(out, "                _ => None,\n            }}\n        }}\n    }}\n");
+            let _ = writeln!(out, "    impl TryFrom<&[u8]> for {} {{", name);
+            let _ = writeln!(out, "        type Error = UnknownEnumValue;");
+            let _ = writeln!(out, "        fn try_from(value: &[u8]) -> Result<{}, UnknownEnumValue> {{", name);
+            let _ = writeln!(out, "            {}::from_bytes(value).ok_or_else(|| UnknownEnumValue::new({:?}, value))", name, name);
+            let _ = writeln!(out, "        }}\n    }}\n");
+            let _ = writeln!(out, "    impl TryFrom<char> for {} {{", name);
+            let _ = writeln!(out, "        type Error = UnknownEnumValue;");
+            let _ = writeln!(out, "        fn try_from(value: char) -> Result<{}, UnknownEnumValue> {{", name);
+            let _ = writeln!(out, "            {}::try_from(value.encode_utf8(&mut [0; 4]).as_bytes())", name);
+            let _ = writeln!(out, "        }}\n    }}\n");
+            let _ = writeln!(out, "    impl FromStr for {} {{", name);
+            let _ = writeln!(out, "        type Err = UnknownEnumValue;");
+            let _ = writeln!(out, "        fn from_str(value: &str) -> Result<{}, UnknownEnumValue> {{", name);
+            let _ = writeln!(out, "            {}::try_from(value.as_bytes())", name);
+            let _ = writeln!(out, "        }}\n    }}\n");
+            let _ = writeln!(out, "    impl Display for {} {{", name);
+            let _ = writeln!(out, "        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {{ f.write_str(self.value()) }}");
+            let _ = writeln!(out, "    }}");
+        }
+        let _ = writeln!(out, "}}");
+    }
+
+    fn write_messages(&self, out: &mut String) {
+        let _ = writeln!(out, "pub mod messages {{");
+        let _ = writeln!(out, "    #![allow(unused_imports)]");
+        let _ = writeln!(out, "    use bytes::Bytes;");
+        let _ = writeln!(out, "    use chrono::NaiveDateTime;");
+        let _ = writeln!(out, "    use rust_decimal::Decimal;");
+        let _ = writeln!(out, "    use crate::fix_42::attribute_enums::{{FixEnum, MsgType}};");
+        let _ = writeln!(out, "    use crate::fix_message::{{FieldError, FixMessage, Group, GroupDef}};");
+        let _ = writeln!(out, "    use crate::fix_msg_builder::FixMessageBuilder;");
+        let _ = writeln!(out, "    use crate::spec::{{get_enum, get_enums, optional}};");
+        let _ = writeln!(out, "    use super::enums;");
+        let _ = writeln!(out, "    use super::fields;");
+        for message in &self.messages {
+            let _ = writeln!(out, "\n    // {} (35={})", message.name, message.msg_type);
+            self.write_struct(out, &message.name, &message.entries);
+            let _ = writeln!(out, "    impl {} {{", message.name);
+            let _ = writeln!(out, "        pub const MSG_TYPE: &'static str = {:?};\n", message.msg_type);
+            let _ = writeln!(out, "        pub fn decode(msg: &FixMessage) -> Result<{}, FieldError> {{", message.name);
+            let _ = writeln!(out, "            {}::decode_fields(&msg.as_group())\n        }}\n", message.name);
+            let _ = writeln!(out, "        // The whole message, with the session header and trailer added by the builder.");
+            let _ = writeln!(out, "        pub fn encode(&self, builder: &mut FixMessageBuilder, seq_no: i32) -> Bytes {{");
+            let _ = writeln!(out, "            builder.start(MsgType::from({}::MSG_TYPE));", message.name);
+            let _ = writeln!(out, "            self.encode_fields(builder);");
+            let _ = writeln!(out, "            builder.finish(seq_no)\n        }}\n");
+            self.write_codec(out, &message.name, &message.entries);
+            let _ = writeln!(out, "    }}\n");
+            let _ = writeln!(out, "    impl crate::spec::Message for {} {{", message.name);
+            let _ = writeln!(out, "        fn msg_type(&self) -> MsgType {{ MsgType::from({}::MSG_TYPE) }}", message.name);
+            let _ = writeln!(out, "        fn encode_fields(&self, builder: &mut FixMessageBuilder) {{ {}::encode_fields(self, builder) }}", message.name);
+            let _ = writeln!(out, "    }}");
+            self.write_groups(out, &message.name, &message.entries);
+        }
+        let _ = writeln!(out, "}}");
+    }
+
+    // The struct and GroupDef for every group in entries, nested groups first.
+    fn write_groups(&self, out: &mut String, parent: &str, entries: &[Entry]) {
+        for entry in entries {
+            let Entry::Group { name, entries, .. } = entry else { continue };
+            let group = format!("{}{}", parent, name);
+            self.write_groups(out, &group, entries);
+
+            let mut fields = entries.iter().filter_map(|entry| match entry {
+                Entry::Field { name, .. } => Some(format!("&fields::{}", screaming(name))),
+                Entry::Group { .. } => None,
+            });
+            let delimiter = match &entries[0] {
+                Entry::Field { name, .. } | Entry::Group { name, .. } => format!("&fields::{}", screaming(name)),
+            };
+            if matches!(entries[0], Entry::Field { .. }) {
+                fields.next();
+            }
+            let nested: Vec<_> = entries.iter().filter_map(|entry| match entry {
+                Entry::Group { name, .. } => Some(format!("&{}", screaming(&format!("{}{}", group, name)))),
+                Entry::Field { .. } => None,
+            }).collect();
+
+            let _ = writeln!(out, "\n    pub static {}: GroupDef = GroupDef {{", screaming(&group));
+            let _ = writeln!(out, "        count_tag : &fields::{},", screaming(name));
+            let _ = writeln!(out, "        delimiter : {},", delimiter);
+            let _ = writeln!(out, "        members   : &[{}],", fields.collect::<Vec<_>>().join(", "));
+            let _ = writeln!(out, "        groups    : &[{}],", nested.join(", "));
+            let _ = writeln!(out, "    }};\n");
+            self.write_struct(out, &group, entries);
+            let _ = writeln!(out, "    impl {} {{", group);
+            self.write_codec(out, &group, entries);
+            let _ = writeln!(out, "    }}");
+        }
+    }
+
+    fn write_struct(&self, out: &mut String, name: &str, entries: &[Entry]) {
+        let _ = writeln!(out, "    #[derive(Clone, Debug, PartialEq)]");
+        let _ = writeln!(out, "    pub struct {} {{", name);
+        for entry in entries {
+            let (member, rust_type) = match entry {
+                Entry::Field { name, required } => {
+                    let field = self.field(name);
+                    let rust_type = rust_type(field);
+                    let rust_type = if *required || is_multiple(field) { rust_type } else { format!("Option<{}>", rust_type) };
+                    (snake(name), rust_type)
+                },
+                Entry::Group { name: group, .. } => (snake(group), format!("Vec<{}{}>", name, group)),
+            };
+            let _ = writeln!(out, "        pub {}: {},", member, rust_type);
+        }
+        let _ = writeln!(out, "    }}\n");
+    }
+
+    fn write_codec(&self, out: &mut String, name: &str, entries: &[Entry]) {
+        let _ = writeln!(out, "        #[allow(unused_variables)]");
+        let _ = writeln!(out, "        pub fn decode_fields(group: &Group) -> Result<{}, FieldError> {{", name);
+        let _ = writeln!(out, "            Ok({} {{", name);
+        for entry in entries {
+            match entry {
+                Entry::Field { name: field_name, required } => {
+                    let field = self.field(field_name);
+                    let tag = format!("&fields::{}", screaming(field_name));
+                    let get = if !field.values.is_empty() {
+                        let getter = if is_multiple(field) { "get_enums" } else { "get_enum" };
+                        format!("{}(group, {}, enums::{}::from_bytes)", getter, tag, field.name)
+                    } else {
+                        format!("group.{}({})", getter(&field.kind), tag)
+                    };
+                    let owned = if rust_type(field) == "String" { ".map(String::from)" } else { "" };
+                    let value = match (*required, is_multiple(field)) {
+                        (true, _) if owned.is_empty() => format!("{}?", get),
+                        (true, _) => format!("{}?.to_string()", get),
+                        (false, true) => format!("optional({})?.unwrap_or_default()", get),
+                        (false, false) => format!("optional({})?{}", get, owned),
+                    };
+                    let _ = writeln!(out, "                {}: {},", snake(field_name), value);
+                },
+                Entry::Group { name: group, .. } => {
+                    let _ = writeln!(out, "                {}: group.get_group(&{})?.iter().map({}{}::decode_fields).collect::<Result<_, _>>()?,",
+                                     snake(group), screaming(&format!("{}{}", name, group)), name, group);
+                },
+            }
+        }
+        let _ = writeln!(out, "            }})\n        }}\n");
+
+        let _ = writeln!(out, "        pub fn encode_fields(&self, builder: &mut FixMessageBuilder) {{");
+        for entry in entries {
+            match entry {
+                Entry::Field { name: field_name, required } => {
+                    let field = self.field(field_name);
+                    let member = snake(field_name);
+                    let tag = format!("fields::{}", screaming(field_name));
+                    let add = if is_multiple(field) && !field.values.is_empty() {
+                        format!("builder.add_string_field({}, &value.iter().map(|v| v.value()).collect::<Vec<_>>().join(\" \"));", tag)
+                    } else if !field.values.is_empty() {
+                        format!("builder.add_string_field({}, value.value());", tag)
+                    } else {
+                        match rust_type(field).as_str() {
+                            "i64" => format!("builder.add_int_field({}, *value);", tag),
+                            "Decimal" => format!("builder.add_decimal_field({}, *value);", tag),
+                            "char" => format!("builder.add_char_field({}, *value);", tag),
+                            "bool" => format!("builder.add_bool_field({}, *value);", tag),
+                            "NaiveDateTime" => format!("builder.add_timestamp_field({}, value.and_utc());", tag),
+                            _ => format!("builder.add_string_field({}, value);", tag),
+                        }
+                    };
+                    if is_multiple(field) && !field.values.is_empty() {
+                        let _ = writeln!(out, "            let value = &self.{};\n            if !value.is_empty() {{ {} }}", member, add);
+                    } else if *required {
+                        let _ = writeln!(out, "            let value = &self.{};\n            {}", member, add);
+                    } else {
+                        let _ = writeln!(out, "            if let Some(value) = &self.{} {{ {} }}", member, add);
+                    }
+                },
+                Entry::Group { name: group, .. } => {
+                    let _ = writeln!(out, "            builder.add_group(&{}, &self.{}, |builder, entry| entry.encode_fields(builder));",
+                                     screaming(&format!("{}{}", name, group)), snake(group));
+                },
+            }
+        }
+        let _ = writeln!(out, "        }}");
+    }
+}
+
+// The fields and groups of a message, component or group, with components flattened into it.
+fn entries(node: roxmltree::Node, components: &HashMap<&str, roxmltree::Node>, required: bool) -> Result<Vec<Entry>, String> {
+    let mut entries = Vec::new();
+    let mut seen = HashSet::new();
+    for child in node.children().filter(|n| n.is_element()) {
+        let name = child.attribute("name").unwrap_or("").to_string();
+        let child_required = required && child.attribute("required") == Some("Y");
+        match child.tag_name().name() {
+            "field" if seen.insert(name.clone()) => {
+                entries.push(Entry::Field { name, required: child_required });
+            },
+            "group" => {
+                let group_entries = entries_of_group(child, components)?;
+                if seen.insert(name.clone()) {
+                    entries.push(Entry::Group { name, entries: group_entries });
+                }
+            },
+            "component" => {
+                let component = components.get(name.as_str()).ok_or_else(|| format!("unknown component {}", name))?;
+                for entry in self::entries(*component, components, child_required)? {
+                    let entry_name = match &entry { Entry::Field { name, .. } | Entry::Group { name, .. } => name.clone() };
+                    if seen.insert(entry_name) {
+                        entries.push(entry);
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+    Ok(entries)
+}
+
+// Whether a member of a group is required only matters within an instance of the group.
+fn entries_of_group(node: roxmltree::Node, components: &HashMap<&str, roxmltree::Node>) -> Result<Vec<Entry>, String> {
+    let entries = entries(node, components, true)?;
+    if entries.is_empty() {
+        return Err(format!("group {:?} has no fields", node.attribute("name")));
+    }
+    Ok(entries)
+}
+
+// Mirrors ValueType::from_name, which the build script can't call.
+fn value_type(kind: &str) -> &'static str {
+    match kind {
+        "INT" | "LENGTH" | "SEQNUM" | "NUMINGROUP" | "TAGNUM" => "Int",
+        "DAYOFMONTH" => "DayOfMonth",
+        "FLOAT" | "PERCENTAGE" => "Float",
+        "QTY" => "Qty",
+        "PRICE" => "Price",
+        "PRICEOFFSET" => "PriceOffset",
+        "AMT" => "Amt",
+        "CHAR" => "Char",
+        "BOOLEAN" => "Boolean",
+        "MULTIPLEVALUESTRING" | "MULTIPLESTRINGVALUE" => "MultipleValueString",
+        "CURRENCY" => "Currency",
+        "COUNTRY" => "Country",
+        "EXCHANGE" => "Exchange",
+        "UTCTIMESTAMP" | "TZTIMESTAMP" | "TIME" => "UTCTimestamp",
+        "UTCTIMEONLY" | "TZTIMEONLY" => "UTCTimeOnly",
+        "LOCALMKTDATE" | "DATE" => "LocalMktDate",
+        "UTCDATE" | "UTCDATEONLY" => "UTCDate",
+        "MONTHYEAR" => "MonthYear",
+        "DATA" => "Data",
+        "XMLDATA" => "XmlData",
+        _ => "String",
+    }
+}
+
+fn is_multiple(field: &Field) -> bool {
+    value_type(&field.kind) == "MultipleValueString" && !field.values.is_empty()
+}
+
+fn rust_type(field: &Field) -> String {
+    if !field.values.is_empty() {
+        return if is_multiple(field) { format!("Vec<enums::{}>", field.name) } else { format!("enums::{}", field.name) };
+    }
+    match value_type(&field.kind) {
+        "Int" | "DayOfMonth" => "i64",
+        "Float" | "Qty" | "Price" | "PriceOffset" | "Amt" => "Decimal",
+        "Char" => "char",
+        "Boolean" => "bool",
+        "UTCTimestamp" => "NaiveDateTime",
+        _ => "String",
+    }.to_string()
+}
+
+fn getter(kind: &str) -> &'static str {
+    match value_type(kind) {
+        "Int" | "DayOfMonth" => "get_int",
+        "Float" | "Qty" | "Price" | "PriceOffset" | "Amt" => "get_decimal",
+        "Char" => "get_char",
+        "Boolean" => "get_bool",
+        "UTCTimestamp" => "get_timestamp",
+        _ => "get_str",
+    }
+}
+
+// One variant per value, named from its description, e.g. NOT_HELD is NotHeld. A description that
+// would give a clash or an invalid name gets the value appended.
+fn variants(field: &Field) -> Vec<(String, String)> {
+    let mut seen = HashSet::new();
+    field.values.iter().map(|(value, description)| {
+        let mut variant: String = description.split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(|word| {
+                let mut chars = word.chars();
+                let first = chars.next().unwrap().to_ascii_uppercase();
+                std::iter::once(first).chain(chars.map(|c| c.to_ascii_lowercase())).collect::<String>()
+            })
+            .collect();
+        if variant.is_empty() || variant.starts_with(|c: char| c.is_ascii_digit()) || variant == "Self" {
+            variant = format!("V{}", variant);
+        }
+        if !seen.insert(variant.clone()) {
+            variant = format!("{}{}", variant, value.chars().filter(|c| c.is_ascii_alphanumeric()).collect::<String>());
+            seen.insert(variant.clone());
+        }
+        (variant, value.clone())
+    }).collect()
+}
+
+// ClOrdID → [Cl, Ord, ID], NoMDEntries → [No, MD, Entries]
+fn words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = vec![String::new()];
+    for (i, c) in chars.iter().enumerate() {
+        let boundary = i > 0 && c.is_ascii_uppercase()
+            && (!chars[i - 1].is_ascii_uppercase() || chars.get(i + 1).is_some_and(|next| next.is_ascii_lowercase()));
+        if boundary {
+            words.push(String::new());
+        }
+        words.last_mut().unwrap().push(*c);
+    }
+    words
+}
+
+fn screaming(name: &str) -> String {
+    words(name).join("_").to_ascii_uppercase()
+}
+
+fn snake(name: &str) -> String {
+    let snake = words(name).join("_").to_ascii_lowercase();
+    match snake.as_str() {
+        "type" | "ref" | "match" | "in" | "as" | "use" | "mod" | "move" | "loop" | "where" | "yield" | "box" => format!("r#{}", snake),
+        _ => snake,
+    }
+}
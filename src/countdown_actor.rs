@@ -9,19 +9,30 @@ pub struct CountdownActor {
     heartbeat_internal_ms : u64,
     alarm_tx: mpsc::Sender<AlarmMessage>,
     reset_rx: mpsc::Receiver<ResetMessage>,
+    received_rx: mpsc::Receiver<ResetMessage>,
     interval_rx: mpsc::Receiver<u64>,
 }
 
-pub enum AlarmMessage { Alarm }
-pub enum ResetMessage { Reset }
+// Alarm: nothing has been sent for a heartbeat interval.
+// InboundTimeout: nothing has been received for a heartbeat interval plus a transmission margin.
+pub enum AlarmMessage { Alarm, InboundTimeout }
+
+// Reset: a message has just been written, Received: a message has just been read.
+// Each arrives on a channel of its own, so a burst of writes can't crowd out the reads.
+pub enum ResetMessage { Reset, Received }
+
+// Allowance for transmission delay on top of the heartbeat interval before we
+// consider the other side to have gone quiet, as a percentage of the interval.
+const INBOUND_MARGIN_PCT: u64 = 20;
 
 impl CountdownActor {
-    pub fn new(alarm_sender : mpsc::Sender<AlarmMessage>, interval_receiver : mpsc::Receiver<u64>, reset_receiver : mpsc::Receiver<ResetMessage>)  -> Self {
+    pub fn new(alarm_sender : mpsc::Sender<AlarmMessage>, interval_receiver : mpsc::Receiver<u64>, reset_receiver : mpsc::Receiver<ResetMessage>, received_receiver : mpsc::Receiver<ResetMessage>)  -> Self {
         Self {
             heartbeat_internal_ms: 0,
             alarm_tx: alarm_sender,
             interval_rx: interval_receiver,
             reset_rx: reset_receiver,
+            received_rx: received_receiver,
         }
     }
 
@@ -35,6 +46,10 @@ impl CountdownActor {
             let mut interval = time::interval(time::Duration::from_millis(hb));
             interval.tick().await;
 
+            // The inbound watchdog runs alongside, but is reset by reads rather than writes.
+            let mut watchdog = time::interval(time::Duration::from_millis(hb + hb * INBOUND_MARGIN_PCT / 100));
+            watchdog.tick().await;
+
             loop {
                 tokio::select! {
                     _ = interval.tick() => {
@@ -50,15 +65,87 @@ impl CountdownActor {
                            // println!("{}:CA: CountdownActor: Alarm sent", chrono::offset::Utc::now().format("%H:%M:%S.%3f").to_string());
                         }
                     }
+                    _ = watchdog.tick() => {
+                        if self.alarm_tx
+                            .send(AlarmMessage::InboundTimeout)
+                            .await
+                            .is_err()
+                        {
+                            eprintln!("CountdownActor: Failed to send InboundTimeout");
+                            break;
+                        }
+                    }
                     Some(_) = self.reset_rx.recv() => {
-                        // Reset the countdown
                         interval.reset();
                     }
+                    Some(_) = self.received_rx.recv() => {
+                        watchdog.reset();
+                    }
+                    _ = self.alarm_tx.closed() => {
+                        // Nobody is listening for alarms any more, the session has ended.
+                        break;
+                    }
                 }
                 yield_now().await;
             }
+            fix_println!("CountdownActor stopped.");
         } else {
             eprintln!("CountdownActor: Failed to receive initial interval");
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::time::{timeout, Duration, Instant};
+
+    struct Countdown {
+        alarm_rx    : mpsc::Receiver<AlarmMessage>,
+        reset_tx    : mpsc::Sender<ResetMessage>,
+        received_tx : mpsc::Sender<ResetMessage>,
+    }
+
+    fn start_countdown(hb: u64) -> Countdown {
+        let (alarm_tx, alarm_rx)       = mpsc::channel::<AlarmMessage>(1);
+        let (interval_tx, interval_rx) = mpsc::channel::<u64>(1);
+        let (reset_tx, reset_rx)       = mpsc::channel::<ResetMessage>(1);
+        let (received_tx, received_rx) = mpsc::channel::<ResetMessage>(1);
+        tokio::spawn(async move {
+            CountdownActor::new(alarm_tx, interval_rx, reset_rx, received_rx).start().await;
+        });
+        interval_tx.try_send(hb).unwrap();
+        Countdown { alarm_rx, reset_tx, received_tx }
+    }
+
+    async fn next_alarm(countdown: &mut Countdown) -> AlarmMessage {
+        timeout(Duration::from_secs(5), countdown.alarm_rx.recv()).await.expect("no alarm").expect("countdown stopped")
+    }
+
+    #[tokio::test]
+    async fn test_alarm_then_inbound_timeout_then_second_timeout() {
+        let mut countdown = start_countdown(100);
+
+        // Nothing is written or read, so the alarm goes at 100ms and the watchdog at 120ms, twice over.
+        assert!(matches!(next_alarm(&mut countdown).await, AlarmMessage::Alarm));
+        assert!(matches!(next_alarm(&mut countdown).await, AlarmMessage::InboundTimeout));
+        assert!(matches!(next_alarm(&mut countdown).await, AlarmMessage::Alarm));
+        assert!(matches!(next_alarm(&mut countdown).await, AlarmMessage::InboundTimeout));
+    }
+
+    #[tokio::test]
+    async fn test_received_is_not_lost_behind_writes() {
+        let mut countdown = start_countdown(200);
+        let started = Instant::now();
+
+        // Keep the write channel full while a read comes in, the watchdog must still be put back.
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        for _ in 0..8 { let _ = countdown.reset_tx.try_send(ResetMessage::Reset); }
+        countdown.received_tx.try_send(ResetMessage::Received).unwrap();
+
+        loop {
+            if let AlarmMessage::InboundTimeout = next_alarm(&mut countdown).await { break; }
+        }
+        assert!(started.elapsed() >= Duration::from_millis(340), "InboundTimeout after {:?}", started.elapsed());
+    }
+}
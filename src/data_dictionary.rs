@@ -0,0 +1,592 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use crate::fix_42::attribute_enums::{MsgType, SessionRejectReason};
+use crate::fix_42::value_types::ValueType;
+use crate::fix_message::FixMessage;
+
+const BEGIN_STRING: u32 = 8;
+const BODY_LENGTH: u32 = 9;
+const MSG_TYPE: u32 = 35;
+const CHECK_SUM: u32 = 10;
+
+#[derive(Debug)]
+pub enum DictionaryError {
+    Io(std::io::Error),
+    Xml(roxmltree::Error),
+    // Well formed XML, but not a spec we can make sense of, e.g. a message using a field that isn't defined.
+    Invalid(String),
+}
+
+impl Display for DictionaryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DictionaryError::Io(e) => write!(f, "Unable to read data dictionary: {}", e),
+            DictionaryError::Xml(e) => write!(f, "Data dictionary isn't valid XML: {}", e),
+            DictionaryError::Invalid(text) => write!(f, "Invalid data dictionary: {}", text),
+        }
+    }
+}
+
+// Which of the checks in DataDictionary::validate are made. Everything is on unless the session's
+// config turns it off.
+#[derive(Clone, Copy, Debug)]
+pub struct ValidationSettings {
+    // Tags that aren't in the dictionary, or aren't defined for the message they're in.
+    pub unknown_fields  : bool,
+    pub required_fields : bool,
+    pub enum_values     : bool,
+    pub data_types      : bool,
+    // BeginString, BodyLength and MsgType first, CheckSum last, and the header and trailer kept
+    // apart from the body.
+    pub field_order     : bool,
+}
+
+impl ValidationSettings {
+    pub fn from_config(config: &HashMap<String, String>) -> ValidationSettings {
+
+        let flag = |key: &str| config.get(key).is_none_or(|v| v == "true" || v == "Y");
+
+        ValidationSettings {
+            unknown_fields  : flag("validate_unknown_fields"),
+            required_fields : flag("validate_required_fields"),
+            enum_values     : flag("validate_enum_values"),
+            data_types      : flag("validate_data_types"),
+            field_order     : flag("validate_field_order"),
+        }
+    }
+}
+
+// The first problem found with a message. The reason is left out where the FIX version in use
+// doesn't define one for the problem.
+#[derive(Debug, PartialEq)]
+pub struct ValidationError {
+    pub reason : Option<SessionRejectReason>,
+    pub tag    : Option<u32>,
+    pub text   : String,
+}
+
+pub struct FieldDef {
+    pub value_type : ValueType,
+    // The enumerated values, empty if the field takes any value of its type.
+    pub values     : HashSet<String>,
+    multiple_value : bool,
+}
+
+// The fields and groups allowed in part of a message, in the order the spec lists them.
+#[derive(Default)]
+pub struct FieldSet {
+    fields : Vec<(u32, bool)>,
+    groups : Vec<GroupSpec>,
+}
+
+pub struct GroupSpec {
+    pub count_tag : u32,
+    pub required  : bool,
+    // The first field of the group, which every instance starts with.
+    pub delimiter : u32,
+    pub members   : FieldSet,
+}
+
+impl FieldSet {
+
+    fn has_field(&self, tag: u32) -> bool {
+        self.fields.iter().any(|(t, _)| *t == tag)
+    }
+
+    fn group(&self, count_tag: u32) -> Option<&GroupSpec> {
+        self.groups.iter().find(|group| group.count_tag == count_tag)
+    }
+
+    // Every tag that can appear, including those within groups at any depth.
+    fn all_tags(&self, tags: &mut HashSet<u32>) {
+        tags.extend(self.fields.iter().map(|(t, _)| *t));
+        for group in &self.groups {
+            tags.insert(group.count_tag);
+            group.members.all_tags(tags);
+        }
+    }
+
+    fn required(&self) -> impl Iterator<Item = u32> + '_ {
+        self.fields.iter().filter(|(_, required)| *required).map(|(t, _)| *t)
+            .chain(self.groups.iter().filter(|g| g.required).map(|g| g.count_tag))
+    }
+}
+
+pub struct MessageDef {
+    pub body : FieldSet,
+}
+
+// A FIX data dictionary loaded from a QuickFIX style XML spec, e.g. spec/FIX42.xml. It knows every
+// field, its type and enumerated values, and which fields and groups make up each message.
+pub struct DataDictionary {
+    major        : u32,
+    minor        : u32,
+    fields       : HashMap<u32, FieldDef>,
+    header       : FieldSet,
+    trailer      : FieldSet,
+    messages     : HashMap<String, MessageDef>,
+    header_tags  : HashSet<u32>,
+    trailer_tags : HashSet<u32>,
+}
+
+impl DataDictionary {
+
+    pub fn load(path: &str) -> Result<DataDictionary, DictionaryError> {
+        let xml = std::fs::read_to_string(path).map_err(DictionaryError::Io)?;
+        DataDictionary::parse(&xml)
+    }
+
+    pub fn parse(xml: &str) -> Result<DataDictionary, DictionaryError> {
+
+        let doc = roxmltree::Document::parse(xml).map_err(DictionaryError::Xml)?;
+        let root = doc.root_element();
+        let child = |name: &str| root.children().find(|n| n.has_tag_name(name));
+
+        let version = |name: &str| root.attribute(name).and_then(|v| v.parse().ok())
+            .ok_or_else(|| DictionaryError::Invalid(format!("missing or invalid {} version", name)));
+        let (major, minor) = (version("major")?, version("minor")?);
+
+        // Fields first, everything else refers to them by name.
+        let mut fields = HashMap::new();
+        let mut numbers = HashMap::new();
+        for node in child("fields").iter().flat_map(|n| n.children()).filter(|n| n.has_tag_name("field")) {
+
+            let name = required_attribute(node, "name")?;
+            let number = required_attribute(node, "number")?.parse::<u32>()
+                .map_err(|_| DictionaryError::Invalid(format!("field {} has an invalid number", name)))?;
+            let type_name = required_attribute(node, "type")?;
+            let value_type = ValueType::from_name(type_name)
+                .ok_or_else(|| DictionaryError::Invalid(format!("field {} has unknown type {}", name, type_name)))?;
+            let values = node.children()
+                .filter(|n| n.has_tag_name("value"))
+                .filter_map(|n| n.attribute("enum"))
+                .map(String::from)
+                .collect();
+
+            numbers.insert(name, number);
+            fields.insert(number, FieldDef {
+                value_type,
+                values,
+                multiple_value: type_name == "MULTIPLEVALUESTRING" || type_name == "MULTIPLECHARVALUE",
+            });
+        }
+
+        let components: HashMap<&str, roxmltree::Node> = child("components").iter()
+            .flat_map(|n| n.children())
+            .filter(|n| n.has_tag_name("component"))
+            .filter_map(|n| n.attribute("name").map(|name| (name, n)))
+            .collect();
+
+        let loader = SetLoader { numbers: &numbers, components: &components };
+
+        let header = match child("header") { Some(node) => loader.load(node)?, None => FieldSet::default() };
+        let trailer = match child("trailer") { Some(node) => loader.load(node)?, None => FieldSet::default() };
+
+        let mut messages = HashMap::new();
+        for node in child("messages").iter().flat_map(|n| n.children()).filter(|n| n.has_tag_name("message")) {
+            let msg_type = String::from(required_attribute(node, "msgtype")?);
+            messages.insert(msg_type, MessageDef { body: loader.load(node)? });
+        }
+
+        let mut header_tags = HashSet::new();
+        header.all_tags(&mut header_tags);
+        let mut trailer_tags = HashSet::new();
+        trailer.all_tags(&mut trailer_tags);
+
+        Ok(DataDictionary { major, minor, fields, header, trailer, messages, header_tags, trailer_tags })
+    }
+
+    // The BeginString of the FIX version the spec is for, which the session has to be using.
+    pub fn begin_string(&self) -> String {
+        if self.major >= 5 { String::from("FIXT.1.1") } else { format!("FIX.{}.{}", self.major, self.minor) }
+    }
+
+    // Checks an inbound message against the dictionary, stopping at the first problem.
+    pub fn validate(&self, msg: &FixMessage, settings: &ValidationSettings) -> Result<(), ValidationError> {
+
+        let fields: Vec<(u32, &[u8])> = msg.fields().collect();
+
+        if settings.field_order {
+            self.check_order(&fields)?;
+        }
+
+        for (tag, value) in &fields {
+            self.check_value(*tag, value, settings)?;
+        }
+
+        // A standard MsgType the spec leaves out is still valid, it's for the application to turn it
+        // away with a BusinessMessageReject. Only its header and trailer can be checked.
+        let message = self.messages.get(msg.get_msg_type().as_str());
+        if message.is_none() && settings.unknown_fields && matches!(msg.get_msg_type(), MsgType::Custom(_)) {
+            return Err(self.error(SessionRejectReason::InvalidMsgType, Some(MSG_TYPE), format!("Invalid MsgType {}", msg.get_msg_type())));
+        }
+
+        let (mut header, mut body, mut trailer) = (Vec::new(), Vec::new(), Vec::new());
+        for field in fields {
+            if self.header_tags.contains(&field.0) {
+                header.push(field);
+            } else if self.trailer_tags.contains(&field.0) {
+                trailer.push(field);
+            } else {
+                body.push(field);
+            }
+        }
+
+        self.check_set(&self.header, &header, &mut 0, true, settings)?;
+        if let Some(message) = message {
+            self.check_set(&message.body, &body, &mut 0, true, settings)?;
+        }
+        self.check_set(&self.trailer, &trailer, &mut 0, true, settings)
+    }
+
+    fn check_order(&self, fields: &[(u32, &[u8])]) -> Result<(), ValidationError> {
+
+        let out_of_order = |tag: u32| self.error(SessionRejectReason::TagSpecifiedOutOfRequiredOrder, Some(tag), format!("Tag {} specified out of required order", tag));
+
+        for (position, expected) in [BEGIN_STRING, BODY_LENGTH, MSG_TYPE].into_iter().enumerate() {
+            if fields.get(position).map(|(tag, _)| *tag) != Some(expected) {
+                return Err(out_of_order(expected));
+            }
+        }
+        if fields.last().map(|(tag, _)| *tag) != Some(CHECK_SUM) {
+            return Err(out_of_order(CHECK_SUM));
+        }
+
+        // Header, then body, then trailer, never going back.
+        let mut section = 0;
+        for (tag, _) in fields {
+            let field_section = if self.header_tags.contains(tag) { 0 } else if self.trailer_tags.contains(tag) { 2 } else { 1 };
+            if field_section < section {
+                return Err(out_of_order(*tag));
+            }
+            section = field_section;
+        }
+        Ok(())
+    }
+
+    fn check_value(&self, tag: u32, value: &[u8], settings: &ValidationSettings) -> Result<(), ValidationError> {
+
+        let Some(field) = self.fields.get(&tag) else {
+            // 5000 - 9999 is reserved for user defined fields, which no dictionary knows about.
+            if settings.unknown_fields && !(5000..=9999).contains(&tag) {
+                return Err(self.error(SessionRejectReason::InvalidTagNumber, Some(tag), format!("Invalid tag number {}", tag)));
+            }
+            return Ok(());
+        };
+
+        if settings.data_types {
+            if value.is_empty() {
+                return Err(self.error(SessionRejectReason::TagSpecifiedWithoutValue, Some(tag), format!("Tag {} specified without a value", tag)));
+            }
+            if !std::str::from_utf8(value).is_ok_and(|v| field.value_type.is_valid(v)) {
+                return Err(self.error(SessionRejectReason::IncorrectDataFormat, Some(tag), format!("Incorrect data format for value of tag {}", tag)));
+            }
+        }
+
+        // An unknown MsgType is reported as such once the value checks are done.
+        if settings.enum_values && !field.values.is_empty() && tag != MSG_TYPE {
+            let value = String::from_utf8_lossy(value);
+            let valid = if field.multiple_value {
+                value.split(' ').all(|v| field.values.contains(v))
+            } else {
+                field.values.contains(value.as_ref())
+            };
+            if !valid {
+                return Err(self.error(SessionRejectReason::ValueIsIncorrect, Some(tag), format!("Value is incorrect (out of range) for tag {}", tag)));
+            }
+        }
+        Ok(())
+    }
+
+    // Works through fields from *pos for as long as they belong to the set, descending into any
+    // groups. At the top of a message every field has to be accounted for, while within a group
+    // instance the first field that isn't a member, or that repeats, ends the instance.
+    fn check_set(&self, set: &FieldSet, fields: &[(u32, &[u8])], pos: &mut usize, top_level: bool, settings: &ValidationSettings) -> Result<(), ValidationError> {
+
+        let mut seen = HashSet::new();
+
+        while let Some((tag, value)) = fields.get(*pos) {
+
+            if !top_level && seen.contains(tag) {
+                break;
+            }
+
+            if let Some(group) = set.group(*tag) {
+                *pos += 1;
+                seen.insert(*tag);
+
+                let count: usize = std::str::from_utf8(value).ok().and_then(|v| v.parse().ok()).unwrap_or(0);
+                let mut instances = 0;
+                while fields.get(*pos).is_some_and(|(t, _)| *t == group.delimiter) {
+                    self.check_set(&group.members, fields, pos, false, settings)?;
+                    instances += 1;
+                }
+                if instances != count {
+                    return Err(self.error(SessionRejectReason::IncorrectNumInGroupCount, Some(*tag), format!("Incorrect NumInGroup count for repeating group {}", tag)));
+                }
+                continue;
+            }
+
+            if set.has_field(*tag) {
+                seen.insert(*tag);
+                *pos += 1;
+                continue;
+            }
+
+            if !top_level {
+                break;
+            }
+            if settings.unknown_fields && self.fields.contains_key(tag) {
+                return Err(self.error(SessionRejectReason::TagNotDefinedForMsgType, Some(*tag), format!("Tag {} not defined for this message type", tag)));
+            }
+            *pos += 1;
+        }
+
+        if settings.required_fields {
+            if let Some(missing) = set.required().find(|tag| !seen.contains(tag)) {
+                return Err(self.error(SessionRejectReason::RequiredTagMissing, Some(missing), format!("Required tag {} missing", missing)));
+            }
+        }
+        Ok(())
+    }
+
+    // SessionRejectReason only goes up to 11 before FIX 4.3, anything later is sent as text alone.
+    fn error(&self, reason: SessionRejectReason, tag: Option<u32>, text: String) -> ValidationError {
+        let defined = self.major > 4 || self.minor >= 3 || reason.value() <= SessionRejectReason::InvalidMsgType.value();
+        ValidationError {
+            reason: if defined { Some(reason) } else { None },
+            tag,
+            text,
+        }
+    }
+}
+
+fn required_attribute<'a>(node: roxmltree::Node<'a, '_>, name: &str) -> Result<&'a str, DictionaryError> {
+    node.attribute(name).ok_or_else(|| DictionaryError::Invalid(format!("<{}> without a {} attribute", node.tag_name().name(), name)))
+}
+
+// Turns the <field>, <group> and <component> entries under a header, trailer, message, group or
+// component into a FieldSet, with names resolved to tag numbers and components flattened out.
+struct SetLoader<'a, 'input> {
+    numbers    : &'a HashMap<&'a str, u32>,
+    components : &'a HashMap<&'a str, roxmltree::Node<'a, 'input>>,
+}
+
+impl SetLoader<'_, '_> {
+
+    fn load(&self, node: roxmltree::Node) -> Result<FieldSet, DictionaryError> {
+        let mut set = FieldSet::default();
+        self.load_into(node, true, &mut set)?;
+        Ok(set)
+    }
+
+    // Members of an optional component are only required when the component is there at all,
+    // which is more than a flat set can say, so they're treated as optional.
+    fn load_into(&self, node: roxmltree::Node, required: bool, set: &mut FieldSet) -> Result<(), DictionaryError> {
+
+        for entry in node.children().filter(|n| n.is_element()) {
+
+            let name = required_attribute(entry, "name")?;
+            let entry_required = required && entry.attribute("required") == Some("Y");
+
+            match entry.tag_name().name() {
+                "field" => set.fields.push((self.number(name)?, entry_required)),
+                "group" => {
+                    set.groups.push(GroupSpec {
+                        count_tag : self.number(name)?,
+                        required  : entry_required,
+                        delimiter : self.first_tag(entry)?,
+                        members   : self.load(entry)?,
+                    });
+                }
+                "component" => {
+                    let component = self.components.get(name)
+                        .ok_or_else(|| DictionaryError::Invalid(format!("unknown component {}", name)))?;
+                    self.load_into(*component, entry_required, set)?;
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    // Whichever field or group is listed first, looking inside a component if that comes first.
+    fn first_tag(&self, node: roxmltree::Node) -> Result<u32, DictionaryError> {
+
+        let name = node.attribute("name").unwrap_or_default();
+        let first = node.children().find(|n| n.is_element())
+            .ok_or_else(|| DictionaryError::Invalid(format!("{} has no fields", name)))?;
+        let first_name = required_attribute(first, "name")?;
+
+        match first.tag_name().name() {
+            "component" => {
+                let component = self.components.get(first_name)
+                    .ok_or_else(|| DictionaryError::Invalid(format!("unknown component {}", first_name)))?;
+                self.first_tag(*component)
+            }
+            _ => self.number(first_name),
+        }
+    }
+
+    fn number(&self, name: &str) -> Result<u32, DictionaryError> {
+        self.numbers.get(name).copied().ok_or_else(|| DictionaryError::Invalid(format!("unknown field {}", name)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use crate::spec::fix42::fields;
+    use crate::fix_msg_builder::{FixMessageBuilder, SessionHeader};
+
+    const FIX42: &str = include_str!("../spec/FIX42.xml");
+
+    const ALL: ValidationSettings = ValidationSettings {
+        unknown_fields: true, required_fields: true, enum_values: true, data_types: true, field_order: true
+    };
+
+    fn builder() -> FixMessageBuilder {
+        FixMessageBuilder::new(SessionHeader::new("FIX.4.2", "TEST_CLIENT", "TEST_SERVER"))
+    }
+
+    fn new_order_single(builder: &mut FixMessageBuilder) -> &mut FixMessageBuilder {
+        builder.start(MsgType::NewOrderSingle)
+            .add_string_field(fields::CL_ORD_ID, "ORD1")
+            .add_char_field(fields::HANDL_INST, '1')
+            .add_string_field(fields::SYMBOL, "IBM")
+            .add_char_field(fields::SIDE, '1')
+            .add_string_field(fields::TRANSACT_TIME, "20250119-16:13:08.931")
+            .add_int_field(fields::ORDER_QTY, 100)
+            .add_char_field(fields::ORD_TYPE, '2')
+            .add_string_field(fields::PRICE, "10.25")
+    }
+
+    fn validate(dictionary: &DataDictionary, msg: Bytes, settings: &ValidationSettings) -> Result<(), ValidationError> {
+        dictionary.validate(&FixMessage::new(msg), settings)
+    }
+
+    #[test]
+    fn test_load_fix42() {
+
+        let dictionary = DataDictionary::parse(FIX42).unwrap();
+
+        assert_eq!(dictionary.begin_string(), "FIX.4.2");
+        assert_eq!(dictionary.fields[&44].value_type, ValueType::Price);
+        assert!(dictionary.fields[&54].values.contains("2"));
+        assert!(dictionary.messages.contains_key("A"));
+        assert!(dictionary.messages["D"].body.has_field(11));
+    }
+
+    #[test]
+    fn test_valid_message_passes() {
+
+        let dictionary = DataDictionary::parse(FIX42).unwrap();
+        let msg = new_order_single(&mut builder()).add_string_field(fields::EXEC_INST, "1 G").finish(1);
+
+        assert_eq!(validate(&dictionary, msg, &ALL), Ok(()));
+    }
+
+    #[test]
+    fn test_each_check() {
+
+        let dictionary = DataDictionary::parse(FIX42).unwrap();
+        let check = |msg: Bytes| validate(&dictionary, msg, &ALL).unwrap_err();
+
+        let e = check(builder().start(MsgType::NewOrderSingle).add_string_field(fields::CL_ORD_ID, "ORD1").finish(1));
+        assert_eq!((e.reason, e.tag), (Some(SessionRejectReason::RequiredTagMissing), Some(21)));
+
+        let e = check(new_order_single(&mut builder()).add_char_field(fields::TIME_IN_FORCE, 'X').finish(1));
+        assert_eq!((e.reason, e.tag), (Some(SessionRejectReason::ValueIsIncorrect), Some(59)));
+
+        let e = check(new_order_single(&mut builder()).add_string_field(fields::MIN_QTY, "lots").finish(1));
+        assert_eq!((e.reason, e.tag), (Some(SessionRejectReason::IncorrectDataFormat), Some(110)));
+
+        let e = check(new_order_single(&mut builder()).add_raw_field("4999", b"x").finish(1));
+        assert_eq!((e.reason, e.tag), (Some(SessionRejectReason::InvalidTagNumber), Some(4999)));
+
+        let e = check(new_order_single(&mut builder()).add_string_field(fields::HEADLINE, "x").finish(1));
+        assert_eq!((e.reason, e.tag), (Some(SessionRejectReason::TagNotDefinedForMsgType), Some(148)));
+
+        // Out of order isn't a SessionRejectReason until FIX 4.3, so only the text says what's wrong.
+        let e = check(new_order_single(&mut builder()).add_string_field(fields::SENDER_SUB_ID, "DESK1").finish(1));
+        assert_eq!((e.reason, e.tag), (None, Some(50)));
+        assert_eq!(e.text, "Tag 50 specified out of required order");
+
+        let e = check(builder().start(MsgType::Custom(String::from("U1"))).finish(1));
+        assert_eq!((e.reason, e.tag), (Some(SessionRejectReason::InvalidMsgType), Some(35)));
+
+        let ioi = builder().start(MsgType::IndicationOfInterest).add_string_field(fields::SYMBOL, "IBM").finish(1);
+        let e = check(ioi.clone());
+        assert_eq!((e.reason, e.tag), (Some(SessionRejectReason::RequiredTagMissing), Some(23)));
+
+        // A spec that leaves out a FIX 4.2 message lets it through, for the application to turn away.
+        let mut partial = DataDictionary::parse(FIX42).unwrap();
+        partial.messages.remove("6");
+        assert_eq!(validate(&partial, ioi, &ALL), Ok(()));
+    }
+
+    #[test]
+    fn test_checks_can_be_turned_off() {
+
+        let dictionary = DataDictionary::parse(FIX42).unwrap();
+        let mut config = HashMap::new();
+        for key in ["validate_unknown_fields", "validate_required_fields", "validate_enum_values", "validate_data_types", "validate_field_order"] {
+            config.insert(String::from(key), String::from("false"));
+        }
+        let none = ValidationSettings::from_config(&config);
+
+        let msg = builder().start(MsgType::NewOrderSingle)
+            .add_char_field(fields::TIME_IN_FORCE, 'X')
+            .add_string_field(fields::MIN_QTY, "lots")
+            .add_raw_field("4999", b"x")
+            .add_string_field(fields::SENDER_SUB_ID, "DESK1")
+            .finish(1);
+
+        assert_eq!(validate(&dictionary, msg, &none), Ok(()));
+    }
+
+    #[test]
+    fn test_groups() {
+
+        let dictionary = DataDictionary::parse(FIX42).unwrap();
+        let orders = [("ORD1", vec!["ACC1", "ACC2"]), ("ORD2", vec![])];
+        let list = |count: usize| {
+            let mut builder = builder();
+            builder.start(MsgType::Custom(String::from("E")))
+                .add_string_field(fields::LIST_ID, "LIST1")
+                .add_int_field(fields::BID_TYPE, 3)
+                .add_int_field(fields::TOT_NO_ORDERS, 2)
+                .add_group(&crate::spec::fix42::messages::NEW_ORDER_LIST_NO_ORDERS, &orders[..count], |builder, (cl_ord_id, accounts)| {
+                    builder.add_string_field(fields::CL_ORD_ID, cl_ord_id)
+                        .add_int_field(fields::LIST_SEQ_NO, 1)
+                        .add_group(&crate::spec::fix42::messages::NEW_ORDER_LIST_NO_ORDERS_NO_ALLOCS, accounts, |builder, account| {
+                            builder.add_string_field(fields::ALLOC_ACCOUNT, account);
+                        })
+                        .add_string_field(fields::SYMBOL, "IBM")
+                        .add_char_field(fields::SIDE, '1');
+                });
+            builder.finish(1)
+        };
+
+        assert_eq!(validate(&dictionary, list(2), &ALL), Ok(()));
+
+        // NoOrders says 2 but only one order follows.
+        let msg = String::from_utf8(list(1).to_vec()).unwrap().replace("\x0173=1\x01", "\x0173=2\x01");
+        let e = validate(&dictionary, Bytes::from(msg), &ALL).unwrap_err();
+        assert_eq!((e.reason, e.tag), (None, Some(73)));
+
+        // A group member that's required but missing.
+        let mut builder = builder();
+        let msg = builder.start(MsgType::Custom(String::from("E")))
+            .add_string_field(fields::LIST_ID, "LIST1")
+            .add_int_field(fields::BID_TYPE, 3)
+            .add_int_field(fields::TOT_NO_ORDERS, 1)
+            .add_group(&crate::spec::fix42::messages::NEW_ORDER_LIST_NO_ORDERS, &["ORD1"], |builder, cl_ord_id| {
+                builder.add_string_field(fields::CL_ORD_ID, cl_ord_id).add_int_field(fields::LIST_SEQ_NO, 1).add_char_field(fields::SIDE, '1');
+            })
+            .finish(1);
+        let e = validate(&dictionary, msg, &ALL).unwrap_err();
+        assert_eq!((e.reason, e.tag), (Some(SessionRejectReason::RequiredTagMissing), Some(55)));
+    }
+}
@@ -1,34 +1,45 @@
 use std::clone::Clone;
+use std::convert::Infallible;
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
-pub trait FixEnum {
-    fn value(&self) -> char;
+// A field whose value is one of a fixed set, e.g. Side (54). The enums themselves are generated
+// from the spec along with the tags and messages, those of FIX 4.2 are re-exported here.
+pub trait FixEnum: Sized {
+    // The value as it's sent, e.g. "1" for Side::Buy.
+    fn value(&self) -> &'static str;
+
+    fn from_bytes(value: &[u8]) -> Option<Self>;
 }
 
-pub enum EncryptMethod {
-    NONE,
-    PKCS,
-    DES,
-    PkcsDes,
-    PGP_DES,
-    PGP_DES_MD5,
-    PEM_DES_MD5
+#[allow(unused_imports)]
+pub use crate::spec::fix42::enums::{
+    BusinessRejectReason, CxlRejReason, CxlRejResponseTo, EncryptMethod, ExecInst, ExecTransType, ExecType,
+    HandlInst, IDSource, OrdRejReason, OrdStatus, OrdType, SecurityType, Side, TimeInForce,
+};
+
+// A value that isn't one of those defined for the field.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownEnumValue {
+    pub field : &'static str,
+    pub value : String
 }
 
-impl FixEnum for EncryptMethod {
-    fn value(&self) -> char {
-        match self {
-            EncryptMethod::NONE           => '0',
-            EncryptMethod::PKCS           => '1',
-            EncryptMethod::DES            => '2',
-            EncryptMethod::PkcsDes        => '3',
-            EncryptMethod::PGP_DES        => '4',
-            EncryptMethod::PGP_DES_MD5    => '5',
-            EncryptMethod::PEM_DES_MD5    => '6',
-        }
+impl UnknownEnumValue {
+    pub fn new(field: &'static str, value: &[u8]) -> UnknownEnumValue {
+        UnknownEnumValue { field, value: String::from_utf8_lossy(value).into_owned() }
+    }
+}
+
+impl Display for UnknownEnumValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} is not a valid {}", self.value, self.field)
     }
 }
 
+// MsgType (35). A string as FIX 4.3 onwards has multi-character types like "AE", and anything
+// we don't know, e.g. a user defined "U1", is kept as Custom so it still round trips.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum MsgType {
     HeartBeat,
     TestRequest,
@@ -46,7 +57,7 @@ pub enum MsgType {
     NewOrderSingle,
     NewOrderList,
     OrderCancelRequest,
-    OrderCancelReplaceRequest ,
+    OrderCancelReplaceRequest,
     OrderStatusRequest,
     Allocation,
     ListCancelRequest,
@@ -75,159 +86,224 @@ pub enum MsgType {
     BusinessMessageReject,
     BidRequest,
     BidResponse,
-    ListStrikePrice
+    ListStrikePrice,
+    Custom(String)
 }
 
-impl FixEnum for MsgType {
-    fn value(&self) -> char {
+impl MsgType {
+    pub fn as_str(&self) -> &str {
         match self {
-            MsgType::HeartBeat                           =>  '0',
-            MsgType::TestRequest                         =>  '1',
-            MsgType::ResendRequest                       =>  '2',
-            MsgType::Reject                              =>  '3',
-            MsgType::SequenceReset                       =>  '4',
-            MsgType::Logout                              =>  '5',
-            MsgType::IndicationOfInterest                =>  '6',
-            MsgType::Advertistment                       =>  '7',
-            MsgType::ExecutionReport                     =>  '8',
-            MsgType::OrderCancelReject                   =>  '9',
-            MsgType::Logon                               =>  'A',
-            MsgType::News                                =>  'B',
-            MsgType::Email                               =>  'C',
-            MsgType::NewOrderSingle                      =>  'D',
-            MsgType::NewOrderList                        =>  'E',
-            MsgType::OrderCancelRequest                  =>  'F',
-            MsgType::OrderCancelReplaceRequest           =>  'G',
-            MsgType::OrderStatusRequest                  =>  'H',
-            MsgType::Allocation                          =>  'J',
-            MsgType::ListCancelRequest                   =>  'K',
-            MsgType::ListExecute                         =>  'L',
-            MsgType::ListStatusRequest                   =>  'M',
-            MsgType::ListStatus                          =>  'N',
-            MsgType::AllocationAck                       =>  'P',
-            MsgType::DontKnowTrade                       =>  'Q',
-            MsgType::QuoteRequest                        =>  'R',
-            MsgType::Quote                               =>  'S',
-            MsgType::SettlementInstructions              =>  'T',
-            MsgType::MarketDataRequest                   =>  'V',
-            MsgType::MarketDataSnapshotFullRefresh       =>  'W',
-            MsgType::MarketDataIncrementalRefresh        =>  'X',
-            MsgType::MarketDataRequestReject             =>  'Y',
-            MsgType::QuoteCancel                         =>  'Z',
-            MsgType::QuoteStatusRequest                  =>  'a',
-            MsgType::QuoteAcknowledgement                =>  'b',
-            MsgType::SecurityDefinitionRequest           =>  'c',
-            MsgType::SecurityDefinition                  =>  'd',
-            MsgType::SecurityStatusRequest               =>  'e',
-            MsgType::SecurityStatus                      =>  'f',
-            MsgType::TradingSessionStatusRequest         =>  'g',
-            MsgType::TradingSessionStatus                =>  'h',
-            MsgType::MassQuote                           =>  'i',
-            MsgType::BusinessMessageReject               =>  'j',
-            MsgType::BidRequest                          =>  'k',
-            MsgType::BidResponse                         =>  'l',
-            MsgType::ListStrikePrice                     =>  'm'
+            MsgType::HeartBeat                       =>  "0",
+            MsgType::TestRequest                     =>  "1",
+            MsgType::ResendRequest                   =>  "2",
+            MsgType::Reject                          =>  "3",
+            MsgType::SequenceReset                   =>  "4",
+            MsgType::Logout                          =>  "5",
+            MsgType::IndicationOfInterest            =>  "6",
+            MsgType::Advertistment                   =>  "7",
+            MsgType::ExecutionReport                 =>  "8",
+            MsgType::OrderCancelReject               =>  "9",
+            MsgType::Logon                           =>  "A",
+            MsgType::News                            =>  "B",
+            MsgType::Email                           =>  "C",
+            MsgType::NewOrderSingle                  =>  "D",
+            MsgType::NewOrderList                    =>  "E",
+            MsgType::OrderCancelRequest              =>  "F",
+            MsgType::OrderCancelReplaceRequest       =>  "G",
+            MsgType::OrderStatusRequest              =>  "H",
+            MsgType::Allocation                      =>  "J",
+            MsgType::ListCancelRequest               =>  "K",
+            MsgType::ListExecute                     =>  "L",
+            MsgType::ListStatusRequest               =>  "M",
+            MsgType::ListStatus                      =>  "N",
+            MsgType::AllocationAck                   =>  "P",
+            MsgType::DontKnowTrade                   =>  "Q",
+            MsgType::QuoteRequest                    =>  "R",
+            MsgType::Quote                           =>  "S",
+            MsgType::SettlementInstructions          =>  "T",
+            MsgType::MarketDataRequest               =>  "V",
+            MsgType::MarketDataSnapshotFullRefresh   =>  "W",
+            MsgType::MarketDataIncrementalRefresh    =>  "X",
+            MsgType::MarketDataRequestReject         =>  "Y",
+            MsgType::QuoteCancel                     =>  "Z",
+            MsgType::QuoteStatusRequest              =>  "a",
+            MsgType::QuoteAcknowledgement            =>  "b",
+            MsgType::SecurityDefinitionRequest       =>  "c",
+            MsgType::SecurityDefinition              =>  "d",
+            MsgType::SecurityStatusRequest           =>  "e",
+            MsgType::SecurityStatus                  =>  "f",
+            MsgType::TradingSessionStatusRequest     =>  "g",
+            MsgType::TradingSessionStatus            =>  "h",
+            MsgType::MassQuote                       =>  "i",
+            MsgType::BusinessMessageReject           =>  "j",
+            MsgType::BidRequest                      =>  "k",
+            MsgType::BidResponse                     =>  "l",
+            MsgType::ListStrikePrice                 =>  "m",
+            MsgType::Custom(value)                   =>  value.as_str()
         }
     }
 }
 
-impl Display for MsgType {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.value())
+impl From<&str> for MsgType {
+    fn from(value: &str) -> Self {
+        match value {
+            "0" => MsgType::HeartBeat,
+            "1" => MsgType::TestRequest,
+            "2" => MsgType::ResendRequest,
+            "3" => MsgType::Reject,
+            "4" => MsgType::SequenceReset,
+            "5" => MsgType::Logout,
+            "6" => MsgType::IndicationOfInterest,
+            "7" => MsgType::Advertistment,
+            "8" => MsgType::ExecutionReport,
+            "9" => MsgType::OrderCancelReject,
+            "A" => MsgType::Logon,
+            "B" => MsgType::News,
+            "C" => MsgType::Email,
+            "D" => MsgType::NewOrderSingle,
+            "E" => MsgType::NewOrderList,
+            "F" => MsgType::OrderCancelRequest,
+            "G" => MsgType::OrderCancelReplaceRequest,
+            "H" => MsgType::OrderStatusRequest,
+            "J" => MsgType::Allocation,
+            "K" => MsgType::ListCancelRequest,
+            "L" => MsgType::ListExecute,
+            "M" => MsgType::ListStatusRequest,
+            "N" => MsgType::ListStatus,
+            "P" => MsgType::AllocationAck,
+            "Q" => MsgType::DontKnowTrade,
+            "R" => MsgType::QuoteRequest,
+            "S" => MsgType::Quote,
+            "T" => MsgType::SettlementInstructions,
+            "V" => MsgType::MarketDataRequest,
+            "W" => MsgType::MarketDataSnapshotFullRefresh,
+            "X" => MsgType::MarketDataIncrementalRefresh,
+            "Y" => MsgType::MarketDataRequestReject,
+            "Z" => MsgType::QuoteCancel,
+            "a" => MsgType::QuoteStatusRequest,
+            "b" => MsgType::QuoteAcknowledgement,
+            "c" => MsgType::SecurityDefinitionRequest,
+            "d" => MsgType::SecurityDefinition,
+            "e" => MsgType::SecurityStatusRequest,
+            "f" => MsgType::SecurityStatus,
+            "g" => MsgType::TradingSessionStatusRequest,
+            "h" => MsgType::TradingSessionStatus,
+            "i" => MsgType::MassQuote,
+            "j" => MsgType::BusinessMessageReject,
+            "k" => MsgType::BidRequest,
+            "l" => MsgType::BidResponse,
+            "m" => MsgType::ListStrikePrice,
+            _   => MsgType::Custom(value.to_string())
+        }
     }
 }
 
-pub mod side {
-
-    use std::fmt::{Display};
+// Never fails, an unknown type comes back as Custom.
+impl FromStr for MsgType {
+    type Err = Infallible;
 
-    pub struct Side {
-        val : char
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(MsgType::from(value))
     }
-
-    pub const BUY: Side = Side { val: '1'};
-    pub const SELL: Side = Side { val: '2'};
-    pub const BUY_MINUS: Side = Side { val: '3'};
-    pub const SELL_PLUS: Side = Side { val: '4'};
-    pub const SELL_SHORT: Side = Side { val: '5'};
-    pub const SELL_SHORT_EXEMPT: Side = Side { val: '6'};
-    pub const UNDISCLOSED: Side = Side { val: '7'};
-    pub const CROSS: Side = Side { val: '8'};
-    pub const CROSS_SHORT: Side = Side { val: '9'};
-
 }
 
-pub mod time_in_force {
-    use std::fmt::{Display};
-
-    pub struct TimeInForce {
-        val : char
+impl Display for MsgType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
     }
-    pub const DAY:TimeInForce = TimeInForce{ val: '0'};
-    pub const GOOD_TILL_CANCEL:TimeInForce = TimeInForce{ val:'1' };
-    pub const AT_THE_OPENING:TimeInForce = TimeInForce{ val:'2'};
-    pub const IMMEDIATE_OR_CANCEL:TimeInForce = TimeInForce{ val:'3'};
-    pub const FILL_OR_KILL:TimeInForce = TimeInForce{ val:'4'};
-    pub const GOOD_TILL_CROSSING:TimeInForce = TimeInForce{ val:'5'};
-    pub const GOOD_TILL_DATE:TimeInForce = TimeInForce{val:'6'};
 }
 
-pub mod id_source {
-    use std::fmt::{Display};
+// SessionRejectReason (373) is numeric and runs past a single digit, so it doesn't fit FixEnum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SessionRejectReason {
+    InvalidTagNumber            = 0,
+    RequiredTagMissing          = 1,
+    TagNotDefinedForMsgType     = 2,
+    UndefinedTag                = 3,
+    TagSpecifiedWithoutValue    = 4,
+    ValueIsIncorrect            = 5,
+    IncorrectDataFormat         = 6,
+    DecryptionProblem           = 7,
+    SignatureProblem            = 8,
+    CompIdProblem               = 9,
+    SendingTimeAccuracyProblem  = 10,
+    InvalidMsgType              = 11,
+    // FIX 4.3 onwards
+    TagSpecifiedOutOfRequiredOrder = 14,
+    IncorrectNumInGroupCount    = 16
+}
 
-    pub struct IdSource {
-        val : char
+impl SessionRejectReason {
+    pub fn value(&self) -> i32 {
+        *self as i32
     }
-    pub const CUSIP:IdSource                = IdSource{val:'1'};
-    pub const SEDOL:IdSource                = IdSource{val:'2'};
-    pub const QUIK:IdSource                 = IdSource{val:	'3'};
-    pub const ISIN:IdSource                 = IdSource{val:	'4'};
-    pub const RIC:IdSource                  = IdSource{val:	'5'};
-    pub const ISO_CURRENCY_CODE:IdSource    = IdSource{val:'6'};
-    pub const ISO_COUNTRY_CODE:IdSource     = IdSource{val:'7'};
-    pub const EXCHANGE_SYMBOL:IdSource      = IdSource{val:'8'};
-    pub const CTA:IdSource                  = IdSource{val:'9'};
 
+    pub fn from_value(value: i32) -> Option<SessionRejectReason> {
+        match value {
+            0  => Some(SessionRejectReason::InvalidTagNumber),
+            1  => Some(SessionRejectReason::RequiredTagMissing),
+            2  => Some(SessionRejectReason::TagNotDefinedForMsgType),
+            3  => Some(SessionRejectReason::UndefinedTag),
+            4  => Some(SessionRejectReason::TagSpecifiedWithoutValue),
+            5  => Some(SessionRejectReason::ValueIsIncorrect),
+            6  => Some(SessionRejectReason::IncorrectDataFormat),
+            7  => Some(SessionRejectReason::DecryptionProblem),
+            8  => Some(SessionRejectReason::SignatureProblem),
+            9  => Some(SessionRejectReason::CompIdProblem),
+            10 => Some(SessionRejectReason::SendingTimeAccuracyProblem),
+            11 => Some(SessionRejectReason::InvalidMsgType),
+            14 => Some(SessionRejectReason::TagSpecifiedOutOfRequiredOrder),
+            16 => Some(SessionRejectReason::IncorrectNumInGroupCount),
+            _  => None
+        }
+    }
 }
 
-pub mod ord_status {
-    use std::fmt::{Display};
-
-    pub struct OrdStatus {
-        val: char
+impl Display for SessionRejectReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}({})", self, self.value())
     }
-
-    pub const NEW: OrdStatus = OrdStatus { val: '0' };
-    pub const PARTIALLY_FILLED: OrdStatus = OrdStatus { val: '1' };
-    pub const FILLED: OrdStatus = OrdStatus { val: '2' };
-    pub const DONE_FOR_DAY: OrdStatus = OrdStatus { val: '3' };
-    pub const CANCELED: OrdStatus = OrdStatus { val: '4' };
-    pub const REPLACED: OrdStatus = OrdStatus { val: '5' };
-    pub const PENDING_CANCEL: OrdStatus = OrdStatus { val: '6' };
-    pub const STOPPED: OrdStatus = OrdStatus { val: '7' };
-    pub const REJECTED: OrdStatus = OrdStatus { val: '8' };
-    pub const SUSPENDED: OrdStatus = OrdStatus { val: '9' };
-    pub const PENDING_NEW: OrdStatus = OrdStatus { val: 'A' };
-    pub const CALCULATED: OrdStatus = OrdStatus { val: 'B' };
-    pub const EXPIRED: OrdStatus = OrdStatus { val: 'C' };
-    pub const ACCEPTED_FOR_BIDDING: OrdStatus = OrdStatus { val: 'D' };
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-pub mod encrypt_method_enum {
+    #[test]
+    fn test_msg_type_round_trips() {
 
-    use std::fmt::{Display};
+        for value in ["0", "A", "D", "j", "AE", "U1"] {
+            let msg_type: MsgType = value.parse().unwrap();
+            assert_eq!(msg_type.to_string(), value);
+        }
+        assert_eq!(MsgType::from("8"), MsgType::ExecutionReport);
+        assert_eq!(MsgType::from("AE"), MsgType::Custom(String::from("AE")));
+    }
 
-    pub struct EncryptMethod { val :char }
+    #[test]
+    fn test_enums_parse_and_format() {
 
-    pub const NONE: EncryptMethod        = EncryptMethod { val: '0' };
-    pub const PKCS: EncryptMethod        = EncryptMethod { val: '1' };
-    pub const DES: EncryptMethod         = EncryptMethod { val: '2' };
-    pub const PKCS_DES: EncryptMethod    = EncryptMethod { val: '3' };
-    pub const PGP_DES: EncryptMethod     = EncryptMethod { val: '4' };
-    pub const PGP_DES_MD5: EncryptMethod = EncryptMethod { val: '5' };
-    pub const PEM_DES_MD5: EncryptMethod = EncryptMethod { val: '6' };
+        assert_eq!(Side::try_from(&b"2"[..]), Ok(Side::Sell));
+        assert_eq!(OrdStatus::try_from('A'), Ok(OrdStatus::PendingNew));
+        assert_eq!("3".parse::<TimeInForce>(), Ok(TimeInForce::ImmediateOrCancel));
+        assert_eq!(ExecType::Fill.to_string(), "2");
+        assert_eq!(HandlInst::ManualOrderBestExecution.value(), "3");
+        assert_eq!(EncryptMethod::None.value(), "0");
 
-}
+        for status in OrdStatus::ALL {
+            assert_eq!(OrdStatus::try_from(status.value().as_bytes()), Ok(*status));
+        }
+        for side in Side::ALL {
+            assert_eq!(side.to_string().parse::<Side>(), Ok(*side));
+        }
+    }
 
+    #[test]
+    fn test_unknown_enum_values_are_errors() {
 
+        assert_eq!(Side::try_from(&b"Z"[..]), Err(UnknownEnumValue { field: "Side", value: String::from("Z") }));
+        assert_eq!(OrdType::try_from('Q').unwrap_err().to_string(), "\"Q\" is not a valid OrdType");
+        assert!("".parse::<ExecTransType>().is_err());
+        assert!("11".parse::<IDSource>().is_err());
+    }
+}
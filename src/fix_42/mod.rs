@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-pub mod tags;
 pub mod value_types;
 pub mod attribute_enums;
+pub mod iso_codes;
 
@@ -1,6140 +0,0 @@
-use crate::fix_42::value_types;
-use super::value_types::*;
-
-#[allow(dead_code)]
-pub const ACCOUNT: FixTag = FixTag { id: "1", datatype: value_types::STRING};
-pub const ADVID: FixTag = FixTag { id: "2", datatype: value_types::STRING};
-pub const ADV_REF_ID: FixTag = FixTag { id: "3", datatype: value_types::STRING};
-pub const ADV_SIDE: FixTag = FixTag { id: "4", datatype: value_types::CHAR};
-pub const ADV_TRANS_TYPE: FixTag = FixTag { id: "5", datatype: value_types::STRING};
-pub const AVG_PX: FixTag = FixTag { id: "6", datatype: value_types::PRICE};
-pub const BEGIN_SEQ_NO: FixTag = FixTag { id: "7", datatype: value_types::SEQ_NO };
-pub const BEGIN_STRING: FixTag = FixTag { id: "8", datatype: value_types::STRING};
-pub const BODY_LENGTH: FixTag = FixTag { id: "9", datatype: value_types::LENGTH};
-pub const CHECK_SUM: FixTag = FixTag { id: "10", datatype: value_types::STRING};
-pub const CL_ORD_ID: FixTag = FixTag { id: "11", datatype: value_types::STRING};
-pub const COMMISSION: FixTag = FixTag { id: "12", datatype: value_types::AMT};
-pub const COMMTYPE: FixTag = FixTag { id: "13", datatype: value_types::CHAR};
-pub const CUM_QTY: FixTag = FixTag { id: "14", datatype: value_types::QTY};
-
-pub const CURRENCY: FixTag = FixTag { id: "15", datatype: value_types::CURRENCY};
-pub const END_SEQ_NO: FixTag = FixTag { id: "16", datatype: value_types::SEQ_NO };
-pub const EXEC_ID: FixTag = FixTag { id: "17", datatype: value_types::STRING};
-pub const EXEC_INST: FixTag = FixTag { id: "18", datatype: value_types::MULTIPLE_CHAR_VALUE };
-pub const EXEC_REF_ID: FixTag = FixTag { id: "19", datatype: value_types::STRING};
-pub const HANDL_INST: FixTag = FixTag { id: "21", datatype: value_types::CHAR};
-pub const SECURITY_ID_SOURCE: FixTag = FixTag { id: "22", datatype: value_types::STRING};
-pub const IOI_ID: FixTag = FixTag { id: "23", datatype: value_types::STRING};
-pub const IOI_QLTY_IND: FixTag = FixTag { id: "25", datatype: value_types::CHAR};
-pub const IOI_REF_ID: FixTag = FixTag { id: "26", datatype: value_types::STRING};
-pub const IOI_QTY: FixTag = FixTag { id: "27", datatype: value_types::STRING};
-pub const IOI_TRANS_TYPE: FixTag = FixTag { id: "28", datatype: value_types::CHAR};
-pub const LAST_CAPACITY: FixTag = FixTag { id: "29", datatype: value_types::CHAR};
-pub const LAST_MKT: FixTag = FixTag { id: "30", datatype: value_types::EXCHANGE};
-pub const LAST_PX: FixTag = FixTag { id: "31", datatype: value_types::PRICE};
-pub const LAST_QTY: FixTag = FixTag { id: "32", datatype: value_types::QTY};
-pub const NO_LINES_OF_TEXT: FixTag = FixTag { id: "33", datatype: value_types::NUM_IN_GROUP };
-pub const MSG_SEQ_NO: FixTag = FixTag { id: "34", datatype: value_types::SEQ_NO };
-pub const MSG_TYPE: FixTag = FixTag { id: "35", datatype: value_types::STRING};
-pub const NEW_SEQ_NO: FixTag = FixTag { id: "36", datatype: value_types::SEQ_NO };
-pub const ORDER_ID: FixTag = FixTag { id: "37", datatype: value_types::STRING};
-pub const ORDER_QTY: FixTag = FixTag { id: "38", datatype: value_types::QTY};
-pub const ORD_STATUS: FixTag = FixTag { id: "39", datatype: value_types::CHAR};
-pub const ORD_TYPE: FixTag = FixTag { id: "40", datatype: value_types::CHAR};
-pub const ORIG_CL_ORD_ID: FixTag = FixTag { id: "41", datatype: value_types::STRING};
-pub const ORIG_TIME: FixTag = FixTag { id: "42", datatype: value_types::UTC_TIMESTAMP};
-pub const POSS_DUP_FLAG: FixTag = FixTag { id: "43", datatype: value_types::BOOLEAN};
-pub const PRICE: FixTag = FixTag { id: "44", datatype: value_types::PRICE};
-pub const REF_SEQ_NUM: FixTag = FixTag { id: "45", datatype: value_types::SEQ_NO };
-pub const SECURITY_ID: FixTag = FixTag { id: "48", datatype: value_types::STRING};
-pub const SENDER_COMP_ID: FixTag = FixTag { id: "49", datatype: value_types::STRING};
-pub const SENDER_SUB_ID: FixTag = FixTag { id: "50", datatype: value_types::STRING};
-pub const SENDING_TIME: FixTag = FixTag { id: "52", datatype: value_types::UTC_TIMESTAMP};
-pub const QUANTITY: FixTag = FixTag { id: "53", datatype: value_types::QTY};
-pub const SIDE: FixTag = FixTag { id: "54", datatype: value_types::CHAR};
-pub const SYMBOL: FixTag = FixTag { id: "55", datatype: value_types::STRING};
-pub const TARGET_COMP_ID: FixTag = FixTag { id: "56", datatype: value_types::STRING};
-pub const TARGET_SUB_ID: FixTag = FixTag { id: "57", datatype: value_types::STRING};
-pub const TEXT: FixTag = FixTag { id: "58", datatype: value_types::STRING};
-pub const TIME_IN_FORCE: FixTag = FixTag { id: "59", datatype: value_types::CHAR};
-pub const TRANSACT_TIME: FixTag = FixTag { id: "60", datatype: value_types::UTC_TIMESTAMP};
-pub const URGENCY: FixTag = FixTag { id: "61", datatype: value_types::CHAR};
-pub const VALID_UNTIL_TIME: FixTag = FixTag { id: "62", datatype: value_types::UTC_TIMESTAMP};
-pub const SETTL_TYPE: FixTag = FixTag { id: "63", datatype: value_types::STRING};
-pub const SETTL_DATE: FixTag = FixTag { id: "64", datatype: value_types::LOCAL_MKT_DATE};
-pub const SYMBOL_SFX: FixTag = FixTag { id: "65", datatype: value_types::STRING};
-pub const LIST_ID: FixTag = FixTag { id: "66", datatype: value_types::STRING};
-pub const LIST_SEQ_NO: FixTag = FixTag { id: "67", datatype: value_types::INT};
-pub const TOT_NO_ORDERS: FixTag = FixTag { id: "68", datatype: value_types::INT};
-pub const LIST_EXEC_INST: FixTag = FixTag { id: "69", datatype: value_types::STRING};
-pub const ALLOC_ID: FixTag = FixTag { id: "70", datatype: value_types::STRING};
-pub const ALLOC_TRANS_TYPE: FixTag = FixTag { id: "71", datatype: value_types::CHAR};
-pub const REFALLOC_ID: FixTag = FixTag { id: "72", datatype: value_types::STRING};
-pub const NO_ORDERS: FixTag = FixTag { id: "73", datatype: value_types::NUM_IN_GROUP };
-pub const AVGPXPRECISION: FixTag = FixTag { id: "74", datatype: value_types::INT};
-pub const TRADEDATE: FixTag = FixTag { id: "75", datatype: value_types::LOCAL_MKT_DATE};
-pub const POSITIONEFFECT: FixTag = FixTag { id: "77", datatype: value_types::CHAR};
-pub const NOALLOCS: FixTag = FixTag { id: "78", datatype: value_types::NUM_IN_GROUP };
-pub const ALLOCACCOUNT: FixTag = FixTag { id: "79", datatype: value_types::STRING};
-pub const ALLOCQTY: FixTag = FixTag { id: "80", datatype: value_types::QTY};
-pub const PROCESSCODE: FixTag = FixTag { id: "81", datatype: value_types::CHAR};
-pub const NORPTS: FixTag = FixTag { id: "82", datatype: value_types::INT};
-pub const RPTSEQ: FixTag = FixTag { id: "83", datatype: value_types::INT};
-pub const CXLQTY: FixTag = FixTag { id: "84", datatype: value_types::QTY};
-pub const NODLVYINST: FixTag = FixTag { id: "85", datatype: value_types::NUM_IN_GROUP };
-pub const ALLOCSTATUS: FixTag = FixTag { id: "87", datatype: value_types::INT};
-pub const ALLOCREJCODE: FixTag = FixTag { id: "88", datatype: value_types::INT};
-pub const SIGNATURE: FixTag = FixTag { id: "89", datatype: value_types::DATA};
-pub const SECUREDATALEN: FixTag = FixTag { id: "90", datatype: value_types::LENGTH};
-pub const SECUREDATA: FixTag = FixTag { id: "91", datatype: value_types::DATA};
-pub const SIGNATURELENGTH: FixTag = FixTag { id: "93", datatype: value_types::LENGTH};
-pub const EMAILTYPE: FixTag = FixTag { id: "94", datatype: value_types::CHAR};
-pub const RAWDATALENGTH: FixTag = FixTag { id: "95", datatype: value_types::LENGTH};
-pub const RAWDATA: FixTag = FixTag { id: "96", datatype: value_types::DATA};
-pub const POSSRESEND: FixTag = FixTag { id: "97", datatype: value_types::BOOLEAN};
-pub const ENCRYPT_METHOD: FixTag = FixTag { id: "98", datatype: value_types::INT};
-pub const STOPPX: FixTag = FixTag { id: "99", datatype: value_types::PRICE};
-pub const EXDESTINATION: FixTag = FixTag { id: "100", datatype: value_types::EXCHANGE};
-pub const CXLREJREASON: FixTag = FixTag { id: "102", datatype: value_types::INT};
-pub const ORDREJREASON: FixTag = FixTag { id: "103", datatype: value_types::INT};
-pub const IOIQUALIFIER: FixTag = FixTag { id: "104", datatype: value_types::CHAR};
-pub const ISSUER: FixTag = FixTag { id: "106", datatype: value_types::STRING};
-pub const SECURITYDESC: FixTag = FixTag { id: "107", datatype: value_types::STRING};
-pub const HEARTBT_INT: FixTag = FixTag { id: "108", datatype: value_types::INT};
-pub const MINQTY: FixTag = FixTag { id: "110", datatype: value_types::QTY};
-pub const MAXFLOOR: FixTag = FixTag { id: "111", datatype: value_types::QTY};
-pub const TEST_REQ_ID: FixTag = FixTag { id: "112", datatype: value_types::STRING};
-pub const REPORTTOEXCH: FixTag = FixTag { id: "113", datatype: value_types::BOOLEAN};
-pub const LOCATEREQD: FixTag = FixTag { id: "114", datatype: value_types::BOOLEAN};
-pub const ONBEHALFOFCOMPID: FixTag = FixTag { id: "115", datatype: value_types::STRING};
-pub const ONBEHALFOFSUBID: FixTag = FixTag { id: "116", datatype: value_types::STRING};
-pub const QUOTEID: FixTag = FixTag { id: "117", datatype: value_types::STRING};
-pub const NETMONEY: FixTag = FixTag { id: "118", datatype: value_types::AMT};
-pub const SETTLCURRAMT: FixTag = FixTag { id: "119", datatype: value_types::AMT};
-pub const SETTLCURRENCY: FixTag = FixTag { id: "120", datatype: value_types::CURRENCY};
-pub const FOREXREQ: FixTag = FixTag { id: "121", datatype: value_types::BOOLEAN};
-pub const ORIGSENDINGTIME: FixTag = FixTag { id: "122", datatype: value_types::UTC_TIMESTAMP};
-pub const GAPFILLFLAG: FixTag = FixTag { id: "123", datatype: value_types::BOOLEAN};
-pub const NOEXECS: FixTag = FixTag { id: "124", datatype: value_types::NUM_IN_GROUP };
-pub const EXPIRETIME: FixTag = FixTag { id: "126", datatype: value_types::UTC_TIMESTAMP};
-pub const DKREASON: FixTag = FixTag { id: "127", datatype: value_types::CHAR};
-pub const DELIVERTOCOMPID: FixTag = FixTag { id: "128", datatype: value_types::STRING};
-pub const DELIVERTOSUBID: FixTag = FixTag { id: "129", datatype: value_types::STRING};
-pub const IOINATURALFLAG: FixTag = FixTag { id: "130", datatype: value_types::BOOLEAN};
-pub const QUOTEREQID: FixTag = FixTag { id: "131", datatype: value_types::STRING};
-pub const BIDPX: FixTag = FixTag { id: "132", datatype: value_types::PRICE};
-pub const OFFERPX: FixTag = FixTag { id: "133", datatype: value_types::PRICE};
-pub const BIDSIZE: FixTag = FixTag { id: "134", datatype: value_types::QTY};
-pub const OFFERSIZE: FixTag = FixTag { id: "135", datatype: value_types::QTY};
-pub const NOMISCFEES: FixTag = FixTag { id: "136", datatype: value_types::NUM_IN_GROUP };
-pub const MISCFEEAMT: FixTag = FixTag { id: "137", datatype: value_types::AMT};
-pub const MISCFEECURR: FixTag = FixTag { id: "138", datatype: value_types::CURRENCY};
-pub const MISCFEETYPE: FixTag = FixTag { id: "139", datatype: value_types::STRING};
-pub const PREVCLOSEPX: FixTag = FixTag { id: "140", datatype: value_types::PRICE};
-pub const RESETSEQNUMFLAG: FixTag = FixTag { id: "141", datatype: value_types::BOOLEAN};
-pub const SENDERLOCATIONID: FixTag = FixTag { id: "142", datatype: value_types::STRING};
-pub const TARGETLOCATIONID: FixTag = FixTag { id: "143", datatype: value_types::STRING};
-pub const ONBEHALFOFLOCATIONID: FixTag = FixTag { id: "144", datatype: value_types::STRING};
-pub const DELIVERTOLOCATIONID: FixTag = FixTag { id: "145", datatype: value_types::STRING};
-pub const NORELATEDSYM: FixTag = FixTag { id: "146", datatype: value_types::NUM_IN_GROUP };
-pub const SUBJECT: FixTag = FixTag { id: "147", datatype: value_types::STRING};
-pub const HEADLINE: FixTag = FixTag { id: "148", datatype: value_types::STRING};
-pub const URLLINK: FixTag = FixTag { id: "149", datatype: value_types::STRING};
-pub const EXECTYPE: FixTag = FixTag { id: "150", datatype: value_types::CHAR};
-pub const LEAVESQTY: FixTag = FixTag { id: "151", datatype: value_types::QTY};
-pub const CASHORDERQTY: FixTag = FixTag { id: "152", datatype: value_types::QTY};
-pub const ALLOCAVGPX: FixTag = FixTag { id: "153", datatype: value_types::PRICE};
-pub const ALLOCNETMONEY: FixTag = FixTag { id: "154", datatype: value_types::AMT};
-pub const SETTLCURRFXRATE: FixTag = FixTag { id: "155", datatype: value_types::FLOAT};
-pub const SETTLCURRFXRATECALC: FixTag = FixTag { id: "156", datatype: value_types::CHAR};
-pub const NUMDAYSINTEREST: FixTag = FixTag { id: "157", datatype: value_types::INT};
-pub const ACCRUEDINTERESTRATE: FixTag = FixTag { id: "158", datatype: value_types::PERCENTAGE};
-pub const ACCRUEDINTERESTAMT: FixTag = FixTag { id: "159", datatype: value_types::AMT};
-pub const SETTLINSTMODE: FixTag = FixTag { id: "160", datatype: value_types::CHAR};
-pub const ALLOCTEXT: FixTag = FixTag { id: "161", datatype: value_types::STRING};
-pub const SETTLINSTID: FixTag = FixTag { id: "162", datatype: value_types::STRING};
-pub const SETTLINSTTRANSTYPE: FixTag = FixTag { id: "163", datatype: value_types::CHAR};
-pub const EMAILTHREADID: FixTag = FixTag { id: "164", datatype: value_types::STRING};
-pub const SETTLINSTSOURCE: FixTag = FixTag { id: "165", datatype: value_types::CHAR};
-pub const SECURITYTYPE: FixTag = FixTag { id: "167", datatype: value_types::STRING};
-pub const EFFECTIVETIME: FixTag = FixTag { id: "168", datatype: value_types::UTC_TIMESTAMP};
-pub const STANDINSTDBTYPE: FixTag = FixTag { id: "169", datatype: value_types::INT};
-pub const STANDINSTDBNAME: FixTag = FixTag { id: "170", datatype: value_types::STRING};
-pub const STANDINSTDBID: FixTag = FixTag { id: "171", datatype: value_types::STRING};
-pub const SETTLDELIVERYTYPE: FixTag = FixTag { id: "172", datatype: value_types::INT};
-pub const BIDSPOTRATE: FixTag = FixTag { id: "188", datatype: value_types::PRICE};
-pub const BIDFORWARDPOINTS: FixTag = FixTag { id: "189", datatype: value_types::PRICE_OFFSET};
-pub const OFFERSPOTRATE: FixTag = FixTag { id: "190", datatype: value_types::PRICE};
-pub const OFFERFORWARDPOINTS: FixTag = FixTag { id: "191", datatype: value_types::PRICE_OFFSET};
-pub const ORDERQTY2: FixTag = FixTag { id: "192", datatype: value_types::QTY};
-pub const SETTLDATE2: FixTag = FixTag { id: "193", datatype: value_types::LOCAL_MKT_DATE};
-pub const LASTSPOTRATE: FixTag = FixTag { id: "194", datatype: value_types::PRICE};
-pub const LASTFORWARDPOINTS: FixTag = FixTag { id: "195", datatype: value_types::PRICE_OFFSET};
-pub const ALLOCLINKID: FixTag = FixTag { id: "196", datatype: value_types::STRING};
-pub const ALLOCLINKTYPE: FixTag = FixTag { id: "197", datatype: value_types::INT};
-pub const SECONDARYORDERID: FixTag = FixTag { id: "198", datatype: value_types::STRING};
-pub const NOIOIQUALIFIERS: FixTag = FixTag { id: "199", datatype: value_types::NUM_IN_GROUP };
-pub const MATURITYMONTH_YEAR: FixTag = FixTag { id: "200", datatype: value_types::MONTH_YEAR};
-pub const PUTORCALL: FixTag = FixTag { id: "201", datatype: value_types::INT};
-pub const STRIKEPRICE: FixTag = FixTag { id: "202", datatype: value_types::PRICE};
-pub const COVEREDORUNCOVERED: FixTag = FixTag { id: "203", datatype: value_types::INT};
-pub const OPTATTRIBUTE: FixTag = FixTag { id: "206", datatype: value_types::CHAR};
-pub const SECURITYEXCHANGE: FixTag = FixTag { id: "207", datatype: value_types::EXCHANGE};
-pub const NOTIFYBROKEROFCREDIT: FixTag = FixTag { id: "208", datatype: value_types::BOOLEAN};
-pub const ALLOCHANDLINST: FixTag = FixTag { id: "209", datatype: value_types::INT};
-pub const MAXSHOW: FixTag = FixTag { id: "210", datatype: value_types::QTY};
-pub const PEGOFFSETVALUE: FixTag = FixTag { id: "211", datatype: value_types::FLOAT};
-pub const XML_DATALEN: FixTag = FixTag { id: "212", datatype: value_types::LENGTH};
-pub const XML_DATA: FixTag = FixTag { id: "213", datatype: value_types::XML_DATA};
-pub const SETTLINSTREFID: FixTag = FixTag { id: "214", datatype: value_types::STRING};
-pub const NOROUTINGIDS: FixTag = FixTag { id: "215", datatype: value_types::NUM_IN_GROUP };
-pub const ROUTINGTYPE: FixTag = FixTag { id: "216", datatype: value_types::INT};
-pub const ROUTINGID: FixTag = FixTag { id: "217", datatype: value_types::STRING};
-pub const SPREAD: FixTag = FixTag { id: "218", datatype: value_types::PRICE_OFFSET};
-pub const BENCHMARKCURVECURRENCY: FixTag = FixTag { id: "220", datatype: value_types::CURRENCY};
-pub const BENCHMARKCURVENAME: FixTag = FixTag { id: "221", datatype: value_types::STRING};
-pub const BENCHMARKCURVEPOINT: FixTag = FixTag { id: "222", datatype: value_types::STRING};
-pub const COUPONRATE: FixTag = FixTag { id: "223", datatype: value_types::PERCENTAGE};
-pub const COUPONPAYMENTDATE: FixTag = FixTag { id: "224", datatype: value_types::LOCAL_MKT_DATE};
-pub const ISSUEDATE: FixTag = FixTag { id: "225", datatype: value_types::LOCAL_MKT_DATE};
-pub const REPURCHASETERM: FixTag = FixTag { id: "226", datatype: value_types::INT};
-pub const REPURCHASERATE: FixTag = FixTag { id: "227", datatype: value_types::PERCENTAGE};
-pub const FACTOR: FixTag = FixTag { id: "228", datatype: value_types::FLOAT};
-pub const TRADEORIGINATIONDATE: FixTag = FixTag { id: "229", datatype: value_types::LOCAL_MKT_DATE};
-pub const EXDATE: FixTag = FixTag { id: "230", datatype: value_types::LOCAL_MKT_DATE};
-pub const CONTRACTMULTIPLIER: FixTag = FixTag { id: "231", datatype: value_types::FLOAT};
-pub const NOSTIPULATIONS: FixTag = FixTag { id: "232", datatype: value_types::NUM_IN_GROUP };
-pub const STIPULATIONTYPE: FixTag = FixTag { id: "233", datatype: value_types::STRING};
-pub const STIPULATIONVALUE: FixTag = FixTag { id: "234", datatype: value_types::STRING};
-pub const YIELDTYPE: FixTag = FixTag { id: "235", datatype: value_types::STRING};
-pub const YIELD: FixTag = FixTag { id: "236", datatype: value_types::PERCENTAGE};
-pub const TOTALTAKEDOWN: FixTag = FixTag { id: "237", datatype: value_types::AMT};
-pub const CONCESSION: FixTag = FixTag { id: "238", datatype: value_types::AMT};
-pub const REPOCOLLATERALSECURITYTYPE: FixTag = FixTag { id: "239", datatype: value_types::STRING};
-pub const REDEMPTIONDATE: FixTag = FixTag { id: "240", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGCOUPONPAYMENTDATE: FixTag = FixTag { id: "241", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGISSUEDATE: FixTag = FixTag { id: "242", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGREPOCOLLATERALSECURITYTYPE: FixTag = FixTag { id: "243", datatype: value_types::STRING};
-pub const UNDERLYINGREPURCHASETERM: FixTag = FixTag { id: "244", datatype: value_types::INT};
-pub const UNDERLYINGREPURCHASERATE: FixTag = FixTag { id: "245", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGFACTOR: FixTag = FixTag { id: "246", datatype: value_types::FLOAT};
-pub const UNDERLYINGREDEMPTIONDATE: FixTag = FixTag { id: "247", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGCOUPONPAYMENTDATE: FixTag = FixTag { id: "248", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGISSUEDATE: FixTag = FixTag { id: "249", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGREPOCOLLATERALSECURITYTYPE: FixTag = FixTag { id: "250", datatype: value_types::STRING};
-pub const LEGREPURCHASETERM: FixTag = FixTag { id: "251", datatype: value_types::INT};
-pub const LEGREPURCHASERATE: FixTag = FixTag { id: "252", datatype: value_types::PERCENTAGE};
-pub const LEGFACTOR: FixTag = FixTag { id: "253", datatype: value_types::FLOAT};
-pub const LEGREDEMPTIONDATE: FixTag = FixTag { id: "254", datatype: value_types::LOCAL_MKT_DATE};
-pub const CREDITRATING: FixTag = FixTag { id: "255", datatype: value_types::STRING};
-pub const UNDERLYINGCREDITRATING: FixTag = FixTag { id: "256", datatype: value_types::STRING};
-pub const LEGCREDITRATING: FixTag = FixTag { id: "257", datatype: value_types::STRING};
-pub const TRADEDFLATSWITCH: FixTag = FixTag { id: "258", datatype: value_types::BOOLEAN};
-pub const BASISFEATUREDATE: FixTag = FixTag { id: "259", datatype: value_types::LOCAL_MKT_DATE};
-pub const BASISFEATUREPRICE: FixTag = FixTag { id: "260", datatype: value_types::PRICE};
-pub const MDREQID: FixTag = FixTag { id: "262", datatype: value_types::STRING};
-pub const SUBSCRIPTIONREQUESTTYPE: FixTag = FixTag { id: "263", datatype: value_types::CHAR};
-pub const MARKETDEPTH: FixTag = FixTag { id: "264", datatype: value_types::INT};
-pub const MDUPDATETYPE: FixTag = FixTag { id: "265", datatype: value_types::INT};
-pub const AGGREGATEDBOOK: FixTag = FixTag { id: "266", datatype: value_types::BOOLEAN};
-pub const NOMDENTRYTYPES: FixTag = FixTag { id: "267", datatype: value_types::NUM_IN_GROUP };
-pub const NOMDENTRIES: FixTag = FixTag { id: "268", datatype: value_types::NUM_IN_GROUP };
-pub const MDENTRYTYPE: FixTag = FixTag { id: "269", datatype: value_types::CHAR};
-pub const MDENTRYPX: FixTag = FixTag { id: "270", datatype: value_types::PRICE};
-pub const MDENTRYSIZE: FixTag = FixTag { id: "271", datatype: value_types::QTY};
-pub const MDENTRYDATE: FixTag = FixTag { id: "272", datatype: value_types::UTC_DATE_ONLY};
-pub const MDENTRYTIME: FixTag = FixTag { id: "273", datatype: value_types::UTC_TIME_ONLY};
-pub const TICKDIRECTION: FixTag = FixTag { id: "274", datatype: value_types::CHAR};
-pub const MDMKT: FixTag = FixTag { id: "275", datatype: value_types::EXCHANGE};
-pub const QUOTECONDITION: FixTag = FixTag { id: "276", datatype: value_types::MULTIPLE_STRING_VALUE};
-pub const TRADECONDITION: FixTag = FixTag { id: "277", datatype: value_types::MULTIPLE_STRING_VALUE};
-pub const MDENTRYID: FixTag = FixTag { id: "278", datatype: value_types::STRING};
-pub const MDUPDATEACTION: FixTag = FixTag { id: "279", datatype: value_types::CHAR};
-pub const MDENTRYREFID: FixTag = FixTag { id: "280", datatype: value_types::STRING};
-pub const MDREQREJREASON: FixTag = FixTag { id: "281", datatype: value_types::CHAR};
-pub const MDENTRYORIGINATOR: FixTag = FixTag { id: "282", datatype: value_types::STRING};
-pub const LOCATIONID: FixTag = FixTag { id: "283", datatype: value_types::STRING};
-pub const DESKID: FixTag = FixTag { id: "284", datatype: value_types::STRING};
-pub const DELETEREASON: FixTag = FixTag { id: "285", datatype: value_types::CHAR};
-pub const OPENCLOSESETTLFLAG: FixTag = FixTag { id: "286", datatype: value_types::MULTIPLE_CHAR_VALUE };
-pub const SELLERDAYS: FixTag = FixTag { id: "287", datatype: value_types::INT};
-pub const MDENTRYBUYER: FixTag = FixTag { id: "288", datatype: value_types::STRING};
-pub const MDENTRYSELLER: FixTag = FixTag { id: "289", datatype: value_types::STRING};
-pub const MDENTRYPOSITIONNO: FixTag = FixTag { id: "290", datatype: value_types::INT};
-pub const FINANCIALSTATUS: FixTag = FixTag { id: "291", datatype: value_types::MULTIPLE_CHAR_VALUE };
-pub const CORPORATEACTION: FixTag = FixTag { id: "292", datatype: value_types::MULTIPLE_CHAR_VALUE };
-pub const DEFBIDSIZE: FixTag = FixTag { id: "293", datatype: value_types::QTY};
-pub const DEFOFFERSIZE: FixTag = FixTag { id: "294", datatype: value_types::QTY};
-pub const NOQUOTEENTRIES: FixTag = FixTag { id: "295", datatype: value_types::NUM_IN_GROUP };
-pub const NOQUOTESETS: FixTag = FixTag { id: "296", datatype: value_types::NUM_IN_GROUP };
-pub const QUOTESTATUS: FixTag = FixTag { id: "297", datatype: value_types::INT};
-pub const QUOTECANCELTYPE: FixTag = FixTag { id: "298", datatype: value_types::INT};
-pub const QUOTEENTRYID: FixTag = FixTag { id: "299", datatype: value_types::STRING};
-pub const QUOTEREJECTREASON: FixTag = FixTag { id: "300", datatype: value_types::INT};
-pub const QUOTERESPONSELEVEL: FixTag = FixTag { id: "301", datatype: value_types::INT};
-pub const QUOTESETID: FixTag = FixTag { id: "302", datatype: value_types::STRING};
-pub const QUOTEREQUESTTYPE: FixTag = FixTag { id: "303", datatype: value_types::INT};
-pub const TOTNOQUOTEENTRIES: FixTag = FixTag { id: "304", datatype: value_types::INT};
-pub const UNDERLYINGSECURITYIDSOURCE: FixTag = FixTag { id: "305", datatype: value_types::STRING};
-pub const UNDERLYINGISSUER: FixTag = FixTag { id: "306", datatype: value_types::STRING};
-pub const UNDERLYINGSECURITYDESC: FixTag = FixTag { id: "307", datatype: value_types::STRING};
-pub const UNDERLYINGSECURITYEXCHANGE: FixTag = FixTag { id: "308", datatype: value_types::EXCHANGE};
-pub const UNDERLYINGSECURITYID: FixTag = FixTag { id: "309", datatype: value_types::STRING};
-pub const UNDERLYINGSECURITYTYPE: FixTag = FixTag { id: "310", datatype: value_types::STRING};
-pub const UNDERLYINGSYMBOL: FixTag = FixTag { id: "311", datatype: value_types::STRING};
-pub const UNDERLYINGSYMBOLSFX: FixTag = FixTag { id: "312", datatype: value_types::STRING};
-pub const UNDERLYINGMATURITYMONTH_YEAR: FixTag = FixTag { id: "313", datatype: value_types::MONTH_YEAR};
-pub const UNDERLYINGPUTORCALL: FixTag = FixTag { id: "315", datatype: value_types::INT};
-pub const UNDERLYINGSTRIKEPRICE: FixTag = FixTag { id: "316", datatype: value_types::PRICE};
-pub const UNDERLYINGOPTATTRIBUTE: FixTag = FixTag { id: "317", datatype: value_types::CHAR};
-pub const UNDERLYINGCURRENCY: FixTag = FixTag { id: "318", datatype: value_types::CURRENCY};
-pub const SECURITYREQID: FixTag = FixTag { id: "320", datatype: value_types::STRING};
-pub const SECURITYREQUESTTYPE: FixTag = FixTag { id: "321", datatype: value_types::INT};
-pub const SECURITYRESPONSEID: FixTag = FixTag { id: "322", datatype: value_types::STRING};
-pub const SECURITYRESPONSETYPE: FixTag = FixTag { id: "323", datatype: value_types::INT};
-pub const SECURITYSTATUSREQID: FixTag = FixTag { id: "324", datatype: value_types::STRING};
-pub const UNSOLICITEDINDICATOR: FixTag = FixTag { id: "325", datatype: value_types::BOOLEAN};
-pub const SECURITYTRADINGSTATUS: FixTag = FixTag { id: "326", datatype: value_types::INT};
-pub const HALTREASON: FixTag = FixTag { id: "327", datatype: value_types::INT};
-pub const INVIEWOFCOMMON: FixTag = FixTag { id: "328", datatype: value_types::BOOLEAN};
-pub const DUETORELATED: FixTag = FixTag { id: "329", datatype: value_types::BOOLEAN};
-pub const BUYVOLUME: FixTag = FixTag { id: "330", datatype: value_types::QTY};
-pub const SELLVOLUME: FixTag = FixTag { id: "331", datatype: value_types::QTY};
-pub const HIGHPX: FixTag = FixTag { id: "332", datatype: value_types::PRICE};
-pub const LOWPX: FixTag = FixTag { id: "333", datatype: value_types::PRICE};
-pub const ADJUSTMENT: FixTag = FixTag { id: "334", datatype: value_types::INT};
-pub const TRADSESREQID: FixTag = FixTag { id: "335", datatype: value_types::STRING};
-pub const TRADINGSESSIONID: FixTag = FixTag { id: "336", datatype: value_types::STRING};
-pub const CONTRATRADER: FixTag = FixTag { id: "337", datatype: value_types::STRING};
-pub const TRADSESMETHOD: FixTag = FixTag { id: "338", datatype: value_types::INT};
-pub const TRADSESMODE: FixTag = FixTag { id: "339", datatype: value_types::INT};
-pub const TRADSESSTATUS: FixTag = FixTag { id: "340", datatype: value_types::INT};
-pub const TRADSESSTARTTIME: FixTag = FixTag { id: "341", datatype: value_types::UTC_TIMESTAMP};
-pub const TRADSESOPENTIME: FixTag = FixTag { id: "342", datatype: value_types::UTC_TIMESTAMP};
-pub const TRADSESPRECLOSETIME: FixTag = FixTag { id: "343", datatype: value_types::UTC_TIMESTAMP};
-pub const TRADSESCLOSETIME: FixTag = FixTag { id: "344", datatype: value_types::UTC_TIMESTAMP};
-pub const TRADSESENDTIME: FixTag = FixTag { id: "345", datatype: value_types::UTC_TIMESTAMP};
-pub const NUMBEROFORDERS: FixTag = FixTag { id: "346", datatype: value_types::INT};
-pub const MESSAGEENCODING: FixTag = FixTag { id: "347", datatype: value_types::STRING};
-pub const ENCODEDISSUERLEN: FixTag = FixTag { id: "348", datatype: value_types::LENGTH};
-pub const ENCODEDISSUER: FixTag = FixTag { id: "349", datatype: value_types::DATA};
-pub const ENCODEDSECURITYDESCLEN: FixTag = FixTag { id: "350", datatype: value_types::LENGTH};
-pub const ENCODEDSECURITYDESC: FixTag = FixTag { id: "351", datatype: value_types::DATA};
-pub const ENCODEDLISTEXECINSTLEN: FixTag = FixTag { id: "352", datatype: value_types::LENGTH};
-pub const ENCODEDLISTEXECINST: FixTag = FixTag { id: "353", datatype: value_types::DATA};
-pub const ENCODEDTEXTLEN: FixTag = FixTag { id: "354", datatype: value_types::LENGTH};
-pub const ENCODEDTEXT: FixTag = FixTag { id: "355", datatype: value_types::DATA};
-pub const ENCODEDSUBJECTLEN: FixTag = FixTag { id: "356", datatype: value_types::LENGTH};
-pub const ENCODEDSUBJECT: FixTag = FixTag { id: "357", datatype: value_types::DATA};
-pub const ENCODEDHEADLINELEN: FixTag = FixTag { id: "358", datatype: value_types::LENGTH};
-pub const ENCODEDHEADLINE: FixTag = FixTag { id: "359", datatype: value_types::DATA};
-pub const ENCODEDALLOCTEXTLEN: FixTag = FixTag { id: "360", datatype: value_types::LENGTH};
-pub const ENCODEDALLOCTEXT: FixTag = FixTag { id: "361", datatype: value_types::DATA};
-pub const ENCODEDUNDERLYINGISSUERLEN: FixTag = FixTag { id: "362", datatype: value_types::LENGTH};
-pub const ENCODEDUNDERLYINGISSUER: FixTag = FixTag { id: "363", datatype: value_types::DATA};
-pub const ENCODEDUNDERLYINGSECURITYDESCLEN: FixTag = FixTag { id: "364", datatype: value_types::LENGTH};
-pub const ENCODEDUNDERLYINGSECURITYDESC: FixTag = FixTag { id: "365", datatype: value_types::DATA};
-pub const ALLOCPRICE: FixTag = FixTag { id: "366", datatype: value_types::PRICE};
-pub const QUOTESETVALIDUNTILTIME: FixTag = FixTag { id: "367", datatype: value_types::UTC_TIMESTAMP};
-pub const QUOTEENTRYREJECTREASON: FixTag = FixTag { id: "368", datatype: value_types::INT};
-pub const LASTMSGSEQNUMPROCESSED: FixTag = FixTag { id: "369", datatype: value_types::SEQ_NO };
-pub const REFTAGID: FixTag = FixTag { id: "371", datatype: value_types::INT};
-pub const REFMSGTYPE: FixTag = FixTag { id: "372", datatype: value_types::STRING};
-pub const SESSIONREJECTREASON: FixTag = FixTag { id: "373", datatype: value_types::INT};
-pub const BIDREQUESTTRANSTYPE: FixTag = FixTag { id: "374", datatype: value_types::CHAR};
-pub const CONTRABROKER: FixTag = FixTag { id: "375", datatype: value_types::STRING};
-pub const COMPLIANCEID: FixTag = FixTag { id: "376", datatype: value_types::STRING};
-pub const SOLICITEDFLAG: FixTag = FixTag { id: "377", datatype: value_types::BOOLEAN};
-pub const EXECRESTATEMENTREASON: FixTag = FixTag { id: "378", datatype: value_types::INT};
-pub const BUSINESSREJECTREFID: FixTag = FixTag { id: "379", datatype: value_types::STRING};
-pub const BUSINESSREJECTREASON: FixTag = FixTag { id: "380", datatype: value_types::INT};
-pub const GROSSTRADEAMT: FixTag = FixTag { id: "381", datatype: value_types::AMT};
-pub const NOCONTRABROKERS: FixTag = FixTag { id: "382", datatype: value_types::NUM_IN_GROUP };
-pub const MAXMESSAGESIZE: FixTag = FixTag { id: "383", datatype: value_types::LENGTH};
-pub const NOMSGTYPES: FixTag = FixTag { id: "384", datatype: value_types::NUM_IN_GROUP };
-pub const MSGDIRECTION: FixTag = FixTag { id: "385", datatype: value_types::CHAR};
-pub const NOTRADINGSESSIONS: FixTag = FixTag { id: "386", datatype: value_types::NUM_IN_GROUP };
-pub const TOTALVOLUMETRADED: FixTag = FixTag { id: "387", datatype: value_types::QTY};
-pub const DISCRETIONINST: FixTag = FixTag { id: "388", datatype: value_types::CHAR};
-pub const DISCRETIONOFFSETVALUE: FixTag = FixTag { id: "389", datatype: value_types::FLOAT};
-pub const BIDID: FixTag = FixTag { id: "390", datatype: value_types::STRING};
-pub const CLIENTBIDID: FixTag = FixTag { id: "391", datatype: value_types::STRING};
-pub const LISTNAME: FixTag = FixTag { id: "392", datatype: value_types::STRING};
-pub const TOTNORELATEDSYM: FixTag = FixTag { id: "393", datatype: value_types::INT};
-pub const BIDTYPE: FixTag = FixTag { id: "394", datatype: value_types::INT};
-pub const NUMTICKETS: FixTag = FixTag { id: "395", datatype: value_types::INT};
-pub const SIDEVALUE1: FixTag = FixTag { id: "396", datatype: value_types::AMT};
-pub const SIDEVALUE2: FixTag = FixTag { id: "397", datatype: value_types::AMT};
-pub const NOBIDDESCRIPTORS: FixTag = FixTag { id: "398", datatype: value_types::NUM_IN_GROUP };
-pub const BIDDESCRIPTORTYPE: FixTag = FixTag { id: "399", datatype: value_types::INT};
-pub const BIDDESCRIPTOR: FixTag = FixTag { id: "400", datatype: value_types::STRING};
-pub const SIDEVALUEIND: FixTag = FixTag { id: "401", datatype: value_types::INT};
-pub const LIQUIDITYPCTLOW: FixTag = FixTag { id: "402", datatype: value_types::PERCENTAGE};
-pub const LIQUIDITYPCTHIGH: FixTag = FixTag { id: "403", datatype: value_types::PERCENTAGE};
-pub const LIQUIDITYVALUE: FixTag = FixTag { id: "404", datatype: value_types::AMT};
-pub const EFPTRACKINGERROR: FixTag = FixTag { id: "405", datatype: value_types::PERCENTAGE};
-pub const FAIRVALUE: FixTag = FixTag { id: "406", datatype: value_types::AMT};
-pub const OUTSIDEINDEXPCT: FixTag = FixTag { id: "407", datatype: value_types::PERCENTAGE};
-pub const VALUEOFFUTURES: FixTag = FixTag { id: "408", datatype: value_types::AMT};
-pub const LIQUIDITYINDTYPE: FixTag = FixTag { id: "409", datatype: value_types::INT};
-pub const WTAVERAGELIQUIDITY: FixTag = FixTag { id: "410", datatype: value_types::PERCENTAGE};
-pub const EXCHANGEFORPHYSICAL: FixTag = FixTag { id: "411", datatype: value_types::BOOLEAN};
-pub const OUTMAINCNTRYUINDEX: FixTag = FixTag { id: "412", datatype: value_types::AMT};
-pub const CROSSPERCENT: FixTag = FixTag { id: "413", datatype: value_types::PERCENTAGE};
-pub const PROGRPTREQS: FixTag = FixTag { id: "414", datatype: value_types::INT};
-pub const PROGPERIODINTERVAL: FixTag = FixTag { id: "415", datatype: value_types::INT};
-pub const INCTAXIND: FixTag = FixTag { id: "416", datatype: value_types::INT};
-pub const NUMBIDDERS: FixTag = FixTag { id: "417", datatype: value_types::INT};
-pub const BIDTRADETYPE: FixTag = FixTag { id: "418", datatype: value_types::CHAR};
-pub const BASISPXTYPE: FixTag = FixTag { id: "419", datatype: value_types::CHAR};
-pub const NOBIDCOMPONENTS: FixTag = FixTag { id: "420", datatype: value_types::NUM_IN_GROUP };
-pub const COUNTRY: FixTag = FixTag { id: "421", datatype: value_types::ISO_COUNTRY};
-pub const TOTNOSTRIKES: FixTag = FixTag { id: "422", datatype: value_types::INT};
-pub const PRICETYPE: FixTag = FixTag { id: "423", datatype: value_types::INT};
-pub const DAYORDERQTY: FixTag = FixTag { id: "424", datatype: value_types::QTY};
-pub const DAYCUMQTY: FixTag = FixTag { id: "425", datatype: value_types::QTY};
-pub const DAYAVGPX: FixTag = FixTag { id: "426", datatype: value_types::PRICE};
-pub const GTBOOKINGINST: FixTag = FixTag { id: "427", datatype: value_types::INT};
-pub const NOSTRIKES: FixTag = FixTag { id: "428", datatype: value_types::NUM_IN_GROUP };
-pub const LISTSTATUSTYPE: FixTag = FixTag { id: "429", datatype: value_types::INT};
-pub const NETGROSSIND: FixTag = FixTag { id: "430", datatype: value_types::INT};
-pub const LISTORDERSTATUS: FixTag = FixTag { id: "431", datatype: value_types::INT};
-pub const EXPIREDATE: FixTag = FixTag { id: "432", datatype: value_types::LOCAL_MKT_DATE};
-pub const LISTEXECINSTTYPE: FixTag = FixTag { id: "433", datatype: value_types::CHAR};
-pub const CXLREJRESPONSETO: FixTag = FixTag { id: "434", datatype: value_types::CHAR};
-pub const UNDERLYINGCOUPONRATE: FixTag = FixTag { id: "435", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGCONTRACTMULTIPLIER: FixTag = FixTag { id: "436", datatype: value_types::FLOAT};
-pub const CONTRATRADEQTY: FixTag = FixTag { id: "437", datatype: value_types::QTY};
-pub const CONTRATRADETIME: FixTag = FixTag { id: "438", datatype: value_types::UTC_TIMESTAMP};
-pub const LIQUIDITYNUMSECURITIES: FixTag = FixTag { id: "441", datatype: value_types::INT};
-pub const MULTILEGREPORTINGTYPE: FixTag = FixTag { id: "442", datatype: value_types::CHAR};
-pub const STRIKETIME: FixTag = FixTag { id: "443", datatype: value_types::UTC_TIMESTAMP};
-pub const LISTSTATUSTEXT: FixTag = FixTag { id: "444", datatype: value_types::STRING};
-pub const ENCODEDLISTSTATUSTEXTLEN: FixTag = FixTag { id: "445", datatype: value_types::LENGTH};
-pub const ENCODEDLISTSTATUSTEXT: FixTag = FixTag { id: "446", datatype: value_types::DATA};
-pub const PARTYIDSOURCE: FixTag = FixTag { id: "447", datatype: value_types::CHAR};
-pub const PARTYID: FixTag = FixTag { id: "448", datatype: value_types::STRING};
-pub const NETCHGPREVDAY: FixTag = FixTag { id: "451", datatype: value_types::PRICE_OFFSET};
-pub const PARTYROLE: FixTag = FixTag { id: "452", datatype: value_types::INT};
-pub const NOPARTYIDS: FixTag = FixTag { id: "453", datatype: value_types::NUM_IN_GROUP };
-pub const NOSECURITYALTID: FixTag = FixTag { id: "454", datatype: value_types::NUM_IN_GROUP };
-pub const SECURITYALTID: FixTag = FixTag { id: "455", datatype: value_types::STRING};
-pub const SECURITYALTIDSOURCE: FixTag = FixTag { id: "456", datatype: value_types::STRING};
-pub const NOUNDERLYINGSECURITYALTID: FixTag = FixTag { id: "457", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGSECURITYALTID: FixTag = FixTag { id: "458", datatype: value_types::STRING};
-pub const UNDERLYINGSECURITYALTIDSOURCE: FixTag = FixTag { id: "459", datatype: value_types::STRING};
-pub const PRODUCT: FixTag = FixTag { id: "460", datatype: value_types::INT};
-pub const CFICODE: FixTag = FixTag { id: "461", datatype: value_types::STRING};
-pub const UNDERLYINGPRODUCT: FixTag = FixTag { id: "462", datatype: value_types::INT};
-pub const UNDERLYINGCFICODE: FixTag = FixTag { id: "463", datatype: value_types::STRING};
-pub const TESTMESSAGEINDICATOR: FixTag = FixTag { id: "464", datatype: value_types::BOOLEAN};
-pub const BOOKINGREFID: FixTag = FixTag { id: "466", datatype: value_types::STRING};
-pub const INDIVIDUALALLOCID: FixTag = FixTag { id: "467", datatype: value_types::STRING};
-pub const ROUNDINGDIRECTION: FixTag = FixTag { id: "468", datatype: value_types::CHAR};
-pub const ROUNDINGMODULUS: FixTag = FixTag { id: "469", datatype: value_types::FLOAT};
-pub const COUNTRYOFISSUE: FixTag = FixTag { id: "470", datatype: value_types::ISO_COUNTRY};
-pub const STATEORPROVINCEOFISSUE: FixTag = FixTag { id: "471", datatype: value_types::STRING};
-pub const LOCALEOFISSUE: FixTag = FixTag { id: "472", datatype: value_types::STRING};
-pub const NOREGISTDTLS: FixTag = FixTag { id: "473", datatype: value_types::NUM_IN_GROUP };
-pub const MAILINGDTLS: FixTag = FixTag { id: "474", datatype: value_types::STRING};
-pub const INVESTORCOUNTRYOFRESIDENCE: FixTag = FixTag { id: "475", datatype: value_types::ISO_COUNTRY};
-pub const PAYMENTREF: FixTag = FixTag { id: "476", datatype: value_types::STRING};
-pub const DISTRIBPAYMENTMETHOD: FixTag = FixTag { id: "477", datatype: value_types::INT};
-pub const CASHDISTRIBCURR: FixTag = FixTag { id: "478", datatype: value_types::CURRENCY};
-pub const COMMCURRENCY: FixTag = FixTag { id: "479", datatype: value_types::CURRENCY};
-pub const CANCELLATIONRIGHTS: FixTag = FixTag { id: "480", datatype: value_types::CHAR};
-pub const MONEYLAUNDERINGSTATUS: FixTag = FixTag { id: "481", datatype: value_types::CHAR};
-pub const MAILINGINST: FixTag = FixTag { id: "482", datatype: value_types::STRING};
-pub const TRANSBKDTIME: FixTag = FixTag { id: "483", datatype: value_types::UTC_TIMESTAMP};
-pub const EXECPRICETYPE: FixTag = FixTag { id: "484", datatype: value_types::CHAR};
-pub const EXECPRICEADJUSTMENT: FixTag = FixTag { id: "485", datatype: value_types::FLOAT};
-pub const DATEOFBIRTH: FixTag = FixTag { id: "486", datatype: value_types::LOCAL_MKT_DATE};
-pub const TRADEREPORTTRANSTYPE: FixTag = FixTag { id: "487", datatype: value_types::INT};
-pub const CARDHOLDERNAME: FixTag = FixTag { id: "488", datatype: value_types::STRING};
-pub const CARDNUMBER: FixTag = FixTag { id: "489", datatype: value_types::STRING};
-pub const CARDEXPDATE: FixTag = FixTag { id: "490", datatype: value_types::LOCAL_MKT_DATE};
-pub const CARDISSNUM: FixTag = FixTag { id: "491", datatype: value_types::STRING};
-pub const PAYMENTMETHOD: FixTag = FixTag { id: "492", datatype: value_types::INT};
-pub const REGISTACCTTYPE: FixTag = FixTag { id: "493", datatype: value_types::STRING};
-pub const DESIGNATION: FixTag = FixTag { id: "494", datatype: value_types::STRING};
-pub const TAXADVANTAGETYPE: FixTag = FixTag { id: "495", datatype: value_types::INT};
-pub const REGISTREJREASONTEXT: FixTag = FixTag { id: "496", datatype: value_types::STRING};
-pub const FUNDRENEWWAIV: FixTag = FixTag { id: "497", datatype: value_types::CHAR};
-pub const CASHDISTRIBAGENTNAME: FixTag = FixTag { id: "498", datatype: value_types::STRING};
-pub const CASHDISTRIBAGENTCODE: FixTag = FixTag { id: "499", datatype: value_types::STRING};
-pub const CASHDISTRIBAGENTACCTNUMBER: FixTag = FixTag { id: "500", datatype: value_types::STRING};
-pub const CASHDISTRIBPAYREF: FixTag = FixTag { id: "501", datatype: value_types::STRING};
-pub const CASHDISTRIBAGENTACCTNAME: FixTag = FixTag { id: "502", datatype: value_types::STRING};
-pub const CARDSTARTDATE: FixTag = FixTag { id: "503", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTDATE: FixTag = FixTag { id: "504", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTREMITTERID: FixTag = FixTag { id: "505", datatype: value_types::STRING};
-pub const REGISTSTATUS: FixTag = FixTag { id: "506", datatype: value_types::CHAR};
-pub const REGISTREJREASONCODE: FixTag = FixTag { id: "507", datatype: value_types::INT};
-pub const REGISTREFID: FixTag = FixTag { id: "508", datatype: value_types::STRING};
-pub const REGISTDTLS: FixTag = FixTag { id: "509", datatype: value_types::STRING};
-pub const NODISTRIBINSTS: FixTag = FixTag { id: "510", datatype: value_types::NUM_IN_GROUP };
-pub const REGISTEMAIL: FixTag = FixTag { id: "511", datatype: value_types::STRING};
-pub const DISTRIBPERCENTAGE: FixTag = FixTag { id: "512", datatype: value_types::PERCENTAGE};
-pub const REGISTID: FixTag = FixTag { id: "513", datatype: value_types::STRING};
-pub const REGISTTRANSTYPE: FixTag = FixTag { id: "514", datatype: value_types::CHAR};
-pub const EXECVALUATIONPOINT: FixTag = FixTag { id: "515", datatype: value_types::UTC_TIMESTAMP};
-pub const ORDERPERCENT: FixTag = FixTag { id: "516", datatype: value_types::PERCENTAGE};
-pub const OWNERSHIPTYPE: FixTag = FixTag { id: "517", datatype: value_types::CHAR};
-pub const NOCONTAMTS: FixTag = FixTag { id: "518", datatype: value_types::NUM_IN_GROUP };
-pub const CONTAMTTYPE: FixTag = FixTag { id: "519", datatype: value_types::INT};
-pub const CONTAMTVALUE: FixTag = FixTag { id: "520", datatype: value_types::FLOAT};
-pub const CONTAMTCURR: FixTag = FixTag { id: "521", datatype: value_types::CURRENCY};
-pub const OWNERTYPE: FixTag = FixTag { id: "522", datatype: value_types::INT};
-pub const PARTYSUBID: FixTag = FixTag { id: "523", datatype: value_types::STRING};
-pub const NESTEDPARTYID: FixTag = FixTag { id: "524", datatype: value_types::STRING};
-pub const NESTEDPARTYIDSOURCE: FixTag = FixTag { id: "525", datatype: value_types::CHAR};
-pub const SECONDARYCLORDID: FixTag = FixTag { id: "526", datatype: value_types::STRING};
-pub const SECONDARYEXECID: FixTag = FixTag { id: "527", datatype: value_types::STRING};
-pub const ORDERCAPACITY: FixTag = FixTag { id: "528", datatype: value_types::CHAR};
-pub const ORDERRESTRICTIONS: FixTag = FixTag { id: "529", datatype: value_types::MULTIPLE_CHAR_VALUE };
-pub const MASSCANCELREQUESTTYPE: FixTag = FixTag { id: "530", datatype: value_types::CHAR};
-pub const MASSCANCELRESPONSE: FixTag = FixTag { id: "531", datatype: value_types::CHAR};
-pub const MASSCANCELREJECTREASON: FixTag = FixTag { id: "532", datatype: value_types::INT};
-pub const TOTALAFFECTEDORDERS: FixTag = FixTag { id: "533", datatype: value_types::INT};
-pub const NOAFFECTEDORDERS: FixTag = FixTag { id: "534", datatype: value_types::NUM_IN_GROUP };
-pub const AFFECTEDORDERID: FixTag = FixTag { id: "535", datatype: value_types::STRING};
-pub const AFFECTEDSECONDARYORDERID: FixTag = FixTag { id: "536", datatype: value_types::STRING};
-pub const QUOTETYPE: FixTag = FixTag { id: "537", datatype: value_types::INT};
-pub const NESTEDPARTYROLE: FixTag = FixTag { id: "538", datatype: value_types::INT};
-pub const NONESTEDPARTYIDS: FixTag = FixTag { id: "539", datatype: value_types::NUM_IN_GROUP };
-pub const TOTALACCRUEDINTERESTAMT: FixTag = FixTag { id: "540", datatype: value_types::AMT};
-pub const MATURITYDATE: FixTag = FixTag { id: "541", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGMATURITYDATE: FixTag = FixTag { id: "542", datatype: value_types::LOCAL_MKT_DATE};
-pub const INSTRREGISTRY: FixTag = FixTag { id: "543", datatype: value_types::STRING};
-pub const CASHMARGIN: FixTag = FixTag { id: "544", datatype: value_types::CHAR};
-pub const NESTEDPARTYSUBID: FixTag = FixTag { id: "545", datatype: value_types::STRING};
-pub const SCOPE: FixTag = FixTag { id: "546", datatype: value_types::MULTIPLE_CHAR_VALUE };
-pub const MDIMPLICITDELETE: FixTag = FixTag { id: "547", datatype: value_types::BOOLEAN};
-pub const CROSSID: FixTag = FixTag { id: "548", datatype: value_types::STRING};
-pub const CROSSTYPE: FixTag = FixTag { id: "549", datatype: value_types::INT};
-pub const CROSSPRIORITIZATION: FixTag = FixTag { id: "550", datatype: value_types::INT};
-pub const ORIGCROSSID: FixTag = FixTag { id: "551", datatype: value_types::STRING};
-pub const NOSIDES: FixTag = FixTag { id: "552", datatype: value_types::NUM_IN_GROUP };
-pub const USERNAME: FixTag = FixTag { id: "553", datatype: value_types::STRING};
-pub const PASSWORD: FixTag = FixTag { id: "554", datatype: value_types::STRING};
-pub const NOLEGS: FixTag = FixTag { id: "555", datatype: value_types::NUM_IN_GROUP };
-pub const LEGCURRENCY: FixTag = FixTag { id: "556", datatype: value_types::CURRENCY};
-pub const TOTNOSECURITYTYPES: FixTag = FixTag { id: "557", datatype: value_types::INT};
-pub const NOSECURITYTYPES: FixTag = FixTag { id: "558", datatype: value_types::NUM_IN_GROUP };
-pub const SECURITYLISTREQUESTTYPE: FixTag = FixTag { id: "559", datatype: value_types::INT};
-pub const SECURITYREQUESTRESULT: FixTag = FixTag { id: "560", datatype: value_types::INT};
-pub const ROUNDLOT: FixTag = FixTag { id: "561", datatype: value_types::QTY};
-pub const MINTRADEVOL: FixTag = FixTag { id: "562", datatype: value_types::QTY};
-pub const MULTILEGRPTTYPEREQ: FixTag = FixTag { id: "563", datatype: value_types::INT};
-pub const LEGPOSITIONEFFECT: FixTag = FixTag { id: "564", datatype: value_types::CHAR};
-pub const LEGCOVEREDORUNCOVERED: FixTag = FixTag { id: "565", datatype: value_types::INT};
-pub const LEGPRICE: FixTag = FixTag { id: "566", datatype: value_types::PRICE};
-pub const TRADSESSTATUSREJREASON: FixTag = FixTag { id: "567", datatype: value_types::INT};
-pub const TRADEREQUESTID: FixTag = FixTag { id: "568", datatype: value_types::STRING};
-pub const TRADEREQUESTTYPE: FixTag = FixTag { id: "569", datatype: value_types::INT};
-pub const PREVIOUSLYREPORTED: FixTag = FixTag { id: "570", datatype: value_types::BOOLEAN};
-pub const TRADEREPORTID: FixTag = FixTag { id: "571", datatype: value_types::STRING};
-pub const TRADEREPORTREFID: FixTag = FixTag { id: "572", datatype: value_types::STRING};
-pub const MATCHSTATUS: FixTag = FixTag { id: "573", datatype: value_types::CHAR};
-pub const MATCHTYPE: FixTag = FixTag { id: "574", datatype: value_types::STRING};
-pub const ODDLOT: FixTag = FixTag { id: "575", datatype: value_types::BOOLEAN};
-pub const NOCLEARINGINSTRUCTIONS: FixTag = FixTag { id: "576", datatype: value_types::NUM_IN_GROUP };
-pub const CLEARINGINSTRUCTION: FixTag = FixTag { id: "577", datatype: value_types::INT};
-pub const TRADEINPUTSOURCE: FixTag = FixTag { id: "578", datatype: value_types::STRING};
-pub const TRADEINPUTDEVICE: FixTag = FixTag { id: "579", datatype: value_types::STRING};
-pub const NODATES: FixTag = FixTag { id: "580", datatype: value_types::NUM_IN_GROUP };
-pub const ACCOUNTTYPE: FixTag = FixTag { id: "581", datatype: value_types::INT};
-pub const CUSTORDERCAPACITY: FixTag = FixTag { id: "582", datatype: value_types::INT};
-pub const CLORDLINKID: FixTag = FixTag { id: "583", datatype: value_types::STRING};
-pub const MASSSTATUSREQID: FixTag = FixTag { id: "584", datatype: value_types::STRING};
-pub const MASSSTATUSREQTYPE: FixTag = FixTag { id: "585", datatype: value_types::INT};
-pub const ORIGORDMODTIME: FixTag = FixTag { id: "586", datatype: value_types::UTC_TIMESTAMP};
-pub const LEGSETTLTYPE: FixTag = FixTag { id: "587", datatype: value_types::STRING};
-pub const LEGSETTLDATE: FixTag = FixTag { id: "588", datatype: value_types::LOCAL_MKT_DATE};
-pub const DAYBOOKINGINST: FixTag = FixTag { id: "589", datatype: value_types::CHAR};
-pub const BOOKINGUNIT: FixTag = FixTag { id: "590", datatype: value_types::CHAR};
-pub const PREALLOCMETHOD: FixTag = FixTag { id: "591", datatype: value_types::CHAR};
-pub const UNDERLYINGCOUNTRYOFISSUE: FixTag = FixTag { id: "592", datatype: value_types::ISO_COUNTRY};
-pub const UNDERLYINGSTATEORPROVINCEOFISSUE: FixTag = FixTag { id: "593", datatype: value_types::STRING};
-pub const UNDERLYINGLOCALEOFISSUE: FixTag = FixTag { id: "594", datatype: value_types::STRING};
-pub const UNDERLYINGINSTRREGISTRY: FixTag = FixTag { id: "595", datatype: value_types::STRING};
-pub const LEGCOUNTRYOFISSUE: FixTag = FixTag { id: "596", datatype: value_types::ISO_COUNTRY};
-pub const LEGSTATEORPROVINCEOFISSUE: FixTag = FixTag { id: "597", datatype: value_types::STRING};
-pub const LEGLOCALEOFISSUE: FixTag = FixTag { id: "598", datatype: value_types::STRING};
-pub const LEGINSTRREGISTRY: FixTag = FixTag { id: "599", datatype: value_types::STRING};
-pub const LEGSYMBOL: FixTag = FixTag { id: "600", datatype: value_types::STRING};
-pub const LEGSYMBOLSFX: FixTag = FixTag { id: "601", datatype: value_types::STRING};
-pub const LEGSECURITYID: FixTag = FixTag { id: "602", datatype: value_types::STRING};
-pub const LEGSECURITYIDSOURCE: FixTag = FixTag { id: "603", datatype: value_types::STRING};
-pub const NOLEGSECURITYALTID: FixTag = FixTag { id: "604", datatype: value_types::NUM_IN_GROUP };
-pub const LEGSECURITYALTID: FixTag = FixTag { id: "605", datatype: value_types::STRING};
-pub const LEGSECURITYALTIDSOURCE: FixTag = FixTag { id: "606", datatype: value_types::STRING};
-pub const LEGPRODUCT: FixTag = FixTag { id: "607", datatype: value_types::INT};
-pub const LEGCFICODE: FixTag = FixTag { id: "608", datatype: value_types::STRING};
-pub const LEGSECURITYTYPE: FixTag = FixTag { id: "609", datatype: value_types::STRING};
-pub const LEGMATURITYMONTH_YEAR: FixTag = FixTag { id: "610", datatype: value_types::MONTH_YEAR};
-pub const LEGMATURITYDATE: FixTag = FixTag { id: "611", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGSTRIKEPRICE: FixTag = FixTag { id: "612", datatype: value_types::PRICE};
-pub const LEGOPTATTRIBUTE: FixTag = FixTag { id: "613", datatype: value_types::CHAR};
-pub const LEGCONTRACTMULTIPLIER: FixTag = FixTag { id: "614", datatype: value_types::FLOAT};
-pub const LEGCOUPONRATE: FixTag = FixTag { id: "615", datatype: value_types::PERCENTAGE};
-pub const LEGSECURITYEXCHANGE: FixTag = FixTag { id: "616", datatype: value_types::EXCHANGE};
-pub const LEGISSUER: FixTag = FixTag { id: "617", datatype: value_types::STRING};
-pub const ENCODEDLEGISSUERLEN: FixTag = FixTag { id: "618", datatype: value_types::LENGTH};
-pub const ENCODEDLEGISSUER: FixTag = FixTag { id: "619", datatype: value_types::DATA};
-pub const LEGSECURITYDESC: FixTag = FixTag { id: "620", datatype: value_types::STRING};
-pub const ENCODEDLEGSECURITYDESCLEN: FixTag = FixTag { id: "621", datatype: value_types::LENGTH};
-pub const ENCODEDLEGSECURITYDESC: FixTag = FixTag { id: "622", datatype: value_types::DATA};
-pub const LEGRATIOQTY: FixTag = FixTag { id: "623", datatype: value_types::FLOAT};
-pub const LEGSIDE: FixTag = FixTag { id: "624", datatype: value_types::CHAR};
-pub const TRADINGSESSIONSUBID: FixTag = FixTag { id: "625", datatype: value_types::STRING};
-pub const ALLOCTYPE: FixTag = FixTag { id: "626", datatype: value_types::INT};
-pub const NOHOPS: FixTag = FixTag { id: "627", datatype: value_types::NUM_IN_GROUP };
-pub const HOPCOMPID: FixTag = FixTag { id: "628", datatype: value_types::STRING};
-pub const HOPSENDINGTIME: FixTag = FixTag { id: "629", datatype: value_types::UTC_TIMESTAMP};
-pub const HOPREFID: FixTag = FixTag { id: "630", datatype: value_types::SEQ_NO };
-pub const MIDPX: FixTag = FixTag { id: "631", datatype: value_types::PRICE};
-pub const BIDYIELD: FixTag = FixTag { id: "632", datatype: value_types::PERCENTAGE};
-pub const MIDYIELD: FixTag = FixTag { id: "633", datatype: value_types::PERCENTAGE};
-pub const OFFERYIELD: FixTag = FixTag { id: "634", datatype: value_types::PERCENTAGE};
-pub const CLEARINGFEEINDICATOR: FixTag = FixTag { id: "635", datatype: value_types::STRING};
-pub const WORKINGINDICATOR: FixTag = FixTag { id: "636", datatype: value_types::BOOLEAN};
-pub const LEGLASTPX: FixTag = FixTag { id: "637", datatype: value_types::PRICE};
-pub const PRIORITYINDICATOR: FixTag = FixTag { id: "638", datatype: value_types::INT};
-pub const PRICEIMPROVEMENT: FixTag = FixTag { id: "639", datatype: value_types::PRICE_OFFSET};
-pub const PRICE2: FixTag = FixTag { id: "640", datatype: value_types::PRICE};
-pub const LASTFORWARDPOINTS2: FixTag = FixTag { id: "641", datatype: value_types::PRICE_OFFSET};
-pub const BIDFORWARDPOINTS2: FixTag = FixTag { id: "642", datatype: value_types::PRICE_OFFSET};
-pub const OFFERFORWARDPOINTS2: FixTag = FixTag { id: "643", datatype: value_types::PRICE_OFFSET};
-pub const RFQREQID: FixTag = FixTag { id: "644", datatype: value_types::STRING};
-pub const MKTBIDPX: FixTag = FixTag { id: "645", datatype: value_types::PRICE};
-pub const MKTOFFERPX: FixTag = FixTag { id: "646", datatype: value_types::PRICE};
-pub const MINBIDSIZE: FixTag = FixTag { id: "647", datatype: value_types::QTY};
-pub const MINOFFERSIZE: FixTag = FixTag { id: "648", datatype: value_types::QTY};
-pub const QUOTESTATUSREQID: FixTag = FixTag { id: "649", datatype: value_types::STRING};
-pub const LEGALCONFIRM: FixTag = FixTag { id: "650", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGLASTPX: FixTag = FixTag { id: "651", datatype: value_types::PRICE};
-pub const UNDERLYINGLASTQTY: FixTag = FixTag { id: "652", datatype: value_types::QTY};
-pub const LEGREFID: FixTag = FixTag { id: "654", datatype: value_types::STRING};
-pub const CONTRALEGREFID: FixTag = FixTag { id: "655", datatype: value_types::STRING};
-pub const SETTLCURRBIDFXRATE: FixTag = FixTag { id: "656", datatype: value_types::FLOAT};
-pub const SETTLCURROFFERFXRATE: FixTag = FixTag { id: "657", datatype: value_types::FLOAT};
-pub const QUOTEREQUESTREJECTREASON: FixTag = FixTag { id: "658", datatype: value_types::INT};
-pub const SIDECOMPLIANCEID: FixTag = FixTag { id: "659", datatype: value_types::STRING};
-pub const ACCTIDSOURCE: FixTag = FixTag { id: "660", datatype: value_types::INT};
-pub const ALLOCACCTIDSOURCE: FixTag = FixTag { id: "661", datatype: value_types::INT};
-pub const BENCHMARKPRICE: FixTag = FixTag { id: "662", datatype: value_types::PRICE};
-pub const BENCHMARKPRICETYPE: FixTag = FixTag { id: "663", datatype: value_types::INT};
-pub const CONFIRMID: FixTag = FixTag { id: "664", datatype: value_types::STRING};
-pub const CONFIRMSTATUS: FixTag = FixTag { id: "665", datatype: value_types::INT};
-pub const CONFIRMTRANSTYPE: FixTag = FixTag { id: "666", datatype: value_types::INT};
-pub const CONTRACTSETTLMONTH: FixTag = FixTag { id: "667", datatype: value_types::MONTH_YEAR};
-pub const DELIVERYFORM: FixTag = FixTag { id: "668", datatype: value_types::INT};
-pub const LASTPARPX: FixTag = FixTag { id: "669", datatype: value_types::PRICE};
-pub const NOLEGALLOCS: FixTag = FixTag { id: "670", datatype: value_types::NUM_IN_GROUP };
-pub const LEGALLOCACCOUNT: FixTag = FixTag { id: "671", datatype: value_types::STRING};
-pub const LEGINDIVIDUALALLOCID: FixTag = FixTag { id: "672", datatype: value_types::STRING};
-pub const LEGALLOCQTY: FixTag = FixTag { id: "673", datatype: value_types::QTY};
-pub const LEGALLOCACCTIDSOURCE: FixTag = FixTag { id: "674", datatype: value_types::INT};
-pub const LEGSETTLCURRENCY: FixTag = FixTag { id: "675", datatype: value_types::CURRENCY};
-pub const LEGBENCHMARKCURVECURRENCY: FixTag = FixTag { id: "676", datatype: value_types::CURRENCY};
-pub const LEGBENCHMARKCURVENAME: FixTag = FixTag { id: "677", datatype: value_types::STRING};
-pub const LEGBENCHMARKCURVEPOINT: FixTag = FixTag { id: "678", datatype: value_types::STRING};
-pub const LEGBENCHMARKPRICE: FixTag = FixTag { id: "679", datatype: value_types::PRICE};
-pub const LEGBENCHMARKPRICETYPE: FixTag = FixTag { id: "680", datatype: value_types::INT};
-pub const LEGBIDPX: FixTag = FixTag { id: "681", datatype: value_types::PRICE};
-pub const LEGIOIQTY: FixTag = FixTag { id: "682", datatype: value_types::STRING};
-pub const NOLEGSTIPULATIONS: FixTag = FixTag { id: "683", datatype: value_types::NUM_IN_GROUP };
-pub const LEGOFFERPX: FixTag = FixTag { id: "684", datatype: value_types::PRICE};
-pub const LEGORDERQTY: FixTag = FixTag { id: "685", datatype: value_types::QTY};
-pub const LEGPRICETYPE: FixTag = FixTag { id: "686", datatype: value_types::INT};
-pub const LEGQTY: FixTag = FixTag { id: "687", datatype: value_types::QTY};
-pub const LEGSTIPULATIONTYPE: FixTag = FixTag { id: "688", datatype: value_types::STRING};
-pub const LEGSTIPULATIONVALUE: FixTag = FixTag { id: "689", datatype: value_types::STRING};
-pub const LEGSWAPTYPE: FixTag = FixTag { id: "690", datatype: value_types::INT};
-pub const POOL: FixTag = FixTag { id: "691", datatype: value_types::STRING};
-pub const QUOTEPRICETYPE: FixTag = FixTag { id: "692", datatype: value_types::INT};
-pub const QUOTERESPID: FixTag = FixTag { id: "693", datatype: value_types::STRING};
-pub const QUOTERESPTYPE: FixTag = FixTag { id: "694", datatype: value_types::INT};
-pub const QUOTEQUALIFIER: FixTag = FixTag { id: "695", datatype: value_types::CHAR};
-pub const YIELDREDEMPTIONDATE: FixTag = FixTag { id: "696", datatype: value_types::LOCAL_MKT_DATE};
-pub const YIELDREDEMPTIONPRICE: FixTag = FixTag { id: "697", datatype: value_types::PRICE};
-pub const YIELDREDEMPTIONPRICETYPE: FixTag = FixTag { id: "698", datatype: value_types::INT};
-pub const BENCHMARKSECURITYID: FixTag = FixTag { id: "699", datatype: value_types::STRING};
-pub const REVERSALINDICATOR: FixTag = FixTag { id: "700", datatype: value_types::BOOLEAN};
-pub const YIELDCALCDATE: FixTag = FixTag { id: "701", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOPOSITIONS: FixTag = FixTag { id: "702", datatype: value_types::NUM_IN_GROUP };
-pub const POSTYPE: FixTag = FixTag { id: "703", datatype: value_types::STRING};
-pub const LONGQTY: FixTag = FixTag { id: "704", datatype: value_types::QTY};
-pub const SHORTQTY: FixTag = FixTag { id: "705", datatype: value_types::QTY};
-pub const POSQTYSTATUS: FixTag = FixTag { id: "706", datatype: value_types::INT};
-pub const POSAMTTYPE: FixTag = FixTag { id: "707", datatype: value_types::STRING};
-pub const POSAMT: FixTag = FixTag { id: "708", datatype: value_types::AMT};
-pub const POSTRANSTYPE: FixTag = FixTag { id: "709", datatype: value_types::INT};
-pub const POSREQID: FixTag = FixTag { id: "710", datatype: value_types::STRING};
-pub const NOUNDERLYINGS: FixTag = FixTag { id: "711", datatype: value_types::NUM_IN_GROUP };
-pub const POSMAINTACTION: FixTag = FixTag { id: "712", datatype: value_types::INT};
-pub const ORIGPOSREQREFID: FixTag = FixTag { id: "713", datatype: value_types::STRING};
-pub const POSMAINTRPTREFID: FixTag = FixTag { id: "714", datatype: value_types::STRING};
-pub const CLEARINGBUSINESSDATE: FixTag = FixTag { id: "715", datatype: value_types::LOCAL_MKT_DATE};
-pub const SETTLSESSID: FixTag = FixTag { id: "716", datatype: value_types::STRING};
-pub const SETTLSESSSUBID: FixTag = FixTag { id: "717", datatype: value_types::STRING};
-pub const ADJUSTMENTTYPE: FixTag = FixTag { id: "718", datatype: value_types::INT};
-pub const CONTRARYINSTRUCTIONINDICATOR: FixTag = FixTag { id: "719", datatype: value_types::BOOLEAN};
-pub const PRIORSPREADINDICATOR: FixTag = FixTag { id: "720", datatype: value_types::BOOLEAN};
-pub const POSMAINTRPTID: FixTag = FixTag { id: "721", datatype: value_types::STRING};
-pub const POSMAINTSTATUS: FixTag = FixTag { id: "722", datatype: value_types::INT};
-pub const POSMAINTRESULT: FixTag = FixTag { id: "723", datatype: value_types::INT};
-pub const POSREQTYPE: FixTag = FixTag { id: "724", datatype: value_types::INT};
-pub const RESPONSETRANSPORTTYPE: FixTag = FixTag { id: "725", datatype: value_types::INT};
-pub const RESPONSEDESTINATION: FixTag = FixTag { id: "726", datatype: value_types::STRING};
-pub const TOTALNUMPOSREPORTS: FixTag = FixTag { id: "727", datatype: value_types::INT};
-pub const POSREQRESULT: FixTag = FixTag { id: "728", datatype: value_types::INT};
-pub const POSREQSTATUS: FixTag = FixTag { id: "729", datatype: value_types::INT};
-pub const SETTLPRICE: FixTag = FixTag { id: "730", datatype: value_types::PRICE};
-pub const SETTLPRICETYPE: FixTag = FixTag { id: "731", datatype: value_types::INT};
-pub const UNDERLYINGSETTLPRICE: FixTag = FixTag { id: "732", datatype: value_types::PRICE};
-pub const UNDERLYINGSETTLPRICETYPE: FixTag = FixTag { id: "733", datatype: value_types::INT};
-pub const PRIORSETTLPRICE: FixTag = FixTag { id: "734", datatype: value_types::PRICE};
-pub const NOQUOTEQUALIFIERS: FixTag = FixTag { id: "735", datatype: value_types::NUM_IN_GROUP };
-pub const ALLOCSETTLCURRENCY: FixTag = FixTag { id: "736", datatype: value_types::CURRENCY};
-pub const ALLOCSETTLCURRAMT: FixTag = FixTag { id: "737", datatype: value_types::AMT};
-pub const INTERESTATMATURITY: FixTag = FixTag { id: "738", datatype: value_types::AMT};
-pub const LEGDATEDDATE: FixTag = FixTag { id: "739", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPOOL: FixTag = FixTag { id: "740", datatype: value_types::STRING};
-pub const ALLOCINTERESTATMATURITY: FixTag = FixTag { id: "741", datatype: value_types::AMT};
-pub const ALLOCACCRUEDINTERESTAMT: FixTag = FixTag { id: "742", datatype: value_types::AMT};
-pub const DELIVERYDATE: FixTag = FixTag { id: "743", datatype: value_types::LOCAL_MKT_DATE};
-pub const ASSIGNMENTMETHOD: FixTag = FixTag { id: "744", datatype: value_types::CHAR};
-pub const ASSIGNMENTUNIT: FixTag = FixTag { id: "745", datatype: value_types::QTY};
-pub const OPENINTEREST: FixTag = FixTag { id: "746", datatype: value_types::AMT};
-pub const EXERCISEMETHOD: FixTag = FixTag { id: "747", datatype: value_types::CHAR};
-pub const TOTNUMTRADEREPORTS: FixTag = FixTag { id: "748", datatype: value_types::INT};
-pub const TRADEREQUESTRESULT: FixTag = FixTag { id: "749", datatype: value_types::INT};
-pub const TRADEREQUESTSTATUS: FixTag = FixTag { id: "750", datatype: value_types::INT};
-pub const TRADEREPORTREJECTREASON: FixTag = FixTag { id: "751", datatype: value_types::INT};
-pub const SIDEMULTILEGREPORTINGTYPE: FixTag = FixTag { id: "752", datatype: value_types::INT};
-pub const NOPOSAMT: FixTag = FixTag { id: "753", datatype: value_types::NUM_IN_GROUP };
-pub const AUTOACCEPTINDICATOR: FixTag = FixTag { id: "754", datatype: value_types::BOOLEAN};
-pub const ALLOCREPORTID: FixTag = FixTag { id: "755", datatype: value_types::STRING};
-pub const NONESTED2PARTYIDS: FixTag = FixTag { id: "756", datatype: value_types::NUM_IN_GROUP };
-pub const NESTED2PARTYID: FixTag = FixTag { id: "757", datatype: value_types::STRING};
-pub const NESTED2PARTYIDSOURCE: FixTag = FixTag { id: "758", datatype: value_types::CHAR};
-pub const NESTED2PARTYROLE: FixTag = FixTag { id: "759", datatype: value_types::INT};
-pub const NESTED2PARTYSUBID: FixTag = FixTag { id: "760", datatype: value_types::STRING};
-pub const BENCHMARKSECURITYIDSOURCE: FixTag = FixTag { id: "761", datatype: value_types::STRING};
-pub const SECURITYSUBTYPE: FixTag = FixTag { id: "762", datatype: value_types::STRING};
-pub const UNDERLYINGSECURITYSUBTYPE: FixTag = FixTag { id: "763", datatype: value_types::STRING};
-pub const LEGSECURITYSUBTYPE: FixTag = FixTag { id: "764", datatype: value_types::STRING};
-pub const ALLOWABLEONESIDEDNESSPCT: FixTag = FixTag { id: "765", datatype: value_types::PERCENTAGE};
-pub const ALLOWABLEONESIDEDNESSVALUE: FixTag = FixTag { id: "766", datatype: value_types::AMT};
-pub const ALLOWABLEONESIDEDNESSCURR: FixTag = FixTag { id: "767", datatype: value_types::CURRENCY};
-pub const NOTRDREGTIMESTAMPS: FixTag = FixTag { id: "768", datatype: value_types::NUM_IN_GROUP };
-pub const TRDREGTIMESTAMP: FixTag = FixTag { id: "769", datatype: value_types::UTC_TIMESTAMP};
-pub const TRDREGTIMESTAMPTYPE: FixTag = FixTag { id: "770", datatype: value_types::INT};
-pub const TRDREGTIMESTAMPORIGIN: FixTag = FixTag { id: "771", datatype: value_types::STRING};
-pub const CONFIRMREFID: FixTag = FixTag { id: "772", datatype: value_types::STRING};
-pub const CONFIRMTYPE: FixTag = FixTag { id: "773", datatype: value_types::INT};
-pub const CONFIRMREJREASON: FixTag = FixTag { id: "774", datatype: value_types::INT};
-pub const BOOKINGTYPE: FixTag = FixTag { id: "775", datatype: value_types::INT};
-pub const INDIVIDUALALLOCREJCODE: FixTag = FixTag { id: "776", datatype: value_types::INT};
-pub const SETTLINSTMSGID: FixTag = FixTag { id: "777", datatype: value_types::STRING};
-pub const NOSETTLINST: FixTag = FixTag { id: "778", datatype: value_types::NUM_IN_GROUP };
-pub const LASTUPDATETIME: FixTag = FixTag { id: "779", datatype: value_types::UTC_TIMESTAMP};
-pub const ALLOCSETTLINSTTYPE: FixTag = FixTag { id: "780", datatype: value_types::INT};
-pub const NOSETTLPARTYIDS: FixTag = FixTag { id: "781", datatype: value_types::NUM_IN_GROUP };
-pub const SETTLPARTYID: FixTag = FixTag { id: "782", datatype: value_types::STRING};
-pub const SETTLPARTYIDSOURCE: FixTag = FixTag { id: "783", datatype: value_types::CHAR};
-pub const SETTLPARTYROLE: FixTag = FixTag { id: "784", datatype: value_types::INT};
-pub const SETTLPARTYSUBID: FixTag = FixTag { id: "785", datatype: value_types::STRING};
-pub const SETTLPARTYSUBIDTYPE: FixTag = FixTag { id: "786", datatype: value_types::INT};
-pub const DLVYINSTTYPE: FixTag = FixTag { id: "787", datatype: value_types::CHAR};
-pub const TERMINATIONTYPE: FixTag = FixTag { id: "788", datatype: value_types::INT};
-pub const NEXTEXPECTEDMSGSEQNUM: FixTag = FixTag { id: "789", datatype: value_types::SEQ_NO };
-pub const ORDSTATUSREQID: FixTag = FixTag { id: "790", datatype: value_types::STRING};
-pub const SETTLINSTREQID: FixTag = FixTag { id: "791", datatype: value_types::STRING};
-pub const SETTLINSTREQREJCODE: FixTag = FixTag { id: "792", datatype: value_types::INT};
-pub const SECONDARYALLOCID: FixTag = FixTag { id: "793", datatype: value_types::STRING};
-pub const ALLOCREPORTTYPE: FixTag = FixTag { id: "794", datatype: value_types::INT};
-pub const ALLOCREPORTREFID: FixTag = FixTag { id: "795", datatype: value_types::STRING};
-pub const ALLOCCANCREPLACEREASON: FixTag = FixTag { id: "796", datatype: value_types::INT};
-pub const COPYMSGINDICATOR: FixTag = FixTag { id: "797", datatype: value_types::BOOLEAN};
-pub const ALLOCACCOUNTTYPE: FixTag = FixTag { id: "798", datatype: value_types::INT};
-pub const ORDERAVGPX: FixTag = FixTag { id: "799", datatype: value_types::PRICE};
-pub const ORDERBOOKINGQTY: FixTag = FixTag { id: "800", datatype: value_types::QTY};
-pub const NOSETTLPARTYSUBIDS: FixTag = FixTag { id: "801", datatype: value_types::NUM_IN_GROUP };
-pub const NOPARTYSUBIDS: FixTag = FixTag { id: "802", datatype: value_types::NUM_IN_GROUP };
-pub const PARTYSUBIDTYPE: FixTag = FixTag { id: "803", datatype: value_types::INT};
-pub const NONESTEDPARTYSUBIDS: FixTag = FixTag { id: "804", datatype: value_types::NUM_IN_GROUP };
-pub const NESTEDPARTYSUBIDTYPE: FixTag = FixTag { id: "805", datatype: value_types::INT};
-pub const NONESTED2PARTYSUBIDS: FixTag = FixTag { id: "806", datatype: value_types::NUM_IN_GROUP };
-pub const NESTED2PARTYSUBIDTYPE: FixTag = FixTag { id: "807", datatype: value_types::INT};
-pub const ALLOCINTERMEDREQTYPE: FixTag = FixTag { id: "808", datatype: value_types::INT};
-pub const NOUSERNAMES: FixTag = FixTag { id: "809", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPX: FixTag = FixTag { id: "810", datatype: value_types::PRICE};
-pub const PRICEDELTA: FixTag = FixTag { id: "811", datatype: value_types::FLOAT};
-pub const APPLQUEUEMAX: FixTag = FixTag { id: "812", datatype: value_types::INT};
-pub const APPLQUEUEDEPTH: FixTag = FixTag { id: "813", datatype: value_types::INT};
-pub const APPLQUEUERESOLUTION: FixTag = FixTag { id: "814", datatype: value_types::INT};
-pub const APPLQUEUEACTION: FixTag = FixTag { id: "815", datatype: value_types::INT};
-pub const NOALTMDSOURCE: FixTag = FixTag { id: "816", datatype: value_types::NUM_IN_GROUP };
-pub const ALTMDSOURCEID: FixTag = FixTag { id: "817", datatype: value_types::STRING};
-pub const SECONDARYTRADEREPORTID: FixTag = FixTag { id: "818", datatype: value_types::STRING};
-pub const AVGPXINDICATOR: FixTag = FixTag { id: "819", datatype: value_types::INT};
-pub const TRADELINKID: FixTag = FixTag { id: "820", datatype: value_types::STRING};
-pub const ORDERINPUTDEVICE: FixTag = FixTag { id: "821", datatype: value_types::STRING};
-pub const UNDERLYINGTRADINGSESSIONID: FixTag = FixTag { id: "822", datatype: value_types::STRING};
-pub const UNDERLYINGTRADINGSESSIONSUBID: FixTag = FixTag { id: "823", datatype: value_types::STRING};
-pub const TRADELEGREFID: FixTag = FixTag { id: "824", datatype: value_types::STRING};
-pub const EXCHANGERULE: FixTag = FixTag { id: "825", datatype: value_types::STRING};
-pub const TRADEALLOCINDICATOR: FixTag = FixTag { id: "826", datatype: value_types::INT};
-pub const EXPIRATIONCYCLE: FixTag = FixTag { id: "827", datatype: value_types::INT};
-pub const TRDTYPE: FixTag = FixTag { id: "828", datatype: value_types::INT};
-pub const TRDSUBTYPE: FixTag = FixTag { id: "829", datatype: value_types::INT};
-pub const TRANSFERREASON: FixTag = FixTag { id: "830", datatype: value_types::STRING};
-pub const TOTNUMASSIGNMENTREPORTS: FixTag = FixTag { id: "832", datatype: value_types::INT};
-pub const ASGNRPTID: FixTag = FixTag { id: "833", datatype: value_types::STRING};
-pub const THRESHOLDAMOUNT: FixTag = FixTag { id: "834", datatype: value_types::PRICE_OFFSET};
-pub const PEGMOVETYPE: FixTag = FixTag { id: "835", datatype: value_types::INT};
-pub const PEGOFFSETTYPE: FixTag = FixTag { id: "836", datatype: value_types::INT};
-pub const PEGLIMITTYPE: FixTag = FixTag { id: "837", datatype: value_types::INT};
-pub const PEGROUNDDIRECTION: FixTag = FixTag { id: "838", datatype: value_types::INT};
-pub const PEGGEDPRICE: FixTag = FixTag { id: "839", datatype: value_types::PRICE};
-pub const PEGSCOPE: FixTag = FixTag { id: "840", datatype: value_types::INT};
-pub const DISCRETIONMOVETYPE: FixTag = FixTag { id: "841", datatype: value_types::INT};
-pub const DISCRETIONOFFSETTYPE: FixTag = FixTag { id: "842", datatype: value_types::INT};
-pub const DISCRETIONLIMITTYPE: FixTag = FixTag { id: "843", datatype: value_types::INT};
-pub const DISCRETIONROUNDDIRECTION: FixTag = FixTag { id: "844", datatype: value_types::INT};
-pub const DISCRETIONPRICE: FixTag = FixTag { id: "845", datatype: value_types::PRICE};
-pub const DISCRETIONSCOPE: FixTag = FixTag { id: "846", datatype: value_types::INT};
-pub const TARGETSTRATEGY: FixTag = FixTag { id: "847", datatype: value_types::INT};
-pub const TARGETSTRATEGYPARAMETERS: FixTag = FixTag { id: "848", datatype: value_types::STRING};
-pub const PARTICIPATIONRATE: FixTag = FixTag { id: "849", datatype: value_types::PERCENTAGE};
-pub const TARGETSTRATEGYPERFORMANCE: FixTag = FixTag { id: "850", datatype: value_types::FLOAT};
-pub const LASTLIQUIDITYIND: FixTag = FixTag { id: "851", datatype: value_types::INT};
-pub const PUBLISHTRDINDICATOR: FixTag = FixTag { id: "852", datatype: value_types::BOOLEAN};
-pub const SHORTSALEREASON: FixTag = FixTag { id: "853", datatype: value_types::INT};
-pub const QTYTYPE: FixTag = FixTag { id: "854", datatype: value_types::INT};
-pub const SECONDARYTRDTYPE: FixTag = FixTag { id: "855", datatype: value_types::INT};
-pub const TRADEREPORTTYPE: FixTag = FixTag { id: "856", datatype: value_types::INT};
-pub const ALLOCNOORDERSTYPE: FixTag = FixTag { id: "857", datatype: value_types::INT};
-pub const SHAREDCOMMISSION: FixTag = FixTag { id: "858", datatype: value_types::AMT};
-pub const CONFIRMREQID: FixTag = FixTag { id: "859", datatype: value_types::STRING};
-pub const AVGPARPX: FixTag = FixTag { id: "860", datatype: value_types::PRICE};
-pub const REPORTEDPX: FixTag = FixTag { id: "861", datatype: value_types::PRICE};
-pub const NOCAPACITIES: FixTag = FixTag { id: "862", datatype: value_types::NUM_IN_GROUP };
-pub const ORDERCAPACITYQTY: FixTag = FixTag { id: "863", datatype: value_types::QTY};
-pub const NOEVENTS: FixTag = FixTag { id: "864", datatype: value_types::NUM_IN_GROUP };
-pub const EVENTTYPE: FixTag = FixTag { id: "865", datatype: value_types::INT};
-pub const EVENTDATE: FixTag = FixTag { id: "866", datatype: value_types::LOCAL_MKT_DATE};
-pub const EVENTPX: FixTag = FixTag { id: "867", datatype: value_types::PRICE};
-pub const EVENTTEXT: FixTag = FixTag { id: "868", datatype: value_types::STRING};
-pub const PCTATRISK: FixTag = FixTag { id: "869", datatype: value_types::PERCENTAGE};
-pub const NOINSTRATTRIB: FixTag = FixTag { id: "870", datatype: value_types::NUM_IN_GROUP };
-pub const INSTRATTRIBTYPE: FixTag = FixTag { id: "871", datatype: value_types::INT};
-pub const INSTRATTRIBVALUE: FixTag = FixTag { id: "872", datatype: value_types::STRING};
-pub const DATEDDATE: FixTag = FixTag { id: "873", datatype: value_types::LOCAL_MKT_DATE};
-pub const INTERESTACCRUALDATE: FixTag = FixTag { id: "874", datatype: value_types::LOCAL_MKT_DATE};
-pub const CPPROGRAM: FixTag = FixTag { id: "875", datatype: value_types::INT};
-pub const CPREGTYPE: FixTag = FixTag { id: "876", datatype: value_types::STRING};
-pub const UNDERLYINGCPPROGRAM: FixTag = FixTag { id: "877", datatype: value_types::INT};
-pub const UNDERLYINGCPREGTYPE: FixTag = FixTag { id: "878", datatype: value_types::STRING};
-pub const UNDERLYINGQTY: FixTag = FixTag { id: "879", datatype: value_types::QTY};
-pub const TRDMATCHID: FixTag = FixTag { id: "880", datatype: value_types::STRING};
-pub const SECONDARYTRADEREPORTREFID: FixTag = FixTag { id: "881", datatype: value_types::STRING};
-pub const UNDERLYINGDIRTYPRICE: FixTag = FixTag { id: "882", datatype: value_types::PRICE};
-pub const UNDERLYINGENDPRICE: FixTag = FixTag { id: "883", datatype: value_types::PRICE};
-pub const UNDERLYINGSTARTVALUE: FixTag = FixTag { id: "884", datatype: value_types::AMT};
-pub const UNDERLYINGCURRENTVALUE: FixTag = FixTag { id: "885", datatype: value_types::AMT};
-pub const UNDERLYINGENDVALUE: FixTag = FixTag { id: "886", datatype: value_types::AMT};
-pub const NOUNDERLYINGSTIPS: FixTag = FixTag { id: "887", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGSTIPTYPE: FixTag = FixTag { id: "888", datatype: value_types::STRING};
-pub const UNDERLYINGSTIPVALUE: FixTag = FixTag { id: "889", datatype: value_types::STRING};
-pub const MATURITYNETMONEY: FixTag = FixTag { id: "890", datatype: value_types::AMT};
-pub const MISCFEEBASIS: FixTag = FixTag { id: "891", datatype: value_types::INT};
-pub const TOTNOALLOCS: FixTag = FixTag { id: "892", datatype: value_types::INT};
-pub const LASTFRAGMENT: FixTag = FixTag { id: "893", datatype: value_types::BOOLEAN};
-pub const COLLREQID: FixTag = FixTag { id: "894", datatype: value_types::STRING};
-pub const COLLASGNREASON: FixTag = FixTag { id: "895", datatype: value_types::INT};
-pub const COLLINQUIRYQUALIFIER: FixTag = FixTag { id: "896", datatype: value_types::INT};
-pub const NOTRADES: FixTag = FixTag { id: "897", datatype: value_types::NUM_IN_GROUP };
-pub const MARGINRATIO: FixTag = FixTag { id: "898", datatype: value_types::PERCENTAGE};
-pub const MARGINEXCESS: FixTag = FixTag { id: "899", datatype: value_types::AMT};
-pub const TOTALNETVALUE: FixTag = FixTag { id: "900", datatype: value_types::AMT};
-pub const CASHOUTSTANDING: FixTag = FixTag { id: "901", datatype: value_types::AMT};
-pub const COLLASGNID: FixTag = FixTag { id: "902", datatype: value_types::STRING};
-pub const COLLASGNTRANSTYPE: FixTag = FixTag { id: "903", datatype: value_types::INT};
-pub const COLLRESPID: FixTag = FixTag { id: "904", datatype: value_types::STRING};
-pub const COLLASGNRESPTYPE: FixTag = FixTag { id: "905", datatype: value_types::INT};
-pub const COLLASGNREJECTREASON: FixTag = FixTag { id: "906", datatype: value_types::INT};
-pub const COLLASGNREFID: FixTag = FixTag { id: "907", datatype: value_types::STRING};
-pub const COLLRPTID: FixTag = FixTag { id: "908", datatype: value_types::STRING};
-pub const COLLINQUIRYID: FixTag = FixTag { id: "909", datatype: value_types::STRING};
-pub const COLLSTATUS: FixTag = FixTag { id: "910", datatype: value_types::INT};
-pub const TOTNUMREPORTS: FixTag = FixTag { id: "911", datatype: value_types::INT};
-pub const LASTRPTREQUESTED: FixTag = FixTag { id: "912", datatype: value_types::BOOLEAN};
-pub const AGREEMENTDESC: FixTag = FixTag { id: "913", datatype: value_types::STRING};
-pub const AGREEMENTID: FixTag = FixTag { id: "914", datatype: value_types::STRING};
-pub const AGREEMENTDATE: FixTag = FixTag { id: "915", datatype: value_types::LOCAL_MKT_DATE};
-pub const STARTDATE: FixTag = FixTag { id: "916", datatype: value_types::LOCAL_MKT_DATE};
-pub const ENDDATE: FixTag = FixTag { id: "917", datatype: value_types::LOCAL_MKT_DATE};
-pub const AGREEMENTCURRENCY: FixTag = FixTag { id: "918", datatype: value_types::CURRENCY};
-pub const DELIVERYTYPE: FixTag = FixTag { id: "919", datatype: value_types::INT};
-pub const ENDACCRUEDINTERESTAMT: FixTag = FixTag { id: "920", datatype: value_types::AMT};
-pub const STARTCASH: FixTag = FixTag { id: "921", datatype: value_types::AMT};
-pub const ENDCASH: FixTag = FixTag { id: "922", datatype: value_types::AMT};
-pub const USERREQUESTID: FixTag = FixTag { id: "923", datatype: value_types::STRING};
-pub const USERREQUESTTYPE: FixTag = FixTag { id: "924", datatype: value_types::INT};
-pub const NEWPASSWORD: FixTag = FixTag { id: "925", datatype: value_types::STRING};
-pub const USERSTATUS: FixTag = FixTag { id: "926", datatype: value_types::INT};
-pub const USERSTATUSTEXT: FixTag = FixTag { id: "927", datatype: value_types::STRING};
-pub const STATUSVALUE: FixTag = FixTag { id: "928", datatype: value_types::INT};
-pub const STATUSTEXT: FixTag = FixTag { id: "929", datatype: value_types::STRING};
-pub const REFCOMPID: FixTag = FixTag { id: "930", datatype: value_types::STRING};
-pub const REFSUBID: FixTag = FixTag { id: "931", datatype: value_types::STRING};
-pub const NETWORKRESPONSEID: FixTag = FixTag { id: "932", datatype: value_types::STRING};
-pub const NETWORKREQUESTID: FixTag = FixTag { id: "933", datatype: value_types::STRING};
-pub const LASTNETWORKRESPONSEID: FixTag = FixTag { id: "934", datatype: value_types::STRING};
-pub const NETWORKREQUESTTYPE: FixTag = FixTag { id: "935", datatype: value_types::INT};
-pub const NOCOMPIDS: FixTag = FixTag { id: "936", datatype: value_types::NUM_IN_GROUP };
-pub const NETWORKSTATUSRESPONSETYPE: FixTag = FixTag { id: "937", datatype: value_types::INT};
-pub const NOCOLLINQUIRYQUALIFIER: FixTag = FixTag { id: "938", datatype: value_types::NUM_IN_GROUP };
-pub const TRDRPTSTATUS: FixTag = FixTag { id: "939", datatype: value_types::INT};
-pub const AFFIRMSTATUS: FixTag = FixTag { id: "940", datatype: value_types::INT};
-pub const UNDERLYINGSTRIKECURRENCY: FixTag = FixTag { id: "941", datatype: value_types::CURRENCY};
-pub const LEGSTRIKECURRENCY: FixTag = FixTag { id: "942", datatype: value_types::CURRENCY};
-pub const TIMEBRACKET: FixTag = FixTag { id: "943", datatype: value_types::STRING};
-pub const COLLACTION: FixTag = FixTag { id: "944", datatype: value_types::INT};
-pub const COLLINQUIRYSTATUS: FixTag = FixTag { id: "945", datatype: value_types::INT};
-pub const COLLINQUIRYRESULT: FixTag = FixTag { id: "946", datatype: value_types::INT};
-pub const STRIKECURRENCY: FixTag = FixTag { id: "947", datatype: value_types::CURRENCY};
-pub const NONESTED3PARTYIDS: FixTag = FixTag { id: "948", datatype: value_types::NUM_IN_GROUP };
-pub const NESTED3PARTYID: FixTag = FixTag { id: "949", datatype: value_types::STRING};
-pub const NESTED3PARTYIDSOURCE: FixTag = FixTag { id: "950", datatype: value_types::CHAR};
-pub const NESTED3PARTYROLE: FixTag = FixTag { id: "951", datatype: value_types::INT};
-pub const NONESTED3PARTYSUBIDS: FixTag = FixTag { id: "952", datatype: value_types::NUM_IN_GROUP };
-pub const NESTED3PARTYSUBID: FixTag = FixTag { id: "953", datatype: value_types::STRING};
-pub const NESTED3PARTYSUBIDTYPE: FixTag = FixTag { id: "954", datatype: value_types::INT};
-pub const LEGCONTRACTSETTLMONTH: FixTag = FixTag { id: "955", datatype: value_types::MONTH_YEAR};
-pub const LEGINTERESTACCRUALDATE: FixTag = FixTag { id: "956", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOSTRATEGYPARAMETERS: FixTag = FixTag { id: "957", datatype: value_types::NUM_IN_GROUP };
-pub const STRATEGYPARAMETERNAME: FixTag = FixTag { id: "958", datatype: value_types::STRING};
-pub const STRATEGYPARAMETERTYPE: FixTag = FixTag { id: "959", datatype: value_types::INT};
-pub const STRATEGYPARAMETERVALUE: FixTag = FixTag { id: "960", datatype: value_types::STRING};
-pub const HOSTCROSSID: FixTag = FixTag { id: "961", datatype: value_types::STRING};
-pub const SIDETIMEINFORCE: FixTag = FixTag { id: "962", datatype: value_types::UTC_TIMESTAMP};
-pub const MDREPORTID: FixTag = FixTag { id: "963", datatype: value_types::INT};
-pub const SECURITYREPORTID: FixTag = FixTag { id: "964", datatype: value_types::INT};
-pub const SECURITYSTATUS: FixTag = FixTag { id: "965", datatype: value_types::STRING};
-pub const SETTLEONOPENFLAG: FixTag = FixTag { id: "966", datatype: value_types::STRING};
-pub const STRIKEMULTIPLIER: FixTag = FixTag { id: "967", datatype: value_types::FLOAT};
-pub const STRIKEVALUE: FixTag = FixTag { id: "968", datatype: value_types::FLOAT};
-pub const MINPRICEINCREMENT: FixTag = FixTag { id: "969", datatype: value_types::FLOAT};
-pub const POSITIONLIMIT: FixTag = FixTag { id: "970", datatype: value_types::INT};
-pub const NTPOSITIONLIMIT: FixTag = FixTag { id: "971", datatype: value_types::INT};
-pub const UNDERLYINGALLOCATIONPERCENT: FixTag = FixTag { id: "972", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGCASHAMOUNT: FixTag = FixTag { id: "973", datatype: value_types::AMT};
-pub const UNDERLYINGCASHTYPE: FixTag = FixTag { id: "974", datatype: value_types::STRING};
-pub const UNDERLYINGSETTLEMENTTYPE: FixTag = FixTag { id: "975", datatype: value_types::INT};
-pub const QUANTITYDATE: FixTag = FixTag { id: "976", datatype: value_types::LOCAL_MKT_DATE};
-pub const CONTINTRPTID: FixTag = FixTag { id: "977", datatype: value_types::STRING};
-pub const LATEINDICATOR: FixTag = FixTag { id: "978", datatype: value_types::BOOLEAN};
-pub const INPUTSOURCE: FixTag = FixTag { id: "979", datatype: value_types::STRING};
-pub const SECURITYUPDATEACTION: FixTag = FixTag { id: "980", datatype: value_types::CHAR};
-pub const NOEXPIRATION: FixTag = FixTag { id: "981", datatype: value_types::NUM_IN_GROUP };
-pub const EXPIRATIONQTYTYPE: FixTag = FixTag { id: "982", datatype: value_types::INT};
-pub const EXPQTY: FixTag = FixTag { id: "983", datatype: value_types::QTY};
-pub const NOUNDERLYINGAMOUNTS: FixTag = FixTag { id: "984", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPAYAMOUNT: FixTag = FixTag { id: "985", datatype: value_types::AMT};
-pub const UNDERLYINGCOLLECTAMOUNT: FixTag = FixTag { id: "986", datatype: value_types::AMT};
-pub const UNDERLYINGSETTLEMENTDATE: FixTag = FixTag { id: "987", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGSETTLEMENTSTATUS: FixTag = FixTag { id: "988", datatype: value_types::STRING};
-pub const SECONDARYINDIVIDUALALLOCID: FixTag = FixTag { id: "989", datatype: value_types::STRING};
-pub const LEGREPORTID: FixTag = FixTag { id: "990", datatype: value_types::STRING};
-pub const RNDPX: FixTag = FixTag { id: "991", datatype: value_types::PRICE};
-pub const INDIVIDUALALLOCTYPE: FixTag = FixTag { id: "992", datatype: value_types::INT};
-pub const ALLOCCUSTOMERCAPACITY: FixTag = FixTag { id: "993", datatype: value_types::STRING};
-pub const TIERCODE: FixTag = FixTag { id: "994", datatype: value_types::STRING};
-pub const UNITOFMEASURE: FixTag = FixTag { id: "996", datatype: value_types::STRING};
-pub const TIMEUNIT: FixTag = FixTag { id: "997", datatype: value_types::STRING};
-pub const UNDERLYINGUNITOFMEASURE: FixTag = FixTag { id: "998", datatype: value_types::STRING};
-pub const LEGUNITOFMEASURE: FixTag = FixTag { id: "999", datatype: value_types::STRING};
-pub const UNDERLYINGTIMEUNIT: FixTag = FixTag { id: "1000", datatype: value_types::STRING};
-pub const LEGTIMEUNIT: FixTag = FixTag { id: "1001", datatype: value_types::STRING};
-pub const ALLOCMETHOD: FixTag = FixTag { id: "1002", datatype: value_types::INT};
-pub const TRADEID: FixTag = FixTag { id: "1003", datatype: value_types::STRING};
-pub const SIDETRADEREPORTID: FixTag = FixTag { id: "1005", datatype: value_types::STRING};
-pub const SIDEFILLSTATIONCD: FixTag = FixTag { id: "1006", datatype: value_types::STRING};
-pub const SIDEREASONCD: FixTag = FixTag { id: "1007", datatype: value_types::STRING};
-pub const SIDETRDSUBTYPE: FixTag = FixTag { id: "1008", datatype: value_types::INT};
-pub const SIDELASTQTY: FixTag = FixTag { id: "1009", datatype: value_types::QTY};
-pub const MESSAGEEVENTSOURCE: FixTag = FixTag { id: "1011", datatype: value_types::STRING};
-pub const SIDETRDREGTIMESTAMP: FixTag = FixTag { id: "1012", datatype: value_types::UTC_TIMESTAMP};
-pub const SIDETRDREGTIMESTAMPTYPE: FixTag = FixTag { id: "1013", datatype: value_types::INT};
-pub const SIDETRDREGTIMESTAMPSRC: FixTag = FixTag { id: "1014", datatype: value_types::STRING};
-pub const ASOFINDICATOR: FixTag = FixTag { id: "1015", datatype: value_types::CHAR};
-pub const NOSIDETRDREGTS: FixTag = FixTag { id: "1016", datatype: value_types::NUM_IN_GROUP };
-pub const LEGOPTIONRATIO: FixTag = FixTag { id: "1017", datatype: value_types::FLOAT};
-pub const NOINSTRUMENTPARTIES: FixTag = FixTag { id: "1018", datatype: value_types::NUM_IN_GROUP };
-pub const INSTRUMENTPARTYID: FixTag = FixTag { id: "1019", datatype: value_types::STRING};
-pub const TRADEVOLUME: FixTag = FixTag { id: "1020", datatype: value_types::QTY};
-pub const MDBOOKTYPE: FixTag = FixTag { id: "1021", datatype: value_types::INT};
-pub const MDFEEDTYPE: FixTag = FixTag { id: "1022", datatype: value_types::STRING};
-pub const MDPRICELEVEL: FixTag = FixTag { id: "1023", datatype: value_types::INT};
-pub const MDORIGINTYPE: FixTag = FixTag { id: "1024", datatype: value_types::INT};
-pub const FIRSTPX: FixTag = FixTag { id: "1025", datatype: value_types::PRICE};
-pub const MDENTRYSPOTRATE: FixTag = FixTag { id: "1026", datatype: value_types::FLOAT};
-pub const MDENTRYFORWARDPOINTS: FixTag = FixTag { id: "1027", datatype: value_types::PRICE_OFFSET};
-pub const MANUALORDERINDICATOR: FixTag = FixTag { id: "1028", datatype: value_types::BOOLEAN};
-pub const CUSTDIRECTEDORDER: FixTag = FixTag { id: "1029", datatype: value_types::BOOLEAN};
-pub const RECEIVEDDEPTID: FixTag = FixTag { id: "1030", datatype: value_types::STRING};
-pub const CUSTORDERHANDLINGINST: FixTag = FixTag { id: "1031", datatype: value_types::MULTIPLE_STRING_VALUE};
-pub const ORDERHANDLINGINSTSOURCE: FixTag = FixTag { id: "1032", datatype: value_types::INT};
-pub const DESKTYPE: FixTag = FixTag { id: "1033", datatype: value_types::STRING};
-pub const DESKTYPESOURCE: FixTag = FixTag { id: "1034", datatype: value_types::INT};
-pub const DESKORDERHANDLINGINST: FixTag = FixTag { id: "1035", datatype: value_types::MULTIPLE_STRING_VALUE};
-pub const EXECACKSTATUS: FixTag = FixTag { id: "1036", datatype: value_types::CHAR};
-pub const UNDERLYINGDELIVERYAMOUNT: FixTag = FixTag { id: "1037", datatype: value_types::AMT};
-pub const UNDERLYINGCAPVALUE: FixTag = FixTag { id: "1038", datatype: value_types::AMT};
-pub const UNDERLYINGSETTLMETHOD: FixTag = FixTag { id: "1039", datatype: value_types::STRING};
-pub const SECONDARYTRADEID: FixTag = FixTag { id: "1040", datatype: value_types::STRING};
-pub const FIRMTRADEID: FixTag = FixTag { id: "1041", datatype: value_types::STRING};
-pub const SECONDARYFIRMTRADEID: FixTag = FixTag { id: "1042", datatype: value_types::STRING};
-pub const COLLAPPLTYPE: FixTag = FixTag { id: "1043", datatype: value_types::INT};
-pub const UNDERLYINGADJUSTEDQUANTITY: FixTag = FixTag { id: "1044", datatype: value_types::QTY};
-pub const UNDERLYINGFXRATE: FixTag = FixTag { id: "1045", datatype: value_types::FLOAT};
-pub const UNDERLYINGFXRATECALC: FixTag = FixTag { id: "1046", datatype: value_types::CHAR};
-pub const ALLOCPOSITIONEFFECT: FixTag = FixTag { id: "1047", datatype: value_types::CHAR};
-pub const DEALINGCAPACITY: FixTag = FixTag { id: "1048", datatype: value_types::CHAR};
-pub const INSTRMTASSIGNMENTMETHOD: FixTag = FixTag { id: "1049", datatype: value_types::CHAR};
-pub const INSTRUMENTPARTYIDSOURCE: FixTag = FixTag { id: "1050", datatype: value_types::CHAR};
-pub const INSTRUMENTPARTYROLE: FixTag = FixTag { id: "1051", datatype: value_types::INT};
-pub const NOINSTRUMENTPARTYSUBIDS: FixTag = FixTag { id: "1052", datatype: value_types::NUM_IN_GROUP };
-pub const INSTRUMENTPARTYSUBID: FixTag = FixTag { id: "1053", datatype: value_types::STRING};
-pub const INSTRUMENTPARTYSUBIDTYPE: FixTag = FixTag { id: "1054", datatype: value_types::INT};
-pub const POSITIONCURRENCY: FixTag = FixTag { id: "1055", datatype: value_types::STRING};
-pub const CALCULATEDCCYLASTQTY: FixTag = FixTag { id: "1056", datatype: value_types::QTY};
-pub const AGGRESSORINDICATOR: FixTag = FixTag { id: "1057", datatype: value_types::BOOLEAN};
-pub const NOUNDLYINSTRUMENTPARTIES: FixTag = FixTag { id: "1058", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGINSTRUMENTPARTYID: FixTag = FixTag { id: "1059", datatype: value_types::STRING};
-pub const UNDERLYINGINSTRUMENTPARTYIDSOURCE: FixTag = FixTag { id: "1060", datatype: value_types::CHAR};
-pub const UNDERLYINGINSTRUMENTPARTYROLE: FixTag = FixTag { id: "1061", datatype: value_types::INT};
-pub const NOUNDLYINSTRUMENTPARTYSUBIDS: FixTag = FixTag { id: "1062", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGINSTRUMENTPARTYSUBID: FixTag = FixTag { id: "1063", datatype: value_types::STRING};
-pub const UNDERLYINGINSTRUMENTPARTYSUBIDTYPE: FixTag = FixTag { id: "1064", datatype: value_types::INT};
-pub const BIDSWAPPOINTS: FixTag = FixTag { id: "1065", datatype: value_types::PRICE_OFFSET};
-pub const OFFERSWAPPOINTS: FixTag = FixTag { id: "1066", datatype: value_types::PRICE_OFFSET};
-pub const LEGBIDFORWARDPOINTS: FixTag = FixTag { id: "1067", datatype: value_types::PRICE_OFFSET};
-pub const LEGOFFERFORWARDPOINTS: FixTag = FixTag { id: "1068", datatype: value_types::PRICE_OFFSET};
-pub const SWAPPOINTS: FixTag = FixTag { id: "1069", datatype: value_types::PRICE_OFFSET};
-pub const MDQUOTETYPE: FixTag = FixTag { id: "1070", datatype: value_types::INT};
-pub const LASTSWAPPOINTS: FixTag = FixTag { id: "1071", datatype: value_types::PRICE_OFFSET};
-pub const SIDEGROSSTRADEAMT: FixTag = FixTag { id: "1072", datatype: value_types::AMT};
-pub const LEGLASTFORWARDPOINTS: FixTag = FixTag { id: "1073", datatype: value_types::PRICE_OFFSET};
-pub const LEGCALCULATEDCCYLASTQTY: FixTag = FixTag { id: "1074", datatype: value_types::QTY};
-pub const LEGGROSSTRADEAMT: FixTag = FixTag { id: "1075", datatype: value_types::AMT};
-pub const MATURITYTIME: FixTag = FixTag { id: "1079", datatype: value_types::TZ_TIME_ONLY};
-pub const REFORDERID: FixTag = FixTag { id: "1080", datatype: value_types::STRING};
-pub const REFORDERIDSOURCE: FixTag = FixTag { id: "1081", datatype: value_types::CHAR};
-pub const SECONDARYDISPLAYQTY: FixTag = FixTag { id: "1082", datatype: value_types::QTY};
-pub const DISPLAYWHEN: FixTag = FixTag { id: "1083", datatype: value_types::CHAR};
-pub const DISPLAYMETHOD: FixTag = FixTag { id: "1084", datatype: value_types::CHAR};
-pub const DISPLAYLOWQTY: FixTag = FixTag { id: "1085", datatype: value_types::QTY};
-pub const DISPLAYHIGHQTY: FixTag = FixTag { id: "1086", datatype: value_types::QTY};
-pub const DISPLAYMININCR: FixTag = FixTag { id: "1087", datatype: value_types::QTY};
-pub const REFRESHQTY: FixTag = FixTag { id: "1088", datatype: value_types::QTY};
-pub const MATCHINCREMENT: FixTag = FixTag { id: "1089", datatype: value_types::QTY};
-pub const MAXPRICELEVELS: FixTag = FixTag { id: "1090", datatype: value_types::INT};
-pub const PRETRADEANONYMITY: FixTag = FixTag { id: "1091", datatype: value_types::BOOLEAN};
-pub const PRICEPROTECTIONSCOPE: FixTag = FixTag { id: "1092", datatype: value_types::CHAR};
-pub const LOTTYPE: FixTag = FixTag { id: "1093", datatype: value_types::CHAR};
-pub const PEGPRICETYPE: FixTag = FixTag { id: "1094", datatype: value_types::INT};
-pub const PEGGEDREFPRICE: FixTag = FixTag { id: "1095", datatype: value_types::PRICE};
-pub const PEGSECURITYIDSOURCE: FixTag = FixTag { id: "1096", datatype: value_types::STRING};
-pub const PEGSECURITYID: FixTag = FixTag { id: "1097", datatype: value_types::STRING};
-pub const PEGSYMBOL: FixTag = FixTag { id: "1098", datatype: value_types::STRING};
-pub const PEGSECURITYDESC: FixTag = FixTag { id: "1099", datatype: value_types::STRING};
-pub const TRIGGERTYPE: FixTag = FixTag { id: "1100", datatype: value_types::CHAR};
-pub const TRIGGERACTION: FixTag = FixTag { id: "1101", datatype: value_types::CHAR};
-pub const TRIGGERPRICE: FixTag = FixTag { id: "1102", datatype: value_types::PRICE};
-pub const TRIGGERSYMBOL: FixTag = FixTag { id: "1103", datatype: value_types::STRING};
-pub const TRIGGERSECURITYID: FixTag = FixTag { id: "1104", datatype: value_types::STRING};
-pub const TRIGGERSECURITYIDSOURCE: FixTag = FixTag { id: "1105", datatype: value_types::STRING};
-pub const TRIGGERSECURITYDESC: FixTag = FixTag { id: "1106", datatype: value_types::STRING};
-pub const TRIGGERPRICETYPE: FixTag = FixTag { id: "1107", datatype: value_types::CHAR};
-pub const TRIGGERPRICETYPESCOPE: FixTag = FixTag { id: "1108", datatype: value_types::CHAR};
-pub const TRIGGERPRICEDIRECTION: FixTag = FixTag { id: "1109", datatype: value_types::CHAR};
-pub const TRIGGERNEWPRICE: FixTag = FixTag { id: "1110", datatype: value_types::PRICE};
-pub const TRIGGERORDERTYPE: FixTag = FixTag { id: "1111", datatype: value_types::CHAR};
-pub const TRIGGERNEWQTY: FixTag = FixTag { id: "1112", datatype: value_types::QTY};
-pub const TRIGGERTRADINGSESSIONID: FixTag = FixTag { id: "1113", datatype: value_types::STRING};
-pub const TRIGGERTRADINGSESSIONSUBID: FixTag = FixTag { id: "1114", datatype: value_types::STRING};
-pub const ORDERCATEGORY: FixTag = FixTag { id: "1115", datatype: value_types::CHAR};
-pub const NOROOTPARTYIDS: FixTag = FixTag { id: "1116", datatype: value_types::NUM_IN_GROUP };
-pub const ROOTPARTYID: FixTag = FixTag { id: "1117", datatype: value_types::STRING};
-pub const ROOTPARTYIDSOURCE: FixTag = FixTag { id: "1118", datatype: value_types::CHAR};
-pub const ROOTPARTYROLE: FixTag = FixTag { id: "1119", datatype: value_types::INT};
-pub const NOROOTPARTYSUBIDS: FixTag = FixTag { id: "1120", datatype: value_types::NUM_IN_GROUP };
-pub const ROOTPARTYSUBID: FixTag = FixTag { id: "1121", datatype: value_types::STRING};
-pub const ROOTPARTYSUBIDTYPE: FixTag = FixTag { id: "1122", datatype: value_types::INT};
-pub const TRADEHANDLINGINSTR: FixTag = FixTag { id: "1123", datatype: value_types::CHAR};
-pub const ORIGTRADEHANDLINGINSTR: FixTag = FixTag { id: "1124", datatype: value_types::CHAR};
-pub const ORIGTRADEDATE: FixTag = FixTag { id: "1125", datatype: value_types::LOCAL_MKT_DATE};
-pub const ORIGTRADEID: FixTag = FixTag { id: "1126", datatype: value_types::STRING};
-pub const ORIGSECONDARYTRADEID: FixTag = FixTag { id: "1127", datatype: value_types::STRING};
-pub const APPLVERID: FixTag = FixTag { id: "1128", datatype: value_types::STRING};
-pub const CSTMAPPLVERID: FixTag = FixTag { id: "1129", datatype: value_types::STRING};
-pub const REFAPPLVERID: FixTag = FixTag { id: "1130", datatype: value_types::STRING};
-pub const REFCSTMAPPLVERID: FixTag = FixTag { id: "1131", datatype: value_types::STRING};
-pub const TZTRANSACTTIME: FixTag = FixTag { id: "1132", datatype: value_types::TZ_TIMESTAMP};
-pub const EXDESTINATIONIDSOURCE: FixTag = FixTag { id: "1133", datatype: value_types::CHAR};
-pub const REPORTEDPXDIFF: FixTag = FixTag { id: "1134", datatype: value_types::BOOLEAN};
-pub const RPTSYS: FixTag = FixTag { id: "1135", datatype: value_types::STRING};
-pub const ALLOCCLEARINGFEEINDICATOR: FixTag = FixTag { id: "1136", datatype: value_types::STRING};
-pub const DEFAULTAPPLVERID: FixTag = FixTag { id: "1137", datatype: value_types::STRING};
-pub const DISPLAYQTY: FixTag = FixTag { id: "1138", datatype: value_types::QTY};
-pub const EXCHANGESPECIALINSTRUCTIONS: FixTag = FixTag { id: "1139", datatype: value_types::STRING};
-pub const MAXTRADEVOL: FixTag = FixTag { id: "1140", datatype: value_types::QTY};
-pub const NOMDFEEDTYPES: FixTag = FixTag { id: "1141", datatype: value_types::NUM_IN_GROUP };
-pub const MATCHALGORITHM: FixTag = FixTag { id: "1142", datatype: value_types::STRING};
-pub const MAXPRICEVARIATION: FixTag = FixTag { id: "1143", datatype: value_types::FLOAT};
-pub const IMPLIEDMARKETINDICATOR: FixTag = FixTag { id: "1144", datatype: value_types::INT};
-pub const EVENTTIME: FixTag = FixTag { id: "1145", datatype: value_types::UTC_TIMESTAMP};
-pub const MINPRICEINCREMENTAMOUNT: FixTag = FixTag { id: "1146", datatype: value_types::AMT};
-pub const UNITOFMEASUREQTY: FixTag = FixTag { id: "1147", datatype: value_types::QTY};
-pub const LOWLIMITPRICE: FixTag = FixTag { id: "1148", datatype: value_types::PRICE};
-pub const HIGHLIMITPRICE: FixTag = FixTag { id: "1149", datatype: value_types::PRICE};
-pub const TRADINGREFERENCEPRICE: FixTag = FixTag { id: "1150", datatype: value_types::PRICE};
-pub const SECURITYGROUP: FixTag = FixTag { id: "1151", datatype: value_types::STRING};
-pub const LEGNUMBER: FixTag = FixTag { id: "1152", datatype: value_types::INT};
-pub const SETTLEMENTCYCLENO: FixTag = FixTag { id: "1153", datatype: value_types::INT};
-pub const SIDECURRENCY: FixTag = FixTag { id: "1154", datatype: value_types::CURRENCY};
-pub const SIDESETTLCURRENCY: FixTag = FixTag { id: "1155", datatype: value_types::CURRENCY};
-pub const APPLEXTID: FixTag = FixTag { id: "1156", datatype: value_types::INT};
-pub const CCYAMT: FixTag = FixTag { id: "1157", datatype: value_types::AMT};
-pub const NOSETTLDETAILS: FixTag = FixTag { id: "1158", datatype: value_types::NUM_IN_GROUP };
-pub const SETTLOBLIGMODE: FixTag = FixTag { id: "1159", datatype: value_types::INT};
-pub const SETTLOBLIGMSGID: FixTag = FixTag { id: "1160", datatype: value_types::STRING};
-pub const SETTLOBLIGID: FixTag = FixTag { id: "1161", datatype: value_types::STRING};
-pub const SETTLOBLIGTRANSTYPE: FixTag = FixTag { id: "1162", datatype: value_types::CHAR};
-pub const SETTLOBLIGREFID: FixTag = FixTag { id: "1163", datatype: value_types::STRING};
-pub const SETTLOBLIGSOURCE: FixTag = FixTag { id: "1164", datatype: value_types::CHAR};
-pub const NOSETTLOBLIG: FixTag = FixTag { id: "1165", datatype: value_types::NUM_IN_GROUP };
-pub const QUOTEMSGID: FixTag = FixTag { id: "1166", datatype: value_types::STRING};
-pub const QUOTEENTRYSTATUS: FixTag = FixTag { id: "1167", datatype: value_types::INT};
-pub const TOTNOCXLDQUOTES: FixTag = FixTag { id: "1168", datatype: value_types::INT};
-pub const TOTNOACCQUOTES: FixTag = FixTag { id: "1169", datatype: value_types::INT};
-pub const TOTNOREJQUOTES: FixTag = FixTag { id: "1170", datatype: value_types::INT};
-pub const PRIVATEQUOTE: FixTag = FixTag { id: "1171", datatype: value_types::BOOLEAN};
-pub const RESPONDENTTYPE: FixTag = FixTag { id: "1172", datatype: value_types::INT};
-pub const MDSUBBOOKTYPE: FixTag = FixTag { id: "1173", datatype: value_types::INT};
-pub const SECURITYTRADINGEVENT: FixTag = FixTag { id: "1174", datatype: value_types::INT};
-pub const NOSTATSINDICATORS: FixTag = FixTag { id: "1175", datatype: value_types::NUM_IN_GROUP };
-pub const STATSTYPE: FixTag = FixTag { id: "1176", datatype: value_types::INT};
-pub const NOOFSECSIZES: FixTag = FixTag { id: "1177", datatype: value_types::NUM_IN_GROUP };
-pub const MDSECSIZETYPE: FixTag = FixTag { id: "1178", datatype: value_types::INT};
-pub const MDSECSIZE: FixTag = FixTag { id: "1179", datatype: value_types::QTY};
-pub const APPLID: FixTag = FixTag { id: "1180", datatype: value_types::STRING};
-pub const APPLSEQNUM: FixTag = FixTag { id: "1181", datatype: value_types::SEQ_NO };
-pub const APPLBEGSEQNUM: FixTag = FixTag { id: "1182", datatype: value_types::SEQ_NO };
-pub const APPLENDSEQNUM: FixTag = FixTag { id: "1183", datatype: value_types::SEQ_NO };
-pub const SECURITYXMLLEN: FixTag = FixTag { id: "1184", datatype: value_types::LENGTH};
-pub const SECURITYXML: FixTag = FixTag { id: "1185", datatype: value_types::XML_DATA};
-pub const SECURITYXMLSCHEMA: FixTag = FixTag { id: "1186", datatype: value_types::STRING};
-pub const REFRESHINDICATOR: FixTag = FixTag { id: "1187", datatype: value_types::BOOLEAN};
-pub const VOLATILITY: FixTag = FixTag { id: "1188", datatype: value_types::FLOAT};
-pub const TIMETOEXPIRATION: FixTag = FixTag { id: "1189", datatype: value_types::FLOAT};
-pub const RISKFREERATE: FixTag = FixTag { id: "1190", datatype: value_types::FLOAT};
-pub const PRICEUNITOFMEASURE: FixTag = FixTag { id: "1191", datatype: value_types::STRING};
-pub const PRICEUNITOFMEASUREQTY: FixTag = FixTag { id: "1192", datatype: value_types::QTY};
-pub const SETTLMETHOD: FixTag = FixTag { id: "1193", datatype: value_types::STRING};
-pub const EXERCISESTYLE: FixTag = FixTag { id: "1194", datatype: value_types::INT};
-pub const OPTPAYOUTAMOUNT: FixTag = FixTag { id: "1195", datatype: value_types::AMT};
-pub const PRICEQUOTEMETHOD: FixTag = FixTag { id: "1196", datatype: value_types::STRING};
-pub const VALUATIONMETHOD: FixTag = FixTag { id: "1197", datatype: value_types::STRING};
-pub const LISTMETHOD: FixTag = FixTag { id: "1198", datatype: value_types::INT};
-pub const CAPPRICE: FixTag = FixTag { id: "1199", datatype: value_types::PRICE};
-pub const FLOORPRICE: FixTag = FixTag { id: "1200", datatype: value_types::PRICE};
-pub const NOSTRIKERULES: FixTag = FixTag { id: "1201", datatype: value_types::NUM_IN_GROUP };
-pub const STARTSTRIKEPXRANGE: FixTag = FixTag { id: "1202", datatype: value_types::PRICE};
-pub const ENDSTRIKEPXRANGE: FixTag = FixTag { id: "1203", datatype: value_types::PRICE};
-pub const STRIKEINCREMENT: FixTag = FixTag { id: "1204", datatype: value_types::FLOAT};
-pub const NOTICKRULES: FixTag = FixTag { id: "1205", datatype: value_types::NUM_IN_GROUP };
-pub const STARTTICKPRICERANGE: FixTag = FixTag { id: "1206", datatype: value_types::PRICE};
-pub const ENDTICKPRICERANGE: FixTag = FixTag { id: "1207", datatype: value_types::PRICE};
-pub const TICKINCREMENT: FixTag = FixTag { id: "1208", datatype: value_types::PRICE};
-pub const TICKRULETYPE: FixTag = FixTag { id: "1209", datatype: value_types::INT};
-pub const NESTEDINSTRATTRIBTYPE: FixTag = FixTag { id: "1210", datatype: value_types::INT};
-pub const NESTEDINSTRATTRIBVALUE: FixTag = FixTag { id: "1211", datatype: value_types::STRING};
-pub const LEGMATURITYTIME: FixTag = FixTag { id: "1212", datatype: value_types::TZ_TIME_ONLY};
-pub const UNDERLYINGMATURITYTIME: FixTag = FixTag { id: "1213", datatype: value_types::TZ_TIME_ONLY};
-pub const DERIVATIVESYMBOL: FixTag = FixTag { id: "1214", datatype: value_types::STRING};
-pub const DERIVATIVESYMBOLSFX: FixTag = FixTag { id: "1215", datatype: value_types::STRING};
-pub const DERIVATIVESECURITYID: FixTag = FixTag { id: "1216", datatype: value_types::STRING};
-pub const DERIVATIVESECURITYIDSOURCE: FixTag = FixTag { id: "1217", datatype: value_types::STRING};
-pub const NODERIVATIVESECURITYALTID: FixTag = FixTag { id: "1218", datatype: value_types::NUM_IN_GROUP };
-pub const DERIVATIVESECURITYALTID: FixTag = FixTag { id: "1219", datatype: value_types::STRING};
-pub const DERIVATIVESECURITYALTIDSOURCE: FixTag = FixTag { id: "1220", datatype: value_types::STRING};
-pub const SECONDARYLOWLIMITPRICE: FixTag = FixTag { id: "1221", datatype: value_types::PRICE};
-pub const MATURITYRULEID: FixTag = FixTag { id: "1222", datatype: value_types::STRING};
-pub const STRIKERULEID: FixTag = FixTag { id: "1223", datatype: value_types::STRING};
-pub const LEGUNITOFMEASUREQTY: FixTag = FixTag { id: "1224", datatype: value_types::QTY};
-pub const DERIVATIVEOPTPAYOUTAMOUNT: FixTag = FixTag { id: "1225", datatype: value_types::AMT};
-pub const ENDMATURITYMONTH_YEAR: FixTag = FixTag { id: "1226", datatype: value_types::MONTH_YEAR};
-pub const PRODUCTCOMPLEX: FixTag = FixTag { id: "1227", datatype: value_types::STRING};
-pub const DERIVATIVEPRODUCTCOMPLEX: FixTag = FixTag { id: "1228", datatype: value_types::STRING};
-pub const MATURITYMONTH_YEARINCREMENT: FixTag = FixTag { id: "1229", datatype: value_types::INT};
-pub const SECONDARYHIGHLIMITPRICE: FixTag = FixTag { id: "1230", datatype: value_types::PRICE};
-pub const MINLOTSIZE: FixTag = FixTag { id: "1231", datatype: value_types::QTY};
-pub const NOEXECINSTRULES: FixTag = FixTag { id: "1232", datatype: value_types::NUM_IN_GROUP };
-pub const COMMRATE: FixTag = FixTag { id: "1233", datatype: value_types::FLOAT};
-pub const NOLOTTYPERULES: FixTag = FixTag { id: "1234", datatype: value_types::NUM_IN_GROUP };
-pub const NOMATCHRULES: FixTag = FixTag { id: "1235", datatype: value_types::NUM_IN_GROUP };
-pub const NOMATURITYRULES: FixTag = FixTag { id: "1236", datatype: value_types::NUM_IN_GROUP };
-pub const NOORDTYPERULES: FixTag = FixTag { id: "1237", datatype: value_types::NUM_IN_GROUP };
-pub const COMMUNITOFMEASURE: FixTag = FixTag { id: "1238", datatype: value_types::STRING};
-pub const NOTIMEINFORCERULES: FixTag = FixTag { id: "1239", datatype: value_types::NUM_IN_GROUP };
-pub const SECONDARYTRADINGREFERENCEPRICE: FixTag = FixTag { id: "1240", datatype: value_types::PRICE};
-pub const STARTMATURITYMONTH_YEAR: FixTag = FixTag { id: "1241", datatype: value_types::MONTH_YEAR};
-pub const FLEXPRODUCTELIGIBILITYINDICATOR: FixTag = FixTag { id: "1242", datatype: value_types::BOOLEAN};
-pub const DERIVFLEXPRODUCTELIGIBILITYINDICATOR: FixTag = FixTag { id: "1243", datatype: value_types::BOOLEAN};
-pub const FLEXIBLEINDICATOR: FixTag = FixTag { id: "1244", datatype: value_types::BOOLEAN};
-pub const TRADINGCURRENCY: FixTag = FixTag { id: "1245", datatype: value_types::CURRENCY};
-pub const DERIVATIVEPRODUCT: FixTag = FixTag { id: "1246", datatype: value_types::INT};
-pub const DERIVATIVESECURITYGROUP: FixTag = FixTag { id: "1247", datatype: value_types::STRING};
-pub const DERIVATIVECFICODE: FixTag = FixTag { id: "1248", datatype: value_types::STRING};
-pub const DERIVATIVESECURITYTYPE: FixTag = FixTag { id: "1249", datatype: value_types::STRING};
-pub const DERIVATIVESECURITYSUBTYPE: FixTag = FixTag { id: "1250", datatype: value_types::STRING};
-pub const DERIVATIVEMATURITYMONTH_YEAR: FixTag = FixTag { id: "1251", datatype: value_types::MONTH_YEAR};
-pub const DERIVATIVEMATURITYDATE: FixTag = FixTag { id: "1252", datatype: value_types::LOCAL_MKT_DATE};
-pub const DERIVATIVEMATURITYTIME: FixTag = FixTag { id: "1253", datatype: value_types::TZ_TIME_ONLY};
-pub const DERIVATIVESETTLEONOPENFLAG: FixTag = FixTag { id: "1254", datatype: value_types::STRING};
-pub const DERIVATIVEINSTRMTASSIGNMENTMETHOD: FixTag = FixTag { id: "1255", datatype: value_types::CHAR};
-pub const DERIVATIVESECURITYSTATUS: FixTag = FixTag { id: "1256", datatype: value_types::STRING};
-pub const DERIVATIVEINSTRREGISTRY: FixTag = FixTag { id: "1257", datatype: value_types::STRING};
-pub const DERIVATIVECOUNTRYOFISSUE: FixTag = FixTag { id: "1258", datatype: value_types::ISO_COUNTRY};
-pub const DERIVATIVESTATEORPROVINCEOFISSUE: FixTag = FixTag { id: "1259", datatype: value_types::STRING};
-pub const DERIVATIVELOCALEOFISSUE: FixTag = FixTag { id: "1260", datatype: value_types::STRING};
-pub const DERIVATIVESTRIKEPRICE: FixTag = FixTag { id: "1261", datatype: value_types::PRICE};
-pub const DERIVATIVESTRIKECURRENCY: FixTag = FixTag { id: "1262", datatype: value_types::CURRENCY};
-pub const DERIVATIVESTRIKEMULTIPLIER: FixTag = FixTag { id: "1263", datatype: value_types::FLOAT};
-pub const DERIVATIVESTRIKEVALUE: FixTag = FixTag { id: "1264", datatype: value_types::FLOAT};
-pub const DERIVATIVEOPTATTRIBUTE: FixTag = FixTag { id: "1265", datatype: value_types::CHAR};
-pub const DERIVATIVECONTRACTMULTIPLIER: FixTag = FixTag { id: "1266", datatype: value_types::FLOAT};
-pub const DERIVATIVEMINPRICEINCREMENT: FixTag = FixTag { id: "1267", datatype: value_types::FLOAT};
-pub const DERIVATIVEMINPRICEINCREMENTAMOUNT: FixTag = FixTag { id: "1268", datatype: value_types::AMT};
-pub const DERIVATIVEUNITOFMEASURE: FixTag = FixTag { id: "1269", datatype: value_types::STRING};
-pub const DERIVATIVEUNITOFMEASUREQTY: FixTag = FixTag { id: "1270", datatype: value_types::QTY};
-pub const DERIVATIVETIMEUNIT: FixTag = FixTag { id: "1271", datatype: value_types::STRING};
-pub const DERIVATIVESECURITYEXCHANGE: FixTag = FixTag { id: "1272", datatype: value_types::EXCHANGE};
-pub const DERIVATIVEPOSITIONLIMIT: FixTag = FixTag { id: "1273", datatype: value_types::INT};
-pub const DERIVATIVENTPOSITIONLIMIT: FixTag = FixTag { id: "1274", datatype: value_types::INT};
-pub const DERIVATIVEISSUER: FixTag = FixTag { id: "1275", datatype: value_types::STRING};
-pub const DERIVATIVEISSUEDATE: FixTag = FixTag { id: "1276", datatype: value_types::LOCAL_MKT_DATE};
-pub const DERIVATIVEENCODEDISSUERLEN: FixTag = FixTag { id: "1277", datatype: value_types::LENGTH};
-pub const DERIVATIVEENCODEDISSUER: FixTag = FixTag { id: "1278", datatype: value_types::DATA};
-pub const DERIVATIVESECURITYDESC: FixTag = FixTag { id: "1279", datatype: value_types::STRING};
-pub const DERIVATIVEENCODEDSECURITYDESCLEN: FixTag = FixTag { id: "1280", datatype: value_types::LENGTH};
-pub const DERIVATIVEENCODEDSECURITYDESC: FixTag = FixTag { id: "1281", datatype: value_types::DATA};
-pub const DERIVATIVESECURITYXMLLEN: FixTag = FixTag { id: "1282", datatype: value_types::LENGTH};
-pub const DERIVATIVESECURITYXML: FixTag = FixTag { id: "1283", datatype: value_types::XML_DATA};
-pub const DERIVATIVESECURITYXMLSCHEMA: FixTag = FixTag { id: "1284", datatype: value_types::STRING};
-pub const DERIVATIVECONTRACTSETTLMONTH: FixTag = FixTag { id: "1285", datatype: value_types::MONTH_YEAR};
-pub const NODERIVATIVEEVENTS: FixTag = FixTag { id: "1286", datatype: value_types::NUM_IN_GROUP };
-pub const DERIVATIVEEVENTTYPE: FixTag = FixTag { id: "1287", datatype: value_types::INT};
-pub const DERIVATIVEEVENTDATE: FixTag = FixTag { id: "1288", datatype: value_types::LOCAL_MKT_DATE};
-pub const DERIVATIVEEVENTTIME: FixTag = FixTag { id: "1289", datatype: value_types::UTC_TIMESTAMP};
-pub const DERIVATIVEEVENTPX: FixTag = FixTag { id: "1290", datatype: value_types::PRICE};
-pub const DERIVATIVEEVENTTEXT: FixTag = FixTag { id: "1291", datatype: value_types::STRING};
-pub const NODERIVATIVEINSTRUMENTPARTIES: FixTag = FixTag { id: "1292", datatype: value_types::NUM_IN_GROUP };
-pub const DERIVATIVEINSTRUMENTPARTYID: FixTag = FixTag { id: "1293", datatype: value_types::STRING};
-pub const DERIVATIVEINSTRUMENTPARTYIDSOURCE: FixTag = FixTag { id: "1294", datatype: value_types::CHAR};
-pub const DERIVATIVEINSTRUMENTPARTYROLE: FixTag = FixTag { id: "1295", datatype: value_types::INT};
-pub const NODERIVATIVEINSTRUMENTPARTYSUBIDS: FixTag = FixTag { id: "1296", datatype: value_types::NUM_IN_GROUP };
-pub const DERIVATIVEINSTRUMENTPARTYSUBID: FixTag = FixTag { id: "1297", datatype: value_types::STRING};
-pub const DERIVATIVEINSTRUMENTPARTYSUBIDTYPE: FixTag = FixTag { id: "1298", datatype: value_types::INT};
-pub const DERIVATIVEEXERCISESTYLE: FixTag = FixTag { id: "1299", datatype: value_types::INT};
-pub const MARKETSEGMENTID: FixTag = FixTag { id: "1300", datatype: value_types::STRING};
-pub const MARKETID: FixTag = FixTag { id: "1301", datatype: value_types::EXCHANGE};
-pub const MATURITYMONTH_YEARINCREMENTUNITS: FixTag = FixTag { id: "1302", datatype: value_types::INT};
-pub const MATURITYMONTH_YEARFORMAT: FixTag = FixTag { id: "1303", datatype: value_types::INT};
-pub const STRIKEEXERCISESTYLE: FixTag = FixTag { id: "1304", datatype: value_types::INT};
-pub const SECONDARYPRICELIMITTYPE: FixTag = FixTag { id: "1305", datatype: value_types::INT};
-pub const PRICELIMITTYPE: FixTag = FixTag { id: "1306", datatype: value_types::INT};
-pub const EXECINSTVALUE: FixTag = FixTag { id: "1308", datatype: value_types::MULTIPLE_CHAR_VALUE };
-pub const NOTRADINGSESSIONRULES: FixTag = FixTag { id: "1309", datatype: value_types::NUM_IN_GROUP };
-pub const NOMARKETSEGMENTS: FixTag = FixTag { id: "1310", datatype: value_types::NUM_IN_GROUP };
-pub const NODERIVATIVEINSTRATTRIB: FixTag = FixTag { id: "1311", datatype: value_types::NUM_IN_GROUP };
-pub const NONESTEDINSTRATTRIB: FixTag = FixTag { id: "1312", datatype: value_types::NUM_IN_GROUP };
-pub const DERIVATIVEINSTRATTRIBTYPE: FixTag = FixTag { id: "1313", datatype: value_types::INT};
-pub const DERIVATIVEINSTRATTRIBVALUE: FixTag = FixTag { id: "1314", datatype: value_types::STRING};
-pub const DERIVATIVEPRICEUNITOFMEASURE: FixTag = FixTag { id: "1315", datatype: value_types::STRING};
-pub const DERIVATIVEPRICEUNITOFMEASUREQTY: FixTag = FixTag { id: "1316", datatype: value_types::QTY};
-pub const DERIVATIVESETTLMETHOD: FixTag = FixTag { id: "1317", datatype: value_types::STRING};
-pub const DERIVATIVEPRICEQUOTEMETHOD: FixTag = FixTag { id: "1318", datatype: value_types::STRING};
-pub const DERIVATIVEVALUATIONMETHOD: FixTag = FixTag { id: "1319", datatype: value_types::STRING};
-pub const DERIVATIVELISTMETHOD: FixTag = FixTag { id: "1320", datatype: value_types::INT};
-pub const DERIVATIVECAPPRICE: FixTag = FixTag { id: "1321", datatype: value_types::PRICE};
-pub const DERIVATIVEFLOORPRICE: FixTag = FixTag { id: "1322", datatype: value_types::PRICE};
-pub const DERIVATIVEPUTORCALL: FixTag = FixTag { id: "1323", datatype: value_types::INT};
-pub const LISTUPDATEACTION: FixTag = FixTag { id: "1324", datatype: value_types::CHAR};
-pub const PARENTMKTSEGMID: FixTag = FixTag { id: "1325", datatype: value_types::STRING};
-pub const TRADINGSESSIONDESC: FixTag = FixTag { id: "1326", datatype: value_types::STRING};
-pub const TRADSESUPDATEACTION: FixTag = FixTag { id: "1327", datatype: value_types::CHAR};
-pub const REJECTTEXT: FixTag = FixTag { id: "1328", datatype: value_types::STRING};
-pub const FEEMULTIPLIER: FixTag = FixTag { id: "1329", datatype: value_types::FLOAT};
-pub const UNDERLYINGLEGSYMBOL: FixTag = FixTag { id: "1330", datatype: value_types::STRING};
-pub const UNDERLYINGLEGSYMBOLSFX: FixTag = FixTag { id: "1331", datatype: value_types::STRING};
-pub const UNDERLYINGLEGSECURITYID: FixTag = FixTag { id: "1332", datatype: value_types::STRING};
-pub const UNDERLYINGLEGSECURITYIDSOURCE: FixTag = FixTag { id: "1333", datatype: value_types::STRING};
-pub const NOUNDERLYINGLEGSECURITYALTID: FixTag = FixTag { id: "1334", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGLEGSECURITYALTID: FixTag = FixTag { id: "1335", datatype: value_types::STRING};
-pub const UNDERLYINGLEGSECURITYALTIDSOURCE: FixTag = FixTag { id: "1336", datatype: value_types::STRING};
-pub const UNDERLYINGLEGSECURITYTYPE: FixTag = FixTag { id: "1337", datatype: value_types::STRING};
-pub const UNDERLYINGLEGSECURITYSUBTYPE: FixTag = FixTag { id: "1338", datatype: value_types::STRING};
-pub const UNDERLYINGLEGMATURITYMONTH_YEAR: FixTag = FixTag { id: "1339", datatype: value_types::MONTH_YEAR};
-pub const UNDERLYINGLEGSTRIKEPRICE: FixTag = FixTag { id: "1340", datatype: value_types::PRICE};
-pub const UNDERLYINGLEGSECURITYEXCHANGE: FixTag = FixTag { id: "1341", datatype: value_types::STRING};
-pub const NOOFLEGUNDERLYINGS: FixTag = FixTag { id: "1342", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGLEGPUTORCALL: FixTag = FixTag { id: "1343", datatype: value_types::INT};
-pub const UNDERLYINGLEGCFICODE: FixTag = FixTag { id: "1344", datatype: value_types::STRING};
-pub const UNDERLYINGLEGMATURITYDATE: FixTag = FixTag { id: "1345", datatype: value_types::LOCAL_MKT_DATE};
-pub const APPLREQID: FixTag = FixTag { id: "1346", datatype: value_types::STRING};
-pub const APPLREQTYPE: FixTag = FixTag { id: "1347", datatype: value_types::INT};
-pub const APPLRESPONSETYPE: FixTag = FixTag { id: "1348", datatype: value_types::INT};
-pub const APPLTOTALMESSAGECOUNT: FixTag = FixTag { id: "1349", datatype: value_types::INT};
-pub const APPLLASTSEQNUM: FixTag = FixTag { id: "1350", datatype: value_types::SEQ_NO };
-pub const NOAPPLIDS: FixTag = FixTag { id: "1351", datatype: value_types::NUM_IN_GROUP };
-pub const APPLRESENDFLAG: FixTag = FixTag { id: "1352", datatype: value_types::BOOLEAN};
-pub const APPLRESPONSEID: FixTag = FixTag { id: "1353", datatype: value_types::STRING};
-pub const APPLRESPONSEERROR: FixTag = FixTag { id: "1354", datatype: value_types::INT};
-pub const REFAPPLID: FixTag = FixTag { id: "1355", datatype: value_types::STRING};
-pub const APPLREPORTID: FixTag = FixTag { id: "1356", datatype: value_types::STRING};
-pub const REFAPPLLASTSEQNUM: FixTag = FixTag { id: "1357", datatype: value_types::SEQ_NO };
-pub const LEGPUTORCALL: FixTag = FixTag { id: "1358", datatype: value_types::INT};
-pub const TOTNOFILLS: FixTag = FixTag { id: "1361", datatype: value_types::INT};
-pub const NOFILLS: FixTag = FixTag { id: "1362", datatype: value_types::NUM_IN_GROUP };
-pub const FILLEXECID: FixTag = FixTag { id: "1363", datatype: value_types::STRING};
-pub const FILLPX: FixTag = FixTag { id: "1364", datatype: value_types::PRICE};
-pub const FILLQTY: FixTag = FixTag { id: "1365", datatype: value_types::QTY};
-pub const LEGALLOCID: FixTag = FixTag { id: "1366", datatype: value_types::STRING};
-pub const LEGALLOCSETTLCURRENCY: FixTag = FixTag { id: "1367", datatype: value_types::CURRENCY};
-pub const TRADSESEVENT: FixTag = FixTag { id: "1368", datatype: value_types::INT};
-pub const MASSACTIONREPORTID: FixTag = FixTag { id: "1369", datatype: value_types::STRING};
-pub const NONOTAFFECTEDORDERS: FixTag = FixTag { id: "1370", datatype: value_types::NUM_IN_GROUP };
-pub const NOTAFFECTEDORDERID: FixTag = FixTag { id: "1371", datatype: value_types::STRING};
-pub const NOTAFFORIGCLORDID: FixTag = FixTag { id: "1372", datatype: value_types::STRING};
-pub const MASSACTIONTYPE: FixTag = FixTag { id: "1373", datatype: value_types::INT};
-pub const MASSACTIONSCOPE: FixTag = FixTag { id: "1374", datatype: value_types::INT};
-pub const MASSACTIONRESPONSE: FixTag = FixTag { id: "1375", datatype: value_types::INT};
-pub const MASSACTIONREJECTREASON: FixTag = FixTag { id: "1376", datatype: value_types::INT};
-pub const MULTILEGMODEL: FixTag = FixTag { id: "1377", datatype: value_types::INT};
-pub const MULTILEGPRICEMETHOD: FixTag = FixTag { id: "1378", datatype: value_types::INT};
-pub const LEGVOLATILITY: FixTag = FixTag { id: "1379", datatype: value_types::FLOAT};
-pub const DIVIDENDYIELD: FixTag = FixTag { id: "1380", datatype: value_types::PERCENTAGE};
-pub const LEGDIVIDENDYIELD: FixTag = FixTag { id: "1381", datatype: value_types::PERCENTAGE};
-pub const CURRENCYRATIO: FixTag = FixTag { id: "1382", datatype: value_types::FLOAT};
-pub const LEGCURRENCYRATIO: FixTag = FixTag { id: "1383", datatype: value_types::FLOAT};
-pub const LEGEXECINST: FixTag = FixTag { id: "1384", datatype: value_types::MULTIPLE_CHAR_VALUE };
-pub const CONTINGENCYTYPE: FixTag = FixTag { id: "1385", datatype: value_types::INT};
-pub const LISTREJECTREASON: FixTag = FixTag { id: "1386", datatype: value_types::INT};
-pub const NOTRDREPINDICATORS: FixTag = FixTag { id: "1387", datatype: value_types::NUM_IN_GROUP };
-pub const TRDREPPARTYROLE: FixTag = FixTag { id: "1388", datatype: value_types::INT};
-pub const TRDREPINDICATOR: FixTag = FixTag { id: "1389", datatype: value_types::BOOLEAN};
-pub const TRADEPUBLISHINDICATOR: FixTag = FixTag { id: "1390", datatype: value_types::INT};
-pub const UNDERLYINGLEGOPTATTRIBUTE: FixTag = FixTag { id: "1391", datatype: value_types::CHAR};
-pub const UNDERLYINGLEGSECURITYDESC: FixTag = FixTag { id: "1392", datatype: value_types::STRING};
-pub const MARKETREQID: FixTag = FixTag { id: "1393", datatype: value_types::STRING};
-pub const MARKETREPORTID: FixTag = FixTag { id: "1394", datatype: value_types::STRING};
-pub const MARKETUPDATEACTION: FixTag = FixTag { id: "1395", datatype: value_types::CHAR};
-pub const MARKETSEGMENTDESC: FixTag = FixTag { id: "1396", datatype: value_types::STRING};
-pub const ENCODEDMKTSEGMDESCLEN: FixTag = FixTag { id: "1397", datatype: value_types::LENGTH};
-pub const ENCODEDMKTSEGMDESC: FixTag = FixTag { id: "1398", datatype: value_types::DATA};
-pub const APPLNEWSEQNUM: FixTag = FixTag { id: "1399", datatype: value_types::SEQ_NO };
-pub const ENCRYPTEDPASSWORDMETHOD: FixTag = FixTag { id: "1400", datatype: value_types::INT};
-pub const ENCRYPTEDPASSWORDLEN: FixTag = FixTag { id: "1401", datatype: value_types::LENGTH};
-pub const ENCRYPTEDPASSWORD: FixTag = FixTag { id: "1402", datatype: value_types::DATA};
-pub const ENCRYPTEDNEWPASSWORDLEN: FixTag = FixTag { id: "1403", datatype: value_types::LENGTH};
-pub const ENCRYPTEDNEWPASSWORD: FixTag = FixTag { id: "1404", datatype: value_types::DATA};
-pub const UNDERLYINGLEGMATURITYTIME: FixTag = FixTag { id: "1405", datatype: value_types::TZ_TIME_ONLY};
-pub const REFAPPLEXTID: FixTag = FixTag { id: "1406", datatype: value_types::INT};
-pub const DEFAULTAPPLEXTID: FixTag = FixTag { id: "1407", datatype: value_types::INT};
-pub const DEFAULTCSTMAPPLVERID: FixTag = FixTag { id: "1408", datatype: value_types::STRING};
-pub const SESSIONSTATUS: FixTag = FixTag { id: "1409", datatype: value_types::INT};
-pub const DEFAULTVERINDICATOR: FixTag = FixTag { id: "1410", datatype: value_types::BOOLEAN};
-pub const NESTED4PARTYSUBIDTYPE: FixTag = FixTag { id: "1411", datatype: value_types::INT};
-pub const NESTED4PARTYSUBID: FixTag = FixTag { id: "1412", datatype: value_types::STRING};
-pub const NONESTED4PARTYSUBIDS: FixTag = FixTag { id: "1413", datatype: value_types::NUM_IN_GROUP };
-pub const NONESTED4PARTYIDS: FixTag = FixTag { id: "1414", datatype: value_types::NUM_IN_GROUP };
-pub const NESTED4PARTYID: FixTag = FixTag { id: "1415", datatype: value_types::STRING};
-pub const NESTED4PARTYIDSOURCE: FixTag = FixTag { id: "1416", datatype: value_types::CHAR};
-pub const NESTED4PARTYROLE: FixTag = FixTag { id: "1417", datatype: value_types::INT};
-pub const LEGLASTQTY: FixTag = FixTag { id: "1418", datatype: value_types::QTY};
-pub const UNDERLYINGEXERCISESTYLE: FixTag = FixTag { id: "1419", datatype: value_types::INT};
-pub const LEGEXERCISESTYLE: FixTag = FixTag { id: "1420", datatype: value_types::INT};
-pub const LEGPRICEUNITOFMEASURE: FixTag = FixTag { id: "1421", datatype: value_types::STRING};
-pub const LEGPRICEUNITOFMEASUREQTY: FixTag = FixTag { id: "1422", datatype: value_types::QTY};
-pub const UNDERLYINGUNITOFMEASUREQTY: FixTag = FixTag { id: "1423", datatype: value_types::QTY};
-pub const UNDERLYINGPRICEUNITOFMEASURE: FixTag = FixTag { id: "1424", datatype: value_types::STRING};
-pub const UNDERLYINGPRICEUNITOFMEASUREQTY: FixTag = FixTag { id: "1425", datatype: value_types::QTY};
-pub const APPLREPORTTYPE: FixTag = FixTag { id: "1426", datatype: value_types::INT};
-pub const SIDEEXECID: FixTag = FixTag { id: "1427", datatype: value_types::STRING};
-pub const ORDERDELAY: FixTag = FixTag { id: "1428", datatype: value_types::INT};
-pub const ORDERDELAYUNIT: FixTag = FixTag { id: "1429", datatype: value_types::INT};
-pub const VENUETYPE: FixTag = FixTag { id: "1430", datatype: value_types::CHAR};
-pub const REFORDIDREASON: FixTag = FixTag { id: "1431", datatype: value_types::INT};
-pub const ORIGCUSTORDERCAPACITY: FixTag = FixTag { id: "1432", datatype: value_types::INT};
-pub const REFAPPLREQID: FixTag = FixTag { id: "1433", datatype: value_types::STRING};
-pub const MODELTYPE: FixTag = FixTag { id: "1434", datatype: value_types::INT};
-pub const CONTRACTMULTIPLIERUNIT: FixTag = FixTag { id: "1435", datatype: value_types::INT};
-pub const LEGCONTRACTMULTIPLIERUNIT: FixTag = FixTag { id: "1436", datatype: value_types::INT};
-pub const UNDERLYINGCONTRACTMULTIPLIERUNIT: FixTag = FixTag { id: "1437", datatype: value_types::INT};
-pub const DERIVATIVECONTRACTMULTIPLIERUNIT: FixTag = FixTag { id: "1438", datatype: value_types::INT};
-pub const FLOWSCHEDULETYPE: FixTag = FixTag { id: "1439", datatype: value_types::INT};
-pub const LEGFLOWSCHEDULETYPE: FixTag = FixTag { id: "1440", datatype: value_types::INT};
-pub const UNDERLYINGFLOWSCHEDULETYPE: FixTag = FixTag { id: "1441", datatype: value_types::INT};
-pub const DERIVATIVEFLOWSCHEDULETYPE: FixTag = FixTag { id: "1442", datatype: value_types::INT};
-pub const FILLLIQUIDITYIND: FixTag = FixTag { id: "1443", datatype: value_types::INT};
-pub const SIDELIQUIDITYIND: FixTag = FixTag { id: "1444", datatype: value_types::INT};
-pub const NORATESOURCES: FixTag = FixTag { id: "1445", datatype: value_types::NUM_IN_GROUP };
-pub const RATESOURCE: FixTag = FixTag { id: "1446", datatype: value_types::INT};
-pub const RATESOURCETYPE: FixTag = FixTag { id: "1447", datatype: value_types::INT};
-pub const REFERENCEPAGE: FixTag = FixTag { id: "1448", datatype: value_types::STRING};
-pub const RESTRUCTURINGTYPE: FixTag = FixTag { id: "1449", datatype: value_types::STRING};
-pub const SENIORITY: FixTag = FixTag { id: "1450", datatype: value_types::STRING};
-pub const NOTIONALPERCENTAGEOUTSTANDING: FixTag = FixTag { id: "1451", datatype: value_types::PERCENTAGE};
-pub const ORIGINALNOTIONALPERCENTAGEOUTSTANDING: FixTag = FixTag { id: "1452", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGRESTRUCTURINGTYPE: FixTag = FixTag { id: "1453", datatype: value_types::STRING};
-pub const UNDERLYINGSENIORITY: FixTag = FixTag { id: "1454", datatype: value_types::STRING};
-pub const UNDERLYINGNOTIONALPERCENTAGEOUTSTANDING: FixTag = FixTag { id: "1455", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGORIGINALNOTIONALPERCENTAGEOUTSTANDING: FixTag = FixTag { id: "1456", datatype: value_types::PERCENTAGE};
-pub const ATTACHMENTPOINT: FixTag = FixTag { id: "1457", datatype: value_types::PERCENTAGE};
-pub const DETACHMENTPOINT: FixTag = FixTag { id: "1458", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGATTACHMENTPOINT: FixTag = FixTag { id: "1459", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGDETACHMENTPOINT: FixTag = FixTag { id: "1460", datatype: value_types::PERCENTAGE};
-pub const NOTARGETPARTYIDS: FixTag = FixTag { id: "1461", datatype: value_types::NUM_IN_GROUP };
-pub const TARGETPARTYID: FixTag = FixTag { id: "1462", datatype: value_types::STRING};
-pub const TARGETPARTYIDSOURCE: FixTag = FixTag { id: "1463", datatype: value_types::CHAR};
-pub const TARGETPARTYROLE: FixTag = FixTag { id: "1464", datatype: value_types::INT};
-pub const SECURITYLISTID: FixTag = FixTag { id: "1465", datatype: value_types::STRING};
-pub const SECURITYLISTREFID: FixTag = FixTag { id: "1466", datatype: value_types::STRING};
-pub const SECURITYLISTDESC: FixTag = FixTag { id: "1467", datatype: value_types::STRING};
-pub const ENCODEDSECURITYLISTDESCLEN: FixTag = FixTag { id: "1468", datatype: value_types::LENGTH};
-pub const ENCODEDSECURITYLISTDESC: FixTag = FixTag { id: "1469", datatype: value_types::DATA};
-pub const SECURITYLISTTYPE: FixTag = FixTag { id: "1470", datatype: value_types::INT};
-pub const SECURITYLISTTYPESOURCE: FixTag = FixTag { id: "1471", datatype: value_types::INT};
-pub const NEWSID: FixTag = FixTag { id: "1472", datatype: value_types::STRING};
-pub const NEWSCATEGORY: FixTag = FixTag { id: "1473", datatype: value_types::INT};
-pub const LANGUAGECODE: FixTag = FixTag { id: "1474", datatype: value_types::LANGUAGE};
-pub const NONEWSREFIDS: FixTag = FixTag { id: "1475", datatype: value_types::NUM_IN_GROUP };
-pub const NEWSREFID: FixTag = FixTag { id: "1476", datatype: value_types::STRING};
-pub const NEWSREFTYPE: FixTag = FixTag { id: "1477", datatype: value_types::INT};
-pub const STRIKEPRICEDETERMINATIONMETHOD: FixTag = FixTag { id: "1478", datatype: value_types::INT};
-pub const STRIKEPRICEBOUNDARYMETHOD: FixTag = FixTag { id: "1479", datatype: value_types::INT};
-pub const STRIKEPRICEBOUNDARYPRECISION: FixTag = FixTag { id: "1480", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGPRICEDETERMINATIONMETHOD: FixTag = FixTag { id: "1481", datatype: value_types::INT};
-pub const OPTPAYOUTTYPE: FixTag = FixTag { id: "1482", datatype: value_types::INT};
-pub const NOCOMPLEXEVENTS: FixTag = FixTag { id: "1483", datatype: value_types::NUM_IN_GROUP };
-pub const COMPLEXEVENTTYPE: FixTag = FixTag { id: "1484", datatype: value_types::INT};
-pub const COMPLEXOPTPAYOUTAMOUNT: FixTag = FixTag { id: "1485", datatype: value_types::AMT};
-pub const COMPLEXEVENTPRICE: FixTag = FixTag { id: "1486", datatype: value_types::PRICE};
-pub const COMPLEXEVENTPRICEBOUNDARYMETHOD: FixTag = FixTag { id: "1487", datatype: value_types::INT};
-pub const COMPLEXEVENTPRICEBOUNDARYPRECISION: FixTag = FixTag { id: "1488", datatype: value_types::PERCENTAGE};
-pub const COMPLEXEVENTPRICETIMETYPE: FixTag = FixTag { id: "1489", datatype: value_types::INT};
-pub const COMPLEXEVENTCONDITION: FixTag = FixTag { id: "1490", datatype: value_types::INT};
-pub const NOCOMPLEXEVENTDATES: FixTag = FixTag { id: "1491", datatype: value_types::NUM_IN_GROUP };
-pub const COMPLEXEVENTSTARTDATE: FixTag = FixTag { id: "1492", datatype: value_types::UTC_DATE_ONLY};
-pub const COMPLEXEVENTENDDATE: FixTag = FixTag { id: "1493", datatype: value_types::UTC_DATE_ONLY};
-pub const NOCOMPLEXEVENTTIMES: FixTag = FixTag { id: "1494", datatype: value_types::NUM_IN_GROUP };
-pub const COMPLEXEVENTSTARTTIME: FixTag = FixTag { id: "1495", datatype: value_types::UTC_TIME_ONLY};
-pub const COMPLEXEVENTENDTIME: FixTag = FixTag { id: "1496", datatype: value_types::UTC_TIME_ONLY};
-pub const STREAMASGNREQID: FixTag = FixTag { id: "1497", datatype: value_types::STRING};
-pub const STREAMASGNREQTYPE: FixTag = FixTag { id: "1498", datatype: value_types::INT};
-pub const NOASGNREQS: FixTag = FixTag { id: "1499", datatype: value_types::NUM_IN_GROUP };
-pub const MDSTREAMID: FixTag = FixTag { id: "1500", datatype: value_types::STRING};
-pub const STREAMASGNRPTID: FixTag = FixTag { id: "1501", datatype: value_types::STRING};
-pub const STREAMASGNREJREASON: FixTag = FixTag { id: "1502", datatype: value_types::INT};
-pub const STREAMASGNACKTYPE: FixTag = FixTag { id: "1503", datatype: value_types::INT};
-pub const RELSYMTRANSACTTIME: FixTag = FixTag { id: "1504", datatype: value_types::UTC_TIMESTAMP};
-pub const PARTYDETAILSLISTREQUESTID: FixTag = FixTag { id: "1505", datatype: value_types::STRING};
-pub const SIDETRADEID: FixTag = FixTag { id: "1506", datatype: value_types::STRING};
-pub const SIDEORIGTRADEID: FixTag = FixTag { id: "1507", datatype: value_types::STRING};
-pub const NOREQUESTEDPARTYROLES: FixTag = FixTag { id: "1508", datatype: value_types::NUM_IN_GROUP };
-pub const REQUESTEDPARTYROLE: FixTag = FixTag { id: "1509", datatype: value_types::INT};
-pub const PARTYDETAILSLISTREPORTID: FixTag = FixTag { id: "1510", datatype: value_types::STRING};
-pub const REQUESTRESULT: FixTag = FixTag { id: "1511", datatype: value_types::INT};
-pub const TOTNOPARTIES: FixTag = FixTag { id: "1512", datatype: value_types::INT};
-pub const DOCUMENTATIONTEXT: FixTag = FixTag { id: "1513", datatype: value_types::STRING};
-pub const NOPARTYRELATIONSHIPS: FixTag = FixTag { id: "1514", datatype: value_types::NUM_IN_GROUP };
-pub const PARTYRELATIONSHIP: FixTag = FixTag { id: "1515", datatype: value_types::INT};
-pub const NOPARTYDETAILALTID: FixTag = FixTag { id: "1516", datatype: value_types::NUM_IN_GROUP };
-pub const PARTYDETAILALTID: FixTag = FixTag { id: "1517", datatype: value_types::STRING};
-pub const PARTYDETAILALTIDSOURCE: FixTag = FixTag { id: "1518", datatype: value_types::CHAR};
-pub const NOPARTYDETAILALTSUBIDS: FixTag = FixTag { id: "1519", datatype: value_types::NUM_IN_GROUP };
-pub const PARTYDETAILALTSUBID: FixTag = FixTag { id: "1520", datatype: value_types::STRING};
-pub const PARTYDETAILALTSUBIDTYPE: FixTag = FixTag { id: "1521", datatype: value_types::INT};
-pub const DIFFERENTIALPRICE: FixTag = FixTag { id: "1522", datatype: value_types::PRICE_OFFSET};
-pub const TRDACKSTATUS: FixTag = FixTag { id: "1523", datatype: value_types::INT};
-pub const PRICEQUOTECURRENCY: FixTag = FixTag { id: "1524", datatype: value_types::CURRENCY};
-pub const ENCODEDDOCUMENTATIONTEXTLEN: FixTag = FixTag { id: "1525", datatype: value_types::LENGTH};
-pub const UNDERLYINGPRICEQUOTECURRENCY: FixTag = FixTag { id: "1526", datatype: value_types::CURRENCY};
-pub const ENCODEDDOCUMENTATIONTEXT: FixTag = FixTag { id: "1527", datatype: value_types::DATA};
-pub const LEGPRICEQUOTECURRENCY: FixTag = FixTag { id: "1528", datatype: value_types::CURRENCY};
-pub const NORISKLIMITTYPES: FixTag = FixTag { id: "1529", datatype: value_types::NUM_IN_GROUP };
-pub const RISKLIMITTYPE: FixTag = FixTag { id: "1530", datatype: value_types::INT};
-pub const RISKLIMITAMOUNT: FixTag = FixTag { id: "1531", datatype: value_types::AMT};
-pub const RISKLIMITCURRENCY: FixTag = FixTag { id: "1532", datatype: value_types::CURRENCY};
-pub const RISKLIMITPLATFORM: FixTag = FixTag { id: "1533", datatype: value_types::STRING};
-pub const NORISKINSTRUMENTSCOPES: FixTag = FixTag { id: "1534", datatype: value_types::NUM_IN_GROUP };
-pub const INSTRUMENTSCOPEOPERATOR: FixTag = FixTag { id: "1535", datatype: value_types::INT};
-pub const INSTRUMENTSCOPESYMBOL: FixTag = FixTag { id: "1536", datatype: value_types::STRING};
-pub const INSTRUMENTSCOPESYMBOLSFX: FixTag = FixTag { id: "1537", datatype: value_types::STRING};
-pub const INSTRUMENTSCOPESECURITYID: FixTag = FixTag { id: "1538", datatype: value_types::STRING};
-pub const INSTRUMENTSCOPESECURITYIDSOURCE: FixTag = FixTag { id: "1539", datatype: value_types::STRING};
-pub const NOINSTRUMENTSCOPESECURITYALTID: FixTag = FixTag { id: "1540", datatype: value_types::NUM_IN_GROUP };
-pub const INSTRUMENTSCOPESECURITYALTID: FixTag = FixTag { id: "1541", datatype: value_types::STRING};
-pub const INSTRUMENTSCOPESECURITYALTIDSOURCE: FixTag = FixTag { id: "1542", datatype: value_types::STRING};
-pub const INSTRUMENTSCOPEPRODUCT: FixTag = FixTag { id: "1543", datatype: value_types::INT};
-pub const INSTRUMENTSCOPEPRODUCTCOMPLEX: FixTag = FixTag { id: "1544", datatype: value_types::STRING};
-pub const INSTRUMENTSCOPESECURITYGROUP: FixTag = FixTag { id: "1545", datatype: value_types::STRING};
-pub const INSTRUMENTSCOPECFICODE: FixTag = FixTag { id: "1546", datatype: value_types::STRING};
-pub const INSTRUMENTSCOPESECURITYTYPE: FixTag = FixTag { id: "1547", datatype: value_types::STRING};
-pub const INSTRUMENTSCOPESECURITYSUBTYPE: FixTag = FixTag { id: "1548", datatype: value_types::STRING};
-pub const INSTRUMENTSCOPEMATURITYMONTH_YEAR: FixTag = FixTag { id: "1549", datatype: value_types::MONTH_YEAR};
-pub const INSTRUMENTSCOPEMATURITYTIME: FixTag = FixTag { id: "1550", datatype: value_types::TZ_TIME_ONLY};
-pub const INSTRUMENTSCOPERESTRUCTURINGTYPE: FixTag = FixTag { id: "1551", datatype: value_types::STRING};
-pub const INSTRUMENTSCOPESENIORITY: FixTag = FixTag { id: "1552", datatype: value_types::STRING};
-pub const INSTRUMENTSCOPEPUTORCALL: FixTag = FixTag { id: "1553", datatype: value_types::INT};
-pub const INSTRUMENTSCOPEFLEXIBLEINDICATOR: FixTag = FixTag { id: "1554", datatype: value_types::BOOLEAN};
-pub const INSTRUMENTSCOPECOUPONRATE: FixTag = FixTag { id: "1555", datatype: value_types::PERCENTAGE};
-pub const INSTRUMENTSCOPESECURITYDESC: FixTag = FixTag { id: "1556", datatype: value_types::STRING};
-pub const INSTRUMENTSCOPESETTLTYPE: FixTag = FixTag { id: "1557", datatype: value_types::STRING};
-pub const RISKINSTRUMENTMULTIPLIER: FixTag = FixTag { id: "1558", datatype: value_types::FLOAT};
-pub const NORISKWARNINGLEVELS: FixTag = FixTag { id: "1559", datatype: value_types::NUM_IN_GROUP };
-pub const RISKWARNINGLEVELPERCENT: FixTag = FixTag { id: "1560", datatype: value_types::PERCENTAGE};
-pub const RISKWARNINGLEVELNAME: FixTag = FixTag { id: "1561", datatype: value_types::STRING};
-pub const NORELATEDPARTYDETAILID: FixTag = FixTag { id: "1562", datatype: value_types::NUM_IN_GROUP };
-pub const RELATEDPARTYDETAILID: FixTag = FixTag { id: "1563", datatype: value_types::STRING};
-pub const RELATEDPARTYDETAILIDSOURCE: FixTag = FixTag { id: "1564", datatype: value_types::CHAR};
-pub const RELATEDPARTYDETAILROLE: FixTag = FixTag { id: "1565", datatype: value_types::INT};
-pub const NORELATEDPARTYDETAILSUBIDS: FixTag = FixTag { id: "1566", datatype: value_types::NUM_IN_GROUP };
-pub const RELATEDPARTYDETAILSUBID: FixTag = FixTag { id: "1567", datatype: value_types::STRING};
-pub const RELATEDPARTYDETAILSUBIDTYPE: FixTag = FixTag { id: "1568", datatype: value_types::INT};
-pub const NORELATEDPARTYDETAILALTID: FixTag = FixTag { id: "1569", datatype: value_types::NUM_IN_GROUP };
-pub const RELATEDPARTYDETAILALTID: FixTag = FixTag { id: "1570", datatype: value_types::STRING};
-pub const RELATEDPARTYDETAILALTIDSOURCE: FixTag = FixTag { id: "1571", datatype: value_types::CHAR};
-pub const NORELATEDPARTYDETAILALTSUBIDS: FixTag = FixTag { id: "1572", datatype: value_types::NUM_IN_GROUP };
-pub const RELATEDPARTYDETAILALTSUBID: FixTag = FixTag { id: "1573", datatype: value_types::STRING};
-pub const RELATEDPARTYDETAILALTSUBIDTYPE: FixTag = FixTag { id: "1574", datatype: value_types::INT};
-pub const SWAPSUBCLASS: FixTag = FixTag { id: "1575", datatype: value_types::STRING};
-pub const DERIVATIVEPRICEQUOTECURRENCY: FixTag = FixTag { id: "1576", datatype: value_types::CURRENCY};
-pub const SETTLRATEINDEX: FixTag = FixTag { id: "1577", datatype: value_types::STRING};
-pub const ENCODEDEVENTTEXTLEN: FixTag = FixTag { id: "1578", datatype: value_types::LENGTH};
-pub const ENCODEDEVENTTEXT: FixTag = FixTag { id: "1579", datatype: value_types::DATA};
-pub const SETTLRATEINDEXLOCATION: FixTag = FixTag { id: "1580", datatype: value_types::STRING};
-pub const OPTIONEXPIRATIONDESC: FixTag = FixTag { id: "1581", datatype: value_types::STRING};
-pub const NOSECURITYCLASSIFICATIONS: FixTag = FixTag { id: "1582", datatype: value_types::NUM_IN_GROUP };
-pub const SECURITYCLASSIFICATIONREASON: FixTag = FixTag { id: "1583", datatype: value_types::INT};
-pub const SECURITYCLASSIFICATIONVALUE: FixTag = FixTag { id: "1584", datatype: value_types::STRING};
-pub const POSAMTREASON: FixTag = FixTag { id: "1585", datatype: value_types::INT};
-pub const NOLEGPOSAMT: FixTag = FixTag { id: "1586", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPOSAMT: FixTag = FixTag { id: "1587", datatype: value_types::AMT};
-pub const LEGPOSAMTTYPE: FixTag = FixTag { id: "1588", datatype: value_types::STRING};
-pub const LEGPOSCURRENCY: FixTag = FixTag { id: "1589", datatype: value_types::CURRENCY};
-pub const LEGPOSAMTREASON: FixTag = FixTag { id: "1590", datatype: value_types::INT};
-pub const LEGQTYTYPE: FixTag = FixTag { id: "1591", datatype: value_types::INT};
-pub const DISCOUNTFACTOR: FixTag = FixTag { id: "1592", datatype: value_types::FLOAT};
-pub const PARENTALLOCID: FixTag = FixTag { id: "1593", datatype: value_types::STRING};
-pub const LEGSECURITYGROUP: FixTag = FixTag { id: "1594", datatype: value_types::STRING};
-pub const POSITIONCONTINGENTPRICE: FixTag = FixTag { id: "1595", datatype: value_types::PRICE};
-pub const CLEARINGTRADEPRICE: FixTag = FixTag { id: "1596", datatype: value_types::PRICE};
-pub const SIDECLEARINGTRADEPRICE: FixTag = FixTag { id: "1597", datatype: value_types::PRICE};
-pub const SIDECLEARINGTRADEPRICETYPE: FixTag = FixTag { id: "1598", datatype: value_types::INT};
-pub const SIDEPRICEDIFFERENTIAL: FixTag = FixTag { id: "1599", datatype: value_types::PRICE};
-pub const FIXENGINENAME: FixTag = FixTag { id: "1600", datatype: value_types::STRING};
-pub const FIXENGINEVERSION: FixTag = FixTag { id: "1601", datatype: value_types::STRING};
-pub const FIXENGINEVENDOR: FixTag = FixTag { id: "1602", datatype: value_types::STRING};
-pub const APPLICATIONSYSTEMNAME: FixTag = FixTag { id: "1603", datatype: value_types::STRING};
-pub const APPLICATIONSYSTEMVERSION: FixTag = FixTag { id: "1604", datatype: value_types::STRING};
-pub const APPLICATIONSYSTEMVENDOR: FixTag = FixTag { id: "1605", datatype: value_types::STRING};
-pub const NUMOFSIMPLEINSTRUMENTS: FixTag = FixTag { id: "1606", datatype: value_types::INT};
-pub const SECURITYREJECTREASON: FixTag = FixTag { id: "1607", datatype: value_types::INT};
-pub const INITIALDISPLAYQTY: FixTag = FixTag { id: "1608", datatype: value_types::QTY};
-pub const THROTTLESTATUS: FixTag = FixTag { id: "1609", datatype: value_types::INT};
-pub const NOTHROTTLES: FixTag = FixTag { id: "1610", datatype: value_types::NUM_IN_GROUP };
-pub const THROTTLEACTION: FixTag = FixTag { id: "1611", datatype: value_types::INT};
-pub const THROTTLETYPE: FixTag = FixTag { id: "1612", datatype: value_types::INT};
-pub const THROTTLENOMSGS: FixTag = FixTag { id: "1613", datatype: value_types::INT};
-pub const THROTTLETIMEINTERVAL: FixTag = FixTag { id: "1614", datatype: value_types::INT};
-pub const THROTTLETIMEUNIT: FixTag = FixTag { id: "1615", datatype: value_types::INT};
-pub const INSTRUMENTSCOPESECURITYEXCHANGE: FixTag = FixTag { id: "1616", datatype: value_types::EXCHANGE};
-pub const STREAMASGNTYPE: FixTag = FixTag { id: "1617", datatype: value_types::INT};
-pub const NOTHROTTLEMSGTYPE: FixTag = FixTag { id: "1618", datatype: value_types::NUM_IN_GROUP };
-pub const THROTTLEMSGTYPE: FixTag = FixTag { id: "1619", datatype: value_types::STRING};
-pub const INSTRUMENTSCOPEENCODEDSECURITYDESCLEN: FixTag = FixTag { id: "1620", datatype: value_types::LENGTH};
-pub const INSTRUMENTSCOPEENCODEDSECURITYDESC: FixTag = FixTag { id: "1621", datatype: value_types::DATA};
-pub const FILLYIELDTYPE: FixTag = FixTag { id: "1622", datatype: value_types::STRING};
-pub const FILLYIELD: FixTag = FixTag { id: "1623", datatype: value_types::PERCENTAGE};
-pub const NOMATCHINST: FixTag = FixTag { id: "1624", datatype: value_types::NUM_IN_GROUP };
-pub const MATCHINST: FixTag = FixTag { id: "1625", datatype: value_types::INT};
-pub const MATCHATTRIBTAGID: FixTag = FixTag { id: "1626", datatype: value_types::TAGNUM};
-pub const MATCHATTRIBVALUE: FixTag = FixTag { id: "1627", datatype: value_types::STRING};
-pub const TRIGGERSCOPE: FixTag = FixTag { id: "1628", datatype: value_types::INT};
-pub const EXPOSUREDURATION: FixTag = FixTag { id: "1629", datatype: value_types::INT};
-pub const NOLIMITAMTS: FixTag = FixTag { id: "1630", datatype: value_types::NUM_IN_GROUP };
-pub const LIMITAMTTYPE: FixTag = FixTag { id: "1631", datatype: value_types::INT};
-pub const LASTLIMITAMT: FixTag = FixTag { id: "1632", datatype: value_types::AMT};
-pub const LIMITAMTREMAINING: FixTag = FixTag { id: "1633", datatype: value_types::AMT};
-pub const LIMITAMTCURRENCY: FixTag = FixTag { id: "1634", datatype: value_types::CURRENCY};
-pub const MARGINREQMTINQID: FixTag = FixTag { id: "1635", datatype: value_types::STRING};
-pub const NOMARGINREQMTINQQUALIFIER: FixTag = FixTag { id: "1636", datatype: value_types::NUM_IN_GROUP };
-pub const MARGINREQMTINQQUALIFIER: FixTag = FixTag { id: "1637", datatype: value_types::INT};
-pub const MARGINREQMTRPTTYPE: FixTag = FixTag { id: "1638", datatype: value_types::INT};
-pub const MARGINCLASS: FixTag = FixTag { id: "1639", datatype: value_types::STRING};
-pub const MARGINREQMTINQSTATUS: FixTag = FixTag { id: "1640", datatype: value_types::INT};
-pub const MARGINREQMTINQRESULT: FixTag = FixTag { id: "1641", datatype: value_types::INT};
-pub const MARGINREQMTRPTID: FixTag = FixTag { id: "1642", datatype: value_types::STRING};
-pub const NOMARGINAMT: FixTag = FixTag { id: "1643", datatype: value_types::NUM_IN_GROUP };
-pub const MARGINAMTTYPE: FixTag = FixTag { id: "1644", datatype: value_types::INT};
-pub const MARGINAMT: FixTag = FixTag { id: "1645", datatype: value_types::AMT};
-pub const MARGINAMTCCY: FixTag = FixTag { id: "1646", datatype: value_types::CURRENCY};
-pub const NORELATEDINSTRUMENTS: FixTag = FixTag { id: "1647", datatype: value_types::NUM_IN_GROUP };
-pub const RELATEDINSTRUMENTTYPE: FixTag = FixTag { id: "1648", datatype: value_types::INT};
-pub const RELATEDSYMBOL: FixTag = FixTag { id: "1649", datatype: value_types::STRING};
-pub const RELATEDSECURITYID: FixTag = FixTag { id: "1650", datatype: value_types::STRING};
-pub const RELATEDSECURITYIDSOURCE: FixTag = FixTag { id: "1651", datatype: value_types::STRING};
-pub const RELATEDSECURITYTYPE: FixTag = FixTag { id: "1652", datatype: value_types::STRING};
-pub const RELATEDMATURITYMONTH_YEAR: FixTag = FixTag { id: "1653", datatype: value_types::MONTH_YEAR};
-pub const COVEREDQTY: FixTag = FixTag { id: "1654", datatype: value_types::QTY};
-pub const MARKETMAKERACTIVITY: FixTag = FixTag { id: "1655", datatype: value_types::INT};
-pub const NOINSTRUMENTSCOPES: FixTag = FixTag { id: "1656", datatype: value_types::NUM_IN_GROUP };
-pub const NOREQUESTINGPARTYIDS: FixTag = FixTag { id: "1657", datatype: value_types::NUM_IN_GROUP };
-pub const REQUESTINGPARTYID: FixTag = FixTag { id: "1658", datatype: value_types::STRING};
-pub const REQUESTINGPARTYIDSOURCE: FixTag = FixTag { id: "1659", datatype: value_types::CHAR};
-pub const REQUESTINGPARTYROLE: FixTag = FixTag { id: "1660", datatype: value_types::INT};
-pub const NOREQUESTINGPARTYSUBIDS: FixTag = FixTag { id: "1661", datatype: value_types::NUM_IN_GROUP };
-pub const REQUESTINGPARTYSUBID: FixTag = FixTag { id: "1662", datatype: value_types::STRING};
-pub const REQUESTINGPARTYSUBIDTYPE: FixTag = FixTag { id: "1663", datatype: value_types::INT};
-pub const ENCODEDREJECTTEXTLEN: FixTag = FixTag { id: "1664", datatype: value_types::LENGTH};
-pub const ENCODEDREJECTTEXT: FixTag = FixTag { id: "1665", datatype: value_types::DATA};
-pub const RISKLIMITREQUESTID: FixTag = FixTag { id: "1666", datatype: value_types::STRING};
-pub const RISKLIMITREPORTID: FixTag = FixTag { id: "1667", datatype: value_types::STRING};
-pub const NOREQUESTEDRISKLIMITTYPE: FixTag = FixTag { id: "1668", datatype: value_types::NUM_IN_GROUP };
-pub const NORISKLIMITS: FixTag = FixTag { id: "1669", datatype: value_types::NUM_IN_GROUP };
-pub const RISKLIMITID: FixTag = FixTag { id: "1670", datatype: value_types::STRING};
-pub const NOPARTYDETAILS: FixTag = FixTag { id: "1671", datatype: value_types::NUM_IN_GROUP };
-pub const PARTYDETAILSTATUS: FixTag = FixTag { id: "1672", datatype: value_types::INT};
-pub const MATCHINSTMARKETID: FixTag = FixTag { id: "1673", datatype: value_types::EXCHANGE};
-pub const PARTYDETAILROLEQUALIFIER: FixTag = FixTag { id: "1674", datatype: value_types::INT};
-pub const RELATEDPARTYDETAILROLEQUALIFIER: FixTag = FixTag { id: "1675", datatype: value_types::INT};
-pub const NOPARTYUPDATES: FixTag = FixTag { id: "1676", datatype: value_types::NUM_IN_GROUP };
-pub const NOPARTYRISKLIMITS: FixTag = FixTag { id: "1677", datatype: value_types::NUM_IN_GROUP };
-pub const ENCODEDOPTIONEXPIRATIONDESCLEN: FixTag = FixTag { id: "1678", datatype: value_types::LENGTH};
-pub const SECURITYMASSTRADINGSTATUS: FixTag = FixTag { id: "1679", datatype: value_types::INT};
-pub const SECURITYMASSTRADINGEVENT: FixTag = FixTag { id: "1680", datatype: value_types::INT};
-pub const MASSHALTREASON: FixTag = FixTag { id: "1681", datatype: value_types::INT};
-pub const MDSECURITYTRADINGSTATUS: FixTag = FixTag { id: "1682", datatype: value_types::INT};
-pub const MDSUBFEEDTYPE: FixTag = FixTag { id: "1683", datatype: value_types::STRING};
-pub const MDHALTREASON: FixTag = FixTag { id: "1684", datatype: value_types::INT};
-pub const THROTTLEINST: FixTag = FixTag { id: "1685", datatype: value_types::INT};
-pub const THROTTLECOUNTINDICATOR: FixTag = FixTag { id: "1686", datatype: value_types::INT};
-pub const SHORTSALERESTRICTION: FixTag = FixTag { id: "1687", datatype: value_types::INT};
-pub const SHORTSALEEXEMPTIONREASON: FixTag = FixTag { id: "1688", datatype: value_types::INT};
-pub const LEGSHORTSALEEXEMPTIONREASON: FixTag = FixTag { id: "1689", datatype: value_types::INT};
-pub const SIDESHORTSALEEXEMPTIONREASON: FixTag = FixTag { id: "1690", datatype: value_types::INT};
-pub const PARTYDETAILID: FixTag = FixTag { id: "1691", datatype: value_types::STRING};
-pub const PARTYDETAILIDSOURCE: FixTag = FixTag { id: "1692", datatype: value_types::CHAR};
-pub const PARTYDETAILROLE: FixTag = FixTag { id: "1693", datatype: value_types::INT};
-pub const NOPARTYDETAILSUBIDS: FixTag = FixTag { id: "1694", datatype: value_types::NUM_IN_GROUP };
-pub const PARTYDETAILSUBID: FixTag = FixTag { id: "1695", datatype: value_types::STRING};
-pub const PARTYDETAILSUBIDTYPE: FixTag = FixTag { id: "1696", datatype: value_types::INT};
-pub const ENCODEDOPTIONEXPIRATIONDESC: FixTag = FixTag { id: "1697", datatype: value_types::DATA};
-pub const STRIKEUNITOFMEASURE: FixTag = FixTag { id: "1698", datatype: value_types::STRING};
-pub const ACCOUNTSUMMARYREPORTID: FixTag = FixTag { id: "1699", datatype: value_types::STRING};
-pub const NOSETTLEMENTAMOUNTS: FixTag = FixTag { id: "1700", datatype: value_types::NUM_IN_GROUP };
-pub const SETTLEMENTAMOUNT: FixTag = FixTag { id: "1701", datatype: value_types::AMT};
-pub const SETTLEMENTAMOUNTCURRENCY: FixTag = FixTag { id: "1702", datatype: value_types::CURRENCY};
-pub const NOCOLLATERALAMOUNTS: FixTag = FixTag { id: "1703", datatype: value_types::NUM_IN_GROUP };
-pub const CURRENTCOLLATERALAMOUNT: FixTag = FixTag { id: "1704", datatype: value_types::AMT};
-pub const COLLATERALCURRENCY: FixTag = FixTag { id: "1705", datatype: value_types::CURRENCY};
-pub const COLLATERALTYPE: FixTag = FixTag { id: "1706", datatype: value_types::STRING};
-pub const NOPAYCOLLECTS: FixTag = FixTag { id: "1707", datatype: value_types::NUM_IN_GROUP };
-pub const PAYCOLLECTTYPE: FixTag = FixTag { id: "1708", datatype: value_types::STRING};
-pub const PAYCOLLECTCURRENCY: FixTag = FixTag { id: "1709", datatype: value_types::CURRENCY};
-pub const PAYAMOUNT: FixTag = FixTag { id: "1710", datatype: value_types::AMT};
-pub const COLLECTAMOUNT: FixTag = FixTag { id: "1711", datatype: value_types::AMT};
-pub const PAYCOLLECTMARKETSEGMENTID: FixTag = FixTag { id: "1712", datatype: value_types::STRING};
-pub const PAYCOLLECTMARKETID: FixTag = FixTag { id: "1713", datatype: value_types::STRING};
-pub const MARGINAMOUNTMARKETSEGMENTID: FixTag = FixTag { id: "1714", datatype: value_types::STRING};
-pub const MARGINAMOUNTMARKETID: FixTag = FixTag { id: "1715", datatype: value_types::STRING};
-pub const UNITOFMEASURECURRENCY: FixTag = FixTag { id: "1716", datatype: value_types::CURRENCY};
-pub const PRICEUNITOFMEASURECURRENCY: FixTag = FixTag { id: "1717", datatype: value_types::CURRENCY};
-pub const UNDERLYINGUNITOFMEASURECURRENCY: FixTag = FixTag { id: "1718", datatype: value_types::CURRENCY};
-pub const UNDERLYINGPRICEUNITOFMEASURECURRENCY: FixTag = FixTag { id: "1719", datatype: value_types::CURRENCY};
-pub const LEGUNITOFMEASURECURRENCY: FixTag = FixTag { id: "1720", datatype: value_types::CURRENCY};
-pub const LEGPRICEUNITOFMEASURECURRENCY: FixTag = FixTag { id: "1721", datatype: value_types::CURRENCY};
-pub const DERIVATIVEUNITOFMEASURECURRENCY: FixTag = FixTag { id: "1722", datatype: value_types::CURRENCY};
-pub const DERIVATIVEPRICEUNITOFMEASURECURRENCY: FixTag = FixTag { id: "1723", datatype: value_types::CURRENCY};
-pub const ORDERORIGINATION: FixTag = FixTag { id: "1724", datatype: value_types::INT};
-pub const ORIGINATINGDEPTID: FixTag = FixTag { id: "1725", datatype: value_types::STRING};
-pub const RECEIVINGDEPTID: FixTag = FixTag { id: "1726", datatype: value_types::STRING};
-pub const INFORMATIONBARRIERID: FixTag = FixTag { id: "1727", datatype: value_types::STRING};
-pub const FIRMGROUPID: FixTag = FixTag { id: "1728", datatype: value_types::STRING};
-pub const FIRMMNEMONIC: FixTag = FixTag { id: "1729", datatype: value_types::STRING};
-pub const ALLOCGROUPID: FixTag = FixTag { id: "1730", datatype: value_types::STRING};
-pub const AVGPXGROUPID: FixTag = FixTag { id: "1731", datatype: value_types::STRING};
-pub const FIRMALLOCTEXT: FixTag = FixTag { id: "1732", datatype: value_types::STRING};
-pub const ENCODEDFIRMALLOCTEXTLEN: FixTag = FixTag { id: "1733", datatype: value_types::LENGTH};
-pub const ENCODEDFIRMALLOCTEXT: FixTag = FixTag { id: "1734", datatype: value_types::DATA};
-pub const ALLOCATIONROLLUPINSTRUCTION: FixTag = FixTag { id: "1735", datatype: value_types::INT};
-pub const ALLOCGROUPQUANTITY: FixTag = FixTag { id: "1736", datatype: value_types::QTY};
-pub const ALLOCGROUPREMAININGQUANTITY: FixTag = FixTag { id: "1737", datatype: value_types::QTY};
-pub const ALLOCREVERSALSTATUS: FixTag = FixTag { id: "1738", datatype: value_types::INT};
-pub const OBLIGATIONTYPE: FixTag = FixTag { id: "1739", datatype: value_types::STRING};
-pub const TRADEPRICENEGOTIATIONMETHOD: FixTag = FixTag { id: "1740", datatype: value_types::INT};
-pub const UPFRONTPRICETYPE: FixTag = FixTag { id: "1741", datatype: value_types::INT};
-pub const UPFRONTPRICE: FixTag = FixTag { id: "1742", datatype: value_types::PRICE};
-pub const LASTUPFRONTPRICE: FixTag = FixTag { id: "1743", datatype: value_types::PRICE};
-pub const APPLLEVELRECOVERYINDICATOR: FixTag = FixTag { id: "1744", datatype: value_types::INT};
-pub const BIDMDENTRYID: FixTag = FixTag { id: "1745", datatype: value_types::STRING};
-pub const OFFERMDENTRYID: FixTag = FixTag { id: "1746", datatype: value_types::STRING};
-pub const BIDQUOTEID: FixTag = FixTag { id: "1747", datatype: value_types::STRING};
-pub const OFFERQUOTEID: FixTag = FixTag { id: "1748", datatype: value_types::STRING};
-pub const TOTALBIDSIZE: FixTag = FixTag { id: "1749", datatype: value_types::QTY};
-pub const TOTALOFFERSIZE: FixTag = FixTag { id: "1750", datatype: value_types::QTY};
-pub const SECONDARYQUOTEID: FixTag = FixTag { id: "1751", datatype: value_types::STRING};
-pub const CUSTODIALLOTID: FixTag = FixTag { id: "1752", datatype: value_types::STRING};
-pub const VERSUSPURCHASEDATE: FixTag = FixTag { id: "1753", datatype: value_types::LOCAL_MKT_DATE};
-pub const VERSUSPURCHASEPRICE: FixTag = FixTag { id: "1754", datatype: value_types::PRICE};
-pub const CURRENTCOSTBASIS: FixTag = FixTag { id: "1755", datatype: value_types::AMT};
-pub const LEGCUSTODIALLOTID: FixTag = FixTag { id: "1756", datatype: value_types::STRING};
-pub const LEGVERSUSPURCHASEDATE: FixTag = FixTag { id: "1757", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGVERSUSPURCHASEPRICE: FixTag = FixTag { id: "1758", datatype: value_types::PRICE};
-pub const LEGCURRENTCOSTBASIS: FixTag = FixTag { id: "1759", datatype: value_types::AMT};
-pub const RISKLIMITREQUESTTYPE: FixTag = FixTag { id: "1760", datatype: value_types::INT};
-pub const RISKLIMITREQUESTRESULT: FixTag = FixTag { id: "1761", datatype: value_types::INT};
-pub const RISKLIMITREQUESTSTATUS: FixTag = FixTag { id: "1762", datatype: value_types::INT};
-pub const RISKLIMITSTATUS: FixTag = FixTag { id: "1763", datatype: value_types::INT};
-pub const RISKLIMITRESULT: FixTag = FixTag { id: "1764", datatype: value_types::INT};
-pub const RISKLIMITUTILIZATIONPERCENT: FixTag = FixTag { id: "1765", datatype: value_types::PERCENTAGE};
-pub const RISKLIMITUTILIZATIONAMOUNT: FixTag = FixTag { id: "1766", datatype: value_types::AMT};
-pub const RISKLIMITACTION: FixTag = FixTag { id: "1767", datatype: value_types::INT};
-pub const RISKWARNINGLEVELAMOUNT: FixTag = FixTag { id: "1768", datatype: value_types::INT};
-pub const RISKWARNINGLEVELACTION: FixTag = FixTag { id: "1769", datatype: value_types::INT};
-pub const ENTITLEMENTREQUESTID: FixTag = FixTag { id: "1770", datatype: value_types::STRING};
-pub const ENTITLEMENTREPORTID: FixTag = FixTag { id: "1771", datatype: value_types::STRING};
-pub const NOPARTYENTITLEMENTS: FixTag = FixTag { id: "1772", datatype: value_types::NUM_IN_GROUP };
-pub const NOENTITLEMENTS: FixTag = FixTag { id: "1773", datatype: value_types::NUM_IN_GROUP };
-pub const ENTITLEMENTINDICATOR: FixTag = FixTag { id: "1774", datatype: value_types::BOOLEAN};
-pub const ENTITLEMENTTYPE: FixTag = FixTag { id: "1775", datatype: value_types::INT};
-pub const ENTITLEMENTID: FixTag = FixTag { id: "1776", datatype: value_types::STRING};
-pub const NOENTITLEMENTATTRIB: FixTag = FixTag { id: "1777", datatype: value_types::NUM_IN_GROUP };
-pub const ENTITLEMENTATTRIBTYPE: FixTag = FixTag { id: "1778", datatype: value_types::INT};
-pub const ENTITLEMENTATTRIBDATATYPE: FixTag = FixTag { id: "1779", datatype: value_types::INT};
-pub const ENTITLEMENTATTRIBVALUE: FixTag = FixTag { id: "1780", datatype: value_types::STRING};
-pub const ENTITLEMENTATTRIBCURRENCY: FixTag = FixTag { id: "1781", datatype: value_types::CURRENCY};
-pub const ENTITLEMENTSTARTDATE: FixTag = FixTag { id: "1782", datatype: value_types::LOCAL_MKT_DATE};
-pub const ENTITLEMENTENDDATE: FixTag = FixTag { id: "1783", datatype: value_types::LOCAL_MKT_DATE};
-pub const ENTITLEMENTPLATFORM: FixTag = FixTag { id: "1784", datatype: value_types::STRING};
-pub const TRADSESCONTROL: FixTag = FixTag { id: "1785", datatype: value_types::INT};
-pub const TRADEVOLTYPE: FixTag = FixTag { id: "1786", datatype: value_types::INT};
-pub const REFTICKTABLEID: FixTag = FixTag { id: "1787", datatype: value_types::INT};
-pub const LEGID: FixTag = FixTag { id: "1788", datatype: value_types::STRING};
-pub const NOTARGETMARKETSEGMENTS: FixTag = FixTag { id: "1789", datatype: value_types::NUM_IN_GROUP };
-pub const TARGETMARKETSEGMENTID: FixTag = FixTag { id: "1790", datatype: value_types::STRING};
-pub const NOAFFECTEDMARKETSEGMENTS: FixTag = FixTag { id: "1791", datatype: value_types::NUM_IN_GROUP };
-pub const AFFECTEDMARKETSEGMENTID: FixTag = FixTag { id: "1792", datatype: value_types::STRING};
-pub const NONOTAFFECTEDMARKETSEGMENTS: FixTag = FixTag { id: "1793", datatype: value_types::NUM_IN_GROUP };
-pub const NOTAFFECTEDMARKETSEGMENTID: FixTag = FixTag { id: "1794", datatype: value_types::STRING};
-pub const NOORDEREVENTS: FixTag = FixTag { id: "1795", datatype: value_types::NUM_IN_GROUP };
-pub const ORDEREVENTTYPE: FixTag = FixTag { id: "1796", datatype: value_types::INT};
-pub const ORDEREVENTEXECID: FixTag = FixTag { id: "1797", datatype: value_types::STRING};
-pub const ORDEREVENTREASON: FixTag = FixTag { id: "1798", datatype: value_types::INT};
-pub const ORDEREVENTPX: FixTag = FixTag { id: "1799", datatype: value_types::PRICE};
-pub const ORDEREVENTQTY: FixTag = FixTag { id: "1800", datatype: value_types::QTY};
-pub const ORDEREVENTLIQUIDITYINDICATOR: FixTag = FixTag { id: "1801", datatype: value_types::INT};
-pub const ORDEREVENTTEXT: FixTag = FixTag { id: "1802", datatype: value_types::STRING};
-pub const AUCTIONTYPE: FixTag = FixTag { id: "1803", datatype: value_types::INT};
-pub const AUCTIONALLOCATIONPCT: FixTag = FixTag { id: "1804", datatype: value_types::PERCENTAGE};
-pub const AUCTIONINSTRUCTION: FixTag = FixTag { id: "1805", datatype: value_types::INT};
-pub const REFCLORDID: FixTag = FixTag { id: "1806", datatype: value_types::STRING};
-pub const LOCKTYPE: FixTag = FixTag { id: "1807", datatype: value_types::INT};
-pub const LOCKEDQTY: FixTag = FixTag { id: "1808", datatype: value_types::QTY};
-pub const SECONDARYLOCKEDQTY: FixTag = FixTag { id: "1809", datatype: value_types::QTY};
-pub const RELEASEINSTRUCTION: FixTag = FixTag { id: "1810", datatype: value_types::INT};
-pub const RELEASEQTY: FixTag = FixTag { id: "1811", datatype: value_types::QTY};
-pub const NODISCLOSUREINSTRUCTIONS: FixTag = FixTag { id: "1812", datatype: value_types::NUM_IN_GROUP };
-pub const DISCLOSURETYPE: FixTag = FixTag { id: "1813", datatype: value_types::INT};
-pub const DISCLOSUREINSTRUCTION: FixTag = FixTag { id: "1814", datatype: value_types::INT};
-pub const TRADINGCAPACITY: FixTag = FixTag { id: "1815", datatype: value_types::INT};
-pub const CLEARINGACCOUNTTYPE: FixTag = FixTag { id: "1816", datatype: value_types::INT};
-pub const LEGCLEARINGACCOUNTTYPE: FixTag = FixTag { id: "1817", datatype: value_types::INT};
-pub const TARGETPARTYROLEQUALIFIER: FixTag = FixTag { id: "1818", datatype: value_types::INT};
-pub const RELATEDHIGHPRICE: FixTag = FixTag { id: "1819", datatype: value_types::PRICE};
-pub const RELATEDLOWPRICE: FixTag = FixTag { id: "1820", datatype: value_types::PRICE};
-pub const RELATEDPRICESOURCE: FixTag = FixTag { id: "1821", datatype: value_types::INT};
-pub const MINQTYMETHOD: FixTag = FixTag { id: "1822", datatype: value_types::INT};
-pub const TRIGGERED: FixTag = FixTag { id: "1823", datatype: value_types::INT};
-pub const AFFECTEDORIGCLORDID: FixTag = FixTag { id: "1824", datatype: value_types::STRING};
-pub const NOTAFFSECONDARYORDERID: FixTag = FixTag { id: "1825", datatype: value_types::STRING};
-pub const EVENTTIMEPERIOD: FixTag = FixTag { id: "1826", datatype: value_types::INT};
-pub const EVENTTIMEUNIT: FixTag = FixTag { id: "1827", datatype: value_types::STRING};
-pub const LASTQTYVARIANCE: FixTag = FixTag { id: "1828", datatype: value_types::QTY};
-pub const NOCROSSLEGS: FixTag = FixTag { id: "1829", datatype: value_types::NUM_IN_GROUP };
-pub const SETTLPRICEINCREMENT: FixTag = FixTag { id: "1830", datatype: value_types::PRICE};
-pub const SETTLPRICESECONDARYINCREMENT: FixTag = FixTag { id: "1831", datatype: value_types::PRICE};
-pub const CLEAREDINDICATOR: FixTag = FixTag { id: "1832", datatype: value_types::INT};
-pub const CONTRACTREFPOSTYPE: FixTag = FixTag { id: "1833", datatype: value_types::INT};
-pub const POSITIONCAPACITY: FixTag = FixTag { id: "1834", datatype: value_types::INT};
-pub const POSQTYUNITOFMEASURECURRENCY: FixTag = FixTag { id: "1835", datatype: value_types::CURRENCY};
-pub const POSQTYUNITOFMEASURE: FixTag = FixTag { id: "1836", datatype: value_types::STRING};
-pub const UNDERLYINGCONTRACTPRICEREFMONTH: FixTag = FixTag { id: "1837", datatype: value_types::MONTH_YEAR};
-pub const NOTRADEPRICECONDITIONS: FixTag = FixTag { id: "1838", datatype: value_types::NUM_IN_GROUP };
-pub const TRADEPRICECONDITION: FixTag = FixTag { id: "1839", datatype: value_types::INT};
-pub const TRADEALLOCSTATUS: FixTag = FixTag { id: "1840", datatype: value_types::INT};
-pub const NOTRADEQTYS: FixTag = FixTag { id: "1841", datatype: value_types::NUM_IN_GROUP };
-pub const TRADEQTYTYPE: FixTag = FixTag { id: "1842", datatype: value_types::INT};
-pub const TRADEQTY: FixTag = FixTag { id: "1843", datatype: value_types::QTY};
-pub const NOTRADEALLOCAMTS: FixTag = FixTag { id: "1844", datatype: value_types::NUM_IN_GROUP };
-pub const TRADEALLOCAMTTYPE: FixTag = FixTag { id: "1845", datatype: value_types::STRING};
-pub const TRADEALLOCAMT: FixTag = FixTag { id: "1846", datatype: value_types::AMT};
-pub const TRADEALLOCCURRENCY: FixTag = FixTag { id: "1847", datatype: value_types::CURRENCY};
-pub const TRADEALLOCGROUPINSTRUCTION: FixTag = FixTag { id: "1848", datatype: value_types::INT};
-pub const OFFSETINSTRUCTION: FixTag = FixTag { id: "1849", datatype: value_types::INT};
-pub const TRADEALLOCAMTREASON: FixTag = FixTag { id: "1850", datatype: value_types::INT};
-pub const STRATEGYLINKID: FixTag = FixTag { id: "1851", datatype: value_types::STRING};
-pub const SIDEAVGPX: FixTag = FixTag { id: "1852", datatype: value_types::PRICE};
-pub const SIDEAVGPXINDICATOR: FixTag = FixTag { id: "1853", datatype: value_types::INT};
-pub const SIDEAVGPXGROUPID: FixTag = FixTag { id: "1854", datatype: value_types::STRING};
-pub const NORELATEDTRADES: FixTag = FixTag { id: "1855", datatype: value_types::NUM_IN_GROUP };
-pub const RELATEDTRADEID: FixTag = FixTag { id: "1856", datatype: value_types::STRING};
-pub const RELATEDTRADEIDSOURCE: FixTag = FixTag { id: "1857", datatype: value_types::INT};
-pub const RELATEDTRADEDATE: FixTag = FixTag { id: "1858", datatype: value_types::LOCAL_MKT_DATE};
-pub const RELATEDTRADEMARKETID: FixTag = FixTag { id: "1859", datatype: value_types::EXCHANGE};
-pub const RELATEDTRADEQUANTITY: FixTag = FixTag { id: "1860", datatype: value_types::QTY};
-pub const NORELATEDPOSITIONS: FixTag = FixTag { id: "1861", datatype: value_types::NUM_IN_GROUP };
-pub const RELATEDPOSITIONID: FixTag = FixTag { id: "1862", datatype: value_types::STRING};
-pub const RELATEDPOSITIONIDSOURCE: FixTag = FixTag { id: "1863", datatype: value_types::INT};
-pub const RELATEDPOSITIONDATE: FixTag = FixTag { id: "1864", datatype: value_types::LOCAL_MKT_DATE};
-pub const QUOTEACKSTATUS: FixTag = FixTag { id: "1865", datatype: value_types::INT};
-pub const STRIKEINDEX: FixTag = FixTag { id: "1866", datatype: value_types::STRING};
-pub const OFFERID: FixTag = FixTag { id: "1867", datatype: value_types::STRING};
-pub const NOVALUECHECKS: FixTag = FixTag { id: "1868", datatype: value_types::NUM_IN_GROUP };
-pub const VALUECHECKTYPE: FixTag = FixTag { id: "1869", datatype: value_types::INT};
-pub const VALUECHECKACTION: FixTag = FixTag { id: "1870", datatype: value_types::INT};
-pub const LEGSECURITYXMLLEN: FixTag = FixTag { id: "1871", datatype: value_types::LENGTH};
-pub const LEGSECURITYXML: FixTag = FixTag { id: "1872", datatype: value_types::XML_DATA};
-pub const LEGSECURITYXMLSCHEMA: FixTag = FixTag { id: "1873", datatype: value_types::STRING};
-pub const UNDERLYINGSECURITYXMLLEN: FixTag = FixTag { id: "1874", datatype: value_types::LENGTH};
-pub const UNDERLYINGSECURITYXML: FixTag = FixTag { id: "1875", datatype: value_types::XML_DATA};
-pub const UNDERLYINGSECURITYXMLSCHEMA: FixTag = FixTag { id: "1876", datatype: value_types::STRING};
-pub const PARTYDETAILREQUESTRESULT: FixTag = FixTag { id: "1877", datatype: value_types::INT};
-pub const PARTYDETAILREQUESTSTATUS: FixTag = FixTag { id: "1878", datatype: value_types::INT};
-pub const PARTYDETAILDEFINITIONSTATUS: FixTag = FixTag { id: "1879", datatype: value_types::INT};
-pub const PARTYDETAILDEFINITIONRESULT: FixTag = FixTag { id: "1880", datatype: value_types::INT};
-pub const ENTITLEMENTREQUESTRESULT: FixTag = FixTag { id: "1881", datatype: value_types::INT};
-pub const ENTITLEMENTREQUESTSTATUS: FixTag = FixTag { id: "1882", datatype: value_types::INT};
-pub const ENTITLEMENTSTATUS: FixTag = FixTag { id: "1883", datatype: value_types::INT};
-pub const ENTITLEMENTRESULT: FixTag = FixTag { id: "1884", datatype: value_types::INT};
-pub const ENTITLEMENTREFID: FixTag = FixTag { id: "1885", datatype: value_types::STRING};
-pub const SETTLPRICEUNITOFMEASURE: FixTag = FixTag { id: "1886", datatype: value_types::STRING};
-pub const SETTLPRICEUNITOFMEASURECURRENCY: FixTag = FixTag { id: "1887", datatype: value_types::CURRENCY};
-pub const TRADEMATCHTIMESTAMP: FixTag = FixTag { id: "1888", datatype: value_types::UTC_TIMESTAMP};
-pub const NOINSTRMTMATCHSIDES: FixTag = FixTag { id: "1889", datatype: value_types::NUM_IN_GROUP };
-pub const NOTRDMATCHSIDES: FixTag = FixTag { id: "1890", datatype: value_types::NUM_IN_GROUP };
-pub const TRDMATCHSUBID: FixTag = FixTag { id: "1891", datatype: value_types::STRING};
-pub const NOLEGEXECS: FixTag = FixTag { id: "1892", datatype: value_types::NUM_IN_GROUP };
-pub const LEGEXECID: FixTag = FixTag { id: "1893", datatype: value_types::STRING};
-pub const LEGTRADEID: FixTag = FixTag { id: "1894", datatype: value_types::STRING};
-pub const LEGTRADEREPORTID: FixTag = FixTag { id: "1895", datatype: value_types::STRING};
-pub const TRADEMATCHACKSTATUS: FixTag = FixTag { id: "1896", datatype: value_types::INT};
-pub const TRADEMATCHREJECTREASON: FixTag = FixTag { id: "1897", datatype: value_types::INT};
-pub const SIDEMARKETSEGMENTID: FixTag = FixTag { id: "1898", datatype: value_types::STRING};
-pub const SIDEVENUETYPE: FixTag = FixTag { id: "1899", datatype: value_types::CHAR};
-pub const SIDEEXECREFID: FixTag = FixTag { id: "1900", datatype: value_types::STRING};
-pub const LEGEXECREFID: FixTag = FixTag { id: "1901", datatype: value_types::STRING};
-pub const HAIRCUTINDICATOR: FixTag = FixTag { id: "1902", datatype: value_types::BOOLEAN};
-pub const REGULATORYTRADEID: FixTag = FixTag { id: "1903", datatype: value_types::STRING};
-pub const REGULATORYTRADEIDEVENT: FixTag = FixTag { id: "1904", datatype: value_types::INT};
-pub const REGULATORYTRADEIDSOURCE: FixTag = FixTag { id: "1905", datatype: value_types::STRING};
-pub const REGULATORYTRADEIDTYPE: FixTag = FixTag { id: "1906", datatype: value_types::INT};
-pub const NOREGULATORYTRADEIDS: FixTag = FixTag { id: "1907", datatype: value_types::NUM_IN_GROUP };
-pub const NOALLOCREGULATORYTRADEIDS: FixTag = FixTag { id: "1908", datatype: value_types::NUM_IN_GROUP };
-pub const ALLOCREGULATORYTRADEID: FixTag = FixTag { id: "1909", datatype: value_types::STRING};
-pub const ALLOCREGULATORYTRADEIDSOURCE: FixTag = FixTag { id: "1910", datatype: value_types::STRING};
-pub const ALLOCREGULATORYTRADEIDEVENT: FixTag = FixTag { id: "1911", datatype: value_types::INT};
-pub const ALLOCREGULATORYTRADEIDTYPE: FixTag = FixTag { id: "1912", datatype: value_types::INT};
-pub const NUMOFCOMPETITORS: FixTag = FixTag { id: "1913", datatype: value_types::INT};
-pub const RESPONSETIME: FixTag = FixTag { id: "1914", datatype: value_types::UTC_TIMESTAMP};
-pub const QUOTEDISPLAYTIME: FixTag = FixTag { id: "1915", datatype: value_types::UTC_TIMESTAMP};
-pub const EXPOSUREDURATIONUNIT: FixTag = FixTag { id: "1916", datatype: value_types::INT};
-pub const COVERPRICE: FixTag = FixTag { id: "1917", datatype: value_types::PRICE};
-pub const NOCLEARINGACCOUNTTYPES: FixTag = FixTag { id: "1918", datatype: value_types::NUM_IN_GROUP };
-pub const NOPRICEMOVEMENTS: FixTag = FixTag { id: "1919", datatype: value_types::NUM_IN_GROUP };
-pub const NOPRICEMOVEMENTVALUES: FixTag = FixTag { id: "1920", datatype: value_types::NUM_IN_GROUP };
-pub const PRICEMOVEMENTVALUE: FixTag = FixTag { id: "1921", datatype: value_types::FLOAT};
-pub const PRICEMOVEMENTPOINT: FixTag = FixTag { id: "1922", datatype: value_types::INT};
-pub const PRICEMOVEMENTTYPE: FixTag = FixTag { id: "1923", datatype: value_types::INT};
-pub const CLEARINGINTENTION: FixTag = FixTag { id: "1924", datatype: value_types::INT};
-pub const TRADECLEARINGINSTRUCTION: FixTag = FixTag { id: "1925", datatype: value_types::INT};
-pub const BACKLOADEDTRADEINDICATOR: FixTag = FixTag { id: "1926", datatype: value_types::BOOLEAN};
-pub const CONFIRMATIONMETHOD: FixTag = FixTag { id: "1927", datatype: value_types::INT};
-pub const MANDATORYCLEARINGINDICATOR: FixTag = FixTag { id: "1928", datatype: value_types::BOOLEAN};
-pub const MIXEDSWAPINDICATOR: FixTag = FixTag { id: "1929", datatype: value_types::BOOLEAN};
-pub const OFFMARKETPRICEINDICATOR: FixTag = FixTag { id: "1930", datatype: value_types::BOOLEAN};
-pub const VERIFICATIONMETHOD: FixTag = FixTag { id: "1931", datatype: value_types::INT};
-pub const CLEARINGREQUIREMENTEXCEPTION: FixTag = FixTag { id: "1932", datatype: value_types::INT};
-pub const IRSDIRECTION: FixTag = FixTag { id: "1933", datatype: value_types::STRING};
-pub const REGULATORYREPORTTYPE: FixTag = FixTag { id: "1934", datatype: value_types::INT};
-pub const VOLUNTARYREGULATORYREPORT: FixTag = FixTag { id: "1935", datatype: value_types::BOOLEAN};
-pub const TRADECOLLATERALIZATION: FixTag = FixTag { id: "1936", datatype: value_types::INT};
-pub const TRADECONTINUATION: FixTag = FixTag { id: "1937", datatype: value_types::INT};
-pub const ASSETCLASS: FixTag = FixTag { id: "1938", datatype: value_types::INT};
-pub const ASSETSUBCLASS: FixTag = FixTag { id: "1939", datatype: value_types::INT};
-pub const ASSETTYPE: FixTag = FixTag { id: "1940", datatype: value_types::STRING};
-pub const SWAPCLASS: FixTag = FixTag { id: "1941", datatype: value_types::STRING};
-pub const NTHTODEFAULT: FixTag = FixTag { id: "1942", datatype: value_types::INT};
-pub const MTHTODEFAULT: FixTag = FixTag { id: "1943", datatype: value_types::INT};
-pub const SETTLEDENTITYMATRIXSOURCE: FixTag = FixTag { id: "1944", datatype: value_types::STRING};
-pub const SETTLEDENTITYMATRIXPUBLICATIONDATE: FixTag = FixTag { id: "1945", datatype: value_types::LOCAL_MKT_DATE};
-pub const COUPONTYPE: FixTag = FixTag { id: "1946", datatype: value_types::INT};
-pub const TOTALISSUEDAMOUNT: FixTag = FixTag { id: "1947", datatype: value_types::AMT};
-pub const COUPONFREQUENCYPERIOD: FixTag = FixTag { id: "1948", datatype: value_types::INT};
-pub const COUPONFREQUENCYUNIT: FixTag = FixTag { id: "1949", datatype: value_types::STRING};
-pub const COUPONDAYCOUNT: FixTag = FixTag { id: "1950", datatype: value_types::INT};
-pub const CONVERTIBLEBONDEQUITYID: FixTag = FixTag { id: "1951", datatype: value_types::STRING};
-pub const CONVERTIBLEBONDEQUITYIDSOURCE: FixTag = FixTag { id: "1952", datatype: value_types::STRING};
-pub const CONTRACTPRICEREFMONTH: FixTag = FixTag { id: "1953", datatype: value_types::MONTH_YEAR};
-pub const LIENSENIORITY: FixTag = FixTag { id: "1954", datatype: value_types::INT};
-pub const LOANFACILITY: FixTag = FixTag { id: "1955", datatype: value_types::INT};
-pub const REFERENCEENTITYTYPE: FixTag = FixTag { id: "1956", datatype: value_types::INT};
-pub const INDEXSERIES: FixTag = FixTag { id: "1957", datatype: value_types::INT};
-pub const INDEXANNEXVERSION: FixTag = FixTag { id: "1958", datatype: value_types::INT};
-pub const INDEXANNEXDATE: FixTag = FixTag { id: "1959", datatype: value_types::LOCAL_MKT_DATE};
-pub const INDEXANNEXSOURCE: FixTag = FixTag { id: "1960", datatype: value_types::STRING};
-pub const AGREEMENTVERSION: FixTag = FixTag { id: "1961", datatype: value_types::STRING};
-pub const MASTERCONFIRMATIONDESC: FixTag = FixTag { id: "1962", datatype: value_types::STRING};
-pub const MASTERCONFIRMATIONDATE: FixTag = FixTag { id: "1963", datatype: value_types::LOCAL_MKT_DATE};
-pub const MASTERCONFIRMATIONANNEXDESC: FixTag = FixTag { id: "1964", datatype: value_types::STRING};
-pub const MASTERCONFIRMATIONANNEXDATE: FixTag = FixTag { id: "1965", datatype: value_types::LOCAL_MKT_DATE};
-pub const BROKERCONFIRMATIONDESC: FixTag = FixTag { id: "1966", datatype: value_types::STRING};
-pub const CREDITSUPPORTAGREEMENTDESC: FixTag = FixTag { id: "1967", datatype: value_types::STRING};
-pub const CREDITSUPPORTAGREEMENTDATE: FixTag = FixTag { id: "1968", datatype: value_types::LOCAL_MKT_DATE};
-pub const CREDITSUPPORTAGREEMENTID: FixTag = FixTag { id: "1969", datatype: value_types::STRING};
-pub const GOVERNINGLAW: FixTag = FixTag { id: "1970", datatype: value_types::STRING};
-pub const NOSIDEREGULATORYTRADEIDS: FixTag = FixTag { id: "1971", datatype: value_types::NUM_IN_GROUP };
-pub const SIDEREGULATORYTRADEID: FixTag = FixTag { id: "1972", datatype: value_types::STRING};
-pub const SIDEREGULATORYTRADEIDSOURCE: FixTag = FixTag { id: "1973", datatype: value_types::STRING};
-pub const SIDEREGULATORYTRADEIDEVENT: FixTag = FixTag { id: "1974", datatype: value_types::INT};
-pub const SIDEREGULATORYTRADEIDTYPE: FixTag = FixTag { id: "1975", datatype: value_types::INT};
-pub const NOSECONDARYASSETCLASSES: FixTag = FixTag { id: "1976", datatype: value_types::NUM_IN_GROUP };
-pub const SECONDARYASSETCLASS: FixTag = FixTag { id: "1977", datatype: value_types::INT};
-pub const SECONDARYASSETSUBCLASS: FixTag = FixTag { id: "1978", datatype: value_types::INT};
-pub const SECONDARYASSETTYPE: FixTag = FixTag { id: "1979", datatype: value_types::STRING};
-pub const BLOCKTRDALLOCINDICATOR: FixTag = FixTag { id: "1980", datatype: value_types::INT};
-pub const NOUNDERLYINGEVENTS: FixTag = FixTag { id: "1981", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGEVENTTYPE: FixTag = FixTag { id: "1982", datatype: value_types::INT};
-pub const UNDERLYINGEVENTDATE: FixTag = FixTag { id: "1983", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGEVENTTIME: FixTag = FixTag { id: "1984", datatype: value_types::UTC_TIMESTAMP};
-pub const UNDERLYINGEVENTTIMEUNIT: FixTag = FixTag { id: "1985", datatype: value_types::STRING};
-pub const UNDERLYINGEVENTTIMEPERIOD: FixTag = FixTag { id: "1986", datatype: value_types::INT};
-pub const UNDERLYINGEVENTPX: FixTag = FixTag { id: "1987", datatype: value_types::PRICE};
-pub const UNDERLYINGCONSTITUENTWEIGHT: FixTag = FixTag { id: "1988", datatype: value_types::FLOAT};
-pub const UNDERLYINGCOUPONTYPE: FixTag = FixTag { id: "1989", datatype: value_types::INT};
-pub const UNDERLYINGTOTALISSUEDAMOUNT: FixTag = FixTag { id: "1990", datatype: value_types::AMT};
-pub const UNDERLYINGCOUPONFREQUENCYPERIOD: FixTag = FixTag { id: "1991", datatype: value_types::INT};
-pub const UNDERLYINGCOUPONFREQUENCYUNIT: FixTag = FixTag { id: "1992", datatype: value_types::STRING};
-pub const UNDERLYINGCOUPONDAYCOUNT: FixTag = FixTag { id: "1993", datatype: value_types::INT};
-pub const UNDERLYINGOBLIGATIONID: FixTag = FixTag { id: "1994", datatype: value_types::STRING};
-pub const UNDERLYINGOBLIGATIONIDSOURCE: FixTag = FixTag { id: "1995", datatype: value_types::STRING};
-pub const UNDERLYINGEQUITYID: FixTag = FixTag { id: "1996", datatype: value_types::STRING};
-pub const UNDERLYINGEQUITYIDSOURCE: FixTag = FixTag { id: "1997", datatype: value_types::STRING};
-pub const UNDERLYINGLIENSENIORITY: FixTag = FixTag { id: "1998", datatype: value_types::INT};
-pub const UNDERLYINGLOANFACILITY: FixTag = FixTag { id: "1999", datatype: value_types::INT};
-pub const UNDERLYINGREFERENCEENTITYTYPE: FixTag = FixTag { id: "2000", datatype: value_types::INT};
-pub const STRIKEINDEXSPREAD: FixTag = FixTag { id: "2001", datatype: value_types::PRICE_OFFSET};
-pub const VALUATIONSOURCE: FixTag = FixTag { id: "2002", datatype: value_types::STRING};
-pub const UNDERLYINGINDEXSERIES: FixTag = FixTag { id: "2003", datatype: value_types::INT};
-pub const UNDERLYINGINDEXANNEXVERSION: FixTag = FixTag { id: "2004", datatype: value_types::INT};
-pub const UNDERLYINGINDEXANNEXDATE: FixTag = FixTag { id: "2005", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGINDEXANNEXSOURCE: FixTag = FixTag { id: "2006", datatype: value_types::STRING};
-pub const UNDERLYINGPRODUCTCOMPLEX: FixTag = FixTag { id: "2007", datatype: value_types::STRING};
-pub const UNDERLYINGSECURITYGROUP: FixTag = FixTag { id: "2008", datatype: value_types::STRING};
-pub const UNDERLYINGSETTLEONOPENFLAG: FixTag = FixTag { id: "2009", datatype: value_types::STRING};
-pub const UNDERLYINGASSIGNMENTMETHOD: FixTag = FixTag { id: "2010", datatype: value_types::CHAR};
-pub const UNDERLYINGSECURITYSTATUS: FixTag = FixTag { id: "2011", datatype: value_types::STRING};
-pub const UNDERLYINGOBLIGATIONTYPE: FixTag = FixTag { id: "2012", datatype: value_types::STRING};
-pub const UNDERLYINGASSETCLASS: FixTag = FixTag { id: "2013", datatype: value_types::INT};
-pub const UNDERLYINGASSETSUBCLASS: FixTag = FixTag { id: "2014", datatype: value_types::INT};
-pub const UNDERLYINGASSETTYPE: FixTag = FixTag { id: "2015", datatype: value_types::STRING};
-pub const UNDERLYINGSWAPCLASS: FixTag = FixTag { id: "2016", datatype: value_types::STRING};
-pub const UNDERLYINGNTHTODEFAULT: FixTag = FixTag { id: "2017", datatype: value_types::INT};
-pub const UNDERLYINGMTHTODEFAULT: FixTag = FixTag { id: "2018", datatype: value_types::INT};
-pub const UNDERLYINGSETTLEDENTITYMATRIXSOURCE: FixTag = FixTag { id: "2019", datatype: value_types::STRING};
-pub const UNDERLYINGSETTLEDENTITYMATRIXPUBLICATIONDATE: FixTag = FixTag { id: "2020", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGSTRIKEMULTIPLIER: FixTag = FixTag { id: "2021", datatype: value_types::FLOAT};
-pub const UNDERLYINGSTRIKEVALUE: FixTag = FixTag { id: "2022", datatype: value_types::FLOAT};
-pub const UNDERLYINGSTRIKEPRICEDETERMINATIONMETHOD: FixTag = FixTag { id: "2023", datatype: value_types::INT};
-pub const UNDERLYINGSTRIKEPRICEBOUNDARYMETHOD: FixTag = FixTag { id: "2024", datatype: value_types::INT};
-pub const UNDERLYINGSTRIKEPRICEBOUNDARYPRECISION: FixTag = FixTag { id: "2025", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGMINPRICEINCREMENT: FixTag = FixTag { id: "2026", datatype: value_types::FLOAT};
-pub const UNDERLYINGMINPRICEINCREMENTAMOUNT: FixTag = FixTag { id: "2027", datatype: value_types::AMT};
-pub const UNDERLYINGOPTPAYOUTTYPE: FixTag = FixTag { id: "2028", datatype: value_types::INT};
-pub const UNDERLYINGOPTPAYOUTAMOUNT: FixTag = FixTag { id: "2029", datatype: value_types::AMT};
-pub const UNDERLYINGPRICEQUOTEMETHOD: FixTag = FixTag { id: "2030", datatype: value_types::STRING};
-pub const UNDERLYINGVALUATIONMETHOD: FixTag = FixTag { id: "2031", datatype: value_types::STRING};
-pub const UNDERLYINGLISTMETHOD: FixTag = FixTag { id: "2032", datatype: value_types::INT};
-pub const UNDERLYINGCAPPRICE: FixTag = FixTag { id: "2033", datatype: value_types::PRICE};
-pub const UNDERLYINGFLOORPRICE: FixTag = FixTag { id: "2034", datatype: value_types::PRICE};
-pub const UNDERLYINGFLEXIBLEINDICATOR: FixTag = FixTag { id: "2035", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGFLEXPRODUCTELIGIBILITYINDICATOR: FixTag = FixTag { id: "2036", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGPOSITIONLIMIT: FixTag = FixTag { id: "2037", datatype: value_types::INT};
-pub const UNDERLYINGNTPOSITIONLIMIT: FixTag = FixTag { id: "2038", datatype: value_types::INT};
-pub const UNDERLYINGPOOL: FixTag = FixTag { id: "2039", datatype: value_types::STRING};
-pub const UNDERLYINGCONTRACTSETTLMONTH: FixTag = FixTag { id: "2040", datatype: value_types::MONTH_YEAR};
-pub const UNDERLYINGDATEDDATE: FixTag = FixTag { id: "2041", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGINTERESTACCRUALDATE: FixTag = FixTag { id: "2042", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGSHORTSALERESTRICTION: FixTag = FixTag { id: "2043", datatype: value_types::INT};
-pub const UNDERLYINGREFTICKTABLEID: FixTag = FixTag { id: "2044", datatype: value_types::INT};
-pub const NOUNDERLYINGCOMPLEXEVENTS: FixTag = FixTag { id: "2045", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGCOMPLEXEVENTTYPE: FixTag = FixTag { id: "2046", datatype: value_types::INT};
-pub const UNDERLYINGCOMPLEXOPTPAYOUTAMOUNT: FixTag = FixTag { id: "2047", datatype: value_types::AMT};
-pub const UNDERLYINGCOMPLEXEVENTPRICE: FixTag = FixTag { id: "2048", datatype: value_types::PRICE};
-pub const UNDERLYINGCOMPLEXEVENTPRICEBOUNDARYMETHOD: FixTag = FixTag { id: "2049", datatype: value_types::INT};
-pub const UNDERLYINGCOMPLEXEVENTPRICEBOUNDARYPRECISION: FixTag = FixTag { id: "2050", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGCOMPLEXEVENTPRICETIMETYPE: FixTag = FixTag { id: "2051", datatype: value_types::INT};
-pub const UNDERLYINGCOMPLEXEVENTCONDITION: FixTag = FixTag { id: "2052", datatype: value_types::INT};
-pub const NOUNDERLYINGCOMPLEXEVENTDATES: FixTag = FixTag { id: "2053", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGCOMPLEXEVENTSTARTDATE: FixTag = FixTag { id: "2054", datatype: value_types::UTC_DATE_ONLY};
-pub const UNDERLYINGCOMPLEXEVENTENDDATE: FixTag = FixTag { id: "2055", datatype: value_types::UTC_DATE_ONLY};
-pub const NOUNDERLYINGCOMPLEXEVENTTIMES: FixTag = FixTag { id: "2056", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGCOMPLEXEVENTSTARTTIME: FixTag = FixTag { id: "2057", datatype: value_types::UTC_TIME_ONLY};
-pub const UNDERLYINGCOMPLEXEVENTENDTIME: FixTag = FixTag { id: "2058", datatype: value_types::UTC_TIME_ONLY};
-pub const NOLEGEVENTS: FixTag = FixTag { id: "2059", datatype: value_types::NUM_IN_GROUP };
-pub const LEGEVENTTYPE: FixTag = FixTag { id: "2060", datatype: value_types::INT};
-pub const LEGEVENTDATE: FixTag = FixTag { id: "2061", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGEVENTTIME: FixTag = FixTag { id: "2062", datatype: value_types::UTC_TIMESTAMP};
-pub const LEGEVENTTIMEUNIT: FixTag = FixTag { id: "2063", datatype: value_types::STRING};
-pub const LEGEVENTTIMEPERIOD: FixTag = FixTag { id: "2064", datatype: value_types::INT};
-pub const LEGEVENTPX: FixTag = FixTag { id: "2065", datatype: value_types::PRICE};
-pub const LEGEVENTTEXT: FixTag = FixTag { id: "2066", datatype: value_types::STRING};
-pub const LEGASSETCLASS: FixTag = FixTag { id: "2067", datatype: value_types::INT};
-pub const LEGASSETSUBCLASS: FixTag = FixTag { id: "2068", datatype: value_types::INT};
-pub const LEGASSETTYPE: FixTag = FixTag { id: "2069", datatype: value_types::STRING};
-pub const LEGSWAPCLASS: FixTag = FixTag { id: "2070", datatype: value_types::STRING};
-pub const UNDERLYINGEVENTTEXT: FixTag = FixTag { id: "2071", datatype: value_types::STRING};
-pub const ENCODEDUNDERLYINGEVENTTEXTLEN: FixTag = FixTag { id: "2072", datatype: value_types::LENGTH};
-pub const ENCODEDUNDERLYINGEVENTTEXT: FixTag = FixTag { id: "2073", datatype: value_types::DATA};
-pub const ENCODEDLEGEVENTTEXTLEN: FixTag = FixTag { id: "2074", datatype: value_types::LENGTH};
-pub const ENCODEDLEGEVENTTEXT: FixTag = FixTag { id: "2075", datatype: value_types::DATA};
-pub const NOLEGSECONDARYASSETCLASSES: FixTag = FixTag { id: "2076", datatype: value_types::NUM_IN_GROUP };
-pub const LEGSECONDARYASSETCLASS: FixTag = FixTag { id: "2077", datatype: value_types::INT};
-pub const LEGSECONDARYASSETSUBCLASS: FixTag = FixTag { id: "2078", datatype: value_types::INT};
-pub const LEGSECONDARYASSETTYPE: FixTag = FixTag { id: "2079", datatype: value_types::STRING};
-pub const NOUNDERLYINGSECONDARYASSETCLASSES: FixTag = FixTag { id: "2080", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGSECONDARYASSETCLASS: FixTag = FixTag { id: "2081", datatype: value_types::INT};
-pub const UNDERLYINGSECONDARYASSETSUBCLASS: FixTag = FixTag { id: "2082", datatype: value_types::INT};
-pub const UNDERLYINGSECONDARYASSETTYPE: FixTag = FixTag { id: "2083", datatype: value_types::STRING};
-pub const PREVIOUSCLEARINGBUSINESSDATE: FixTag = FixTag { id: "2084", datatype: value_types::LOCAL_MKT_DATE};
-pub const VALUATIONDATE: FixTag = FixTag { id: "2085", datatype: value_types::LOCAL_MKT_DATE};
-pub const VALUATIONTIME: FixTag = FixTag { id: "2086", datatype: value_types::LOCAL_MKT_TIME};
-pub const VALUATIONBUSINESSCENTER: FixTag = FixTag { id: "2087", datatype: value_types::STRING};
-pub const MARGINAMTFXRATE: FixTag = FixTag { id: "2088", datatype: value_types::FLOAT};
-pub const MARGINAMTFXRATECALC: FixTag = FixTag { id: "2089", datatype: value_types::CHAR};
-pub const COLLATERALFXRATE: FixTag = FixTag { id: "2090", datatype: value_types::FLOAT};
-pub const COLLATERALFXRATECALC: FixTag = FixTag { id: "2091", datatype: value_types::CHAR};
-pub const COLLATERALAMOUNTMARKETSEGMENTID: FixTag = FixTag { id: "2092", datatype: value_types::STRING};
-pub const COLLATERALAMOUNTMARKETID: FixTag = FixTag { id: "2093", datatype: value_types::STRING};
-pub const PAYCOLLECTFXRATE: FixTag = FixTag { id: "2094", datatype: value_types::FLOAT};
-pub const PAYCOLLECTFXRATECALC: FixTag = FixTag { id: "2095", datatype: value_types::CHAR};
-pub const POSAMTSTREAMDESC: FixTag = FixTag { id: "2096", datatype: value_types::STRING};
-pub const POSITIONFXRATE: FixTag = FixTag { id: "2097", datatype: value_types::FLOAT};
-pub const POSITIONFXRATECALC: FixTag = FixTag { id: "2098", datatype: value_types::CHAR};
-pub const POSAMTMARKETSEGMENTID: FixTag = FixTag { id: "2099", datatype: value_types::STRING};
-pub const POSAMTMARKETID: FixTag = FixTag { id: "2100", datatype: value_types::STRING};
-pub const TERMINATEDINDICATOR: FixTag = FixTag { id: "2101", datatype: value_types::BOOLEAN};
-pub const SHORTMARKINGEXEMPTINDICATOR: FixTag = FixTag { id: "2102", datatype: value_types::BOOLEAN};
-pub const RELATEDREGULATORYTRADEIDSOURCE: FixTag = FixTag { id: "2103", datatype: value_types::STRING};
-pub const NOATTACHMENTS: FixTag = FixTag { id: "2104", datatype: value_types::NUM_IN_GROUP };
-pub const ATTACHMENTNAME: FixTag = FixTag { id: "2105", datatype: value_types::STRING};
-pub const ATTACHMENTMEDIATYPE: FixTag = FixTag { id: "2106", datatype: value_types::STRING};
-pub const ATTACHMENTCLASSIFICATION: FixTag = FixTag { id: "2107", datatype: value_types::STRING};
-pub const ATTACHMENTEXTERNALURL: FixTag = FixTag { id: "2108", datatype: value_types::STRING};
-pub const ATTACHMENTENCODINGTYPE: FixTag = FixTag { id: "2109", datatype: value_types::INT};
-pub const UNENCODEDATTACHMENTLEN: FixTag = FixTag { id: "2110", datatype: value_types::INT};
-pub const ENCODEDATTACHMENTLEN: FixTag = FixTag { id: "2111", datatype: value_types::LENGTH};
-pub const ENCODEDATTACHMENT: FixTag = FixTag { id: "2112", datatype: value_types::DATA};
-pub const NOATTACHMENTKEYWORDS: FixTag = FixTag { id: "2113", datatype: value_types::NUM_IN_GROUP };
-pub const ATTACHMENTKEYWORD: FixTag = FixTag { id: "2114", datatype: value_types::STRING};
-pub const NEGOTIATIONMETHOD: FixTag = FixTag { id: "2115", datatype: value_types::INT};
-pub const NEXTAUCTIONTIME: FixTag = FixTag { id: "2116", datatype: value_types::UTC_TIMESTAMP};
-pub const COMPLEXOPTPAYOUTPAYSIDE: FixTag = FixTag { id: "2117", datatype: value_types::INT};
-pub const COMPLEXOPTPAYOUTRECEIVESIDE: FixTag = FixTag { id: "2118", datatype: value_types::INT};
-pub const COMPLEXOPTPAYOUTUNDERLIER: FixTag = FixTag { id: "2119", datatype: value_types::STRING};
-pub const COMPLEXOPTPAYOUTPERCENTAGE: FixTag = FixTag { id: "2120", datatype: value_types::PERCENTAGE};
-pub const COMPLEXOPTPAYOUTTIME: FixTag = FixTag { id: "2121", datatype: value_types::INT};
-pub const COMPLEXOPTPAYOUTCURRENCY: FixTag = FixTag { id: "2122", datatype: value_types::CURRENCY};
-pub const COMPLEXEVENTPRICEPERCENTAGE: FixTag = FixTag { id: "2123", datatype: value_types::PERCENTAGE};
-pub const COMPLEXEVENTCURRENCYONE: FixTag = FixTag { id: "2124", datatype: value_types::CURRENCY};
-pub const COMPLEXEVENTCURRENCYTWO: FixTag = FixTag { id: "2125", datatype: value_types::CURRENCY};
-pub const COMPLEXEVENTQUOTEBASIS: FixTag = FixTag { id: "2126", datatype: value_types::INT};
-pub const COMPLEXEVENTFIXEDFXRATE: FixTag = FixTag { id: "2127", datatype: value_types::FLOAT};
-pub const COMPLEXEVENTDETERMINATIONMETHOD: FixTag = FixTag { id: "2128", datatype: value_types::STRING};
-pub const COMPLEXEVENTCALCULATIONAGENT: FixTag = FixTag { id: "2129", datatype: value_types::INT};
-pub const COMPLEXEVENTSTRIKEPRICE: FixTag = FixTag { id: "2130", datatype: value_types::PRICE};
-pub const COMPLEXEVENTSTRIKEFACTOR: FixTag = FixTag { id: "2131", datatype: value_types::FLOAT};
-pub const COMPLEXEVENTSTRIKENUMBEROFOPTIONS: FixTag = FixTag { id: "2132", datatype: value_types::INT};
-pub const COMPLEXEVENTCREDITEVENTSXIDREF: FixTag = FixTag { id: "2133", datatype: value_types::XIDREF};
-pub const COMPLEXEVENTCREDITEVENTNOTIFYINGPARTY: FixTag = FixTag { id: "2134", datatype: value_types::INT};
-pub const COMPLEXEVENTCREDITEVENTBUSINESSCENTER: FixTag = FixTag { id: "2135", datatype: value_types::STRING};
-pub const COMPLEXEVENTCREDITEVENTSTANDARDSOURCES: FixTag = FixTag { id: "2136", datatype: value_types::BOOLEAN};
-pub const COMPLEXEVENTCREDITEVENTMINIMUMSOURCES: FixTag = FixTag { id: "2137", datatype: value_types::INT};
-pub const COMPLEXEVENTXID: FixTag = FixTag { id: "2138", datatype: value_types::XID};
-pub const COMPLEXEVENTXIDREF: FixTag = FixTag { id: "2139", datatype: value_types::XIDREF};
-pub const VALUATIONREFERENCEMODEL: FixTag = FixTag { id: "2140", datatype: value_types::STRING};
-pub const STRATEGYTYPE: FixTag = FixTag { id: "2141", datatype: value_types::STRING};
-pub const COMMONPRICINGINDICATOR: FixTag = FixTag { id: "2142", datatype: value_types::BOOLEAN};
-pub const SETTLDISRUPTIONPROVISION: FixTag = FixTag { id: "2143", datatype: value_types::INT};
-pub const INSTRUMENTROUNDINGDIRECTION: FixTag = FixTag { id: "2144", datatype: value_types::CHAR};
-pub const INSTRUMENTROUNDINGPRECISION: FixTag = FixTag { id: "2145", datatype: value_types::INT};
-pub const LEGSETTLEONOPENFLAG: FixTag = FixTag { id: "2146", datatype: value_types::STRING};
-pub const LEGINSTRMTASSIGNMENTMETHOD: FixTag = FixTag { id: "2147", datatype: value_types::CHAR};
-pub const LEGSECURITYSTATUS: FixTag = FixTag { id: "2148", datatype: value_types::STRING};
-pub const LEGRESTRUCTURINGTYPE: FixTag = FixTag { id: "2149", datatype: value_types::STRING};
-pub const LEGSENIORITY: FixTag = FixTag { id: "2150", datatype: value_types::STRING};
-pub const LEGNOTIONALPERCENTAGEOUTSTANDING: FixTag = FixTag { id: "2151", datatype: value_types::PERCENTAGE};
-pub const LEGORIGINALNOTIONALPERCENTAGEOUTSTANDING: FixTag = FixTag { id: "2152", datatype: value_types::PERCENTAGE};
-pub const LEGATTACHMENTPOINT: FixTag = FixTag { id: "2153", datatype: value_types::PERCENTAGE};
-pub const LEGDETACHMENTPOINT: FixTag = FixTag { id: "2154", datatype: value_types::PERCENTAGE};
-pub const LEGOBLIGATIONTYPE: FixTag = FixTag { id: "2155", datatype: value_types::STRING};
-pub const LEGSWAPSUBCLASS: FixTag = FixTag { id: "2156", datatype: value_types::STRING};
-pub const LEGNTHTODEFAULT: FixTag = FixTag { id: "2157", datatype: value_types::INT};
-pub const LEGMTHTODEFAULT: FixTag = FixTag { id: "2158", datatype: value_types::INT};
-pub const LEGSETTLEDENTITYMATRIXSOURCE: FixTag = FixTag { id: "2159", datatype: value_types::STRING};
-pub const LEGSETTLEDENTITYMATRIXPUBLICATIONDATE: FixTag = FixTag { id: "2160", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGCOUPONTYPE: FixTag = FixTag { id: "2161", datatype: value_types::INT};
-pub const LEGTOTALISSUEDAMOUNT: FixTag = FixTag { id: "2162", datatype: value_types::AMT};
-pub const LEGCOUPONFREQUENCYPERIOD: FixTag = FixTag { id: "2163", datatype: value_types::INT};
-pub const LEGCOUPONFREQUENCYUNIT: FixTag = FixTag { id: "2164", datatype: value_types::STRING};
-pub const LEGCOUPONDAYCOUNT: FixTag = FixTag { id: "2165", datatype: value_types::INT};
-pub const LEGCONVERTIBLEBONDEQUITYID: FixTag = FixTag { id: "2166", datatype: value_types::STRING};
-pub const LEGCONVERTIBLEBONDEQUITYIDSOURCE: FixTag = FixTag { id: "2167", datatype: value_types::STRING};
-pub const LEGCONTRACTPRICEREFMONTH: FixTag = FixTag { id: "2168", datatype: value_types::MONTH_YEAR};
-pub const LEGLIENSENIORITY: FixTag = FixTag { id: "2169", datatype: value_types::INT};
-pub const LEGLOANFACILITY: FixTag = FixTag { id: "2170", datatype: value_types::INT};
-pub const LEGREFERENCEENTITYTYPE: FixTag = FixTag { id: "2171", datatype: value_types::INT};
-pub const LEGINDEXSERIES: FixTag = FixTag { id: "2172", datatype: value_types::INT};
-pub const LEGINDEXANNEXVERSION: FixTag = FixTag { id: "2173", datatype: value_types::INT};
-pub const LEGINDEXANNEXDATE: FixTag = FixTag { id: "2174", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGINDEXANNEXSOURCE: FixTag = FixTag { id: "2175", datatype: value_types::STRING};
-pub const LEGSETTLRATEINDEX: FixTag = FixTag { id: "2176", datatype: value_types::STRING};
-pub const LEGSETTLRATEINDEXLOCATION: FixTag = FixTag { id: "2177", datatype: value_types::STRING};
-pub const LEGOPTIONEXPIRATIONDESC: FixTag = FixTag { id: "2178", datatype: value_types::STRING};
-pub const ENCODEDLEGOPTIONEXPIRATIONDESCLEN: FixTag = FixTag { id: "2179", datatype: value_types::LENGTH};
-pub const ENCODEDLEGOPTIONEXPIRATIONDESC: FixTag = FixTag { id: "2180", datatype: value_types::DATA};
-pub const LEGSTRIKEMULTIPLIER: FixTag = FixTag { id: "2181", datatype: value_types::FLOAT};
-pub const LEGSTRIKEVALUE: FixTag = FixTag { id: "2182", datatype: value_types::FLOAT};
-pub const LEGSTRIKEUNITOFMEASURE: FixTag = FixTag { id: "2183", datatype: value_types::STRING};
-pub const LEGSTRIKEINDEX: FixTag = FixTag { id: "2184", datatype: value_types::STRING};
-pub const LEGSTRIKEINDEXSPREAD: FixTag = FixTag { id: "2185", datatype: value_types::PRICE_OFFSET};
-pub const LEGSTRIKEPRICEDETERMINATIONMETHOD: FixTag = FixTag { id: "2186", datatype: value_types::INT};
-pub const LEGSTRIKEPRICEBOUNDARYMETHOD: FixTag = FixTag { id: "2187", datatype: value_types::INT};
-pub const LEGSTRIKEPRICEBOUNDARYPRECISION: FixTag = FixTag { id: "2188", datatype: value_types::PERCENTAGE};
-pub const LEGUNDERLYINGPRICEDETERMINATIONMETHOD: FixTag = FixTag { id: "2189", datatype: value_types::INT};
-pub const LEGMINPRICEINCREMENT: FixTag = FixTag { id: "2190", datatype: value_types::FLOAT};
-pub const LEGMINPRICEINCREMENTAMOUNT: FixTag = FixTag { id: "2191", datatype: value_types::AMT};
-pub const LEGSETTLMETHOD: FixTag = FixTag { id: "2192", datatype: value_types::STRING};
-pub const LEGOPTPAYOUTTYPE: FixTag = FixTag { id: "2193", datatype: value_types::INT};
-pub const LEGOPTPAYOUTAMOUNT: FixTag = FixTag { id: "2194", datatype: value_types::AMT};
-pub const LEGPRICEQUOTEMETHOD: FixTag = FixTag { id: "2195", datatype: value_types::STRING};
-pub const LEGVALUATIONMETHOD: FixTag = FixTag { id: "2196", datatype: value_types::STRING};
-pub const LEGVALUATIONSOURCE: FixTag = FixTag { id: "2197", datatype: value_types::STRING};
-pub const LEGVALUATIONREFERENCEMODEL: FixTag = FixTag { id: "2198", datatype: value_types::STRING};
-pub const LEGLISTMETHOD: FixTag = FixTag { id: "2199", datatype: value_types::INT};
-pub const LEGCAPPRICE: FixTag = FixTag { id: "2200", datatype: value_types::PRICE};
-pub const LEGFLOORPRICE: FixTag = FixTag { id: "2201", datatype: value_types::PRICE};
-pub const LEGFLEXIBLEINDICATOR: FixTag = FixTag { id: "2202", datatype: value_types::BOOLEAN};
-pub const LEGFLEXPRODUCTELIGIBILITYINDICATOR: FixTag = FixTag { id: "2203", datatype: value_types::BOOLEAN};
-pub const LEGCOMPLEXEVENTSTARTTIME: FixTag = FixTag { id: "2204", datatype: value_types::UTC_TIME_ONLY};
-pub const LEGPOSITIONLIMIT: FixTag = FixTag { id: "2205", datatype: value_types::INT};
-pub const LEGNTPOSITIONLIMIT: FixTag = FixTag { id: "2206", datatype: value_types::INT};
-pub const LEGCPPROGRAM: FixTag = FixTag { id: "2207", datatype: value_types::INT};
-pub const LEGCPREGTYPE: FixTag = FixTag { id: "2208", datatype: value_types::STRING};
-pub const LEGSHORTSALERESTRICTION: FixTag = FixTag { id: "2209", datatype: value_types::INT};
-pub const ASSETGROUP: FixTag = FixTag { id: "2210", datatype: value_types::INT};
-pub const LEGSTRATEGYTYPE: FixTag = FixTag { id: "2211", datatype: value_types::STRING};
-pub const LEGCOMMONPRICINGINDICATOR: FixTag = FixTag { id: "2212", datatype: value_types::BOOLEAN};
-pub const LEGSETTLDISRUPTIONPROVISION: FixTag = FixTag { id: "2213", datatype: value_types::INT};
-pub const LEGINSTRUMENTROUNDINGDIRECTION: FixTag = FixTag { id: "2214", datatype: value_types::CHAR};
-pub const LEGINSTRUMENTROUNDINGPRECISION: FixTag = FixTag { id: "2215", datatype: value_types::INT};
-pub const MISCFEERATE: FixTag = FixTag { id: "2216", datatype: value_types::PERCENTAGE};
-pub const MISCFEEAMOUNTDUE: FixTag = FixTag { id: "2217", datatype: value_types::AMT};
-pub const NOLEGCOMPLEXEVENTS: FixTag = FixTag { id: "2218", datatype: value_types::NUM_IN_GROUP };
-pub const LEGCOMPLEXEVENTTYPE: FixTag = FixTag { id: "2219", datatype: value_types::INT};
-pub const LEGCOMPLEXOPTPAYOUTPAYSIDE: FixTag = FixTag { id: "2220", datatype: value_types::INT};
-pub const LEGCOMPLEXOPTPAYOUTRECEIVESIDE: FixTag = FixTag { id: "2221", datatype: value_types::INT};
-pub const LEGCOMPLEXOPTPAYOUTUNDERLIER: FixTag = FixTag { id: "2222", datatype: value_types::STRING};
-pub const LEGCOMPLEXOPTPAYOUTAMOUNT: FixTag = FixTag { id: "2223", datatype: value_types::AMT};
-pub const LEGCOMPLEXOPTPAYOUTPERCENTAGE: FixTag = FixTag { id: "2224", datatype: value_types::PERCENTAGE};
-pub const LEGCOMPLEXOPTPAYOUTTIME: FixTag = FixTag { id: "2225", datatype: value_types::INT};
-pub const LEGCOMPLEXOPTPAYOUTCURRENCY: FixTag = FixTag { id: "2226", datatype: value_types::CURRENCY};
-pub const LEGCOMPLEXEVENTPRICE: FixTag = FixTag { id: "2227", datatype: value_types::PRICE};
-pub const LEGCOMPLEXEVENTPRICEPERCENTAGE: FixTag = FixTag { id: "2228", datatype: value_types::PERCENTAGE};
-pub const LEGCOMPLEXEVENTPRICEBOUNDARYMETHOD: FixTag = FixTag { id: "2229", datatype: value_types::INT};
-pub const LEGCOMPLEXEVENTPRICEBOUNDARYPRECISION: FixTag = FixTag { id: "2230", datatype: value_types::PERCENTAGE};
-pub const LEGCOMPLEXEVENTPRICETIMETYPE: FixTag = FixTag { id: "2231", datatype: value_types::INT};
-pub const LEGCOMPLEXEVENTCONDITION: FixTag = FixTag { id: "2232", datatype: value_types::INT};
-pub const LEGCOMPLEXEVENTCURRENCYONE: FixTag = FixTag { id: "2233", datatype: value_types::CURRENCY};
-pub const LEGCOMPLEXEVENTCURRENCYTWO: FixTag = FixTag { id: "2234", datatype: value_types::CURRENCY};
-pub const LEGCOMPLEXEVENTQUOTEBASIS: FixTag = FixTag { id: "2235", datatype: value_types::INT};
-pub const LEGCOMPLEXEVENTFIXEDFXRATE: FixTag = FixTag { id: "2236", datatype: value_types::FLOAT};
-pub const LEGCOMPLEXEVENTDETERMINATIONMETHOD: FixTag = FixTag { id: "2237", datatype: value_types::STRING};
-pub const LEGCOMPLEXEVENTCALCULATIONAGENT: FixTag = FixTag { id: "2238", datatype: value_types::INT};
-pub const LEGCOMPLEXEVENTSTRIKEPRICE: FixTag = FixTag { id: "2239", datatype: value_types::PRICE};
-pub const LEGCOMPLEXEVENTSTRIKEFACTOR: FixTag = FixTag { id: "2240", datatype: value_types::FLOAT};
-pub const LEGCOMPLEXEVENTSTRIKENUMBEROFOPTIONS: FixTag = FixTag { id: "2241", datatype: value_types::INT};
-pub const LEGCOMPLEXEVENTCREDITEVENTSXIDREF: FixTag = FixTag { id: "2242", datatype: value_types::XIDREF};
-pub const LEGCOMPLEXEVENTCREDITEVENTNOTIFYINGPARTY: FixTag = FixTag { id: "2243", datatype: value_types::INT};
-pub const LEGCOMPLEXEVENTCREDITEVENTBUSINESSCENTER: FixTag = FixTag { id: "2244", datatype: value_types::STRING};
-pub const LEGCOMPLEXEVENTCREDITEVENTSTANDARDSOURCES: FixTag = FixTag { id: "2245", datatype: value_types::BOOLEAN};
-pub const LEGCOMPLEXEVENTCREDITEVENTMINIMUMSOURCES: FixTag = FixTag { id: "2246", datatype: value_types::INT};
-pub const LEGCOMPLEXEVENTENDTIME: FixTag = FixTag { id: "2247", datatype: value_types::UTC_TIME_ONLY};
-pub const LEGCOMPLEXEVENTXID: FixTag = FixTag { id: "2248", datatype: value_types::XID};
-pub const LEGCOMPLEXEVENTXIDREF: FixTag = FixTag { id: "2249", datatype: value_types::XIDREF};
-pub const NOLEGCOMPLEXEVENTDATES: FixTag = FixTag { id: "2250", datatype: value_types::NUM_IN_GROUP };
-pub const LEGCOMPLEXEVENTSTARTDATE: FixTag = FixTag { id: "2251", datatype: value_types::UTC_DATE_ONLY};
-pub const LEGCOMPLEXEVENTENDDATE: FixTag = FixTag { id: "2252", datatype: value_types::UTC_DATE_ONLY};
-pub const NOLEGCOMPLEXEVENTTIMES: FixTag = FixTag { id: "2253", datatype: value_types::NUM_IN_GROUP };
-pub const NOLEGINSTRUMENTPARTIES: FixTag = FixTag { id: "2254", datatype: value_types::NUM_IN_GROUP };
-pub const LEGINSTRUMENTPARTYID: FixTag = FixTag { id: "2255", datatype: value_types::STRING};
-pub const LEGINSTRUMENTPARTYIDSOURCE: FixTag = FixTag { id: "2256", datatype: value_types::CHAR};
-pub const LEGINSTRUMENTPARTYROLE: FixTag = FixTag { id: "2257", datatype: value_types::INT};
-pub const NOLEGINSTRUMENTPARTYSUBIDS: FixTag = FixTag { id: "2258", datatype: value_types::NUM_IN_GROUP };
-pub const LEGINSTRUMENTPARTYSUBID: FixTag = FixTag { id: "2259", datatype: value_types::STRING};
-pub const LEGINSTRUMENTPARTYSUBIDTYPE: FixTag = FixTag { id: "2260", datatype: value_types::INT};
-pub const UNDERLYINGCOMPLEXOPTPAYOUTPAYSIDE: FixTag = FixTag { id: "2261", datatype: value_types::INT};
-pub const UNDERLYINGCOMPLEXOPTPAYOUTRECEIVESIDE: FixTag = FixTag { id: "2262", datatype: value_types::INT};
-pub const UNDERLYINGCOMPLEXOPTPAYOUTUNDERLIER: FixTag = FixTag { id: "2263", datatype: value_types::STRING};
-pub const UNDERLYINGCOMPLEXOPTPAYOUTPERCENTAGE: FixTag = FixTag { id: "2264", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGCOMPLEXOPTPAYOUTTIME: FixTag = FixTag { id: "2265", datatype: value_types::INT};
-pub const UNDERLYINGCOMPLEXOPTPAYOUTCURRENCY: FixTag = FixTag { id: "2266", datatype: value_types::CURRENCY};
-pub const UNDERLYINGCOMPLEXEVENTPRICEPERCENTAGE: FixTag = FixTag { id: "2267", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGCOMPLEXEVENTCURRENCYONE: FixTag = FixTag { id: "2268", datatype: value_types::CURRENCY};
-pub const UNDERLYINGCOMPLEXEVENTCURRENCYTWO: FixTag = FixTag { id: "2269", datatype: value_types::CURRENCY};
-pub const UNDERLYINGCOMPLEXEVENTQUOTEBASIS: FixTag = FixTag { id: "2270", datatype: value_types::INT};
-pub const UNDERLYINGCOMPLEXEVENTFIXEDFXRATE: FixTag = FixTag { id: "2271", datatype: value_types::FLOAT};
-pub const UNDERLYINGCOMPLEXEVENTDETERMINATIONMETHOD: FixTag = FixTag { id: "2272", datatype: value_types::STRING};
-pub const UNDERLYINGCOMPLEXEVENTCALCULATIONAGENT: FixTag = FixTag { id: "2273", datatype: value_types::INT};
-pub const UNDERLYINGCOMPLEXEVENTSTRIKEPRICE: FixTag = FixTag { id: "2274", datatype: value_types::PRICE};
-pub const UNDERLYINGCOMPLEXEVENTSTRIKEFACTOR: FixTag = FixTag { id: "2275", datatype: value_types::FLOAT};
-pub const UNDERLYINGCOMPLEXEVENTSTRIKENUMBEROFOPTIONS: FixTag = FixTag { id: "2276", datatype: value_types::INT};
-pub const UNDERLYINGCOMPLEXEVENTCREDITEVENTSXIDREF: FixTag = FixTag { id: "2277", datatype: value_types::XIDREF};
-pub const UNDERLYINGCOMPLEXEVENTCREDITEVENTNOTIFYINGPARTY: FixTag = FixTag { id: "2278", datatype: value_types::INT};
-pub const UNDERLYINGCOMPLEXEVENTCREDITEVENTBUSINESSCENTER: FixTag = FixTag { id: "2279", datatype: value_types::STRING};
-pub const UNDERLYINGCOMPLEXEVENTCREDITEVENTSTANDARDSOURCES: FixTag = FixTag { id: "2280", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGCOMPLEXEVENTCREDITEVENTMINIMUMSOURCES: FixTag = FixTag { id: "2281", datatype: value_types::INT};
-pub const UNDERLYINGCOMPLEXEVENTXID: FixTag = FixTag { id: "2282", datatype: value_types::XID};
-pub const UNDERLYINGCOMPLEXEVENTXIDREF: FixTag = FixTag { id: "2283", datatype: value_types::XIDREF};
-pub const UNDERLYINGSETTLRATEINDEX: FixTag = FixTag { id: "2284", datatype: value_types::STRING};
-pub const UNDERLYINGSETTLRATEINDEXLOCATION: FixTag = FixTag { id: "2285", datatype: value_types::STRING};
-pub const UNDERLYINGOPTIONEXPIRATIONDESC: FixTag = FixTag { id: "2286", datatype: value_types::STRING};
-pub const ENCODEDUNDERLYINGOPTIONEXPIRATIONDESCLEN: FixTag = FixTag { id: "2287", datatype: value_types::LENGTH};
-pub const ENCODEDUNDERLYINGOPTIONEXPIRATIONDESC: FixTag = FixTag { id: "2288", datatype: value_types::DATA};
-pub const UNDERLYINGSWAPSUBCLASS: FixTag = FixTag { id: "2289", datatype: value_types::STRING};
-pub const UNDERLYINGSTRIKEUNITOFMEASURE: FixTag = FixTag { id: "2290", datatype: value_types::STRING};
-pub const UNDERLYINGSTRIKEINDEX: FixTag = FixTag { id: "2291", datatype: value_types::STRING};
-pub const UNDERLYINGSTRIKEINDEXSPREAD: FixTag = FixTag { id: "2292", datatype: value_types::PRICE_OFFSET};
-pub const UNDERLYINGVALUATIONSOURCE: FixTag = FixTag { id: "2293", datatype: value_types::STRING};
-pub const UNDERLYINGVALUATIONREFERENCEMODEL: FixTag = FixTag { id: "2294", datatype: value_types::STRING};
-pub const UNDERLYINGSTRATEGYTYPE: FixTag = FixTag { id: "2295", datatype: value_types::STRING};
-pub const UNDERLYINGCOMMONPRICINGINDICATOR: FixTag = FixTag { id: "2296", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGSETTLDISRUPTIONPROVISION: FixTag = FixTag { id: "2297", datatype: value_types::INT};
-pub const UNDERLYINGINSTRUMENTROUNDINGDIRECTION: FixTag = FixTag { id: "2298", datatype: value_types::CHAR};
-pub const UNDERLYINGINSTRUMENTROUNDINGPRECISION: FixTag = FixTag { id: "2299", datatype: value_types::INT};
-pub const ALLOCGROSSTRADEAMT: FixTag = FixTag { id: "2300", datatype: value_types::AMT};
-pub const LASTQTYCHANGED: FixTag = FixTag { id: "2301", datatype: value_types::QTY};
-pub const TRADEVERSION: FixTag = FixTag { id: "2302", datatype: value_types::STRING};
-pub const HISTORICALREPORTINDICATOR: FixTag = FixTag { id: "2303", datatype: value_types::BOOLEAN};
-pub const NOASSETATTRIBUTES: FixTag = FixTag { id: "2304", datatype: value_types::NUM_IN_GROUP };
-pub const ASSETATTRIBUTETYPE: FixTag = FixTag { id: "2305", datatype: value_types::STRING};
-pub const ASSETATTRIBUTEVALUE: FixTag = FixTag { id: "2306", datatype: value_types::STRING};
-pub const ASSETATTRIBUTELIMIT: FixTag = FixTag { id: "2307", datatype: value_types::STRING};
-pub const NOLEGASSETATTRIBUTES: FixTag = FixTag { id: "2308", datatype: value_types::NUM_IN_GROUP };
-pub const LEGASSETATTRIBUTETYPE: FixTag = FixTag { id: "2309", datatype: value_types::STRING};
-pub const LEGASSETATTRIBUTEVALUE: FixTag = FixTag { id: "2310", datatype: value_types::STRING};
-pub const LEGASSETATTRIBUTELIMIT: FixTag = FixTag { id: "2311", datatype: value_types::STRING};
-pub const NOUNDERLYINGASSETATTRIBUTES: FixTag = FixTag { id: "2312", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGASSETATTRIBUTETYPE: FixTag = FixTag { id: "2313", datatype: value_types::STRING};
-pub const UNDERLYINGASSETATTRIBUTEVALUE: FixTag = FixTag { id: "2314", datatype: value_types::STRING};
-pub const UNDERLYINGASSETATTRIBUTELIMIT: FixTag = FixTag { id: "2315", datatype: value_types::STRING};
-pub const RISKLIMITREPORTSTATUS: FixTag = FixTag { id: "2316", datatype: value_types::INT};
-pub const RISKLIMITREPORTREJECTREASON: FixTag = FixTag { id: "2317", datatype: value_types::INT};
-pub const RISKLIMITCHECKREQUESTID: FixTag = FixTag { id: "2318", datatype: value_types::STRING};
-pub const RISKLIMITCHECKID: FixTag = FixTag { id: "2319", datatype: value_types::STRING};
-pub const RISKLIMITCHECKTRANSTYPE: FixTag = FixTag { id: "2320", datatype: value_types::INT};
-pub const RISKLIMITCHECKTYPE: FixTag = FixTag { id: "2321", datatype: value_types::INT};
-pub const RISKLIMITCHECKREQUESTREFID: FixTag = FixTag { id: "2322", datatype: value_types::INT};
-pub const RISKLIMITCHECKREQUESTTYPE: FixTag = FixTag { id: "2323", datatype: value_types::INT};
-pub const RISKLIMITCHECKAMOUNT: FixTag = FixTag { id: "2324", datatype: value_types::AMT};
-pub const RISKLIMITCHECKREQUESTSTATUS: FixTag = FixTag { id: "2325", datatype: value_types::INT};
-pub const RISKLIMITCHECKREQUESTRESULT: FixTag = FixTag { id: "2326", datatype: value_types::INT};
-pub const RISKLIMITAPPROVEDAMOUNT: FixTag = FixTag { id: "2327", datatype: value_types::AMT};
-pub const PARTYACTIONREQUESTID: FixTag = FixTag { id: "2328", datatype: value_types::STRING};
-pub const PARTYACTIONTYPE: FixTag = FixTag { id: "2329", datatype: value_types::INT};
-pub const APPLTESTMESSAGEINDICATOR: FixTag = FixTag { id: "2330", datatype: value_types::BOOLEAN};
-pub const PARTYACTIONREPORTID: FixTag = FixTag { id: "2331", datatype: value_types::STRING};
-pub const PARTYACTIONRESPONSE: FixTag = FixTag { id: "2332", datatype: value_types::INT};
-pub const PARTYACTIONREJECTREASON: FixTag = FixTag { id: "2333", datatype: value_types::INT};
-pub const REFRISKLIMITCHECKID: FixTag = FixTag { id: "2334", datatype: value_types::STRING};
-pub const REFRISKLIMITCHECKIDTYPE: FixTag = FixTag { id: "2335", datatype: value_types::INT};
-pub const RISKLIMITVELOCITYPERIOD: FixTag = FixTag { id: "2336", datatype: value_types::INT};
-pub const RISKLIMITVELOCITYUNIT: FixTag = FixTag { id: "2337", datatype: value_types::STRING};
-pub const REQUESTINGPARTYROLEQUALIFIER: FixTag = FixTag { id: "2338", datatype: value_types::INT};
-pub const RISKLIMITCHECKMODELTYPE: FixTag = FixTag { id: "2339", datatype: value_types::INT};
-pub const EVENTMONTH_YEAR: FixTag = FixTag { id: "2340", datatype: value_types::MONTH_YEAR};
-pub const LEGEVENTMONTH_YEAR: FixTag = FixTag { id: "2341", datatype: value_types::MONTH_YEAR};
-pub const UNDERLYINGEVENTMONTH_YEAR: FixTag = FixTag { id: "2342", datatype: value_types::MONTH_YEAR};
-pub const RISKLIMITCHECKSTATUS: FixTag = FixTag { id: "2343", datatype: value_types::INT};
-pub const SIDERISKLIMITCHECKSTATUS: FixTag = FixTag { id: "2344", datatype: value_types::INT};
-pub const NOENTITLEMENTTYPES: FixTag = FixTag { id: "2345", datatype: value_types::NUM_IN_GROUP };
-pub const LEGMIDPX: FixTag = FixTag { id: "2346", datatype: value_types::PRICE};
-pub const REGULATORYTRANSACTIONTYPE: FixTag = FixTag { id: "2347", datatype: value_types::INT};
-pub const LEGASSETGROUP: FixTag = FixTag { id: "2348", datatype: value_types::INT};
-pub const PRICEPRECISION: FixTag = FixTag { id: "2349", datatype: value_types::INT};
-pub const COLLATERALPORTFOLIOID: FixTag = FixTag { id: "2350", datatype: value_types::STRING};
-pub const ENCODEDCOMPLIANCETEXTLEN: FixTag = FixTag { id: "2351", datatype: value_types::LENGTH};
-pub const ENCODEDCOMPLIANCETEXT: FixTag = FixTag { id: "2352", datatype: value_types::DATA};
-pub const TRADINGUNITPERIODMULTIPLIER: FixTag = FixTag { id: "2353", datatype: value_types::INT};
-pub const LEGTRADINGUNITPERIODMULTIPLIER: FixTag = FixTag { id: "2354", datatype: value_types::INT};
-pub const PARTYRISKLIMITSTATUS: FixTag = FixTag { id: "2355", datatype: value_types::INT};
-pub const REMUNERATIONINDICATOR: FixTag = FixTag { id: "2356", datatype: value_types::INT};
-pub const LEGTOTALTRADEQTY: FixTag = FixTag { id: "2357", datatype: value_types::QTY};
-pub const LEGLASTMULTIPLIEDQTY: FixTag = FixTag { id: "2358", datatype: value_types::QTY};
-pub const LEGTOTALGROSSTRADEAMT: FixTag = FixTag { id: "2359", datatype: value_types::AMT};
-pub const LEGTOTALTRADEMULTIPLIEDQTY: FixTag = FixTag { id: "2360", datatype: value_types::QTY};
-pub const COMPRESSIONGROUPID: FixTag = FixTag { id: "2361", datatype: value_types::STRING};
-pub const SELFMATCHPREVENTIONID: FixTag = FixTag { id: "2362", datatype: value_types::STRING};
-pub const UNDERLYINGTRADINGUNITPERIODMULTIPLIER: FixTag = FixTag { id: "2363", datatype: value_types::INT};
-pub const POSREPORTACTION: FixTag = FixTag { id: "2364", datatype: value_types::INT};
-pub const SETTLFORWARDPOINTS: FixTag = FixTag { id: "2365", datatype: value_types::PRICE_OFFSET};
-pub const SETTLPRICEFXRATECALC: FixTag = FixTag { id: "2366", datatype: value_types::CHAR};
-pub const TOTALTRADEQTY: FixTag = FixTag { id: "2367", datatype: value_types::QTY};
-pub const LASTMULTIPLIEDQTY: FixTag = FixTag { id: "2368", datatype: value_types::QTY};
-pub const TOTALGROSSTRADEAMT: FixTag = FixTag { id: "2369", datatype: value_types::AMT};
-pub const TOTALTRADEMULTIPLIEDQTY: FixTag = FixTag { id: "2370", datatype: value_types::QTY};
-pub const ENCODEDTRADECONTINUATIONTEXT: FixTag = FixTag { id: "2371", datatype: value_types::DATA};
-pub const ENCODEDTRADECONTINUATIONTEXTLEN: FixTag = FixTag { id: "2372", datatype: value_types::LENGTH};
-pub const INTRAFIRMTRADEINDICATOR: FixTag = FixTag { id: "2373", datatype: value_types::BOOLEAN};
-pub const TRADECONTINUATIONTEXT: FixTag = FixTag { id: "2374", datatype: value_types::STRING};
-pub const TAXONOMYTYPE: FixTag = FixTag { id: "2375", datatype: value_types::CHAR};
-pub const PARTYROLEQUALIFIER: FixTag = FixTag { id: "2376", datatype: value_types::INT};
-pub const DERIVATIVEINSTRUMENTPARTYROLEQUALIFIER: FixTag = FixTag { id: "2377", datatype: value_types::INT};
-pub const INSTRUMENTPARTYROLEQUALIFIER: FixTag = FixTag { id: "2378", datatype: value_types::INT};
-pub const LEGINSTRUMENTPARTYROLEQUALIFIER: FixTag = FixTag { id: "2379", datatype: value_types::INT};
-pub const LEGPROVISIONPARTYROLEQUALIFIER: FixTag = FixTag { id: "2380", datatype: value_types::INT};
-pub const NESTED2PARTYROLEQUALIFIER: FixTag = FixTag { id: "2381", datatype: value_types::INT};
-pub const NESTED3PARTYROLEQUALIFIER: FixTag = FixTag { id: "2382", datatype: value_types::INT};
-pub const NESTED4PARTYROLEQUALIFIER: FixTag = FixTag { id: "2383", datatype: value_types::INT};
-pub const NESTEDPARTYROLEQUALIFIER: FixTag = FixTag { id: "2384", datatype: value_types::INT};
-pub const PROVISIONPARTYROLEQUALIFIER: FixTag = FixTag { id: "2385", datatype: value_types::INT};
-pub const REQUESTEDPARTYROLEQUALIFIER: FixTag = FixTag { id: "2386", datatype: value_types::INT};
-pub const TRADECONTINGENCY: FixTag = FixTag { id: "2387", datatype: value_types::INT};
-pub const ROOTPARTYROLEQUALIFIER: FixTag = FixTag { id: "2388", datatype: value_types::INT};
-pub const SETTLPARTYROLEQUALIFIER: FixTag = FixTag { id: "2389", datatype: value_types::INT};
-pub const TRADECONFIRMATIONREFERENCEID: FixTag = FixTag { id: "2390", datatype: value_types::STRING};
-pub const UNDERLYINGINSTRUMENTPARTYROLEQUALIFIER: FixTag = FixTag { id: "2391", datatype: value_types::INT};
-pub const ALLOCREFRISKLIMITCHECKID: FixTag = FixTag { id: "2392", datatype: value_types::STRING};
-pub const ALLOCREFRISKLIMITCHECKIDTYPE: FixTag = FixTag { id: "2393", datatype: value_types::INT};
-pub const LIMITUTILIZATIONAMT: FixTag = FixTag { id: "2394", datatype: value_types::AMT};
-pub const LIMITAMT: FixTag = FixTag { id: "2395", datatype: value_types::AMT};
-pub const LIMITROLE: FixTag = FixTag { id: "2396", datatype: value_types::INT};
-pub const REGULATORYTRADEIDSCOPE: FixTag = FixTag { id: "2397", datatype: value_types::INT};
-pub const SIDEREGULATORYTRADEIDSCOPE: FixTag = FixTag { id: "2398", datatype: value_types::INT};
-pub const ALLOCREGULATORYTRADEIDSCOPE: FixTag = FixTag { id: "2399", datatype: value_types::INT};
-pub const EFFECTIVEBUSINESSDATE: FixTag = FixTag { id: "2400", datatype: value_types::LOCAL_MKT_DATE};
-pub const LISTMANUALORDERINDICATOR: FixTag = FixTag { id: "2401", datatype: value_types::BOOLEAN};
-pub const ENTITLEMENTSUBTYPE: FixTag = FixTag { id: "2402", datatype: value_types::INT};
-pub const QUOTEMODELTYPE: FixTag = FixTag { id: "2403", datatype: value_types::INT};
-pub const COMPLIANCETEXT: FixTag = FixTag { id: "2404", datatype: value_types::STRING};
-pub const EXECMETHOD: FixTag = FixTag { id: "2405", datatype: value_types::INT};
-pub const ALLOCREGULATORYLEGREFID: FixTag = FixTag { id: "2406", datatype: value_types::STRING};
-pub const COMPLEXEVENTSPOTRATE: FixTag = FixTag { id: "2407", datatype: value_types::PRICE};
-pub const COMPLEXEVENTFORWARDPOINTS: FixTag = FixTag { id: "2408", datatype: value_types::PRICE_OFFSET};
-pub const LEGCOMPLEXEVENTSPOTRATE: FixTag = FixTag { id: "2409", datatype: value_types::PRICE};
-pub const LEGCOMPLEXEVENTFORWARDPOINTS: FixTag = FixTag { id: "2410", datatype: value_types::PRICE_OFFSET};
-pub const REGULATORYLEGREFID: FixTag = FixTag { id: "2411", datatype: value_types::STRING};
-pub const RATESOURCEREFEREMCEPAGEHEADING: FixTag = FixTag { id: "2412", datatype: value_types::STRING};
-pub const RELATEDTOSECURITYID: FixTag = FixTag { id: "2413", datatype: value_types::STRING};
-pub const RELATEDTOSECURITYIDSOURCE: FixTag = FixTag { id: "2414", datatype: value_types::STRING};
-pub const RELATEDTOSTREAMXIDREF: FixTag = FixTag { id: "2415", datatype: value_types::XIDREF};
-pub const SIDEREGULATORYLEGREFID: FixTag = FixTag { id: "2416", datatype: value_types::STRING};
-pub const RELATEDTODIVIDENDPERIODXIDREF: FixTag = FixTag { id: "2417", datatype: value_types::XIDREF};
-pub const FIRMTRADEEVENTID: FixTag = FixTag { id: "2418", datatype: value_types::STRING};
-pub const UNDERLYINGCOMPLEXEVENTSPOTRATE: FixTag = FixTag { id: "2419", datatype: value_types::PRICE};
-pub const UNDERLYINGCOMPLEXEVENTFORWARDPOINTS: FixTag = FixTag { id: "2420", datatype: value_types::PRICE_OFFSET};
-pub const FILLREFID: FixTag = FixTag { id: "2421", datatype: value_types::STRING};
-pub const ORDERREQUESTID: FixTag = FixTag { id: "2422", datatype: value_types::INT};
-pub const MASSORDERREQUESTID: FixTag = FixTag { id: "2423", datatype: value_types::STRING};
-pub const MASSORDERREPORTID: FixTag = FixTag { id: "2424", datatype: value_types::STRING};
-pub const MASSORDERREQUESTSTATUS: FixTag = FixTag { id: "2425", datatype: value_types::INT};
-pub const MASSORDERREQUESTRESULT: FixTag = FixTag { id: "2426", datatype: value_types::INT};
-pub const ORDERRESPONSELEVEL: FixTag = FixTag { id: "2427", datatype: value_types::INT};
-pub const NOORDERENTRIES: FixTag = FixTag { id: "2428", datatype: value_types::NUM_IN_GROUP };
-pub const ORDERENTRYACTION: FixTag = FixTag { id: "2429", datatype: value_types::CHAR};
-pub const ORDERENTRYID: FixTag = FixTag { id: "2430", datatype: value_types::INT};
-pub const EXECTYPEREASON: FixTag = FixTag { id: "2431", datatype: value_types::INT};
-pub const TOTNOORDERENTRIES: FixTag = FixTag { id: "2432", datatype: value_types::INT};
-pub const NOTARGETPARTYSUBIDS: FixTag = FixTag { id: "2433", datatype: value_types::NUM_IN_GROUP };
-pub const TARGETPARTYSUBID: FixTag = FixTag { id: "2434", datatype: value_types::STRING};
-pub const TARGETPARTYSUBIDTYPE: FixTag = FixTag { id: "2435", datatype: value_types::INT};
-pub const TRANSFERINSTRUCTIONID: FixTag = FixTag { id: "2436", datatype: value_types::STRING};
-pub const TRANSFERID: FixTag = FixTag { id: "2437", datatype: value_types::STRING};
-pub const TRANSFERREPORTID: FixTag = FixTag { id: "2438", datatype: value_types::STRING};
-pub const TRANSFERTRANSTYPE: FixTag = FixTag { id: "2439", datatype: value_types::INT};
-pub const TRANSFERTYPE: FixTag = FixTag { id: "2440", datatype: value_types::INT};
-pub const TRANSFERSCOPE: FixTag = FixTag { id: "2441", datatype: value_types::INT};
-pub const TRANSFERSTATUS: FixTag = FixTag { id: "2442", datatype: value_types::INT};
-pub const TRANSFERREJECTREASON: FixTag = FixTag { id: "2443", datatype: value_types::INT};
-pub const TRANSFERREPORTTYPE: FixTag = FixTag { id: "2444", datatype: value_types::INT};
-pub const AGGRESSORTIME: FixTag = FixTag { id: "2445", datatype: value_types::UTC_TIMESTAMP};
-pub const AGGRESSORSIDE: FixTag = FixTag { id: "2446", datatype: value_types::CHAR};
-pub const FASTMARKETINDICATOR: FixTag = FixTag { id: "2447", datatype: value_types::BOOLEAN};
-pub const LINKAGEHANDLINGINDICATOR: FixTag = FixTag { id: "2448", datatype: value_types::BOOLEAN};
-pub const NUMBEROFBUYORDERS: FixTag = FixTag { id: "2449", datatype: value_types::INT};
-pub const NUMBEROFSELLORDERS: FixTag = FixTag { id: "2450", datatype: value_types::INT};
-pub const SETTLPRICEDETERMINATIONMETHOD: FixTag = FixTag { id: "2451", datatype: value_types::INT};
-pub const MDSTATISTICREQID: FixTag = FixTag { id: "2452", datatype: value_types::STRING};
-pub const MDSTATISTICRPTID: FixTag = FixTag { id: "2453", datatype: value_types::STRING};
-pub const MDSTATISTICNAME: FixTag = FixTag { id: "2454", datatype: value_types::STRING};
-pub const MDSTATISTICDESC: FixTag = FixTag { id: "2455", datatype: value_types::STRING};
-pub const MDSTATISTICTYPE: FixTag = FixTag { id: "2456", datatype: value_types::INT};
-pub const MDSTATISTICSCOPE: FixTag = FixTag { id: "2457", datatype: value_types::INT};
-pub const MDSTATISTICSUBSCOPE: FixTag = FixTag { id: "2458", datatype: value_types::INT};
-pub const MDSTATISTICSCOPETYPE: FixTag = FixTag { id: "2459", datatype: value_types::INT};
-pub const MDSTATISTICFREQUENCYPERIOD: FixTag = FixTag { id: "2460", datatype: value_types::INT};
-pub const MDSTATISTICFREQUENCYUNIT: FixTag = FixTag { id: "2461", datatype: value_types::INT};
-pub const MDSTATISTICDELAYPERIOD: FixTag = FixTag { id: "2462", datatype: value_types::INT};
-pub const MDSTATISTICDELAYUNIT: FixTag = FixTag { id: "2463", datatype: value_types::INT};
-pub const MDSTATISTICINTERVALTYPE: FixTag = FixTag { id: "2464", datatype: value_types::INT};
-pub const MDSTATISTICINTERVALTYPEUNIT: FixTag = FixTag { id: "2465", datatype: value_types::STRING};
-pub const MDSTATISTICINTERVALPERIOD: FixTag = FixTag { id: "2466", datatype: value_types::INT};
-pub const MDSTATISTICINTERVALUNIT: FixTag = FixTag { id: "2467", datatype: value_types::INT};
-pub const MDSTATISTICSTARTDATE: FixTag = FixTag { id: "2468", datatype: value_types::UTC_TIMESTAMP};
-pub const MDSTATISTICENDDATE: FixTag = FixTag { id: "2469", datatype: value_types::UTC_TIMESTAMP};
-pub const MDSTATISTICSTARTTIME: FixTag = FixTag { id: "2470", datatype: value_types::UTC_TIME_ONLY};
-pub const MDSTATISTICENDTIME: FixTag = FixTag { id: "2471", datatype: value_types::UTC_TIME_ONLY};
-pub const MDSTATISTICRATIOTYPE: FixTag = FixTag { id: "2472", datatype: value_types::INT};
-pub const MDSTATISTICREQUESTRESULT: FixTag = FixTag { id: "2473", datatype: value_types::INT};
-pub const NOMDSTATISTICS: FixTag = FixTag { id: "2474", datatype: value_types::NUM_IN_GROUP };
-pub const MDSTATISTICID: FixTag = FixTag { id: "2475", datatype: value_types::STRING};
-pub const MDSTATISTICTIME: FixTag = FixTag { id: "2476", datatype: value_types::UTC_TIMESTAMP};
-pub const MDSTATISTICSTATUS: FixTag = FixTag { id: "2477", datatype: value_types::INT};
-pub const MDSTATISTICVALUE: FixTag = FixTag { id: "2478", datatype: value_types::FLOAT};
-pub const MDSTATISTICVALUETYPE: FixTag = FixTag { id: "2479", datatype: value_types::INT};
-pub const MDSTATISTICVALUEUNIT: FixTag = FixTag { id: "2480", datatype: value_types::INT};
-pub const ENCODEDMDSTATISTICDESCLEN: FixTag = FixTag { id: "2481", datatype: value_types::LENGTH};
-pub const ENCODEDMDSTATISTICDESC: FixTag = FixTag { id: "2482", datatype: value_types::DATA};
-pub const ALLOCRISKLIMITCHECKSTATUS: FixTag = FixTag { id: "2483", datatype: value_types::INT};
-pub const FIRMTRANSACTIONID: FixTag = FixTag { id: "2484", datatype: value_types::STRING};
-pub const TRANSACTIONID: FixTag = FixTag { id: "2485", datatype: value_types::STRING};
-pub const WIREREFERENCE: FixTag = FixTag { id: "2486", datatype: value_types::STRING};
-pub const COLLRPTREJECTREASON: FixTag = FixTag { id: "2487", datatype: value_types::INT};
-pub const COLLRPTSTATUS: FixTag = FixTag { id: "2488", datatype: value_types::INT};
-pub const PACKAGEID: FixTag = FixTag { id: "2489", datatype: value_types::STRING};
-pub const TRADENUMBER: FixTag = FixTag { id: "2490", datatype: value_types::INT};
-pub const UNDERLYINGASSETGROUP: FixTag = FixTag { id: "2491", datatype: value_types::INT};
-pub const LEGDIFFERENTIALPRICE: FixTag = FixTag { id: "2492", datatype: value_types::PRICE_OFFSET};
-pub const ENCODEDLEGDOCUMENTATIONTEXT: FixTag = FixTag { id: "2493", datatype: value_types::DATA};
-pub const ENCODEDLEGDOCUMENTATIONTEXTLEN: FixTag = FixTag { id: "2494", datatype: value_types::LENGTH};
-pub const LEGAGREEMENTCURRENCY: FixTag = FixTag { id: "2495", datatype: value_types::CURRENCY};
-pub const LEGAGREEMENTDATE: FixTag = FixTag { id: "2496", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGAGREEMENTDESC: FixTag = FixTag { id: "2497", datatype: value_types::STRING};
-pub const LEGAGREEMENTID: FixTag = FixTag { id: "2498", datatype: value_types::STRING};
-pub const LEGAGREEMENTVERSION: FixTag = FixTag { id: "2499", datatype: value_types::STRING};
-pub const LEGBROKERCONFIRMATIONDESC: FixTag = FixTag { id: "2500", datatype: value_types::STRING};
-pub const LEGCREDITSUPPORTAGREEMENTDATE: FixTag = FixTag { id: "2501", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGCREDITSUPPORTAGREEMENTDESC: FixTag = FixTag { id: "2502", datatype: value_types::STRING};
-pub const LEGCREDITSUPPORTAGREEMENTID: FixTag = FixTag { id: "2503", datatype: value_types::STRING};
-pub const LEGDELIVERYTYPE: FixTag = FixTag { id: "2504", datatype: value_types::INT};
-pub const LEGDOCUMENTATIONTEXT: FixTag = FixTag { id: "2505", datatype: value_types::STRING};
-pub const LEGENDDATE: FixTag = FixTag { id: "2506", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGGOVERNINGLAW: FixTag = FixTag { id: "2507", datatype: value_types::STRING};
-pub const LEGMARGINRATIO: FixTag = FixTag { id: "2508", datatype: value_types::PERCENTAGE};
-pub const LEGMASTERCONFIRMATIONANNEXDATE: FixTag = FixTag { id: "2509", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGMASTERCONFIRMATIONDATE: FixTag = FixTag { id: "2510", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGMASTERCONFIRMATIONDESC: FixTag = FixTag { id: "2511", datatype: value_types::STRING};
-pub const LEGMASTERCONFIRMATIONANNEXDESC: FixTag = FixTag { id: "2512", datatype: value_types::STRING};
-pub const LEGSTARTDATE: FixTag = FixTag { id: "2513", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGTERMINATIONTYPE: FixTag = FixTag { id: "2514", datatype: value_types::INT};
-pub const ALLOCCALCULATEDCCYQTY: FixTag = FixTag { id: "2515", datatype: value_types::QTY};
-pub const COLLATERALREQUESTINSTRUCTION: FixTag = FixTag { id: "2516", datatype: value_types::STRING};
-pub const COLLATERALREQUESTLINKID: FixTag = FixTag { id: "2517", datatype: value_types::STRING};
-pub const COLLATERALREQUESTNUMBER: FixTag = FixTag { id: "2518", datatype: value_types::INT};
-pub const TOTNUMCOLLATERALREQUESTS: FixTag = FixTag { id: "2519", datatype: value_types::INT};
-pub const WARNINGTEXT: FixTag = FixTag { id: "2520", datatype: value_types::STRING};
-pub const ENCODEDWARNINGTEXT: FixTag = FixTag { id: "2521", datatype: value_types::DATA};
-pub const ENCODEDWARNINGTEXTLEN: FixTag = FixTag { id: "2522", datatype: value_types::LENGTH};
-pub const CROSSEDINDICATOR: FixTag = FixTag { id: "2523", datatype: value_types::INT};
-pub const TRADEREPORTINGINDICATOR: FixTag = FixTag { id: "2524", datatype: value_types::INT};
-pub const AFFILIATEDFIRMSTRADEINDICATOR: FixTag = FixTag { id: "2525", datatype: value_types::BOOLEAN};
-pub const INTERNATIONALSWAPINDICATOR: FixTag = FixTag { id: "2526", datatype: value_types::BOOLEAN};
-pub const MULTIASSETSWAPINDICATOR: FixTag = FixTag { id: "2527", datatype: value_types::BOOLEAN};
-pub const CLEARINGSETTLPRICE: FixTag = FixTag { id: "2528", datatype: value_types::PRICE};
-pub const NORELATIVEVALUES: FixTag = FixTag { id: "2529", datatype: value_types::NUM_IN_GROUP };
-pub const RELATIVEVALUETYPE: FixTag = FixTag { id: "2530", datatype: value_types::INT};
-pub const RELATIVEVALUE: FixTag = FixTag { id: "2531", datatype: value_types::FLOAT};
-pub const RELATIVEVALUESIDE: FixTag = FixTag { id: "2532", datatype: value_types::INT};
-pub const BIDSPREAD: FixTag = FixTag { id: "2533", datatype: value_types::FLOAT};
-pub const OFFERSPREAD: FixTag = FixTag { id: "2534", datatype: value_types::FLOAT};
-pub const MDREPORTEVENT: FixTag = FixTag { id: "2535", datatype: value_types::INT};
-pub const MDREPORTCOUNT: FixTag = FixTag { id: "2536", datatype: value_types::INT};
-pub const TOTNOMARKETSEGMENTREPORTS: FixTag = FixTag { id: "2537", datatype: value_types::INT};
-pub const TOTNOINSTRUMENTREPORTS: FixTag = FixTag { id: "2538", datatype: value_types::INT};
-pub const TOTNOPARTYDETAILREPORTS: FixTag = FixTag { id: "2539", datatype: value_types::INT};
-pub const TOTNOENTITLEMENTREPORTS: FixTag = FixTag { id: "2540", datatype: value_types::INT};
-pub const TOTNORISKLIMITREPORTS: FixTag = FixTag { id: "2541", datatype: value_types::INT};
-pub const MARKETSEGMENTSTATUS: FixTag = FixTag { id: "2542", datatype: value_types::INT};
-pub const MARKETSEGMENTTYPE: FixTag = FixTag { id: "2543", datatype: value_types::INT};
-pub const MARKETSEGMENTSUBTYPE: FixTag = FixTag { id: "2544", datatype: value_types::INT};
-pub const NORELATEDMARKETSEGMENTS: FixTag = FixTag { id: "2545", datatype: value_types::NUM_IN_GROUP };
-pub const RELATEDMARKETSEGMENTID: FixTag = FixTag { id: "2546", datatype: value_types::STRING};
-pub const MARKETSEGMENTRELATIONSHIP: FixTag = FixTag { id: "2547", datatype: value_types::INT};
-pub const NOAUCTIONTYPERULES: FixTag = FixTag { id: "2548", datatype: value_types::NUM_IN_GROUP };
-pub const AUCTIONTYPEPRODUCTCOMPLEX: FixTag = FixTag { id: "2549", datatype: value_types::STRING};
-pub const NOPRICERANGERULES: FixTag = FixTag { id: "2550", datatype: value_types::NUM_IN_GROUP };
-pub const STARTPRICERANGE: FixTag = FixTag { id: "2551", datatype: value_types::PRICE};
-pub const ENDPRICERANGE: FixTag = FixTag { id: "2552", datatype: value_types::PRICE};
-pub const PRICERANGEVALUE: FixTag = FixTag { id: "2553", datatype: value_types::PRICE};
-pub const PRICERANGEPERCENTAGE: FixTag = FixTag { id: "2554", datatype: value_types::PERCENTAGE};
-pub const PRICERANGEPRODUCTCOMPLEX: FixTag = FixTag { id: "2555", datatype: value_types::STRING};
-pub const PRICERANGERULEID: FixTag = FixTag { id: "2556", datatype: value_types::STRING};
-pub const FASTMARKETPERCENTAGE: FixTag = FixTag { id: "2557", datatype: value_types::PERCENTAGE};
-pub const NOQUOTESIZERULES: FixTag = FixTag { id: "2558", datatype: value_types::NUM_IN_GROUP };
-pub const QUOTESIDEINDICATOR: FixTag = FixTag { id: "2559", datatype: value_types::BOOLEAN};
-pub const NOFLEXPRODUCTELIGIBILITIES: FixTag = FixTag { id: "2560", datatype: value_types::NUM_IN_GROUP };
-pub const FLEXPRODUCTELIGIBILITYCOMPLEX: FixTag = FixTag { id: "2561", datatype: value_types::STRING};
-pub const NUMOFCOMPLEXINSTRUMENTS: FixTag = FixTag { id: "2562", datatype: value_types::INT};
-pub const MARKETDEPTHTIMEINTERVAL: FixTag = FixTag { id: "2563", datatype: value_types::INT};
-pub const MARKETDEPTHTIMEINTERVALUNIT: FixTag = FixTag { id: "2564", datatype: value_types::INT};
-pub const MDRECOVERYTIMEINTERVAL: FixTag = FixTag { id: "2565", datatype: value_types::INT};
-pub const MDRECOVERYTIMEINTERVALUNIT: FixTag = FixTag { id: "2566", datatype: value_types::INT};
-pub const PRIMARYSERVICELOCATIONID: FixTag = FixTag { id: "2567", datatype: value_types::STRING};
-pub const SECONDARYSERVICELOCATIONID: FixTag = FixTag { id: "2568", datatype: value_types::STRING};
-pub const MATCHRULEPRODUCTCOMPLEX: FixTag = FixTag { id: "2569", datatype: value_types::STRING};
-pub const CUSTOMERPRIORITY: FixTag = FixTag { id: "2570", datatype: value_types::INT};
-pub const TICKRULEPRODUCTCOMPLEX: FixTag = FixTag { id: "2571", datatype: value_types::STRING};
-pub const PREVIOUSADJUSTEDOPENINTEREST: FixTag = FixTag { id: "2572", datatype: value_types::AMT};
-pub const PREVIOUSUNADJUSTEDOPENINTEREST: FixTag = FixTag { id: "2573", datatype: value_types::AMT};
-pub const LOWEXERCISEPRICEOPTIONINDICATOR: FixTag = FixTag { id: "2574", datatype: value_types::BOOLEAN};
-pub const BLOCKTRADEELIGIBILITYINDICATOR: FixTag = FixTag { id: "2575", datatype: value_types::BOOLEAN};
-pub const INSTRUMENTPRICEPRECISION: FixTag = FixTag { id: "2576", datatype: value_types::INT};
-pub const STRIKEPRICEPRECISION: FixTag = FixTag { id: "2577", datatype: value_types::INT};
-pub const ORIGSTRIKEPRICE: FixTag = FixTag { id: "2578", datatype: value_types::PRICE};
-pub const SETTLSUBMETHOD: FixTag = FixTag { id: "2579", datatype: value_types::INT};
-pub const NOCLEARINGPRICEPARAMETERS: FixTag = FixTag { id: "2580", datatype: value_types::NUM_IN_GROUP };
-pub const BUSINESSDAYTYPE: FixTag = FixTag { id: "2581", datatype: value_types::INT};
-pub const CLEARINGPRICE_OFFSET: FixTag = FixTag { id: "2582", datatype: value_types::PRICE_OFFSET};
-pub const VEGAMULTIPLIER: FixTag = FixTag { id: "2583", datatype: value_types::FLOAT};
-pub const ANNUALTRADINGBUSINESSDAYS: FixTag = FixTag { id: "2584", datatype: value_types::INT};
-pub const TOTALTRADINGBUSINESSDAYS: FixTag = FixTag { id: "2585", datatype: value_types::INT};
-pub const TRADINGBUSINESSDAYS: FixTag = FixTag { id: "2586", datatype: value_types::INT};
-pub const REALIZEDVARIANCE: FixTag = FixTag { id: "2587", datatype: value_types::FLOAT};
-pub const STANDARDVARIANCE: FixTag = FixTag { id: "2588", datatype: value_types::FLOAT};
-pub const RELATEDCLOSEPRICE: FixTag = FixTag { id: "2589", datatype: value_types::PRICE};
-pub const OVERNIGHTINTERESTRATE: FixTag = FixTag { id: "2590", datatype: value_types::FLOAT};
-pub const ACCUMULATEDRETURNMODIFIEDVARIATIONMARGIN: FixTag = FixTag { id: "2591", datatype: value_types::FLOAT};
-pub const CALCULATIONMETHOD: FixTag = FixTag { id: "2592", datatype: value_types::INT};
-pub const NOORDERATTRIBUTES: FixTag = FixTag { id: "2593", datatype: value_types::NUM_IN_GROUP };
-pub const ORDERATTRIBUTETYPE: FixTag = FixTag { id: "2594", datatype: value_types::INT};
-pub const ORDERATTRIBUTEVALUE: FixTag = FixTag { id: "2595", datatype: value_types::STRING};
-pub const DELTACROSSED: FixTag = FixTag { id: "2596", datatype: value_types::BOOLEAN};
-pub const COMPLEXEVENTFUTURESPRICEVALUATION: FixTag = FixTag { id: "2597", datatype: value_types::BOOLEAN};
-pub const COMPLEXEVENTOPTIONSPRICEVALUATION: FixTag = FixTag { id: "2598", datatype: value_types::BOOLEAN};
-pub const COMPLEXEVENTPVFINALPRICEELECTIONFALLBACK: FixTag = FixTag { id: "2599", datatype: value_types::INT};
-pub const STRIKEINDEXCURVEPOINT: FixTag = FixTag { id: "2600", datatype: value_types::STRING};
-pub const STRIKEINDEXQUOTE: FixTag = FixTag { id: "2601", datatype: value_types::INT};
-pub const EXTRAORDINARYEVENTADJUSTMENTMETHOD: FixTag = FixTag { id: "2602", datatype: value_types::INT};
-pub const EXCHANGELOOKALIKE: FixTag = FixTag { id: "2603", datatype: value_types::BOOLEAN};
-pub const LEGSTRIKEINDEXCURVEPOINT: FixTag = FixTag { id: "2604", datatype: value_types::STRING};
-pub const LEGSTRIKEINDEXQUOTE: FixTag = FixTag { id: "2605", datatype: value_types::INT};
-pub const LEGEXTRAORDINARYEVENTADJUSTMENTMETHOD: FixTag = FixTag { id: "2606", datatype: value_types::INT};
-pub const LEGEXCHANGELOOKALIKE: FixTag = FixTag { id: "2607", datatype: value_types::BOOLEAN};
-pub const LEGCOMPLEXEVENTFUTURESPRICEVALUATION: FixTag = FixTag { id: "2608", datatype: value_types::BOOLEAN};
-pub const LEGCOMPLEXEVENTOPTIONSPRICEVALUATION: FixTag = FixTag { id: "2609", datatype: value_types::BOOLEAN};
-pub const LEGCOMPLEXEVENTPVFINALPRICEELECTIONFALLBACK: FixTag = FixTag { id: "2610", datatype: value_types::INT};
-pub const UNDERLYINGCOMPLEXEVENTFUTURESPRICEVALUATION: FixTag = FixTag { id: "2611", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGCOMPLEXEVENTOPTIONSPRICEVALUATION: FixTag = FixTag { id: "2612", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGCOMPLEXEVENTPVFINALPRICEELECTIONFALLBACK: FixTag = FixTag { id: "2613", datatype: value_types::INT};
-pub const UNDERLYINGNOTIONAL: FixTag = FixTag { id: "2614", datatype: value_types::AMT};
-pub const UNDERLYINGNOTIONALCURRENCY: FixTag = FixTag { id: "2615", datatype: value_types::CURRENCY};
-pub const UNDERLYINGNOTIONALDETERMINATIONMETHOD: FixTag = FixTag { id: "2616", datatype: value_types::STRING};
-pub const UNDERLYINGNOTIONALADJUSTMENTS: FixTag = FixTag { id: "2617", datatype: value_types::INT};
-pub const POSITIONID: FixTag = FixTag { id: "2618", datatype: value_types::STRING};
-pub const UNDERLYINGNOTIONALXIDREF: FixTag = FixTag { id: "2619", datatype: value_types::XIDREF};
-pub const UNDERLYINGFUTUREID: FixTag = FixTag { id: "2620", datatype: value_types::STRING};
-pub const UNDERLYINGFUTUREIDSOURCE: FixTag = FixTag { id: "2621", datatype: value_types::STRING};
-pub const UNDERLYINGSTRIKEINDEXCURVEPOINT: FixTag = FixTag { id: "2622", datatype: value_types::STRING};
-pub const UNDERLYINGSTRIKEINDEXQUOTE: FixTag = FixTag { id: "2623", datatype: value_types::INT};
-pub const UNDERLYINGEXTRAORDINARYEVENTADJUSTMENTMETHOD: FixTag = FixTag { id: "2624", datatype: value_types::INT};
-pub const UNDERLYINGEXCHANGELOOKALIKE: FixTag = FixTag { id: "2625", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGAVERAGEVOLUMELIMITATIONPERCENTAGE: FixTag = FixTag { id: "2626", datatype: value_types::AMT};
-pub const UNDERLYINGAVERAGEVOLUMELIMITATIONPERIODDAYS: FixTag = FixTag { id: "2627", datatype: value_types::INT};
-pub const UNDERLYINGDEPOSITORYRECEIPTINDICATOR: FixTag = FixTag { id: "2628", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGOPENUNITS: FixTag = FixTag { id: "2629", datatype: value_types::QTY};
-pub const UNDERLYINGBASKETDIVISOR: FixTag = FixTag { id: "2630", datatype: value_types::FLOAT};
-pub const UNDERLYINGINSTRUMENTXID: FixTag = FixTag { id: "2631", datatype: value_types::XID};
-pub const COLLATERALAMOUNTTYPE: FixTag = FixTag { id: "2632", datatype: value_types::INT};
-pub const NOMISCFEESUBTYPES: FixTag = FixTag { id: "2633", datatype: value_types::NUM_IN_GROUP };
-pub const MISCFEESUBTYPE: FixTag = FixTag { id: "2634", datatype: value_types::STRING};
-pub const MISCFEESUBTYPEAMT: FixTag = FixTag { id: "2635", datatype: value_types::AMT};
-pub const MISCFEESUBTYPEDESC: FixTag = FixTag { id: "2636", datatype: value_types::STRING};
-pub const ENCODEDMISCFEESUBTYPEDESCLEN: FixTag = FixTag { id: "2637", datatype: value_types::LENGTH};
-pub const ENCODEDMISCFEESUBTYPEDESC: FixTag = FixTag { id: "2638", datatype: value_types::DATA};
-pub const NOCOMMISSIONS: FixTag = FixTag { id: "2639", datatype: value_types::NUM_IN_GROUP };
-pub const COMMISSIONAMOUNT: FixTag = FixTag { id: "2640", datatype: value_types::AMT};
-pub const COMMISSIONAMOUNTTYPE: FixTag = FixTag { id: "2641", datatype: value_types::INT};
-pub const COMMISSIONBASIS: FixTag = FixTag { id: "2642", datatype: value_types::CHAR};
-pub const COMMISSIONCURRENCY: FixTag = FixTag { id: "2643", datatype: value_types::CURRENCY};
-pub const COMMISSIONUNITOFMEASURE: FixTag = FixTag { id: "2644", datatype: value_types::STRING};
-pub const COMMISSIONUNITOFMEASURECURRENCY: FixTag = FixTag { id: "2645", datatype: value_types::CURRENCY};
-pub const COMMISSIONRATE: FixTag = FixTag { id: "2646", datatype: value_types::FLOAT};
-pub const COMMISSIONSHAREDINDICATOR: FixTag = FixTag { id: "2647", datatype: value_types::BOOLEAN};
-pub const COMMISSIONAMOUNTSHARED: FixTag = FixTag { id: "2648", datatype: value_types::AMT};
-pub const COMMISSIONLEGREFID: FixTag = FixTag { id: "2649", datatype: value_types::STRING};
-pub const COMMISSIONDESC: FixTag = FixTag { id: "2650", datatype: value_types::STRING};
-pub const ENCODEDCOMMISSIONDESCLEN: FixTag = FixTag { id: "2651", datatype: value_types::LENGTH};
-pub const ENCODEDCOMMISSIONDESC: FixTag = FixTag { id: "2652", datatype: value_types::DATA};
-pub const NOALLOCCOMMISSIONS: FixTag = FixTag { id: "2653", datatype: value_types::NUM_IN_GROUP };
-pub const ALLOCCOMMISSIONAMOUNT: FixTag = FixTag { id: "2654", datatype: value_types::AMT};
-pub const ALLOCCOMMISSIONAMOUNTTYPE: FixTag = FixTag { id: "2655", datatype: value_types::INT};
-pub const ALLOCCOMMISSIONBASIS: FixTag = FixTag { id: "2656", datatype: value_types::CHAR};
-pub const ALLOCCOMMISSIONCURRENCY: FixTag = FixTag { id: "2657", datatype: value_types::CURRENCY};
-pub const ALLOCCOMMISSIONUNITOFMEASURE: FixTag = FixTag { id: "2658", datatype: value_types::STRING};
-pub const ALLOCCOMMISSIONUNITOFMEASURECURRENCY: FixTag = FixTag { id: "2659", datatype: value_types::CURRENCY};
-pub const ALLOCCOMMISSIONRATE: FixTag = FixTag { id: "2660", datatype: value_types::FLOAT};
-pub const ALLOCCOMMISSIONSHAREDINDICATOR: FixTag = FixTag { id: "2661", datatype: value_types::BOOLEAN};
-pub const ALLOCCOMMISSIONAMOUNTSHARED: FixTag = FixTag { id: "2662", datatype: value_types::AMT};
-pub const ALLOCCOMMISSIONLEGREFID: FixTag = FixTag { id: "2663", datatype: value_types::STRING};
-pub const ALLOCCOMMISSIONDESC: FixTag = FixTag { id: "2664", datatype: value_types::STRING};
-pub const ENCODEDALLOCCOMMISSIONDESCLEN: FixTag = FixTag { id: "2665", datatype: value_types::LENGTH};
-pub const ENCODEDALLOCCOMMISSIONDESC: FixTag = FixTag { id: "2666", datatype: value_types::DATA};
-pub const ALGORITHMICTRADEINDICATOR: FixTag = FixTag { id: "2667", datatype: value_types::INT};
-pub const NOTRDREGPUBLICATIONS: FixTag = FixTag { id: "2668", datatype: value_types::NUM_IN_GROUP };
-pub const TRDREGPUBLICATIONTYPE: FixTag = FixTag { id: "2669", datatype: value_types::INT};
-pub const TRDREGPUBLICATIONREASON: FixTag = FixTag { id: "2670", datatype: value_types::INT};
-pub const SIDETRADEREPORTINGINDICATOR: FixTag = FixTag { id: "2671", datatype: value_types::INT};
-pub const CROSSREQUESTID: FixTag = FixTag { id: "2672", datatype: value_types::STRING};
-pub const FILLMATCHID: FixTag = FixTag { id: "2673", datatype: value_types::STRING};
-pub const FILLMATCHSUBID: FixTag = FixTag { id: "2674", datatype: value_types::STRING};
-pub const MASSACTIONREASON: FixTag = FixTag { id: "2675", datatype: value_types::INT};
-pub const MAXIMUMPRICEDEVIATION: FixTag = FixTag { id: "2676", datatype: value_types::PERCENTAGE};
-pub const NOTAFFECTEDREASON: FixTag = FixTag { id: "2677", datatype: value_types::INT};
-pub const TOTALNOTAFFECTEDORDERS: FixTag = FixTag { id: "2678", datatype: value_types::INT};
-pub const ORDEROWNERSHIPINDICATOR: FixTag = FixTag { id: "2679", datatype: value_types::INT};
-pub const LEGACCOUNT: FixTag = FixTag { id: "2680", datatype: value_types::STRING};
-pub const INTHEMONEYCONDITION: FixTag = FixTag { id: "2681", datatype: value_types::INT};
-pub const LEGINTHEMONEYCONDITION: FixTag = FixTag { id: "2682", datatype: value_types::INT};
-pub const UNDERLYINGINTHEMONEYCONDITION: FixTag = FixTag { id: "2683", datatype: value_types::INT};
-pub const DERIVATIVEINTHEMONEYCONDITION: FixTag = FixTag { id: "2684", datatype: value_types::INT};
-pub const CONTRARYINSTRUCTIONELIGIBILITYINDICATOR: FixTag = FixTag { id: "2685", datatype: value_types::BOOLEAN};
-pub const LEGCONTRARYINSTRUCTIONELIGIBILITYINDICATOR: FixTag = FixTag { id: "2686", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGCONTRARYINSTRUCTIONELIGIBILITYINDICATOR: FixTag = FixTag { id: "2687", datatype: value_types::BOOLEAN};
-pub const DERIVATIVECONTRARYINSTRUCTIONELIGIBILITYINDICATOR: FixTag = FixTag { id: "2688", datatype: value_types::BOOLEAN};
-pub const COLLATERALMARKETPRICE: FixTag = FixTag { id: "2689", datatype: value_types::PRICE};
-pub const COLLATERALPERCENTOVERAGE: FixTag = FixTag { id: "2690", datatype: value_types::PERCENTAGE};
-pub const NOSIDECOLLATERALAMOUNTS: FixTag = FixTag { id: "2691", datatype: value_types::NUM_IN_GROUP };
-pub const SIDECOLLATERALAMOUNTMARKETID: FixTag = FixTag { id: "2692", datatype: value_types::STRING};
-pub const SIDECOLLATERALAMOUNTMARKETSEGMENTID: FixTag = FixTag { id: "2693", datatype: value_types::STRING};
-pub const SIDECOLLATERALAMOUNTTYPE: FixTag = FixTag { id: "2694", datatype: value_types::INT};
-pub const SIDECOLLATERALCURRENCY: FixTag = FixTag { id: "2695", datatype: value_types::CURRENCY};
-pub const SIDECOLLATERALFXRATE: FixTag = FixTag { id: "2696", datatype: value_types::FLOAT};
-pub const SIDECOLLATERALFXRATECALC: FixTag = FixTag { id: "2697", datatype: value_types::CHAR};
-pub const SIDECOLLATERALMARKETPRICE: FixTag = FixTag { id: "2698", datatype: value_types::PRICE};
-pub const SIDECOLLATERALPERCENTOVERAGE: FixTag = FixTag { id: "2699", datatype: value_types::PERCENTAGE};
-pub const SIDECOLLATERALPORTFOLIOID: FixTag = FixTag { id: "2700", datatype: value_types::STRING};
-pub const SIDECOLLATERALTYPE: FixTag = FixTag { id: "2701", datatype: value_types::STRING};
-pub const SIDECURRENTCOLLATERALAMOUNT: FixTag = FixTag { id: "2702", datatype: value_types::AMT};
-pub const SIDEHAIRCUTINDICATOR: FixTag = FixTag { id: "2703", datatype: value_types::BOOLEAN};
-pub const EXDESTINATIONTYPE: FixTag = FixTag { id: "2704", datatype: value_types::INT};
-pub const MARKETCONDITION: FixTag = FixTag { id: "2705", datatype: value_types::INT};
-pub const NOQUOTEATTRIBUTES: FixTag = FixTag { id: "2706", datatype: value_types::NUM_IN_GROUP };
-pub const QUOTEATTRIBUTETYPE: FixTag = FixTag { id: "2707", datatype: value_types::INT};
-pub const QUOTEATTRIBUTEVALUE: FixTag = FixTag { id: "2708", datatype: value_types::STRING};
-pub const NOPRICEQUALIFIERS: FixTag = FixTag { id: "2709", datatype: value_types::NUM_IN_GROUP };
-pub const PRICEQUALIFIER: FixTag = FixTag { id: "2710", datatype: value_types::INT};
-pub const MDVALUETIER: FixTag = FixTag { id: "2711", datatype: value_types::INT};
-pub const MISCFEEQUALIFIER: FixTag = FixTag { id: "2712", datatype: value_types::INT};
-pub const MISCFEEDESC: FixTag = FixTag { id: "2713", datatype: value_types::STRING};
-pub const FINANCIALINSTRUMENTFULLNAME: FixTag = FixTag { id: "2714", datatype: value_types::STRING};
-pub const ENCODEDFINANCIALINSTRUMENTFULLNAMELEN: FixTag = FixTag { id: "2715", datatype: value_types::LENGTH};
-pub const ENCODEDFINANCIALINSTRUMENTFULLNAME: FixTag = FixTag { id: "2716", datatype: value_types::DATA};
-pub const LEGFINANCIALINSTRUMENTFULLNAME: FixTag = FixTag { id: "2717", datatype: value_types::STRING};
-pub const ENCODEDLEGFINANCIALINSTRUMENTFULLNAMELEN: FixTag = FixTag { id: "2718", datatype: value_types::LENGTH};
-pub const ENCODEDLEGFINANCIALINSTRUMENTFULLNAME: FixTag = FixTag { id: "2719", datatype: value_types::DATA};
-pub const UNDERLYINGFINANCIALINSTRUMENTFULLNAME: FixTag = FixTag { id: "2720", datatype: value_types::STRING};
-pub const ENCODEDUNDERLYINGFINANCIALINSTRUMENTFULLNAMELEN: FixTag = FixTag { id: "2721", datatype: value_types::LENGTH};
-pub const ENCODEDUNDERLYINGFINANCIALINSTRUMENTFULLNAME: FixTag = FixTag { id: "2722", datatype: value_types::DATA};
-pub const UNDERLYINGINDEXCURVEUNIT: FixTag = FixTag { id: "2723", datatype: value_types::STRING};
-pub const UNDERLYINGINDEXCURVEPERIOD: FixTag = FixTag { id: "2724", datatype: value_types::INT};
-pub const COMMISSIONAMOUNTSUBTYPE: FixTag = FixTag { id: "2725", datatype: value_types::INT};
-pub const ALLOCCOMMISSIONAMOUNTSUBTYPE: FixTag = FixTag { id: "2726", datatype: value_types::INT};
-pub const ALLOCLEGREFID: FixTag = FixTag { id: "2727", datatype: value_types::STRING};
-pub const FLOATINGRATEINDEXCURVEPERIOD: FixTag = FixTag { id: "2728", datatype: value_types::INT};
-pub const FLOATINGRATEINDEXCURVESPREAD: FixTag = FixTag { id: "2729", datatype: value_types::PRICE_OFFSET};
-pub const FLOATINGRATEINDEXCURVEUNIT: FixTag = FixTag { id: "2730", datatype: value_types::STRING};
-pub const FLOATINGRATEINDEXID: FixTag = FixTag { id: "2731", datatype: value_types::STRING};
-pub const FLOATINGRATEINDEXIDSOURCE: FixTag = FixTag { id: "2732", datatype: value_types::STRING};
-pub const INDEXROLLMONTH: FixTag = FixTag { id: "2733", datatype: value_types::STRING};
-pub const NOINDEXROLLMONTHS: FixTag = FixTag { id: "2734", datatype: value_types::NUM_IN_GROUP };
-pub const ASSETSUBTYPE: FixTag = FixTag { id: "2735", datatype: value_types::STRING};
-pub const COMMODITYFINALPRICETYPE: FixTag = FixTag { id: "2736", datatype: value_types::INT};
-pub const FINANCIALINSTRUMENTSHORTNAME: FixTag = FixTag { id: "2737", datatype: value_types::STRING};
-pub const NEXTINDEXROLLDATE: FixTag = FixTag { id: "2738", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGASSETSUBTYPE: FixTag = FixTag { id: "2739", datatype: value_types::STRING};
-pub const LEGFINANCIALINSTRUMENTSHORTNAME: FixTag = FixTag { id: "2740", datatype: value_types::STRING};
-pub const SECONDARYASSETSUBTYPE: FixTag = FixTag { id: "2741", datatype: value_types::STRING};
-pub const UNDERLYINGFINANCIALINSTRUMENTSHORTNAME: FixTag = FixTag { id: "2742", datatype: value_types::STRING};
-pub const LEGSECONDARYASSETSUBTYPE: FixTag = FixTag { id: "2743", datatype: value_types::STRING};
-pub const UNDERLYINGASSETSUBTYPE: FixTag = FixTag { id: "2744", datatype: value_types::STRING};
-pub const UNDERLYINGSECONDARYASSETSUBTYPE: FixTag = FixTag { id: "2745", datatype: value_types::STRING};
-pub const NOREFERENCEDATADATES: FixTag = FixTag { id: "2746", datatype: value_types::NUM_IN_GROUP };
-pub const REFERENCEDATADATE: FixTag = FixTag { id: "2747", datatype: value_types::UTC_TIMESTAMP};
-pub const REFERENCEDATADATETYPE: FixTag = FixTag { id: "2748", datatype: value_types::INT};
-pub const EXECUTIONTIMESTAMP: FixTag = FixTag { id: "2749", datatype: value_types::UTC_TIMESTAMP};
-pub const REPORTINGPX: FixTag = FixTag { id: "2750", datatype: value_types::PRICE};
-pub const REPORTINGQTY: FixTag = FixTag { id: "2751", datatype: value_types::QTY};
-pub const DELIVERYROUTEORCHARTER: FixTag = FixTag { id: "2752", datatype: value_types::STRING};
-pub const RETURNTRIGGER: FixTag = FixTag { id: "2753", datatype: value_types::INT};
-pub const LEGDELIVERYROUTEORCHARTER: FixTag = FixTag { id: "2754", datatype: value_types::STRING};
-pub const LEGRETURNTRIGGER: FixTag = FixTag { id: "2755", datatype: value_types::INT};
-pub const UNDERLYINGDELIVERYROUTEORCHARTER: FixTag = FixTag { id: "2756", datatype: value_types::STRING};
-pub const UNDERLYINGRETURNTRIGGER: FixTag = FixTag { id: "2757", datatype: value_types::INT};
-pub const ALLOCREQUESTID: FixTag = FixTag { id: "2758", datatype: value_types::STRING};
-pub const GROUPAMOUNT: FixTag = FixTag { id: "2759", datatype: value_types::AMT};
-pub const GROUPREMAININGAMOUNT: FixTag = FixTag { id: "2760", datatype: value_types::AMT};
-pub const ALLOCGROUPAMOUNT: FixTag = FixTag { id: "2761", datatype: value_types::AMT};
-pub const PRICEMARKUP: FixTag = FixTag { id: "2762", datatype: value_types::PRICE_OFFSET};
-pub const AVERAGEPRICETYPE: FixTag = FixTag { id: "2763", datatype: value_types::INT};
-pub const AVERAGEPRICESTARTTIME: FixTag = FixTag { id: "2764", datatype: value_types::UTC_TIMESTAMP};
-pub const AVERAGEPRICEENDTIME: FixTag = FixTag { id: "2765", datatype: value_types::UTC_TIMESTAMP};
-pub const ORDERPERCENTOFTOTALVOLUME: FixTag = FixTag { id: "2766", datatype: value_types::PERCENTAGE};
-pub const ALLOCGROUPSTATUS: FixTag = FixTag { id: "2767", datatype: value_types::INT};
-pub const ALLOCREQUESTSTATUS: FixTag = FixTag { id: "2768", datatype: value_types::INT};
-pub const ALLOCAVGPXINDICATOR: FixTag = FixTag { id: "2769", datatype: value_types::INT};
-pub const ALLOCAVGPXGROUPID: FixTag = FixTag { id: "2770", datatype: value_types::STRING};
-pub const PREVIOUSALLOCGROUPID: FixTag = FixTag { id: "2771", datatype: value_types::STRING};
-pub const NOMATCHEXCEPTIONS: FixTag = FixTag { id: "2772", datatype: value_types::NUM_IN_GROUP };
-pub const MATCHEXCEPTIONTYPE: FixTag = FixTag { id: "2773", datatype: value_types::INT};
-pub const MATCHEXCEPTIONELEMENTTYPE: FixTag = FixTag { id: "2774", datatype: value_types::INT};
-pub const MATCHEXCEPTIONELEMENTNAME: FixTag = FixTag { id: "2775", datatype: value_types::STRING};
-pub const MATCHEXCEPTIONALLOCVALUE: FixTag = FixTag { id: "2776", datatype: value_types::STRING};
-pub const MATCHEXCEPTIONCONFIRMVALUE: FixTag = FixTag { id: "2777", datatype: value_types::STRING};
-pub const MATCHEXCEPTIONTOLERANCEVALUE: FixTag = FixTag { id: "2778", datatype: value_types::FLOAT};
-pub const MATCHEXCEPTIONTOLERANCEVALUETYPE: FixTag = FixTag { id: "2779", datatype: value_types::INT};
-pub const MATCHEXCEPTIONTEXT: FixTag = FixTag { id: "2780", datatype: value_types::STRING};
-pub const NOMATCHINGDATAPOINTS: FixTag = FixTag { id: "2781", datatype: value_types::NUM_IN_GROUP };
-pub const MATCHINGDATAPOINTINDICATOR: FixTag = FixTag { id: "2782", datatype: value_types::INT};
-pub const MATCHINGDATAPOINTVALUE: FixTag = FixTag { id: "2783", datatype: value_types::STRING};
-pub const MATCHINGDATAPOINTTYPE: FixTag = FixTag { id: "2784", datatype: value_types::INT};
-pub const MATCHINGDATAPOINTNAME: FixTag = FixTag { id: "2785", datatype: value_types::STRING};
-pub const TRADEAGGREGATIONREQUESTID: FixTag = FixTag { id: "2786", datatype: value_types::STRING};
-pub const TRADEAGGREGATIONREQUESTREFID: FixTag = FixTag { id: "2787", datatype: value_types::STRING};
-pub const TRADEAGGREGATIONTRANSTYPE: FixTag = FixTag { id: "2788", datatype: value_types::INT};
-pub const AGGREGATEDQTY: FixTag = FixTag { id: "2789", datatype: value_types::QTY};
-pub const TRADEAGGREGATIONREQUESTSTATUS: FixTag = FixTag { id: "2790", datatype: value_types::INT};
-pub const TRADEAGGREGATIONREJECTREASON: FixTag = FixTag { id: "2791", datatype: value_types::INT};
-pub const TRADEAGGREGATIONREPORTID: FixTag = FixTag { id: "2792", datatype: value_types::STRING};
-pub const AVGSPOTRATE: FixTag = FixTag { id: "2793", datatype: value_types::PRICE};
-pub const AVGFORWARDPOINTS: FixTag = FixTag { id: "2794", datatype: value_types::PRICE_OFFSET};
-pub const OFFSHOREINDICATOR: FixTag = FixTag { id: "2795", datatype: value_types::INT};
-pub const FXBENCHMARKRATEFIX: FixTag = FixTag { id: "2796", datatype: value_types::STRING};
-pub const ENCODEDMATCHEXCEPTIONTEXTLEN: FixTag = FixTag { id: "2797", datatype: value_types::LENGTH};
-pub const ENCODEDMATCHEXCEPTIONTEXT: FixTag = FixTag { id: "2798", datatype: value_types::DATA};
-pub const PAYREPORTID: FixTag = FixTag { id: "2799", datatype: value_types::STRING};
-pub const PAYDISPUTEREASON: FixTag = FixTag { id: "2800", datatype: value_types::INT};
-pub const ENCODEDREPLACETEXT: FixTag = FixTag { id: "2801", datatype: value_types::DATA};
-pub const ENCODEDREPLACETEXTLEN: FixTag = FixTag { id: "2802", datatype: value_types::LENGTH};
-pub const PAYREPORTREFID: FixTag = FixTag { id: "2803", datatype: value_types::STRING};
-pub const PAYREPORTTRANSTYPE: FixTag = FixTag { id: "2804", datatype: value_types::INT};
-pub const REPLACETEXT: FixTag = FixTag { id: "2805", datatype: value_types::STRING};
-pub const PAYREPORTSTATUS: FixTag = FixTag { id: "2806", datatype: value_types::INT};
-pub const CANCELTEXT: FixTag = FixTag { id: "2807", datatype: value_types::STRING};
-pub const ENCODEDCANCELTEXT: FixTag = FixTag { id: "2808", datatype: value_types::DATA};
-pub const ENCODEDCANCELTEXTLEN: FixTag = FixTag { id: "2809", datatype: value_types::LENGTH};
-pub const PAYREQUESTREFID: FixTag = FixTag { id: "2810", datatype: value_types::STRING};
-pub const PAYREQUESTTRANSTYPE: FixTag = FixTag { id: "2811", datatype: value_types::INT};
-pub const PAYREQUESTID: FixTag = FixTag { id: "2812", datatype: value_types::STRING};
-pub const PAYREQUESTSTATUS: FixTag = FixTag { id: "2813", datatype: value_types::INT};
-pub const ENCODEDPOSTTRADEPAYMENTDESC: FixTag = FixTag { id: "2814", datatype: value_types::DATA};
-pub const ENCODEDPOSTTRADEPAYMENTDESCLEN: FixTag = FixTag { id: "2815", datatype: value_types::LENGTH};
-pub const POSTTRADEPAYMENTACCOUNT: FixTag = FixTag { id: "2816", datatype: value_types::STRING};
-pub const POSTTRADEPAYMENTAMOUNT: FixTag = FixTag { id: "2817", datatype: value_types::AMT};
-pub const POSTTRADEPAYMENTCURRENCY: FixTag = FixTag { id: "2818", datatype: value_types::CURRENCY};
-pub const POSTTRADEPAYMENTDEBITORCREDIT: FixTag = FixTag { id: "2819", datatype: value_types::INT};
-pub const POSTTRADEPAYMENTDESC: FixTag = FixTag { id: "2820", datatype: value_types::STRING};
-pub const POSTTRADEPAYMENTID: FixTag = FixTag { id: "2821", datatype: value_types::STRING};
-pub const POSTTRADEPAYMENTLINKID: FixTag = FixTag { id: "2822", datatype: value_types::STRING};
-pub const POSTTRADEPAYMENTSTATUS: FixTag = FixTag { id: "2823", datatype: value_types::INT};
-pub const POSTTRADEPAYMENTTYPE: FixTag = FixTag { id: "2824", datatype: value_types::STRING};
-pub const POSTTRADEPAYMENTCALCULATIONDATE: FixTag = FixTag { id: "2825", datatype: value_types::LOCAL_MKT_DATE};
-pub const POSTTRADEPAYMENTVALUEDATE: FixTag = FixTag { id: "2826", datatype: value_types::LOCAL_MKT_DATE};
-pub const POSTTRADEPAYMENTFINALVALUEDATE: FixTag = FixTag { id: "2827", datatype: value_types::LOCAL_MKT_DATE};
-pub const CURRENTDISPLAYPRICE: FixTag = FixTag { id: "2828", datatype: value_types::PRICE};
-pub const DUPLICATECLORDIDINDICATOR: FixTag = FixTag { id: "2829", datatype: value_types::BOOLEAN};
-pub const EVENTINITIATORTYPE: FixTag = FixTag { id: "2830", datatype: value_types::CHAR};
-pub const NBBOENTRYTYPE: FixTag = FixTag { id: "2831", datatype: value_types::INT};
-pub const NBBOPRICE: FixTag = FixTag { id: "2832", datatype: value_types::PRICE};
-pub const NBBOQTY: FixTag = FixTag { id: "2833", datatype: value_types::QTY};
-pub const NBBOSOURCE: FixTag = FixTag { id: "2834", datatype: value_types::INT};
-pub const ORDERORIGINATIONFIRMID: FixTag = FixTag { id: "2835", datatype: value_types::STRING};
-pub const RELATEDORDERTIME: FixTag = FixTag { id: "2836", datatype: value_types::UTC_TIMESTAMP};
-pub const SINGLEQUOTEINDICATOR: FixTag = FixTag { id: "2837", datatype: value_types::BOOLEAN};
-pub const CURRENTWORKINGPRICE: FixTag = FixTag { id: "2838", datatype: value_types::PRICE};
-pub const TRDREGTIMESTAMPMANUALINDICATOR: FixTag = FixTag { id: "2839", datatype: value_types::BOOLEAN};
-pub const COLLATERALREINVESTMENTRATE: FixTag = FixTag { id: "2840", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGREFID: FixTag = FixTag { id: "2841", datatype: value_types::STRING};
-pub const COLLATERALREINVESTMENTAMOUNT: FixTag = FixTag { id: "2842", datatype: value_types::AMT};
-pub const COLLATERALREINVESTMENTCURRENCY: FixTag = FixTag { id: "2843", datatype: value_types::CURRENCY};
-pub const COLLATERALREINVESTMENTTYPE: FixTag = FixTag { id: "2844", datatype: value_types::INT};
-pub const NOCOLLATERALREINVESTMENTS: FixTag = FixTag { id: "2845", datatype: value_types::NUM_IN_GROUP };
-pub const FUNDINGSOURCE: FixTag = FixTag { id: "2846", datatype: value_types::INT};
-pub const FUNDINGSOURCECURRENCY: FixTag = FixTag { id: "2847", datatype: value_types::CURRENCY};
-pub const FUNDINGSOURCEMARKETVALUE: FixTag = FixTag { id: "2848", datatype: value_types::AMT};
-pub const NOFUNDINGSOURCES: FixTag = FixTag { id: "2849", datatype: value_types::NUM_IN_GROUP };
-pub const MARGINDIRECTION: FixTag = FixTag { id: "2851", datatype: value_types::INT};
-pub const SIDECOLLATERALREINVESTMENTRATE: FixTag = FixTag { id: "2862", datatype: value_types::PERCENTAGE};
-pub const SIDEUNDERLYINGREFID: FixTag = FixTag { id: "2863", datatype: value_types::STRING};
-pub const NOSIDECOLLATERALREINVESTMENTS: FixTag = FixTag { id: "2864", datatype: value_types::NUM_IN_GROUP };
-pub const SIDECOLLATERALREINVESTMENTAMOUNT: FixTag = FixTag { id: "2865", datatype: value_types::AMT};
-pub const SIDECOLLATERALREINVESTMENTCURRENCY: FixTag = FixTag { id: "2866", datatype: value_types::CURRENCY};
-pub const SIDECOLLATERALREINVESTMENTTYPE: FixTag = FixTag { id: "2867", datatype: value_types::INT};
-pub const COLLATERALIZATIONVALUEDATE: FixTag = FixTag { id: "2868", datatype: value_types::LOCAL_MKT_DATE};
-pub const REGULATORYREPORTTYPEBUSINESSDATE: FixTag = FixTag { id: "2869", datatype: value_types::LOCAL_MKT_DATE};
-pub const CLEARINGPORTFOLIOID: FixTag = FixTag { id: "2870", datatype: value_types::STRING};
-pub const NOTRANSACTIONATTRIBUTES: FixTag = FixTag { id: "2871", datatype: value_types::NUM_IN_GROUP };
-pub const TRANSACTIONATTRIBUTETYPE: FixTag = FixTag { id: "2872", datatype: value_types::INT};
-pub const TRANSACTIONATTRIBUTEVALUE: FixTag = FixTag { id: "2873", datatype: value_types::STRING};
-pub const UNDERLYINGID: FixTag = FixTag { id: "2874", datatype: value_types::STRING};
-pub const POSAMTPRICE: FixTag = FixTag { id: "2876", datatype: value_types::PRICE};
-pub const POSAMTPRICETYPE: FixTag = FixTag { id: "2877", datatype: value_types::INT};
-pub const TERMINATIONDATE: FixTag = FixTag { id: "2878", datatype: value_types::LOCAL_MKT_DATE};
-pub const COUPONOTHERDAYCOUNT: FixTag = FixTag { id: "2879", datatype: value_types::STRING};
-pub const LEGCOUPONOTHERDAYCOUNT: FixTag = FixTag { id: "2880", datatype: value_types::STRING};
-pub const UNDERLYINGCOUPONOTHERDAYCOUNT: FixTag = FixTag { id: "2881", datatype: value_types::STRING};
-pub const CONTRAORDERORIGINATION: FixTag = FixTag { id: "2882", datatype: value_types::INT};
-pub const ROUTINGARRANGMENTINDICATOR: FixTag = FixTag { id: "2883", datatype: value_types::INT};
-pub const CONTRAROUTINGARRANGMENTINDICATOR: FixTag = FixTag { id: "2884", datatype: value_types::INT};
-pub const UNDERLYINGACCRUEDINTERESTAMT: FixTag = FixTag { id: "2885", datatype: value_types::AMT};
-pub const UNDERLYINGNUMDAYSINTEREST: FixTag = FixTag { id: "2886", datatype: value_types::INT};
-pub const RELATEDORDERID: FixTag = FixTag { id: "2887", datatype: value_types::STRING};
-pub const RELATEDORDERIDSOURCE: FixTag = FixTag { id: "2888", datatype: value_types::INT};
-pub const RELATEDORDERQTY: FixTag = FixTag { id: "2889", datatype: value_types::QTY};
-pub const ORDERRELATIONSHIP: FixTag = FixTag { id: "2890", datatype: value_types::INT};
-pub const UPICODE: FixTag = FixTag { id: "2891", datatype: value_types::STRING};
-pub const DERIVATIVEUPICODE: FixTag = FixTag { id: "2892", datatype: value_types::STRING};
-pub const LEGUPICODE: FixTag = FixTag { id: "2893", datatype: value_types::STRING};
-pub const UNDERLYINGUPICODE: FixTag = FixTag { id: "2894", datatype: value_types::STRING};
-pub const INSTRUMENTSCOPEUPICODE: FixTag = FixTag { id: "2895", datatype: value_types::STRING};
-pub const TERTIARYTRDTYPE: FixTag = FixTag { id: "2896", datatype: value_types::INT};
-pub const CURRENCYCODESOURCE: FixTag = FixTag { id: "2897", datatype: value_types::STRING};
-pub const LEGCURRENCYCODESOURCE: FixTag = FixTag { id: "2898", datatype: value_types::STRING};
-pub const SETTLCURRENCYCODESOURCE: FixTag = FixTag { id: "2899", datatype: value_types::STRING};
-pub const LEGSETTLCURRENCYCODESOURCE: FixTag = FixTag { id: "2900", datatype: value_types::STRING};
-pub const SIDECURRENCYCODESOURCE: FixTag = FixTag { id: "2901", datatype: value_types::STRING};
-pub const SIDESETTLCURRENCYCODESOURCE: FixTag = FixTag { id: "2902", datatype: value_types::STRING};
-pub const SETTLEMENTAMOUNTCURRENCYCODESOURCE: FixTag = FixTag { id: "2903", datatype: value_types::STRING};
-pub const STRIKECURRENCYCODESOURCE: FixTag = FixTag { id: "2904", datatype: value_types::STRING};
-pub const UNITOFMEASURECURRENCYCODESOURCE: FixTag = FixTag { id: "2905", datatype: value_types::STRING};
-pub const PRICEUNITOFMEASURECURRENCYCODESOURCE: FixTag = FixTag { id: "2906", datatype: value_types::STRING};
-pub const PRICEQUOTECURRENCYCODESOURCE: FixTag = FixTag { id: "2907", datatype: value_types::STRING};
-pub const LEGSTRIKECURRENCYCODESOURCE: FixTag = FixTag { id: "2908", datatype: value_types::STRING};
-pub const LEGUNITOFMEASURECURRENCYCODESOURCE: FixTag = FixTag { id: "2909", datatype: value_types::STRING};
-pub const LEGPRICEUNITOFMEASURECURRENCYCODESOURCE: FixTag = FixTag { id: "2910", datatype: value_types::STRING};
-pub const LEGPRICEQUOTECURRENCYCODESOURCE: FixTag = FixTag { id: "2911", datatype: value_types::STRING};
-pub const DERIVATIVESTRIKECURRENCYCODESOURCE: FixTag = FixTag { id: "2912", datatype: value_types::STRING};
-pub const DERIVATIVEUNITOFMEASURECURRENCYCODESOURCE: FixTag = FixTag { id: "2913", datatype: value_types::STRING};
-pub const DERIVATIVEPRICEUNITOFMEASURECURRENCYCODESOURCE: FixTag = FixTag { id: "2914", datatype: value_types::STRING};
-pub const DERIVATIVEPRICEQUOTECURRENCYCODESOURCE: FixTag = FixTag { id: "2915", datatype: value_types::STRING};
-pub const UNDERLYINGCURRENCYCODESOURCE: FixTag = FixTag { id: "2916", datatype: value_types::STRING};
-pub const UNDERLYINGSTRIKECURRENCYCODESOURCE: FixTag = FixTag { id: "2917", datatype: value_types::STRING};
-pub const UNDERLYINGUNITOFMEASURECURRENCYCODESOURCE: FixTag = FixTag { id: "2918", datatype: value_types::STRING};
-pub const UNDERLYINGPRICEUNITOFMEASURECURRENCYCODESOURCE: FixTag = FixTag { id: "2919", datatype: value_types::STRING};
-pub const UNDERLYINGPRICEQUOTECURRENCYCODESOURCE: FixTag = FixTag { id: "2920", datatype: value_types::STRING};
-pub const UNDERLYINGNOTIONALCURRENCYCODESOURCE: FixTag = FixTag { id: "2921", datatype: value_types::STRING};
-pub const COMMCURRENCYCODESOURCE: FixTag = FixTag { id: "2922", datatype: value_types::STRING};
-pub const COMMISSIONCURRENCYCODESOURCE: FixTag = FixTag { id: "2923", datatype: value_types::STRING};
-pub const COMMISSIONUNITOFMEASURECURRENCYCODESOURCE: FixTag = FixTag { id: "2924", datatype: value_types::STRING};
-pub const ALLOCCOMMISSIONCURRENCYCODESOURCE: FixTag = FixTag { id: "2925", datatype: value_types::STRING};
-pub const ALLOCCOMMISSIONUNITOFMEASURECURRENCYCODESOURCE: FixTag = FixTag { id: "2926", datatype: value_types::STRING};
-pub const ALLOCSETTLCURRENCYCODESOURCE: FixTag = FixTag { id: "2927", datatype: value_types::STRING};
-pub const LEGALLOCSETTLCURRENCYCODESOURCE: FixTag = FixTag { id: "2928", datatype: value_types::STRING};
-pub const COLLATERALCURRENCYCODESOURCE: FixTag = FixTag { id: "2929", datatype: value_types::STRING};
-pub const SIDECOLLATERALCURRENCYCODESOURCE: FixTag = FixTag { id: "2930", datatype: value_types::STRING};
-pub const COLLATERALREINVESTMENTCURRENCYCODESOURCE: FixTag = FixTag { id: "2931", datatype: value_types::STRING};
-pub const SIDECOLLATERALREINVESTMENTCURRENCYCODESOURCE: FixTag = FixTag { id: "2932", datatype: value_types::STRING};
-pub const TRADEALLOCCURRENCYCODESOURCE: FixTag = FixTag { id: "2933", datatype: value_types::STRING};
-pub const TRADINGCURRENCYCODESOURCE: FixTag = FixTag { id: "2934", datatype: value_types::STRING};
-pub const LIMITAMTCURRENCYCODESOURCE: FixTag = FixTag { id: "2935", datatype: value_types::STRING};
-pub const POSQTYUNITOFMEASURECURRENCYCODESOURCE: FixTag = FixTag { id: "2936", datatype: value_types::STRING};
-pub const POSITIONCURRENCYCODESOURCE: FixTag = FixTag { id: "2937", datatype: value_types::STRING};
-pub const LEGPOSCURRENCYCODESOURCE: FixTag = FixTag { id: "2938", datatype: value_types::STRING};
-pub const RISKLIMITCURRENCYCODESOURCE: FixTag = FixTag { id: "2939", datatype: value_types::STRING};
-pub const ENTITLEMENTATTRIBCURRENCYCODESOURCE: FixTag = FixTag { id: "2940", datatype: value_types::STRING};
-pub const COMPLEXOPTPAYOUTCURRENCYCODESOURCE: FixTag = FixTag { id: "2941", datatype: value_types::STRING};
-pub const COMPLEXEVENTCURRENCYONECODESOURCE: FixTag = FixTag { id: "2942", datatype: value_types::STRING};
-pub const COMPLEXEVENTCURRENCYTWOCODESOURCE: FixTag = FixTag { id: "2943", datatype: value_types::STRING};
-pub const LEGCOMPLEXOPTPAYOUTCURRENCYCODESOURCE: FixTag = FixTag { id: "2944", datatype: value_types::STRING};
-pub const LEGCOMPLEXEVENTCURRENCYONECODESOURCE: FixTag = FixTag { id: "2945", datatype: value_types::STRING};
-pub const LEGCOMPLEXEVENTCURRENCYTWOCODESOURCE: FixTag = FixTag { id: "2946", datatype: value_types::STRING};
-pub const UNDERLYINGCOMPLEXOPTPAYOUTCURRENCYCODESOURCE: FixTag = FixTag { id: "2947", datatype: value_types::STRING};
-pub const UNDERLYINGCOMPLEXEVENTCURRENCYONECODESOURCE: FixTag = FixTag { id: "2948", datatype: value_types::STRING};
-pub const UNDERLYINGCOMPLEXEVENTCURRENCYTWOCODESOURCE: FixTag = FixTag { id: "2949", datatype: value_types::STRING};
-pub const BENCHMARKCURVECURRENCYCODESOURCE: FixTag = FixTag { id: "2950", datatype: value_types::STRING};
-pub const LEGBENCHMARKCURVECURRENCYCODESOURCE: FixTag = FixTag { id: "2951", datatype: value_types::STRING};
-pub const AGREEMENTCURRENCYCODESOURCE: FixTag = FixTag { id: "2952", datatype: value_types::STRING};
-pub const LEGAGREEMENTCURRENCYCODESOURCE: FixTag = FixTag { id: "2953", datatype: value_types::STRING};
-pub const FUNDINGSOURCECURRENCYCODESOURCE: FixTag = FixTag { id: "2954", datatype: value_types::STRING};
-pub const PAYCOLLECTCURRENCYCODESOURCE: FixTag = FixTag { id: "2955", datatype: value_types::STRING};
-pub const POSTTRADEPAYMENTCURRENCYCODESOURCE: FixTag = FixTag { id: "2956", datatype: value_types::STRING};
-pub const SYMBOLPOSITIONNUMBER: FixTag = FixTag { id: "2957", datatype: value_types::INT};
-pub const LEGSYMBOLPOSITIONNUMBER: FixTag = FixTag { id: "2958", datatype: value_types::INT};
-pub const UNDERLYINGSYMBOLPOSITIONNUMBER: FixTag = FixTag { id: "2959", datatype: value_types::INT};
-pub const SETTLPRICEUNITOFMEASURECURRENCYCODESOURCE: FixTag = FixTag { id: "2960", datatype: value_types::STRING};
-pub const ANONYMOUSTRADEINDICATOR: FixTag = FixTag { id: "2961", datatype: value_types::BOOLEAN};
-pub const SECURITYREFERENCEDATASUPPLEMENT: FixTag = FixTag { id: "2962", datatype: value_types::STRING};
-pub const MULTIJURISDICTIONREPORTINGINDICATOR: FixTag = FixTag { id: "2963", datatype: value_types::INT};
-pub const SELFMATCHPREVENTIONINSTRUCTION: FixTag = FixTag { id: "2964", datatype: value_types::INT};
-pub const SETTLSTATUSREQUESTID: FixTag = FixTag { id: "2965", datatype: value_types::STRING};
-pub const SETTLSTATUSREQUESTSTATUS: FixTag = FixTag { id: "2966", datatype: value_types::INT};
-pub const SETTLSTATUSREPORTID: FixTag = FixTag { id: "2967", datatype: value_types::STRING};
-pub const SETTLSTATUS: FixTag = FixTag { id: "2968", datatype: value_types::STRING};
-pub const SETTLSTATUSREASON: FixTag = FixTag { id: "2969", datatype: value_types::STRING};
-pub const SETTLSTATUSREASONTEXT: FixTag = FixTag { id: "2970", datatype: value_types::STRING};
-pub const ENCODEDSETTLSTATUSREASONTEXTLEN: FixTag = FixTag { id: "2971", datatype: value_types::LENGTH};
-pub const ENCODEDSETTLSTATUSREASONTEXT: FixTag = FixTag { id: "2972", datatype: value_types::DATA};
-pub const SETTLSTATUSREPORTSTATUS: FixTag = FixTag { id: "2973", datatype: value_types::INT};
-pub const ALLOCGROUPSUBQTYID: FixTag = FixTag { id: "2974", datatype: value_types::STRING};
-pub const NOALLOCGROUPSUBQTYS: FixTag = FixTag { id: "2975", datatype: value_types::NUM_IN_GROUP };
-pub const ALLOCGROUPSUBQTY: FixTag = FixTag { id: "2976", datatype: value_types::QTY};
-pub const ALLOCGROUPSUBQTYOFFSET: FixTag = FixTag { id: "2977", datatype: value_types::QTY};
-pub const ALLOCGROUPREMAININGSUBQTY: FixTag = FixTag { id: "2978", datatype: value_types::QTY};
-pub const NOALLOCGROUPSUBQTYATTRIBUTES: FixTag = FixTag { id: "2979", datatype: value_types::NUM_IN_GROUP };
-pub const ALLOCGROUPSUBQTYTYPE: FixTag = FixTag { id: "2980", datatype: value_types::INT};
-pub const ALLOCGROUPSUBQTYVALUE: FixTag = FixTag { id: "2981", datatype: value_types::STRING};
-pub const MATURITYFREQUENCYUNIT: FixTag = FixTag { id: "2982", datatype: value_types::STRING};
-pub const MATURITYFREQUENCYPERIOD: FixTag = FixTag { id: "2983", datatype: value_types::INT};
-pub const UNDERLYINGMATURITYFREQUENCYUNIT: FixTag = FixTag { id: "2984", datatype: value_types::STRING};
-pub const UNDERLYINGMATURITYFREQUENCYPERIOD: FixTag = FixTag { id: "2985", datatype: value_types::INT};
-pub const LEGMATURITYFREQUENCYUNIT: FixTag = FixTag { id: "2986", datatype: value_types::STRING};
-pub const LEGMATURITYFREQUENCYPERIOD: FixTag = FixTag { id: "2987", datatype: value_types::INT};
-pub const SECURITYRISKMETRICSREPORTID: FixTag = FixTag { id: "2988", datatype: value_types::STRING};
-pub const RISKMETRICSSECURITYGROUP: FixTag = FixTag { id: "2989", datatype: value_types::STRING};
-pub const RISKMETRICSSECURITYSUBGROUP: FixTag = FixTag { id: "2990", datatype: value_types::STRING};
-pub const UNDERLYINGBIDPX: FixTag = FixTag { id: "2991", datatype: value_types::PRICE};
-pub const UNDERLYINGOFFERPX: FixTag = FixTag { id: "2992", datatype: value_types::PRICE};
-pub const METRICSCALCULATIONPRICESOURCE: FixTag = FixTag { id: "2993", datatype: value_types::INT};
-pub const ASSETVALUATIONMODEL: FixTag = FixTag { id: "2994", datatype: value_types::INT};
-pub const NOSECURITYRISKMETRICS: FixTag = FixTag { id: "2995", datatype: value_types::NUM_IN_GROUP };
-pub const GAMMA: FixTag = FixTag { id: "2996", datatype: value_types::FLOAT};
-pub const RHO: FixTag = FixTag { id: "2997", datatype: value_types::FLOAT};
-pub const THETA: FixTag = FixTag { id: "2998", datatype: value_types::FLOAT};
-pub const VEGA: FixTag = FixTag { id: "2999", datatype: value_types::FLOAT};
-pub const VOLATILITYTIME: FixTag = FixTag { id: "3000", datatype: value_types::UTC_TIMESTAMP};
-pub const BIDVOLATILITY: FixTag = FixTag { id: "3001", datatype: value_types::FLOAT};
-pub const OFFERVOLATILITY: FixTag = FixTag { id: "3002", datatype: value_types::FLOAT};
-pub const MIDVOLATILITY: FixTag = FixTag { id: "3003", datatype: value_types::FLOAT};
-pub const RELATIVEVALUETIMESTAMP: FixTag = FixTag { id: "3004", datatype: value_types::UTC_TIMESTAMP};
-pub const NOTRADETYPES: FixTag = FixTag { id: "3005", datatype: value_types::NUM_IN_GROUP };
-pub const TRADETYPE: FixTag = FixTag { id: "3006", datatype: value_types::INT};
-pub const TRADESUBTYPE: FixTag = FixTag { id: "3007", datatype: value_types::INT};
-pub const NOALLOCTRDREGTIMESTAMPS: FixTag = FixTag { id: "3008", datatype: value_types::NUM_IN_GROUP };
-pub const ALLOCTRDREGTIMESTAMP: FixTag = FixTag { id: "3009", datatype: value_types::UTC_TIMESTAMP};
-pub const ALLOCTRDREGTIMESTAMPTYPE: FixTag = FixTag { id: "3010", datatype: value_types::INT};
-pub const ALLOCTRDREGTIMESTAMPSRC: FixTag = FixTag { id: "3011", datatype: value_types::STRING};
-pub const ALGOCERTIFICATEID: FixTag = FixTag { id: "3012", datatype: value_types::STRING};
-pub const ALGOCERTIFICATEDESC: FixTag = FixTag { id: "3013", datatype: value_types::STRING};
-pub const ALGOCERTIFICATEREQUESTID: FixTag = FixTag { id: "3014", datatype: value_types::STRING};
-pub const ALGOCERTIFICATEREQUESTREFID: FixTag = FixTag { id: "3015", datatype: value_types::STRING};
-pub const ALGOCERTIFICATEREQUESTTRANSTYPE: FixTag = FixTag { id: "3016", datatype: value_types::INT};
-pub const ALGOCERTIFICATEREQUESTSTATUS: FixTag = FixTag { id: "3017", datatype: value_types::INT};
-pub const ALGOCERTIFICATEREPORTID: FixTag = FixTag { id: "3018", datatype: value_types::STRING};
-pub const ALGOCERTIFICATEREPORTREFID: FixTag = FixTag { id: "3019", datatype: value_types::STRING};
-pub const ALGOCERTIFICATEREPORTTRANSTYPE: FixTag = FixTag { id: "3020", datatype: value_types::INT};
-pub const ALGOCERTIFICATEREPORTSTATUS: FixTag = FixTag { id: "3021", datatype: value_types::INT};
-pub const ALGOCERTIFICATESTATUS: FixTag = FixTag { id: "3022", datatype: value_types::INT};
-pub const APPROVALTIME: FixTag = FixTag { id: "3023", datatype: value_types::UTC_TIMESTAMP};
-pub const ALGOTESTDESC: FixTag = FixTag { id: "3024", datatype: value_types::STRING};
-pub const NOALGOSYSTEMMODULES: FixTag = FixTag { id: "3025", datatype: value_types::NUM_IN_GROUP };
-pub const ALGOSYSTEMMODULENAME: FixTag = FixTag { id: "3026", datatype: value_types::STRING};
-pub const ALGOSYSTEMMODULEVERSION: FixTag = FixTag { id: "3027", datatype: value_types::STRING};
-pub const NOTESTSCENARIOS: FixTag = FixTag { id: "3028", datatype: value_types::NUM_IN_GROUP };
-pub const TESTSCENARIOID: FixTag = FixTag { id: "3029", datatype: value_types::STRING};
-pub const TESTSCENARIOSTATUS: FixTag = FixTag { id: "3030", datatype: value_types::INT};
-pub const TESTSCENARIOSTARTTIME: FixTag = FixTag { id: "3031", datatype: value_types::UTC_TIMESTAMP};
-pub const TESTSCENARIOENDTIME: FixTag = FixTag { id: "3032", datatype: value_types::UTC_TIMESTAMP};
-pub const MDORIGINDESC: FixTag = FixTag { id: "3033", datatype: value_types::STRING};
-pub const MDORIGINTIME: FixTag = FixTag { id: "3034", datatype: value_types::UTC_TIMESTAMP};
-pub const TESTSTEPGROUPID: FixTag = FixTag { id: "3035", datatype: value_types::STRING};
-pub const NOTESTSTEPS: FixTag = FixTag { id: "3036", datatype: value_types::NUM_IN_GROUP };
-pub const TESTSTEPID: FixTag = FixTag { id: "3037", datatype: value_types::STRING};
-pub const TESTSTEPDESC: FixTag = FixTag { id: "3038", datatype: value_types::STRING};
-pub const TESTSTEPSTARTTIME: FixTag = FixTag { id: "3039", datatype: value_types::UTC_TIMESTAMP};
-pub const TESTSTEPSTARTOFFSETPERIOD: FixTag = FixTag { id: "3040", datatype: value_types::INT};
-pub const TESTSTEPSTARTOFFSETUNIT: FixTag = FixTag { id: "3041", datatype: value_types::INT};
-pub const TESTSTEPENDTIME: FixTag = FixTag { id: "3042", datatype: value_types::UTC_TIMESTAMP};
-pub const TESTSTEPENDOFFSETPERIOD: FixTag = FixTag { id: "3043", datatype: value_types::INT};
-pub const TESTSTEPENDOFFSETUNIT: FixTag = FixTag { id: "3044", datatype: value_types::INT};
-pub const NOTESTSTEPPARAMETERS: FixTag = FixTag { id: "3045", datatype: value_types::NUM_IN_GROUP };
-pub const TESTSTEPPARAMETERNAME: FixTag = FixTag { id: "3046", datatype: value_types::STRING};
-pub const TESTSTEPPARAMETERTYPE: FixTag = FixTag { id: "3047", datatype: value_types::INT};
-pub const TESTSTEPPARAMETERVALUE: FixTag = FixTag { id: "3048", datatype: value_types::STRING};
-pub const NOTESTSYSTEMMODULES: FixTag = FixTag { id: "3049", datatype: value_types::NUM_IN_GROUP };
-pub const TESTSYSTEMMODULENAME: FixTag = FixTag { id: "3050", datatype: value_types::STRING};
-pub const TESTSYSTEMMODULEVERSION: FixTag = FixTag { id: "3051", datatype: value_types::STRING};
-pub const NOTESTMEASURES: FixTag = FixTag { id: "3052", datatype: value_types::NUM_IN_GROUP };
-pub const TESTMEASURENAME: FixTag = FixTag { id: "3053", datatype: value_types::STRING};
-pub const TESTMEASUREDESC: FixTag = FixTag { id: "3054", datatype: value_types::STRING};
-pub const TESTMEASURETYPE: FixTag = FixTag { id: "3055", datatype: value_types::INT};
-pub const TESTMEASUREPRECISION: FixTag = FixTag { id: "3056", datatype: value_types::INT};
-pub const TESTMEASURERESULT: FixTag = FixTag { id: "3057", datatype: value_types::INT};
-pub const TESTTHRESHOLDTYPE: FixTag = FixTag { id: "3058", datatype: value_types::INT};
-pub const TESTWARNINGLEVELVALUE: FixTag = FixTag { id: "3059", datatype: value_types::FLOAT};
-pub const TESTFAILLEVELVALUE: FixTag = FixTag { id: "3060", datatype: value_types::FLOAT};
-pub const TESTPEAKLEVELVALUE: FixTag = FixTag { id: "3061", datatype: value_types::FLOAT};
-pub const TESTSUITEREQUESTID: FixTag = FixTag { id: "3062", datatype: value_types::STRING};
-pub const TESTSUITEREQUESTREFID: FixTag = FixTag { id: "3063", datatype: value_types::STRING};
-pub const TESTSUITEREQUESTTRANSTYPE: FixTag = FixTag { id: "3064", datatype: value_types::INT};
-pub const TESTSUITEREQUESTSTATUS: FixTag = FixTag { id: "3065", datatype: value_types::INT};
-pub const TESTACTIONREQUESTID: FixTag = FixTag { id: "3066", datatype: value_types::STRING};
-pub const TESTACTIONTYPE: FixTag = FixTag { id: "3067", datatype: value_types::INT};
-pub const TESTACTIONREQUESTSTATUS: FixTag = FixTag { id: "3068", datatype: value_types::INT};
-pub const TESTSUITEACTIVITYSTATE: FixTag = FixTag { id: "3069", datatype: value_types::INT};
-pub const TESTSUITESTATUS: FixTag = FixTag { id: "3070", datatype: value_types::INT};
-pub const TESTACTIONREPORTID: FixTag = FixTag { id: "3071", datatype: value_types::STRING};
-pub const NOADDITIONALTERMBONDREFS: FixTag = FixTag { id: "40000", datatype: value_types::NUM_IN_GROUP };
-pub const ADDITIONALTERMBONDSECURITYID: FixTag = FixTag { id: "40001", datatype: value_types::STRING};
-pub const ADDITIONALTERMBONDSECURITYIDSOURCE: FixTag = FixTag { id: "40002", datatype: value_types::STRING};
-pub const ADDITIONALTERMBONDDESC: FixTag = FixTag { id: "40003", datatype: value_types::STRING};
-pub const ENCODEDADDITIONALTERMBONDDESCLEN: FixTag = FixTag { id: "40004", datatype: value_types::LENGTH};
-pub const ENCODEDADDITIONALTERMBONDDESC: FixTag = FixTag { id: "40005", datatype: value_types::DATA};
-pub const ADDITIONALTERMBONDCURRENCY: FixTag = FixTag { id: "40006", datatype: value_types::CURRENCY};
-pub const ADDITIONALTERMBONDISSUER: FixTag = FixTag { id: "40007", datatype: value_types::STRING};
-pub const ENCODEDADDITIONALTERMBONDISSUERLEN: FixTag = FixTag { id: "40008", datatype: value_types::LENGTH};
-pub const ENCODEDADDITIONALTERMBONDISSUER: FixTag = FixTag { id: "40009", datatype: value_types::DATA};
-pub const ADDITIONALTERMBONDSENIORITY: FixTag = FixTag { id: "40010", datatype: value_types::STRING};
-pub const ADDITIONALTERMBONDCOUPONTYPE: FixTag = FixTag { id: "40011", datatype: value_types::INT};
-pub const ADDITIONALTERMBONDCOUPONRATE: FixTag = FixTag { id: "40012", datatype: value_types::PERCENTAGE};
-pub const ADDITIONALTERMBONDMATURITYDATE: FixTag = FixTag { id: "40013", datatype: value_types::LOCAL_MKT_DATE};
-pub const ADDITIONALTERMBONDPARVALUE: FixTag = FixTag { id: "40014", datatype: value_types::AMT};
-pub const ADDITIONALTERMBONDCURRENTTOTALISSUEDAMOUNT: FixTag = FixTag { id: "40015", datatype: value_types::AMT};
-pub const ADDITIONALTERMBONDCOUPONFREQUENCYPERIOD: FixTag = FixTag { id: "40016", datatype: value_types::INT};
-pub const ADDITIONALTERMBONDCOUPONFREQUENCYUNIT: FixTag = FixTag { id: "40017", datatype: value_types::STRING};
-pub const ADDITIONALTERMBONDDAYCOUNT: FixTag = FixTag { id: "40018", datatype: value_types::INT};
-pub const NOADDITIONALTERMS: FixTag = FixTag { id: "40019", datatype: value_types::NUM_IN_GROUP };
-pub const ADDITIONALTERMCONDITIONPRECEDENTBONDINDICATOR: FixTag = FixTag { id: "40020", datatype: value_types::BOOLEAN};
-pub const ADDITIONALTERMDISCREPANCYCLAUSEINDICATOR: FixTag = FixTag { id: "40021", datatype: value_types::BOOLEAN};
-pub const NOCASHSETTLTERMS: FixTag = FixTag { id: "40022", datatype: value_types::NUM_IN_GROUP };
-pub const CASHSETTLCURRENCY: FixTag = FixTag { id: "40023", datatype: value_types::CURRENCY};
-pub const CASHSETTLVALUATIONFIRSTBUSINESSDAYOFFSET: FixTag = FixTag { id: "40024", datatype: value_types::INT};
-pub const CASHSETTLVALUATIONTIME: FixTag = FixTag { id: "40025", datatype: value_types::LOCAL_MKT_TIME};
-pub const CASHSETTLBUSINESSCENTER: FixTag = FixTag { id: "40026", datatype: value_types::STRING};
-pub const CASHSETTLQUOTEMETHOD: FixTag = FixTag { id: "40027", datatype: value_types::INT};
-pub const CASHSETTLQUOTEAMOUNT: FixTag = FixTag { id: "40028", datatype: value_types::AMT};
-pub const CASHSETTLQUOTECURRENCY: FixTag = FixTag { id: "40029", datatype: value_types::CURRENCY};
-pub const CASHSETTLMINIMUMQUOTEAMOUNT: FixTag = FixTag { id: "40030", datatype: value_types::AMT};
-pub const CASHSETTLMINIMUMQUOTECURRENCY: FixTag = FixTag { id: "40031", datatype: value_types::CURRENCY};
-pub const CASHSETTLDEALER: FixTag = FixTag { id: "40032", datatype: value_types::STRING};
-pub const CASHSETTLBUSINESSDAYS: FixTag = FixTag { id: "40033", datatype: value_types::INT};
-pub const CASHSETTLAMOUNT: FixTag = FixTag { id: "40034", datatype: value_types::AMT};
-pub const CASHSETTLRECOVERYFACTOR: FixTag = FixTag { id: "40035", datatype: value_types::FLOAT};
-pub const CASHSETTLFIXEDTERMINDICATOR: FixTag = FixTag { id: "40036", datatype: value_types::BOOLEAN};
-pub const CASHSETTLACCRUEDINTERESTINDICATOR: FixTag = FixTag { id: "40037", datatype: value_types::BOOLEAN};
-pub const CASHSETTLVALUATIONMETHOD: FixTag = FixTag { id: "40038", datatype: value_types::INT};
-pub const CASHSETTLTERMXID: FixTag = FixTag { id: "40039", datatype: value_types::XID};
-pub const NOCONTRACTUALDEFINITIONS: FixTag = FixTag { id: "40040", datatype: value_types::NUM_IN_GROUP };
-pub const CONTRACTUALDEFINITION: FixTag = FixTag { id: "40041", datatype: value_types::STRING};
-pub const NOCONTRACTUALMATRICES: FixTag = FixTag { id: "40042", datatype: value_types::NUM_IN_GROUP };
-pub const CONTRACTUALMATRIXSOURCE: FixTag = FixTag { id: "40043", datatype: value_types::STRING};
-pub const CONTRACTUALMATRIXDATE: FixTag = FixTag { id: "40044", datatype: value_types::LOCAL_MKT_DATE};
-pub const CONTRACTUALMATRIXTERM: FixTag = FixTag { id: "40045", datatype: value_types::STRING};
-pub const NOFINANCINGTERMSUPPLEMENTS: FixTag = FixTag { id: "40046", datatype: value_types::NUM_IN_GROUP };
-pub const FINANCINGTERMSUPPLEMENTDESC: FixTag = FixTag { id: "40047", datatype: value_types::STRING};
-pub const FINANCINGTERMSUPPLEMENTDATE: FixTag = FixTag { id: "40048", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOSTREAMS: FixTag = FixTag { id: "40049", datatype: value_types::NUM_IN_GROUP };
-pub const STREAMTYPE: FixTag = FixTag { id: "40050", datatype: value_types::INT};
-pub const STREAMDESC: FixTag = FixTag { id: "40051", datatype: value_types::STRING};
-pub const STREAMPAYSIDE: FixTag = FixTag { id: "40052", datatype: value_types::INT};
-pub const STREAMRECEIVESIDE: FixTag = FixTag { id: "40053", datatype: value_types::INT};
-pub const STREAMNOTIONAL: FixTag = FixTag { id: "40054", datatype: value_types::AMT};
-pub const STREAMCURRENCY: FixTag = FixTag { id: "40055", datatype: value_types::CURRENCY};
-pub const STREAMTEXT: FixTag = FixTag { id: "40056", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMEFFECTIVEDATEUNADJUSTED: FixTag = FixTag { id: "40057", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGSTREAMEFFECTIVEDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40058", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMEFFECTIVEDATEBUSINESSCENTER: FixTag = FixTag { id: "40059", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMEFFECTIVEDATERELATIVETO: FixTag = FixTag { id: "40060", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMEFFECTIVEDATEOFFSETPERIOD: FixTag = FixTag { id: "40061", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMEFFECTIVEDATEOFFSETUNIT: FixTag = FixTag { id: "40062", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMEFFECTIVEDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40063", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMEFFECTIVEDATEADJUSTED: FixTag = FixTag { id: "40064", datatype: value_types::LOCAL_MKT_DATE};
-pub const STREAMTERMINATIONDATEUNADJUSTED: FixTag = FixTag { id: "40065", datatype: value_types::LOCAL_MKT_DATE};
-pub const STREAMTERMINATIONDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40066", datatype: value_types::INT};
-pub const STREAMTERMINATIONDATEBUSINESSCENTER: FixTag = FixTag { id: "40067", datatype: value_types::STRING};
-pub const STREAMTERMINATIONDATERELATIVETO: FixTag = FixTag { id: "40068", datatype: value_types::INT};
-pub const STREAMTERMINATIONDATEOFFSETPERIOD: FixTag = FixTag { id: "40069", datatype: value_types::INT};
-pub const STREAMTERMINATIONDATEOFFSETUNIT: FixTag = FixTag { id: "40070", datatype: value_types::STRING};
-pub const STREAMTERMINATIONDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40071", datatype: value_types::INT};
-pub const STREAMTERMINATIONDATEADJUSTED: FixTag = FixTag { id: "40072", datatype: value_types::LOCAL_MKT_DATE};
-pub const STREAMCALCULATIONPERIODBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40073", datatype: value_types::INT};
-pub const STREAMCALCULATIONPERIODBUSINESSCENTER: FixTag = FixTag { id: "40074", datatype: value_types::STRING};
-pub const STREAMFIRSTPERIODSTARTDATEUNADJUSTED: FixTag = FixTag { id: "40075", datatype: value_types::LOCAL_MKT_DATE};
-pub const STREAMFIRSTPERIODSTARTDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40076", datatype: value_types::INT};
-pub const STREAMFIRSTPERIODSTARTDATEBUSINESSCENTER: FixTag = FixTag { id: "40077", datatype: value_types::STRING};
-pub const STREAMFIRSTPERIODSTARTDATEADJUSTED: FixTag = FixTag { id: "40078", datatype: value_types::LOCAL_MKT_DATE};
-pub const STREAMFIRSTREGULARPERIODSTARTDATEUNADJUSTED: FixTag = FixTag { id: "40079", datatype: value_types::LOCAL_MKT_DATE};
-pub const STREAMFIRSTCOMPOUNDINGPERIODENDDATEUNADJUSTED: FixTag = FixTag { id: "40080", datatype: value_types::LOCAL_MKT_DATE};
-pub const STREAMLASTREGULARPERIODENDDATEUNADJUSTED: FixTag = FixTag { id: "40081", datatype: value_types::LOCAL_MKT_DATE};
-pub const STREAMCALCULATIONFREQUENCYPERIOD: FixTag = FixTag { id: "40082", datatype: value_types::INT};
-pub const STREAMCALCULATIONFREQUENCYUNIT: FixTag = FixTag { id: "40083", datatype: value_types::STRING};
-pub const STREAMCALCULATIONROLLCONVENTION: FixTag = FixTag { id: "40084", datatype: value_types::STRING};
-pub const NOSETTLRATEFALLBACKS: FixTag = FixTag { id: "40085", datatype: value_types::NUM_IN_GROUP };
-pub const SETTLRATEPOSTPONEMENTMAXIMUMDAYS: FixTag = FixTag { id: "40086", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMNONDELIVERABLESETTLRATESOURCE: FixTag = FixTag { id: "40087", datatype: value_types::INT};
-pub const SETTLRATEPOSTPONEMENTSURVEY: FixTag = FixTag { id: "40088", datatype: value_types::BOOLEAN};
-pub const SETTLRATEPOSTPONEMENTCALCULATIONAGENT: FixTag = FixTag { id: "40089", datatype: value_types::INT};
-pub const NOPROVISIONS: FixTag = FixTag { id: "40090", datatype: value_types::NUM_IN_GROUP };
-pub const PROVISIONTYPE: FixTag = FixTag { id: "40091", datatype: value_types::INT};
-pub const PROVISIONDATEUNADJUSTED: FixTag = FixTag { id: "40092", datatype: value_types::LOCAL_MKT_DATE};
-pub const PROVISIONDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40093", datatype: value_types::INT};
-pub const PROVISIONDATEBUSINESSCENTER: FixTag = FixTag { id: "40094", datatype: value_types::STRING};
-pub const PROVISIONDATEADJUSTED: FixTag = FixTag { id: "40095", datatype: value_types::LOCAL_MKT_DATE};
-pub const PROVISIONDATETENORPERIOD: FixTag = FixTag { id: "40096", datatype: value_types::INT};
-pub const PROVISIONDATETENORUNIT: FixTag = FixTag { id: "40097", datatype: value_types::STRING};
-pub const PROVISIONCALCULATIONAGENT: FixTag = FixTag { id: "40098", datatype: value_types::INT};
-pub const PROVISIONOPTIONSINGLEPARTYBUYERSIDE: FixTag = FixTag { id: "40099", datatype: value_types::INT};
-pub const PROVISIONOPTIONSINGLEPARTYSELLERSIDE: FixTag = FixTag { id: "40100", datatype: value_types::INT};
-pub const PROVISIONOPTIONEXERCISESTYLE: FixTag = FixTag { id: "40101", datatype: value_types::INT};
-pub const PROVISIONOPTIONEXERCISEMULTIPLENOTIONAL: FixTag = FixTag { id: "40102", datatype: value_types::AMT};
-pub const PROVISIONOPTIONEXERCISEMINIMUMNOTIONAL: FixTag = FixTag { id: "40103", datatype: value_types::AMT};
-pub const PROVISIONOPTIONEXERCISEMAXIMUMNOTIONAL: FixTag = FixTag { id: "40104", datatype: value_types::AMT};
-pub const PROVISIONOPTIONMINIMUMNUMBER: FixTag = FixTag { id: "40105", datatype: value_types::INT};
-pub const PROVISIONOPTIONMAXIMUMNUMBER: FixTag = FixTag { id: "40106", datatype: value_types::INT};
-pub const PROVISIONOPTIONEXERCISECONFIRMATION: FixTag = FixTag { id: "40107", datatype: value_types::BOOLEAN};
-pub const PROVISIONCASHSETTLMETHOD: FixTag = FixTag { id: "40108", datatype: value_types::INT};
-pub const PROVISIONCASHSETTLCURRENCY: FixTag = FixTag { id: "40109", datatype: value_types::CURRENCY};
-pub const PROVISIONCASHSETTLCURRENCY2: FixTag = FixTag { id: "40110", datatype: value_types::CURRENCY};
-pub const PROVISIONCASHSETTLQUOTETYPE: FixTag = FixTag { id: "40111", datatype: value_types::INT};
-pub const PROVISIONCASHSETTLQUOTESOURCE: FixTag = FixTag { id: "40112", datatype: value_types::INT};
-pub const PROVISIONTEXT: FixTag = FixTag { id: "40113", datatype: value_types::STRING};
-pub const PROVISIONCASHSETTLVALUETIME: FixTag = FixTag { id: "40114", datatype: value_types::LOCAL_MKT_TIME};
-pub const PROVISIONCASHSETTLVALUETIMEBUSINESSCENTER: FixTag = FixTag { id: "40115", datatype: value_types::STRING};
-pub const PROVISIONCASHSETTLVALUEDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40116", datatype: value_types::INT};
-pub const PROVISIONCASHSETTLVALUEDATEBUSINESSCENTER: FixTag = FixTag { id: "40117", datatype: value_types::STRING};
-pub const PROVISIONCASHSETTLVALUEDATERELATIVETO: FixTag = FixTag { id: "40118", datatype: value_types::INT};
-pub const PROVISIONCASHSETTLVALUEDATEOFFSETPERIOD: FixTag = FixTag { id: "40119", datatype: value_types::INT};
-pub const PROVISIONCASHSETTLVALUEDATEOFFSETUNIT: FixTag = FixTag { id: "40120", datatype: value_types::STRING};
-pub const PROVISIONCASHSETTLVALUEDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40121", datatype: value_types::INT};
-pub const PROVISIONCASHSETTLVALUEDATEADJUSTED: FixTag = FixTag { id: "40122", datatype: value_types::LOCAL_MKT_DATE};
-pub const PROVISIONOPTIONEXERCISEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40123", datatype: value_types::INT};
-pub const PROVISIONOPTIONEXERCISEBUSINESSCENTER: FixTag = FixTag { id: "40124", datatype: value_types::STRING};
-pub const PROVISIONOPTIONEXERCISEEARLIESTDATEOFFSETPERIOD: FixTag = FixTag { id: "40125", datatype: value_types::INT};
-pub const PROVISIONOPTIONEXERCISEEARLIESTDATEOFFSETUNIT: FixTag = FixTag { id: "40126", datatype: value_types::STRING};
-pub const PROVISIONOPTIONEXERCISEFREQUENCYPERIOD: FixTag = FixTag { id: "40127", datatype: value_types::INT};
-pub const PROVISIONOPTIONEXERCISEFREQUENCYUNIT: FixTag = FixTag { id: "40128", datatype: value_types::STRING};
-pub const PROVISIONOPTIONEXERCISESTARTDATEUNADJUSTED: FixTag = FixTag { id: "40129", datatype: value_types::LOCAL_MKT_DATE};
-pub const PROVISIONOPTIONEXERCISESTARTDATERELATIVETO: FixTag = FixTag { id: "40130", datatype: value_types::INT};
-pub const PROVISIONOPTIONEXERCISESTARTDATEOFFSETPERIOD: FixTag = FixTag { id: "40131", datatype: value_types::INT};
-pub const PROVISIONOPTIONEXERCISESTARTDATEOFFSETUNIT: FixTag = FixTag { id: "40132", datatype: value_types::STRING};
-pub const PROVISIONOPTIONEXERCISESTARTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40133", datatype: value_types::INT};
-pub const PROVISIONOPTIONEXERCISESTARTDATEADJUSTED: FixTag = FixTag { id: "40134", datatype: value_types::LOCAL_MKT_DATE};
-pub const PROVISIONOPTIONEXERCISEPERIODSKIP: FixTag = FixTag { id: "40135", datatype: value_types::INT};
-pub const PROVISIONOPTIONEXERCISEBOUNDSFIRSTDATEUNADJUSTED: FixTag = FixTag { id: "40136", datatype: value_types::LOCAL_MKT_DATE};
-pub const PROVISIONOPTIONEXERCISEBOUNDSLASTDATEUNADJUSTED: FixTag = FixTag { id: "40137", datatype: value_types::LOCAL_MKT_DATE};
-pub const PROVISIONOPTIONEXERCISEEARLIESTTIME: FixTag = FixTag { id: "40138", datatype: value_types::LOCAL_MKT_TIME};
-pub const PROVISIONOPTIONEXERCISEEARLIESTTIMEBUSINESSCENTER: FixTag = FixTag { id: "40139", datatype: value_types::STRING};
-pub const PROVISIONOPTIONEXERCISELATESTTIME: FixTag = FixTag { id: "40140", datatype: value_types::LOCAL_MKT_TIME};
-pub const PROVISIONOPTIONEXERCISELATESTTIMEBUSINESSCENTER: FixTag = FixTag { id: "40141", datatype: value_types::STRING};
-pub const NOPROVISIONOPTIONEXERCISEFIXEDDATES: FixTag = FixTag { id: "40142", datatype: value_types::NUM_IN_GROUP };
-pub const PROVISIONOPTIONEXERCISEFIXEDDATE: FixTag = FixTag { id: "40143", datatype: value_types::LOCAL_MKT_DATE};
-pub const PROVISIONOPTIONEXERCISEFIXEDDATETYPE: FixTag = FixTag { id: "40144", datatype: value_types::INT};
-pub const PROVISIONOPTIONEXPIRATIONDATEUNADJUSTED: FixTag = FixTag { id: "40145", datatype: value_types::LOCAL_MKT_DATE};
-pub const PROVISIONOPTIONEXPIRATIONDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40146", datatype: value_types::INT};
-pub const PROVISIONOPTIONEXPIRATIONDATEBUSINESSCENTER: FixTag = FixTag { id: "40147", datatype: value_types::STRING};
-pub const PROVISIONOPTIONEXPIRATIONDATERELATIVETO: FixTag = FixTag { id: "40148", datatype: value_types::INT};
-pub const PROVISIONOPTIONEXPIRATIONDATEOFFSETPERIOD: FixTag = FixTag { id: "40149", datatype: value_types::INT};
-pub const PROVISIONOPTIONEXPIRATIONDATEOFFSETUNIT: FixTag = FixTag { id: "40150", datatype: value_types::STRING};
-pub const PROVISIONOPTIONEXPIRATIONDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40151", datatype: value_types::INT};
-pub const PROVISIONOPTIONEXPIRATIONDATEADJUSTED: FixTag = FixTag { id: "40152", datatype: value_types::LOCAL_MKT_DATE};
-pub const PROVISIONOPTIONEXPIRATIONTIME: FixTag = FixTag { id: "40153", datatype: value_types::LOCAL_MKT_TIME};
-pub const PROVISIONOPTIONEXPIRATIONTIMEBUSINESSCENTER: FixTag = FixTag { id: "40154", datatype: value_types::STRING};
-pub const PROVISIONOPTIONRELEVANTUNDERLYINGDATEUNADJUSTED: FixTag = FixTag { id: "40155", datatype: value_types::LOCAL_MKT_DATE};
-pub const PROVISIONOPTIONRELEVANTUNDERLYINGDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40156", datatype: value_types::INT};
-pub const PROVISIONOPTIONRELEVANTUNDERLYINGDATEBUSINESSCENTER: FixTag = FixTag { id: "40157", datatype: value_types::STRING};
-pub const PROVISIONOPTIONRELEVANTUNDERLYINGDATERELATIVETO: FixTag = FixTag { id: "40158", datatype: value_types::INT};
-pub const PROVISIONOPTIONRELEVANTUNDERLYINGDATEOFFSETPERIOD: FixTag = FixTag { id: "40159", datatype: value_types::INT};
-pub const PROVISIONOPTIONRELEVANTUNDERLYINGDATEOFFSETUNIT: FixTag = FixTag { id: "40160", datatype: value_types::STRING};
-pub const PROVISIONOPTIONRELEVANTUNDERLYINGDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40161", datatype: value_types::INT};
-pub const PROVISIONOPTIONRELEVANTUNDERLYINGDATEADJUSTED: FixTag = FixTag { id: "40162", datatype: value_types::LOCAL_MKT_DATE};
-pub const PROVISIONCASHSETTLPAYMENTDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40163", datatype: value_types::INT};
-pub const PROVISIONCASHSETTLPAYMENTDATEBUSINESSCENTER: FixTag = FixTag { id: "40164", datatype: value_types::STRING};
-pub const PROVISIONCASHSETTLPAYMENTDATERELATIVETO: FixTag = FixTag { id: "40165", datatype: value_types::INT};
-pub const PROVISIONCASHSETTLPAYMENTDATEOFFSETPERIOD: FixTag = FixTag { id: "40166", datatype: value_types::INT};
-pub const PROVISIONCASHSETTLPAYMENTDATEOFFSETUNIT: FixTag = FixTag { id: "40167", datatype: value_types::STRING};
-pub const PROVISIONCASHSETTLPAYMENTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40168", datatype: value_types::INT};
-pub const PROVISIONCASHSETTLPAYMENTDATERANGEFIRST: FixTag = FixTag { id: "40169", datatype: value_types::LOCAL_MKT_DATE};
-pub const PROVISIONCASHSETTLPAYMENTDATERANGELAST: FixTag = FixTag { id: "40170", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOPROVISIONCASHSETTLPAYMENTDATES: FixTag = FixTag { id: "40171", datatype: value_types::NUM_IN_GROUP };
-pub const PROVISIONCASHSETTLPAYMENTDATE: FixTag = FixTag { id: "40172", datatype: value_types::LOCAL_MKT_DATE};
-pub const PROVISIONCASHSETTLPAYMENTDATETYPE: FixTag = FixTag { id: "40173", datatype: value_types::INT};
-pub const NOPROVISIONPARTYIDS: FixTag = FixTag { id: "40174", datatype: value_types::NUM_IN_GROUP };
-pub const PROVISIONPARTYID: FixTag = FixTag { id: "40175", datatype: value_types::STRING};
-pub const PROVISIONPARTYIDSOURCE: FixTag = FixTag { id: "40176", datatype: value_types::CHAR};
-pub const PROVISIONPARTYROLE: FixTag = FixTag { id: "40177", datatype: value_types::INT};
-pub const NOPROVISIONPARTYSUBIDS: FixTag = FixTag { id: "40178", datatype: value_types::NUM_IN_GROUP };
-pub const PROVISIONPARTYSUBID: FixTag = FixTag { id: "40179", datatype: value_types::STRING};
-pub const PROVISIONPARTYSUBIDTYPE: FixTag = FixTag { id: "40180", datatype: value_types::INT};
-pub const NOPROTECTIONTERMS: FixTag = FixTag { id: "40181", datatype: value_types::NUM_IN_GROUP };
-pub const PROTECTIONTERMNOTIONAL: FixTag = FixTag { id: "40182", datatype: value_types::AMT};
-pub const PROTECTIONTERMCURRENCY: FixTag = FixTag { id: "40183", datatype: value_types::CURRENCY};
-pub const PROTECTIONTERMSELLERNOTIFIES: FixTag = FixTag { id: "40184", datatype: value_types::BOOLEAN};
-pub const PROTECTIONTERMBUYERNOTIFIES: FixTag = FixTag { id: "40185", datatype: value_types::BOOLEAN};
-pub const PROTECTIONTERMEVENTBUSINESSCENTER: FixTag = FixTag { id: "40186", datatype: value_types::STRING};
-pub const PROTECTIONTERMSTANDARDSOURCES: FixTag = FixTag { id: "40187", datatype: value_types::BOOLEAN};
-pub const PROTECTIONTERMEVENTMINIMUMSOURCES: FixTag = FixTag { id: "40188", datatype: value_types::INT};
-pub const PROTECTIONTERMEVENTNEWSSOURCE: FixTag = FixTag { id: "40189", datatype: value_types::STRING};
-pub const PROTECTIONTERMXID: FixTag = FixTag { id: "40190", datatype: value_types::XID};
-pub const NOPROTECTIONTERMEVENTS: FixTag = FixTag { id: "40191", datatype: value_types::NUM_IN_GROUP };
-pub const PROTECTIONTERMEVENTTYPE: FixTag = FixTag { id: "40192", datatype: value_types::STRING};
-pub const PROTECTIONTERMEVENTVALUE: FixTag = FixTag { id: "40193", datatype: value_types::STRING};
-pub const PROTECTIONTERMEVENTCURRENCY: FixTag = FixTag { id: "40194", datatype: value_types::CURRENCY};
-pub const PROTECTIONTERMEVENTPERIOD: FixTag = FixTag { id: "40195", datatype: value_types::INT};
-pub const PROTECTIONTERMEVENTUNIT: FixTag = FixTag { id: "40196", datatype: value_types::STRING};
-pub const PROTECTIONTERMEVENTDAYTYPE: FixTag = FixTag { id: "40197", datatype: value_types::INT};
-pub const PROTECTIONTERMEVENTRATESOURCE: FixTag = FixTag { id: "40198", datatype: value_types::STRING};
-pub const NOPROTECTIONTERMEVENTQUALIFIERS: FixTag = FixTag { id: "40199", datatype: value_types::NUM_IN_GROUP };
-pub const PROTECTIONTERMEVENTQUALIFIER: FixTag = FixTag { id: "40200", datatype: value_types::CHAR};
-pub const NOPROTECTIONTERMOBLIGATIONS: FixTag = FixTag { id: "40201", datatype: value_types::NUM_IN_GROUP };
-pub const PROTECTIONTERMOBLIGATIONTYPE: FixTag = FixTag { id: "40202", datatype: value_types::STRING};
-pub const PROTECTIONTERMOBLIGATIONVALUE: FixTag = FixTag { id: "40203", datatype: value_types::STRING};
-pub const NOPHYSICALSETTLTERMS: FixTag = FixTag { id: "40204", datatype: value_types::NUM_IN_GROUP };
-pub const PHYSICALSETTLCURRENCY: FixTag = FixTag { id: "40205", datatype: value_types::CURRENCY};
-pub const PHYSICALSETTLBUSINESSDAYS: FixTag = FixTag { id: "40206", datatype: value_types::INT};
-pub const PHYSICALSETTLMAXIMUMBUSINESSDAYS: FixTag = FixTag { id: "40207", datatype: value_types::INT};
-pub const PHYSICALSETTLTERMXID: FixTag = FixTag { id: "40208", datatype: value_types::XID};
-pub const NOPHYSICALSETTLDELIVERABLEOBLIGATIONS: FixTag = FixTag { id: "40209", datatype: value_types::NUM_IN_GROUP };
-pub const PHYSICALSETTLDELIVERABLEOBLIGATIONTYPE: FixTag = FixTag { id: "40210", datatype: value_types::STRING};
-pub const PHYSICALSETTLDELIVERABLEOBLIGATIONVALUE: FixTag = FixTag { id: "40211", datatype: value_types::STRING};
-pub const NOPAYMENTS: FixTag = FixTag { id: "40212", datatype: value_types::NUM_IN_GROUP };
-pub const PAYMENTTYPE: FixTag = FixTag { id: "40213", datatype: value_types::INT};
-pub const PAYMENTPAYSIDE: FixTag = FixTag { id: "40214", datatype: value_types::INT};
-pub const PAYMENTRECEIVESIDE: FixTag = FixTag { id: "40215", datatype: value_types::INT};
-pub const PAYMENTCURRENCY: FixTag = FixTag { id: "40216", datatype: value_types::CURRENCY};
-pub const PAYMENTAMOUNT: FixTag = FixTag { id: "40217", datatype: value_types::AMT};
-pub const PAYMENTPRICE: FixTag = FixTag { id: "40218", datatype: value_types::PRICE};
-pub const PAYMENTDATEUNADJUSTED: FixTag = FixTag { id: "40219", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40220", datatype: value_types::INT};
-pub const PAYMENTBUSINESSCENTER: FixTag = FixTag { id: "40221", datatype: value_types::STRING};
-pub const PAYMENTDATEADJUSTED: FixTag = FixTag { id: "40222", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGMARKETDISRUPTIONVALUE: FixTag = FixTag { id: "40223", datatype: value_types::STRING};
-pub const PAYMENTDISCOUNTFACTOR: FixTag = FixTag { id: "40224", datatype: value_types::FLOAT};
-pub const PAYMENTPRESENTVALUEAMOUNT: FixTag = FixTag { id: "40225", datatype: value_types::AMT};
-pub const PAYMENTPRESENTVALUECURRENCY: FixTag = FixTag { id: "40226", datatype: value_types::CURRENCY};
-pub const PAYMENTSETTLSTYLE: FixTag = FixTag { id: "40227", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMNONDELIVERABLESETTLREFERENCEPAGE: FixTag = FixTag { id: "40228", datatype: value_types::STRING};
-pub const PAYMENTTEXT: FixTag = FixTag { id: "40229", datatype: value_types::STRING};
-pub const NOPAYMENTSETTLS: FixTag = FixTag { id: "40230", datatype: value_types::NUM_IN_GROUP };
-pub const PAYMENTSETTLAMOUNT: FixTag = FixTag { id: "40231", datatype: value_types::AMT};
-pub const PAYMENTSETTLCURRENCY: FixTag = FixTag { id: "40232", datatype: value_types::CURRENCY};
-pub const NOPAYMENTSETTLPARTYIDS: FixTag = FixTag { id: "40233", datatype: value_types::NUM_IN_GROUP };
-pub const PAYMENTSETTLPARTYID: FixTag = FixTag { id: "40234", datatype: value_types::STRING};
-pub const PAYMENTSETTLPARTYIDSOURCE: FixTag = FixTag { id: "40235", datatype: value_types::CHAR};
-pub const PAYMENTSETTLPARTYROLE: FixTag = FixTag { id: "40236", datatype: value_types::INT};
-pub const PAYMENTSETTLPARTYROLEQUALIFIER: FixTag = FixTag { id: "40237", datatype: value_types::INT};
-pub const NOPAYMENTSETTLPARTYSUBIDS: FixTag = FixTag { id: "40238", datatype: value_types::NUM_IN_GROUP };
-pub const PAYMENTSETTLPARTYSUBID: FixTag = FixTag { id: "40239", datatype: value_types::STRING};
-pub const PAYMENTSETTLPARTYSUBIDTYPE: FixTag = FixTag { id: "40240", datatype: value_types::INT};
-pub const NOLEGSTREAMS: FixTag = FixTag { id: "40241", datatype: value_types::NUM_IN_GROUP };
-pub const LEGSTREAMTYPE: FixTag = FixTag { id: "40242", datatype: value_types::INT};
-pub const LEGSTREAMDESC: FixTag = FixTag { id: "40243", datatype: value_types::STRING};
-pub const LEGSTREAMPAYSIDE: FixTag = FixTag { id: "40244", datatype: value_types::INT};
-pub const LEGSTREAMRECEIVESIDE: FixTag = FixTag { id: "40245", datatype: value_types::INT};
-pub const LEGSTREAMNOTIONAL: FixTag = FixTag { id: "40246", datatype: value_types::AMT};
-pub const LEGSTREAMCURRENCY: FixTag = FixTag { id: "40247", datatype: value_types::CURRENCY};
-pub const LEGSTREAMTEXT: FixTag = FixTag { id: "40248", datatype: value_types::STRING};
-pub const LEGSTREAMEFFECTIVEDATEUNADJUSTED: FixTag = FixTag { id: "40249", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGSTREAMEFFECTIVEDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40250", datatype: value_types::INT};
-pub const LEGSTREAMEFFECTIVEDATEBUSINESSCENTER: FixTag = FixTag { id: "40251", datatype: value_types::STRING};
-pub const LEGSTREAMEFFECTIVEDATERELATIVETO: FixTag = FixTag { id: "40252", datatype: value_types::INT};
-pub const LEGSTREAMEFFECTIVEDATEOFFSETPERIOD: FixTag = FixTag { id: "40253", datatype: value_types::INT};
-pub const LEGSTREAMEFFECTIVEDATEOFFSETUNIT: FixTag = FixTag { id: "40254", datatype: value_types::STRING};
-pub const LEGSTREAMEFFECTIVEDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40255", datatype: value_types::INT};
-pub const LEGSTREAMEFFECTIVEDATEADJUSTED: FixTag = FixTag { id: "40256", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGSTREAMTERMINATIONDATEUNADJUSTED: FixTag = FixTag { id: "40257", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGSTREAMTERMINATIONDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40258", datatype: value_types::INT};
-pub const LEGSTREAMTERMINATIONDATEBUSINESSCENTER: FixTag = FixTag { id: "40259", datatype: value_types::STRING};
-pub const LEGSTREAMTERMINATIONDATERELATIVETO: FixTag = FixTag { id: "40260", datatype: value_types::INT};
-pub const LEGSTREAMTERMINATIONDATEOFFSETPERIOD: FixTag = FixTag { id: "40261", datatype: value_types::INT};
-pub const LEGSTREAMTERMINATIONDATEOFFSETUNIT: FixTag = FixTag { id: "40262", datatype: value_types::STRING};
-pub const LEGSTREAMTERMINATIONDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40263", datatype: value_types::INT};
-pub const LEGSTREAMTERMINATIONDATEADJUSTED: FixTag = FixTag { id: "40264", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGSTREAMCALCULATIONPERIODBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40265", datatype: value_types::INT};
-pub const LEGSTREAMCALCULATIONPERIODBUSINESSCENTER: FixTag = FixTag { id: "40266", datatype: value_types::STRING};
-pub const LEGSTREAMFIRSTPERIODSTARTDATEUNADJUSTED: FixTag = FixTag { id: "40267", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGSTREAMFIRSTPERIODSTARTDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40268", datatype: value_types::INT};
-pub const LEGSTREAMFIRSTPERIODSTARTDATEBUSINESSCENTER: FixTag = FixTag { id: "40269", datatype: value_types::STRING};
-pub const LEGSTREAMFIRSTPERIODSTARTDATEADJUSTED: FixTag = FixTag { id: "40270", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGSTREAMFIRSTREGULARPERIODSTARTDATEUNADJUSTED: FixTag = FixTag { id: "40271", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGSTREAMFIRSTCOMPOUNDINGPERIODENDDATEUNADJUSTED: FixTag = FixTag { id: "40272", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGSTREAMLASTREGULARPERIODENDDATEUNADJUSTED: FixTag = FixTag { id: "40273", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGSTREAMCALCULATIONFREQUENCYPERIOD: FixTag = FixTag { id: "40274", datatype: value_types::INT};
-pub const LEGSTREAMCALCULATIONFREQUENCYUNIT: FixTag = FixTag { id: "40275", datatype: value_types::STRING};
-pub const LEGSTREAMCALCULATIONROLLCONVENTION: FixTag = FixTag { id: "40276", datatype: value_types::STRING};
-pub const NOCASHSETTLDEALERS: FixTag = FixTag { id: "40277", datatype: value_types::NUM_IN_GROUP };
-pub const NOBUSINESSCENTERS: FixTag = FixTag { id: "40278", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPAYMENTSTREAMTYPE: FixTag = FixTag { id: "40279", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMMARKETRATE: FixTag = FixTag { id: "40280", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMDELAYINDICATOR: FixTag = FixTag { id: "40281", datatype: value_types::BOOLEAN};
-pub const LEGPAYMENTSTREAMSETTLCURRENCY: FixTag = FixTag { id: "40282", datatype: value_types::CURRENCY};
-pub const LEGPAYMENTSTREAMDAYCOUNT: FixTag = FixTag { id: "40283", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMACCRUALDAYS: FixTag = FixTag { id: "40284", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMDISCOUNTTYPE: FixTag = FixTag { id: "40285", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMDISCOUNTRATE: FixTag = FixTag { id: "40286", datatype: value_types::PERCENTAGE};
-pub const LEGPAYMENTSTREAMDISCOUNTRATEDAYCOUNT: FixTag = FixTag { id: "40287", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGMETHOD: FixTag = FixTag { id: "40288", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMINITIALPRINCIPALEXCHANGEINDICATOR: FixTag = FixTag { id: "40289", datatype: value_types::BOOLEAN};
-pub const LEGPAYMENTSTREAMINTERIMPRINCIPALEXCHANGEINDICATOR: FixTag = FixTag { id: "40290", datatype: value_types::BOOLEAN};
-pub const LEGPAYMENTSTREAMFINALPRINCIPALEXCHANGEINDICATOR: FixTag = FixTag { id: "40291", datatype: value_types::BOOLEAN};
-pub const LEGPAYMENTSTREAMPAYMENTDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40292", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMPAYMENTDATEBUSINESSCENTER: FixTag = FixTag { id: "40293", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMPAYMENTFREQUENCYPERIOD: FixTag = FixTag { id: "40294", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMPAYMENTFREQUENCYUNIT: FixTag = FixTag { id: "40295", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMPAYMENTROLLCONVENTION: FixTag = FixTag { id: "40296", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMFIRSTPAYMENTDATEUNADJUSTED: FixTag = FixTag { id: "40297", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPAYMENTSTREAMLASTREGULARPAYMENTDATEUNADJUSTED: FixTag = FixTag { id: "40298", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPAYMENTSTREAMPAYMENTDATERELATIVETO: FixTag = FixTag { id: "40299", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMPAYMENTDATEOFFSETPERIOD: FixTag = FixTag { id: "40300", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMPAYMENTDATEOFFSETUNIT: FixTag = FixTag { id: "40301", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMPAYMENTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40302", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMRESETDATERELATIVETO: FixTag = FixTag { id: "40303", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMRESETDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40304", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMRESETDATEBUSINESSCENTER: FixTag = FixTag { id: "40305", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMRESETFREQUENCYPERIOD: FixTag = FixTag { id: "40306", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMRESETFREQUENCYUNIT: FixTag = FixTag { id: "40307", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMRESETWEEKLYROLLCONVENTION: FixTag = FixTag { id: "40308", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMINITIALFIXINGDATERELATIVETO: FixTag = FixTag { id: "40309", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMINITIALFIXINGDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40310", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMINITIALFIXINGDATEBUSINESSCENTER: FixTag = FixTag { id: "40311", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMINITIALFIXINGDATEOFFSETPERIOD: FixTag = FixTag { id: "40312", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMINITIALFIXINGDATEOFFSETUNIT: FixTag = FixTag { id: "40313", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMINITIALFIXINGDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40314", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMINITIALFIXINGDATEADJUSTED: FixTag = FixTag { id: "40315", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPAYMENTSTREAMFIXINGDATERELATIVETO: FixTag = FixTag { id: "40316", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMFIXINGDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40317", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMFIXINGDATEBUSINESSCENTER: FixTag = FixTag { id: "40318", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMFIXINGDATEOFFSETPERIOD: FixTag = FixTag { id: "40319", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMFIXINGDATEOFFSETUNIT: FixTag = FixTag { id: "40320", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMFIXINGDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40321", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMFIXINGDATEADJUSTED: FixTag = FixTag { id: "40322", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPAYMENTSTREAMRATECUTOFFDATEOFFSETPERIOD: FixTag = FixTag { id: "40323", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMRATECUTOFFDATEOFFSETUNIT: FixTag = FixTag { id: "40324", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMRATECUTOFFDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40325", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMRATE: FixTag = FixTag { id: "40326", datatype: value_types::PERCENTAGE};
-pub const LEGPAYMENTSTREAMFIXEDAMOUNT: FixTag = FixTag { id: "40327", datatype: value_types::AMT};
-pub const LEGPAYMENTSTREAMRATEORAMOUNTCURRENCY: FixTag = FixTag { id: "40328", datatype: value_types::CURRENCY};
-pub const LEGPAYMENTSTREAMFUTUREVALUENOTIONAL: FixTag = FixTag { id: "40329", datatype: value_types::AMT};
-pub const LEGPAYMENTSTREAMFUTUREVALUEDATEADJUSTED: FixTag = FixTag { id: "40330", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPAYMENTSTREAMRATEINDEX: FixTag = FixTag { id: "40331", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMRATEINDEXSOURCE: FixTag = FixTag { id: "40332", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMRATEINDEXCURVEUNIT: FixTag = FixTag { id: "40333", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMRATEINDEXCURVEPERIOD: FixTag = FixTag { id: "40334", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMRATEMULTIPLIER: FixTag = FixTag { id: "40335", datatype: value_types::FLOAT};
-pub const LEGPAYMENTSTREAMRATESPREAD: FixTag = FixTag { id: "40336", datatype: value_types::PRICE_OFFSET};
-pub const LEGPAYMENTSTREAMRATESPREADPOSITIONTYPE: FixTag = FixTag { id: "40337", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMRATETREATMENT: FixTag = FixTag { id: "40338", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCAPRATE: FixTag = FixTag { id: "40339", datatype: value_types::PERCENTAGE};
-pub const LEGPAYMENTSTREAMCAPRATEBUYSIDE: FixTag = FixTag { id: "40340", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCAPRATESELLSIDE: FixTag = FixTag { id: "40341", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMFLOORRATE: FixTag = FixTag { id: "40342", datatype: value_types::PERCENTAGE};
-pub const LEGPAYMENTSTREAMFLOORRATEBUYSIDE: FixTag = FixTag { id: "40343", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMFLOORRATESELLSIDE: FixTag = FixTag { id: "40344", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMINITIALRATE: FixTag = FixTag { id: "40345", datatype: value_types::PERCENTAGE};
-pub const LEGPAYMENTSTREAMFINALRATEROUNDINGDIRECTION: FixTag = FixTag { id: "40346", datatype: value_types::CHAR};
-pub const LEGPAYMENTSTREAMFINALRATEPRECISION: FixTag = FixTag { id: "40347", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMAVERAGINGMETHOD: FixTag = FixTag { id: "40348", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMNEGATIVERATETREATMENT: FixTag = FixTag { id: "40349", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMINFLATIONLAGPERIOD: FixTag = FixTag { id: "40350", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMINFLATIONLAGUNIT: FixTag = FixTag { id: "40351", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMINFLATIONLAGDAYTYPE: FixTag = FixTag { id: "40352", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMINFLATIONINTERPOLATIONMETHOD: FixTag = FixTag { id: "40353", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMINFLATIONINDEXSOURCE: FixTag = FixTag { id: "40354", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMINFLATIONPUBLICATIONSOURCE: FixTag = FixTag { id: "40355", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMINFLATIONINITIALINDEXLEVEL: FixTag = FixTag { id: "40356", datatype: value_types::FLOAT};
-pub const LEGPAYMENTSTREAMINFLATIONFALLBACKBONDAPPLICABLE: FixTag = FixTag { id: "40357", datatype: value_types::BOOLEAN};
-pub const LEGPAYMENTSTREAMFRADISCOUNTING: FixTag = FixTag { id: "40358", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMNONDELIVERABLEREFCURRENCY: FixTag = FixTag { id: "40359", datatype: value_types::CURRENCY};
-pub const LEGPAYMENTSTREAMNONDELIVERABLEFIXINGDATESBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40360", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMNONDELIVERABLEFIXINGDATESBUSINESSCENTER: FixTag = FixTag { id: "40361", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMNONDELIVERABLEFIXINGDATESRELATIVETO: FixTag = FixTag { id: "40362", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMNONDELIVERABLEFIXINGDATESOFFSETPERIOD: FixTag = FixTag { id: "40363", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMNONDELIVERABLEFIXINGDATESOFFSETUNIT: FixTag = FixTag { id: "40364", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMNONDELIVERABLEFIXINGDATESOFFSETDAYTYPE: FixTag = FixTag { id: "40365", datatype: value_types::INT};
-pub const LEGSETTLRATEFALLBACKRATESOURCE: FixTag = FixTag { id: "40366", datatype: value_types::INT};
-pub const NOLEGNONDELIVERABLEFIXINGDATES: FixTag = FixTag { id: "40367", datatype: value_types::NUM_IN_GROUP };
-pub const LEGNONDELIVERABLEFIXINGDATE: FixTag = FixTag { id: "40368", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGNONDELIVERABLEFIXINGDATETYPE: FixTag = FixTag { id: "40369", datatype: value_types::INT};
-pub const LEGSETTLRATEFALLBACKREFERENCEPAGE: FixTag = FixTag { id: "40370", datatype: value_types::STRING};
-pub const PAYMENTSTREAMNONDELIVERABLESETTLRATESOURCE: FixTag = FixTag { id: "40371", datatype: value_types::INT};
-pub const PAYMENTSTREAMNONDELIVERABLESETTLREFERENCEPAGE: FixTag = FixTag { id: "40372", datatype: value_types::STRING};
-pub const SETTLRATEFALLBACKRATESOURCE: FixTag = FixTag { id: "40373", datatype: value_types::INT};
-pub const NOLEGPAYMENTSCHEDULES: FixTag = FixTag { id: "40374", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPAYMENTSCHEDULETYPE: FixTag = FixTag { id: "40375", datatype: value_types::INT};
-pub const LEGPAYMENTSCHEDULESTUBTYPE: FixTag = FixTag { id: "40376", datatype: value_types::INT};
-pub const LEGPAYMENTSCHEDULESTARTDATEUNADJUSTED: FixTag = FixTag { id: "40377", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPAYMENTSCHEDULEENDDATEUNADJUSTED: FixTag = FixTag { id: "40378", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPAYMENTSCHEDULEPAYSIDE: FixTag = FixTag { id: "40379", datatype: value_types::INT};
-pub const LEGPAYMENTSCHEDULERECEIVESIDE: FixTag = FixTag { id: "40380", datatype: value_types::INT};
-pub const LEGPAYMENTSCHEDULENOTIONAL: FixTag = FixTag { id: "40381", datatype: value_types::AMT};
-pub const LEGPAYMENTSCHEDULECURRENCY: FixTag = FixTag { id: "40382", datatype: value_types::CURRENCY};
-pub const LEGPAYMENTSCHEDULERATE: FixTag = FixTag { id: "40383", datatype: value_types::PERCENTAGE};
-pub const LEGPAYMENTSCHEDULERATEMULTIPLIER: FixTag = FixTag { id: "40384", datatype: value_types::FLOAT};
-pub const LEGPAYMENTSCHEDULERATESPREAD: FixTag = FixTag { id: "40385", datatype: value_types::PRICE_OFFSET};
-pub const LEGPAYMENTSCHEDULERATESPREADPOSITIONTYPE: FixTag = FixTag { id: "40386", datatype: value_types::INT};
-pub const LEGPAYMENTSCHEDULERATETREATMENT: FixTag = FixTag { id: "40387", datatype: value_types::INT};
-pub const LEGPAYMENTSCHEDULEFIXEDAMOUNT: FixTag = FixTag { id: "40388", datatype: value_types::AMT};
-pub const LEGPAYMENTSCHEDULEFIXEDCURRENCY: FixTag = FixTag { id: "40389", datatype: value_types::CURRENCY};
-pub const LEGPAYMENTSCHEDULESTEPFREQUENCYPERIOD: FixTag = FixTag { id: "40390", datatype: value_types::INT};
-pub const LEGPAYMENTSCHEDULESTEPFREQUENCYUNIT: FixTag = FixTag { id: "40391", datatype: value_types::STRING};
-pub const LEGPAYMENTSCHEDULESTEPOFFSETVALUE: FixTag = FixTag { id: "40392", datatype: value_types::AMT};
-pub const LEGPAYMENTSCHEDULESTEPRATE: FixTag = FixTag { id: "40393", datatype: value_types::PERCENTAGE};
-pub const LEGPAYMENTSCHEDULESTEPOFFSETRATE: FixTag = FixTag { id: "40394", datatype: value_types::PERCENTAGE};
-pub const LEGPAYMENTSCHEDULESTEPRELATIVETO: FixTag = FixTag { id: "40395", datatype: value_types::INT};
-pub const LEGPAYMENTSCHEDULEFIXINGDATEUNADJUSTED: FixTag = FixTag { id: "40396", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPAYMENTSCHEDULEWEIGHT: FixTag = FixTag { id: "40397", datatype: value_types::FLOAT};
-pub const LEGPAYMENTSCHEDULEFIXINGDATERELATIVETO: FixTag = FixTag { id: "40398", datatype: value_types::INT};
-pub const LEGPAYMENTSCHEDULEFIXINGDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40399", datatype: value_types::INT};
-pub const LEGPAYMENTSCHEDULEFIXINGDATEBUSINESSCENTER: FixTag = FixTag { id: "40400", datatype: value_types::STRING};
-pub const LEGPAYMENTSCHEDULEFIXINGDATEOFFSETPERIOD: FixTag = FixTag { id: "40401", datatype: value_types::INT};
-pub const LEGPAYMENTSCHEDULEFIXINGDATEOFFSETUNIT: FixTag = FixTag { id: "40402", datatype: value_types::STRING};
-pub const LEGPAYMENTSCHEDULEFIXINGDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40403", datatype: value_types::INT};
-pub const LEGPAYMENTSCHEDULEFIXINGDATEADJUSTED: FixTag = FixTag { id: "40404", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPAYMENTSCHEDULEFIXINGTIME: FixTag = FixTag { id: "40405", datatype: value_types::LOCAL_MKT_TIME};
-pub const LEGPAYMENTSCHEDULEFIXINGTIMEBUSINESSCENTER: FixTag = FixTag { id: "40406", datatype: value_types::STRING};
-pub const LEGPAYMENTSCHEDULEINTERIMEXCHANGEPAYMENTDATERELATIVETO: FixTag = FixTag { id: "40407", datatype: value_types::INT};
-pub const LEGPAYMENTSCHEDULEINTERIMEXCHANGEDATESBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40408", datatype: value_types::INT};
-pub const LEGPAYMENTSCHEDULEINTERIMEXCHANGEDATESBUSINESSCENTER: FixTag = FixTag { id: "40409", datatype: value_types::STRING};
-pub const LEGPAYMENTSCHEDULEINTERIMEXCHANGEDATESOFFSETPERIOD: FixTag = FixTag { id: "40410", datatype: value_types::INT};
-pub const LEGPAYMENTSCHEDULEINTERIMEXCHANGEDATESOFFSETUNIT: FixTag = FixTag { id: "40411", datatype: value_types::STRING};
-pub const LEGPAYMENTSCHEDULEINTERIMEXCHANGEDATESOFFSETDAYTYPE: FixTag = FixTag { id: "40412", datatype: value_types::INT};
-pub const LEGPAYMENTSCHEDULEINTERIMEXCHANGEDATEADJUSTED: FixTag = FixTag { id: "40413", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOLEGPAYMENTSCHEDULERATESOURCES: FixTag = FixTag { id: "40414", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPAYMENTSCHEDULERATESOURCE: FixTag = FixTag { id: "40415", datatype: value_types::INT};
-pub const LEGPAYMENTSCHEDULERATESOURCETYPE: FixTag = FixTag { id: "40416", datatype: value_types::INT};
-pub const LEGPAYMENTSCHEDULEREFERENCEPAGE: FixTag = FixTag { id: "40417", datatype: value_types::STRING};
-pub const NOLEGPAYMENTSTUBS: FixTag = FixTag { id: "40418", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPAYMENTSTUBTYPE: FixTag = FixTag { id: "40419", datatype: value_types::INT};
-pub const LEGPAYMENTSTUBLENGTH: FixTag = FixTag { id: "40420", datatype: value_types::INT};
-pub const LEGPAYMENTSTUBRATE: FixTag = FixTag { id: "40421", datatype: value_types::PERCENTAGE};
-pub const LEGPAYMENTSTUBFIXEDAMOUNT: FixTag = FixTag { id: "40422", datatype: value_types::AMT};
-pub const LEGPAYMENTSTUBFIXEDCURRENCY: FixTag = FixTag { id: "40423", datatype: value_types::CURRENCY};
-pub const LEGPAYMENTSTUBINDEX: FixTag = FixTag { id: "40424", datatype: value_types::STRING};
-pub const LEGPAYMENTSTUBINDEXSOURCE: FixTag = FixTag { id: "40425", datatype: value_types::INT};
-pub const LEGPAYMENTSTUBINDEXCURVEPERIOD: FixTag = FixTag { id: "40426", datatype: value_types::INT};
-pub const LEGPAYMENTSTUBINDEXCURVEUNIT: FixTag = FixTag { id: "40427", datatype: value_types::STRING};
-pub const LEGPAYMENTSTUBINDEXRATEMULTIPLIER: FixTag = FixTag { id: "40428", datatype: value_types::FLOAT};
-pub const LEGPAYMENTSTUBINDEXRATESPREAD: FixTag = FixTag { id: "40429", datatype: value_types::PRICE_OFFSET};
-pub const LEGPAYMENTSTUBINDEXRATESPREADPOSITIONTYPE: FixTag = FixTag { id: "40430", datatype: value_types::INT};
-pub const LEGPAYMENTSTUBINDEXRATETREATMENT: FixTag = FixTag { id: "40431", datatype: value_types::INT};
-pub const LEGPAYMENTSTUBINDEXCAPRATE: FixTag = FixTag { id: "40432", datatype: value_types::PERCENTAGE};
-pub const LEGPAYMENTSTUBINDEXCAPRATEBUYSIDE: FixTag = FixTag { id: "40433", datatype: value_types::INT};
-pub const LEGPAYMENTSTUBINDEXCAPRATESELLSIDE: FixTag = FixTag { id: "40434", datatype: value_types::INT};
-pub const LEGPAYMENTSTUBINDEXFLOORRATE: FixTag = FixTag { id: "40435", datatype: value_types::PERCENTAGE};
-pub const LEGPAYMENTSTUBINDEXFLOORRATEBUYSIDE: FixTag = FixTag { id: "40436", datatype: value_types::INT};
-pub const LEGPAYMENTSTUBINDEXFLOORRATESELLSIDE: FixTag = FixTag { id: "40437", datatype: value_types::INT};
-pub const LEGPAYMENTSTUBINDEX2: FixTag = FixTag { id: "40438", datatype: value_types::STRING};
-pub const LEGPAYMENTSTUBINDEX2SOURCE: FixTag = FixTag { id: "40439", datatype: value_types::INT};
-pub const LEGPAYMENTSTUBINDEX2CURVEPERIOD: FixTag = FixTag { id: "40440", datatype: value_types::INT};
-pub const LEGPAYMENTSTUBINDEX2CURVEUNIT: FixTag = FixTag { id: "40441", datatype: value_types::STRING};
-pub const LEGPAYMENTSTUBINDEX2RATEMULTIPLIER: FixTag = FixTag { id: "40442", datatype: value_types::FLOAT};
-pub const LEGPAYMENTSTUBINDEX2RATESPREAD: FixTag = FixTag { id: "40443", datatype: value_types::PRICE_OFFSET};
-pub const LEGPAYMENTSTUBINDEX2RATESPREADPOSITIONTYPE: FixTag = FixTag { id: "40444", datatype: value_types::INT};
-pub const LEGPAYMENTSTUBINDEX2RATETREATMENT: FixTag = FixTag { id: "40445", datatype: value_types::INT};
-pub const LEGPAYMENTSTUBINDEX2CAPRATE: FixTag = FixTag { id: "40446", datatype: value_types::PERCENTAGE};
-pub const LEGPAYMENTSTUBINDEX2FLOORRATE: FixTag = FixTag { id: "40447", datatype: value_types::PERCENTAGE};
-pub const NOLEGPROVISIONS: FixTag = FixTag { id: "40448", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPROVISIONTYPE: FixTag = FixTag { id: "40449", datatype: value_types::INT};
-pub const LEGPROVISIONDATEUNADJUSTED: FixTag = FixTag { id: "40450", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPROVISIONDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40451", datatype: value_types::INT};
-pub const LEGPROVISIONDATEBUSINESSCENTER: FixTag = FixTag { id: "40452", datatype: value_types::STRING};
-pub const LEGPROVISIONDATEADJUSTED: FixTag = FixTag { id: "40453", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPROVISIONDATETENORPERIOD: FixTag = FixTag { id: "40454", datatype: value_types::INT};
-pub const LEGPROVISIONDATETENORUNIT: FixTag = FixTag { id: "40455", datatype: value_types::STRING};
-pub const LEGPROVISIONCALCULATIONAGENT: FixTag = FixTag { id: "40456", datatype: value_types::INT};
-pub const LEGPROVISIONOPTIONSINGLEPARTYBUYERSIDE: FixTag = FixTag { id: "40457", datatype: value_types::INT};
-pub const LEGPROVISIONOPTIONSINGLEPARTYSELLERSIDE: FixTag = FixTag { id: "40458", datatype: value_types::INT};
-pub const LEGPROVISIONOPTIONEXERCISESTYLE: FixTag = FixTag { id: "40459", datatype: value_types::INT};
-pub const LEGPROVISIONOPTIONEXERCISEMULTIPLENOTIONAL: FixTag = FixTag { id: "40460", datatype: value_types::AMT};
-pub const LEGPROVISIONOPTIONEXERCISEMINIMUMNOTIONAL: FixTag = FixTag { id: "40461", datatype: value_types::AMT};
-pub const LEGPROVISIONOPTIONEXERCISEMAXIMUMNOTIONAL: FixTag = FixTag { id: "40462", datatype: value_types::AMT};
-pub const LEGPROVISIONOPTIONMINIMUMNUMBER: FixTag = FixTag { id: "40463", datatype: value_types::INT};
-pub const LEGPROVISIONOPTIONMAXIMUMNUMBER: FixTag = FixTag { id: "40464", datatype: value_types::INT};
-pub const LEGPROVISIONOPTIONEXERCISECONFIRMATION: FixTag = FixTag { id: "40465", datatype: value_types::BOOLEAN};
-pub const LEGPROVISIONCASHSETTLMETHOD: FixTag = FixTag { id: "40466", datatype: value_types::INT};
-pub const LEGPROVISIONCASHSETTLCURRENCY: FixTag = FixTag { id: "40467", datatype: value_types::CURRENCY};
-pub const LEGPROVISIONCASHSETTLCURRENCY2: FixTag = FixTag { id: "40468", datatype: value_types::CURRENCY};
-pub const LEGPROVISIONCASHSETTLQUOTETYPE: FixTag = FixTag { id: "40469", datatype: value_types::INT};
-pub const LEGPROVISIONCASHSETTLQUOTESOURCE: FixTag = FixTag { id: "40470", datatype: value_types::INT};
-pub const BUSINESSCENTER: FixTag = FixTag { id: "40471", datatype: value_types::STRING};
-pub const LEGPROVISIONTEXT: FixTag = FixTag { id: "40472", datatype: value_types::STRING};
-pub const NOLEGPROVISIONCASHSETTLPAYMENTDATES: FixTag = FixTag { id: "40473", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPROVISIONCASHSETTLPAYMENTDATE: FixTag = FixTag { id: "40474", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPROVISIONCASHSETTLPAYMENTDATETYPE: FixTag = FixTag { id: "40475", datatype: value_types::INT};
-pub const LEGPROVISIONOPTIONEXERCISEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40476", datatype: value_types::INT};
-pub const LEGPROVISIONOPTIONEXERCISEBUSINESSCENTER: FixTag = FixTag { id: "40477", datatype: value_types::STRING};
-pub const LEGPROVISIONOPTIONEXERCISEEARLIESTDATEOFFSETPERIOD: FixTag = FixTag { id: "40478", datatype: value_types::INT};
-pub const LEGPROVISIONOPTIONEXERCISEEARLIESTDATEOFFSETUNIT: FixTag = FixTag { id: "40479", datatype: value_types::STRING};
-pub const LEGPROVISIONOPTIONEXERCISEFREQUENCYPERIOD: FixTag = FixTag { id: "40480", datatype: value_types::INT};
-pub const LEGPROVISIONOPTIONEXERCISEFREQUENCYUNIT: FixTag = FixTag { id: "40481", datatype: value_types::STRING};
-pub const LEGPROVISIONOPTIONEXERCISESTARTDATEUNADJUSTED: FixTag = FixTag { id: "40482", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPROVISIONOPTIONEXERCISESTARTDATERELATIVETO: FixTag = FixTag { id: "40483", datatype: value_types::INT};
-pub const LEGPROVISIONOPTIONEXERCISESTARTDATEOFFSETPERIOD: FixTag = FixTag { id: "40484", datatype: value_types::INT};
-pub const LEGPROVISIONOPTIONEXERCISESTARTDATEOFFSETUNIT: FixTag = FixTag { id: "40485", datatype: value_types::STRING};
-pub const LEGPROVISIONOPTIONEXERCISESTARTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40486", datatype: value_types::INT};
-pub const LEGPROVISIONOPTIONEXERCISESTARTDATEADJUSTED: FixTag = FixTag { id: "40487", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPROVISIONOPTIONEXERCISEPERIODSKIP: FixTag = FixTag { id: "40488", datatype: value_types::INT};
-pub const LEGPROVISIONOPTIONEXERCISEBOUNDSFIRSTDATEUNADJUSTED: FixTag = FixTag { id: "40489", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPROVISIONOPTIONEXERCISEBOUNDSLASTDATEUNADJUSTED: FixTag = FixTag { id: "40490", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPROVISIONOPTIONEXERCISEEARLIESTTIME: FixTag = FixTag { id: "40491", datatype: value_types::LOCAL_MKT_TIME};
-pub const LEGPROVISIONOPTIONEXERCISEEARLIESTTIMEBUSINESSCENTER: FixTag = FixTag { id: "40492", datatype: value_types::STRING};
-pub const LEGPROVISIONOPTIONEXERCISELATESTTIME: FixTag = FixTag { id: "40493", datatype: value_types::LOCAL_MKT_TIME};
-pub const LEGPROVISIONOPTIONEXERCISELATESTTIMEBUSINESSCENTER: FixTag = FixTag { id: "40494", datatype: value_types::STRING};
-pub const NOLEGPROVISIONOPTIONEXERCISEFIXEDDATES: FixTag = FixTag { id: "40495", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPROVISIONOPTIONEXERCISEFIXEDDATE: FixTag = FixTag { id: "40496", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPROVISIONOPTIONEXERCISEFIXEDDATETYPE: FixTag = FixTag { id: "40497", datatype: value_types::INT};
-pub const LEGPROVISIONOPTIONEXPIRATIONDATEUNADJUSTED: FixTag = FixTag { id: "40498", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPROVISIONOPTIONEXPIRATIONDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40499", datatype: value_types::INT};
-pub const LEGPROVISIONOPTIONEXPIRATIONDATEBUSINESSCENTER: FixTag = FixTag { id: "40500", datatype: value_types::STRING};
-pub const LEGPROVISIONOPTIONEXPIRATIONDATERELATIVETO: FixTag = FixTag { id: "40501", datatype: value_types::INT};
-pub const LEGPROVISIONOPTIONEXPIRATIONDATEOFFSETPERIOD: FixTag = FixTag { id: "40502", datatype: value_types::INT};
-pub const LEGPROVISIONOPTIONEXPIRATIONDATEOFFSETUNIT: FixTag = FixTag { id: "40503", datatype: value_types::STRING};
-pub const LEGPROVISIONOPTIONEXPIRATIONDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40504", datatype: value_types::INT};
-pub const LEGPROVISIONOPTIONEXPIRATIONDATEADJUSTED: FixTag = FixTag { id: "40505", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPROVISIONOPTIONEXPIRATIONTIME: FixTag = FixTag { id: "40506", datatype: value_types::LOCAL_MKT_TIME};
-pub const LEGPROVISIONOPTIONEXPIRATIONTIMEBUSINESSCENTER: FixTag = FixTag { id: "40507", datatype: value_types::STRING};
-pub const LEGPROVISIONOPTIONRELEVANTUNDERLYINGDATEUNADJUSTED: FixTag = FixTag { id: "40508", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPROVISIONOPTIONRELEVANTUNDERLYINGDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40509", datatype: value_types::INT};
-pub const LEGPROVISIONOPTIONRELEVANTUNDERLYINGDATEBUSINESSCENTER: FixTag = FixTag { id: "40510", datatype: value_types::STRING};
-pub const LEGPROVISIONOPTIONRELEVANTUNDERLYINGDATERELATIVETO: FixTag = FixTag { id: "40511", datatype: value_types::INT};
-pub const LEGPROVISIONOPTIONRELEVANTUNDERLYINGDATEOFFSETPERIOD: FixTag = FixTag { id: "40512", datatype: value_types::INT};
-pub const LEGPROVISIONOPTIONRELEVANTUNDERLYINGDATEOFFSETUNIT: FixTag = FixTag { id: "40513", datatype: value_types::STRING};
-pub const LEGPROVISIONOPTIONRELEVANTUNDERLYINGDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40514", datatype: value_types::INT};
-pub const LEGPROVISIONOPTIONRELEVANTUNDERLYINGDATEADJUSTED: FixTag = FixTag { id: "40515", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPROVISIONCASHSETTLPAYMENTDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40516", datatype: value_types::INT};
-pub const LEGPROVISIONCASHSETTLPAYMENTDATEBUSINESSCENTER: FixTag = FixTag { id: "40517", datatype: value_types::STRING};
-pub const LEGPROVISIONCASHSETTLPAYMENTDATERELATIVETO: FixTag = FixTag { id: "40518", datatype: value_types::INT};
-pub const LEGPROVISIONCASHSETTLPAYMENTDATEOFFSETPERIOD: FixTag = FixTag { id: "40519", datatype: value_types::INT};
-pub const LEGPROVISIONCASHSETTLPAYMENTDATEOFFSETUNIT: FixTag = FixTag { id: "40520", datatype: value_types::STRING};
-pub const LEGPROVISIONCASHSETTLPAYMENTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40521", datatype: value_types::INT};
-pub const LEGPROVISIONCASHSETTLPAYMENTDATERANGEFIRST: FixTag = FixTag { id: "40522", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPROVISIONCASHSETTLPAYMENTDATERANGELAST: FixTag = FixTag { id: "40523", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPROVISIONCASHSETTLVALUETIME: FixTag = FixTag { id: "40524", datatype: value_types::LOCAL_MKT_TIME};
-pub const LEGPROVISIONCASHSETTLVALUETIMEBUSINESSCENTER: FixTag = FixTag { id: "40525", datatype: value_types::STRING};
-pub const LEGPROVISIONCASHSETTLVALUEDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40526", datatype: value_types::INT};
-pub const LEGPROVISIONCASHSETTLVALUEDATEBUSINESSCENTER: FixTag = FixTag { id: "40527", datatype: value_types::STRING};
-pub const LEGPROVISIONCASHSETTLVALUEDATERELATIVETO: FixTag = FixTag { id: "40528", datatype: value_types::INT};
-pub const LEGPROVISIONCASHSETTLVALUEDATEOFFSETPERIOD: FixTag = FixTag { id: "40529", datatype: value_types::INT};
-pub const LEGPROVISIONCASHSETTLVALUEDATEOFFSETUNIT: FixTag = FixTag { id: "40530", datatype: value_types::STRING};
-pub const LEGPROVISIONCASHSETTLVALUEDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40531", datatype: value_types::INT};
-pub const LEGPROVISIONCASHSETTLVALUEDATEADJUSTED: FixTag = FixTag { id: "40532", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOLEGPROVISIONPARTYIDS: FixTag = FixTag { id: "40533", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPROVISIONPARTYID: FixTag = FixTag { id: "40534", datatype: value_types::STRING};
-pub const LEGPROVISIONPARTYIDSOURCE: FixTag = FixTag { id: "40535", datatype: value_types::CHAR};
-pub const LEGPROVISIONPARTYROLE: FixTag = FixTag { id: "40536", datatype: value_types::INT};
-pub const NOLEGPROVISIONPARTYSUBIDS: FixTag = FixTag { id: "40537", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPROVISIONPARTYSUBID: FixTag = FixTag { id: "40538", datatype: value_types::STRING};
-pub const LEGPROVISIONPARTYSUBIDTYPE: FixTag = FixTag { id: "40539", datatype: value_types::INT};
-pub const NOUNDERLYINGSTREAMS: FixTag = FixTag { id: "40540", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGSTREAMTYPE: FixTag = FixTag { id: "40541", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMDESC: FixTag = FixTag { id: "40542", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMPAYSIDE: FixTag = FixTag { id: "40543", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMRECEIVESIDE: FixTag = FixTag { id: "40544", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMNOTIONAL: FixTag = FixTag { id: "40545", datatype: value_types::AMT};
-pub const UNDERLYINGSTREAMCURRENCY: FixTag = FixTag { id: "40546", datatype: value_types::CURRENCY};
-pub const UNDERLYINGSTREAMTEXT: FixTag = FixTag { id: "40547", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMTERMINATIONDATEUNADJUSTED: FixTag = FixTag { id: "40548", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGSTREAMTERMINATIONDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40549", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMTERMINATIONDATEBUSINESSCENTER: FixTag = FixTag { id: "40550", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMTERMINATIONDATERELATIVETO: FixTag = FixTag { id: "40551", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMTERMINATIONDATEOFFSETPERIOD: FixTag = FixTag { id: "40552", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMTERMINATIONDATEOFFSETUNIT: FixTag = FixTag { id: "40553", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMTERMINATIONDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40554", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMTERMINATIONDATEADJUSTED: FixTag = FixTag { id: "40555", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGSTREAMCALCULATIONPERIODBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40556", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMCALCULATIONPERIODBUSINESSCENTER: FixTag = FixTag { id: "40557", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMFIRSTPERIODSTARTDATEUNADJUSTED: FixTag = FixTag { id: "40558", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGSTREAMFIRSTPERIODSTARTDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40559", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMFIRSTPERIODSTARTDATEBUSINESSCENTER: FixTag = FixTag { id: "40560", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMFIRSTPERIODSTARTDATEADJUSTED: FixTag = FixTag { id: "40561", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGSTREAMFIRSTREGULARPERIODSTARTDATEUNADJUSTED: FixTag = FixTag { id: "40562", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGSTREAMFIRSTCOMPOUNDINGPERIODENDDATEUNADJUSTED: FixTag = FixTag { id: "40563", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGSTREAMLASTREGULARPERIODENDDATEUNADJUSTED: FixTag = FixTag { id: "40564", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGSTREAMCALCULATIONFREQUENCYPERIOD: FixTag = FixTag { id: "40565", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMCALCULATIONFREQUENCYUNIT: FixTag = FixTag { id: "40566", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMCALCULATIONROLLCONVENTION: FixTag = FixTag { id: "40567", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMTYPE: FixTag = FixTag { id: "40568", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMMARKETRATE: FixTag = FixTag { id: "40569", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMDELAYINDICATOR: FixTag = FixTag { id: "40570", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGPAYMENTSTREAMSETTLCURRENCY: FixTag = FixTag { id: "40571", datatype: value_types::CURRENCY};
-pub const UNDERLYINGPAYMENTSTREAMDAYCOUNT: FixTag = FixTag { id: "40572", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMACCRUALDAYS: FixTag = FixTag { id: "40573", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMDISCOUNTTYPE: FixTag = FixTag { id: "40574", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMDISCOUNTRATE: FixTag = FixTag { id: "40575", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGPAYMENTSTREAMDISCOUNTRATEDAYCOUNT: FixTag = FixTag { id: "40576", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGMETHOD: FixTag = FixTag { id: "40577", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMINITIALPRINCIPALEXCHANGEINDICATOR: FixTag = FixTag { id: "40578", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGPAYMENTSTREAMINTERIMPRINCIPALEXCHANGEINDICATOR: FixTag = FixTag { id: "40579", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGPAYMENTSTREAMFINALPRINCIPALEXCHANGEINDICATOR: FixTag = FixTag { id: "40580", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGPAYMENTSTREAMPAYMENTDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40581", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMPAYMENTDATEBUSINESSCENTER: FixTag = FixTag { id: "40582", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMPAYMENTFREQUENCYPERIOD: FixTag = FixTag { id: "40583", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMPAYMENTFREQUENCYUNIT: FixTag = FixTag { id: "40584", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMPAYMENTROLLCONVENTION: FixTag = FixTag { id: "40585", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMFIRSTPAYMENTDATEUNADJUSTED: FixTag = FixTag { id: "40586", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPAYMENTSTREAMLASTREGULARPAYMENTDATEUNADJUSTED: FixTag = FixTag { id: "40587", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPAYMENTSTREAMPAYMENTDATERELATIVETO: FixTag = FixTag { id: "40588", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMPAYMENTDATEOFFSETPERIOD: FixTag = FixTag { id: "40589", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMPAYMENTDATEOFFSETUNIT: FixTag = FixTag { id: "40590", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMPAYMENTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40591", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMRESETDATERELATIVETO: FixTag = FixTag { id: "40592", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMRESETDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40593", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMRESETDATEBUSINESSCENTER: FixTag = FixTag { id: "40594", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMRESETFREQUENCYPERIOD: FixTag = FixTag { id: "40595", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMRESETFREQUENCYUNIT: FixTag = FixTag { id: "40596", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMRESETWEEKLYROLLCONVENTION: FixTag = FixTag { id: "40597", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMINITIALFIXINGDATERELATIVETO: FixTag = FixTag { id: "40598", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMINITIALFIXINGDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40599", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMINITIALFIXINGDATEBUSINESSCENTER: FixTag = FixTag { id: "40600", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMINITIALFIXINGDATEOFFSETPERIOD: FixTag = FixTag { id: "40601", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMINITIALFIXINGDATEOFFSETUNIT: FixTag = FixTag { id: "40602", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMINITIALFIXINGDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40603", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMINITIALFIXINGDATEADJUSTED: FixTag = FixTag { id: "40604", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPAYMENTSTREAMFIXINGDATERELATIVETO: FixTag = FixTag { id: "40605", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMFIXINGDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40606", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMFIXINGDATEBUSINESSCENTER: FixTag = FixTag { id: "40607", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMFIXINGDATEOFFSETPERIOD: FixTag = FixTag { id: "40608", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMFIXINGDATEOFFSETUNIT: FixTag = FixTag { id: "40609", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMFIXINGDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40610", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMFIXINGDATEADJUSTED: FixTag = FixTag { id: "40611", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPAYMENTSTREAMRATECUTOFFDATEOFFSETPERIOD: FixTag = FixTag { id: "40612", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMRATECUTOFFDATEOFFSETUNIT: FixTag = FixTag { id: "40613", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMRATECUTOFFDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40614", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMRATE: FixTag = FixTag { id: "40615", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGPAYMENTSTREAMFIXEDAMOUNT: FixTag = FixTag { id: "40616", datatype: value_types::AMT};
-pub const UNDERLYINGPAYMENTSTREAMRATEORAMOUNTCURRENCY: FixTag = FixTag { id: "40617", datatype: value_types::CURRENCY};
-pub const UNDERLYINGPAYMENTSTREAMFUTUREVALUENOTIONAL: FixTag = FixTag { id: "40618", datatype: value_types::AMT};
-pub const UNDERLYINGPAYMENTSTREAMFUTUREVALUEDATEADJUSTED: FixTag = FixTag { id: "40619", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPAYMENTSTREAMRATEINDEX: FixTag = FixTag { id: "40620", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMRATEINDEXSOURCE: FixTag = FixTag { id: "40621", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMRATEINDEXCURVEUNIT: FixTag = FixTag { id: "40622", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMRATEINDEXCURVEPERIOD: FixTag = FixTag { id: "40623", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMRATEMULTIPLIER: FixTag = FixTag { id: "40624", datatype: value_types::FLOAT};
-pub const UNDERLYINGPAYMENTSTREAMRATESPREAD: FixTag = FixTag { id: "40625", datatype: value_types::PRICE_OFFSET};
-pub const UNDERLYINGPAYMENTSTREAMRATESPREADPOSITIONTYPE: FixTag = FixTag { id: "40626", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMRATETREATMENT: FixTag = FixTag { id: "40627", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCAPRATE: FixTag = FixTag { id: "40628", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGPAYMENTSTREAMCAPRATEBUYSIDE: FixTag = FixTag { id: "40629", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCAPRATESELLSIDE: FixTag = FixTag { id: "40630", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMFLOORRATE: FixTag = FixTag { id: "40631", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGPAYMENTSTREAMFLOORRATEBUYSIDE: FixTag = FixTag { id: "40632", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMFLOORRATESELLSIDE: FixTag = FixTag { id: "40633", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMINITIALRATE: FixTag = FixTag { id: "40634", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGPAYMENTSTREAMFINALRATEROUNDINGDIRECTION: FixTag = FixTag { id: "40635", datatype: value_types::CHAR};
-pub const UNDERLYINGPAYMENTSTREAMFINALRATEPRECISION: FixTag = FixTag { id: "40636", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMAVERAGINGMETHOD: FixTag = FixTag { id: "40637", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMNEGATIVERATETREATMENT: FixTag = FixTag { id: "40638", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMINFLATIONLAGPERIOD: FixTag = FixTag { id: "40639", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMINFLATIONLAGUNIT: FixTag = FixTag { id: "40640", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMINFLATIONLAGDAYTYPE: FixTag = FixTag { id: "40641", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMINFLATIONINTERPOLATIONMETHOD: FixTag = FixTag { id: "40642", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMINFLATIONINDEXSOURCE: FixTag = FixTag { id: "40643", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMINFLATIONPUBLICATIONSOURCE: FixTag = FixTag { id: "40644", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMINFLATIONINITIALINDEXLEVEL: FixTag = FixTag { id: "40645", datatype: value_types::FLOAT};
-pub const UNDERLYINGPAYMENTSTREAMINFLATIONFALLBACKBONDAPPLICABLE: FixTag = FixTag { id: "40646", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGPAYMENTSTREAMFRADISCOUNTING: FixTag = FixTag { id: "40647", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMNONDELIVERABLEREFCURRENCY: FixTag = FixTag { id: "40648", datatype: value_types::CURRENCY};
-pub const UNDERLYINGPAYMENTSTREAMNONDELIVERABLEFIXINGDATESBIZDAYCONVENTION: FixTag = FixTag { id: "40649", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMNONDELIVERABLEFIXINGDATESBUSINESSCENTER: FixTag = FixTag { id: "40650", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMNONDELIVERABLEFIXINGDATESRELATIVETO: FixTag = FixTag { id: "40651", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMNONDELIVERABLEFIXINGDATESOFFSETPERIOD: FixTag = FixTag { id: "40652", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMNONDELIVERABLEFIXINGDATESOFFSETUNIT: FixTag = FixTag { id: "40653", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMNONDELIVERABLEFIXINGDATESOFFSETDAYTYPE: FixTag = FixTag { id: "40654", datatype: value_types::INT};
-pub const SETTLRATEFALLBACKREFERENCEPAGE: FixTag = FixTag { id: "40655", datatype: value_types::STRING};
-pub const NOUNDERLYINGNONDELIVERABLEFIXINGDATES: FixTag = FixTag { id: "40656", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGNONDELIVERABLEFIXINGDATE: FixTag = FixTag { id: "40657", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGNONDELIVERABLEFIXINGDATETYPE: FixTag = FixTag { id: "40658", datatype: value_types::INT};
-pub const NOUNDERLYINGSETTLRATEFALLBACKS: FixTag = FixTag { id: "40659", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGSETTLRATEPOSTPONEMENTMAXIMUMDAYS: FixTag = FixTag { id: "40660", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMNONDELIVERABLESETTLRATESOURCE: FixTag = FixTag { id: "40661", datatype: value_types::INT};
-pub const UNDERLYINGSETTLRATEPOSTPONEMENTSURVEY: FixTag = FixTag { id: "40662", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGSETTLRATEPOSTPONEMENTCALCULATIONAGENT: FixTag = FixTag { id: "40663", datatype: value_types::INT};
-pub const NOUNDERLYINGPAYMENTSCHEDULES: FixTag = FixTag { id: "40664", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPAYMENTSCHEDULETYPE: FixTag = FixTag { id: "40665", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSCHEDULESTUBTYPE: FixTag = FixTag { id: "40666", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSCHEDULESTARTDATEUNADJUSTED: FixTag = FixTag { id: "40667", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPAYMENTSCHEDULEENDDATEUNADJUSTED: FixTag = FixTag { id: "40668", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPAYMENTSCHEDULEPAYSIDE: FixTag = FixTag { id: "40669", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSCHEDULERECEIVESIDE: FixTag = FixTag { id: "40670", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSCHEDULENOTIONAL: FixTag = FixTag { id: "40671", datatype: value_types::AMT};
-pub const UNDERLYINGPAYMENTSCHEDULECURRENCY: FixTag = FixTag { id: "40672", datatype: value_types::CURRENCY};
-pub const UNDERLYINGPAYMENTSCHEDULERATE: FixTag = FixTag { id: "40673", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGPAYMENTSCHEDULERATEMULTIPLIER: FixTag = FixTag { id: "40674", datatype: value_types::FLOAT};
-pub const UNDERLYINGPAYMENTSCHEDULERATESPREAD: FixTag = FixTag { id: "40675", datatype: value_types::PRICE_OFFSET};
-pub const UNDERLYINGPAYMENTSCHEDULERATESPREADPOSITIONTYPE: FixTag = FixTag { id: "40676", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSCHEDULERATETREATMENT: FixTag = FixTag { id: "40677", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSCHEDULEFIXEDAMOUNT: FixTag = FixTag { id: "40678", datatype: value_types::AMT};
-pub const UNDERLYINGPAYMENTSCHEDULEFIXEDCURRENCY: FixTag = FixTag { id: "40679", datatype: value_types::CURRENCY};
-pub const UNDERLYINGPAYMENTSCHEDULESTEPFREQUENCYPERIOD: FixTag = FixTag { id: "40680", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSCHEDULESTEPFREQUENCYUNIT: FixTag = FixTag { id: "40681", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSCHEDULESTEPOFFSETVALUE: FixTag = FixTag { id: "40682", datatype: value_types::AMT};
-pub const UNDERLYINGPAYMENTSCHEDULESTEPRATE: FixTag = FixTag { id: "40683", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGPAYMENTSCHEDULESTEPOFFSETRATE: FixTag = FixTag { id: "40684", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGPAYMENTSCHEDULESTEPRELATIVETO: FixTag = FixTag { id: "40685", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSCHEDULEFIXINGDATEUNADJUSTED: FixTag = FixTag { id: "40686", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPAYMENTSCHEDULEWEIGHT: FixTag = FixTag { id: "40687", datatype: value_types::FLOAT};
-pub const UNDERLYINGPAYMENTSCHEDULEFIXINGDATERELATIVETO: FixTag = FixTag { id: "40688", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSCHEDULEFIXINGDATEBUSINESSDAYCNVTN: FixTag = FixTag { id: "40689", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSCHEDULEFIXINGDATEBUSINESSCENTER: FixTag = FixTag { id: "40690", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSCHEDULEFIXINGDATEOFFSETPERIOD: FixTag = FixTag { id: "40691", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSCHEDULEFIXINGDATEOFFSETUNIT: FixTag = FixTag { id: "40692", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSCHEDULEFIXINGDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40693", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSCHEDULEFIXINGDATEADJUSTED: FixTag = FixTag { id: "40694", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPAYMENTSCHEDULEFIXINGTIME: FixTag = FixTag { id: "40695", datatype: value_types::LOCAL_MKT_TIME};
-pub const UNDERLYINGPAYMENTSCHEDULEFIXINGTIMEBUSINESSCENTER: FixTag = FixTag { id: "40696", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSCHEDULEINTERIMEXCHANGEPAYMENTDATERELATIVETO: FixTag = FixTag { id: "40697", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSCHEDULEINTERIMEXCHANGEDATESBIZDAYCONVENTION: FixTag = FixTag { id: "40698", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSCHEDULEINTERIMEXCHANGEDATESBUSINESSCENTER: FixTag = FixTag { id: "40699", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSCHEDULEINTERIMEXCHANGEDATESOFFSETPERIOD: FixTag = FixTag { id: "40700", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSCHEDULEINTERIMEXCHANGEDATESOFFSETUNIT: FixTag = FixTag { id: "40701", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSCHEDULEINTERIMEXCHANGEDATESOFFSETDAYTYPE: FixTag = FixTag { id: "40702", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSCHEDULEINTERIMEXCHANGEDATEADJUSTED: FixTag = FixTag { id: "40703", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOUNDERLYINGPAYMENTSCHEDULERATESOURCES: FixTag = FixTag { id: "40704", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPAYMENTSCHEDULERATESOURCE: FixTag = FixTag { id: "40705", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSCHEDULERATESOURCETYPE: FixTag = FixTag { id: "40706", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSCHEDULEREFERENCEPAGE: FixTag = FixTag { id: "40707", datatype: value_types::STRING};
-pub const NOUNDERLYINGPAYMENTSTUBS: FixTag = FixTag { id: "40708", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPAYMENTSTUBTYPE: FixTag = FixTag { id: "40709", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTUBLENGTH: FixTag = FixTag { id: "40710", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTUBRATE: FixTag = FixTag { id: "40711", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGPAYMENTSTUBFIXEDAMOUNT: FixTag = FixTag { id: "40712", datatype: value_types::AMT};
-pub const UNDERLYINGPAYMENTSTUBFIXEDCURRENCY: FixTag = FixTag { id: "40713", datatype: value_types::CURRENCY};
-pub const UNDERLYINGPAYMENTSTUBINDEX: FixTag = FixTag { id: "40714", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTUBINDEXSOURCE: FixTag = FixTag { id: "40715", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTUBINDEXCURVEPERIOD: FixTag = FixTag { id: "40716", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTUBINDEXCURVEUNIT: FixTag = FixTag { id: "40717", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTUBINDEXRATEMULTIPLIER: FixTag = FixTag { id: "40718", datatype: value_types::FLOAT};
-pub const UNDERLYINGPAYMENTSTUBINDEXRATESPREAD: FixTag = FixTag { id: "40719", datatype: value_types::PRICE_OFFSET};
-pub const UNDERLYINGPAYMENTSTUBINDEXRATESPREADPOSITIONTYPE: FixTag = FixTag { id: "40720", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTUBINDEXRATETREATMENT: FixTag = FixTag { id: "40721", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTUBINDEXCAPRATE: FixTag = FixTag { id: "40722", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGPAYMENTSTUBINDEXCAPRATEBUYSIDE: FixTag = FixTag { id: "40723", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTUBINDEXCAPRATESELLSIDE: FixTag = FixTag { id: "40724", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTUBINDEXFLOORRATE: FixTag = FixTag { id: "40725", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGPAYMENTSTUBINDEXFLOORRATEBUYSIDE: FixTag = FixTag { id: "40726", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTUBINDEXFLOORRATESELLSIDE: FixTag = FixTag { id: "40727", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTUBINDEX2: FixTag = FixTag { id: "40728", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTUBINDEX2SOURCE: FixTag = FixTag { id: "40729", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTUBINDEX2CURVEPERIOD: FixTag = FixTag { id: "40730", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTUBINDEX2CURVEUNIT: FixTag = FixTag { id: "40731", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTUBINDEX2RATEMULTIPLIER: FixTag = FixTag { id: "40732", datatype: value_types::FLOAT};
-pub const UNDERLYINGPAYMENTSTUBINDEX2RATESPREAD: FixTag = FixTag { id: "40733", datatype: value_types::PRICE_OFFSET};
-pub const UNDERLYINGPAYMENTSTUBINDEX2RATESPREADPOSITIONTYPE: FixTag = FixTag { id: "40734", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTUBINDEX2RATETREATMENT: FixTag = FixTag { id: "40735", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTUBINDEX2CAPRATE: FixTag = FixTag { id: "40736", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGPAYMENTSTUBINDEX2FLOORRATE: FixTag = FixTag { id: "40737", datatype: value_types::PERCENTAGE};
-pub const PAYMENTSTREAMTYPE: FixTag = FixTag { id: "40738", datatype: value_types::INT};
-pub const PAYMENTSTREAMMARKETRATE: FixTag = FixTag { id: "40739", datatype: value_types::INT};
-pub const PAYMENTSTREAMDELAYINDICATOR: FixTag = FixTag { id: "40740", datatype: value_types::BOOLEAN};
-pub const PAYMENTSTREAMSETTLCURRENCY: FixTag = FixTag { id: "40741", datatype: value_types::CURRENCY};
-pub const PAYMENTSTREAMDAYCOUNT: FixTag = FixTag { id: "40742", datatype: value_types::INT};
-pub const PAYMENTSTREAMACCRUALDAYS: FixTag = FixTag { id: "40743", datatype: value_types::INT};
-pub const PAYMENTSTREAMDISCOUNTTYPE: FixTag = FixTag { id: "40744", datatype: value_types::INT};
-pub const PAYMENTSTREAMDISCOUNTRATE: FixTag = FixTag { id: "40745", datatype: value_types::PERCENTAGE};
-pub const PAYMENTSTREAMDISCOUNTRATEDAYCOUNT: FixTag = FixTag { id: "40746", datatype: value_types::INT};
-pub const PAYMENTSTREAMCOMPOUNDINGMETHOD: FixTag = FixTag { id: "40747", datatype: value_types::INT};
-pub const PAYMENTSTREAMINITIALPRINCIPALEXCHANGEINDICATOR: FixTag = FixTag { id: "40748", datatype: value_types::BOOLEAN};
-pub const PAYMENTSTREAMINTERIMPRINCIPALEXCHANGEINDICATOR: FixTag = FixTag { id: "40749", datatype: value_types::BOOLEAN};
-pub const PAYMENTSTREAMFINALPRINCIPALEXCHANGEINDICATOR: FixTag = FixTag { id: "40750", datatype: value_types::BOOLEAN};
-pub const PAYMENTSTREAMPAYMENTDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40751", datatype: value_types::INT};
-pub const PAYMENTSTREAMPAYMENTDATEBUSINESSCENTER: FixTag = FixTag { id: "40752", datatype: value_types::STRING};
-pub const PAYMENTSTREAMPAYMENTFREQUENCYPERIOD: FixTag = FixTag { id: "40753", datatype: value_types::INT};
-pub const PAYMENTSTREAMPAYMENTFREQUENCYUNIT: FixTag = FixTag { id: "40754", datatype: value_types::STRING};
-pub const PAYMENTSTREAMPAYMENTROLLCONVENTION: FixTag = FixTag { id: "40755", datatype: value_types::STRING};
-pub const PAYMENTSTREAMFIRSTPAYMENTDATEUNADJUSTED: FixTag = FixTag { id: "40756", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTSTREAMLASTREGULARPAYMENTDATEUNADJUSTED: FixTag = FixTag { id: "40757", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTSTREAMPAYMENTDATERELATIVETO: FixTag = FixTag { id: "40758", datatype: value_types::INT};
-pub const PAYMENTSTREAMPAYMENTDATEOFFSETPERIOD: FixTag = FixTag { id: "40759", datatype: value_types::INT};
-pub const PAYMENTSTREAMPAYMENTDATEOFFSETUNIT: FixTag = FixTag { id: "40760", datatype: value_types::STRING};
-pub const PAYMENTSTREAMRESETDATERELATIVETO: FixTag = FixTag { id: "40761", datatype: value_types::INT};
-pub const PAYMENTSTREAMRESETDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40762", datatype: value_types::INT};
-pub const PAYMENTSTREAMRESETDATEBUSINESSCENTER: FixTag = FixTag { id: "40763", datatype: value_types::STRING};
-pub const PAYMENTSTREAMRESETFREQUENCYPERIOD: FixTag = FixTag { id: "40764", datatype: value_types::INT};
-pub const PAYMENTSTREAMRESETFREQUENCYUNIT: FixTag = FixTag { id: "40765", datatype: value_types::STRING};
-pub const PAYMENTSTREAMRESETWEEKLYROLLCONVENTION: FixTag = FixTag { id: "40766", datatype: value_types::STRING};
-pub const PAYMENTSTREAMINITIALFIXINGDATERELATIVETO: FixTag = FixTag { id: "40767", datatype: value_types::INT};
-pub const PAYMENTSTREAMINITIALFIXINGDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40768", datatype: value_types::INT};
-pub const PAYMENTSTREAMINITIALFIXINGDATEBUSINESSCENTER: FixTag = FixTag { id: "40769", datatype: value_types::STRING};
-pub const PAYMENTSTREAMINITIALFIXINGDATEOFFSETPERIOD: FixTag = FixTag { id: "40770", datatype: value_types::INT};
-pub const PAYMENTSTREAMINITIALFIXINGDATEOFFSETUNIT: FixTag = FixTag { id: "40771", datatype: value_types::STRING};
-pub const PAYMENTSTREAMINITIALFIXINGDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40772", datatype: value_types::INT};
-pub const PAYMENTSTREAMINITIALFIXINGDATEADJUSTED: FixTag = FixTag { id: "40773", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTSTREAMFIXINGDATERELATIVETO: FixTag = FixTag { id: "40774", datatype: value_types::INT};
-pub const PAYMENTSTREAMFIXINGDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40775", datatype: value_types::INT};
-pub const PAYMENTSTREAMFIXINGDATEBUSINESSCENTER: FixTag = FixTag { id: "40776", datatype: value_types::STRING};
-pub const PAYMENTSTREAMFIXINGDATEOFFSETPERIOD: FixTag = FixTag { id: "40777", datatype: value_types::INT};
-pub const PAYMENTSTREAMFIXINGDATEOFFSETUNIT: FixTag = FixTag { id: "40778", datatype: value_types::STRING};
-pub const PAYMENTSTREAMFIXINGDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40779", datatype: value_types::INT};
-pub const PAYMENTSTREAMFIXINGDATEADJUSTED: FixTag = FixTag { id: "40780", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTSTREAMRATECUTOFFDATEOFFSETPERIOD: FixTag = FixTag { id: "40781", datatype: value_types::INT};
-pub const PAYMENTSTREAMRATECUTOFFDATEOFFSETUNIT: FixTag = FixTag { id: "40782", datatype: value_types::STRING};
-pub const PAYMENTSTREAMRATECUTOFFDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40783", datatype: value_types::INT};
-pub const PAYMENTSTREAMRATE: FixTag = FixTag { id: "40784", datatype: value_types::PERCENTAGE};
-pub const PAYMENTSTREAMFIXEDAMOUNT: FixTag = FixTag { id: "40785", datatype: value_types::AMT};
-pub const PAYMENTSTREAMRATEORAMOUNTCURRENCY: FixTag = FixTag { id: "40786", datatype: value_types::CURRENCY};
-pub const PAYMENTSTREAMFUTUREVALUENOTIONAL: FixTag = FixTag { id: "40787", datatype: value_types::AMT};
-pub const PAYMENTSTREAMFUTUREVALUEDATEADJUSTED: FixTag = FixTag { id: "40788", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTSTREAMRATEINDEX: FixTag = FixTag { id: "40789", datatype: value_types::STRING};
-pub const PAYMENTSTREAMRATEINDEXSOURCE: FixTag = FixTag { id: "40790", datatype: value_types::INT};
-pub const PAYMENTSTREAMRATEINDEXCURVEUNIT: FixTag = FixTag { id: "40791", datatype: value_types::STRING};
-pub const PAYMENTSTREAMRATEINDEXCURVEPERIOD: FixTag = FixTag { id: "40792", datatype: value_types::INT};
-pub const PAYMENTSTREAMRATEMULTIPLIER: FixTag = FixTag { id: "40793", datatype: value_types::FLOAT};
-pub const PAYMENTSTREAMRATESPREAD: FixTag = FixTag { id: "40794", datatype: value_types::PRICE_OFFSET};
-pub const PAYMENTSTREAMRATESPREADPOSITIONTYPE: FixTag = FixTag { id: "40795", datatype: value_types::INT};
-pub const PAYMENTSTREAMRATETREATMENT: FixTag = FixTag { id: "40796", datatype: value_types::INT};
-pub const PAYMENTSTREAMCAPRATE: FixTag = FixTag { id: "40797", datatype: value_types::PERCENTAGE};
-pub const PAYMENTSTREAMCAPRATEBUYSIDE: FixTag = FixTag { id: "40798", datatype: value_types::INT};
-pub const PAYMENTSTREAMCAPRATESELLSIDE: FixTag = FixTag { id: "40799", datatype: value_types::INT};
-pub const PAYMENTSTREAMFLOORRATE: FixTag = FixTag { id: "40800", datatype: value_types::PERCENTAGE};
-pub const PAYMENTSTREAMFLOORRATEBUYSIDE: FixTag = FixTag { id: "40801", datatype: value_types::INT};
-pub const PAYMENTSTREAMFLOORRATESELLSIDE: FixTag = FixTag { id: "40802", datatype: value_types::INT};
-pub const PAYMENTSTREAMINITIALRATE: FixTag = FixTag { id: "40803", datatype: value_types::PERCENTAGE};
-pub const PAYMENTSTREAMFINALRATEROUNDINGDIRECTION: FixTag = FixTag { id: "40804", datatype: value_types::CHAR};
-pub const PAYMENTSTREAMFINALRATEPRECISION: FixTag = FixTag { id: "40805", datatype: value_types::INT};
-pub const PAYMENTSTREAMAVERAGINGMETHOD: FixTag = FixTag { id: "40806", datatype: value_types::INT};
-pub const PAYMENTSTREAMNEGATIVERATETREATMENT: FixTag = FixTag { id: "40807", datatype: value_types::INT};
-pub const PAYMENTSTREAMINFLATIONLAGPERIOD: FixTag = FixTag { id: "40808", datatype: value_types::INT};
-pub const PAYMENTSTREAMINFLATIONLAGUNIT: FixTag = FixTag { id: "40809", datatype: value_types::STRING};
-pub const PAYMENTSTREAMINFLATIONLAGDAYTYPE: FixTag = FixTag { id: "40810", datatype: value_types::INT};
-pub const PAYMENTSTREAMINFLATIONINTERPOLATIONMETHOD: FixTag = FixTag { id: "40811", datatype: value_types::INT};
-pub const PAYMENTSTREAMINFLATIONINDEXSOURCE: FixTag = FixTag { id: "40812", datatype: value_types::INT};
-pub const PAYMENTSTREAMINFLATIONPUBLICATIONSOURCE: FixTag = FixTag { id: "40813", datatype: value_types::STRING};
-pub const PAYMENTSTREAMINFLATIONINITIALINDEXLEVEL: FixTag = FixTag { id: "40814", datatype: value_types::FLOAT};
-pub const PAYMENTSTREAMINFLATIONFALLBACKBONDAPPLICABLE: FixTag = FixTag { id: "40815", datatype: value_types::BOOLEAN};
-pub const PAYMENTSTREAMFRADISCOUNTING: FixTag = FixTag { id: "40816", datatype: value_types::INT};
-pub const PAYMENTSTREAMNONDELIVERABLEREFCURRENCY: FixTag = FixTag { id: "40817", datatype: value_types::CURRENCY};
-pub const PAYMENTSTREAMNONDELIVERABLEFIXINGDATESBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40818", datatype: value_types::INT};
-pub const PAYMENTSTREAMNONDELIVERABLEFIXINGDATESBUSINESSCENTER: FixTag = FixTag { id: "40819", datatype: value_types::STRING};
-pub const PAYMENTSTREAMNONDELIVERABLEFIXINGDATESRELATIVETO: FixTag = FixTag { id: "40820", datatype: value_types::INT};
-pub const PAYMENTSTREAMNONDELIVERABLEFIXINGDATESOFFSETPERIOD: FixTag = FixTag { id: "40821", datatype: value_types::INT};
-pub const PAYMENTSTREAMNONDELIVERABLEFIXINGDATESOFFSETUNIT: FixTag = FixTag { id: "40822", datatype: value_types::STRING};
-pub const PAYMENTSTREAMNONDELIVERABLEFIXINGDATESOFFSETDAYTYPE: FixTag = FixTag { id: "40823", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMNONDELIVERABLESETTLREFERENCEPAGE: FixTag = FixTag { id: "40824", datatype: value_types::STRING};
-pub const NONONDELIVERABLEFIXINGDATES: FixTag = FixTag { id: "40825", datatype: value_types::NUM_IN_GROUP };
-pub const NONDELIVERABLEFIXINGDATE: FixTag = FixTag { id: "40826", datatype: value_types::LOCAL_MKT_DATE};
-pub const NONDELIVERABLEFIXINGDATETYPE: FixTag = FixTag { id: "40827", datatype: value_types::INT};
-pub const NOPAYMENTSCHEDULES: FixTag = FixTag { id: "40828", datatype: value_types::NUM_IN_GROUP };
-pub const PAYMENTSCHEDULETYPE: FixTag = FixTag { id: "40829", datatype: value_types::INT};
-pub const PAYMENTSCHEDULESTUBTYPE: FixTag = FixTag { id: "40830", datatype: value_types::INT};
-pub const PAYMENTSCHEDULESTARTDATEUNADJUSTED: FixTag = FixTag { id: "40831", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTSCHEDULEENDDATEUNADJUSTED: FixTag = FixTag { id: "40832", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTSCHEDULEPAYSIDE: FixTag = FixTag { id: "40833", datatype: value_types::INT};
-pub const PAYMENTSCHEDULERECEIVESIDE: FixTag = FixTag { id: "40834", datatype: value_types::INT};
-pub const PAYMENTSCHEDULENOTIONAL: FixTag = FixTag { id: "40835", datatype: value_types::AMT};
-pub const PAYMENTSCHEDULECURRENCY: FixTag = FixTag { id: "40836", datatype: value_types::CURRENCY};
-pub const PAYMENTSCHEDULERATE: FixTag = FixTag { id: "40837", datatype: value_types::PERCENTAGE};
-pub const PAYMENTSCHEDULERATEMULTIPLIER: FixTag = FixTag { id: "40838", datatype: value_types::FLOAT};
-pub const PAYMENTSCHEDULERATESPREAD: FixTag = FixTag { id: "40839", datatype: value_types::PRICE_OFFSET};
-pub const PAYMENTSCHEDULERATESPREADPOSITIONTYPE: FixTag = FixTag { id: "40840", datatype: value_types::INT};
-pub const PAYMENTSCHEDULERATETREATMENT: FixTag = FixTag { id: "40841", datatype: value_types::INT};
-pub const PAYMENTSCHEDULEFIXEDAMOUNT: FixTag = FixTag { id: "40842", datatype: value_types::AMT};
-pub const PAYMENTSCHEDULEFIXEDCURRENCY: FixTag = FixTag { id: "40843", datatype: value_types::CURRENCY};
-pub const PAYMENTSCHEDULESTEPFREQUENCYPERIOD: FixTag = FixTag { id: "40844", datatype: value_types::INT};
-pub const PAYMENTSCHEDULESTEPFREQUENCYUNIT: FixTag = FixTag { id: "40845", datatype: value_types::STRING};
-pub const PAYMENTSCHEDULESTEPOFFSETVALUE: FixTag = FixTag { id: "40846", datatype: value_types::AMT};
-pub const PAYMENTSCHEDULESTEPRATE: FixTag = FixTag { id: "40847", datatype: value_types::PERCENTAGE};
-pub const PAYMENTSCHEDULESTEPOFFSETRATE: FixTag = FixTag { id: "40848", datatype: value_types::PERCENTAGE};
-pub const PAYMENTSCHEDULESTEPRELATIVETO: FixTag = FixTag { id: "40849", datatype: value_types::INT};
-pub const PAYMENTSCHEDULEFIXINGDATEUNADJUSTED: FixTag = FixTag { id: "40850", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTSCHEDULEWEIGHT: FixTag = FixTag { id: "40851", datatype: value_types::FLOAT};
-pub const PAYMENTSCHEDULEFIXINGDATERELATIVETO: FixTag = FixTag { id: "40852", datatype: value_types::INT};
-pub const PAYMENTSCHEDULEFIXINGDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40853", datatype: value_types::INT};
-pub const PAYMENTSCHEDULEFIXINGDATEBUSINESSCENTER: FixTag = FixTag { id: "40854", datatype: value_types::STRING};
-pub const PAYMENTSCHEDULEFIXINGDATEOFFSETPERIOD: FixTag = FixTag { id: "40855", datatype: value_types::INT};
-pub const PAYMENTSCHEDULEFIXINGDATEOFFSETUNIT: FixTag = FixTag { id: "40856", datatype: value_types::STRING};
-pub const PAYMENTSCHEDULEFIXINGDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40857", datatype: value_types::INT};
-pub const PAYMENTSCHEDULEFIXINGDATEADJUSTED: FixTag = FixTag { id: "40858", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTSCHEDULEFIXINGTIME: FixTag = FixTag { id: "40859", datatype: value_types::LOCAL_MKT_TIME};
-pub const PAYMENTSCHEDULEFIXINGTIMEBUSINESSCENTER: FixTag = FixTag { id: "40860", datatype: value_types::STRING};
-pub const PAYMENTSCHEDULEINTERIMEXCHANGEPAYMENTDATERELATIVETO: FixTag = FixTag { id: "40861", datatype: value_types::INT};
-pub const PAYMENTSCHEDULEINTERIMEXCHANGEDATESBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40862", datatype: value_types::INT};
-pub const PAYMENTSCHEDULEINTERIMEXCHANGEDATESBUSINESSCENTER: FixTag = FixTag { id: "40863", datatype: value_types::STRING};
-pub const PAYMENTSCHEDULEINTERIMEXCHANGEDATESOFFSETPERIOD: FixTag = FixTag { id: "40864", datatype: value_types::INT};
-pub const PAYMENTSCHEDULEINTERIMEXCHANGEDATESOFFSETUNIT: FixTag = FixTag { id: "40865", datatype: value_types::STRING};
-pub const PAYMENTSCHEDULEINTERIMEXCHANGEDATESOFFSETDAYTYPE: FixTag = FixTag { id: "40866", datatype: value_types::INT};
-pub const PAYMENTSCHEDULEINTERIMEXCHANGEDATEADJUSTED: FixTag = FixTag { id: "40867", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOPAYMENTSCHEDULERATESOURCES: FixTag = FixTag { id: "40868", datatype: value_types::NUM_IN_GROUP };
-pub const PAYMENTSCHEDULERATESOURCE: FixTag = FixTag { id: "40869", datatype: value_types::INT};
-pub const PAYMENTSCHEDULERATESOURCETYPE: FixTag = FixTag { id: "40870", datatype: value_types::INT};
-pub const PAYMENTSCHEDULEREFERENCEPAGE: FixTag = FixTag { id: "40871", datatype: value_types::STRING};
-pub const NOPAYMENTSTUBS: FixTag = FixTag { id: "40872", datatype: value_types::NUM_IN_GROUP };
-pub const PAYMENTSTUBTYPE: FixTag = FixTag { id: "40873", datatype: value_types::INT};
-pub const PAYMENTSTUBLENGTH: FixTag = FixTag { id: "40874", datatype: value_types::INT};
-pub const PAYMENTSTUBRATE: FixTag = FixTag { id: "40875", datatype: value_types::PERCENTAGE};
-pub const PAYMENTSTUBFIXEDAMOUNT: FixTag = FixTag { id: "40876", datatype: value_types::AMT};
-pub const PAYMENTSTUBFIXEDCURRENCY: FixTag = FixTag { id: "40877", datatype: value_types::CURRENCY};
-pub const PAYMENTSTUBINDEX: FixTag = FixTag { id: "40878", datatype: value_types::STRING};
-pub const PAYMENTSTUBINDEXSOURCE: FixTag = FixTag { id: "40879", datatype: value_types::INT};
-pub const PAYMENTSTUBINDEXCURVEPERIOD: FixTag = FixTag { id: "40880", datatype: value_types::INT};
-pub const PAYMENTSTUBINDEXCURVEUNIT: FixTag = FixTag { id: "40881", datatype: value_types::STRING};
-pub const PAYMENTSTUBINDEXRATEMULTIPLIER: FixTag = FixTag { id: "40882", datatype: value_types::FLOAT};
-pub const PAYMENTSTUBINDEXRATESPREAD: FixTag = FixTag { id: "40883", datatype: value_types::PRICE_OFFSET};
-pub const PAYMENTSTUBINDEXRATESPREADPOSITIONTYPE: FixTag = FixTag { id: "40884", datatype: value_types::INT};
-pub const PAYMENTSTUBINDEXRATETREATMENT: FixTag = FixTag { id: "40885", datatype: value_types::INT};
-pub const PAYMENTSTUBINDEXCAPRATE: FixTag = FixTag { id: "40886", datatype: value_types::PERCENTAGE};
-pub const PAYMENTSTUBINDEXCAPRATEBUYSIDE: FixTag = FixTag { id: "40887", datatype: value_types::INT};
-pub const PAYMENTSTUBINDEXCAPRATESELLSIDE: FixTag = FixTag { id: "40888", datatype: value_types::INT};
-pub const PAYMENTSTUBINDEXFLOORRATE: FixTag = FixTag { id: "40889", datatype: value_types::PERCENTAGE};
-pub const PAYMENTSTUBINDEXFLOORRATEBUYSIDE: FixTag = FixTag { id: "40890", datatype: value_types::INT};
-pub const PAYMENTSTUBINDEXFLOORRATESELLSIDE: FixTag = FixTag { id: "40891", datatype: value_types::INT};
-pub const PAYMENTSTUBINDEX2: FixTag = FixTag { id: "40892", datatype: value_types::STRING};
-pub const PAYMENTSTUBINDEX2SOURCE: FixTag = FixTag { id: "40893", datatype: value_types::INT};
-pub const PAYMENTSTUBINDEX2CURVEPERIOD: FixTag = FixTag { id: "40894", datatype: value_types::INT};
-pub const PAYMENTSTUBINDEX2CURVEUNIT: FixTag = FixTag { id: "40895", datatype: value_types::STRING};
-pub const PAYMENTSTUBINDEX2RATEMULTIPLIER: FixTag = FixTag { id: "40896", datatype: value_types::FLOAT};
-pub const PAYMENTSTUBINDEX2RATESPREAD: FixTag = FixTag { id: "40897", datatype: value_types::PRICE_OFFSET};
-pub const PAYMENTSTUBINDEX2RATESPREADPOSITIONTYPE: FixTag = FixTag { id: "40898", datatype: value_types::INT};
-pub const PAYMENTSTUBINDEX2RATETREATMENT: FixTag = FixTag { id: "40899", datatype: value_types::INT};
-pub const PAYMENTSTUBINDEX2CAPRATE: FixTag = FixTag { id: "40900", datatype: value_types::PERCENTAGE};
-pub const PAYMENTSTUBINDEX2FLOORRATE: FixTag = FixTag { id: "40901", datatype: value_types::PERCENTAGE};
-pub const NOLEGSETTLRATEFALLBACKS: FixTag = FixTag { id: "40902", datatype: value_types::NUM_IN_GROUP };
-pub const LEGSETTLRATEPOSTPONEMENTMAXIMUMDAYS: FixTag = FixTag { id: "40903", datatype: value_types::INT};
-pub const UNDERLYINGSETTLRATEFALLBACKRATESOURCE: FixTag = FixTag { id: "40904", datatype: value_types::INT};
-pub const LEGSETTLRATEPOSTPONEMENTSURVEY: FixTag = FixTag { id: "40905", datatype: value_types::BOOLEAN};
-pub const LEGSETTLRATEPOSTPONEMENTCALCULATIONAGENT: FixTag = FixTag { id: "40906", datatype: value_types::INT};
-pub const STREAMEFFECTIVEDATEUNADJUSTED: FixTag = FixTag { id: "40907", datatype: value_types::LOCAL_MKT_DATE};
-pub const STREAMEFFECTIVEDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40908", datatype: value_types::INT};
-pub const STREAMEFFECTIVEDATEBUSINESSCENTER: FixTag = FixTag { id: "40909", datatype: value_types::STRING};
-pub const STREAMEFFECTIVEDATERELATIVETO: FixTag = FixTag { id: "40910", datatype: value_types::INT};
-pub const STREAMEFFECTIVEDATEOFFSETPERIOD: FixTag = FixTag { id: "40911", datatype: value_types::INT};
-pub const STREAMEFFECTIVEDATEOFFSETUNIT: FixTag = FixTag { id: "40912", datatype: value_types::STRING};
-pub const STREAMEFFECTIVEDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40913", datatype: value_types::INT};
-pub const STREAMEFFECTIVEDATEADJUSTED: FixTag = FixTag { id: "40914", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGSETTLRATEFALLBACKREFERENCEPAGE: FixTag = FixTag { id: "40915", datatype: value_types::STRING};
-pub const CASHSETTLVALUATIONSUBSEQUENTBUSINESSDAYSOFFSET: FixTag = FixTag { id: "40916", datatype: value_types::INT};
-pub const CASHSETTLNUMOFVALUATIONDATES: FixTag = FixTag { id: "40917", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONPARTYROLEQUALIFIER: FixTag = FixTag { id: "40918", datatype: value_types::INT};
-pub const PAYMENTPRICETYPE: FixTag = FixTag { id: "40919", datatype: value_types::INT};
-pub const PAYMENTSTREAMPAYMENTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "40920", datatype: value_types::INT};
-pub const BUSINESSDAYCONVENTION: FixTag = FixTag { id: "40921", datatype: value_types::INT};
-pub const DATEROLLCONVENTION: FixTag = FixTag { id: "40922", datatype: value_types::STRING};
-pub const NOLEGBUSINESSCENTERS: FixTag = FixTag { id: "40923", datatype: value_types::NUM_IN_GROUP };
-pub const LEGBUSINESSCENTER: FixTag = FixTag { id: "40924", datatype: value_types::STRING};
-pub const LEGBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40925", datatype: value_types::INT};
-pub const LEGDATEROLLCONVENTION: FixTag = FixTag { id: "40926", datatype: value_types::STRING};
-pub const NOLEGPAYMENTSCHEDULEFIXINGDATEBUSINESSCENTERS: FixTag = FixTag { id: "40927", datatype: value_types::NUM_IN_GROUP };
-pub const NOLEGPAYMENTSCHEDULEINTERIMEXCHANGEDATEBUSINESSCENTERS: FixTag = FixTag { id: "40928", datatype: value_types::NUM_IN_GROUP };
-pub const NOLEGPAYMENTSTREAMNONDELIVERABLEFIXINGDATEBUSINESSCENTERS: FixTag = FixTag { id: "40929", datatype: value_types::NUM_IN_GROUP };
-pub const NOLEGPAYMENTSTREAMPAYMENTDATEBUSINESSCENTERS: FixTag = FixTag { id: "40930", datatype: value_types::NUM_IN_GROUP };
-pub const NOLEGPAYMENTSTREAMRESETDATEBUSINESSCENTERS: FixTag = FixTag { id: "40931", datatype: value_types::NUM_IN_GROUP };
-pub const NOLEGPAYMENTSTREAMINITIALFIXINGDATEBUSINESSCENTERS: FixTag = FixTag { id: "40932", datatype: value_types::NUM_IN_GROUP };
-pub const NOLEGPAYMENTSTREAMFIXINGDATEBUSINESSCENTERS: FixTag = FixTag { id: "40933", datatype: value_types::NUM_IN_GROUP };
-pub const NOLEGPROVISIONCASHSETTLPAYMENTDATEBUSINESSCENTERS: FixTag = FixTag { id: "40934", datatype: value_types::NUM_IN_GROUP };
-pub const NOLEGPROVISIONCASHSETTLVALUEDATEBUSINESSCENTERS: FixTag = FixTag { id: "40935", datatype: value_types::NUM_IN_GROUP };
-pub const NOLEGPROVISIONOPTIONEXERCISEBUSINESSCENTERS: FixTag = FixTag { id: "40936", datatype: value_types::NUM_IN_GROUP };
-pub const NOLEGPROVISIONOPTIONEXPIRATIONDATEBUSINESSCENTERS: FixTag = FixTag { id: "40937", datatype: value_types::NUM_IN_GROUP };
-pub const NOLEGPROVISIONOPTIONRELEVANTUNDERLYINGDATEBUSINESSCENTERS: FixTag = FixTag { id: "40938", datatype: value_types::NUM_IN_GROUP };
-pub const NOLEGPROVISIONDATEBUSINESSCENTERS: FixTag = FixTag { id: "40939", datatype: value_types::NUM_IN_GROUP };
-pub const NOLEGSTREAMCALCULATIONPERIODBUSINESSCENTERS: FixTag = FixTag { id: "40940", datatype: value_types::NUM_IN_GROUP };
-pub const NOLEGSTREAMFIRSTPERIODSTARTDATEBUSINESSCENTERS: FixTag = FixTag { id: "40941", datatype: value_types::NUM_IN_GROUP };
-pub const NOLEGSTREAMEFFECTIVEDATEBUSINESSCENTERS: FixTag = FixTag { id: "40942", datatype: value_types::NUM_IN_GROUP };
-pub const NOLEGSTREAMTERMINATIONDATEBUSINESSCENTERS: FixTag = FixTag { id: "40943", datatype: value_types::NUM_IN_GROUP };
-pub const NOPAYMENTBUSINESSCENTERS: FixTag = FixTag { id: "40944", datatype: value_types::NUM_IN_GROUP };
-pub const NOPAYMENTSCHEDULEINTERIMEXCHANGEDATEBUSINESSCENTERS: FixTag = FixTag { id: "40945", datatype: value_types::NUM_IN_GROUP };
-pub const NOPAYMENTSTREAMNONDELIVERABLEFIXINGDATESBUSINESSCENTERS: FixTag = FixTag { id: "40946", datatype: value_types::NUM_IN_GROUP };
-pub const NOPAYMENTSTREAMPAYMENTDATEBUSINESSCENTERS: FixTag = FixTag { id: "40947", datatype: value_types::NUM_IN_GROUP };
-pub const NOPAYMENTSTREAMRESETDATEBUSINESSCENTERS: FixTag = FixTag { id: "40948", datatype: value_types::NUM_IN_GROUP };
-pub const NOPAYMENTSTREAMINITIALFIXINGDATEBUSINESSCENTERS: FixTag = FixTag { id: "40949", datatype: value_types::NUM_IN_GROUP };
-pub const NOPAYMENTSTREAMFIXINGDATEBUSINESSCENTERS: FixTag = FixTag { id: "40950", datatype: value_types::NUM_IN_GROUP };
-pub const NOPROTECTIONTERMEVENTNEWSSOURCES: FixTag = FixTag { id: "40951", datatype: value_types::NUM_IN_GROUP };
-pub const NOPROVISIONCASHSETTLPAYMENTDATEBUSINESSCENTERS: FixTag = FixTag { id: "40952", datatype: value_types::NUM_IN_GROUP };
-pub const NOPROVISIONCASHSETTLVALUEDATEBUSINESSCENTERS: FixTag = FixTag { id: "40953", datatype: value_types::NUM_IN_GROUP };
-pub const NOPROVISIONOPTIONEXERCISEBUSINESSCENTERS: FixTag = FixTag { id: "40954", datatype: value_types::NUM_IN_GROUP };
-pub const NOPROVISIONOPTIONEXPIRATIONDATEBUSINESSCENTERS: FixTag = FixTag { id: "40955", datatype: value_types::NUM_IN_GROUP };
-pub const NOPROVISIONOPTIONRELEVANTUNDERLYINGDATEBUSINESSCENTERS: FixTag = FixTag { id: "40956", datatype: value_types::NUM_IN_GROUP };
-pub const NOPROVISIONDATEBUSINESSCENTERS: FixTag = FixTag { id: "40957", datatype: value_types::NUM_IN_GROUP };
-pub const NOSTREAMCALCULATIONPERIODBUSINESSCENTERS: FixTag = FixTag { id: "40958", datatype: value_types::NUM_IN_GROUP };
-pub const NOSTREAMFIRSTPERIODSTARTDATEBUSINESSCENTERS: FixTag = FixTag { id: "40959", datatype: value_types::NUM_IN_GROUP };
-pub const NOSTREAMEFFECTIVEDATEBUSINESSCENTERS: FixTag = FixTag { id: "40960", datatype: value_types::NUM_IN_GROUP };
-pub const NOSTREAMTERMINATIONDATEBUSINESSCENTERS: FixTag = FixTag { id: "40961", datatype: value_types::NUM_IN_GROUP };
-pub const NOUNDERLYINGBUSINESSCENTERS: FixTag = FixTag { id: "40962", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGBUSINESSCENTER: FixTag = FixTag { id: "40963", datatype: value_types::STRING};
-pub const UNDERLYINGBUSINESSDAYCONVENTION: FixTag = FixTag { id: "40964", datatype: value_types::INT};
-pub const UNDERLYINGDATEROLLCONVENTION: FixTag = FixTag { id: "40965", datatype: value_types::STRING};
-pub const NOUNDERLYINGPAYMENTSCHEDULEFIXINGDATEBUSINESSCENTERS: FixTag = FixTag { id: "40966", datatype: value_types::NUM_IN_GROUP };
-pub const NOUNDERLYINGPAYMENTSCHEDULEINTERIMEXCHANGEDATEBUSINESSCENTERS: FixTag = FixTag { id: "40967", datatype: value_types::NUM_IN_GROUP };
-pub const NOUNDERLYINGPAYMENTSTREAMNONDELIVERABLEFIXINGDATESBIZCENTERS: FixTag = FixTag { id: "40968", datatype: value_types::NUM_IN_GROUP };
-pub const NOUNDERLYINGPAYMENTSTREAMPAYMENTDATEBUSINESSCENTERS: FixTag = FixTag { id: "40969", datatype: value_types::NUM_IN_GROUP };
-pub const NOUNDERLYINGPAYMENTSTREAMRESETDATEBUSINESSCENTERS: FixTag = FixTag { id: "40970", datatype: value_types::NUM_IN_GROUP };
-pub const NOUNDERLYINGPAYMENTSTREAMINITIALFIXINGDATEBUSINESSCENTERS: FixTag = FixTag { id: "40971", datatype: value_types::NUM_IN_GROUP };
-pub const NOUNDERLYINGPAYMENTSTREAMFIXINGDATEBUSINESSCENTERS: FixTag = FixTag { id: "40972", datatype: value_types::NUM_IN_GROUP };
-pub const NOUNDERLYINGSTREAMCALCULATIONPERIODBUSINESSCENTERS: FixTag = FixTag { id: "40973", datatype: value_types::NUM_IN_GROUP };
-pub const NOUNDERLYINGSTREAMFIRSTPERIODSTARTDATEBUSINESSCENTERS: FixTag = FixTag { id: "40974", datatype: value_types::NUM_IN_GROUP };
-pub const NOUNDERLYINGSTREAMEFFECTIVEDATEBUSINESSCENTERS: FixTag = FixTag { id: "40975", datatype: value_types::NUM_IN_GROUP };
-pub const NOUNDERLYINGSTREAMTERMINATIONDATEBUSINESSCENTERS: FixTag = FixTag { id: "40976", datatype: value_types::NUM_IN_GROUP };
-pub const NOPAYMENTSCHEDULEFIXINGDATEBUSINESSCENTERS: FixTag = FixTag { id: "40977", datatype: value_types::NUM_IN_GROUP };
-pub const ENCODEDLEGSTREAMTEXTLEN: FixTag = FixTag { id: "40978", datatype: value_types::LENGTH};
-pub const ENCODEDLEGSTREAMTEXT: FixTag = FixTag { id: "40979", datatype: value_types::DATA};
-pub const ENCODEDLEGPROVISIONTEXTLEN: FixTag = FixTag { id: "40980", datatype: value_types::LENGTH};
-pub const ENCODEDLEGPROVISIONTEXT: FixTag = FixTag { id: "40981", datatype: value_types::DATA};
-pub const ENCODEDSTREAMTEXTLEN: FixTag = FixTag { id: "40982", datatype: value_types::LENGTH};
-pub const ENCODEDSTREAMTEXT: FixTag = FixTag { id: "40983", datatype: value_types::DATA};
-pub const ENCODEDPAYMENTTEXTLEN: FixTag = FixTag { id: "40984", datatype: value_types::LENGTH};
-pub const ENCODEDPAYMENTTEXT: FixTag = FixTag { id: "40985", datatype: value_types::DATA};
-pub const ENCODEDPROVISIONTEXTLEN: FixTag = FixTag { id: "40986", datatype: value_types::LENGTH};
-pub const ENCODEDPROVISIONTEXT: FixTag = FixTag { id: "40987", datatype: value_types::DATA};
-pub const ENCODEDUNDERLYINGSTREAMTEXTLEN: FixTag = FixTag { id: "40988", datatype: value_types::LENGTH};
-pub const ENCODEDUNDERLYINGSTREAMTEXT: FixTag = FixTag { id: "40989", datatype: value_types::DATA};
-pub const LEGMARKETDISRUPTIONFALLBACKVALUE: FixTag = FixTag { id: "40990", datatype: value_types::STRING};
-pub const MARKETDISRUPTIONVALUE: FixTag = FixTag { id: "40991", datatype: value_types::STRING};
-pub const MARKETDISRUPTIONFALLBACKVALUE: FixTag = FixTag { id: "40992", datatype: value_types::STRING};
-pub const PAYMENTSUBTYPE: FixTag = FixTag { id: "40993", datatype: value_types::INT};
-pub const NOCOMPLEXEVENTAVERAGINGOBSERVATIONS: FixTag = FixTag { id: "40994", datatype: value_types::NUM_IN_GROUP };
-pub const COMPLEXEVENTAVERAGINGOBSERVATIONNUMBER: FixTag = FixTag { id: "40995", datatype: value_types::INT};
-pub const COMPLEXEVENTAVERAGINGWEIGHT: FixTag = FixTag { id: "40996", datatype: value_types::FLOAT};
-pub const NOCOMPLEXEVENTCREDITEVENTS: FixTag = FixTag { id: "40997", datatype: value_types::NUM_IN_GROUP };
-pub const COMPLEXEVENTCREDITEVENTTYPE: FixTag = FixTag { id: "40998", datatype: value_types::STRING};
-pub const COMPLEXEVENTCREDITEVENTVALUE: FixTag = FixTag { id: "40999", datatype: value_types::STRING};
-pub const COMPLEXEVENTCREDITEVENTCURRENCY: FixTag = FixTag { id: "41000", datatype: value_types::CURRENCY};
-pub const COMPLEXEVENTCREDITEVENTPERIOD: FixTag = FixTag { id: "41001", datatype: value_types::INT};
-pub const COMPLEXEVENTCREDITEVENTUNIT: FixTag = FixTag { id: "41002", datatype: value_types::STRING};
-pub const COMPLEXEVENTCREDITEVENTDAYTYPE: FixTag = FixTag { id: "41003", datatype: value_types::INT};
-pub const COMPLEXEVENTCREDITEVENTRATESOURCE: FixTag = FixTag { id: "41004", datatype: value_types::INT};
-pub const NOCOMPLEXEVENTCREDITEVENTQUALIFIERS: FixTag = FixTag { id: "41005", datatype: value_types::NUM_IN_GROUP };
-pub const COMPLEXEVENTCREDITEVENTQUALIFIER: FixTag = FixTag { id: "41006", datatype: value_types::CHAR};
-pub const NOCOMPLEXEVENTPERIODDATETIMES: FixTag = FixTag { id: "41007", datatype: value_types::NUM_IN_GROUP };
-pub const COMPLEXEVENTPERIODDATE: FixTag = FixTag { id: "41008", datatype: value_types::LOCAL_MKT_DATE};
-pub const COMPLEXEVENTPERIODTIME: FixTag = FixTag { id: "41009", datatype: value_types::LOCAL_MKT_TIME};
-pub const NOCOMPLEXEVENTPERIODS: FixTag = FixTag { id: "41010", datatype: value_types::NUM_IN_GROUP };
-pub const COMPLEXEVENTPERIODTYPE: FixTag = FixTag { id: "41011", datatype: value_types::INT};
-pub const COMPLEXEVENTBUSINESSCENTER: FixTag = FixTag { id: "41012", datatype: value_types::STRING};
-pub const NOCOMPLEXEVENTRATESOURCES: FixTag = FixTag { id: "41013", datatype: value_types::NUM_IN_GROUP };
-pub const COMPLEXEVENTRATESOURCE: FixTag = FixTag { id: "41014", datatype: value_types::INT};
-pub const COMPLEXEVENTRATESOURCETYPE: FixTag = FixTag { id: "41015", datatype: value_types::INT};
-pub const COMPLEXEVENTREFERENCEPAGE: FixTag = FixTag { id: "41016", datatype: value_types::STRING};
-pub const COMPLEXEVENTREFERENCEPAGEHEADING: FixTag = FixTag { id: "41017", datatype: value_types::STRING};
-pub const NOCOMPLEXEVENTDATEBUSINESSCENTERS: FixTag = FixTag { id: "41018", datatype: value_types::NUM_IN_GROUP };
-pub const COMPLEXEVENTDATEBUSINESSCENTER: FixTag = FixTag { id: "41019", datatype: value_types::STRING};
-pub const COMPLEXEVENTDATEUNADJUSTED: FixTag = FixTag { id: "41020", datatype: value_types::LOCAL_MKT_DATE};
-pub const COMPLEXEVENTDATERELATIVETO: FixTag = FixTag { id: "41021", datatype: value_types::INT};
-pub const COMPLEXEVENTDATEOFFSETPERIOD: FixTag = FixTag { id: "41022", datatype: value_types::INT};
-pub const COMPLEXEVENTDATEOFFSETUNIT: FixTag = FixTag { id: "41023", datatype: value_types::STRING};
-pub const COMPLEXEVENTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "41024", datatype: value_types::INT};
-pub const COMPLEXEVENTDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "41025", datatype: value_types::INT};
-pub const COMPLEXEVENTDATEADJUSTED: FixTag = FixTag { id: "41026", datatype: value_types::LOCAL_MKT_DATE};
-pub const COMPLEXEVENTFIXINGTIME: FixTag = FixTag { id: "41027", datatype: value_types::LOCAL_MKT_TIME};
-pub const COMPLEXEVENTFIXINGTIMEBUSINESSCENTER: FixTag = FixTag { id: "41028", datatype: value_types::STRING};
-pub const NOCOMPLEXEVENTCREDITEVENTSOURCES: FixTag = FixTag { id: "41029", datatype: value_types::NUM_IN_GROUP };
-pub const COMPLEXEVENTCREDITEVENTSOURCE: FixTag = FixTag { id: "41030", datatype: value_types::STRING};
-pub const NOCOMPLEXEVENTSCHEDULES: FixTag = FixTag { id: "41031", datatype: value_types::NUM_IN_GROUP };
-pub const COMPLEXEVENTSCHEDULESTARTDATE: FixTag = FixTag { id: "41032", datatype: value_types::LOCAL_MKT_DATE};
-pub const COMPLEXEVENTSCHEDULEENDDATE: FixTag = FixTag { id: "41033", datatype: value_types::LOCAL_MKT_DATE};
-pub const COMPLEXEVENTSCHEDULEFREQUENCYPERIOD: FixTag = FixTag { id: "41034", datatype: value_types::INT};
-pub const COMPLEXEVENTSCHEDULEFREQUENCYUNIT: FixTag = FixTag { id: "41035", datatype: value_types::STRING};
-pub const COMPLEXEVENTSCHEDULEROLLCONVENTION: FixTag = FixTag { id: "41036", datatype: value_types::STRING};
-pub const NODELIVERYSCHEDULES: FixTag = FixTag { id: "41037", datatype: value_types::NUM_IN_GROUP };
-pub const DELIVERYSCHEDULETYPE: FixTag = FixTag { id: "41038", datatype: value_types::INT};
-pub const DELIVERYSCHEDULEXID: FixTag = FixTag { id: "41039", datatype: value_types::XID};
-pub const DELIVERYSCHEDULENOTIONAL: FixTag = FixTag { id: "41040", datatype: value_types::QTY};
-pub const DELIVERYSCHEDULENOTIONALUNITOFMEASURE: FixTag = FixTag { id: "41041", datatype: value_types::STRING};
-pub const DELIVERYSCHEDULENOTIONALCOMMODITYFREQUENCY: FixTag = FixTag { id: "41042", datatype: value_types::INT};
-pub const DELIVERYSCHEDULENEGATIVETOLERANCE: FixTag = FixTag { id: "41043", datatype: value_types::FLOAT};
-pub const DELIVERYSCHEDULEPOSITIVETOLERANCE: FixTag = FixTag { id: "41044", datatype: value_types::FLOAT};
-pub const DELIVERYSCHEDULETOLERANCEUNITOFMEASURE: FixTag = FixTag { id: "41045", datatype: value_types::STRING};
-pub const DELIVERYSCHEDULETOLERANCETYPE: FixTag = FixTag { id: "41046", datatype: value_types::INT};
-pub const DELIVERYSCHEDULESETTLCOUNTRY: FixTag = FixTag { id: "41047", datatype: value_types::ISO_COUNTRY};
-pub const DELIVERYSCHEDULESETTLTIMEZONE: FixTag = FixTag { id: "41048", datatype: value_types::STRING};
-pub const DELIVERYSCHEDULESETTLFLOWTYPE: FixTag = FixTag { id: "41049", datatype: value_types::INT};
-pub const DELIVERYSCHEDULESETTLHOLIDAYSPROCESSINGINSTRUCTION: FixTag = FixTag { id: "41050", datatype: value_types::INT};
-pub const NODELIVERYSCHEDULESETTLDAYS: FixTag = FixTag { id: "41051", datatype: value_types::NUM_IN_GROUP };
-pub const DELIVERYSCHEDULESETTLDAY: FixTag = FixTag { id: "41052", datatype: value_types::INT};
-pub const DELIVERYSCHEDULESETTLTOTALHOURS: FixTag = FixTag { id: "41053", datatype: value_types::INT};
-pub const NODELIVERYSCHEDULESETTLTIMES: FixTag = FixTag { id: "41054", datatype: value_types::NUM_IN_GROUP };
-pub const DELIVERYSCHEDULESETTLSTART: FixTag = FixTag { id: "41055", datatype: value_types::STRING};
-pub const DELIVERYSCHEDULESETTLEND: FixTag = FixTag { id: "41056", datatype: value_types::STRING};
-pub const DELIVERYSCHEDULESETTLTIMETYPE: FixTag = FixTag { id: "41057", datatype: value_types::INT};
-pub const DELIVERYSTREAMTYPE: FixTag = FixTag { id: "41058", datatype: value_types::INT};
-pub const DELIVERYSTREAMPIPELINE: FixTag = FixTag { id: "41059", datatype: value_types::STRING};
-pub const DELIVERYSTREAMENTRYPOINT: FixTag = FixTag { id: "41060", datatype: value_types::STRING};
-pub const DELIVERYSTREAMWITHDRAWALPOINT: FixTag = FixTag { id: "41061", datatype: value_types::STRING};
-pub const DELIVERYSTREAMDELIVERYPOINT: FixTag = FixTag { id: "41062", datatype: value_types::STRING};
-pub const DELIVERYSTREAMDELIVERYRESTRICTION: FixTag = FixTag { id: "41063", datatype: value_types::INT};
-pub const DELIVERYSTREAMDELIVERYCONTINGENCY: FixTag = FixTag { id: "41064", datatype: value_types::STRING};
-pub const DELIVERYSTREAMDELIVERYCONTINGENTPARTYSIDE: FixTag = FixTag { id: "41065", datatype: value_types::INT};
-pub const DELIVERYSTREAMDELIVERATSOURCEINDICATOR: FixTag = FixTag { id: "41066", datatype: value_types::BOOLEAN};
-pub const DELIVERYSTREAMRISKAPPORTIONMENT: FixTag = FixTag { id: "41067", datatype: value_types::STRING};
-pub const DELIVERYSTREAMTITLETRANSFERLOCATION: FixTag = FixTag { id: "41068", datatype: value_types::STRING};
-pub const DELIVERYSTREAMTITLETRANSFERCONDITION: FixTag = FixTag { id: "41069", datatype: value_types::INT};
-pub const DELIVERYSTREAMIMPORTEROFRECORD: FixTag = FixTag { id: "41070", datatype: value_types::STRING};
-pub const DELIVERYSTREAMNEGATIVETOLERANCE: FixTag = FixTag { id: "41071", datatype: value_types::FLOAT};
-pub const DELIVERYSTREAMPOSITIVETOLERANCE: FixTag = FixTag { id: "41072", datatype: value_types::FLOAT};
-pub const DELIVERYSTREAMTOLERANCEUNITOFMEASURE: FixTag = FixTag { id: "41073", datatype: value_types::STRING};
-pub const DELIVERYSTREAMTOLERANCETYPE: FixTag = FixTag { id: "41074", datatype: value_types::INT};
-pub const DELIVERYSTREAMTOLERANCEOPTIONSIDE: FixTag = FixTag { id: "41075", datatype: value_types::INT};
-pub const DELIVERYSTREAMTOTALPOSITIVETOLERANCE: FixTag = FixTag { id: "41076", datatype: value_types::PERCENTAGE};
-pub const DELIVERYSTREAMTOTALNEGATIVETOLERANCE: FixTag = FixTag { id: "41077", datatype: value_types::PERCENTAGE};
-pub const DELIVERYSTREAMNOTIONALCONVERSIONFACTOR: FixTag = FixTag { id: "41078", datatype: value_types::FLOAT};
-pub const DELIVERYSTREAMTRANSPORTEQUIPMENT: FixTag = FixTag { id: "41079", datatype: value_types::STRING};
-pub const DELIVERYSTREAMELECTINGPARTYSIDE: FixTag = FixTag { id: "41080", datatype: value_types::INT};
-pub const NODELIVERYSTREAMCYCLES: FixTag = FixTag { id: "41081", datatype: value_types::NUM_IN_GROUP };
-pub const DELIVERYSTREAMCYCLEDESC: FixTag = FixTag { id: "41082", datatype: value_types::STRING};
-pub const ENCODEDDELIVERYSTREAMCYCLEDESCLEN: FixTag = FixTag { id: "41083", datatype: value_types::LENGTH};
-pub const ENCODEDDELIVERYSTREAMCYCLEDESC: FixTag = FixTag { id: "41084", datatype: value_types::DATA};
-pub const NODELIVERYSTREAMCOMMODITYSOURCES: FixTag = FixTag { id: "41085", datatype: value_types::NUM_IN_GROUP };
-pub const DELIVERYSTREAMCOMMODITYSOURCE: FixTag = FixTag { id: "41086", datatype: value_types::STRING};
-pub const MARKETDISRUPTIONPROVISION: FixTag = FixTag { id: "41087", datatype: value_types::INT};
-pub const MARKETDISRUPTIONFALLBACKPROVISION: FixTag = FixTag { id: "41088", datatype: value_types::INT};
-pub const MARKETDISRUPTIONMAXIMUMDAYS: FixTag = FixTag { id: "41089", datatype: value_types::INT};
-pub const MARKETDISRUPTIONMATERIALITYPERCENTAGE: FixTag = FixTag { id: "41090", datatype: value_types::PERCENTAGE};
-pub const MARKETDISRUPTIONMINIMUMFUTURESCONTRACTS: FixTag = FixTag { id: "41091", datatype: value_types::INT};
-pub const NOMARKETDISRUPTIONEVENTS: FixTag = FixTag { id: "41092", datatype: value_types::NUM_IN_GROUP };
-pub const MARKETDISRUPTIONEVENT: FixTag = FixTag { id: "41093", datatype: value_types::STRING};
-pub const NOMARKETDISRUPTIONFALLBACKS: FixTag = FixTag { id: "41094", datatype: value_types::NUM_IN_GROUP };
-pub const MARKETDISRUPTIONFALLBACKTYPE: FixTag = FixTag { id: "41095", datatype: value_types::STRING};
-pub const NOMARKETDISRUPTIONFALLBACKREFERENCEPRICES: FixTag = FixTag { id: "41096", datatype: value_types::NUM_IN_GROUP };
-pub const MARKETDISRUPTIONFALLBACKUNDERLIERTYPE: FixTag = FixTag { id: "41097", datatype: value_types::INT};
-pub const MARKETDISRUPTIONFALLBACKUNDERLIERSECURITYID: FixTag = FixTag { id: "41098", datatype: value_types::STRING};
-pub const MARKETDISRUPTIONFALLBACKUNDERLIERSECURITYIDSOURCE: FixTag = FixTag { id: "41099", datatype: value_types::STRING};
-pub const MARKETDISRUPTIONFALLBACKUNDERLIERSECURITYDESC: FixTag = FixTag { id: "41100", datatype: value_types::STRING};
-pub const ENCODEDMARKETDISRUPTIONFALLBACKUNDERLIERSECURITYDESCLEN: FixTag = FixTag { id: "41101", datatype: value_types::LENGTH};
-pub const ENCODEDMARKETDISRUPTIONFALLBACKUNDERLIERSECURITYDESC: FixTag = FixTag { id: "41102", datatype: value_types::DATA};
-pub const MARKETDISRUPTIONFALLBACKOPENUNITS: FixTag = FixTag { id: "41103", datatype: value_types::QTY};
-pub const MARKETDISRUPTIONFALLBACKBASKETCURRENCY: FixTag = FixTag { id: "41104", datatype: value_types::CURRENCY};
-pub const MARKETDISRUPTIONFALLBACKBASKETDIVISOR: FixTag = FixTag { id: "41105", datatype: value_types::FLOAT};
-pub const EXERCISEDESC: FixTag = FixTag { id: "41106", datatype: value_types::STRING};
-pub const ENCODEDEXERCISEDESCLEN: FixTag = FixTag { id: "41107", datatype: value_types::LENGTH};
-pub const ENCODEDEXERCISEDESC: FixTag = FixTag { id: "41108", datatype: value_types::DATA};
-pub const AUTOMATICEXERCISEINDICATOR: FixTag = FixTag { id: "41109", datatype: value_types::BOOLEAN};
-pub const AUTOMATICEXERCISETHRESHOLDRATE: FixTag = FixTag { id: "41110", datatype: value_types::FLOAT};
-pub const EXERCISECONFIRMATIONMETHOD: FixTag = FixTag { id: "41111", datatype: value_types::INT};
-pub const MANUALNOTICEBUSINESSCENTER: FixTag = FixTag { id: "41112", datatype: value_types::STRING};
-pub const FALLBACKEXERCISEINDICATOR: FixTag = FixTag { id: "41113", datatype: value_types::BOOLEAN};
-pub const LIMITEDRIGHTTOCONFIRMINDICATOR: FixTag = FixTag { id: "41114", datatype: value_types::BOOLEAN};
-pub const EXERCISESPLITTICKETINDICATOR: FixTag = FixTag { id: "41115", datatype: value_types::BOOLEAN};
-pub const NOOPTIONEXERCISEBUSINESSCENTERS: FixTag = FixTag { id: "41116", datatype: value_types::NUM_IN_GROUP };
-pub const OPTIONEXERCISEBUSINESSCENTER: FixTag = FixTag { id: "41117", datatype: value_types::STRING};
-pub const OPTIONEXERCISEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "41118", datatype: value_types::INT};
-pub const OPTIONEXERCISEEARLIESTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "41119", datatype: value_types::INT};
-pub const OPTIONEXERCISEEARLIESTDATEOFFSETPERIOD: FixTag = FixTag { id: "41120", datatype: value_types::INT};
-pub const OPTIONEXERCISEEARLIESTDATEOFFSETUNIT: FixTag = FixTag { id: "41121", datatype: value_types::STRING};
-pub const OPTIONEXERCISEFREQUENCYPERIOD: FixTag = FixTag { id: "41122", datatype: value_types::INT};
-pub const OPTIONEXERCISEFREQUENCYUNIT: FixTag = FixTag { id: "41123", datatype: value_types::STRING};
-pub const OPTIONEXERCISESTARTDATEUNADJUSTED: FixTag = FixTag { id: "41124", datatype: value_types::LOCAL_MKT_DATE};
-pub const OPTIONEXERCISESTARTDATERELATIVETO: FixTag = FixTag { id: "41125", datatype: value_types::INT};
-pub const OPTIONEXERCISESTARTDATEOFFSETPERIOD: FixTag = FixTag { id: "41126", datatype: value_types::INT};
-pub const OPTIONEXERCISESTARTDATEOFFSETUNIT: FixTag = FixTag { id: "41127", datatype: value_types::STRING};
-pub const OPTIONEXERCISESTARTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "41128", datatype: value_types::INT};
-pub const OPTIONEXERCISESTARTDATEADJUSTED: FixTag = FixTag { id: "41129", datatype: value_types::LOCAL_MKT_DATE};
-pub const OPTIONEXERCISESKIP: FixTag = FixTag { id: "41130", datatype: value_types::INT};
-pub const OPTIONEXERCISENOMINATIONDEADLINE: FixTag = FixTag { id: "41131", datatype: value_types::LOCAL_MKT_DATE};
-pub const OPTIONEXERCISEFIRSTDATEUNADJUSTED: FixTag = FixTag { id: "41132", datatype: value_types::LOCAL_MKT_DATE};
-pub const OPTIONEXERCISELASTDATEUNADJUSTED: FixTag = FixTag { id: "41133", datatype: value_types::LOCAL_MKT_DATE};
-pub const OPTIONEXERCISEEARLIESTTIME: FixTag = FixTag { id: "41134", datatype: value_types::LOCAL_MKT_TIME};
-pub const OPTIONEXERCISELATESTTIME: FixTag = FixTag { id: "41135", datatype: value_types::LOCAL_MKT_TIME};
-pub const OPTIONEXERCISETIMEBUSINESSCENTER: FixTag = FixTag { id: "41136", datatype: value_types::STRING};
-pub const NOOPTIONEXERCISEDATES: FixTag = FixTag { id: "41137", datatype: value_types::NUM_IN_GROUP };
-pub const OPTIONEXERCISEDATE: FixTag = FixTag { id: "41138", datatype: value_types::LOCAL_MKT_DATE};
-pub const OPTIONEXERCISEDATETYPE: FixTag = FixTag { id: "41139", datatype: value_types::INT};
-pub const NOOPTIONEXERCISEEXPIRATIONDATEBUSINESSCENTERS: FixTag = FixTag { id: "41140", datatype: value_types::NUM_IN_GROUP };
-pub const OPTIONEXERCISEEXPIRATIONDATEBUSINESSCENTER: FixTag = FixTag { id: "41141", datatype: value_types::STRING};
-pub const OPTIONEXERCISEEXPIRATIONDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "41142", datatype: value_types::INT};
-pub const OPTIONEXERCISEEXPIRATIONDATERELATIVETO: FixTag = FixTag { id: "41143", datatype: value_types::INT};
-pub const OPTIONEXERCISEEXPIRATIONDATEOFFSETPERIOD: FixTag = FixTag { id: "41144", datatype: value_types::INT};
-pub const OPTIONEXERCISEEXPIRATIONDATEOFFSETUNIT: FixTag = FixTag { id: "41145", datatype: value_types::STRING};
-pub const OPTIONEXERCISEEXPIRATIONFREQUENCYPERIOD: FixTag = FixTag { id: "41146", datatype: value_types::INT};
-pub const OPTIONEXERCISEEXPIRATIONFREQUENCYUNIT: FixTag = FixTag { id: "41147", datatype: value_types::STRING};
-pub const OPTIONEXERCISEEXPIRATIONROLLCONVENTION: FixTag = FixTag { id: "41148", datatype: value_types::STRING};
-pub const OPTIONEXERCISEEXPIRATIONDATEOFFSETDAYTYPE: FixTag = FixTag { id: "41149", datatype: value_types::INT};
-pub const OPTIONEXERCISEEXPIRATIONTIME: FixTag = FixTag { id: "41150", datatype: value_types::LOCAL_MKT_TIME};
-pub const OPTIONEXERCISEEXPIRATIONTIMEBUSINESSCENTER: FixTag = FixTag { id: "41151", datatype: value_types::STRING};
-pub const NOOPTIONEXERCISEEXPIRATIONDATES: FixTag = FixTag { id: "41152", datatype: value_types::NUM_IN_GROUP };
-pub const OPTIONEXERCISEEXPIRATIONDATE: FixTag = FixTag { id: "41153", datatype: value_types::LOCAL_MKT_DATE};
-pub const OPTIONEXERCISEEXPIRATIONDATETYPE: FixTag = FixTag { id: "41154", datatype: value_types::INT};
-pub const PAYMENTUNITOFMEASURE: FixTag = FixTag { id: "41155", datatype: value_types::STRING};
-pub const PAYMENTDATERELATIVETO: FixTag = FixTag { id: "41156", datatype: value_types::INT};
-pub const PAYMENTDATEOFFSETPERIOD: FixTag = FixTag { id: "41157", datatype: value_types::INT};
-pub const PAYMENTDATEOFFSETUNIT: FixTag = FixTag { id: "41158", datatype: value_types::STRING};
-pub const PAYMENTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "41159", datatype: value_types::INT};
-pub const PAYMENTFORWARDSTARTTYPE: FixTag = FixTag { id: "41160", datatype: value_types::INT};
-pub const NOPAYMENTSCHEDULEFIXINGDAYS: FixTag = FixTag { id: "41161", datatype: value_types::NUM_IN_GROUP };
-pub const PAYMENTSCHEDULEFIXINGDAYOFWEEK: FixTag = FixTag { id: "41162", datatype: value_types::INT};
-pub const PAYMENTSCHEDULEFIXINGDAYNUMBER: FixTag = FixTag { id: "41163", datatype: value_types::INT};
-pub const PAYMENTSCHEDULEXID: FixTag = FixTag { id: "41164", datatype: value_types::XID};
-pub const PAYMENTSCHEDULEXIDREF: FixTag = FixTag { id: "41165", datatype: value_types::XIDREF};
-pub const PAYMENTSCHEDULERATECURRENCY: FixTag = FixTag { id: "41166", datatype: value_types::CURRENCY};
-pub const PAYMENTSCHEDULERATEUNITOFMEASURE: FixTag = FixTag { id: "41167", datatype: value_types::STRING};
-pub const PAYMENTSCHEDULERATECONVERSIONFACTOR: FixTag = FixTag { id: "41168", datatype: value_types::FLOAT};
-pub const PAYMENTSCHEDULERATESPREADTYPE: FixTag = FixTag { id: "41169", datatype: value_types::INT};
-pub const PAYMENTSCHEDULESETTLPERIODPRICE: FixTag = FixTag { id: "41170", datatype: value_types::PRICE};
-pub const PAYMENTSCHEDULESETTLPERIODPRICECURRENCY: FixTag = FixTag { id: "41171", datatype: value_types::CURRENCY};
-pub const PAYMENTSCHEDULESETTLPERIODPRICEUNITOFMEASURE: FixTag = FixTag { id: "41172", datatype: value_types::STRING};
-pub const PAYMENTSCHEDULESTEPUNITOFMEASURE: FixTag = FixTag { id: "41173", datatype: value_types::STRING};
-pub const PAYMENTSCHEDULEFIXINGDAYDISTRIBUTION: FixTag = FixTag { id: "41174", datatype: value_types::INT};
-pub const PAYMENTSCHEDULEFIXINGDAYCOUNT: FixTag = FixTag { id: "41175", datatype: value_types::INT};
-pub const PAYMENTSCHEDULEFIXINGLAGPERIOD: FixTag = FixTag { id: "41176", datatype: value_types::INT};
-pub const PAYMENTSCHEDULEFIXINGLAGUNIT: FixTag = FixTag { id: "41177", datatype: value_types::STRING};
-pub const PAYMENTSCHEDULEFIXINGFIRSTOBSERVATIONDATEOFFSETPERIOD: FixTag = FixTag { id: "41178", datatype: value_types::INT};
-pub const PAYMENTSCHEDULEFIXINGFIRSTOBSERVATIONDATEOFFSETUNIT: FixTag = FixTag { id: "41179", datatype: value_types::STRING};
-pub const PAYMENTSTREAMFLATRATEINDICATOR: FixTag = FixTag { id: "41180", datatype: value_types::BOOLEAN};
-pub const PAYMENTSTREAMFLATRATEAMOUNT: FixTag = FixTag { id: "41181", datatype: value_types::AMT};
-pub const PAYMENTSTREAMFLATRATECURRENCY: FixTag = FixTag { id: "41182", datatype: value_types::CURRENCY};
-pub const PAYMENTSTREAMMAXIMUMPAYMENTAMOUNT: FixTag = FixTag { id: "41183", datatype: value_types::AMT};
-pub const PAYMENTSTREAMMAXIMUMPAYMENTCURRENCY: FixTag = FixTag { id: "41184", datatype: value_types::CURRENCY};
-pub const PAYMENTSTREAMMAXIMUMTRANSACTIONAMOUNT: FixTag = FixTag { id: "41185", datatype: value_types::AMT};
-pub const PAYMENTSTREAMMAXIMUMTRANSACTIONCURRENCY: FixTag = FixTag { id: "41186", datatype: value_types::CURRENCY};
-pub const PAYMENTSTREAMFIXEDAMOUNTUNITOFMEASURE: FixTag = FixTag { id: "41187", datatype: value_types::STRING};
-pub const PAYMENTSTREAMTOTALFIXEDAMOUNT: FixTag = FixTag { id: "41188", datatype: value_types::AMT};
-pub const PAYMENTSTREAMWORLDSCALERATE: FixTag = FixTag { id: "41189", datatype: value_types::FLOAT};
-pub const PAYMENTSTREAMCONTRACTPRICE: FixTag = FixTag { id: "41190", datatype: value_types::PRICE};
-pub const PAYMENTSTREAMCONTRACTPRICECURRENCY: FixTag = FixTag { id: "41191", datatype: value_types::CURRENCY};
-pub const NOPAYMENTSTREAMPRICINGBUSINESSCENTERS: FixTag = FixTag { id: "41192", datatype: value_types::NUM_IN_GROUP };
-pub const PAYMENTSTREAMPRICINGBUSINESSCENTER: FixTag = FixTag { id: "41193", datatype: value_types::STRING};
-pub const PAYMENTSTREAMRATEINDEX2CURVEPERIOD: FixTag = FixTag { id: "41194", datatype: value_types::INT};
-pub const PAYMENTSTREAMRATEINDEX2CURVEUNIT: FixTag = FixTag { id: "41195", datatype: value_types::STRING};
-pub const PAYMENTSTREAMRATEINDEXLOCATION: FixTag = FixTag { id: "41196", datatype: value_types::STRING};
-pub const PAYMENTSTREAMRATEINDEXLEVEL: FixTag = FixTag { id: "41197", datatype: value_types::QTY};
-pub const PAYMENTSTREAMRATEINDEXUNITOFMEASURE: FixTag = FixTag { id: "41198", datatype: value_types::STRING};
-pub const PAYMENTSTREAMSETTLLEVEL: FixTag = FixTag { id: "41199", datatype: value_types::INT};
-pub const PAYMENTSTREAMREFERENCELEVEL: FixTag = FixTag { id: "41200", datatype: value_types::QTY};
-pub const PAYMENTSTREAMREFERENCELEVELUNITOFMEASURE: FixTag = FixTag { id: "41201", datatype: value_types::STRING};
-pub const PAYMENTSTREAMREFERENCELEVELEQUALSZEROINDICATOR: FixTag = FixTag { id: "41202", datatype: value_types::BOOLEAN};
-pub const PAYMENTSTREAMRATESPREADCURRENCY: FixTag = FixTag { id: "41203", datatype: value_types::CURRENCY};
-pub const PAYMENTSTREAMRATESPREADUNITOFMEASURE: FixTag = FixTag { id: "41204", datatype: value_types::STRING};
-pub const PAYMENTSTREAMRATECONVERSIONFACTOR: FixTag = FixTag { id: "41205", datatype: value_types::FLOAT};
-pub const PAYMENTSTREAMRATESPREADTYPE: FixTag = FixTag { id: "41206", datatype: value_types::INT};
-pub const PAYMENTSTREAMLASTRESETRATE: FixTag = FixTag { id: "41207", datatype: value_types::PERCENTAGE};
-pub const PAYMENTSTREAMFINALRATE: FixTag = FixTag { id: "41208", datatype: value_types::PERCENTAGE};
-pub const PAYMENTSTREAMCALCULATIONLAGPERIOD: FixTag = FixTag { id: "41209", datatype: value_types::INT};
-pub const PAYMENTSTREAMCALCULATIONLAGUNIT: FixTag = FixTag { id: "41210", datatype: value_types::STRING};
-pub const PAYMENTSTREAMFIRSTOBSERVATIONDATEOFFSETPERIOD: FixTag = FixTag { id: "41211", datatype: value_types::INT};
-pub const PAYMENTSTREAMFIRSTOBSERVATIONDATEOFFSETUNIT: FixTag = FixTag { id: "41212", datatype: value_types::STRING};
-pub const PAYMENTSTREAMPRICINGDAYTYPE: FixTag = FixTag { id: "41213", datatype: value_types::INT};
-pub const PAYMENTSTREAMPRICINGDAYDISTRIBUTION: FixTag = FixTag { id: "41214", datatype: value_types::INT};
-pub const PAYMENTSTREAMPRICINGDAYCOUNT: FixTag = FixTag { id: "41215", datatype: value_types::INT};
-pub const PAYMENTSTREAMPRICINGBUSINESSCALENDAR: FixTag = FixTag { id: "41216", datatype: value_types::STRING};
-pub const PAYMENTSTREAMPRICINGBUSINESSDAYCONVENTION: FixTag = FixTag { id: "41217", datatype: value_types::INT};
-pub const DELIVERYSTREAMRISKAPPORTIONMENTSOURCE: FixTag = FixTag { id: "41218", datatype: value_types::STRING};
-pub const LEGDELIVERYSTREAMRISKAPPORTIONMENTSOURCE: FixTag = FixTag { id: "41219", datatype: value_types::STRING};
-pub const NOPAYMENTSTREAMPAYMENTDATES: FixTag = FixTag { id: "41220", datatype: value_types::NUM_IN_GROUP };
-pub const PAYMENTSTREAMPAYMENTDATE: FixTag = FixTag { id: "41221", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTSTREAMPAYMENTDATETYPE: FixTag = FixTag { id: "41222", datatype: value_types::INT};
-pub const PAYMENTSTREAMMASTERAGREEMENTPAYMENTDATESINDICATOR: FixTag = FixTag { id: "41223", datatype: value_types::BOOLEAN};
-pub const NOPAYMENTSTREAMPRICINGDATES: FixTag = FixTag { id: "41224", datatype: value_types::NUM_IN_GROUP };
-pub const PAYMENTSTREAMPRICINGDATE: FixTag = FixTag { id: "41225", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTSTREAMPRICINGDATETYPE: FixTag = FixTag { id: "41226", datatype: value_types::INT};
-pub const NOPAYMENTSTREAMPRICINGDAYS: FixTag = FixTag { id: "41227", datatype: value_types::NUM_IN_GROUP };
-pub const PAYMENTSTREAMPRICINGDAYOFWEEK: FixTag = FixTag { id: "41228", datatype: value_types::INT};
-pub const PAYMENTSTREAMPRICINGDAYNUMBER: FixTag = FixTag { id: "41229", datatype: value_types::INT};
-pub const NOPRICINGDATEBUSINESSCENTERS: FixTag = FixTag { id: "41230", datatype: value_types::NUM_IN_GROUP };
-pub const PRICINGDATEBUSINESSCENTER: FixTag = FixTag { id: "41231", datatype: value_types::STRING};
-pub const PRICINGDATEUNADJUSTED: FixTag = FixTag { id: "41232", datatype: value_types::LOCAL_MKT_DATE};
-pub const PRICINGDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "41233", datatype: value_types::INT};
-pub const PRICINGDATEADJUSTED: FixTag = FixTag { id: "41234", datatype: value_types::LOCAL_MKT_DATE};
-pub const PRICINGTIME: FixTag = FixTag { id: "41235", datatype: value_types::LOCAL_MKT_TIME};
-pub const PRICINGTIMEBUSINESSCENTER: FixTag = FixTag { id: "41236", datatype: value_types::STRING};
-pub const NOSTREAMASSETATTRIBUTES: FixTag = FixTag { id: "41237", datatype: value_types::NUM_IN_GROUP };
-pub const STREAMASSETATTRIBUTETYPE: FixTag = FixTag { id: "41238", datatype: value_types::STRING};
-pub const STREAMASSETATTRIBUTEVALUE: FixTag = FixTag { id: "41239", datatype: value_types::STRING};
-pub const STREAMASSETATTRIBUTELIMIT: FixTag = FixTag { id: "41240", datatype: value_types::STRING};
-pub const NOSTREAMCALCULATIONPERIODDATES: FixTag = FixTag { id: "41241", datatype: value_types::NUM_IN_GROUP };
-pub const STREAMCALCULATIONPERIODDATE: FixTag = FixTag { id: "41242", datatype: value_types::LOCAL_MKT_DATE};
-pub const STREAMCALCULATIONPERIODDATETYPE: FixTag = FixTag { id: "41243", datatype: value_types::INT};
-pub const STREAMCALCULATIONPERIODDATESXID: FixTag = FixTag { id: "41244", datatype: value_types::XID};
-pub const STREAMCALCULATIONPERIODDATESXIDREF: FixTag = FixTag { id: "41245", datatype: value_types::XIDREF};
-pub const STREAMCALCULATIONBALANCEOFFIRSTPERIOD: FixTag = FixTag { id: "41246", datatype: value_types::BOOLEAN};
-pub const STREAMCALCULATIONCORRECTIONPERIOD: FixTag = FixTag { id: "41247", datatype: value_types::INT};
-pub const STREAMCALCULATIONCORRECTIONUNIT: FixTag = FixTag { id: "41248", datatype: value_types::STRING};
-pub const NOSTREAMCOMMODITYSETTLBUSINESSCENTERS: FixTag = FixTag { id: "41249", datatype: value_types::NUM_IN_GROUP };
-pub const STREAMCOMMODITYSETTLBUSINESSCENTER: FixTag = FixTag { id: "41250", datatype: value_types::STRING};
-pub const STREAMCOMMODITYBASE: FixTag = FixTag { id: "41251", datatype: value_types::STRING};
-pub const STREAMCOMMODITYTYPE: FixTag = FixTag { id: "41252", datatype: value_types::STRING};
-pub const STREAMCOMMODITYSECURITYID: FixTag = FixTag { id: "41253", datatype: value_types::STRING};
-pub const STREAMCOMMODITYSECURITYIDSOURCE: FixTag = FixTag { id: "41254", datatype: value_types::STRING};
-pub const STREAMCOMMODITYDESC: FixTag = FixTag { id: "41255", datatype: value_types::STRING};
-pub const ENCODEDSTREAMCOMMODITYDESCLEN: FixTag = FixTag { id: "41256", datatype: value_types::LENGTH};
-pub const ENCODEDSTREAMCOMMODITYDESC: FixTag = FixTag { id: "41257", datatype: value_types::DATA};
-pub const STREAMCOMMODITYUNITOFMEASURE: FixTag = FixTag { id: "41258", datatype: value_types::STRING};
-pub const STREAMCOMMODITYCURRENCY: FixTag = FixTag { id: "41259", datatype: value_types::CURRENCY};
-pub const STREAMCOMMODITYEXCHANGE: FixTag = FixTag { id: "41260", datatype: value_types::EXCHANGE};
-pub const STREAMCOMMODITYRATESOURCE: FixTag = FixTag { id: "41261", datatype: value_types::INT};
-pub const STREAMCOMMODITYRATEREFERENCEPAGE: FixTag = FixTag { id: "41262", datatype: value_types::STRING};
-pub const STREAMCOMMODITYRATEREFERENCEPAGEHEADING: FixTag = FixTag { id: "41263", datatype: value_types::STRING};
-pub const STREAMDATAPROVIDER: FixTag = FixTag { id: "41264", datatype: value_types::STRING};
-pub const STREAMCOMMODITYPRICINGTYPE: FixTag = FixTag { id: "41265", datatype: value_types::STRING};
-pub const STREAMCOMMODITYNEARBYSETTLDAYPERIOD: FixTag = FixTag { id: "41266", datatype: value_types::INT};
-pub const STREAMCOMMODITYNEARBYSETTLDAYUNIT: FixTag = FixTag { id: "41267", datatype: value_types::STRING};
-pub const STREAMCOMMODITYSETTLDATEUNADJUSTED: FixTag = FixTag { id: "41268", datatype: value_types::LOCAL_MKT_DATE};
-pub const STREAMCOMMODITYSETTLDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "41269", datatype: value_types::INT};
-pub const STREAMCOMMODITYSETTLDATEADJUSTED: FixTag = FixTag { id: "41270", datatype: value_types::LOCAL_MKT_DATE};
-pub const STREAMCOMMODITYSETTLMONTH: FixTag = FixTag { id: "41271", datatype: value_types::INT};
-pub const STREAMCOMMODITYSETTLDATEROLLPERIOD: FixTag = FixTag { id: "41272", datatype: value_types::INT};
-pub const STREAMCOMMODITYSETTLDATEROLLUNIT: FixTag = FixTag { id: "41273", datatype: value_types::STRING};
-pub const STREAMCOMMODITYSETTLDAYTYPE: FixTag = FixTag { id: "41274", datatype: value_types::INT};
-pub const STREAMCOMMODITYXID: FixTag = FixTag { id: "41275", datatype: value_types::XID};
-pub const STREAMCOMMODITYXIDREF: FixTag = FixTag { id: "41276", datatype: value_types::XIDREF};
-pub const NOSTREAMCOMMODITYALTIDS: FixTag = FixTag { id: "41277", datatype: value_types::NUM_IN_GROUP };
-pub const STREAMCOMMODITYALTID: FixTag = FixTag { id: "41278", datatype: value_types::STRING};
-pub const STREAMCOMMODITYALTIDSOURCE: FixTag = FixTag { id: "41279", datatype: value_types::STRING};
-pub const NOSTREAMCOMMODITYDATASOURCES: FixTag = FixTag { id: "41280", datatype: value_types::NUM_IN_GROUP };
-pub const STREAMCOMMODITYDATASOURCEID: FixTag = FixTag { id: "41281", datatype: value_types::STRING};
-pub const STREAMCOMMODITYDATASOURCEIDTYPE: FixTag = FixTag { id: "41282", datatype: value_types::INT};
-pub const NOSTREAMCOMMODITYSETTLDAYS: FixTag = FixTag { id: "41283", datatype: value_types::NUM_IN_GROUP };
-pub const STREAMCOMMODITYSETTLDAY: FixTag = FixTag { id: "41284", datatype: value_types::INT};
-pub const STREAMCOMMODITYSETTLTOTALHOURS: FixTag = FixTag { id: "41285", datatype: value_types::INT};
-pub const NOSTREAMCOMMODITYSETTLTIMES: FixTag = FixTag { id: "41286", datatype: value_types::NUM_IN_GROUP };
-pub const STREAMCOMMODITYSETTLSTART: FixTag = FixTag { id: "41287", datatype: value_types::STRING};
-pub const STREAMCOMMODITYSETTLEND: FixTag = FixTag { id: "41288", datatype: value_types::STRING};
-pub const NOSTREAMCOMMODITYSETTLPERIODS: FixTag = FixTag { id: "41289", datatype: value_types::NUM_IN_GROUP };
-pub const STREAMCOMMODITYSETTLCOUNTRY: FixTag = FixTag { id: "41290", datatype: value_types::ISO_COUNTRY};
-pub const STREAMCOMMODITYSETTLTIMEZONE: FixTag = FixTag { id: "41291", datatype: value_types::STRING};
-pub const STREAMCOMMODITYSETTLFLOWTYPE: FixTag = FixTag { id: "41292", datatype: value_types::INT};
-pub const STREAMCOMMODITYSETTLPERIODNOTIONAL: FixTag = FixTag { id: "41293", datatype: value_types::QTY};
-pub const STREAMCOMMODITYSETTLPERIODNOTIONALUNITOFMEASURE: FixTag = FixTag { id: "41294", datatype: value_types::STRING};
-pub const STREAMCOMMODITYSETTLPERIODFREQUENCYPERIOD: FixTag = FixTag { id: "41295", datatype: value_types::INT};
-pub const STREAMCOMMODITYSETTLPERIODFREQUENCYUNIT: FixTag = FixTag { id: "41296", datatype: value_types::STRING};
-pub const STREAMCOMMODITYSETTLPERIODPRICE: FixTag = FixTag { id: "41297", datatype: value_types::PRICE};
-pub const STREAMCOMMODITYSETTLPERIODPRICEUNITOFMEASURE: FixTag = FixTag { id: "41298", datatype: value_types::STRING};
-pub const STREAMCOMMODITYSETTLPERIODPRICECURRENCY: FixTag = FixTag { id: "41299", datatype: value_types::CURRENCY};
-pub const STREAMCOMMODITYSETTLHOLIDAYSPROCESSINGINSTRUCTION: FixTag = FixTag { id: "41300", datatype: value_types::INT};
-pub const STREAMCOMMODITYSETTLPERIODXID: FixTag = FixTag { id: "41301", datatype: value_types::XID};
-pub const STREAMCOMMODITYSETTLPERIODXIDREF: FixTag = FixTag { id: "41302", datatype: value_types::XIDREF};
-pub const STREAMXID: FixTag = FixTag { id: "41303", datatype: value_types::XID};
-pub const PAYMENTLEGREFID: FixTag = FixTag { id: "41304", datatype: value_types::STRING};
-pub const STREAMNOTIONALXIDREF: FixTag = FixTag { id: "41305", datatype: value_types::XIDREF};
-pub const STREAMNOTIONALFREQUENCYPERIOD: FixTag = FixTag { id: "41306", datatype: value_types::INT};
-pub const STREAMNOTIONALFREQUENCYUNIT: FixTag = FixTag { id: "41307", datatype: value_types::STRING};
-pub const STREAMNOTIONALCOMMODITYFREQUENCY: FixTag = FixTag { id: "41308", datatype: value_types::INT};
-pub const STREAMNOTIONALUNITOFMEASURE: FixTag = FixTag { id: "41309", datatype: value_types::STRING};
-pub const STREAMTOTALNOTIONAL: FixTag = FixTag { id: "41310", datatype: value_types::QTY};
-pub const STREAMTOTALNOTIONALUNITOFMEASURE: FixTag = FixTag { id: "41311", datatype: value_types::STRING};
-pub const NOMANDATORYCLEARINGJURISDICTIONS: FixTag = FixTag { id: "41312", datatype: value_types::NUM_IN_GROUP };
-pub const MANDATORYCLEARINGJURISDICTION: FixTag = FixTag { id: "41313", datatype: value_types::STRING};
-pub const UNDERLYINGPROTECTIONTERMXIDREF: FixTag = FixTag { id: "41314", datatype: value_types::XIDREF};
-pub const UNDERLYINGSETTLTERMXIDREF: FixTag = FixTag { id: "41315", datatype: value_types::XIDREF};
-pub const NOLEGADDITIONALTERMBONDREFS: FixTag = FixTag { id: "41316", datatype: value_types::NUM_IN_GROUP };
-pub const LEGADDITIONALTERMBONDSECURITYID: FixTag = FixTag { id: "41317", datatype: value_types::STRING};
-pub const LEGADDITIONALTERMBONDSECURITYIDSOURCE: FixTag = FixTag { id: "41318", datatype: value_types::STRING};
-pub const LEGADDITIONALTERMBONDDESC: FixTag = FixTag { id: "41319", datatype: value_types::STRING};
-pub const ENCODEDLEGADDITIONALTERMBONDDESCLEN: FixTag = FixTag { id: "41320", datatype: value_types::LENGTH};
-pub const ENCODEDLEGADDITIONALTERMBONDDESC: FixTag = FixTag { id: "41321", datatype: value_types::DATA};
-pub const LEGADDITIONALTERMBONDCURRENCY: FixTag = FixTag { id: "41322", datatype: value_types::CURRENCY};
-pub const LEGADDITIONALTERMBONDISSUER: FixTag = FixTag { id: "41323", datatype: value_types::STRING};
-pub const ENCODEDLEGADDITIONALTERMBONDISSUERLEN: FixTag = FixTag { id: "41324", datatype: value_types::LENGTH};
-pub const ENCODEDLEGADDITIONALTERMBONDISSUER: FixTag = FixTag { id: "41325", datatype: value_types::DATA};
-pub const LEGADDITIONALTERMBONDSENIORITY: FixTag = FixTag { id: "41326", datatype: value_types::STRING};
-pub const LEGADDITIONALTERMBONDCOUPONTYPE: FixTag = FixTag { id: "41327", datatype: value_types::INT};
-pub const LEGADDITIONALTERMBONDCOUPONRATE: FixTag = FixTag { id: "41328", datatype: value_types::PERCENTAGE};
-pub const LEGADDITIONALTERMBONDMATURITYDATE: FixTag = FixTag { id: "41329", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGADDITIONALTERMBONDPARVALUE: FixTag = FixTag { id: "41330", datatype: value_types::AMT};
-pub const LEGADDITIONALTERMBONDCURRENTTOTALISSUEDAMOUNT: FixTag = FixTag { id: "41331", datatype: value_types::AMT};
-pub const LEGADDITIONALTERMBONDCOUPONFREQUENCYPERIOD: FixTag = FixTag { id: "41332", datatype: value_types::INT};
-pub const LEGADDITIONALTERMBONDCOUPONFREQUENCYUNIT: FixTag = FixTag { id: "41333", datatype: value_types::STRING};
-pub const LEGADDITIONALTERMBONDDAYCOUNT: FixTag = FixTag { id: "41334", datatype: value_types::INT};
-pub const NOLEGADDITIONALTERMS: FixTag = FixTag { id: "41335", datatype: value_types::NUM_IN_GROUP };
-pub const LEGADDITIONALTERMCONDITIONPRECEDENTBONDINDICATOR: FixTag = FixTag { id: "41336", datatype: value_types::BOOLEAN};
-pub const LEGADDITIONALTERMDISCREPANCYCLAUSEINDICATOR: FixTag = FixTag { id: "41337", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGMARKETDISRUPTIONVALUE: FixTag = FixTag { id: "41338", datatype: value_types::STRING};
-pub const UNDERLYINGMARKETDISRUPTIONFALLBACKVALUE: FixTag = FixTag { id: "41339", datatype: value_types::STRING};
-pub const NOUNDERLYINGADDITIONALTERMBONDREFS: FixTag = FixTag { id: "41340", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGADDITIONALTERMBONDSECURITYID: FixTag = FixTag { id: "41341", datatype: value_types::STRING};
-pub const NOLEGCASHSETTLDEALERS: FixTag = FixTag { id: "41342", datatype: value_types::NUM_IN_GROUP };
-pub const LEGCASHSETTLDEALER: FixTag = FixTag { id: "41343", datatype: value_types::STRING};
-pub const NOLEGCASHSETTLTERMS: FixTag = FixTag { id: "41344", datatype: value_types::NUM_IN_GROUP };
-pub const LEGCASHSETTLCURRENCY: FixTag = FixTag { id: "41345", datatype: value_types::CURRENCY};
-pub const LEGCASSETTLVALUATIONFIRSTBUSINESSDAYOFFSET: FixTag = FixTag { id: "41346", datatype: value_types::INT};
-pub const LEGCASHSETTLVALUATIONSUBSEQUENTBUSINESSDAYSOFFSET: FixTag = FixTag { id: "41347", datatype: value_types::INT};
-pub const LEGCASHSETTLNUMOFVALUATIONDATES: FixTag = FixTag { id: "41348", datatype: value_types::INT};
-pub const LEGCASHSETTLVALUATIONTIME: FixTag = FixTag { id: "41349", datatype: value_types::LOCAL_MKT_TIME};
-pub const LEGCASHSETTLBUSINESSCENTER: FixTag = FixTag { id: "41350", datatype: value_types::STRING};
-pub const LEGCASHSETTLQUOTEMETHOD: FixTag = FixTag { id: "41351", datatype: value_types::INT};
-pub const LEGCASHSETTLQUOTEAMOUNT: FixTag = FixTag { id: "41352", datatype: value_types::AMT};
-pub const LEGCASHSETTLQUOTECURRENCY: FixTag = FixTag { id: "41353", datatype: value_types::CURRENCY};
-pub const LEGCASHSETTLMINIMUMQUOTEAMOUNT: FixTag = FixTag { id: "41354", datatype: value_types::AMT};
-pub const LEGCASHSETTLMINIMUMQUOTECURRENCY: FixTag = FixTag { id: "41355", datatype: value_types::CURRENCY};
-pub const LEGCASHSETTLBUSINESSDAYS: FixTag = FixTag { id: "41356", datatype: value_types::INT};
-pub const LEGCASHSETTLAMOUNT: FixTag = FixTag { id: "41357", datatype: value_types::AMT};
-pub const LEGCASHSETTLRECOVERYFACTOR: FixTag = FixTag { id: "41358", datatype: value_types::FLOAT};
-pub const LEGCASHSETTLFIXEDTERMINDICATOR: FixTag = FixTag { id: "41359", datatype: value_types::BOOLEAN};
-pub const LEGCASHSETTLACCRUEDINTERESTINDICATOR: FixTag = FixTag { id: "41360", datatype: value_types::BOOLEAN};
-pub const LEGCASHSETTLVALUATIONMETHOD: FixTag = FixTag { id: "41361", datatype: value_types::INT};
-pub const LEGCASHSETTLTERMXID: FixTag = FixTag { id: "41362", datatype: value_types::XID};
-pub const NOLEGCOMPLEXEVENTAVERAGINGOBSERVATIONS: FixTag = FixTag { id: "41363", datatype: value_types::NUM_IN_GROUP };
-pub const LEGCOMPLEXEVENTAVERAGINGOBSERVATIONNUMBER: FixTag = FixTag { id: "41364", datatype: value_types::INT};
-pub const LEGCOMPLEXEVENTAVERAGINGWEIGHT: FixTag = FixTag { id: "41365", datatype: value_types::FLOAT};
-pub const NOLEGCOMPLEXEVENTCREDITEVENTS: FixTag = FixTag { id: "41366", datatype: value_types::NUM_IN_GROUP };
-pub const LEGCOMPLEXEVENTCREDITEVENTTYPE: FixTag = FixTag { id: "41367", datatype: value_types::STRING};
-pub const LEGCOMPLEXEVENTCREDITEVENTVALUE: FixTag = FixTag { id: "41368", datatype: value_types::STRING};
-pub const LEGCOMPLEXEVENTCREDITEVENTCURRENCY: FixTag = FixTag { id: "41369", datatype: value_types::CURRENCY};
-pub const LEGCOMPLEXEVENTCREDITEVENTPERIOD: FixTag = FixTag { id: "41370", datatype: value_types::INT};
-pub const LEGCOMPLEXEVENTCREDITEVENTUNIT: FixTag = FixTag { id: "41371", datatype: value_types::STRING};
-pub const LEGCOMPLEXEVENTCREDITEVENTDAYTYPE: FixTag = FixTag { id: "41372", datatype: value_types::INT};
-pub const LEGCOMPLEXEVENTCREDITEVENTRATESOURCE: FixTag = FixTag { id: "41373", datatype: value_types::INT};
-pub const NOLEGCOMPLEXEVENTCREDITEVENTQUALIFIERS: FixTag = FixTag { id: "41374", datatype: value_types::NUM_IN_GROUP };
-pub const LEGCOMPLEXEVENTCREDITEVENTQUALIFIER: FixTag = FixTag { id: "41375", datatype: value_types::CHAR};
-pub const NOLEGCOMPLEXEVENTPERIODDATETIMES: FixTag = FixTag { id: "41376", datatype: value_types::NUM_IN_GROUP };
-pub const LEGCOMPLEXEVENTPERIODDATE: FixTag = FixTag { id: "41377", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGCOMPLEXEVENTPERIODTIME: FixTag = FixTag { id: "41378", datatype: value_types::LOCAL_MKT_TIME};
-pub const NOLEGCOMPLEXEVENTPERIODS: FixTag = FixTag { id: "41379", datatype: value_types::NUM_IN_GROUP };
-pub const LEGCOMPLEXEVENTPERIODTYPE: FixTag = FixTag { id: "41380", datatype: value_types::INT};
-pub const LEGCOMPLEXEVENTBUSINESSCENTER: FixTag = FixTag { id: "41381", datatype: value_types::STRING};
-pub const NOLEGCOMPLEXEVENTRATESOURCES: FixTag = FixTag { id: "41382", datatype: value_types::NUM_IN_GROUP };
-pub const LEGCOMPLEXEVENTRATESOURCE: FixTag = FixTag { id: "41383", datatype: value_types::INT};
-pub const LEGCOMPLEXEVENTRATESOURCETYPE: FixTag = FixTag { id: "41384", datatype: value_types::INT};
-pub const LEGCOMPLEXEVENTREFERENCEPAGE: FixTag = FixTag { id: "41385", datatype: value_types::STRING};
-pub const LEGCOMPLEXEVENREFERENCEPAGEHEADING: FixTag = FixTag { id: "41386", datatype: value_types::STRING};
-pub const NOLEGCOMPLEXEVENTDATEBUSINESSCENTERS: FixTag = FixTag { id: "41387", datatype: value_types::NUM_IN_GROUP };
-pub const LEGCOMPLEXEVENTDATEBUSINESSCENTER: FixTag = FixTag { id: "41388", datatype: value_types::STRING};
-pub const LEGCOMPLEXEVENTDATEUNADJUSTED: FixTag = FixTag { id: "41389", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGCOMPLEXEVENTDATERELATIVETO: FixTag = FixTag { id: "41390", datatype: value_types::INT};
-pub const LEGCOMPLEXEVENTDATEOFFSETPERIOD: FixTag = FixTag { id: "41391", datatype: value_types::INT};
-pub const LEGCOMPLEXEVENTDATEOFFSETUNIT: FixTag = FixTag { id: "41392", datatype: value_types::STRING};
-pub const LEGCOMPLEXEVENTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "41393", datatype: value_types::INT};
-pub const LEGCOMPLEXEVENTDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "41394", datatype: value_types::INT};
-pub const LEGCOMPLEXEVENTDATEADJUSTED: FixTag = FixTag { id: "41395", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGCOMPLEXEVENTFIXINGTIME: FixTag = FixTag { id: "41396", datatype: value_types::LOCAL_MKT_TIME};
-pub const LEGCOMPLEXEVENTFIXINGTIMEBUSINESSCENTER: FixTag = FixTag { id: "41397", datatype: value_types::STRING};
-pub const NOLEGCOMPLEXEVENTCREDITEVENTSOURCES: FixTag = FixTag { id: "41398", datatype: value_types::NUM_IN_GROUP };
-pub const LEGCOMPLEXEVENTCREDITEVENTSOURCE: FixTag = FixTag { id: "41399", datatype: value_types::STRING};
-pub const NOLEGCOMPLEXEVENTSCHEDULES: FixTag = FixTag { id: "41400", datatype: value_types::NUM_IN_GROUP };
-pub const LEGCOMPLEXEVENTSCHEDULESTARTDATE: FixTag = FixTag { id: "41401", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGCOMPLEXEVENTSCHEDULEENDDATE: FixTag = FixTag { id: "41402", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGCOMPLEXEVENTSCHEDULEFREQUENCYPERIOD: FixTag = FixTag { id: "41403", datatype: value_types::INT};
-pub const LEGCOMPLEXEVENTSCHEDULEFREQUENCYUNIT: FixTag = FixTag { id: "41404", datatype: value_types::STRING};
-pub const LEGCOMPLEXEVENTSCHEDULEROLLCONVENTION: FixTag = FixTag { id: "41405", datatype: value_types::STRING};
-pub const PROVISIONCASHSETTLQUOTEREFERENCEPAGE: FixTag = FixTag { id: "41406", datatype: value_types::STRING};
-pub const LEGPROVISIONCASHSETTLQUOTEREFERENCEPAGE: FixTag = FixTag { id: "41407", datatype: value_types::STRING};
-pub const NOLEGDELIVERYSCHEDULES: FixTag = FixTag { id: "41408", datatype: value_types::NUM_IN_GROUP };
-pub const LEGDELIVERYSCHEDULETYPE: FixTag = FixTag { id: "41409", datatype: value_types::INT};
-pub const LEGDELIVERYSCHEDULEXID: FixTag = FixTag { id: "41410", datatype: value_types::XID};
-pub const LEGDELIVERYSCHEDULENOTIONAL: FixTag = FixTag { id: "41411", datatype: value_types::QTY};
-pub const LEGDELIVERYSCHEDULENOTIONALUNITOFMEASURE: FixTag = FixTag { id: "41412", datatype: value_types::STRING};
-pub const LEGDELIVERYSCHEDULENOTIONALCOMMODITYFREQUENCY: FixTag = FixTag { id: "41413", datatype: value_types::INT};
-pub const LEGDELIVERYSCHEDULENEGATIVETOLERANCE: FixTag = FixTag { id: "41414", datatype: value_types::FLOAT};
-pub const LEGDELIVERYSCHEDULEPOSITIVETOLERANCE: FixTag = FixTag { id: "41415", datatype: value_types::FLOAT};
-pub const LEGDELIVERYSCHEDULETOLERANCEUNITOFMEASURE: FixTag = FixTag { id: "41416", datatype: value_types::STRING};
-pub const LEGDELIVERYSCHEDULETOLERANCETYPE: FixTag = FixTag { id: "41417", datatype: value_types::INT};
-pub const LEGDELIVERYSCHEDULESETTLCOUNTRY: FixTag = FixTag { id: "41418", datatype: value_types::ISO_COUNTRY};
-pub const LEGDELIVERYSCHEDULESETTLTIMEZONE: FixTag = FixTag { id: "41419", datatype: value_types::STRING};
-pub const LEGDELIVERYSCHEDULESETTLFLOWTYPE: FixTag = FixTag { id: "41420", datatype: value_types::INT};
-pub const LEGDELIVERYSCHEDULESETTLHOLIDAYSPROCESSINGINSTRUCTION: FixTag = FixTag { id: "41421", datatype: value_types::INT};
-pub const NOLEGDELIVERYSCHEDULESETTLDAYS: FixTag = FixTag { id: "41422", datatype: value_types::NUM_IN_GROUP };
-pub const LEGDELIVERYSCHEDULESETTLDAY: FixTag = FixTag { id: "41423", datatype: value_types::INT};
-pub const LEGDELIVERYSCHEDULESETTLTOTALHOURS: FixTag = FixTag { id: "41424", datatype: value_types::INT};
-pub const NOLEGDELIVERYSCHEDULESETTLTIMES: FixTag = FixTag { id: "41425", datatype: value_types::NUM_IN_GROUP };
-pub const LEGDELIVERYSCHEDULESETTLSTART: FixTag = FixTag { id: "41426", datatype: value_types::STRING};
-pub const LEGDELIVERYSCHEDULESETTLEND: FixTag = FixTag { id: "41427", datatype: value_types::STRING};
-pub const LEGDELIVERYSCHEDULESETTLTIMETYPE: FixTag = FixTag { id: "41428", datatype: value_types::INT};
-pub const LEGDELIVERYSTREAMTYPE: FixTag = FixTag { id: "41429", datatype: value_types::INT};
-pub const LEGDELIVERYSTREAMPIPELINE: FixTag = FixTag { id: "41430", datatype: value_types::STRING};
-pub const LEGDELIVERYSTREAMENTRYPOINT: FixTag = FixTag { id: "41431", datatype: value_types::STRING};
-pub const LEGDELIVERYSTREAMWITHDRAWALPOINT: FixTag = FixTag { id: "41432", datatype: value_types::STRING};
-pub const LEGDELIVERYSTREAMDELIVERYPOINT: FixTag = FixTag { id: "41433", datatype: value_types::STRING};
-pub const LEGDELIVERYSTREAMDELIVERYRESTRICTION: FixTag = FixTag { id: "41434", datatype: value_types::INT};
-pub const LEGDELIVERYSTREAMDELIVERYCONTINGENCY: FixTag = FixTag { id: "41435", datatype: value_types::STRING};
-pub const LEGDELIVERYSTREAMDELIVERYCONTINGENTPARTYSIDE: FixTag = FixTag { id: "41436", datatype: value_types::INT};
-pub const LEGDELIVERYSTREAMDELIVERATSOURCEINDICATOR: FixTag = FixTag { id: "41437", datatype: value_types::BOOLEAN};
-pub const LEGDELIVERYSTREAMRISKAPPORTIONMENT: FixTag = FixTag { id: "41438", datatype: value_types::STRING};
-pub const LEGDELIVERYSTREAMTITLETRANSFERLOCATION: FixTag = FixTag { id: "41439", datatype: value_types::STRING};
-pub const LEGDELIVERYSTREAMTITLETRANSFERCONDITION: FixTag = FixTag { id: "41440", datatype: value_types::INT};
-pub const LEGDELIVERYSTREAMIMPORTEROFRECORD: FixTag = FixTag { id: "41441", datatype: value_types::STRING};
-pub const LEGDELIVERYSTREAMNEGATIVETOLERANCE: FixTag = FixTag { id: "41442", datatype: value_types::FLOAT};
-pub const LEGDELIVERYSTREAMPOSITIVETOLERANCE: FixTag = FixTag { id: "41443", datatype: value_types::FLOAT};
-pub const LEGDELIVERYSTREAMTOLERANCEUNITOFMEASURE: FixTag = FixTag { id: "41444", datatype: value_types::STRING};
-pub const LEGDELIVERYSTREAMTOLERANCETYPE: FixTag = FixTag { id: "41445", datatype: value_types::INT};
-pub const LEGDELIVERYSTREAMTOLERANCEOPTIONSIDE: FixTag = FixTag { id: "41446", datatype: value_types::INT};
-pub const LEGDELIVERYSTREAMTOTALPOSITIVETOLERANCE: FixTag = FixTag { id: "41447", datatype: value_types::PERCENTAGE};
-pub const LEGDELIVERYSTREAMTOTALNEGATIVETOLERANCE: FixTag = FixTag { id: "41448", datatype: value_types::PERCENTAGE};
-pub const LEGDELIVERYSTREAMNOTIONALCONVERSIONFACTOR: FixTag = FixTag { id: "41449", datatype: value_types::FLOAT};
-pub const LEGDELIVERYSTREAMTRANSPORTEQUIPMENT: FixTag = FixTag { id: "41450", datatype: value_types::STRING};
-pub const LEGDELIVERYSTREAMELECTINGPARTYSIDE: FixTag = FixTag { id: "41451", datatype: value_types::INT};
-pub const NOLEGSTREAMASSETATTRIBUTES: FixTag = FixTag { id: "41452", datatype: value_types::NUM_IN_GROUP };
-pub const LEGSTREAMASSETATTRIBUTETYPE: FixTag = FixTag { id: "41453", datatype: value_types::STRING};
-pub const LEGSTREAMASSETATTRIBUTEVALUE: FixTag = FixTag { id: "41454", datatype: value_types::STRING};
-pub const LEGSTREAMASSETATTRIBUTELIMIT: FixTag = FixTag { id: "41455", datatype: value_types::STRING};
-pub const NOLEGDELIVERYSTREAMCYCLES: FixTag = FixTag { id: "41456", datatype: value_types::NUM_IN_GROUP };
-pub const LEGDELIVERYSTREAMCYCLEDESC: FixTag = FixTag { id: "41457", datatype: value_types::STRING};
-pub const ENCODEDLEGDELIVERYSTREAMCYCLEDESCLEN: FixTag = FixTag { id: "41458", datatype: value_types::LENGTH};
-pub const ENCODEDLEGDELIVERYSTREAMCYCLEDESC: FixTag = FixTag { id: "41459", datatype: value_types::DATA};
-pub const NOLEGDELIVERYSTREAMCOMMODITYSOURCES: FixTag = FixTag { id: "41460", datatype: value_types::NUM_IN_GROUP };
-pub const LEGDELIVERYSTREAMCOMMODITYSOURCE: FixTag = FixTag { id: "41461", datatype: value_types::STRING};
-pub const LEGMARKETDISRUPTIONPROVISION: FixTag = FixTag { id: "41462", datatype: value_types::INT};
-pub const LEGMARKETDISRUPTIONFALLBACKPROVISION: FixTag = FixTag { id: "41463", datatype: value_types::INT};
-pub const LEGMARKETDISRUPTIONMAXIMUMDAYS: FixTag = FixTag { id: "41464", datatype: value_types::INT};
-pub const LEGMARKETDISRUPTIONMATERIALITYPERCENTAGE: FixTag = FixTag { id: "41465", datatype: value_types::PERCENTAGE};
-pub const LEGMARKETDISRUPTIONMINIMUMFUTURESCONTRACTS: FixTag = FixTag { id: "41466", datatype: value_types::INT};
-pub const NOLEGMARKETDISRUPTIONEVENTS: FixTag = FixTag { id: "41467", datatype: value_types::NUM_IN_GROUP };
-pub const LEGMARKETDISRUPTIONEVENT: FixTag = FixTag { id: "41468", datatype: value_types::STRING};
-pub const NOLEGMARKETDISRUPTIONFALLBACKS: FixTag = FixTag { id: "41469", datatype: value_types::NUM_IN_GROUP };
-pub const LEGMARKETDISRUPTIONFALLBACKTYPE: FixTag = FixTag { id: "41470", datatype: value_types::STRING};
-pub const NOLEGMARKETDISRUPTIONFALLBACKREFERENCEPRICES: FixTag = FixTag { id: "41471", datatype: value_types::NUM_IN_GROUP };
-pub const LEGMARKETDISRUPTIONFALLBACKUNDERLIERTYPE: FixTag = FixTag { id: "41472", datatype: value_types::INT};
-pub const LEGMARKETDISRUPTIONFALLBACKUNDERLIERSECURITYID: FixTag = FixTag { id: "41473", datatype: value_types::STRING};
-pub const LEGMARKETDISRUPTIONFALLBACKUNDERLIERSECURITYIDSOURCE: FixTag = FixTag { id: "41474", datatype: value_types::STRING};
-pub const LEGMARKETDISRUPTIONFALLBACKUNDERLIERSECURITYDESC: FixTag = FixTag { id: "41475", datatype: value_types::STRING};
-pub const ENCODEDLEGMARKETDISRUPTIONFALLBACKUNDERLIERSECURITYDESCLEN: FixTag = FixTag { id: "41476", datatype: value_types::LENGTH};
-pub const ENCODEDLEGMARKETDISRUPTIONFALLBACKUNDERLIERSECURITYDESC: FixTag = FixTag { id: "41477", datatype: value_types::DATA};
-pub const LEGMARKETDISRUPTIONFALLBACKOPENUNITS: FixTag = FixTag { id: "41478", datatype: value_types::QTY};
-pub const LEGMARKETDISRUPTIONFALLBACKBASKETCURRENCY: FixTag = FixTag { id: "41479", datatype: value_types::CURRENCY};
-pub const LEGMARKETDISRUPTIONFALLBACKBASKETDIVISOR: FixTag = FixTag { id: "41480", datatype: value_types::FLOAT};
-pub const LEGEXERCISEDESC: FixTag = FixTag { id: "41481", datatype: value_types::STRING};
-pub const ENCODEDLEGEXERCISEDESCLEN: FixTag = FixTag { id: "41482", datatype: value_types::LENGTH};
-pub const ENCODEDLEGEXERCISEDESC: FixTag = FixTag { id: "41483", datatype: value_types::DATA};
-pub const LEGAUTOMATICEXERCISEINDICATOR: FixTag = FixTag { id: "41484", datatype: value_types::BOOLEAN};
-pub const LEGAUTOMATICEXERCISETHRESHOLDRATE: FixTag = FixTag { id: "41485", datatype: value_types::FLOAT};
-pub const LEGEXERCISECONFIRMATIONMETHOD: FixTag = FixTag { id: "41486", datatype: value_types::INT};
-pub const LEGMANUALNOTICEBUSINESSCENTER: FixTag = FixTag { id: "41487", datatype: value_types::STRING};
-pub const LEGFALLBACKEXERCISEINDICATOR: FixTag = FixTag { id: "41488", datatype: value_types::BOOLEAN};
-pub const LEGLIMITRIGHTTOCONFIRMINDICATOR: FixTag = FixTag { id: "41489", datatype: value_types::BOOLEAN};
-pub const LEGEXERCISESPLITTICKETINDICATOR: FixTag = FixTag { id: "41490", datatype: value_types::BOOLEAN};
-pub const NOLEGOPTIONEXERCISEBUSINESSCENTERS: FixTag = FixTag { id: "41491", datatype: value_types::NUM_IN_GROUP };
-pub const LEGOPTIONEXERCISEBUSINESSCENTER: FixTag = FixTag { id: "41492", datatype: value_types::STRING};
-pub const LEGOPTIONEXERCISEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "41493", datatype: value_types::INT};
-pub const LEGOPTIONEXERCISEEARLIESTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "41494", datatype: value_types::INT};
-pub const LEGOPTIONEXERCISEEARLIESTDATEOFFSETPERIOD: FixTag = FixTag { id: "41495", datatype: value_types::INT};
-pub const LEGOPTIONEXERCISEEARLIESTDATEOFFSETUNIT: FixTag = FixTag { id: "41496", datatype: value_types::STRING};
-pub const LEGOPTIONEXERCISEFREQUENCYPERIOD: FixTag = FixTag { id: "41497", datatype: value_types::INT};
-pub const LEGOPTIONEXERCISEFREQUENCYUNIT: FixTag = FixTag { id: "41498", datatype: value_types::STRING};
-pub const LEGOPTIONEXERCISESTARTDATEUNADJUSTED: FixTag = FixTag { id: "41499", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGOPTIONEXERCISESTARTDATERELATIVETO: FixTag = FixTag { id: "41500", datatype: value_types::INT};
-pub const LEGOPTIONEXERCISESTARTDATEOFFSETPERIOD: FixTag = FixTag { id: "41501", datatype: value_types::INT};
-pub const LEGOPTIONEXERCISESTARTDATEOFFSETUNIT: FixTag = FixTag { id: "41502", datatype: value_types::STRING};
-pub const LEGOPTIONEXERCISESTARTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "41503", datatype: value_types::INT};
-pub const LEGOPTIONEXERCISESTARTDATEADJUSTED: FixTag = FixTag { id: "41504", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGOPTIONEXERCISESKIP: FixTag = FixTag { id: "41505", datatype: value_types::INT};
-pub const LEGOPTIONEXERCISENOMINATIONDEADLINE: FixTag = FixTag { id: "41506", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGOPTIONEXERCISEFIRSTDATEUNADJUSTED: FixTag = FixTag { id: "41507", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGOPTIONEXERCISELASTDATEUNADJUSTED: FixTag = FixTag { id: "41508", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGOPTIONEXERCISEEARLIESTTIME: FixTag = FixTag { id: "41509", datatype: value_types::LOCAL_MKT_TIME};
-pub const LEGOPTIONEXERCISELATESTTIME: FixTag = FixTag { id: "41510", datatype: value_types::LOCAL_MKT_TIME};
-pub const LEGOPTIONEXERCISETIMEBUSINESSCENTER: FixTag = FixTag { id: "41511", datatype: value_types::STRING};
-pub const NOLEGOPTIONEXERCISEDATES: FixTag = FixTag { id: "41512", datatype: value_types::NUM_IN_GROUP };
-pub const LEGOPTIONEXERCISEDATE: FixTag = FixTag { id: "41513", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGOPTIONEXERCISEDATETYPE: FixTag = FixTag { id: "41514", datatype: value_types::INT};
-pub const NOLEGOPTIONEXERCISEEXPIRATIONDATEBUSINESSCENTERS: FixTag = FixTag { id: "41515", datatype: value_types::NUM_IN_GROUP };
-pub const LEGOPTIONEXERCISEEXPIRATIONDATEBUSINESSCENTER: FixTag = FixTag { id: "41516", datatype: value_types::STRING};
-pub const LEGOPTIONEXERCISEEXPIRATIONDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "41517", datatype: value_types::INT};
-pub const LEGOPTIONEXERCISEEXPIRATIONDATERELATIVETO: FixTag = FixTag { id: "41518", datatype: value_types::INT};
-pub const LEGOPTIONEXERCISEEXPIRATIONDATEOFFSETPERIOD: FixTag = FixTag { id: "41519", datatype: value_types::INT};
-pub const LEGOPTIONEXERCISEEXPIRATIONDATEOFFSETUNIT: FixTag = FixTag { id: "41520", datatype: value_types::STRING};
-pub const LEGOPTIONEXERCISEEXPIRATIONFREQUENCYPERIOD: FixTag = FixTag { id: "41521", datatype: value_types::INT};
-pub const LEGOPTIONEXERCISEEXPIRATIONFREQUENCYUNIT: FixTag = FixTag { id: "41522", datatype: value_types::STRING};
-pub const LEGOPTIONEXERCISEEXPIRATIONROLLCONVENTION: FixTag = FixTag { id: "41523", datatype: value_types::STRING};
-pub const LEGOPTIONEXERCISEEXPIRATIONDATEOFFSETDAYTYPE: FixTag = FixTag { id: "41524", datatype: value_types::INT};
-pub const LEGOPTIONEXERCISEEXPIRATIONTIME: FixTag = FixTag { id: "41525", datatype: value_types::LOCAL_MKT_TIME};
-pub const LEGOPTIONEXERCISEEXPIRATIONTIMEBUSINESSCENTER: FixTag = FixTag { id: "41526", datatype: value_types::STRING};
-pub const NOLEGOPTIONEXERCISEEXPIRATIONDATES: FixTag = FixTag { id: "41527", datatype: value_types::NUM_IN_GROUP };
-pub const LEGOPTIONEXERCISEEXPIRATIONDATE: FixTag = FixTag { id: "41528", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGOPTIONEXERCISEEXPIRATIONDATETYPE: FixTag = FixTag { id: "41529", datatype: value_types::INT};
-pub const NOLEGPAYMENTSCHEDULEFIXINGDAYS: FixTag = FixTag { id: "41530", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPAYMENTSCHEDULEFIXINGDAYOFWEEK: FixTag = FixTag { id: "41531", datatype: value_types::INT};
-pub const LEGPAYMENTSCHEDULEFIXINGDAYNUMBER: FixTag = FixTag { id: "41532", datatype: value_types::INT};
-pub const LEGPAYMENTSCHEDULEXID: FixTag = FixTag { id: "41533", datatype: value_types::XID};
-pub const LEGPAYMENTSCHEDULEXIDREF: FixTag = FixTag { id: "41534", datatype: value_types::XIDREF};
-pub const LEGPAYMENTSCHEDULERATECURRENCY: FixTag = FixTag { id: "41535", datatype: value_types::CURRENCY};
-pub const LEGPAYMENTSCHEDULERATEUNITOFMEASURE: FixTag = FixTag { id: "41536", datatype: value_types::STRING};
-pub const LEGPAYMENTSCHEDULERATECONVERSIONFACTOR: FixTag = FixTag { id: "41537", datatype: value_types::FLOAT};
-pub const LEGPAYMENTSCHEDULERATESPREADTYPE: FixTag = FixTag { id: "41538", datatype: value_types::INT};
-pub const LEGPAYMENTSCHEDULESETTLPERIODPRICE: FixTag = FixTag { id: "41539", datatype: value_types::PRICE};
-pub const LEGPAYMENTSCHEDULESETTLPERIODPRICECURRENCY: FixTag = FixTag { id: "41540", datatype: value_types::CURRENCY};
-pub const LEGPAYMENTSCHEDULESETTLPERIODPRICEUNITOFMEASURE: FixTag = FixTag { id: "41541", datatype: value_types::STRING};
-pub const LEGPAYMENTSCHEDULESTEPUNITOFMEASURE: FixTag = FixTag { id: "41542", datatype: value_types::STRING};
-pub const LEGPAYMENTSCHEDULEFIXINGDAYDISTRIBUTION: FixTag = FixTag { id: "41543", datatype: value_types::INT};
-pub const LEGPAYMENTSCHEDULEFIXINGDAYCOUNT: FixTag = FixTag { id: "41544", datatype: value_types::INT};
-pub const LEGPAYMENTSCHEDULEFIXINGLAGPERIOD: FixTag = FixTag { id: "41545", datatype: value_types::INT};
-pub const LEGPAYMENTSCHEDULEFIXINGLAGUNIT: FixTag = FixTag { id: "41546", datatype: value_types::STRING};
-pub const LEGPAYMENTSCHEDULEFIXINGFIRSTOBSERVATIONDATEOFFSETPERIOD: FixTag = FixTag { id: "41547", datatype: value_types::INT};
-pub const LEGPAYMENTSCHEDULEFIXINGFIRSTOBSERVATIONDATEOFFSETUNIT: FixTag = FixTag { id: "41548", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMFLATRATEINDICATOR: FixTag = FixTag { id: "41549", datatype: value_types::BOOLEAN};
-pub const LEGPAYMENTSTREAMFLATRATEAMOUNT: FixTag = FixTag { id: "41550", datatype: value_types::AMT};
-pub const LEGPAYMENTSTREAMFLATRATECURRENCY: FixTag = FixTag { id: "41551", datatype: value_types::CURRENCY};
-pub const LEGSTREAMMAXIMUMPAYMENTAMOUNT: FixTag = FixTag { id: "41552", datatype: value_types::AMT};
-pub const LEGSTREAMMAXIMUMPAYMENTCURRENCY: FixTag = FixTag { id: "41553", datatype: value_types::CURRENCY};
-pub const LEGSTREAMMAXIMUMTRANSACTIONAMOUNT: FixTag = FixTag { id: "41554", datatype: value_types::AMT};
-pub const LEGSTREAMMAXIMUMTRANSACTIONCURRENCY: FixTag = FixTag { id: "41555", datatype: value_types::CURRENCY};
-pub const LEGPAYMENTSTREAMFIXEDAMOUNTUNITOFMEASURE: FixTag = FixTag { id: "41556", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMTOTALFIXEDAMOUNT: FixTag = FixTag { id: "41557", datatype: value_types::AMT};
-pub const LEGPAYMENTSTREAMWORLDSCALERATE: FixTag = FixTag { id: "41558", datatype: value_types::FLOAT};
-pub const LEGPAYMENTSTREAMCONTRACTPRICE: FixTag = FixTag { id: "41559", datatype: value_types::PRICE};
-pub const LEGPAYMENTSTREAMCONTRACTPRICECURRENCY: FixTag = FixTag { id: "41560", datatype: value_types::CURRENCY};
-pub const NOLEGPAYMENTSTREAMPRICINGBUSINESSCENTERS: FixTag = FixTag { id: "41561", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPAYMENTSTREAMPRICINGBUSINESSCENTER: FixTag = FixTag { id: "41562", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMRATEINDEX2CURVEUNIT: FixTag = FixTag { id: "41563", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMRATEINDEX2CURVEPERIOD: FixTag = FixTag { id: "41564", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMRATEINDEXLOCATION: FixTag = FixTag { id: "41565", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMRATEINDEXLEVEL: FixTag = FixTag { id: "41566", datatype: value_types::QTY};
-pub const LEGPAYMENTSTREAMRATEINDEXUNITOFMEASURE: FixTag = FixTag { id: "41567", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMSETTLLEVEL: FixTag = FixTag { id: "41568", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMREFERENCELEVEL: FixTag = FixTag { id: "41569", datatype: value_types::QTY};
-pub const LEGPAYMENTSTREAMREFERENCELEVELUNITOFMEASURE: FixTag = FixTag { id: "41570", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMREFERENCELEVELEQUALSZEROINDICATOR: FixTag = FixTag { id: "41571", datatype: value_types::BOOLEAN};
-pub const LEGPAYMENTSTREAMRATESPREADCURRENCY: FixTag = FixTag { id: "41572", datatype: value_types::CURRENCY};
-pub const LEGPAYMENTSTREAMRATESPREADUNITOFMEASURE: FixTag = FixTag { id: "41573", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMRATECONVERSIONFACTOR: FixTag = FixTag { id: "41574", datatype: value_types::FLOAT};
-pub const LEGPAYMENTSTREAMRATESPREADTYPE: FixTag = FixTag { id: "41575", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMLASTRESETRATE: FixTag = FixTag { id: "41576", datatype: value_types::PERCENTAGE};
-pub const LEGPAYMENTSTREAMFINALRATE: FixTag = FixTag { id: "41577", datatype: value_types::PERCENTAGE};
-pub const LEGPAYMENTSTREAMCALCULATIONLAGPERIOD: FixTag = FixTag { id: "41578", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCALCULATIONLAGUNIT: FixTag = FixTag { id: "41579", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMFIRSTOBSERVATIONDATEOFFSETPERIOD: FixTag = FixTag { id: "41580", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMFIRSTOBSERVATIONDATEOFFSETUNIT: FixTag = FixTag { id: "41581", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMPRICINGDAYTYPE: FixTag = FixTag { id: "41582", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMPRICINGDAYDISTRIBUTION: FixTag = FixTag { id: "41583", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMPRICINGDAYCOUNT: FixTag = FixTag { id: "41584", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMPRICINGBUSINESSCALENDAR: FixTag = FixTag { id: "41585", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMPRICINGBUSINESSDAYCONVENTION: FixTag = FixTag { id: "41586", datatype: value_types::INT};
-pub const UNDERLYINGDELIVERYSTREAMRISKAPPORTIONMENTSOURCE: FixTag = FixTag { id: "41587", datatype: value_types::STRING};
-pub const STREAMCOMMODITYSETTLTIMETYPE: FixTag = FixTag { id: "41588", datatype: value_types::INT};
-pub const NOLEGPAYMENTSTREAMPAYMENTDATES: FixTag = FixTag { id: "41589", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPAYMENTSTREAMPAYMENTDATE: FixTag = FixTag { id: "41590", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPAYMENTSTREAMPAYMENTDATETYPE: FixTag = FixTag { id: "41591", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMMASTERAGREEMENTPAYMENTDATESINDICATOR: FixTag = FixTag { id: "41592", datatype: value_types::BOOLEAN};
-pub const NOLEGPAYMENTSTREAMPRICINGDATES: FixTag = FixTag { id: "41593", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPAYMENTSTREAMPRICINGDATE: FixTag = FixTag { id: "41594", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPAYMENTSTREAMPRICINGDATETYPE: FixTag = FixTag { id: "41595", datatype: value_types::INT};
-pub const NOLEGPAYMENTSTREAMPRICINGDAYS: FixTag = FixTag { id: "41596", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPAYMENTSTREAMPRICINGDAYOFWEEK: FixTag = FixTag { id: "41597", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMPRICINGDAYNUMBER: FixTag = FixTag { id: "41598", datatype: value_types::INT};
-pub const NOLEGPHYSICALSETTLTERMS: FixTag = FixTag { id: "41599", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPHYSICALSETTLTERMXID: FixTag = FixTag { id: "41600", datatype: value_types::XID};
-pub const LEGPHYSICALSETTLCURENCY: FixTag = FixTag { id: "41601", datatype: value_types::CURRENCY};
-pub const LEGPHYSICALSETTLBUSINESSDAYS: FixTag = FixTag { id: "41602", datatype: value_types::INT};
-pub const LEGPHYSICALSETTLMAXIMUMBUSINESSDAYS: FixTag = FixTag { id: "41603", datatype: value_types::INT};
-pub const NOLEGPHYSICALSETTLDELIVERABLEOBLIGATIONS: FixTag = FixTag { id: "41604", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPHYSICALSETTLDELIVERABLEOBLIGATIONTYPE: FixTag = FixTag { id: "41605", datatype: value_types::STRING};
-pub const LEGPHYSICALSETTLDELIVERABLEOBLIGATIONVALUE: FixTag = FixTag { id: "41606", datatype: value_types::STRING};
-pub const NOLEGPRICINGDATEBUSINESSCENTERS: FixTag = FixTag { id: "41607", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPRICINGDATEBUSINESSCENTER: FixTag = FixTag { id: "41608", datatype: value_types::STRING};
-pub const LEGPRICINGDATEUNADJUSTED: FixTag = FixTag { id: "41609", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPRICINGDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "41610", datatype: value_types::INT};
-pub const LEGPRICINGDATEADJUSTED: FixTag = FixTag { id: "41611", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPRICINGTIME: FixTag = FixTag { id: "41612", datatype: value_types::LOCAL_MKT_TIME};
-pub const LEGPRICINGTIMEBUSINESSCENTER: FixTag = FixTag { id: "41613", datatype: value_types::STRING};
-pub const NOLEGPROTECTIONTERMEVENTNEWSSOURCES: FixTag = FixTag { id: "41614", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPROTECTIONTERMEVENTNEWSSOURCE: FixTag = FixTag { id: "41615", datatype: value_types::STRING};
-pub const NOLEGPROTECTIONTERMS: FixTag = FixTag { id: "41616", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPROTECTIONTERMXID: FixTag = FixTag { id: "41617", datatype: value_types::XID};
-pub const LEGPROTECTIONTERMNOTIONAL: FixTag = FixTag { id: "41618", datatype: value_types::AMT};
-pub const LEGPROTECTIONTERMCURRENCY: FixTag = FixTag { id: "41619", datatype: value_types::CURRENCY};
-pub const LEGPROTECTIONTERMSELLERNOTIFIES: FixTag = FixTag { id: "41620", datatype: value_types::BOOLEAN};
-pub const LEGPROTECTIONTERMBUYERNOTIFIES: FixTag = FixTag { id: "41621", datatype: value_types::BOOLEAN};
-pub const LEGPROTECTIONTERMEVENTBUSINESSCENTER: FixTag = FixTag { id: "41622", datatype: value_types::STRING};
-pub const LEGPROTECTIONTERMSTANDARDSOURCES: FixTag = FixTag { id: "41623", datatype: value_types::BOOLEAN};
-pub const LEGPROTECTIONTERMEVENTMINIMUMSOURCES: FixTag = FixTag { id: "41624", datatype: value_types::INT};
-pub const NOLEGPROTECTIONTERMEVENTS: FixTag = FixTag { id: "41625", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPROTECTIONTERMEVENTTYPE: FixTag = FixTag { id: "41626", datatype: value_types::STRING};
-pub const LEGPROTECTIONTERMEVENTVALUE: FixTag = FixTag { id: "41627", datatype: value_types::STRING};
-pub const LEGPROTECTIONTERMEVENTCURRENCY: FixTag = FixTag { id: "41628", datatype: value_types::CURRENCY};
-pub const LEGPROTECTIONTERMEVENTPERIOD: FixTag = FixTag { id: "41629", datatype: value_types::INT};
-pub const LEGPROTECTIONTERMEVENTUNIT: FixTag = FixTag { id: "41630", datatype: value_types::STRING};
-pub const LEGPROTECTIONTERMEVENTDAYTYPE: FixTag = FixTag { id: "41631", datatype: value_types::INT};
-pub const LEGPROTECTIONTERMEVENTRATESOURCE: FixTag = FixTag { id: "41632", datatype: value_types::STRING};
-pub const NOLEGPROTECTIONTERMEVENTQUALIFIERS: FixTag = FixTag { id: "41633", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPROTECTIONTERMEVENTQUALIFIER: FixTag = FixTag { id: "41634", datatype: value_types::CHAR};
-pub const NOLEGPROTECTIONTERMOBLIGATIONS: FixTag = FixTag { id: "41635", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPROTECTIONTERMOBLIGATIONTYPE: FixTag = FixTag { id: "41636", datatype: value_types::STRING};
-pub const LEGPROTECTIONTERMOBLIGATIONVALUE: FixTag = FixTag { id: "41637", datatype: value_types::STRING};
-pub const NOLEGSTREAMCALCULATIONPERIODDATES: FixTag = FixTag { id: "41638", datatype: value_types::NUM_IN_GROUP };
-pub const LEGSTREAMCALCULATIONPERIODDATE: FixTag = FixTag { id: "41639", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGSTREAMCALCULATIONPERIODDATETYPE: FixTag = FixTag { id: "41640", datatype: value_types::INT};
-pub const LEGSTREAMCALCULATIONPERIODDATESXID: FixTag = FixTag { id: "41641", datatype: value_types::XID};
-pub const LEGSTREAMCALCULATIONPERIODDATESXIDREF: FixTag = FixTag { id: "41642", datatype: value_types::XIDREF};
-pub const LEGSTREAMCALCULATIONBALANCEOFFIRSTPERIOD: FixTag = FixTag { id: "41643", datatype: value_types::BOOLEAN};
-pub const LEGSTREAMCALCULATIONCORRECTIONPERIOD: FixTag = FixTag { id: "41644", datatype: value_types::INT};
-pub const LEGSTREAMCALCULATIONCORRECTIONUNIT: FixTag = FixTag { id: "41645", datatype: value_types::STRING};
-pub const NOLEGSTREAMCOMMODITYSETTLBUSINESSCENTERS: FixTag = FixTag { id: "41646", datatype: value_types::NUM_IN_GROUP };
-pub const LEGSTREAMCOMMODITYSETTLBUSINESSCENTER: FixTag = FixTag { id: "41647", datatype: value_types::STRING};
-pub const LEGSTREAMCOMMODITYBASE: FixTag = FixTag { id: "41648", datatype: value_types::STRING};
-pub const LEGSTREAMCOMMODITYTYPE: FixTag = FixTag { id: "41649", datatype: value_types::STRING};
-pub const LEGSTREAMCOMMODITYSECURITYID: FixTag = FixTag { id: "41650", datatype: value_types::STRING};
-pub const LEGSTREAMCOMMODITYSECURITYIDSOURCE: FixTag = FixTag { id: "41651", datatype: value_types::STRING};
-pub const LEGSTREAMCOMMODITYDESC: FixTag = FixTag { id: "41652", datatype: value_types::STRING};
-pub const ENCODEDLEGSTREAMCOMMODITYDESCLEN: FixTag = FixTag { id: "41653", datatype: value_types::LENGTH};
-pub const ENCODEDLEGSTREAMCOMMODITYDESC: FixTag = FixTag { id: "41654", datatype: value_types::DATA};
-pub const LEGSTREAMCOMMODITYUNITOFMEASURE: FixTag = FixTag { id: "41655", datatype: value_types::STRING};
-pub const LEGSTREAMCOMMODITYCURRENCY: FixTag = FixTag { id: "41656", datatype: value_types::CURRENCY};
-pub const LEGSTREAMCOMMODITYEXCHANGE: FixTag = FixTag { id: "41657", datatype: value_types::EXCHANGE};
-pub const LEGSTREAMCOMMODITYRATESOURCE: FixTag = FixTag { id: "41658", datatype: value_types::INT};
-pub const LEGSTREAMCOMMODITYRATEREFERENCEPAGE: FixTag = FixTag { id: "41659", datatype: value_types::STRING};
-pub const LEGSTREAMCOMMODITYRATEREFERENCEPAGEHEADING: FixTag = FixTag { id: "41660", datatype: value_types::STRING};
-pub const LEGSTREAMDATAPROVIDER: FixTag = FixTag { id: "41661", datatype: value_types::STRING};
-pub const LEGSTREAMCOMMODITYPRICINGTYPE: FixTag = FixTag { id: "41662", datatype: value_types::STRING};
-pub const LEGSTREAMCOMMODITYNEARBYSETTLDAYPERIOD: FixTag = FixTag { id: "41663", datatype: value_types::INT};
-pub const LEGSTREAMCOMMODITYNEARBYSETTLDAYUNIT: FixTag = FixTag { id: "41664", datatype: value_types::STRING};
-pub const LEGSTREAMCOMMODITYSETTLDATEUNADJUSTED: FixTag = FixTag { id: "41665", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGSTREAMCOMMODITYSETTLDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "41666", datatype: value_types::INT};
-pub const LEGSTREAMCOMMODITYSETTLDATEADJUSTED: FixTag = FixTag { id: "41667", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGSTREAMCOMMODITYSETTLMONTH: FixTag = FixTag { id: "41668", datatype: value_types::INT};
-pub const LEGSTREAMCOMMODITYSETTLDATEROLLPERIOD: FixTag = FixTag { id: "41669", datatype: value_types::INT};
-pub const LEGSTREAMCOMMODITYSETTLDATEROLLUNIT: FixTag = FixTag { id: "41670", datatype: value_types::STRING};
-pub const LEGSTREAMCOMMODITYSETTLDAYTYPE: FixTag = FixTag { id: "41671", datatype: value_types::INT};
-pub const LEGSTREAMCOMMODITYXID: FixTag = FixTag { id: "41672", datatype: value_types::XID};
-pub const LEGSTREAMCOMMODITYXIDREF: FixTag = FixTag { id: "41673", datatype: value_types::XIDREF};
-pub const NOLEGSTREAMCOMMODITYALTIDS: FixTag = FixTag { id: "41674", datatype: value_types::NUM_IN_GROUP };
-pub const LEGSTREAMCOMMODITYALTID: FixTag = FixTag { id: "41675", datatype: value_types::STRING};
-pub const LEGSTREAMCOMMODITYALTIDSOURCE: FixTag = FixTag { id: "41676", datatype: value_types::STRING};
-pub const NOLEGSTREAMCOMMODITYDATASOURCES: FixTag = FixTag { id: "41677", datatype: value_types::NUM_IN_GROUP };
-pub const LEGSTREAMCOMMODITYDATASOURCEID: FixTag = FixTag { id: "41678", datatype: value_types::STRING};
-pub const LEGSTREAMCOMMODITYDATASOURCEIDTYPE: FixTag = FixTag { id: "41679", datatype: value_types::INT};
-pub const NOLEGSTREAMCOMMODITYSETTLDAYS: FixTag = FixTag { id: "41680", datatype: value_types::NUM_IN_GROUP };
-pub const LEGSTREAMCOMMODITYSETTLDAY: FixTag = FixTag { id: "41681", datatype: value_types::INT};
-pub const LEGSTREAMCOMMODITYSETTLTOTALHOURS: FixTag = FixTag { id: "41682", datatype: value_types::INT};
-pub const NOLEGSTREAMCOMMODITYSETTLTIMES: FixTag = FixTag { id: "41683", datatype: value_types::NUM_IN_GROUP };
-pub const LEGSTREAMCOMMODITYSETTLSTART: FixTag = FixTag { id: "41684", datatype: value_types::STRING};
-pub const LEGSTREAMCOMMODITYSETTLEND: FixTag = FixTag { id: "41685", datatype: value_types::STRING};
-pub const NOLEGSTREAMCOMMODITYSETTLPERIODS: FixTag = FixTag { id: "41686", datatype: value_types::NUM_IN_GROUP };
-pub const LEGSTREAMCOMMODITYSETTLCOUNTRY: FixTag = FixTag { id: "41687", datatype: value_types::ISO_COUNTRY};
-pub const LEGSTREAMCOMMODITYSETTLTIMEZONE: FixTag = FixTag { id: "41688", datatype: value_types::STRING};
-pub const LEGSTREAMCOMMODITYSETTLFLOWTYPE: FixTag = FixTag { id: "41689", datatype: value_types::INT};
-pub const LEGSTREAMCOMMODITYSETTLPERIODNOTIONAL: FixTag = FixTag { id: "41690", datatype: value_types::QTY};
-pub const LEGSTREAMCOMMODITYSETTLPERIODNOTIONALUNITOFMEASURE: FixTag = FixTag { id: "41691", datatype: value_types::STRING};
-pub const LEGSTREAMCOMMODITYSETTLPERIODFREQUENCYPERIOD: FixTag = FixTag { id: "41692", datatype: value_types::INT};
-pub const LEGSTREAMCOMMODITYSETTLPERIODFREQUENCYUNIT: FixTag = FixTag { id: "41693", datatype: value_types::STRING};
-pub const LEGSTREAMCOMMODITYSETTLPERIODPRICE: FixTag = FixTag { id: "41694", datatype: value_types::PRICE};
-pub const LEGSTREAMCOMMODITYSETTLPERIODPRICEUNITOFMEASURE: FixTag = FixTag { id: "41695", datatype: value_types::STRING};
-pub const LEGSTREAMCOMMODITYSETTLPERIODPRICECURRENCY: FixTag = FixTag { id: "41696", datatype: value_types::CURRENCY};
-pub const LEGSTREAMCOMMODITYSETTLHOLIDAYSPROCESSINGINSTRUCTION: FixTag = FixTag { id: "41697", datatype: value_types::INT};
-pub const LEGSTREAMCOMMODITYSETTLPERIODXID: FixTag = FixTag { id: "41698", datatype: value_types::XID};
-pub const LEGSTREAMCOMMODITYSETTLPERIODXIDREF: FixTag = FixTag { id: "41699", datatype: value_types::XIDREF};
-pub const LEGSTREAMXID: FixTag = FixTag { id: "41700", datatype: value_types::XID};
-pub const UNDERLYINGADDITIONALTERMBONDSECURITYIDSOURCE: FixTag = FixTag { id: "41701", datatype: value_types::STRING};
-pub const LEGSTREAMNOTIONALXIDREF: FixTag = FixTag { id: "41702", datatype: value_types::XIDREF};
-pub const LEGSTREAMNOTIONALFREQUENCYPERIOD: FixTag = FixTag { id: "41703", datatype: value_types::INT};
-pub const LEGSTREAMNOTIONALFREQUENCYUNIT: FixTag = FixTag { id: "41704", datatype: value_types::STRING};
-pub const LEGSTREAMNOTIONALCOMMODITYFREQUENCY: FixTag = FixTag { id: "41705", datatype: value_types::INT};
-pub const LEGSTREAMNOTIONALUNITOFMEASURE: FixTag = FixTag { id: "41706", datatype: value_types::STRING};
-pub const LEGSTREAMTOTALNOTIONAL: FixTag = FixTag { id: "41707", datatype: value_types::QTY};
-pub const LEGSTREAMTOTALNOTIONALUNITOFMEASURE: FixTag = FixTag { id: "41708", datatype: value_types::STRING};
-pub const UNDERLYINGADDITIONALTERMBONDDESC: FixTag = FixTag { id: "41709", datatype: value_types::STRING};
-pub const ENCODEDUNDERLYINGADDITIONALTERMBONDDESCLEN: FixTag = FixTag { id: "41710", datatype: value_types::LENGTH};
-pub const ENCODEDUNDERLYINGADDITIONALTERMBONDDESC: FixTag = FixTag { id: "41711", datatype: value_types::DATA};
-pub const UNDERLYINGADDITIONALTERMBONDCURRENCY: FixTag = FixTag { id: "41712", datatype: value_types::CURRENCY};
-pub const NOUNDERLYINGCOMPLEXEVENTAVERAGINGOBSERVATIONS: FixTag = FixTag { id: "41713", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGCOMPLEXEVENTAVERAGINGOBSERVATIONNUMBER: FixTag = FixTag { id: "41714", datatype: value_types::INT};
-pub const UNDERLYINGCOMPLEXEVENTAVERAGINGWEIGHT: FixTag = FixTag { id: "41715", datatype: value_types::FLOAT};
-pub const NOUNDERLYINGCOMPLEXEVENTCREDITEVENTS: FixTag = FixTag { id: "41716", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGCOMPLEXEVENTCREDITEVENTTYPE: FixTag = FixTag { id: "41717", datatype: value_types::STRING};
-pub const UNDERLYINGCOMPLEXEVENTCREDITEVENTVALUE: FixTag = FixTag { id: "41718", datatype: value_types::STRING};
-pub const UNDERLYINGCOMPLEXEVENTCREDITEVENTCURRENCY: FixTag = FixTag { id: "41719", datatype: value_types::CURRENCY};
-pub const UNDERLYINGCOMPLEXEVENTCREDITEVENTPERIOD: FixTag = FixTag { id: "41720", datatype: value_types::INT};
-pub const UNDERLYINGCOMPLEXEVENTCREDITEVENTUNIT: FixTag = FixTag { id: "41721", datatype: value_types::STRING};
-pub const UNDERLYINGCOMPLEXEVENTCREDITEVENTDAYTYPE: FixTag = FixTag { id: "41722", datatype: value_types::INT};
-pub const UNDERLYINGCOMPLEXEVENTCREDITEVENTRATESOURCE: FixTag = FixTag { id: "41723", datatype: value_types::INT};
-pub const NOUNDERLYINGCOMPLEXEVENTCREDITEVENTQUALIFIERS: FixTag = FixTag { id: "41724", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGCOMPLEXEVENTCREDITEVENTQUALIFIER: FixTag = FixTag { id: "41725", datatype: value_types::CHAR};
-pub const NOUNDERLYINGCOMPLEXEVENTPERIODDATETIMES: FixTag = FixTag { id: "41726", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGCOMPLEXEVENTPERIODDATE: FixTag = FixTag { id: "41727", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGCOMPLEXEVENTPERIODTIME: FixTag = FixTag { id: "41728", datatype: value_types::LOCAL_MKT_TIME};
-pub const NOUNDERLYINGCOMPLEXEVENTPERIODS: FixTag = FixTag { id: "41729", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGCOMPLEXEVENTPERIODTYPE: FixTag = FixTag { id: "41730", datatype: value_types::INT};
-pub const UNDERLYINGCOMPLEXEVENTBUSINESSCENTER: FixTag = FixTag { id: "41731", datatype: value_types::STRING};
-pub const NOUNDERLYINGCOMPLEXEVENTRATESOURCES: FixTag = FixTag { id: "41732", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGCOMPLEXEVENTRATESOURCE: FixTag = FixTag { id: "41733", datatype: value_types::INT};
-pub const UNDERLYINGCOMPLEXEVENTRATESOURCETYPE: FixTag = FixTag { id: "41734", datatype: value_types::INT};
-pub const UNDERLYINGCOMPLEXEVENTREFERENCEPAGE: FixTag = FixTag { id: "41735", datatype: value_types::STRING};
-pub const UNDERLYINGCOMPLEXEVENTREFERENCEPAGEHEADING: FixTag = FixTag { id: "41736", datatype: value_types::STRING};
-pub const NOUNDERLYINGCOMPLEXEVENTDATEBUSINESSCENTERS: FixTag = FixTag { id: "41737", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGCOMPLEXEVENTDATEBUSINESSCENTER: FixTag = FixTag { id: "41738", datatype: value_types::STRING};
-pub const UNDERLYINGCOMPLEXEVENTDATEUNADJUSTED: FixTag = FixTag { id: "41739", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGCOMPLEXEVENTDATERELATIVETO: FixTag = FixTag { id: "41740", datatype: value_types::INT};
-pub const UNDERLYINGCOMPLEXEVENTDATEOFFSETPERIOD: FixTag = FixTag { id: "41741", datatype: value_types::INT};
-pub const UNDERLYINGCOMPLEXEVENTDATEOFFSETUNIT: FixTag = FixTag { id: "41742", datatype: value_types::STRING};
-pub const UNDERLYINGCOMPLEXEVENTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "41743", datatype: value_types::INT};
-pub const UNDERLYINGCOMPLEXEVENTDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "41744", datatype: value_types::INT};
-pub const UNDERLYINGCOMPLEXEVENTDATEADJUSTED: FixTag = FixTag { id: "41745", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGCOMPLEXEVENTFIXINGTIME: FixTag = FixTag { id: "41746", datatype: value_types::LOCAL_MKT_TIME};
-pub const UNDERLYINGCOMPLEXEVENTFIXINGTIMEBUSINESSCENTER: FixTag = FixTag { id: "41747", datatype: value_types::STRING};
-pub const NOUNDERLYINGCOMPLEXEVENTCREDITEVENTSOURCES: FixTag = FixTag { id: "41748", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGCOMPLEXEVENTCREDITEVENTSOURCE: FixTag = FixTag { id: "41749", datatype: value_types::STRING};
-pub const NOUNDERLYINGCOMPLEXEVENTSCHEDULES: FixTag = FixTag { id: "41750", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGCOMPLEXEVENTSCHEDULESTARTDATE: FixTag = FixTag { id: "41751", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGCOMPLEXEVENTSCHEDULEENDDATE: FixTag = FixTag { id: "41752", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGCOMPLEXEVENTSCHEDULEFREQUENCYPERIOD: FixTag = FixTag { id: "41753", datatype: value_types::INT};
-pub const UNDERLYINGCOMPLEXEVENTSCHEDULEFREQUENCYUNIT: FixTag = FixTag { id: "41754", datatype: value_types::STRING};
-pub const UNDERLYINGCOMPLEXEVENTSCHEDULEROLLCONVENTION: FixTag = FixTag { id: "41755", datatype: value_types::STRING};
-pub const NOUNDERLYINGDELIVERYSCHEDULES: FixTag = FixTag { id: "41756", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGDELIVERYSCHEDULETYPE: FixTag = FixTag { id: "41757", datatype: value_types::INT};
-pub const UNDERLYINGDELIVERYSCHEDULEXID: FixTag = FixTag { id: "41758", datatype: value_types::XID};
-pub const UNDERLYINGDELIVERYSCHEDULENOTIONAL: FixTag = FixTag { id: "41759", datatype: value_types::QTY};
-pub const UNDERLYINGDELIVERYSCHEDULENOTIONALUNITOFMEASURE: FixTag = FixTag { id: "41760", datatype: value_types::STRING};
-pub const UNDERLYINGDELIVERYSCHEDULENOTIONALCOMMODITYFREQUENCY: FixTag = FixTag { id: "41761", datatype: value_types::INT};
-pub const UNDERLYINGDELIVERYSCHEDULENEGATIVETOLERANCE: FixTag = FixTag { id: "41762", datatype: value_types::FLOAT};
-pub const UNDERLYINGDELIVERYSCHEDULEPOSITIVETOLERANCE: FixTag = FixTag { id: "41763", datatype: value_types::FLOAT};
-pub const UNDERLYINGDELIVERYSCHEDULETOLERANCEUNITOFMEASURE: FixTag = FixTag { id: "41764", datatype: value_types::STRING};
-pub const UNDERLYINGDELIVERYSCHEDULETOLERANCETYPE: FixTag = FixTag { id: "41765", datatype: value_types::INT};
-pub const UNDERLYINGDELIVERYSCHEDULESETTLCOUNTRY: FixTag = FixTag { id: "41766", datatype: value_types::ISO_COUNTRY};
-pub const UNDERLYINGDELIVERYSCHEDULESETTLTIMEZONE: FixTag = FixTag { id: "41767", datatype: value_types::STRING};
-pub const UNDERLYINGDELIVERYSCHEDULESETTLFLOWTYPE: FixTag = FixTag { id: "41768", datatype: value_types::INT};
-pub const UNDERLYINGDELIVERYSCHEDULESETTLHOLIDAYSPROCESSINGINSTRUCTION: FixTag = FixTag { id: "41769", datatype: value_types::INT};
-pub const NOUNDERLYINGDELIVERYSCHEDULESETTLDAYS: FixTag = FixTag { id: "41770", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGDELIVERYSCHEDULESETTLDAY: FixTag = FixTag { id: "41771", datatype: value_types::INT};
-pub const UNDERLYINGDELIVERYSCHEDULESETTLTOTALHOURS: FixTag = FixTag { id: "41772", datatype: value_types::INT};
-pub const NOUNDERLYINGDELIVERYSCHEDULESETTLTIMES: FixTag = FixTag { id: "41773", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGDELIVERYSCHEDULESETTLSTART: FixTag = FixTag { id: "41774", datatype: value_types::STRING};
-pub const UNDERLYINGDELIVERYSCHEDULESETTLEND: FixTag = FixTag { id: "41775", datatype: value_types::STRING};
-pub const UNDERLYINGDELIVERYSCHEDULESETTLTIMETYPE: FixTag = FixTag { id: "41776", datatype: value_types::INT};
-pub const UNDERLYINGDELIVERYSTREAMTYPE: FixTag = FixTag { id: "41777", datatype: value_types::INT};
-pub const UNDERLYINGDELIVERYSTREAMPIPELINE: FixTag = FixTag { id: "41778", datatype: value_types::STRING};
-pub const UNDERLYINGDELIVERYSTREAMENTRYPOINT: FixTag = FixTag { id: "41779", datatype: value_types::STRING};
-pub const UNDERLYINGDELIVERYSTREAMWITHDRAWALPOINT: FixTag = FixTag { id: "41780", datatype: value_types::STRING};
-pub const UNDERLYINGDELIVERYSTREAMDELIVERYPOINT: FixTag = FixTag { id: "41781", datatype: value_types::STRING};
-pub const UNDERLYINGDELIVERYSTREAMDELIVERYRESTRICTION: FixTag = FixTag { id: "41782", datatype: value_types::INT};
-pub const UNDERLYINGDELIVERYSTREAMDELIVERYCONTINGENCY: FixTag = FixTag { id: "41783", datatype: value_types::STRING};
-pub const UNDERLYINGDELIVERYSTREAMDELIVERYCONTINGENTPARTYSIDE: FixTag = FixTag { id: "41784", datatype: value_types::INT};
-pub const UNDERLYINGDELIVERYSTREAMDELIVERATSOURCEINDICATOR: FixTag = FixTag { id: "41785", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGDELIVERYSTREAMRISKAPPORTIONMENT: FixTag = FixTag { id: "41786", datatype: value_types::STRING};
-pub const UNDERLYINGDELIVERYSTREAMTITLETRANSFERLOCATION: FixTag = FixTag { id: "41787", datatype: value_types::STRING};
-pub const UNDERLYINGDELIVERYSTREAMTITLETRANSFERCONDITION: FixTag = FixTag { id: "41788", datatype: value_types::INT};
-pub const UNDERLYINGDELIVERYSTREAMIMPORTEROFRECORD: FixTag = FixTag { id: "41789", datatype: value_types::STRING};
-pub const UNDERLYINGDELIVERYSTREAMNEGATIVETOLERANCE: FixTag = FixTag { id: "41790", datatype: value_types::FLOAT};
-pub const UNDERLYINGDELIVERYSTREAMPOSITIVETOLERANCE: FixTag = FixTag { id: "41791", datatype: value_types::FLOAT};
-pub const UNDERLYINGDELIVERYSTREAMTOLERANCEUNITOFMEASURE: FixTag = FixTag { id: "41792", datatype: value_types::STRING};
-pub const UNDERLYINGDELIVERYSTREAMTOLERANCETYPE: FixTag = FixTag { id: "41793", datatype: value_types::INT};
-pub const UNDERLYINGDELIVERYSTREAMTOLERANCEOPTIONSIDE: FixTag = FixTag { id: "41794", datatype: value_types::INT};
-pub const UNDERLYINGDELIVERYSTREAMTOTALPOSITIVETOLERANCE: FixTag = FixTag { id: "41795", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGDELIVERYSTREAMTOTALNEGATIVETOLERANCE: FixTag = FixTag { id: "41796", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGDELIVERYSTREAMNOTIONALCONVERSIONFACTOR: FixTag = FixTag { id: "41797", datatype: value_types::FLOAT};
-pub const UNDERLYINGDELIVERYSTREAMTRANSPORTEQUIPMENT: FixTag = FixTag { id: "41798", datatype: value_types::STRING};
-pub const UNDERLYINGDELIVERYSTREAMELECTINGPARTYSIDE: FixTag = FixTag { id: "41799", datatype: value_types::INT};
-pub const NOUNDERLYINGSTREAMASSETATTRIBUTES: FixTag = FixTag { id: "41800", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGSTREAMASSETATTRIBUTETYPE: FixTag = FixTag { id: "41801", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMASSETATTRIBUTEVALUE: FixTag = FixTag { id: "41802", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMASSETATTRIBUTELIMIT: FixTag = FixTag { id: "41803", datatype: value_types::STRING};
-pub const NOUNDERLYINGDELIVERYSTREAMCYCLES: FixTag = FixTag { id: "41804", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGDELIVERYSTREAMCYCLEDESC: FixTag = FixTag { id: "41805", datatype: value_types::STRING};
-pub const ENCODEDUNDERLYINGDELIVERYSTREAMCYCLEDESCLEN: FixTag = FixTag { id: "41806", datatype: value_types::LENGTH};
-pub const ENCODEDUNDERLYINGDELIVERYSTREAMCYCLEDESC: FixTag = FixTag { id: "41807", datatype: value_types::DATA};
-pub const NOUNDERLYINGDELIVERYSTREAMCOMMODITYSOURCES: FixTag = FixTag { id: "41808", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGDELIVERYSTREAMCOMMODITYSOURCE: FixTag = FixTag { id: "41809", datatype: value_types::STRING};
-pub const UNDERLYINGEXERCISEDESC: FixTag = FixTag { id: "41810", datatype: value_types::STRING};
-pub const ENCODEDUNDERLYINGEXERCISEDESCLEN: FixTag = FixTag { id: "41811", datatype: value_types::LENGTH};
-pub const ENCODEDUNDERLYINGEXERCISEDESC: FixTag = FixTag { id: "41812", datatype: value_types::DATA};
-pub const UNDERLYINGAUTOMATICEXERCISEINDICATOR: FixTag = FixTag { id: "41813", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGAUTOMATICEXERCISETHRESHOLDRATE: FixTag = FixTag { id: "41814", datatype: value_types::FLOAT};
-pub const UNDERLYINGEXERCISECONFIRMATIONMETHOD: FixTag = FixTag { id: "41815", datatype: value_types::INT};
-pub const UNDERLYINGMANUALNOTICEBUSINESSCENTER: FixTag = FixTag { id: "41816", datatype: value_types::STRING};
-pub const UNDERLYINGFALLBACKEXERCISEINDICATOR: FixTag = FixTag { id: "41817", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGLIMITEDRIGHTTOCONFIRMINDICATOR: FixTag = FixTag { id: "41818", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGEXERCISESPLITTICKETINDICATOR: FixTag = FixTag { id: "41819", datatype: value_types::BOOLEAN};
-pub const NOUNDERLYINGOPTIONEXERCISEBUSINESSCENTERS: FixTag = FixTag { id: "41820", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGOPTIONEXERCISEBUSINESSCENTER: FixTag = FixTag { id: "41821", datatype: value_types::STRING};
-pub const UNDERLYINGOPTIONEXERCISEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "41822", datatype: value_types::INT};
-pub const UNDERLYINGOPTIONEXERCISEEARLIESTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "41823", datatype: value_types::INT};
-pub const UNDERLYINGOPTIONEXERCISEEARLIESTDATEOFFSETPERIOD: FixTag = FixTag { id: "41824", datatype: value_types::INT};
-pub const UNDERLYINGOPTIONEXERCISEEARLIESTDATEOFFSETUNIT: FixTag = FixTag { id: "41825", datatype: value_types::STRING};
-pub const UNDERLYINGOPTIONEXERCISEFREQUENCYPERIOD: FixTag = FixTag { id: "41826", datatype: value_types::INT};
-pub const UNDERLYINGOPTIONEXERCISEFREQUENCYUNIT: FixTag = FixTag { id: "41827", datatype: value_types::STRING};
-pub const UNDERLYINGOPTIONEXERCISESTARTDATEUNADJUSTED: FixTag = FixTag { id: "41828", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGOPTIONEXERCISESTARTDATERELATIVETO: FixTag = FixTag { id: "41829", datatype: value_types::INT};
-pub const UNDERLYINGOPTIONEXERCISESTARTDATEOFFSETPERIOD: FixTag = FixTag { id: "41830", datatype: value_types::INT};
-pub const UNDERLYINGOPTIONEXERCISESTARTDATEOFFSETUNIT: FixTag = FixTag { id: "41831", datatype: value_types::STRING};
-pub const UNDERLYINGOPTIONEXERCISESTARTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "41832", datatype: value_types::INT};
-pub const UNDERLYINGOPTIONEXERCISESTARTDATEADJUSTED: FixTag = FixTag { id: "41833", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGOPTIONEXERCISESKIP: FixTag = FixTag { id: "41834", datatype: value_types::INT};
-pub const UNDERLYINGOPTIONEXERCISENOMINATIONDEADLINE: FixTag = FixTag { id: "41835", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGOPTIONEXERCISEFIRSTDATEUNADJUSTED: FixTag = FixTag { id: "41836", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGOPTIONEXERCISELASTDATEUNADJUSTED: FixTag = FixTag { id: "41837", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGOPTIONEXERCISEEARLIESTTIME: FixTag = FixTag { id: "41838", datatype: value_types::LOCAL_MKT_TIME};
-pub const UNDERLYINGOPTIONEXERCISELATESTTIME: FixTag = FixTag { id: "41839", datatype: value_types::LOCAL_MKT_TIME};
-pub const UNDERLYINGOPTIONEXERCISETIMEBUSINESSCENTER: FixTag = FixTag { id: "41840", datatype: value_types::STRING};
-pub const NOUNDERLYINGOPTIONEXERCISEDATES: FixTag = FixTag { id: "41841", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGOPTIONEXERCISEDATE: FixTag = FixTag { id: "41842", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGOPTIONEXERCISEDATETYPE: FixTag = FixTag { id: "41843", datatype: value_types::INT};
-pub const NOUNDERLYINGOPTIONEXERCISEEXPIRATIONDATEBUSINESSCENTERS: FixTag = FixTag { id: "41844", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGOPTIONEXERCISEEXPIRATIONDATEBUSINESSCENTER: FixTag = FixTag { id: "41845", datatype: value_types::STRING};
-pub const UNDERLYINGOPTIONEXERCISEEXPIRATIONDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "41846", datatype: value_types::INT};
-pub const UNDERLYINGOPTIONEXERCISEEXPIRATIONDATERELATIVETO: FixTag = FixTag { id: "41847", datatype: value_types::INT};
-pub const UNDERLYINGOPTIONEXERCISEEXPIRATIONDATEOFFSETPERIOD: FixTag = FixTag { id: "41848", datatype: value_types::INT};
-pub const UNDERLYINGOPTIONEXERCISEEXPIRATIONDATEOFFSETUNIT: FixTag = FixTag { id: "41849", datatype: value_types::STRING};
-pub const UNDERLYINGOPTIONEXERCISEEXPIRATIONFREQUENCYPERIOD: FixTag = FixTag { id: "41850", datatype: value_types::INT};
-pub const UNDERLYINGOPTIONEXERCISEEXPIRATIONFREQUENCYUNIT: FixTag = FixTag { id: "41851", datatype: value_types::STRING};
-pub const UNDERLYINGOPTIONEXERCISEEXPIRATIONROLLCONVENTION: FixTag = FixTag { id: "41852", datatype: value_types::STRING};
-pub const UNDERLYINGOPTIONEXERCISEEXPIRATIONDATEOFFSETDAYTYPE: FixTag = FixTag { id: "41853", datatype: value_types::INT};
-pub const UNDERLYINGOPTIONEXERCISEEXPIRATIONTIME: FixTag = FixTag { id: "41854", datatype: value_types::LOCAL_MKT_TIME};
-pub const UNDERLYINGOPTIONEXERCISEEXPIRATIONTIMEBUSINESSCENTER: FixTag = FixTag { id: "41855", datatype: value_types::STRING};
-pub const NOUNDERLYINGOPTIONEXERCISEEXPIRATIONDATES: FixTag = FixTag { id: "41856", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGOPTIONEXERCISEEXPIRATIONDATE: FixTag = FixTag { id: "41857", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGOPTIONEXERCISEEXPIRATIONDATETYPE: FixTag = FixTag { id: "41858", datatype: value_types::INT};
-pub const UNDERLYINGMARKETDISRUPTIONPROVISION: FixTag = FixTag { id: "41859", datatype: value_types::INT};
-pub const UNDERLYINGMARKETDISRUPTIONFALLBACKPROVISION: FixTag = FixTag { id: "41860", datatype: value_types::INT};
-pub const UNDERLYINGMARKETDISRUPTIONMAXIMUMDAYS: FixTag = FixTag { id: "41861", datatype: value_types::INT};
-pub const UNDERLYINGMARKETDISRUPTIONMATERIALITYPERCENTAGE: FixTag = FixTag { id: "41862", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGMARKETDISRUPTIONMINIMUMFUTURESCONTRACTS: FixTag = FixTag { id: "41863", datatype: value_types::INT};
-pub const NOUNDERLYINGMARKETDISRUPTIONEVENTS: FixTag = FixTag { id: "41864", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGMARKETDISRUPTIONEVENT: FixTag = FixTag { id: "41865", datatype: value_types::STRING};
-pub const NOUNDERLYINGMARKETDISRUPTIONFALLBACKS: FixTag = FixTag { id: "41866", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGMARKETDISRUPTIONFALLBACKTYPE: FixTag = FixTag { id: "41867", datatype: value_types::STRING};
-pub const NOUNDERLYINGMARKETDISRUPTIONFALLBACKREFERENCEPRICES: FixTag = FixTag { id: "41868", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGMARKETDISRUPTIONFALLBACKUNDERLIERTYPE: FixTag = FixTag { id: "41869", datatype: value_types::INT};
-pub const UNDERLYINGMARKETDISRUPTIONFALLBACKUNDERLIERSECURITYID: FixTag = FixTag { id: "41870", datatype: value_types::STRING};
-pub const UNDERLYINGMARKETDISRUPTIONFALLBACKUNDERLIERSECURITYIDSOURCE: FixTag = FixTag { id: "41871", datatype: value_types::STRING};
-pub const UNDERLYINGMARKETDISRUPTIONFALLBACKUNDERLIERSECURITYDESC: FixTag = FixTag { id: "41872", datatype: value_types::STRING};
-pub const ENCODEDUNDERLYINGMARKETDISRUPTIONFALLBACKUNDERLIERSECDESCLEN: FixTag = FixTag { id: "41873", datatype: value_types::LENGTH};
-pub const ENCODEDUNDERLYINGMARKETDISRUPTIONFALLBACKUNDERLIERSECURITYDESC: FixTag = FixTag { id: "41874", datatype: value_types::DATA};
-pub const UNDERLYINGMARKETDISRUPTIONFALLBACKOPENUNITS: FixTag = FixTag { id: "41875", datatype: value_types::QTY};
-pub const UNDERLYINGMARKETDISRUPTIONFALLBACKBASKETCURRENCY: FixTag = FixTag { id: "41876", datatype: value_types::CURRENCY};
-pub const UNDERLYINGMARKETDISRUPTIONFALLBACKBASKETDIVISOR: FixTag = FixTag { id: "41877", datatype: value_types::FLOAT};
-pub const NOUNDERLYINGPAYMENTSCHEDULEFIXINGDAYS: FixTag = FixTag { id: "41878", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPAYMENTSCHEDULEFIXINGDAYOFWEEK: FixTag = FixTag { id: "41879", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSCHEDULEFIXINGDAYNUMBER: FixTag = FixTag { id: "41880", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSCHEDULEXID: FixTag = FixTag { id: "41881", datatype: value_types::XID};
-pub const UNDERLYINGPAYMENTSCHEDULEXIDREF: FixTag = FixTag { id: "41882", datatype: value_types::XIDREF};
-pub const UNDERLYINGPAYMENTSCHEDULERATECURRENCY: FixTag = FixTag { id: "41883", datatype: value_types::CURRENCY};
-pub const UNDERLYINGPAYMENTSCHEDULERATEUNITOFMEASURE: FixTag = FixTag { id: "41884", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSCHEDULERATECONVERSIONFACTOR: FixTag = FixTag { id: "41885", datatype: value_types::FLOAT};
-pub const UNDERLYINGPAYMENTSCHEDULERATESPREADTYPE: FixTag = FixTag { id: "41886", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSCHEDULESETTLPERIODPRICE: FixTag = FixTag { id: "41887", datatype: value_types::PRICE};
-pub const UNDERLYINGPAYMENTSCHEDULESETTLPERIODPRICECURRENCY: FixTag = FixTag { id: "41888", datatype: value_types::CURRENCY};
-pub const UNDERLYINGPAYMENTSCHEDULESETTLPERIODPRICEUNITOFMEASURE: FixTag = FixTag { id: "41889", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSCHEDULESTEPUNITOFMEASURE: FixTag = FixTag { id: "41890", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSCHEDULEFIXINGDAYDISTRIBUTION: FixTag = FixTag { id: "41891", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSCHEDULEFIXINGDAYCOUNT: FixTag = FixTag { id: "41892", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSCHEDULEFIXINGLAGPERIOD: FixTag = FixTag { id: "41893", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSCHEDULEFIXINGLAGUNIT: FixTag = FixTag { id: "41894", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSCHEDULEFIXINGFIRSTOBSERVATIONDATEOFFSETPERIOD: FixTag = FixTag { id: "41895", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSCHEDULEFIXINGFIRSTOBSERVATIONDATEOFFSETUNIT: FixTag = FixTag { id: "41896", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMFLATRATEINDICATOR: FixTag = FixTag { id: "41897", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGPAYMENTSTREAMFLATRATEAMOUNT: FixTag = FixTag { id: "41898", datatype: value_types::AMT};
-pub const UNDERLYINGPAYMENTSTREAMFLATRATECURRENCY: FixTag = FixTag { id: "41899", datatype: value_types::CURRENCY};
-pub const UNDERLYINGPAYMENTSTREAMMAXIMUMPAYMENTAMOUNT: FixTag = FixTag { id: "41900", datatype: value_types::AMT};
-pub const UNDERLYINGPAYMENTSTREAMMAXIMUMPAYMENTCURRENCY: FixTag = FixTag { id: "41901", datatype: value_types::CURRENCY};
-pub const UNDERLYINGPAYMENTSTREAMMAXIMUMTRANSACTIONAMOUNT: FixTag = FixTag { id: "41902", datatype: value_types::AMT};
-pub const UNDERLYINGPAYMENTSTREAMMAXIMUMTRANSACTIONCURRENCY: FixTag = FixTag { id: "41903", datatype: value_types::CURRENCY};
-pub const UNDERLYINGPAYMENTSTREAMFIXEDAMOUNTUNITOFMEASURE: FixTag = FixTag { id: "41904", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMTOTALFIXEDAMOUNT: FixTag = FixTag { id: "41905", datatype: value_types::AMT};
-pub const UNDERLYINGPAYMENTSTREAMWORLDSCALERATE: FixTag = FixTag { id: "41906", datatype: value_types::FLOAT};
-pub const UNDERLYINGPAYMENTSTREAMCONTRACTPRICE: FixTag = FixTag { id: "41907", datatype: value_types::PRICE};
-pub const UNDERLYINGPAYMENTSTREAMCONTRACTPRICECURRENCY: FixTag = FixTag { id: "41908", datatype: value_types::CURRENCY};
-pub const NOUNDERLYINGPAYMENTSTREAMPRICINGBUSINESSCENTERS: FixTag = FixTag { id: "41909", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPAYMENTSTREAMPRICINGBUSINESSCENTER: FixTag = FixTag { id: "41910", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMRATEINDEX2CURVEUNIT: FixTag = FixTag { id: "41911", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMRATEINDEX2CURVEPERIOD: FixTag = FixTag { id: "41912", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMRATEINDEXLOCATION: FixTag = FixTag { id: "41913", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMRATEINDEXLEVEL: FixTag = FixTag { id: "41914", datatype: value_types::QTY};
-pub const UNDERLYINGPAYMENTSTREAMRATEINDEXUNITOFMEASURE: FixTag = FixTag { id: "41915", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMSETTLLEVEL: FixTag = FixTag { id: "41916", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMREFERENCELEVEL: FixTag = FixTag { id: "41917", datatype: value_types::QTY};
-pub const UNDERLYINGPAYMENTSTREAMREFERENCELEVELUNITOFMEASURE: FixTag = FixTag { id: "41918", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMREFERENCELEVELEQUALSZEROINDICATOR: FixTag = FixTag { id: "41919", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGPAYMENTSTREAMRATESPREADCURRENCY: FixTag = FixTag { id: "41920", datatype: value_types::CURRENCY};
-pub const UNDERLYINGPAYMENTSTREAMRATESPREADUNITOFMEASURE: FixTag = FixTag { id: "41921", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMRATECONVERSIONFACTOR: FixTag = FixTag { id: "41922", datatype: value_types::FLOAT};
-pub const UNDERLYINGPAYMENTSTREAMRATESPREADTYPE: FixTag = FixTag { id: "41923", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMLASTRESETRATE: FixTag = FixTag { id: "41924", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGPAYMENTSTREAMFINALRATE: FixTag = FixTag { id: "41925", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGPAYMENTSTREAMCALCULATIONLAGPERIOD: FixTag = FixTag { id: "41926", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCALCULATIONLAGUNIT: FixTag = FixTag { id: "41927", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMFIRSTOBSERVATIONDATEOFFSETPERIOD: FixTag = FixTag { id: "41928", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMFIRSTOBSERVATIONDATEOFFSETUNIT: FixTag = FixTag { id: "41929", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMPRICINGDAYTYPE: FixTag = FixTag { id: "41930", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMPRICINGDAYDISTRIBUTION: FixTag = FixTag { id: "41931", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMPRICINGDAYCOUNT: FixTag = FixTag { id: "41932", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMPRICINGBUSINESSCALENDAR: FixTag = FixTag { id: "41933", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMPRICINGBUSINESSDAYCONVENTION: FixTag = FixTag { id: "41934", datatype: value_types::INT};
-pub const LEGSTREAMCOMMODITYSETTLTIMETYPE: FixTag = FixTag { id: "41935", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMCOMMODITYSETTLTIMETYPE: FixTag = FixTag { id: "41936", datatype: value_types::INT};
-pub const NOUNDERLYINGPAYMENTSTREAMPAYMENTDATES: FixTag = FixTag { id: "41937", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPAYMENTSTREAMPAYMENTDATE: FixTag = FixTag { id: "41938", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPAYMENTSTREAMPAYMENTDATETYPE: FixTag = FixTag { id: "41939", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMMASTERAGREEMENTPAYMENTDATESINDICATOR: FixTag = FixTag { id: "41940", datatype: value_types::BOOLEAN};
-pub const NOUNDERLYINGPAYMENTSTREAMPRICINGDATES: FixTag = FixTag { id: "41941", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPAYMENTSTREAMPRICINGDATE: FixTag = FixTag { id: "41942", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPAYMENTSTREAMPRICINGDATETYPE: FixTag = FixTag { id: "41943", datatype: value_types::INT};
-pub const NOUNDERLYINGPAYMENTSTREAMPRICINGDAYS: FixTag = FixTag { id: "41944", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPAYMENTSTREAMPRICINGDAYOFWEEK: FixTag = FixTag { id: "41945", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMPRICINGDAYNUMBER: FixTag = FixTag { id: "41946", datatype: value_types::INT};
-pub const NOUNDERLYINGPRICINGDATEBUSINESSCENTERS: FixTag = FixTag { id: "41947", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPRICINGDATEBUSINESSCENTER: FixTag = FixTag { id: "41948", datatype: value_types::STRING};
-pub const UNDERLYINGPRICINGDATEUNADJUSTED: FixTag = FixTag { id: "41949", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPRICINGDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "41950", datatype: value_types::INT};
-pub const UNDERLYINGPRICINGDATEADJUSTED: FixTag = FixTag { id: "41951", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPRICINGTIME: FixTag = FixTag { id: "41952", datatype: value_types::LOCAL_MKT_TIME};
-pub const UNDERLYINGPRICINGTIMEBUSINESSCENTER: FixTag = FixTag { id: "41953", datatype: value_types::STRING};
-pub const NOUNDERLYINGSTREAMCALCULATIONPERIODDATES: FixTag = FixTag { id: "41954", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGSTREAMCALCULATIONPERIODDATE: FixTag = FixTag { id: "41955", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGSTREAMCALCULATIONPERIODDATETYPE: FixTag = FixTag { id: "41956", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMCALCULATIONPERIODDATESXID: FixTag = FixTag { id: "41957", datatype: value_types::XID};
-pub const UNDERLYINGSTREAMCALCULATIONPERIODDATESXIDREF: FixTag = FixTag { id: "41958", datatype: value_types::XIDREF};
-pub const UNDERLYINGSTREAMCALCULATIONBALANCEOFFIRSTPERIOD: FixTag = FixTag { id: "41959", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGSTREAMCALCULATIONCORRECTIONPERIOD: FixTag = FixTag { id: "41960", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMCALCULATIONCORRECTIONUNIT: FixTag = FixTag { id: "41961", datatype: value_types::STRING};
-pub const NOUNDERLYINGSTREAMCOMMODITYSETTLBUSINESSCENTERS: FixTag = FixTag { id: "41962", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGSTREAMCOMMODITYSETTLBUSINESSCENTER: FixTag = FixTag { id: "41963", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMCOMMODITYBASE: FixTag = FixTag { id: "41964", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMCOMMODITYTYPE: FixTag = FixTag { id: "41965", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMCOMMODITYSECURITYID: FixTag = FixTag { id: "41966", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMCOMMODITYSECURITYIDSOURCE: FixTag = FixTag { id: "41967", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMCOMMODITYDESC: FixTag = FixTag { id: "41968", datatype: value_types::STRING};
-pub const ENCODEDUNDERLYINGSTREAMCOMMODITYDESCLEN: FixTag = FixTag { id: "41969", datatype: value_types::LENGTH};
-pub const ENCODEDUNDERLYINGSTREAMCOMMODITYDESC: FixTag = FixTag { id: "41970", datatype: value_types::DATA};
-pub const UNDERLYINGSTREAMCOMMODITYUNITOFMEASURE: FixTag = FixTag { id: "41971", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMCOMMODITYCURRENCY: FixTag = FixTag { id: "41972", datatype: value_types::CURRENCY};
-pub const UNDERLYINGSTREAMCOMMODITYEXCHANGE: FixTag = FixTag { id: "41973", datatype: value_types::EXCHANGE};
-pub const UNDERLYINGSTREAMCOMMODITYRATESOURCE: FixTag = FixTag { id: "41974", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMCOMMODITYRATEREFERENCEPAGE: FixTag = FixTag { id: "41975", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMCOMMODITYRATEREFERENCEPAGEHEADING: FixTag = FixTag { id: "41976", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMDATAPROVIDER: FixTag = FixTag { id: "41977", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMCOMMODITYPRICINGTYPE: FixTag = FixTag { id: "41978", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMCOMMODITYNEARBYSETTLDAYPERIOD: FixTag = FixTag { id: "41979", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMCOMMODITYNEARBYSETTLDAYUNIT: FixTag = FixTag { id: "41980", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMCOMMODITYSETTLDATEUNADJUSTED: FixTag = FixTag { id: "41981", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGSTREAMCOMMODITYSETTLDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "41982", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMCOMMODITYSETTLDATEADJUSTED: FixTag = FixTag { id: "41983", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGSTREAMCOMMODITYSETTLMONTH: FixTag = FixTag { id: "41984", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMCOMMODITYSETTLDATEROLLPERIOD: FixTag = FixTag { id: "41985", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMCOMMODITYSETTLDATEROLLUNIT: FixTag = FixTag { id: "41986", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMCOMMODITYSETTLDAYTYPE: FixTag = FixTag { id: "41987", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMCOMMODITYXID: FixTag = FixTag { id: "41988", datatype: value_types::XID};
-pub const UNDERLYINGSTREAMCOMMODITYXIDREF: FixTag = FixTag { id: "41989", datatype: value_types::XIDREF};
-pub const NOUNDERLYINGSTREAMCOMMODITYALTIDS: FixTag = FixTag { id: "41990", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGSTREAMCOMMODITYALTID: FixTag = FixTag { id: "41991", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMCOMMODITYALTIDSOURCE: FixTag = FixTag { id: "41992", datatype: value_types::STRING};
-pub const NOUNDERLYINGSTREAMCOMMODITYDATASOURCES: FixTag = FixTag { id: "41993", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGSTREAMCOMMODITYDATASOURCEID: FixTag = FixTag { id: "41994", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMCOMMODITYDATASOURCEIDTYPE: FixTag = FixTag { id: "41995", datatype: value_types::INT};
-pub const NOUNDERLYINGSTREAMCOMMODITYSETTLDAYS: FixTag = FixTag { id: "41996", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGSTREAMCOMMODITYSETTLDAY: FixTag = FixTag { id: "41997", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMCOMMODITYSETTLTOTALHOURS: FixTag = FixTag { id: "41998", datatype: value_types::INT};
-pub const NOUNDERLYINGSTREAMCOMMODITYSETTLTIMES: FixTag = FixTag { id: "41999", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGSTREAMCOMMODITYSETTLSTART: FixTag = FixTag { id: "42000", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMCOMMODITYSETTLEND: FixTag = FixTag { id: "42001", datatype: value_types::STRING};
-pub const NOUNDERLYINGSTREAMCOMMODITYSETTLPERIODS: FixTag = FixTag { id: "42002", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGSTREAMCOMMODITYSETTLCOUNTRY: FixTag = FixTag { id: "42003", datatype: value_types::ISO_COUNTRY};
-pub const UNDERLYINGSTREAMCOMMODITYSETTLTIMEZONE: FixTag = FixTag { id: "42004", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMCOMMODITYSETTLFLOWTYPE: FixTag = FixTag { id: "42005", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMCOMMODITYSETTLPERIODNOTIONAL: FixTag = FixTag { id: "42006", datatype: value_types::QTY};
-pub const UNDERLYINGSTREAMCOMMODITYSETTLPERIODNOTIONALUNITOFMEASURE: FixTag = FixTag { id: "42007", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMCOMMODITYSETTLPERIODFREQUENCYPERIOD: FixTag = FixTag { id: "42008", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMCOMMODITYSETTLPERIODFREQUENCYUNIT: FixTag = FixTag { id: "42009", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMCOMMODITYSETTLPERIODPRICE: FixTag = FixTag { id: "42010", datatype: value_types::PRICE};
-pub const UNDERLYINGSTREAMCOMMODITYSETTLPERIODPRICEUNITOFMEASURE: FixTag = FixTag { id: "42011", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMCOMMODITYSETTLPERIODPRICECURRENCY: FixTag = FixTag { id: "42012", datatype: value_types::CURRENCY};
-pub const UNDERLYINGSTREAMCOMMODITYSETTLHOLIDAYSPROCESSINGINSTRUCTION: FixTag = FixTag { id: "42013", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMCOMMODITYSETTLPERIODXID: FixTag = FixTag { id: "42014", datatype: value_types::XID};
-pub const UNDERLYINGSTREAMCOMMODITYSETTLPERIODXIDREF: FixTag = FixTag { id: "42015", datatype: value_types::XIDREF};
-pub const UNDERLYINGSTREAMXID: FixTag = FixTag { id: "42016", datatype: value_types::XID};
-pub const UNDERLYINGADDITIONALTERMBONDISSUER: FixTag = FixTag { id: "42017", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMNOTIONALXIDREF: FixTag = FixTag { id: "42018", datatype: value_types::XIDREF};
-pub const UNDERLYINGSTREAMNOTIONALFREQUENCYPERIOD: FixTag = FixTag { id: "42019", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMNOTIONALFREQUENCYUNIT: FixTag = FixTag { id: "42020", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMNOTIONALCOMMODITYFREQUENCY: FixTag = FixTag { id: "42021", datatype: value_types::INT};
-pub const UNDERLYINGSTREAMNOTIONALUNITOFMEASURE: FixTag = FixTag { id: "42022", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMTOTALNOTIONAL: FixTag = FixTag { id: "42023", datatype: value_types::QTY};
-pub const UNDERLYINGSTREAMTOTALNOTIONALUNITOFMEASURE: FixTag = FixTag { id: "42024", datatype: value_types::STRING};
-pub const ENCODEDUNDERLYINGADDITIONALTERMBONDISSUERLEN: FixTag = FixTag { id: "42025", datatype: value_types::LENGTH};
-pub const ENCODEDUNDERLYINGADDITIONALTERMBONDISSUER: FixTag = FixTag { id: "42026", datatype: value_types::DATA};
-pub const UNDERLYINGADDITIONALTERMBONDSENIORITY: FixTag = FixTag { id: "42027", datatype: value_types::STRING};
-pub const UNDERLYINGADDITIONALTERMBONDCOUPONTYPE: FixTag = FixTag { id: "42028", datatype: value_types::INT};
-pub const UNDERLYINGADDITIONALTERMBONDCOUPONRATE: FixTag = FixTag { id: "42029", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGADDITIONALTERMBONDMATURITYDATE: FixTag = FixTag { id: "42030", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGADDITIONALTERMBONDPARVALUE: FixTag = FixTag { id: "42031", datatype: value_types::AMT};
-pub const UNDERLYINGADDITIONALTERMBONDCURRENTTOTALISSUEDAMOUNT: FixTag = FixTag { id: "42032", datatype: value_types::AMT};
-pub const UNDERLYINGADDITIONALTERMBONDCOUPONFREQUENCYPERIOD: FixTag = FixTag { id: "42033", datatype: value_types::INT};
-pub const UNDERLYINGADDITIONALTERMBONDCOUPONFREQUENCYUNIT: FixTag = FixTag { id: "42034", datatype: value_types::STRING};
-pub const UNDERLYINGADDITIONALTERMBONDDAYCOUNT: FixTag = FixTag { id: "42035", datatype: value_types::INT};
-pub const NOUNDERLYINGADDITIONALTERMS: FixTag = FixTag { id: "42036", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGADDITIONALTERMCONDITIONPRECEDENTBONDINDICATOR: FixTag = FixTag { id: "42037", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGADDITIONALTERMDISCREPANCYCLAUSEINDICATOR: FixTag = FixTag { id: "42038", datatype: value_types::BOOLEAN};
-pub const NOUNDERLYINGCASHSETTLDEALERS: FixTag = FixTag { id: "42039", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGCASHSETTLDEALER: FixTag = FixTag { id: "42040", datatype: value_types::STRING};
-pub const NOUNDERLYINGCASHSETTLTERMS: FixTag = FixTag { id: "42041", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGCASHSETTLCURRENCY: FixTag = FixTag { id: "42042", datatype: value_types::CURRENCY};
-pub const UNDERLYINGCASHSETTLVALUATIONFIRSTBUSINESSDAYOFFSET: FixTag = FixTag { id: "42043", datatype: value_types::INT};
-pub const UNDERLYINGCASHSETTLVALUATIONSUBSEQUENTBUSINESSDAYSOFFSET: FixTag = FixTag { id: "42044", datatype: value_types::INT};
-pub const UNDERLYINGCASHSETTLNUMOFVALUATIONDATES: FixTag = FixTag { id: "42045", datatype: value_types::INT};
-pub const UNDERLYINGCASHSETTLVALUATIONTIME: FixTag = FixTag { id: "42046", datatype: value_types::LOCAL_MKT_TIME};
-pub const UNDERLYINGCASHSETTLBUSINESSCENTER: FixTag = FixTag { id: "42047", datatype: value_types::STRING};
-pub const UNDERLYINGCASHSETTLQUOTEMETHOD: FixTag = FixTag { id: "42048", datatype: value_types::INT};
-pub const UNDERLYINGCASHSETTLQUOTEAMOUNT: FixTag = FixTag { id: "42049", datatype: value_types::AMT};
-pub const UNDERLYINGCASHSETTLQUOTECURRENCY: FixTag = FixTag { id: "42050", datatype: value_types::CURRENCY};
-pub const UNDERLYINGCASHSETTLMINIMUMQUOTEAMOUNT: FixTag = FixTag { id: "42051", datatype: value_types::AMT};
-pub const UNDERLYINGCASHSETTLMINIMUMQUOTECURRENCY: FixTag = FixTag { id: "42052", datatype: value_types::CURRENCY};
-pub const UNDERLYINGCASHSETTLBUSINESSDAYS: FixTag = FixTag { id: "42053", datatype: value_types::INT};
-pub const UNDERLYINGCASHSETTLAMOUNT: FixTag = FixTag { id: "42054", datatype: value_types::AMT};
-pub const UNDERLYINGCASHSETTLRECOVERYFACTOR: FixTag = FixTag { id: "42055", datatype: value_types::FLOAT};
-pub const UNDERLYINGCASHSETTLFIXEDTERMINDICATOR: FixTag = FixTag { id: "42056", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGCASHSETTLACCRUEDINTERESTINDICATOR: FixTag = FixTag { id: "42057", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGCASHSETTLVALUATIONMETHOD: FixTag = FixTag { id: "42058", datatype: value_types::INT};
-pub const UNDERLYINGCASHSETTLTERMXID: FixTag = FixTag { id: "42059", datatype: value_types::XID};
-pub const NOUNDERLYINGPHYSICALSETTLTERMS: FixTag = FixTag { id: "42060", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPHYSICALSETTLCURRENCY: FixTag = FixTag { id: "42061", datatype: value_types::CURRENCY};
-pub const UNDERLYINGPHYSICALSETTLBUSINESSDAYS: FixTag = FixTag { id: "42062", datatype: value_types::INT};
-pub const UNDERLYINGPHYSICALSETTLMAXIMUMBUSINESSDAYS: FixTag = FixTag { id: "42063", datatype: value_types::INT};
-pub const UNDERLYINGPHYSICALSETTLTERMXID: FixTag = FixTag { id: "42064", datatype: value_types::XID};
-pub const NOUNDERLYINGPHYSICALSETTLDELIVERABLEOBLIGATIONS: FixTag = FixTag { id: "42065", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPHYSICALSETTLDELIVERABLEOBLIGATIONTYPE: FixTag = FixTag { id: "42066", datatype: value_types::STRING};
-pub const UNDERLYINGPHYSICALSETTLDELIVERABLEOBLIGATIONVALUE: FixTag = FixTag { id: "42067", datatype: value_types::STRING};
-pub const NOUNDERLYINGPROTECTIONTERMS: FixTag = FixTag { id: "42068", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPROTECTIONTERMNOTIONAL: FixTag = FixTag { id: "42069", datatype: value_types::AMT};
-pub const UNDERLYINGPROTECTIONTERMCURRENCY: FixTag = FixTag { id: "42070", datatype: value_types::CURRENCY};
-pub const UNDERLYINGPROTECTIONTERMSELLERNOTIFIES: FixTag = FixTag { id: "42071", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGPROTECTIONTERMBUYERNOTIFIES: FixTag = FixTag { id: "42072", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGPROTECTIONTERMEVENTBUSINESSCENTER: FixTag = FixTag { id: "42073", datatype: value_types::STRING};
-pub const UNDERLYINGPROTECTIONTERMSTANDARDSOURCES: FixTag = FixTag { id: "42074", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGPROTECTIONTERMEVENTMINIMUMSOURCES: FixTag = FixTag { id: "42075", datatype: value_types::INT};
-pub const UNDERLYINGPROTECTIONTERMXID: FixTag = FixTag { id: "42076", datatype: value_types::XID};
-pub const NOUNDERLYINGPROTECTIONTERMEVENTS: FixTag = FixTag { id: "42077", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPROTECTIONTERMEVENTTYPE: FixTag = FixTag { id: "42078", datatype: value_types::STRING};
-pub const UNDERLYINGPROTECTIONTERMEVENTVALUE: FixTag = FixTag { id: "42079", datatype: value_types::STRING};
-pub const UNDERLYINGPROTECTIONTERMEVENTCURRENCY: FixTag = FixTag { id: "42080", datatype: value_types::CURRENCY};
-pub const UNDERLYINGPROTECTIONTERMEVENTPERIOD: FixTag = FixTag { id: "42081", datatype: value_types::INT};
-pub const UNDERLYINGPROTECTIONTERMEVENTUNIT: FixTag = FixTag { id: "42082", datatype: value_types::STRING};
-pub const UNDERLYINGPROTECTIONTERMEVENTDAYTYPE: FixTag = FixTag { id: "42083", datatype: value_types::INT};
-pub const UNDERLYINGPROTECTIONTERMEVENTRATESOURCE: FixTag = FixTag { id: "42084", datatype: value_types::STRING};
-pub const NOUNDERLYINGPROTECTIONTERMEVENTQUALIFIERS: FixTag = FixTag { id: "42085", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPROTECTIONTERMEVENTQUALIFIER: FixTag = FixTag { id: "42086", datatype: value_types::CHAR};
-pub const NOUNDERLYINGPROTECTIONTERMOBLIGATIONS: FixTag = FixTag { id: "42087", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPROTECTIONTERMOBLIGATIONTYPE: FixTag = FixTag { id: "42088", datatype: value_types::STRING};
-pub const UNDERLYINGPROTECTIONTERMOBLIGATIONVALUE: FixTag = FixTag { id: "42089", datatype: value_types::STRING};
-pub const NOUNDERLYINGPROTECTIONTERMEVENTNEWSSOURCES: FixTag = FixTag { id: "42090", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPROTECTIONTERMEVENTNEWSSOURCE: FixTag = FixTag { id: "42091", datatype: value_types::STRING};
-pub const UNDERLYINGPROVISIONCASHSETTLPAYMENTDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42092", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONCASHSETTLPAYMENTDATERELATIVETO: FixTag = FixTag { id: "42093", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONCASHSETTLPAYMENTDATEOFFSETPERIOD: FixTag = FixTag { id: "42094", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONCASHSETTLPAYMENTDATEOFFSETUNIT: FixTag = FixTag { id: "42095", datatype: value_types::STRING};
-pub const UNDERLYINGPROVISIONCASHSETTLPAYMENTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42096", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONCASHSETTLPAYMENTDATERANGEFIRST: FixTag = FixTag { id: "42097", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPROVISIONCASHSETTLPAYMENTDATERANGELAST: FixTag = FixTag { id: "42098", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOUNDERLYINGPROVISIONCASHSETTLPAYMENTDATES: FixTag = FixTag { id: "42099", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPROVISIONCASHSETTLPAYMENTDATE: FixTag = FixTag { id: "42100", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPROVISIONCASHSETTLPAYMENTDATETYPE: FixTag = FixTag { id: "42101", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONCASHSETTLQUOTESOURCE: FixTag = FixTag { id: "42102", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONCASHSETTLQUOTEREFERENCEPAGE: FixTag = FixTag { id: "42103", datatype: value_types::STRING};
-pub const UNDERLYINGPROVISIONCASHSETTLVALUETIME: FixTag = FixTag { id: "42104", datatype: value_types::LOCAL_MKT_TIME};
-pub const UNDERLYINGPROVISIONCASHSETTLVALUETIMEBUSINESSCENTER: FixTag = FixTag { id: "42105", datatype: value_types::STRING};
-pub const UNDERLYINGPROVISIONCASHSETTLVALUEDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42106", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONCASHSETTLVALUEDATERELATIVETO: FixTag = FixTag { id: "42107", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONCASHSETTLVALUEDATEOFFSETPERIOD: FixTag = FixTag { id: "42108", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONCASHSETTLVALUEDATEOFFSETUNIT: FixTag = FixTag { id: "42109", datatype: value_types::STRING};
-pub const UNDERLYINGPROVISIONCASHSETTLVALUEDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42110", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONCASHSETTLVALUEDATEADJUSTED: FixTag = FixTag { id: "42111", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOUNDERLYINGPROVISIONOPTIONEXERCISEFIXEDDATES: FixTag = FixTag { id: "42112", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPROVISIONOPTIONEXERCISEFIXEDDATE: FixTag = FixTag { id: "42113", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPROVISIONOPTIONEXERCISEFIXEDDATETYPE: FixTag = FixTag { id: "42114", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONOPTIONEXERCISEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42115", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONOPTIONEXERCISEEARLIESTDATEOFFSETPERIOD: FixTag = FixTag { id: "42116", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONOPTIONEXERCISEEARLIESTDATEOFFSETUNIT: FixTag = FixTag { id: "42117", datatype: value_types::STRING};
-pub const UNDERLYINGPROVISIONOPTIONEXERCISEFREQUENCYPERIOD: FixTag = FixTag { id: "42118", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONOPTIONEXERCISEFREQUENCYUNIT: FixTag = FixTag { id: "42119", datatype: value_types::STRING};
-pub const UNDERLYINGPROVISIONOPTIONEXERCISESTARTDATEUNADJUSTED: FixTag = FixTag { id: "42120", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPROVISIONOPTIONEXERCISESTARTDATERELATIVETO: FixTag = FixTag { id: "42121", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONOPTIONEXERCISESTARTDATEOFFSETPERIOD: FixTag = FixTag { id: "42122", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONOPTIONEXERCISESTARTDATEOFFSETUNIT: FixTag = FixTag { id: "42123", datatype: value_types::STRING};
-pub const UNDERLYINGPROVISIONOPTIONEXERCISESTARTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42124", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONOPTIONEXERCISESTARTDATEADJUSTED: FixTag = FixTag { id: "42125", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPROVISIONOPTIONEXERCISEPERIODSKIP: FixTag = FixTag { id: "42126", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONOPTIONEXERCISEBOUNDSFIRSTDATEUNADJUSTED: FixTag = FixTag { id: "42127", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPROVISIONOPTIONEXERCISEBOUNDSLASTDATEUNADJUSTED: FixTag = FixTag { id: "42128", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPROVISIONOPTIONEXERCISEEARLIESTTIME: FixTag = FixTag { id: "42129", datatype: value_types::LOCAL_MKT_TIME};
-pub const UNDERLYINGPROVISIONOPTIONEXERCISEEARLIESTTIMEBUSINESSCENTER: FixTag = FixTag { id: "42130", datatype: value_types::STRING};
-pub const UNDERLYINGPROVISIONOPTIONEXERCISELATESTTIME: FixTag = FixTag { id: "42131", datatype: value_types::LOCAL_MKT_TIME};
-pub const UNDERLYINGPROVISIONOPTIONEXERCISELATESTTIMEBUSINESSCENTER: FixTag = FixTag { id: "42132", datatype: value_types::STRING};
-pub const UNDERLYINGPROVISIONOPTIONEXPIRATIONDATEUNADJUSTED: FixTag = FixTag { id: "42133", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPROVISIONOPTIONEXPIRATIONDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42134", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONOPTIONEXPIRATIONDATERELATIVETO: FixTag = FixTag { id: "42135", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONOPTIONEXPIRATIONDATEOFFSETPERIOD: FixTag = FixTag { id: "42136", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONOPTIONEXPIRATIONDATEOFFSETUNIT: FixTag = FixTag { id: "42137", datatype: value_types::STRING};
-pub const UNDERLYINGPROVISIONOPTIONEXPIRATIONDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42138", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONOPTIONEXPIRATIONDATEADJUSTED: FixTag = FixTag { id: "42139", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPROVISIONOPTIONEXPIRATIONTIME: FixTag = FixTag { id: "42140", datatype: value_types::LOCAL_MKT_TIME};
-pub const UNDERLYINGPROVISIONOPTIONEXPIRATIONTIMEBUSINESSCENTER: FixTag = FixTag { id: "42141", datatype: value_types::STRING};
-pub const UNDERLYINGPROVISIONOPTIONRELEVANTUNDERLYINGDATEUNADJUSTED: FixTag = FixTag { id: "42142", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPROVISIONOPTIONRELEVANTUNDERLYINGDATEBIZDAYCONVENTION: FixTag = FixTag { id: "42143", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONOPTIONRELEVANTUNDERLYINGDATERELATIVETO: FixTag = FixTag { id: "42144", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONOPTIONRELEVANTUNDERLYINGDATEOFFSETPERIOD: FixTag = FixTag { id: "42145", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONOPTIONRELEVANTUNDERLYINGDATEOFFSETUNIT: FixTag = FixTag { id: "42146", datatype: value_types::STRING};
-pub const UNDERLYINGPROVISIONOPTIONRELEVANTUNDERLYINGDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42147", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONOPTIONRELEVANTUNDERLYINGDATEADJUSTED: FixTag = FixTag { id: "42148", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOUNDERLYINGPROVISIONS: FixTag = FixTag { id: "42149", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPROVISIONTYPE: FixTag = FixTag { id: "42150", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONDATEUNADJUSTED: FixTag = FixTag { id: "42151", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPROVISIONDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42152", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONDATEADJUSTED: FixTag = FixTag { id: "42153", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPROVISIONDATETENORPERIOD: FixTag = FixTag { id: "42154", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONDATETENORUNIT: FixTag = FixTag { id: "42155", datatype: value_types::STRING};
-pub const UNDERLYINGPROVISIONCALCULATIONAGENT: FixTag = FixTag { id: "42156", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONOPTIONSINGLEPARTYBUYERSIDE: FixTag = FixTag { id: "42157", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONOPTIONSINGLEPARTYSELLERSIDE: FixTag = FixTag { id: "42158", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONOPTIONEXERCISESTYLE: FixTag = FixTag { id: "42159", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONOPTIONEXERCISEMULTIPLENOTIONAL: FixTag = FixTag { id: "42160", datatype: value_types::AMT};
-pub const UNDERLYINGPROVISIONOPTIONEXERCISEMINIMUMNOTIONAL: FixTag = FixTag { id: "42161", datatype: value_types::AMT};
-pub const UNDERLYINGPROVISIONOPTIONEXERCISEMAXIMUMNOTIONAL: FixTag = FixTag { id: "42162", datatype: value_types::AMT};
-pub const UNDERLYINGPROVISIONOPTIONMINIMUMNUMBER: FixTag = FixTag { id: "42163", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONOPTIONMAXIMUMNUMBER: FixTag = FixTag { id: "42164", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONOPTIONEXERCISECONFIRMATION: FixTag = FixTag { id: "42165", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGPROVISIONCASHSETTLMETHOD: FixTag = FixTag { id: "42166", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONCASHSETTLCURRENCY: FixTag = FixTag { id: "42167", datatype: value_types::CURRENCY};
-pub const UNDERLYINGPROVISIONCASHSETTLCURRENCY2: FixTag = FixTag { id: "42168", datatype: value_types::CURRENCY};
-pub const UNDERLYINGPROVISIONCASHSETTLQUOTETYPE: FixTag = FixTag { id: "42169", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONTEXT: FixTag = FixTag { id: "42170", datatype: value_types::STRING};
-pub const ENCODEDUNDERLYINGPROVISIONTEXTLEN: FixTag = FixTag { id: "42171", datatype: value_types::LENGTH};
-pub const ENCODEDUNDERLYINGPROVISIONTEXT: FixTag = FixTag { id: "42172", datatype: value_types::DATA};
-pub const NOUNDERLYINGPROVISIONPARTYIDS: FixTag = FixTag { id: "42173", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPROVISIONPARTYID: FixTag = FixTag { id: "42174", datatype: value_types::STRING};
-pub const UNDERLYINGPROVISIONPARTYIDSOURCE: FixTag = FixTag { id: "42175", datatype: value_types::CHAR};
-pub const UNDERLYINGPROVISIONPARTYROLE: FixTag = FixTag { id: "42176", datatype: value_types::INT};
-pub const NOUNDERLYINGPROVISIONPARTYSUBIDS: FixTag = FixTag { id: "42177", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPROVISIONPARTYSUBID: FixTag = FixTag { id: "42178", datatype: value_types::STRING};
-pub const UNDERLYINGPROVISIONPARTYSUBIDTYPE: FixTag = FixTag { id: "42179", datatype: value_types::INT};
-pub const NOUNDERLYINGPROVISIONCASHSETTLPAYMENTDATEBUSINESSCENTERS: FixTag = FixTag { id: "42180", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPROVISIONCASHSETTLPAYMENTDATEBUSINESSCENTER: FixTag = FixTag { id: "42181", datatype: value_types::STRING};
-pub const NOUNDERLYINGPROVISIONCASHSETTLVALUEDATEBUSINESSCENTERS: FixTag = FixTag { id: "42182", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPROVISIONCASHSETTLVALUEDATEBUSINESSCENTER: FixTag = FixTag { id: "42183", datatype: value_types::STRING};
-pub const NOUNDERLYINGPROVISIONOPTIONEXERCISEBUSINESSCENTERS: FixTag = FixTag { id: "42184", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPROVISIONOPTIONEXERCISEBUSINESSCENTER: FixTag = FixTag { id: "42185", datatype: value_types::STRING};
-pub const NOUNDERLYINGPROVISIONOPTIONEXPIRATIONDATEBUSINESSCENTERS: FixTag = FixTag { id: "42186", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPROVISIONOPTIONEXPIRATIONDATEBUSINESSCENTER: FixTag = FixTag { id: "42187", datatype: value_types::STRING};
-pub const NOUNDERLYINGPROVISIONOPTIONRELEVANTUNDERLYINGDATEBUSINESSCENTERS: FixTag = FixTag { id: "42188", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPROVISIONOPTIONRELEVANTUNDERLYINGDATEBUSINESSCENTER: FixTag = FixTag { id: "42189", datatype: value_types::STRING};
-pub const NOUNDERLYINGPROVISIONDATEBUSINESSCENTERS: FixTag = FixTag { id: "42190", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPROVISIONDATEBUSINESSCENTER: FixTag = FixTag { id: "42191", datatype: value_types::STRING};
-pub const DELIVERYSTREAMDELIVERYPOINTSOURCE: FixTag = FixTag { id: "42192", datatype: value_types::INT};
-pub const DELIVERYSTREAMDELIVERYPOINTDESC: FixTag = FixTag { id: "42193", datatype: value_types::STRING};
-pub const LEGDELIVERYSTREAMDELIVERYPOINTSOURCE: FixTag = FixTag { id: "42194", datatype: value_types::INT};
-pub const LEGDELIVERYSTREAMDELIVERYPOINTDESC: FixTag = FixTag { id: "42195", datatype: value_types::STRING};
-pub const UNDERLYINGDELIVERYSTREAMDELIVERYPOINTSOURCE: FixTag = FixTag { id: "42196", datatype: value_types::INT};
-pub const UNDERLYINGDELIVERYSTREAMDELIVERYPOINTDESC: FixTag = FixTag { id: "42197", datatype: value_types::STRING};
-pub const NOLEGCONTRACTUALDEFINITIONS: FixTag = FixTag { id: "42198", datatype: value_types::NUM_IN_GROUP };
-pub const LEGCONTRACTUALDEFINITION: FixTag = FixTag { id: "42199", datatype: value_types::STRING};
-pub const NOLEGFINANCINGTERMSUPPLEMENTS: FixTag = FixTag { id: "42200", datatype: value_types::NUM_IN_GROUP };
-pub const LEGFINANCINGTERMSUPPLEMENTDESC: FixTag = FixTag { id: "42201", datatype: value_types::STRING};
-pub const LEGFINANCINGTERMSUPPLEMENTDATE: FixTag = FixTag { id: "42202", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOLEGCONTRACTUALMATRICES: FixTag = FixTag { id: "42203", datatype: value_types::NUM_IN_GROUP };
-pub const LEGCONTRACTUALMATRIXSOURCE: FixTag = FixTag { id: "42204", datatype: value_types::STRING};
-pub const LEGCONTRACTUALMATRIXDATE: FixTag = FixTag { id: "42205", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGCONTRACTUALMATRIXTERM: FixTag = FixTag { id: "42206", datatype: value_types::STRING};
-pub const CASHSETTLDATEUNADJUSTED: FixTag = FixTag { id: "42207", datatype: value_types::LOCAL_MKT_DATE};
-pub const CASHSETTLDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42208", datatype: value_types::INT};
-pub const CASHSETTLDATERELATIVETO: FixTag = FixTag { id: "42209", datatype: value_types::INT};
-pub const CASHSETTLDATEOFFSETPERIOD: FixTag = FixTag { id: "42210", datatype: value_types::INT};
-pub const CASHSETTLDATEOFFSETUNIT: FixTag = FixTag { id: "42211", datatype: value_types::STRING};
-pub const CASHSETTLDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42212", datatype: value_types::INT};
-pub const CASHSETTLDATEADJUSTED: FixTag = FixTag { id: "42213", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOCASHSETTLDATEBUSINESSCENTERS: FixTag = FixTag { id: "42214", datatype: value_types::NUM_IN_GROUP };
-pub const CASHSETTLDATEBUSINESSCENTER: FixTag = FixTag { id: "42215", datatype: value_types::STRING};
-pub const CASHSETTLPRICESOURCE: FixTag = FixTag { id: "42216", datatype: value_types::STRING};
-pub const CASHSETTLPRICEDEFAULT: FixTag = FixTag { id: "42217", datatype: value_types::INT};
-pub const DIVIDENDFLOATINGRATEINDEX: FixTag = FixTag { id: "42218", datatype: value_types::STRING};
-pub const DIVIDENDFLOATINGRATEINDEXCURVEPERIOD: FixTag = FixTag { id: "42219", datatype: value_types::INT};
-pub const DIVIDENDFLOATINGRATEINDEXCURVEUNIT: FixTag = FixTag { id: "42220", datatype: value_types::STRING};
-pub const DIVIDENDFLOATINGRATEMULTIPLIER: FixTag = FixTag { id: "42221", datatype: value_types::FLOAT};
-pub const DIVIDENDFLOATINGRATESPREAD: FixTag = FixTag { id: "42222", datatype: value_types::PRICE_OFFSET};
-pub const DIVIDENDFLOATINGRATESPREADPOSITIONTYPE: FixTag = FixTag { id: "42223", datatype: value_types::INT};
-pub const DIVIDENDFLOATINGRATETREATMENT: FixTag = FixTag { id: "42224", datatype: value_types::INT};
-pub const DIVIDENDCAPRATE: FixTag = FixTag { id: "42225", datatype: value_types::PERCENTAGE};
-pub const DIVIDENDCAPRATEBUYSIDE: FixTag = FixTag { id: "42226", datatype: value_types::INT};
-pub const DIVIDENDCAPRATESELLSIDE: FixTag = FixTag { id: "42227", datatype: value_types::INT};
-pub const DIVIDENDFLOORRATE: FixTag = FixTag { id: "42228", datatype: value_types::PERCENTAGE};
-pub const DIVIDENDFLOORRATEBUYSIDE: FixTag = FixTag { id: "42229", datatype: value_types::INT};
-pub const DIVIDENDFLOORRATESELLSIDE: FixTag = FixTag { id: "42230", datatype: value_types::INT};
-pub const DIVIDENDINITIALRATE: FixTag = FixTag { id: "42231", datatype: value_types::PERCENTAGE};
-pub const DIVIDENDFINALRATEROUNDINGDIRECTION: FixTag = FixTag { id: "42232", datatype: value_types::CHAR};
-pub const DIVIDENDFINALRATEPRECISION: FixTag = FixTag { id: "42233", datatype: value_types::INT};
-pub const DIVIDENDAVERAGINGMETHOD: FixTag = FixTag { id: "42234", datatype: value_types::INT};
-pub const DIVIDENDNEGATIVERATETREATMENT: FixTag = FixTag { id: "42235", datatype: value_types::INT};
-pub const NODIVIDENDACCRUALPAYMENTDATEBUSINESSCENTERS: FixTag = FixTag { id: "42236", datatype: value_types::NUM_IN_GROUP };
-pub const DIVIDENDACCRUALPAYMENTDATEBUSINESSCENTER: FixTag = FixTag { id: "42237", datatype: value_types::STRING};
-pub const DIVIDENDACCRUALPAYMENTDATERELATIVETO: FixTag = FixTag { id: "42238", datatype: value_types::INT};
-pub const DIVIDENDACCRUALPAYMENTDATEOFFSETPERIOD: FixTag = FixTag { id: "42239", datatype: value_types::INT};
-pub const DIVIDENDACCRUALPAYMENTDATEOFFSETUNIT: FixTag = FixTag { id: "42240", datatype: value_types::STRING};
-pub const DIVIDENDACCRUALPAYMENTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42241", datatype: value_types::INT};
-pub const DIVIDENDACCRUALPAYMENTDATEUNADJUSTED: FixTag = FixTag { id: "42242", datatype: value_types::LOCAL_MKT_DATE};
-pub const DIVIDENDACCRUALPAYMEENTDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42243", datatype: value_types::INT};
-pub const DIVIDENDACCRUALPAYMENTDATEADJUSTED: FixTag = FixTag { id: "42244", datatype: value_types::LOCAL_MKT_DATE};
-pub const DIVIDENDREINVESTMENTINDICATOR: FixTag = FixTag { id: "42245", datatype: value_types::BOOLEAN};
-pub const DIVIDENDENTITLEMENTEVENT: FixTag = FixTag { id: "42246", datatype: value_types::INT};
-pub const DIVIDENDAMOUNTTYPE: FixTag = FixTag { id: "42247", datatype: value_types::INT};
-pub const DIVIDENDUNDERLIERREFID: FixTag = FixTag { id: "42248", datatype: value_types::STRING};
-pub const EXTRAORDINARYDIVIDENDPARTYSIDE: FixTag = FixTag { id: "42249", datatype: value_types::INT};
-pub const EXTRAORDINARYDIVIDENDAMOUNTTYPE: FixTag = FixTag { id: "42250", datatype: value_types::INT};
-pub const EXTRAORDINARYDIVIDENDCURRENCY: FixTag = FixTag { id: "42251", datatype: value_types::CURRENCY};
-pub const EXTRAORDINARYDIVIDENDDETERMINATIONMETHOD: FixTag = FixTag { id: "42252", datatype: value_types::STRING};
-pub const DIVIDENDACCRUALFIXEDRATE: FixTag = FixTag { id: "42253", datatype: value_types::PERCENTAGE};
-pub const DIVIDENDCOMPOUNDINGMETHOD: FixTag = FixTag { id: "42254", datatype: value_types::INT};
-pub const DIVIDENDNUMOFINDEXUNITS: FixTag = FixTag { id: "42255", datatype: value_types::INT};
-pub const DIVIDENDCASHPERCENTAGE: FixTag = FixTag { id: "42256", datatype: value_types::PERCENTAGE};
-pub const DIVIDENDCASHEQUIVALENTPERCENTAGE: FixTag = FixTag { id: "42257", datatype: value_types::PERCENTAGE};
-pub const NONCASHDIVIDENDTREATMENT: FixTag = FixTag { id: "42258", datatype: value_types::INT};
-pub const DIVIDENDCOMPOSITION: FixTag = FixTag { id: "42259", datatype: value_types::INT};
-pub const SPECIALDIVIDENDSINDICATOR: FixTag = FixTag { id: "42260", datatype: value_types::BOOLEAN};
-pub const MATERIALDIVIDENDSINDICATOR: FixTag = FixTag { id: "42261", datatype: value_types::BOOLEAN};
-pub const OPTIONSEXCHANGEDIVIDENDSINDICATOR: FixTag = FixTag { id: "42262", datatype: value_types::BOOLEAN};
-pub const ADDITIONALDIVIDENDSINDICATOR: FixTag = FixTag { id: "42263", datatype: value_types::BOOLEAN};
-pub const ALLDIVIDENDSINDICATOR: FixTag = FixTag { id: "42264", datatype: value_types::BOOLEAN};
-pub const DIVIDENDFXTRIGGERDATERELATIVETO: FixTag = FixTag { id: "42265", datatype: value_types::INT};
-pub const DIVIDENDFXTRIGGERDATEOFFSETPERIOD: FixTag = FixTag { id: "42266", datatype: value_types::INT};
-pub const DIVIDENDFXTRIGGERDATEOFFSETUNIT: FixTag = FixTag { id: "42267", datatype: value_types::STRING};
-pub const DIVIDENDFXTRIGGERDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42268", datatype: value_types::INT};
-pub const DIVIDENDFXTRIGGERDATEUNADJUSTED: FixTag = FixTag { id: "42269", datatype: value_types::LOCAL_MKT_DATE};
-pub const DIVIDENDFXTRIGGERDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42270", datatype: value_types::INT};
-pub const DIVIDENDFXTRIGGERDATEADJUSTED: FixTag = FixTag { id: "42271", datatype: value_types::LOCAL_MKT_DATE};
-pub const NODIVIDENDFXTRIGGERDATEBUSINESSCENTERS: FixTag = FixTag { id: "42272", datatype: value_types::NUM_IN_GROUP };
-pub const DIVIDENDFXTRIGGERDATEBUSINESSCENTER: FixTag = FixTag { id: "42273", datatype: value_types::STRING};
-pub const NODIVIDENDPERIODS: FixTag = FixTag { id: "42274", datatype: value_types::NUM_IN_GROUP };
-pub const DIVIDENDPERIODSEQUENCE: FixTag = FixTag { id: "42275", datatype: value_types::INT};
-pub const DIVIDENDPERIODSTARTDATEUNADJUSTED: FixTag = FixTag { id: "42276", datatype: value_types::LOCAL_MKT_DATE};
-pub const DIVIDENDPERIODENDDATEUNADJUSTED: FixTag = FixTag { id: "42277", datatype: value_types::LOCAL_MKT_DATE};
-pub const DIVIDENDPERIODUNDERLIERREFID: FixTag = FixTag { id: "42278", datatype: value_types::STRING};
-pub const DIVIDENDPERIODSTRIKEPRICE: FixTag = FixTag { id: "42279", datatype: value_types::PRICE};
-pub const DIVIDENDPERIODBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42280", datatype: value_types::INT};
-pub const DIVIDENDPERIODVALUATIONDATEUNADJUSTED: FixTag = FixTag { id: "42281", datatype: value_types::LOCAL_MKT_DATE};
-pub const DIVIDENDPERIODVALUATIONDATERELATIVETO: FixTag = FixTag { id: "42282", datatype: value_types::INT};
-pub const DIVIDENDPERIODVALUATIONDATEOFFSETPERIOD: FixTag = FixTag { id: "42283", datatype: value_types::INT};
-pub const DIVIDENDPERIODVALUATIONDATEOFFSETUNIT: FixTag = FixTag { id: "42284", datatype: value_types::STRING};
-pub const DIVIDENDPERIODVALUATIONDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42285", datatype: value_types::INT};
-pub const DIVIDENDPERIODVALUATIONDATEADJUSTED: FixTag = FixTag { id: "42286", datatype: value_types::LOCAL_MKT_DATE};
-pub const DIVIDENDPERIODPAYMENTDATEUNADJUSTED: FixTag = FixTag { id: "42287", datatype: value_types::LOCAL_MKT_DATE};
-pub const DIVIDENDPERIODPAYMENTDATERELATIVETO: FixTag = FixTag { id: "42288", datatype: value_types::INT};
-pub const DIVIDENDPERIODPAYMENTDATEOFFSETPERIOD: FixTag = FixTag { id: "42289", datatype: value_types::INT};
-pub const DIVIDENDPERIODPAYMENTDATEOFFSETUNIT: FixTag = FixTag { id: "42290", datatype: value_types::STRING};
-pub const DIVIDENDPERIODPAYMENTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42291", datatype: value_types::INT};
-pub const DIVIDENDPERIODPAYMENTDATEADJUSTED: FixTag = FixTag { id: "42292", datatype: value_types::LOCAL_MKT_DATE};
-pub const DIVIDENDPERIODXID: FixTag = FixTag { id: "42293", datatype: value_types::XID};
-pub const NODIVIDENDPERIODBUSINESSCENTERS: FixTag = FixTag { id: "42294", datatype: value_types::NUM_IN_GROUP };
-pub const DIVIDENDPERIODBUSINESSCENTER: FixTag = FixTag { id: "42295", datatype: value_types::STRING};
-pub const NOEXTRAORDINARYEVENTS: FixTag = FixTag { id: "42296", datatype: value_types::NUM_IN_GROUP };
-pub const EXTRAORDINARYEVENTTYPE: FixTag = FixTag { id: "42297", datatype: value_types::STRING};
-pub const EXTRAORDINARYEVENTVALUE: FixTag = FixTag { id: "42298", datatype: value_types::STRING};
-pub const LEGCASHSETTLDATEUNADJUSTED: FixTag = FixTag { id: "42299", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGCASHSETTLDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42300", datatype: value_types::INT};
-pub const LEGCASHSETTLDATERELATIVETO: FixTag = FixTag { id: "42301", datatype: value_types::INT};
-pub const LEGCASHSETTLDATEOFFSETPERIOD: FixTag = FixTag { id: "42302", datatype: value_types::INT};
-pub const LEGCASHSETTLDATEOFFSETUNIT: FixTag = FixTag { id: "42303", datatype: value_types::STRING};
-pub const LEGCASHSETTLDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42304", datatype: value_types::INT};
-pub const LEGCASHSETTLDATEADJUSTED: FixTag = FixTag { id: "42305", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOLEGCASHSETTLDATEBUSINESSCENTERS: FixTag = FixTag { id: "42306", datatype: value_types::NUM_IN_GROUP };
-pub const LEGCASHSETTLDATEBUSINESSCENTER: FixTag = FixTag { id: "42307", datatype: value_types::STRING};
-pub const LEGCASHSETTLPRICESOURCE: FixTag = FixTag { id: "42308", datatype: value_types::STRING};
-pub const LEGCASHSETTLPRICEDEFAULT: FixTag = FixTag { id: "42309", datatype: value_types::INT};
-pub const NOLEGDIVIDENDACCRUALPAYMENTDATEBUSINESSCENTERS: FixTag = FixTag { id: "42310", datatype: value_types::NUM_IN_GROUP };
-pub const LEGDIVIDENDACCRUALPAYMENTDATEBUSINESSCENTER: FixTag = FixTag { id: "42311", datatype: value_types::STRING};
-pub const LEGDIVIDENDFLOATINGRATEINDEX: FixTag = FixTag { id: "42312", datatype: value_types::STRING};
-pub const LEGDIVIDENDFLOATINGRATEINDEXCURVEPERIOD: FixTag = FixTag { id: "42313", datatype: value_types::INT};
-pub const LEGDIVIDENDFLOATINGRATEINDEXCURVEUNIT: FixTag = FixTag { id: "42314", datatype: value_types::STRING};
-pub const LEGDIVIDENDFLOATINGRATEMULTIPLIER: FixTag = FixTag { id: "42315", datatype: value_types::FLOAT};
-pub const LEGDIVIDENDFLOATINGRATESPREAD: FixTag = FixTag { id: "42316", datatype: value_types::PRICE_OFFSET};
-pub const LEGDIVIDENDFLOATINGRATESPREADPOSITIONTYPE: FixTag = FixTag { id: "42317", datatype: value_types::INT};
-pub const LEGDIVIDENDFLOATINGRATETREATMENT: FixTag = FixTag { id: "42318", datatype: value_types::INT};
-pub const LEGDIVIDENDCAPRATE: FixTag = FixTag { id: "42319", datatype: value_types::PERCENTAGE};
-pub const LEGDIVIDENDCAPRATEBUYSIDE: FixTag = FixTag { id: "42320", datatype: value_types::INT};
-pub const LEGDIVIDENDCAPRATESELLSIDE: FixTag = FixTag { id: "42321", datatype: value_types::INT};
-pub const LEGDIVIDENDFLOORRATE: FixTag = FixTag { id: "42322", datatype: value_types::PERCENTAGE};
-pub const LEGDIVIDENDFLOORRATEBUYSIDE: FixTag = FixTag { id: "42323", datatype: value_types::INT};
-pub const LEGDIVIDENDFLOORRATESELLSIDE: FixTag = FixTag { id: "42324", datatype: value_types::INT};
-pub const LEGDIVIDENDINITIALRATE: FixTag = FixTag { id: "42325", datatype: value_types::PERCENTAGE};
-pub const LEGDIVIDENDFINALRATEROUNDINGDIRECTION: FixTag = FixTag { id: "42326", datatype: value_types::CHAR};
-pub const LEGDIVIDENDFINALRATEPRECISION: FixTag = FixTag { id: "42327", datatype: value_types::INT};
-pub const LEGDIVIDENDAVERAGINGMETHOD: FixTag = FixTag { id: "42328", datatype: value_types::INT};
-pub const LEGDIVIDENDNEGATIVERATETREATMENT: FixTag = FixTag { id: "42329", datatype: value_types::INT};
-pub const LEGDIVIDENDACCRUALPAYMENTDATERELATIVETO: FixTag = FixTag { id: "42330", datatype: value_types::INT};
-pub const LEGDIVIDENDACCRUALPAYMENTDATEOFFSETPERIOD: FixTag = FixTag { id: "42331", datatype: value_types::INT};
-pub const LEGDIVIDENDACCRUALPAYMENTDATEOFFSETUNIT: FixTag = FixTag { id: "42332", datatype: value_types::STRING};
-pub const LEGDIVIDENDACCRUALPAYMENTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42333", datatype: value_types::INT};
-pub const LEGDIVIDENDACCRUALPAYMENTDATEUNADJUSTED: FixTag = FixTag { id: "42334", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGDIVIDENDACCRUALPAYMENTDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42335", datatype: value_types::INT};
-pub const LEGDIVIDENDACCRUALPAYMENTDATEADJUSTED: FixTag = FixTag { id: "42336", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGDIVIDENDREINVESTMENTINDICATOR: FixTag = FixTag { id: "42337", datatype: value_types::BOOLEAN};
-pub const LEGDIVIDENDENTITLEMENTEVENT: FixTag = FixTag { id: "42338", datatype: value_types::INT};
-pub const LEGDIVIDENDAMOUNTTYPE: FixTag = FixTag { id: "42339", datatype: value_types::INT};
-pub const LEGDIVIDENDUNDERLIERREFID: FixTag = FixTag { id: "42340", datatype: value_types::STRING};
-pub const LEGEXTRAORDINARYDIVIDENDPARTYSIDE: FixTag = FixTag { id: "42341", datatype: value_types::INT};
-pub const LEGEXTRAORDINARYDIVIDENDAMOUNTTYPE: FixTag = FixTag { id: "42342", datatype: value_types::INT};
-pub const LEGEXTRAORDINARYDIVIDENDCURRENCY: FixTag = FixTag { id: "42343", datatype: value_types::CURRENCY};
-pub const LEGEXTRAORDINARYDIVIDENDDETERMINATIONMETHOD: FixTag = FixTag { id: "42344", datatype: value_types::STRING};
-pub const LEGDIVIDENDACCRUALFIXEDRATE: FixTag = FixTag { id: "42345", datatype: value_types::PERCENTAGE};
-pub const LEGDIVIDENDCOMPOUNDINGMETHOD: FixTag = FixTag { id: "42346", datatype: value_types::INT};
-pub const LEGDIVIDENDNUMOFINDEXUNITS: FixTag = FixTag { id: "42347", datatype: value_types::INT};
-pub const LEGDIVIDENDCASHPERCENTAGE: FixTag = FixTag { id: "42348", datatype: value_types::PERCENTAGE};
-pub const LEGDIVIDENDCASHEQUIVALENTPERCENTAGE: FixTag = FixTag { id: "42349", datatype: value_types::PERCENTAGE};
-pub const LEGNONCASHDIVIDENDTREATMENT: FixTag = FixTag { id: "42350", datatype: value_types::INT};
-pub const LEGDIVIDENDCOMPOSITION: FixTag = FixTag { id: "42351", datatype: value_types::INT};
-pub const LEGSPECIALDIVIDENDSINDICATOR: FixTag = FixTag { id: "42352", datatype: value_types::BOOLEAN};
-pub const LEGMATERIALDIVIDENDSINDICATOR: FixTag = FixTag { id: "42353", datatype: value_types::BOOLEAN};
-pub const LEGOPTIONSEXCHANGEDIVIDENDSINDICATOR: FixTag = FixTag { id: "42354", datatype: value_types::BOOLEAN};
-pub const LEGADDITIONALDIVIDENDSINDICATOR: FixTag = FixTag { id: "42355", datatype: value_types::BOOLEAN};
-pub const LEGALLDIVIDENDSINDICATOR: FixTag = FixTag { id: "42356", datatype: value_types::BOOLEAN};
-pub const LEGDIVIDENDFXTRIGGERDATERELATIVETO: FixTag = FixTag { id: "42357", datatype: value_types::INT};
-pub const LEGDIVIDENDFXTRIGGERDATEOFFSETPERIOD: FixTag = FixTag { id: "42358", datatype: value_types::INT};
-pub const LEGDIVIDENDFXTRIGGERDATEOFFSETUNIT: FixTag = FixTag { id: "42359", datatype: value_types::STRING};
-pub const LEGDIVIDENDFXTRIGGERDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42360", datatype: value_types::INT};
-pub const LEGDIVIDENDFXTRIGGERDATEUNADJUSTED: FixTag = FixTag { id: "42361", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGDIVIDENDFXTRIGGERDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42362", datatype: value_types::INT};
-pub const LEGDIVIDENDFXTRIGGERDATEADJUSTED: FixTag = FixTag { id: "42363", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOLEGDIVIDENDFXTRIGGERDATEBUSINESSCENTERS: FixTag = FixTag { id: "42364", datatype: value_types::NUM_IN_GROUP };
-pub const LEGDIVIDENDFXTRIGGERDATEBUSINESSCENTER: FixTag = FixTag { id: "42365", datatype: value_types::STRING};
-pub const NOLEGDIVIDENDPERIODS: FixTag = FixTag { id: "42366", datatype: value_types::NUM_IN_GROUP };
-pub const LEGDIVIDENDPERIODSEQUENCE: FixTag = FixTag { id: "42367", datatype: value_types::INT};
-pub const LEGDIVIDENDPERIODSTARTDATEUNADJUSTED: FixTag = FixTag { id: "42368", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGDIVIDENDPERIODENDDATEUNADJUSTED: FixTag = FixTag { id: "42369", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGDIVIDENDPERIODUNDERLIERREFID: FixTag = FixTag { id: "42370", datatype: value_types::STRING};
-pub const LEGDIVIDENDPERIODSTRIKEPRICE: FixTag = FixTag { id: "42371", datatype: value_types::PRICE};
-pub const LEGDIVIDENDPERIODBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42372", datatype: value_types::INT};
-pub const LEGDIVIDENDPERIODVALUATIONDATEUNADJUSTED: FixTag = FixTag { id: "42373", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGDIVIDENDPERIODVALUATIONDATERELATIVETO: FixTag = FixTag { id: "42374", datatype: value_types::INT};
-pub const LEGDIVIDENDPERIODVALUATIONDATEOFFSETPERIOD: FixTag = FixTag { id: "42375", datatype: value_types::INT};
-pub const LEGDIVIDENDPERIODVALUATIONDATEOFFSETUNIT: FixTag = FixTag { id: "42376", datatype: value_types::STRING};
-pub const LEGDIVIDENDPERIODVALUATIONDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42377", datatype: value_types::INT};
-pub const LEGDIVIDENDPERIODVALUATIONDATEADJUSTED: FixTag = FixTag { id: "42378", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGDIVIDENDPERIODPAYMENTDATEUNADJUSTED: FixTag = FixTag { id: "42379", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGDIVIDENDPERIODPAYMENTDATERELATIVETO: FixTag = FixTag { id: "42380", datatype: value_types::INT};
-pub const LEGDIVIDENDPERIODPAYMENTDATEOFFSETPERIOD: FixTag = FixTag { id: "42381", datatype: value_types::INT};
-pub const LEGDIVIDENDPERIODPAYMENTDATEOFFSETUNIT: FixTag = FixTag { id: "42382", datatype: value_types::STRING};
-pub const LEGDIVIDENDPERIODPAYMENTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42383", datatype: value_types::INT};
-pub const LEGDIVIDENDPERIODPAYMENTDATEADJUSTED: FixTag = FixTag { id: "42384", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGDIVIDENDPERIODXID: FixTag = FixTag { id: "42385", datatype: value_types::XID};
-pub const NOLEGDIVIDENDPERIODBUSINESSCENTERS: FixTag = FixTag { id: "42386", datatype: value_types::NUM_IN_GROUP };
-pub const LEGDIVIDENDPERIODBUSINESSCENTER: FixTag = FixTag { id: "42387", datatype: value_types::STRING};
-pub const NOLEGEXTRAORDINARYEVENTS: FixTag = FixTag { id: "42388", datatype: value_types::NUM_IN_GROUP };
-pub const LEGEXTRAORDINARYEVENTTYPE: FixTag = FixTag { id: "42389", datatype: value_types::STRING};
-pub const LEGEXTRAORDINARYEVENTVALUE: FixTag = FixTag { id: "42390", datatype: value_types::STRING};
-pub const LEGSETTLMETHODELECTINGPARTYSIDE: FixTag = FixTag { id: "42391", datatype: value_types::INT};
-pub const LEGMAKEWHOLEDATE: FixTag = FixTag { id: "42392", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGMAKEWHOLEAMOUNT: FixTag = FixTag { id: "42393", datatype: value_types::AMT};
-pub const LEGMAKEWHOLEBENCHMARKCURVENAME: FixTag = FixTag { id: "42394", datatype: value_types::STRING};
-pub const LEGMAKEWHOLEBENCHMARKCURVEPOINT: FixTag = FixTag { id: "42395", datatype: value_types::STRING};
-pub const LEGMAKEWHOLERECALLSPREAD: FixTag = FixTag { id: "42396", datatype: value_types::PRICE_OFFSET};
-pub const LEGMAKEWHOLEBENCHMARKQUOTE: FixTag = FixTag { id: "42397", datatype: value_types::INT};
-pub const LEGMAKEWHOLEINTERPOLATIONMETHOD: FixTag = FixTag { id: "42398", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCASHSETTLINDICATOR: FixTag = FixTag { id: "42399", datatype: value_types::BOOLEAN};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGXIDREF: FixTag = FixTag { id: "42400", datatype: value_types::XIDREF};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGSPREAD: FixTag = FixTag { id: "42401", datatype: value_types::PRICE_OFFSET};
-pub const LEGPAYMENTSTREAMINTERPOLATIONMETHOD: FixTag = FixTag { id: "42402", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMINTERPOLATIONPERIOD: FixTag = FixTag { id: "42403", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGFIXEDRATE: FixTag = FixTag { id: "42404", datatype: value_types::FLOAT};
-pub const NOLEGPAYMENTSTREAMCOMPOUNDINGDATES: FixTag = FixTag { id: "42405", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPAYMENTSTREAMCOMPOUNDINGDATE: FixTag = FixTag { id: "42406", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGDATETYPE: FixTag = FixTag { id: "42407", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGDATESBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42408", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGDATESRELATIVETO: FixTag = FixTag { id: "42409", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGDATESOFFSETPERIOD: FixTag = FixTag { id: "42410", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGDATESOFFSETUNIT: FixTag = FixTag { id: "42411", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGDATESOFFSETDAYTYPE: FixTag = FixTag { id: "42412", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGPERIODSKIP: FixTag = FixTag { id: "42413", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGFREQUENCYPERIOD: FixTag = FixTag { id: "42414", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGFREQUENCYUNIT: FixTag = FixTag { id: "42415", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGROLLCONVENTION: FixTag = FixTag { id: "42416", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMBOUNDSFIRSTDATEUNADJUSTED: FixTag = FixTag { id: "42417", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPAYMENTSTREAMBOUNDSLASTDATEUNADJUSTED: FixTag = FixTag { id: "42418", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOLEGPAYMENTSTREAMCOMPOUNDINGDATESBUSINESSCENTERS: FixTag = FixTag { id: "42419", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPAYMENTSTREAMCOMPOUNDINGDATESBUSINESSCENTER: FixTag = FixTag { id: "42420", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGENDDATEUNADJUSTED: FixTag = FixTag { id: "42421", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGENDDATERELATIVETO: FixTag = FixTag { id: "42422", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGENDDATEOFFSETPERIOD: FixTag = FixTag { id: "42423", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGENDDATEOFFSETUNIT: FixTag = FixTag { id: "42424", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGENDDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42425", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGENDDATEADJUSTED: FixTag = FixTag { id: "42426", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGRATEINDEX: FixTag = FixTag { id: "42427", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGRATEINDEXCURVEPERIOD: FixTag = FixTag { id: "42428", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGRATEINDEXCURVEUNIT: FixTag = FixTag { id: "42429", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGRATEMULTIPLIER: FixTag = FixTag { id: "42430", datatype: value_types::FLOAT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGRATESPREAD: FixTag = FixTag { id: "42431", datatype: value_types::PRICE_OFFSET};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGRATESPREADPOSITIONTYPE: FixTag = FixTag { id: "42432", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGRATETREATMENT: FixTag = FixTag { id: "42433", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGCAPRATE: FixTag = FixTag { id: "42434", datatype: value_types::PERCENTAGE};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGCAPRATEBUYSIDE: FixTag = FixTag { id: "42435", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGCAPRATESELLSIDE: FixTag = FixTag { id: "42436", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGFLOORRATE: FixTag = FixTag { id: "42437", datatype: value_types::PERCENTAGE};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGFLOORRATEBUYSIDE: FixTag = FixTag { id: "42438", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGFLOORRATESELLSIDE: FixTag = FixTag { id: "42439", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGINITIALRATE: FixTag = FixTag { id: "42440", datatype: value_types::PERCENTAGE};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGFINALRATEROUNDINGDIRECTION: FixTag = FixTag { id: "42441", datatype: value_types::CHAR};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGFINALRATEPRECISION: FixTag = FixTag { id: "42442", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGAVERAGINGMETHOD: FixTag = FixTag { id: "42443", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGNEGATIVERATETREATMENT: FixTag = FixTag { id: "42444", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGSTARTDATEUNADJUSTED: FixTag = FixTag { id: "42445", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGSTARTDATERELATIVETO: FixTag = FixTag { id: "42446", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGSTARTDATEOFFSETPERIOD: FixTag = FixTag { id: "42447", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGSTARTDATEOFFSETUNIT: FixTag = FixTag { id: "42448", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGSTARTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42449", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMCOMPOUNDINGSTARTDATEADJUSTED: FixTag = FixTag { id: "42450", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPAYMENTSTREAMFORMULAIMAGELENGTH: FixTag = FixTag { id: "42451", datatype: value_types::LENGTH};
-pub const LEGPAYMENTSTREAMFORMULAIMAGE: FixTag = FixTag { id: "42452", datatype: value_types::DATA};
-pub const LEGPAYMENTSTREAMFINALPRICEPAYMENTDATEUNADJUSTED: FixTag = FixTag { id: "42453", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPAYMENTSTREAMFINALPRICEPAYMENTDATERELATIVETO: FixTag = FixTag { id: "42454", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMFINALPRICEPAYMENTDATEOFFSETPERIOD: FixTag = FixTag { id: "42455", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMFINALPRICEPAYMENTDATEOFFSETUNIT: FixTag = FixTag { id: "42456", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMFINALPRICEPAYMENTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42457", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMFINALPRICEPAYMENTDATEADJUSTED: FixTag = FixTag { id: "42458", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOLEGPAYMENTSTREAMFIXINGDATES: FixTag = FixTag { id: "42459", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPAYMENTSTREAMFIXINGDATE: FixTag = FixTag { id: "42460", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPAYMENTSTREAMFIXINGDATETYPE: FixTag = FixTag { id: "42461", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMFIRSTOBSERVATIONDATEUNADJUSTED: FixTag = FixTag { id: "42462", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPAYMENTSTREAMFIRSTOBSERVATIONDATERELATIVETO: FixTag = FixTag { id: "42463", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMFIRSTOBSERVATIONDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42464", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMFIRSTOBSERVATIONDATEADJUSTED: FixTag = FixTag { id: "42465", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPAYMENTSTREAMUNDERLIERREFID: FixTag = FixTag { id: "42466", datatype: value_types::STRING};
-pub const LEGRETURNRATENOTIONALRESET: FixTag = FixTag { id: "42467", datatype: value_types::BOOLEAN};
-pub const LEGPAYMENTSTREAMLINKINITIALLEVEL: FixTag = FixTag { id: "42468", datatype: value_types::PRICE};
-pub const LEGPAYMENTSTREAMLINKCLOSINGLEVELINDICATOR: FixTag = FixTag { id: "42469", datatype: value_types::BOOLEAN};
-pub const LEGPAYMENTSTREAMLINKEXPIRINGLEVELINDICATOR: FixTag = FixTag { id: "42470", datatype: value_types::BOOLEAN};
-pub const LEGPAYMENTSTREAMLINKESTIMATEDTRADINGDAYS: FixTag = FixTag { id: "42471", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMLINKSTRIKEPRICE: FixTag = FixTag { id: "42472", datatype: value_types::PRICE};
-pub const LEGPAYMENTSTREAMLINKSTRIKEPRICETYPE: FixTag = FixTag { id: "42473", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMLINKMAXIMUMBOUNDARY: FixTag = FixTag { id: "42474", datatype: value_types::FLOAT};
-pub const LEGPAYMENTSTREAMLINKMINIMUMBOUNDARY: FixTag = FixTag { id: "42475", datatype: value_types::FLOAT};
-pub const LEGPAYMENTSTREAMLINKNUMBEROFDATASERIES: FixTag = FixTag { id: "42476", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMVARIANCEUNADJUSTEDCAP: FixTag = FixTag { id: "42477", datatype: value_types::FLOAT};
-pub const LEGPAYMENTSTREAMREALIZEDVARIANCEMETHOD: FixTag = FixTag { id: "42478", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMDAYSADJUSTMENTINDICATOR: FixTag = FixTag { id: "42479", datatype: value_types::BOOLEAN};
-pub const LEGPAYMENTSTREAMNEARESTEXCHANGECONTRACTREFID: FixTag = FixTag { id: "42480", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMVEGANOTIONALAMOUNT: FixTag = FixTag { id: "42481", datatype: value_types::FLOAT};
-pub const LEGPAYMENTSTREAMFORMULACURRENCY: FixTag = FixTag { id: "42482", datatype: value_types::CURRENCY};
-pub const LEGPAYMENTSTREAMFORMULACURRENCYDETERMINATIONMETHOD: FixTag = FixTag { id: "42483", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMFORMULAREFERENCEAMOUNT: FixTag = FixTag { id: "42484", datatype: value_types::INT};
-pub const NOLEGPAYMENTSTREAMFORMULAS: FixTag = FixTag { id: "42485", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPAYMENTSTREAMFORMULA: FixTag = FixTag { id: "42486", datatype: value_types::XML_DATA};
-pub const LEGPAYMENTSTREAMFORMULADESC: FixTag = FixTag { id: "42487", datatype: value_types::STRING};
-pub const LEGPAYMENTSTUBENDDATEUNADJUSTED: FixTag = FixTag { id: "42488", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPAYMENTSTUBENDDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42489", datatype: value_types::INT};
-pub const LEGPAYMENTSTUBENDDATERELATIVETO: FixTag = FixTag { id: "42490", datatype: value_types::INT};
-pub const LEGPAYMENTSTUBENDDATEOFFSETPERIOD: FixTag = FixTag { id: "42491", datatype: value_types::INT};
-pub const LEGPAYMENTSTUBENDDATEOFFSETUNIT: FixTag = FixTag { id: "42492", datatype: value_types::STRING};
-pub const LEGPAYMENTSTUBENDDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42493", datatype: value_types::INT};
-pub const LEGPAYMENTSTUBENDDATEADJUSTED: FixTag = FixTag { id: "42494", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOLEGPAYMENTSTUBENDDATEBUSINESSCENTERS: FixTag = FixTag { id: "42495", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPAYMENTSTUBENDDATEBUSINESSCENTER: FixTag = FixTag { id: "42496", datatype: value_types::STRING};
-pub const LEGPAYMENTSTUBSTARTDATEUNADJUSTED: FixTag = FixTag { id: "42497", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGPAYMENTSTUBSTARTDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42498", datatype: value_types::INT};
-pub const LEGPAYMENTSTUBSTARTDATERELATIVETO: FixTag = FixTag { id: "42499", datatype: value_types::INT};
-pub const LEGPAYMENTSTUBSTARTDATEOFFSETPERIOD: FixTag = FixTag { id: "42500", datatype: value_types::INT};
-pub const LEGPAYMENTSTUBSTARTDATEOFFSETUNIT: FixTag = FixTag { id: "42501", datatype: value_types::STRING};
-pub const LEGPAYMENTSTUBSTARTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42502", datatype: value_types::INT};
-pub const LEGPAYMENTSTUBSTARTDATEADJUSTED: FixTag = FixTag { id: "42503", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOLEGPAYMENTSTUBSTARTDATEBUSINESSCENTERS: FixTag = FixTag { id: "42504", datatype: value_types::NUM_IN_GROUP };
-pub const LEGPAYMENTSTUBSTARTDATEBUSINESSCENTER: FixTag = FixTag { id: "42505", datatype: value_types::STRING};
-pub const LEGPROVISIONBREAKFEEELECTION: FixTag = FixTag { id: "42506", datatype: value_types::INT};
-pub const LEGPROVISIONBREAKFEERATE: FixTag = FixTag { id: "42507", datatype: value_types::PERCENTAGE};
-pub const NOLEGRETURNRATEDATES: FixTag = FixTag { id: "42508", datatype: value_types::NUM_IN_GROUP };
-pub const LEGRETURNRATEDATEMODE: FixTag = FixTag { id: "42509", datatype: value_types::INT};
-pub const LEGRETURNRATEVALUATIONDATERELATIVETO: FixTag = FixTag { id: "42510", datatype: value_types::INT};
-pub const LEGRETURNRATEVALUATIONDATEOFFSETPERIOD: FixTag = FixTag { id: "42511", datatype: value_types::INT};
-pub const LEGRETURNRATEVALUATIONDATEOFFSETUNIT: FixTag = FixTag { id: "42512", datatype: value_types::STRING};
-pub const LEGRETURNRATEVALUATIONDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42513", datatype: value_types::INT};
-pub const LEGRETURNRATEVALUATIONSTARTDATEUNADJUSTED: FixTag = FixTag { id: "42514", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGRETURNRATEVALUATIONSTARTDATERELATIVETO: FixTag = FixTag { id: "42515", datatype: value_types::INT};
-pub const LEGRETURNRATEVALUATIONSTARTDATEOFFSETPERIOD: FixTag = FixTag { id: "42516", datatype: value_types::INT};
-pub const LEGRETURNRATEVALUATIONSTARTDATEOFFSETUNIT: FixTag = FixTag { id: "42517", datatype: value_types::STRING};
-pub const LEGRETURNRATEVALUATIONSTARTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42518", datatype: value_types::INT};
-pub const LEGRETURNRATEVALUATIONSTARTDATEADJUSTED: FixTag = FixTag { id: "42519", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGRETURNRATEVALUATIONENDDATEUNADJUSTED: FixTag = FixTag { id: "42520", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGRETURNRATEVALUATIONENDDATERELATIVETO: FixTag = FixTag { id: "42521", datatype: value_types::INT};
-pub const LEGRETURNRATEVALUATIONENDDATEOFFSETPERIOD: FixTag = FixTag { id: "42522", datatype: value_types::INT};
-pub const LEGRETURNRATEVALUATIONENDDATEOFFSETUNIT: FixTag = FixTag { id: "42523", datatype: value_types::STRING};
-pub const LEGRETURNRATEVALUATIONENDDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42524", datatype: value_types::INT};
-pub const LEGRETURNRATEVALUATIONENDDATEADJUSTED: FixTag = FixTag { id: "42525", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGRETURNRATEVALUATIONFREQUENCYPERIOD: FixTag = FixTag { id: "42526", datatype: value_types::INT};
-pub const LEGRETURNRATEVALUATIONFREQUENCYUNIT: FixTag = FixTag { id: "42527", datatype: value_types::STRING};
-pub const LEGRETURNRATEVALUATIONFREQUENCYROLLCONVENTION: FixTag = FixTag { id: "42528", datatype: value_types::STRING};
-pub const LEGRETURNRATEVALUATIONDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42529", datatype: value_types::INT};
-pub const NOLEGRETURNRATEFXCONVERSIONS: FixTag = FixTag { id: "42530", datatype: value_types::NUM_IN_GROUP };
-pub const LEGRETURNRATEFXCURRENCYSYMBOL: FixTag = FixTag { id: "42531", datatype: value_types::STRING};
-pub const LEGRETURNRATEFXRATE: FixTag = FixTag { id: "42532", datatype: value_types::FLOAT};
-pub const LEGRETURNRATEFXRATECALC: FixTag = FixTag { id: "42533", datatype: value_types::CHAR};
-pub const NOLEGRETURNRATES: FixTag = FixTag { id: "42534", datatype: value_types::NUM_IN_GROUP };
-pub const LEGRETURNRATEPRICESEQUENCE: FixTag = FixTag { id: "42535", datatype: value_types::INT};
-pub const LEGRETURNRATECOMMISSIONBASIS: FixTag = FixTag { id: "42536", datatype: value_types::CHAR};
-pub const LEGRETURNRATECOMMISSIONAMOUNT: FixTag = FixTag { id: "42537", datatype: value_types::AMT};
-pub const LEGRETURNRATECOMMISSIONCURRENCY: FixTag = FixTag { id: "42538", datatype: value_types::CURRENCY};
-pub const LEGRETURNRATETOTALCOMMISSIONPERTRADE: FixTag = FixTag { id: "42539", datatype: value_types::AMT};
-pub const LEGRETURNRATEDETERMINATIONMETHOD: FixTag = FixTag { id: "42540", datatype: value_types::STRING};
-pub const LEGRETURNRATEAMOUNTRELATIVETO: FixTag = FixTag { id: "42541", datatype: value_types::INT};
-pub const LEGRETURNRATEQUOTEMEASURETYPE: FixTag = FixTag { id: "42542", datatype: value_types::STRING};
-pub const LEGRETURNRATEQUOTEUNITS: FixTag = FixTag { id: "42543", datatype: value_types::STRING};
-pub const LEGRETURNRATEQUOTEMETHOD: FixTag = FixTag { id: "42544", datatype: value_types::INT};
-pub const LEGRETURNRATEQUOTECURRENCY: FixTag = FixTag { id: "42545", datatype: value_types::CURRENCY};
-pub const LEGRETURNRATEQUOTECURRENCYTYPE: FixTag = FixTag { id: "42546", datatype: value_types::STRING};
-pub const LEGRETURNRATEQUOTETIMETYPE: FixTag = FixTag { id: "42547", datatype: value_types::INT};
-pub const LEGRETURNRATEQUOTETIME: FixTag = FixTag { id: "42548", datatype: value_types::LOCAL_MKT_TIME};
-pub const LEGRETURNRATEQUOTEDATE: FixTag = FixTag { id: "42549", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGRETURNRATEQUOTEEXPIRATIONTIME: FixTag = FixTag { id: "42550", datatype: value_types::LOCAL_MKT_TIME};
-pub const LEGRETURNRATEQUOTEBUSINESSCENTER: FixTag = FixTag { id: "42551", datatype: value_types::STRING};
-pub const LEGRETURNRATEQUOTEEXCHANGE: FixTag = FixTag { id: "42552", datatype: value_types::EXCHANGE};
-pub const LEGRETURNRATEQUOTEPRICINGMODEL: FixTag = FixTag { id: "42553", datatype: value_types::STRING};
-pub const LEGRETURNRATECASHFLOWTYPE: FixTag = FixTag { id: "42554", datatype: value_types::STRING};
-pub const LEGRETURNRATEVALUATIONTIMETYPE: FixTag = FixTag { id: "42555", datatype: value_types::INT};
-pub const LEGRETURNRATEVALUATIONTIME: FixTag = FixTag { id: "42556", datatype: value_types::LOCAL_MKT_TIME};
-pub const LEGRETURNRATEVALUATIONTIMEBUSINESSCENTER: FixTag = FixTag { id: "42557", datatype: value_types::STRING};
-pub const LEGRETURNRATEVALUATIONPRICEOPTION: FixTag = FixTag { id: "42558", datatype: value_types::INT};
-pub const LEGRETURNRATEFINALPRICEFALLBACK: FixTag = FixTag { id: "42559", datatype: value_types::INT};
-pub const NOLEGRETURNRATEINFORMATIONSOURCES: FixTag = FixTag { id: "42560", datatype: value_types::NUM_IN_GROUP };
-pub const LEGRETURNRATEINFORMATIONSOURCE: FixTag = FixTag { id: "42561", datatype: value_types::INT};
-pub const LEGRETURNRATEREFERENCEPAGE: FixTag = FixTag { id: "42562", datatype: value_types::STRING};
-pub const LEGRETURNRATEREFERENCEPAGEHEADING: FixTag = FixTag { id: "42563", datatype: value_types::STRING};
-pub const NOLEGRETURNRATEPRICES: FixTag = FixTag { id: "42564", datatype: value_types::NUM_IN_GROUP };
-pub const LEGRETURNRATEPRICEBASIS: FixTag = FixTag { id: "42565", datatype: value_types::INT};
-pub const LEGRETURNRATEPRICE: FixTag = FixTag { id: "42566", datatype: value_types::PRICE};
-pub const LEGRETURNRATEPRICECURRENCY: FixTag = FixTag { id: "42567", datatype: value_types::CURRENCY};
-pub const LEGRETURNRATEPRICETYPE: FixTag = FixTag { id: "42568", datatype: value_types::INT};
-pub const NOLEGRETURNRATEVALUATIONDATEBUSINESSCENTERS: FixTag = FixTag { id: "42569", datatype: value_types::NUM_IN_GROUP };
-pub const LEGRETURNRATEVALUATIONDATEBUSINESSCENTER: FixTag = FixTag { id: "42570", datatype: value_types::STRING};
-pub const NOLEGRETURNRATEVALUATIONDATES: FixTag = FixTag { id: "42571", datatype: value_types::NUM_IN_GROUP };
-pub const LEGRETURNRATEVALUATIONDATE: FixTag = FixTag { id: "42572", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGRETURNRATEVALUATIONDATETYPE: FixTag = FixTag { id: "42573", datatype: value_types::INT};
-pub const LEGSETTLMETHODELECTIONDATEUNADJUSTED: FixTag = FixTag { id: "42574", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGSETTLMETHODELECTIONDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42575", datatype: value_types::INT};
-pub const LEGSETTLMETHODELECTIONDATERELATIVETO: FixTag = FixTag { id: "42576", datatype: value_types::INT};
-pub const LEGSETTLMETHODELECTIONDATEOFFSETPERIOD: FixTag = FixTag { id: "42577", datatype: value_types::INT};
-pub const LEGSETTLMETHODELECTIONDATEOFFSETUNIT: FixTag = FixTag { id: "42578", datatype: value_types::STRING};
-pub const LEGSETTLMETHODELECTIONDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42579", datatype: value_types::INT};
-pub const LEGSETTLMETHODELECTIONDATEADJUSTED: FixTag = FixTag { id: "42580", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOLEGSETTLMETHODELECTIONDATEBUSINESSCENTERS: FixTag = FixTag { id: "42581", datatype: value_types::NUM_IN_GROUP };
-pub const LEGSETTLMETHODELECTIONDATEBUSINESSCENTER: FixTag = FixTag { id: "42582", datatype: value_types::STRING};
-pub const LEGSTREAMVERSION: FixTag = FixTag { id: "42583", datatype: value_types::STRING};
-pub const LEGSTREAMVERSIONEFFECTIVEDATE: FixTag = FixTag { id: "42584", datatype: value_types::LOCAL_MKT_DATE};
-pub const LEGSTREAMNOTIONALDETERMINATIONMETHOD: FixTag = FixTag { id: "42585", datatype: value_types::STRING};
-pub const LEGSTREAMNOTIONALADJUSTMENTS: FixTag = FixTag { id: "42586", datatype: value_types::INT};
-pub const STREAMCOMMODITYDELIVERYPRICINGREGION: FixTag = FixTag { id: "42587", datatype: value_types::STRING};
-pub const LEGSTREAMCOMMODITYDELIVERYPRICINGREGION: FixTag = FixTag { id: "42588", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMCOMMODITYDELIVERYPRICINGREGION: FixTag = FixTag { id: "42589", datatype: value_types::STRING};
-pub const SETTLMETHODELECTINGPARTYSIDE: FixTag = FixTag { id: "42590", datatype: value_types::INT};
-pub const MAKEWHOLEDATE: FixTag = FixTag { id: "42591", datatype: value_types::LOCAL_MKT_DATE};
-pub const MAKEWHOLEAMOUNT: FixTag = FixTag { id: "42592", datatype: value_types::AMT};
-pub const MAKEWHOLEBENCHMARKCURVENAME: FixTag = FixTag { id: "42593", datatype: value_types::STRING};
-pub const MAKEWHOLEBENCHMARKCURVEPOINT: FixTag = FixTag { id: "42594", datatype: value_types::STRING};
-pub const MAKEWHOLERECALLSPREAD: FixTag = FixTag { id: "42595", datatype: value_types::PRICE_OFFSET};
-pub const MAKEWHOLEBENCHMARKQUOTE: FixTag = FixTag { id: "42596", datatype: value_types::INT};
-pub const MAKEWHOLEINTERPOLATIONMETHOD: FixTag = FixTag { id: "42597", datatype: value_types::INT};
-pub const PAYMENTAMOUNTRELATIVETO: FixTag = FixTag { id: "42598", datatype: value_types::INT};
-pub const PAYMENTAMOUNTDETERMINATIONMETHOD: FixTag = FixTag { id: "42599", datatype: value_types::STRING};
-pub const PAYMENTSTREAMCASHSETTLINDICATOR: FixTag = FixTag { id: "42600", datatype: value_types::BOOLEAN};
-pub const PAYMENTSTREAMCOMPOUNDINGXIDREF: FixTag = FixTag { id: "42601", datatype: value_types::XIDREF};
-pub const PAYMENTSTREAMCOMPOUNDINGSPREAD: FixTag = FixTag { id: "42602", datatype: value_types::PRICE_OFFSET};
-pub const PAYMENTSTREAMINTERPOLATIONMETHOD: FixTag = FixTag { id: "42603", datatype: value_types::INT};
-pub const PAYMENTSTREAMINTERPOLATIONPERIOD: FixTag = FixTag { id: "42604", datatype: value_types::INT};
-pub const PAYMENTSTREAMCOMPOUNDINGFIXEDRATE: FixTag = FixTag { id: "42605", datatype: value_types::FLOAT};
-pub const NOPAYMENTSTREAMCOMPOUNDINGDATES: FixTag = FixTag { id: "42606", datatype: value_types::NUM_IN_GROUP };
-pub const PAYMENTSTREAMCOMPOUNDINGDATE: FixTag = FixTag { id: "42607", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTSTREAMCOMPOUNDINGDATETYPE: FixTag = FixTag { id: "42608", datatype: value_types::INT};
-pub const PAYMENTSTREAMCOMPOUNDINGDATESBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42609", datatype: value_types::INT};
-pub const PAYMENTSTREAMCOMPOUNDINGDATESRELATIVETO: FixTag = FixTag { id: "42610", datatype: value_types::INT};
-pub const PAYMENTSTREAMCOMPOUNDINGDATESOFFSETPERIOD: FixTag = FixTag { id: "42611", datatype: value_types::INT};
-pub const PAYMENTSTREAMCOMPOUNDINGDATESOFFSETUNIT: FixTag = FixTag { id: "42612", datatype: value_types::STRING};
-pub const PAYMENTSTREAMCOMPOUNDINGDATESOFFSETDAYTYPE: FixTag = FixTag { id: "42613", datatype: value_types::INT};
-pub const PAYMENTSTREAMCOMPOUNDINGPERIODSKIP: FixTag = FixTag { id: "42614", datatype: value_types::INT};
-pub const PAYMENTSTREAMCOMPOUNDINGFREQUENCYPERIOD: FixTag = FixTag { id: "42615", datatype: value_types::INT};
-pub const PAYMENTSTREAMCOMPOUNDINGFREQUENCYUNIT: FixTag = FixTag { id: "42616", datatype: value_types::STRING};
-pub const PAYMENTSTREAMCOMPOUNDINGROLLCONVENTION: FixTag = FixTag { id: "42617", datatype: value_types::STRING};
-pub const PAYMENTSTREAMBOUNDSFIRSTDATEUNADJUSTED: FixTag = FixTag { id: "42618", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTSTREAMBOUNDSLASTDATEUNADJUSTED: FixTag = FixTag { id: "42619", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOPAYMENTSTREAMCOMPOUNDINGDATESBUSINESSCENTERS: FixTag = FixTag { id: "42620", datatype: value_types::NUM_IN_GROUP };
-pub const PAYMENTSTREAMCOMPOUNDINGDATESBUSINESSCENTER: FixTag = FixTag { id: "42621", datatype: value_types::STRING};
-pub const PAYMENTSTREAMCOMPOUNDINGENDDATEUNADJUSTED: FixTag = FixTag { id: "42622", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTSTREAMCOMPOUNDINGENDDATERELATIVETO: FixTag = FixTag { id: "42623", datatype: value_types::INT};
-pub const PAYMENTSTREAMCOMPOUNDINGENDDATEOFFSETPERIOD: FixTag = FixTag { id: "42624", datatype: value_types::INT};
-pub const PAYMENTSTREAMCOMPOUNDINGENDDATEOFFSETUNIT: FixTag = FixTag { id: "42625", datatype: value_types::STRING};
-pub const PAYMENTSTREAMCOMPOUNDINGENDDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42626", datatype: value_types::INT};
-pub const PAYMENTSTREAMCOMPOUNDINGENDDATEADJUSTED: FixTag = FixTag { id: "42627", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTSTREAMCOMPOUNDINGRATEINDEX: FixTag = FixTag { id: "42628", datatype: value_types::STRING};
-pub const PAYMENTSTREAMCOMPOUNDINGRATEINDEXCURVEPERIOD: FixTag = FixTag { id: "42629", datatype: value_types::INT};
-pub const PAYMENTSTREAMCOMPOUNDINGRATEINDEXCURVEUNIT: FixTag = FixTag { id: "42630", datatype: value_types::STRING};
-pub const PAYMENTSTREAMCOMPOUNDINGRATEMULTIPLIER: FixTag = FixTag { id: "42631", datatype: value_types::FLOAT};
-pub const PAYMENTSTREAMCOMPOUNDINGRATESPREAD: FixTag = FixTag { id: "42632", datatype: value_types::PRICE_OFFSET};
-pub const PAYMENTSTREAMCOMPOUNDINGRATESPREADPOSITIONTYPE: FixTag = FixTag { id: "42633", datatype: value_types::INT};
-pub const PAYMENTSTREAMCOMPOUNDINGRATETREATMENT: FixTag = FixTag { id: "42634", datatype: value_types::INT};
-pub const PAYMENTSTREAMCOMPOUNDINGCAPRATE: FixTag = FixTag { id: "42635", datatype: value_types::PERCENTAGE};
-pub const PAYMENTSTREAMCOMPOUNDINGCAPRATEBUYSIDE: FixTag = FixTag { id: "42636", datatype: value_types::INT};
-pub const PAYMENTSTREAMCOMPOUNDINGCAPRATESELLSIDE: FixTag = FixTag { id: "42637", datatype: value_types::INT};
-pub const PAYMENTSTREAMCOMPOUNDINGFLOORRATE: FixTag = FixTag { id: "42638", datatype: value_types::PERCENTAGE};
-pub const PAYMENTSTREAMCOMPOUNDINGFLOORRATEBUYSIDE: FixTag = FixTag { id: "42639", datatype: value_types::INT};
-pub const PAYMENTSTREAMCOMPOUNDINGFLOORRATESELLSIDE: FixTag = FixTag { id: "42640", datatype: value_types::INT};
-pub const PAYMENTSTREAMCOMPOUNDINGINITIALRATE: FixTag = FixTag { id: "42641", datatype: value_types::PERCENTAGE};
-pub const PAYMENTSTREAMCOMPOUNDINGFINALRATEROUNDINGDIRECTION: FixTag = FixTag { id: "42642", datatype: value_types::CHAR};
-pub const PAYMENTSTREAMCOMPOUNDINGFINALRATEPRECISION: FixTag = FixTag { id: "42643", datatype: value_types::INT};
-pub const PAYMENTSTREAMCOMPOUNDINGAVERAGINGMETHOD: FixTag = FixTag { id: "42644", datatype: value_types::INT};
-pub const PAYMENTSTREAMCOMPOUNDINGNEGATIVERATETREATMENT: FixTag = FixTag { id: "42645", datatype: value_types::INT};
-pub const PAYMENTSTREAMCOMPOUNDINGSTARTDATEUNADJUSTED: FixTag = FixTag { id: "42646", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTSTREAMCOMPOUNDINGSTARTDATERELATIVETO: FixTag = FixTag { id: "42647", datatype: value_types::INT};
-pub const PAYMENTSTREAMCOMPOUNDINGSTARTDATEOFFSETPERIOD: FixTag = FixTag { id: "42648", datatype: value_types::INT};
-pub const PAYMENTSTREAMCOMPOUNDINGSTARTDATEOFFSETUNIT: FixTag = FixTag { id: "42649", datatype: value_types::STRING};
-pub const PAYMENTSTREAMCOMPOUNDINGSTARTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42650", datatype: value_types::INT};
-pub const PAYMENTSTREAMCOMPOUNDINGSTARTDATEADJUSTED: FixTag = FixTag { id: "42651", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTSTREAMFORMULAIMAGELENGTH: FixTag = FixTag { id: "42652", datatype: value_types::LENGTH};
-pub const PAYMENTSTREAMFORMULAIMAGE: FixTag = FixTag { id: "42653", datatype: value_types::DATA};
-pub const PAYMENTSTREAMFINALPRICEPAYMENTDATEUNADJUSTED: FixTag = FixTag { id: "42654", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTSTREAMFINALPRICEPAYMENTDATERELATIVETO: FixTag = FixTag { id: "42655", datatype: value_types::INT};
-pub const PAYMENTSTREAMFINALPRICEPAYMENTDATEOFFSETFPERIOD: FixTag = FixTag { id: "42656", datatype: value_types::INT};
-pub const PAYMENTSTREAMFINALPRICEPAYMENTDATEOFFSETUNIT: FixTag = FixTag { id: "42657", datatype: value_types::STRING};
-pub const PAYMENTSTREAMFINALPRICEPAYMENTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42658", datatype: value_types::INT};
-pub const PAYMENTSTREAMFINALPRICEPAYMENTDATEADJUSTED: FixTag = FixTag { id: "42659", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOPAYMENTSTREAMFIXINGDATES: FixTag = FixTag { id: "42660", datatype: value_types::NUM_IN_GROUP };
-pub const PAYMENTSTREAMFIXINGDATE: FixTag = FixTag { id: "42661", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTSTREAMFIXINGDATETYPE: FixTag = FixTag { id: "42662", datatype: value_types::INT};
-pub const PAYMENTSTREAMFIRSTOBSERVATIONDATEUNADJUSTED: FixTag = FixTag { id: "42663", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTSTREAMFIRSTOBSERVATIONDATERELATIVETO: FixTag = FixTag { id: "42664", datatype: value_types::INT};
-pub const PAYMENTSTREAMFIRSTOBSERVATIONDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42665", datatype: value_types::INT};
-pub const PAYMENTSTREAMFIRSTOBSERVATIONDATEADJUSTED: FixTag = FixTag { id: "42666", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTSTREAMUNDERLIERREFID: FixTag = FixTag { id: "42667", datatype: value_types::STRING};
-pub const RETURNRATENOTIONALRESET: FixTag = FixTag { id: "42668", datatype: value_types::BOOLEAN};
-pub const PAYMENTSTREAMLINKINITIALLEVEL: FixTag = FixTag { id: "42669", datatype: value_types::PRICE};
-pub const PAYMENTSTREAMLINKCLOSINGLEVELINDICATOR: FixTag = FixTag { id: "42670", datatype: value_types::BOOLEAN};
-pub const PAYMENTSTREAMLINKEXPIRINGLEVELINDICATOR: FixTag = FixTag { id: "42671", datatype: value_types::BOOLEAN};
-pub const PAYMENTSTREAMLINKESTIMATEDTRADINGDAYS: FixTag = FixTag { id: "42672", datatype: value_types::INT};
-pub const PAYMENTSTREAMLINKSTRIKEPRICE: FixTag = FixTag { id: "42673", datatype: value_types::PRICE};
-pub const PAYMENTSTREAMLINKSTRIKEPRICETYPE: FixTag = FixTag { id: "42674", datatype: value_types::INT};
-pub const PAYMENTSTREAMLINKMAXIMUMBOUNDARY: FixTag = FixTag { id: "42675", datatype: value_types::FLOAT};
-pub const PAYMENTSTREAMLINKMINIMUMBOUNDARY: FixTag = FixTag { id: "42676", datatype: value_types::FLOAT};
-pub const PAYMENTSTREAMLINKNUMBEROFDATASERIES: FixTag = FixTag { id: "42677", datatype: value_types::INT};
-pub const PAYMENTSTREAMVARIANCEUNADJUSTEDCAP: FixTag = FixTag { id: "42678", datatype: value_types::FLOAT};
-pub const PAYMENTSTREAMREALIZEDVARIANCEMETHOD: FixTag = FixTag { id: "42679", datatype: value_types::INT};
-pub const PAYMENTSTREAMDAYSADJUSTMENTINDICATOR: FixTag = FixTag { id: "42680", datatype: value_types::BOOLEAN};
-pub const PAYMENTSTREAMNEARESTEXCHANGECONTRACTREFID: FixTag = FixTag { id: "42681", datatype: value_types::STRING};
-pub const PAYMENTSTREAMVEGANOTIONALAMOUNT: FixTag = FixTag { id: "42682", datatype: value_types::FLOAT};
-pub const NOPAYMENTSTREAMFORMULAS: FixTag = FixTag { id: "42683", datatype: value_types::NUM_IN_GROUP };
-pub const PAYMENTSTREAMFORMULA: FixTag = FixTag { id: "42684", datatype: value_types::XML_DATA};
-pub const PAYMENTSTREAMFORMULADESC: FixTag = FixTag { id: "42685", datatype: value_types::STRING};
-pub const PAYMENTSTREAMFORMULACURRENCY: FixTag = FixTag { id: "42686", datatype: value_types::CURRENCY};
-pub const PAYMENTSTREAMFORMULACURRENCYDETERMINATIONMETHOD: FixTag = FixTag { id: "42687", datatype: value_types::STRING};
-pub const PAYMENTSTREAMFORMULAREFERENCEAMOUNT: FixTag = FixTag { id: "42688", datatype: value_types::INT};
-pub const PAYMENTSTUBENDDATEUNADJUSTED: FixTag = FixTag { id: "42689", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTSTUBENDDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42690", datatype: value_types::INT};
-pub const PAYMENTSTUBENDDATERELATIVETO: FixTag = FixTag { id: "42691", datatype: value_types::INT};
-pub const PAYMENTSTUBENDDATEOFFSETPERIOD: FixTag = FixTag { id: "42692", datatype: value_types::INT};
-pub const PAYMENTSTUBENDDATEOFFSETUNIT: FixTag = FixTag { id: "42693", datatype: value_types::STRING};
-pub const PAYMENTSTUBENDDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42694", datatype: value_types::INT};
-pub const PAYMENTSTUBENDDATEADJUSTED: FixTag = FixTag { id: "42695", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOPAYMENTSTUBENDDATEBUSINESSCENTERS: FixTag = FixTag { id: "42696", datatype: value_types::NUM_IN_GROUP };
-pub const PAYMENTSTUBENDDATEBUSINESSCENTER: FixTag = FixTag { id: "42697", datatype: value_types::STRING};
-pub const PAYMENTSTUBSTARTDATEUNADJUSTED: FixTag = FixTag { id: "42698", datatype: value_types::LOCAL_MKT_DATE};
-pub const PAYMENTSTUBSTARTDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42699", datatype: value_types::INT};
-pub const PAYMENTSTUBSTARTDATERELATIVETO: FixTag = FixTag { id: "42700", datatype: value_types::INT};
-pub const PAYMENTSTUBSTARTDATEOFFSETPERIOD: FixTag = FixTag { id: "42701", datatype: value_types::INT};
-pub const PAYMENTSTUBSTARTDATEOFFSETUNIT: FixTag = FixTag { id: "42702", datatype: value_types::STRING};
-pub const PAYMENTSTUBSTARTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42703", datatype: value_types::INT};
-pub const PAYMENTSTUBSTARTDATEADJUSTED: FixTag = FixTag { id: "42704", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOPAYMENTSTUBSTARTDATEBUSINESSCENTERS: FixTag = FixTag { id: "42705", datatype: value_types::NUM_IN_GROUP };
-pub const PAYMENTSTUBSTARTDATEBUSINESSCENTER: FixTag = FixTag { id: "42706", datatype: value_types::STRING};
-pub const PROVISIONBREAKFEEELECTION: FixTag = FixTag { id: "42707", datatype: value_types::INT};
-pub const PROVISIONBREAKFEERATE: FixTag = FixTag { id: "42708", datatype: value_types::PERCENTAGE};
-pub const NORETURNRATEDATES: FixTag = FixTag { id: "42709", datatype: value_types::NUM_IN_GROUP };
-pub const RETURNRATEDATEMODE: FixTag = FixTag { id: "42710", datatype: value_types::INT};
-pub const RETURNRATEVALUATIONDATERELATIVETO: FixTag = FixTag { id: "42711", datatype: value_types::INT};
-pub const RETURNRATEVALUATIONDATEOFFSETPERIOD: FixTag = FixTag { id: "42712", datatype: value_types::INT};
-pub const RETURNRATEVALUATIONDATEOFFSETUNIT: FixTag = FixTag { id: "42713", datatype: value_types::STRING};
-pub const RETURNRATEVALUATIONDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42714", datatype: value_types::INT};
-pub const RETURNRATEVALUATIONSTARTDATEUNADJUSTED: FixTag = FixTag { id: "42715", datatype: value_types::LOCAL_MKT_DATE};
-pub const RETURNRATEVALUATIONSTARTDATERELATIVETO: FixTag = FixTag { id: "42716", datatype: value_types::INT};
-pub const RETURNRATEVALUATIONSTARTDATEOFFSETPERIOD: FixTag = FixTag { id: "42717", datatype: value_types::INT};
-pub const RETURNRATEVALUATIONSTARTDATEOFFSETUNIT: FixTag = FixTag { id: "42718", datatype: value_types::STRING};
-pub const RETURNRATEVALUATIONSTARTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42719", datatype: value_types::INT};
-pub const RETURNRATEVALUATIONSTARTDATEADJUSTED: FixTag = FixTag { id: "42720", datatype: value_types::LOCAL_MKT_DATE};
-pub const RETURNRATEVALUATIONENDDATEUNADJUSTED: FixTag = FixTag { id: "42721", datatype: value_types::LOCAL_MKT_DATE};
-pub const RETURNRATEVALUATIONENDDATERELATIVETO: FixTag = FixTag { id: "42722", datatype: value_types::INT};
-pub const RETURNRATEVALUATIONENDDATEOFFSETPERIOD: FixTag = FixTag { id: "42723", datatype: value_types::INT};
-pub const RETURNRATEVALUATIONENDDATEOFFSETUNIT: FixTag = FixTag { id: "42724", datatype: value_types::STRING};
-pub const RETURNRATEVALUATIONENDDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42725", datatype: value_types::INT};
-pub const RETURNRATEVALUATIONENDDATEADJUSTED: FixTag = FixTag { id: "42726", datatype: value_types::LOCAL_MKT_DATE};
-pub const RETURNRATEVALUATIONFREQUENCYPERIOD: FixTag = FixTag { id: "42727", datatype: value_types::INT};
-pub const RETURNRATEVALUATIONFREQUENCYUNIT: FixTag = FixTag { id: "42728", datatype: value_types::STRING};
-pub const RETURNRATEVALUATIONFREQUENCYROLLCONVENTION: FixTag = FixTag { id: "42729", datatype: value_types::STRING};
-pub const RETURNRATEVALUATIONDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42730", datatype: value_types::INT};
-pub const NORETURNRATEFXCONVERSIONS: FixTag = FixTag { id: "42731", datatype: value_types::NUM_IN_GROUP };
-pub const RETURNRATEFXCURRENCYSYMBOL: FixTag = FixTag { id: "42732", datatype: value_types::STRING};
-pub const RETURNRATEFXRATE: FixTag = FixTag { id: "42733", datatype: value_types::FLOAT};
-pub const RETURNRATEFXRATECALC: FixTag = FixTag { id: "42734", datatype: value_types::CHAR};
-pub const NORETURNRATES: FixTag = FixTag { id: "42735", datatype: value_types::NUM_IN_GROUP };
-pub const RETURNRATEPRICESEQUENCE: FixTag = FixTag { id: "42736", datatype: value_types::INT};
-pub const RETURNRATECOMMISSIONBASIS: FixTag = FixTag { id: "42737", datatype: value_types::CHAR};
-pub const RETURNRATECOMMISSIONAMOUNT: FixTag = FixTag { id: "42738", datatype: value_types::AMT};
-pub const RETURNRATECOMMISSIONCURRENCY: FixTag = FixTag { id: "42739", datatype: value_types::CURRENCY};
-pub const RETURNRATETOTALCOMMISSIONPERTRADE: FixTag = FixTag { id: "42740", datatype: value_types::AMT};
-pub const RETURNRATEDETERMINATIONMETHOD: FixTag = FixTag { id: "42741", datatype: value_types::STRING};
-pub const RETURNRATEAMOUNTRELATIVETO: FixTag = FixTag { id: "42742", datatype: value_types::INT};
-pub const RETURNRATEQUOTEMEASURETYPE: FixTag = FixTag { id: "42743", datatype: value_types::STRING};
-pub const RETURNRATEQUOTEUNITS: FixTag = FixTag { id: "42744", datatype: value_types::STRING};
-pub const RETURNRATEQUOTEMETHOD: FixTag = FixTag { id: "42745", datatype: value_types::INT};
-pub const RETURNRATEQUOTECURRENCY: FixTag = FixTag { id: "42746", datatype: value_types::CURRENCY};
-pub const RETURNRATEQUOTECURRENCYTYPE: FixTag = FixTag { id: "42747", datatype: value_types::STRING};
-pub const RETURNRATEQUOTETIMETYPE: FixTag = FixTag { id: "42748", datatype: value_types::INT};
-pub const RETURNRATEQUOTETIME: FixTag = FixTag { id: "42749", datatype: value_types::LOCAL_MKT_TIME};
-pub const RETURNRATEQUOTEDATE: FixTag = FixTag { id: "42750", datatype: value_types::LOCAL_MKT_DATE};
-pub const RETURNRATEQUOTEEXPIRATIONTIME: FixTag = FixTag { id: "42751", datatype: value_types::LOCAL_MKT_TIME};
-pub const RETURNRATEQUOTEBUSINESSCENTER: FixTag = FixTag { id: "42752", datatype: value_types::STRING};
-pub const RETURNRATEQUOTEEXCHANGE: FixTag = FixTag { id: "42753", datatype: value_types::EXCHANGE};
-pub const RETURNRATEQUOTEPRICINGMODEL: FixTag = FixTag { id: "42754", datatype: value_types::STRING};
-pub const RETURNRATECASHFLOWTYPE: FixTag = FixTag { id: "42755", datatype: value_types::STRING};
-pub const RETURNRATEVALUATIONTIMETYPE: FixTag = FixTag { id: "42756", datatype: value_types::INT};
-pub const RETURNRATEVALUATIONTIME: FixTag = FixTag { id: "42757", datatype: value_types::LOCAL_MKT_TIME};
-pub const RETURNRATEVALUATIONTIMEBUSINESSCENTER: FixTag = FixTag { id: "42758", datatype: value_types::STRING};
-pub const RETURNRATEVALUATIONPRICEOPTION: FixTag = FixTag { id: "42759", datatype: value_types::INT};
-pub const RETURNRATEFINALPRICEFALLBACK: FixTag = FixTag { id: "42760", datatype: value_types::INT};
-pub const NORETURNRATEINFORMATIONSOURCES: FixTag = FixTag { id: "42761", datatype: value_types::NUM_IN_GROUP };
-pub const RETURNRATEINFORMATIONSOURCE: FixTag = FixTag { id: "42762", datatype: value_types::INT};
-pub const RETURNRATEREFERENCEPAGE: FixTag = FixTag { id: "42763", datatype: value_types::STRING};
-pub const RETURNRATEREFERENCEPAGEHEADING: FixTag = FixTag { id: "42764", datatype: value_types::STRING};
-pub const NORETURNRATEPRICES: FixTag = FixTag { id: "42765", datatype: value_types::NUM_IN_GROUP };
-pub const RETURNRATEPRICEBASIS: FixTag = FixTag { id: "42766", datatype: value_types::INT};
-pub const RETURNRATEPRICE: FixTag = FixTag { id: "42767", datatype: value_types::PRICE};
-pub const RETURNRATEPRICECURRENCY: FixTag = FixTag { id: "42768", datatype: value_types::CURRENCY};
-pub const RETURNRATEPRICETYPE: FixTag = FixTag { id: "42769", datatype: value_types::INT};
-pub const NORETURNRATEVALUATIONDATEBUSINESSCENTERS: FixTag = FixTag { id: "42770", datatype: value_types::NUM_IN_GROUP };
-pub const RETURNRATEVALUATIONDATEBUSINESSCENTER: FixTag = FixTag { id: "42771", datatype: value_types::STRING};
-pub const NORETURNRATEVALUATIONDATES: FixTag = FixTag { id: "42772", datatype: value_types::NUM_IN_GROUP };
-pub const RETURNRATEVALUATIONDATE: FixTag = FixTag { id: "42773", datatype: value_types::LOCAL_MKT_DATE};
-pub const RETURNRATEVALUATIONDATETYPE: FixTag = FixTag { id: "42774", datatype: value_types::INT};
-pub const NOSETTLMETHODELECTIONDATEBUSINESSCENTERS: FixTag = FixTag { id: "42775", datatype: value_types::NUM_IN_GROUP };
-pub const SETTLMETHODELECTIONDATEBUSINESSCENTER: FixTag = FixTag { id: "42776", datatype: value_types::STRING};
-pub const SETTLMETHODELECTIONDATEUNADJUSTED: FixTag = FixTag { id: "42777", datatype: value_types::LOCAL_MKT_DATE};
-pub const SETTLMETHODELECTIONDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42778", datatype: value_types::INT};
-pub const SETTLMETHODELECTIONDATERELATIVETO: FixTag = FixTag { id: "42779", datatype: value_types::INT};
-pub const SETTLMETHODELECTIONDATEOFFSETPERIOD: FixTag = FixTag { id: "42780", datatype: value_types::INT};
-pub const SETTLMETHODELECTIONDATEOFFSETUNIT: FixTag = FixTag { id: "42781", datatype: value_types::STRING};
-pub const SETTLMETHODELECTIONDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42782", datatype: value_types::INT};
-pub const SETTLMETHODELECTIONDATEADJUSTED: FixTag = FixTag { id: "42783", datatype: value_types::LOCAL_MKT_DATE};
-pub const STREAMVERSION: FixTag = FixTag { id: "42784", datatype: value_types::STRING};
-pub const STREAMVERSIONEFFECTIVEDATE: FixTag = FixTag { id: "42785", datatype: value_types::LOCAL_MKT_DATE};
-pub const STREAMNOTIONALDETERMINATIONMETHOD: FixTag = FixTag { id: "42786", datatype: value_types::STRING};
-pub const STREAMNOTIONALADJUSTMENTS: FixTag = FixTag { id: "42787", datatype: value_types::INT};
-pub const NOUNDERLYINGCASHSETTLDATEBUSINESSCENTERS: FixTag = FixTag { id: "42788", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGCASHSETTLDATEBUSINESSCENTER: FixTag = FixTag { id: "42789", datatype: value_types::STRING};
-pub const UNDERLYINGCASHSETTLDATEUNADJUSTED: FixTag = FixTag { id: "42790", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGCASHSETTLDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42791", datatype: value_types::INT};
-pub const UNDERLYINGCASHSETTLDATERELATIVETO: FixTag = FixTag { id: "42792", datatype: value_types::INT};
-pub const UNDERLYINGCASHSETTLDATEOFFSETPERIOD: FixTag = FixTag { id: "42793", datatype: value_types::INT};
-pub const UNDERLYINGCASHSETTLDATEOFFSETUNIT: FixTag = FixTag { id: "42794", datatype: value_types::STRING};
-pub const UNDERLYINGCASHSETTLDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42795", datatype: value_types::INT};
-pub const UNDERLYINGCASHSETTLDATEADJUSTED: FixTag = FixTag { id: "42796", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGCASHSETTLPRICESOURCE: FixTag = FixTag { id: "42797", datatype: value_types::STRING};
-pub const UNDERLYINGCASHSETTLPRICEDEFAULT: FixTag = FixTag { id: "42798", datatype: value_types::INT};
-pub const NOUNDERLYINGDIVIDENDACCRUALPAYMENTDATEBUSINESSCENTERS: FixTag = FixTag { id: "42799", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGDIVIDENDACCRUALPAYMENTDATEBUSINESSCENTER: FixTag = FixTag { id: "42800", datatype: value_types::STRING};
-pub const UNDERLYINGDIVIDENDFLOATINGRATEINDEX: FixTag = FixTag { id: "42801", datatype: value_types::STRING};
-pub const UNDERLYINGDIVIDENDFLOATINGRATEINDEXCURVEPERIOD: FixTag = FixTag { id: "42802", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDFLOATINGRATEINDEXCURVEUNIT: FixTag = FixTag { id: "42803", datatype: value_types::STRING};
-pub const UNDERLYINGDIVIDENDFLOATINGRATEMULTIPLIER: FixTag = FixTag { id: "42804", datatype: value_types::FLOAT};
-pub const UNDERLYINGDIVIDENDFLOATINGRATESPREAD: FixTag = FixTag { id: "42805", datatype: value_types::PRICE_OFFSET};
-pub const UNDERLYINGDIVIDENDFLOATINGRATESPREADPOSITIONTYPE: FixTag = FixTag { id: "42806", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDFLOATINGRATETREATMENT: FixTag = FixTag { id: "42807", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDCAPRATE: FixTag = FixTag { id: "42808", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGDIVIDENDCAPRATEBUYSIDE: FixTag = FixTag { id: "42809", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDCAPRATESELLSIDE: FixTag = FixTag { id: "42810", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDFLOORRATE: FixTag = FixTag { id: "42811", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGDIVIDENDFLOORRATEBUYSIDE: FixTag = FixTag { id: "42812", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDFLOORRATESELLSIDE: FixTag = FixTag { id: "42813", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDINITIALRATE: FixTag = FixTag { id: "42814", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGDIVIDENDFINALRATEROUNDINGDIRECTION: FixTag = FixTag { id: "42815", datatype: value_types::CHAR};
-pub const UNDERLYINGDIVIDENDFINALRATEPRECISION: FixTag = FixTag { id: "42816", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDAVERAGINGMETHOD: FixTag = FixTag { id: "42817", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDNEGATIVERATETREATMENT: FixTag = FixTag { id: "42818", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDACCRUALPAYMENTDATERELATIVETO: FixTag = FixTag { id: "42819", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDACCRUALPAYMENTDATEOFFSETPERIOD: FixTag = FixTag { id: "42820", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDACCRUALPAYMENTDATEOFFSETUNIT: FixTag = FixTag { id: "42821", datatype: value_types::STRING};
-pub const UNDERLYINGDIVIDENDACCRUALPAYMENTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42822", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDACCRUALPAYMENTDATEUNADJUSTED: FixTag = FixTag { id: "42823", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGDIVIDENDACCRUALPAYMENTDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42824", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDACCRUALPAYMENTDATEADJUSTED: FixTag = FixTag { id: "42825", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGDIVIDENDREINVESTMENTINDICATOR: FixTag = FixTag { id: "42826", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGDIVIDENDENTITLEMENTEVENT: FixTag = FixTag { id: "42827", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDAMOUNTTYPE: FixTag = FixTag { id: "42828", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDUNDERLIERREFID: FixTag = FixTag { id: "42829", datatype: value_types::STRING};
-pub const UNDERLYINGEXTRAORDINARYDIVIDENDPARTYSIDE: FixTag = FixTag { id: "42830", datatype: value_types::INT};
-pub const UNDERLYINGEXTRAORDINARYDIVIDENDAMOUNTTYPE: FixTag = FixTag { id: "42831", datatype: value_types::INT};
-pub const UNDERLYINGEXTRAORDINARYDIVIDENDCURRENCY: FixTag = FixTag { id: "42832", datatype: value_types::CURRENCY};
-pub const UNDERLYINGEXTRAORDINARYDIVIDENDDETERMINATIONMETHOD: FixTag = FixTag { id: "42833", datatype: value_types::STRING};
-pub const UNDERLYINGDIVIDENDACCRUALFIXEDRATE: FixTag = FixTag { id: "42834", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGDIVIDENDCOMPOUNDINGMETHOD: FixTag = FixTag { id: "42835", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDNUMOFINDEXUNITS: FixTag = FixTag { id: "42836", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDCASHPERCENTAGE: FixTag = FixTag { id: "42837", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGDIVIDENDCASHEQUIVALENTPERCENTAGE: FixTag = FixTag { id: "42838", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGNONCASHDIVIDENDTREATMENT: FixTag = FixTag { id: "42839", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDCOMPOSITION: FixTag = FixTag { id: "42840", datatype: value_types::INT};
-pub const UNDERLYINGSPECIALDIVIDENDSINDICATOR: FixTag = FixTag { id: "42841", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGMATERIALDIVIDENDSINDICATOR: FixTag = FixTag { id: "42842", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGOPTIONSEXCHANGEDIVIDENDSINDICATOR: FixTag = FixTag { id: "42843", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGADDITIONALDIVIDENDSINDICATOR: FixTag = FixTag { id: "42844", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGALLDIVIDENDSINDICATOR: FixTag = FixTag { id: "42845", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGDIVIDENDFXTRIGGERDATERELATIVETO: FixTag = FixTag { id: "42846", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDFXTRIGGERDATEOFFSETPERIOD: FixTag = FixTag { id: "42847", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDFXTRIGGERDATEOFFSETUNIT: FixTag = FixTag { id: "42848", datatype: value_types::STRING};
-pub const UNDERLYINGDIVIDENDFXTRIGGERDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42849", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDFXTRIGGERDATEUNADJUSTED: FixTag = FixTag { id: "42850", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGDIVIDENDFXTRIGGERDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42851", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDFXTRIGGERDATEADJUSTED: FixTag = FixTag { id: "42852", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOUNDERLYINGDIVIDENDFXTRIGGERDATEBUSINESSCENTERS: FixTag = FixTag { id: "42853", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGDIVIDENDFXTRIGGERDATEBUSINESSCENTER: FixTag = FixTag { id: "42854", datatype: value_types::STRING};
-pub const NOUNDERLYINGDIVIDENDPAYMENTS: FixTag = FixTag { id: "42855", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGDIVIDENDPAYMENTDATE: FixTag = FixTag { id: "42856", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGDIVIDENDPAYMENTAMOUNT: FixTag = FixTag { id: "42857", datatype: value_types::AMT};
-pub const UNDERLYINGDIVIDENDPAYMENTCURRENCY: FixTag = FixTag { id: "42858", datatype: value_types::CURRENCY};
-pub const UNDERLYINGDIVIDENDACCRUEDINTEREST: FixTag = FixTag { id: "42859", datatype: value_types::AMT};
-pub const UNDERLYINGDIVIDENDPAYOUTRATIO: FixTag = FixTag { id: "42860", datatype: value_types::FLOAT};
-pub const UNDERLYINGDIVIDENDPAYOUTCONDITIONS: FixTag = FixTag { id: "42861", datatype: value_types::STRING};
-pub const NOUNDERLYINGDIVIDENDPERIODS: FixTag = FixTag { id: "42862", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGDIVIDENDPERIODSEQUENCE: FixTag = FixTag { id: "42863", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDPERIODSTARTDATEUNADJUSTED: FixTag = FixTag { id: "42864", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGDIVIDENDPERIODENDDATEUNADJUSTED: FixTag = FixTag { id: "42865", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGDIVIDENDPERIODUNDERLIERREFID: FixTag = FixTag { id: "42866", datatype: value_types::STRING};
-pub const UNDERLYINGDIVIDENDPERIODSTRIKEPRICE: FixTag = FixTag { id: "42867", datatype: value_types::PRICE};
-pub const UNDERLYINGDIVIDENDPERIODBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42868", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDPERIODVALUATIONDATEUNADJUSTED: FixTag = FixTag { id: "42869", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGDIVIDENDPERIODVALUATIONDATERELATIVETO: FixTag = FixTag { id: "42870", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDPERIODVALUATIONDATEOFFSETPERIOD: FixTag = FixTag { id: "42871", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDPERIODVALUATIONDATEOFFSETUNIT: FixTag = FixTag { id: "42872", datatype: value_types::STRING};
-pub const UNDERLYINGDIVIDENDPERIODVALUATIONDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42873", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDPERIODVALUATIONDATEADJUSTED: FixTag = FixTag { id: "42874", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGDIVIDENDPERIODPAYMENTDATEUNADJUSTED: FixTag = FixTag { id: "42875", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGDIVIDENDPERIODPAYMENTDATERELATIVETO: FixTag = FixTag { id: "42876", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDPERIODPAYMENTDATEOFFSETPERIOD: FixTag = FixTag { id: "42877", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDPERIODPAYMENTDATEOFFSETUNIT: FixTag = FixTag { id: "42878", datatype: value_types::STRING};
-pub const UNDERLYINGDIVIDENDPERIODPAYMENTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42879", datatype: value_types::INT};
-pub const UNDERLYINGDIVIDENDPERIODPAYMENTDATEADJUSTED: FixTag = FixTag { id: "42880", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGDIVIDENDPERIODXID: FixTag = FixTag { id: "42881", datatype: value_types::XID};
-pub const NOUNDERLYINGDIVIDENDPERIODBUSINESSCENTERS: FixTag = FixTag { id: "42882", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGDIVIDENDPERIODBUSINESSCENTER: FixTag = FixTag { id: "42883", datatype: value_types::STRING};
-pub const NOUNDERLYINGEXTRAORDINARYEVENTS: FixTag = FixTag { id: "42884", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGEXTRAORDINARYEVENTTYPE: FixTag = FixTag { id: "42885", datatype: value_types::STRING};
-pub const UNDERLYINGEXTRAORDINARYEVENTVALUE: FixTag = FixTag { id: "42886", datatype: value_types::STRING};
-pub const UNDERLYINGSETTLMETHODELECTINGPARTYSIDE: FixTag = FixTag { id: "42887", datatype: value_types::INT};
-pub const UNDERLYINGMAKEWHOLEDATE: FixTag = FixTag { id: "42888", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGMAKEWHOLEAMOUNT: FixTag = FixTag { id: "42889", datatype: value_types::AMT};
-pub const UNDERLYINGMAKEWHOLEBENCHMARKCURVENAME: FixTag = FixTag { id: "42890", datatype: value_types::STRING};
-pub const UNDERLYINGMAKEWHOLEBENCHMARKCURVEPOINT: FixTag = FixTag { id: "42891", datatype: value_types::STRING};
-pub const UNDERLYINGMAKEWHOLERECALLSPREAD: FixTag = FixTag { id: "42892", datatype: value_types::PRICE_OFFSET};
-pub const UNDERLYINGMAKEWHOLEBENCHMARKQUOTE: FixTag = FixTag { id: "42893", datatype: value_types::INT};
-pub const UNDERLYINGMAKEWHOLEINTERPOLATIONMETHOD: FixTag = FixTag { id: "42894", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCASHSETTLINDICATOR: FixTag = FixTag { id: "42895", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGXIDREF: FixTag = FixTag { id: "42896", datatype: value_types::XIDREF};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGSPREAD: FixTag = FixTag { id: "42897", datatype: value_types::PRICE_OFFSET};
-pub const UNDERLYINGPAYMENTSTREAMINTERPOLATIONMETHOD: FixTag = FixTag { id: "42898", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMINTERPOLATIONPERIOD: FixTag = FixTag { id: "42899", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGFIXEDRATE: FixTag = FixTag { id: "42900", datatype: value_types::FLOAT};
-pub const NOUNDERLYINGPAYMENTSTREAMCOMPOUNDINGDATES: FixTag = FixTag { id: "42901", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGDATE: FixTag = FixTag { id: "42902", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGDATETYPE: FixTag = FixTag { id: "42903", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGDATESBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42904", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGDATESRELATIVETO: FixTag = FixTag { id: "42905", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGDATESOFFSETPERIOD: FixTag = FixTag { id: "42906", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGDATESOFFSETUNIT: FixTag = FixTag { id: "42907", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGDATESOFFSETDAYTYPE: FixTag = FixTag { id: "42908", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGPERIODSKIP: FixTag = FixTag { id: "42909", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGFREQUENCYPERIOD: FixTag = FixTag { id: "42910", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGFREQUENCYUNIT: FixTag = FixTag { id: "42911", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGROLLCONVENTION: FixTag = FixTag { id: "42912", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMBOUNDSFIRSTDATEUNADJUSTED: FixTag = FixTag { id: "42913", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPAYMENTSTREAMBOUNDSLASTDATEUNADJUSTED: FixTag = FixTag { id: "42914", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOUNDERLYINGPAYMENTSTREAMCOMPOUNDINGDATESBUSINESSCENTERS: FixTag = FixTag { id: "42915", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGDATESBUSINESSCENTER: FixTag = FixTag { id: "42916", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGENDDATEUNADJUSTED: FixTag = FixTag { id: "42917", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGENDDATERELATIVETO: FixTag = FixTag { id: "42918", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGENDDATEOFFSETPERIOD: FixTag = FixTag { id: "42919", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGENDDATEOFFSETUNIT: FixTag = FixTag { id: "42920", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGENDDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42921", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGENDDATEADJUSTED: FixTag = FixTag { id: "42922", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGRATEINDEX: FixTag = FixTag { id: "42923", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGRATEINDEXCURVEPERIOD: FixTag = FixTag { id: "42924", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGRATEINDEXCURVEUNIT: FixTag = FixTag { id: "42925", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGRATEMULTIPLIER: FixTag = FixTag { id: "42926", datatype: value_types::FLOAT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGRATESPREAD: FixTag = FixTag { id: "42927", datatype: value_types::PRICE_OFFSET};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGRATESPREADPOSITIONTYPE: FixTag = FixTag { id: "42928", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGRATETREATMENT: FixTag = FixTag { id: "42929", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGCAPRATE: FixTag = FixTag { id: "42930", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGCAPRATEBUYSIDE: FixTag = FixTag { id: "42931", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGCAPRATESELLSIDE: FixTag = FixTag { id: "42932", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGFLOORRATE: FixTag = FixTag { id: "42933", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGFLOORRATEBUYSIDE: FixTag = FixTag { id: "42934", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGFLOORRATESELLSIDE: FixTag = FixTag { id: "42935", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGINITIALRATE: FixTag = FixTag { id: "42936", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGFINALRATEROUNDINGDIRECTION: FixTag = FixTag { id: "42937", datatype: value_types::CHAR};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGFINALRATEPRECISION: FixTag = FixTag { id: "42938", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGAVERAGINGMETHOD: FixTag = FixTag { id: "42939", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGNEGATIVERATETREATMENT: FixTag = FixTag { id: "42940", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGSTARTDATEUNADJUSTED: FixTag = FixTag { id: "42941", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGSTARTDATERELATIVETO: FixTag = FixTag { id: "42942", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGSTARTDATEOFFSETPERIOD: FixTag = FixTag { id: "42943", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGSTARTDATEOFFSETUNIT: FixTag = FixTag { id: "42944", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGSTARTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42945", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMCOMPOUNDINGSTARTDATEADJUSTED: FixTag = FixTag { id: "42946", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPAYMENTSTREAMFORMULAIMAGELENGTH: FixTag = FixTag { id: "42947", datatype: value_types::LENGTH};
-pub const UNDERLYINGPAYMENTSTREAMFORMULAIMAGE: FixTag = FixTag { id: "42948", datatype: value_types::DATA};
-pub const UNDERLYINGPAYMENTSTREAMFINALPRICEPAYMENTDATEUNADJUSTED: FixTag = FixTag { id: "42949", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPAYMENTSTREAMFINALPRICEPAYMENTDATERELATIVETO: FixTag = FixTag { id: "42950", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMFINALPRICEPAYMENTDATEOFFSETPERIOD: FixTag = FixTag { id: "42951", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMFINALPRICEPAYMENTDATEOFFSETUNIT: FixTag = FixTag { id: "42952", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMFINALPRICEPAYMENTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42953", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMFINALPRICEPAYMENTDATEADJUSTED: FixTag = FixTag { id: "42954", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOUNDERLYINGPAYMENTSTREAMFIXINGDATES: FixTag = FixTag { id: "42955", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPAYMENTSTREAMFIXINGDATE: FixTag = FixTag { id: "42956", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPAYMENTSTREAMFIXINGDATETYPE: FixTag = FixTag { id: "42957", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMFIRSTOBSERVATIONDATEUNADJUSTED: FixTag = FixTag { id: "42958", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPAYMENTSTREAMFIRSTOBSERVATIONDATERELATIVETO: FixTag = FixTag { id: "42959", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMFIRSTOBSERVATIONDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42960", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMFIRSTOBSERVATIONDATEADJUSTED: FixTag = FixTag { id: "42961", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPAYMENTSTREAMUNDERLIERREFID: FixTag = FixTag { id: "42962", datatype: value_types::STRING};
-pub const UNDERLYINGRETURNRATENOTIONALRESET: FixTag = FixTag { id: "42963", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGPAYMENTSTREAMLINKINITIALLEVEL: FixTag = FixTag { id: "42964", datatype: value_types::PRICE};
-pub const UNDERLYINGPAYMENTSTREAMLINKCLOSINGLEVELINDICATOR: FixTag = FixTag { id: "42965", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGPAYMENTSTREAMLINKEXPIRINGLEVELINDICATOR: FixTag = FixTag { id: "42966", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGPAYMENTSTREAMLINKESTIMATEDTRADINGDAYS: FixTag = FixTag { id: "42967", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMLINKSTRIKEPRICE: FixTag = FixTag { id: "42968", datatype: value_types::PRICE};
-pub const UNDERLYINGPAYMENTSTREAMLINKSTRIKEPRICETYPE: FixTag = FixTag { id: "42969", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMLINKMAXIMUMBOUNDARY: FixTag = FixTag { id: "42970", datatype: value_types::FLOAT};
-pub const UNDERLYINGPAYMENTSTREAMLINKMINIMUMBOUNDARY: FixTag = FixTag { id: "42971", datatype: value_types::FLOAT};
-pub const UNDERLYINGPAYMENTSTREAMLINKNUMBEROFDATASERIES: FixTag = FixTag { id: "42972", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMVARIANCEUNADJUSTEDCAP: FixTag = FixTag { id: "42973", datatype: value_types::FLOAT};
-pub const UNDERLYINGPAYMENTSTREAMREALIZEDVARIANCEMETHOD: FixTag = FixTag { id: "42974", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMDAYSADJUSTMENTINDICATOR: FixTag = FixTag { id: "42975", datatype: value_types::BOOLEAN};
-pub const UNDERLYINGPAYMENTSTREAMNEARESTEXCHANGECONTRACTREFID: FixTag = FixTag { id: "42976", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMVEGANOTIONALAMOUNT: FixTag = FixTag { id: "42977", datatype: value_types::FLOAT};
-pub const UNDERLYINGPAYMENTSTREAMFORMULACURRENCY: FixTag = FixTag { id: "42978", datatype: value_types::CURRENCY};
-pub const UNDERLYINGPAYMENTSTREAMFORMULACURRENCYDETERMINATIONMETHOD: FixTag = FixTag { id: "42979", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMFORMULAREFERENCEAMOUNT: FixTag = FixTag { id: "42980", datatype: value_types::INT};
-pub const NOUNDERLYINGPAYMENTSTREAMFORMULAS: FixTag = FixTag { id: "42981", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPAYMENTSTREAMFORMULA: FixTag = FixTag { id: "42982", datatype: value_types::XML_DATA};
-pub const UNDERLYINGPAYMENTSTREAMFORMULADESC: FixTag = FixTag { id: "42983", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTUBENDDATEUNADJUSTED: FixTag = FixTag { id: "42984", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPAYMENTSTUBENDDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42985", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTUBENDDATERELATIVETO: FixTag = FixTag { id: "42986", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTUBENDDATEOFFSETPERIOD: FixTag = FixTag { id: "42987", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTUBENDDATEOFFSETUNIT: FixTag = FixTag { id: "42988", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTUBENDDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42989", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTUBENDDATEADJUSTED: FixTag = FixTag { id: "42990", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOUNDERLYINGPAYMENTSTUBENDDATEBUSINESSCENTERS: FixTag = FixTag { id: "42991", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPAYMENTSTUBENDDATEBUSINESSCENTER: FixTag = FixTag { id: "42992", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTUBSTARTDATEUNADJUSTED: FixTag = FixTag { id: "42993", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGPAYMENTSTUBSTARTDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "42994", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTUBSTARTDATERELATIVETO: FixTag = FixTag { id: "42995", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTUBSTARTDATEOFFSETPERIOD: FixTag = FixTag { id: "42996", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTUBSTARTDATEOFFSETUNIT: FixTag = FixTag { id: "42997", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTUBSTARTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "42998", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTUBSTARTDATEADJUSTED: FixTag = FixTag { id: "42999", datatype: value_types::LOCAL_MKT_DATE};
-pub const NOUNDERLYINGPAYMENTSTUBSTARTDATEBUSINESSCENTERS: FixTag = FixTag { id: "43000", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGPAYMENTSTUBSTARTDATEBUSINESSCENTER: FixTag = FixTag { id: "43001", datatype: value_types::STRING};
-pub const UNDERLYINGPROVISIONBREAKFEEELECTION: FixTag = FixTag { id: "43002", datatype: value_types::INT};
-pub const UNDERLYINGPROVISIONBREAKFEERATE: FixTag = FixTag { id: "43003", datatype: value_types::PERCENTAGE};
-pub const UNDERLYINGRATESPREADINITIALVALUE: FixTag = FixTag { id: "43004", datatype: value_types::FLOAT};
-pub const NOUNDERLYINGRATESPREADSTEPS: FixTag = FixTag { id: "43005", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGRATESPREADSTEPDATE: FixTag = FixTag { id: "43006", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGRATESPREADSTEPVALUE: FixTag = FixTag { id: "43007", datatype: value_types::FLOAT};
-pub const NOUNDERLYINGRETURNRATEDATES: FixTag = FixTag { id: "43008", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGRETURNRATEDATEMODE: FixTag = FixTag { id: "43009", datatype: value_types::INT};
-pub const UNDERLYINGRETURNRATEVALUATIONDATERELATIVETO: FixTag = FixTag { id: "43010", datatype: value_types::INT};
-pub const UNDERLYINGRETURNRATEVALUATIONDATEOFFSETPERIOD: FixTag = FixTag { id: "43011", datatype: value_types::INT};
-pub const UNDERLYINGRETURNRATEVALUATIONDATEOFFSETUNIT: FixTag = FixTag { id: "43012", datatype: value_types::STRING};
-pub const UNDERLYINGRETURNRATEVALUATIONDATEOFFSETDAYTYPE: FixTag = FixTag { id: "43013", datatype: value_types::INT};
-pub const UNDERLYINGRETURNRATEVALUATIONSTARTDATEUNADJUSTED: FixTag = FixTag { id: "43014", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGRETURNRATEVALUATIONSTARTDATERELATIVETO: FixTag = FixTag { id: "43015", datatype: value_types::INT};
-pub const UNDERLYINGRETURNRATEVALUATIONSTARTDATEOFFSETPERIOD: FixTag = FixTag { id: "43016", datatype: value_types::INT};
-pub const UNDERLYINGRETURNRATEVALUATIONSTARTDATEOFFSETUNIT: FixTag = FixTag { id: "43017", datatype: value_types::STRING};
-pub const UNDERLYINGRETURNRATEVALUATIONSTARTDATEOFFSETDAYTYPE: FixTag = FixTag { id: "43018", datatype: value_types::INT};
-pub const UNDERLYINGRETURNRATEVALUATIONSTARTDATEADJUSTED: FixTag = FixTag { id: "43019", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGRETURNRATEVALUATIONENDDATEUNADJUSTED: FixTag = FixTag { id: "43020", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGRETURNRATEVALUATIONENDDATERELATIVETO: FixTag = FixTag { id: "43021", datatype: value_types::INT};
-pub const UNDERLYINGRETURNRATEVALUATIONENDDATEOFFSETPERIOD: FixTag = FixTag { id: "43022", datatype: value_types::INT};
-pub const UNDERLYINGRETURNRATEVALUATIONENDDATEOFFSETUNIT: FixTag = FixTag { id: "43023", datatype: value_types::STRING};
-pub const UNDERLYINGRETURNRATEVALUATIONENDDATEOFFSETDAYTYPE: FixTag = FixTag { id: "43024", datatype: value_types::INT};
-pub const UNDERLYINGRETURNRATEVALUATIONENDDATEADJUSTED: FixTag = FixTag { id: "43025", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGRETURNRATEVALUATIONFREQUENCYPERIOD: FixTag = FixTag { id: "43026", datatype: value_types::INT};
-pub const UNDERLYINGRETURNRATEVALUATIONFREQUENCYUNIT: FixTag = FixTag { id: "43027", datatype: value_types::STRING};
-pub const UNDERLYINGRETURNRATEVALUATIONFREQUENCYROLLCONVENTION: FixTag = FixTag { id: "43028", datatype: value_types::STRING};
-pub const UNDERLYINGRETURNRATEVALUATIONDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "43029", datatype: value_types::INT};
-pub const NOUNDERLYINGRETURNRATEFXCONVERSIONS: FixTag = FixTag { id: "43030", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGRETURNRATEFXCURRENCYSYMBOL: FixTag = FixTag { id: "43031", datatype: value_types::STRING};
-pub const UNDERLYINGRETURNRATEFXRATE: FixTag = FixTag { id: "43032", datatype: value_types::FLOAT};
-pub const UNDERLYINGRETURNRATEFXRATECALC: FixTag = FixTag { id: "43033", datatype: value_types::CHAR};
-pub const NOUNDERLYINGRETURNRATES: FixTag = FixTag { id: "43034", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGRETURNRATEPRICESEQUENCE: FixTag = FixTag { id: "43035", datatype: value_types::INT};
-pub const UNDERLYINGRETURNRATECOMMISSIONBASIS: FixTag = FixTag { id: "43036", datatype: value_types::CHAR};
-pub const UNDERLYINGRETURNRATECOMMISSIONAMOUNT: FixTag = FixTag { id: "43037", datatype: value_types::AMT};
-pub const UNDERLYINGRETURNRATECOMMISSIONCURRENCY: FixTag = FixTag { id: "43038", datatype: value_types::CURRENCY};
-pub const UNDERLYINGRETURNRATETOTALCOMMISSIONPERTRADE: FixTag = FixTag { id: "43039", datatype: value_types::AMT};
-pub const UNDERLYINGRETURNRATEDETERMINATIONMETHOD: FixTag = FixTag { id: "43040", datatype: value_types::STRING};
-pub const UNDERLYINGRETURNRATEAMOUNTRELATIVETO: FixTag = FixTag { id: "43041", datatype: value_types::INT};
-pub const UNDERLYINGRETURNRATEQUOTEMEASURETYPE: FixTag = FixTag { id: "43042", datatype: value_types::STRING};
-pub const UNDERLYINGRETURNRATEQUOTEUNITS: FixTag = FixTag { id: "43043", datatype: value_types::STRING};
-pub const UNDERLYINGRETURNRATEQUOTEMETHOD: FixTag = FixTag { id: "43044", datatype: value_types::INT};
-pub const UNDERLYINGRETURNRATEQUOTECURRENCY: FixTag = FixTag { id: "43045", datatype: value_types::CURRENCY};
-pub const UNDERLYINGRETURNRATEQUOTECURRENCYTYPE: FixTag = FixTag { id: "43046", datatype: value_types::STRING};
-pub const UNDERLYINGRETURNRATEQUOTETIMETYPE: FixTag = FixTag { id: "43047", datatype: value_types::INT};
-pub const UNDERLYINGRETURNRATEQUOTETIME: FixTag = FixTag { id: "43048", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGRETURNRATEQUOTEDATE: FixTag = FixTag { id: "43049", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGRETURNRATEQUOTEEXPIRATIONTIME: FixTag = FixTag { id: "43050", datatype: value_types::LOCAL_MKT_TIME};
-pub const UNDERLYINGRETURNRATEQUOTEBUSINESSCENTER: FixTag = FixTag { id: "43051", datatype: value_types::STRING};
-pub const UNDERLYINGRETURNRATEQUOTEEXCHANGE: FixTag = FixTag { id: "43052", datatype: value_types::EXCHANGE};
-pub const UNDERLYINGRETURNRATEQUOTEPRICINGMODEL: FixTag = FixTag { id: "43053", datatype: value_types::STRING};
-pub const UNDERLYINGRETURNRATECASHFLOWTYPE: FixTag = FixTag { id: "43054", datatype: value_types::STRING};
-pub const UNDERLYINGRETURNRATEVALUATIONTIMETYPE: FixTag = FixTag { id: "43055", datatype: value_types::INT};
-pub const UNDERLYINGRETURNRATEVALUATIONTIME: FixTag = FixTag { id: "43056", datatype: value_types::LOCAL_MKT_TIME};
-pub const UNDERLYINGRETURNRATEVALUATIONTIMEBUSINESSCENTER: FixTag = FixTag { id: "43057", datatype: value_types::STRING};
-pub const UNDERLYINGRETURNRATEVALUATIONPRICEOPTION: FixTag = FixTag { id: "43058", datatype: value_types::INT};
-pub const UNDERLYINGRETURNRATEFINALPRICEFALLBACK: FixTag = FixTag { id: "43059", datatype: value_types::INT};
-pub const NOUNDERLYINGRETURNRATEINFORMATIONSOURCES: FixTag = FixTag { id: "43060", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGRETURNRATEINFORMATIONSOURCE: FixTag = FixTag { id: "43061", datatype: value_types::INT};
-pub const UNDERLYINGRETURNRATEREFERENCEPAGE: FixTag = FixTag { id: "43062", datatype: value_types::STRING};
-pub const UNDERLYINGRETURNRATEREFERENCEPAGEHEADING: FixTag = FixTag { id: "43063", datatype: value_types::STRING};
-pub const NOUNDERLYINGRETURNRATEPRICES: FixTag = FixTag { id: "43064", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGRETURNRATEPRICEBASIS: FixTag = FixTag { id: "43065", datatype: value_types::INT};
-pub const UNDERLYINGRETURNRATEPRICE: FixTag = FixTag { id: "43066", datatype: value_types::PRICE};
-pub const UNDERLYINGRETURNRATEPRICECURRENCY: FixTag = FixTag { id: "43067", datatype: value_types::CURRENCY};
-pub const UNDERLYINGRETURNRATEPRICETYPE: FixTag = FixTag { id: "43068", datatype: value_types::INT};
-pub const NOUNDERLYINGRETURNRATEVALUATIONDATEBUSINESSCENTERS: FixTag = FixTag { id: "43069", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGRETURNRATEVALUATIONDATEBUSINESSCENTER: FixTag = FixTag { id: "43070", datatype: value_types::STRING};
-pub const NOUNDERLYINGRETURNRATEVALUATIONDATES: FixTag = FixTag { id: "43071", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGRETURNRATEVALUATIONDATE: FixTag = FixTag { id: "43072", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGRETURNRATEVALUATIONDATETYPE: FixTag = FixTag { id: "43073", datatype: value_types::INT};
-pub const NOUNDERLYINGSETTLMETHODELECTIONDATEBUSINESSCENTERS: FixTag = FixTag { id: "43074", datatype: value_types::NUM_IN_GROUP };
-pub const UNDERLYINGSETTLMETHODELECTIONDATEBUSINESSCENTER: FixTag = FixTag { id: "43075", datatype: value_types::STRING};
-pub const UNDERLYINGSETTLMETHODELECTIONDATEUNADJUSTED: FixTag = FixTag { id: "43076", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGSETTLMETHODELECTIONDATEBUSINESSDAYCONVENTION: FixTag = FixTag { id: "43077", datatype: value_types::INT};
-pub const UNDERLYINGSETTLMETHODELECTIONDATERELATIVETO: FixTag = FixTag { id: "43078", datatype: value_types::INT};
-pub const UNDERLYINGSETTLMETHODELECTIONDATEOFFSETPERIOD: FixTag = FixTag { id: "43079", datatype: value_types::INT};
-pub const UNDERLYINGSETTLMETHODELECTIONDATEOFFSETUNIT: FixTag = FixTag { id: "43080", datatype: value_types::STRING};
-pub const UNDERLYINGSETTLMETHODELECTIONDATEOFFSETDAYTYPE: FixTag = FixTag { id: "43081", datatype: value_types::INT};
-pub const UNDERLYINGSETTLMETHODELECTIONDATEADJUSTED: FixTag = FixTag { id: "43082", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGSTREAMVERSION: FixTag = FixTag { id: "43083", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMVERSIONEFFECTIVEDATE: FixTag = FixTag { id: "43084", datatype: value_types::LOCAL_MKT_DATE};
-pub const UNDERLYINGSTREAMNOTIONALDETERMINATIONMETHOD: FixTag = FixTag { id: "43085", datatype: value_types::STRING};
-pub const UNDERLYINGSTREAMNOTIONALADJUSTMENTS: FixTag = FixTag { id: "43086", datatype: value_types::INT};
-pub const PAYMENTDESC: FixTag = FixTag { id: "43087", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMRATEINDEXID: FixTag = FixTag { id: "43088", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMRATEINDEXIDSOURCE: FixTag = FixTag { id: "43089", datatype: value_types::STRING};
-pub const PAYMENTSTREAMRATEINDEXID: FixTag = FixTag { id: "43090", datatype: value_types::STRING};
-pub const PAYMENTSTREAMRATEINDEXIDSOURCE: FixTag = FixTag { id: "43091", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMRATEINDEXID: FixTag = FixTag { id: "43092", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMRATEINDEXIDSOURCE: FixTag = FixTag { id: "43093", datatype: value_types::STRING};
-pub const DELIVERYSTREAMROUTEORCHARTER: FixTag = FixTag { id: "43094", datatype: value_types::STRING};
-pub const LEGDELIVERYSTREAMROUTEORCHARTER: FixTag = FixTag { id: "43095", datatype: value_types::STRING};
-pub const UNDERLYINGDELIVERYSTREAMROUTEORCHARTER: FixTag = FixTag { id: "43096", datatype: value_types::STRING};
-pub const PAYMENTFIXEDRATE: FixTag = FixTag { id: "43097", datatype: value_types::PERCENTAGE};
-pub const PAYMENTFLOATINGRATEINDEX: FixTag = FixTag { id: "43098", datatype: value_types::STRING};
-pub const PAYMENTFLOATINGRATEINDEXCURVEPERIOD: FixTag = FixTag { id: "43099", datatype: value_types::INT};
-pub const PAYMENTFLOATINGRATEINDEXCURVEUNIT: FixTag = FixTag { id: "43100", datatype: value_types::STRING};
-pub const PAYMENTFLOATINGRATESPREAD: FixTag = FixTag { id: "43101", datatype: value_types::PRICE_OFFSET};
-pub const PAYMENTFREQUENCYPERIOD: FixTag = FixTag { id: "43102", datatype: value_types::INT};
-pub const PAYMENTFREQUENCYUNIT: FixTag = FixTag { id: "43103", datatype: value_types::STRING};
-pub const PAYMENTRATERESETFREQUENCYPERIOD: FixTag = FixTag { id: "43104", datatype: value_types::INT};
-pub const PAYMENTRATERESETFREQUENCYUNIT: FixTag = FixTag { id: "43105", datatype: value_types::STRING};
-pub const PAYMENTSTREAMOTHERDAYCOUNT: FixTag = FixTag { id: "43106", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMOTHERDAYCOUNT: FixTag = FixTag { id: "43107", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMOTHERDAYCOUNT: FixTag = FixTag { id: "43108", datatype: value_types::STRING};
-pub const PAYMENTSTREAMFORMULALENGTH: FixTag = FixTag { id: "43109", datatype: value_types::LENGTH};
-pub const LEGPAYMENTSTREAMFORMULALENGTH: FixTag = FixTag { id: "43110", datatype: value_types::LENGTH};
-pub const UNDERLYINGPAYMENTSTREAMFORMULALENGTH: FixTag = FixTag { id: "43111", datatype: value_types::LENGTH};
-pub const PAYMENTSTREAMRATEINDEX2: FixTag = FixTag { id: "43112", datatype: value_types::STRING};
-pub const PAYMENTSTREAMRATEINDEX2SOURCE: FixTag = FixTag { id: "43113", datatype: value_types::INT};
-pub const PAYMENTSTREAMRATEINDEX2ID: FixTag = FixTag { id: "43114", datatype: value_types::STRING};
-pub const PAYMENTSTREAMRATEINDEX2IDSOURCE: FixTag = FixTag { id: "43115", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMRATEINDEX2: FixTag = FixTag { id: "43116", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMRATEINDEX2SOURCE: FixTag = FixTag { id: "43117", datatype: value_types::INT};
-pub const LEGPAYMENTSTREAMRATEINDEX2ID: FixTag = FixTag { id: "43118", datatype: value_types::STRING};
-pub const LEGPAYMENTSTREAMRATEINDEX2IDSOURCE: FixTag = FixTag { id: "43119", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMRATEINDEX2: FixTag = FixTag { id: "43120", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMRATEINDEX2SOURCE: FixTag = FixTag { id: "43121", datatype: value_types::INT};
-pub const UNDERLYINGPAYMENTSTREAMRATEINDEX2ID: FixTag = FixTag { id: "43122", datatype: value_types::STRING};
-pub const UNDERLYINGPAYMENTSTREAMRATEINDEX2IDSOURCE: FixTag = FixTag { id: "43123", datatype: value_types::STRING};
-pub const BATCHID: FixTag = FixTag { id: "50000", datatype: value_types::STRING};
-pub const BATCHTOTALMESSAGES: FixTag = FixTag { id: "50001", datatype: value_types::INT};
-pub const BATCHPROCESSMODE: FixTag = FixTag { id: "50002", datatype: value_types::INT};
\ No newline at end of file
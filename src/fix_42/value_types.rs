@@ -1,5 +1,11 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use rust_decimal::Decimal;
+use crate::fix_42::iso_codes;
+
 #[allow(dead_code)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ValueType {
     Int,
     DayOfMonth,
@@ -24,6 +30,246 @@ pub enum ValueType {
     Data
 }
 
+impl ValueType {
+
+    // The type as it's named in a QuickFIX style XML spec.
+    pub fn from_name(name: &str) -> Option<ValueType> {
+        match name {
+            "INT" | "LENGTH" | "SEQNUM" | "NUMINGROUP" | "TAGNUM" => Some(ValueType::Int),
+            "DAYOFMONTH" => Some(ValueType::DayOfMonth),
+            "FLOAT" | "PERCENTAGE" => Some(ValueType::Float),
+            "QTY" => Some(ValueType::Qty),
+            "PRICE" => Some(ValueType::Price),
+            "PRICEOFFSET" => Some(ValueType::PriceOffset),
+            "AMT" => Some(ValueType::Amt),
+            "CHAR" => Some(ValueType::Char),
+            "BOOLEAN" => Some(ValueType::Boolean),
+            "STRING" | "LANGUAGE" | "MULTIPLECHARVALUE" => Some(ValueType::String),
+            "MULTIPLEVALUESTRING" | "MULTIPLESTRINGVALUE" => Some(ValueType::MultipleValueString),
+            "CURRENCY" => Some(ValueType::Currency),
+            "COUNTRY" => Some(ValueType::Country),
+            "EXCHANGE" => Some(ValueType::Exchange),
+            "UTCTIMESTAMP" | "TZTIMESTAMP" | "TIME" => Some(ValueType::UTCTimestamp),
+            "UTCTIMEONLY" | "TZTIMEONLY" => Some(ValueType::UTCTimeOnly),
+            "LOCALMKTDATE" | "DATE" => Some(ValueType::LocalMktDate),
+            "UTCDATE" | "UTCDATEONLY" => Some(ValueType::UTCDate),
+            "MONTHYEAR" => Some(ValueType::MonthYear),
+            "DATA" => Some(ValueType::Data),
+            "XMLDATA" => Some(ValueType::XmlData),
+            _ => None
+        }
+    }
+
+    // A format check only, i.e. whether the value could be a valid field of this type.
+    pub fn is_valid(&self, value: &str) -> bool {
+        self.parse(value).is_ok()
+    }
+
+    pub fn parse(&self, value: &str) -> Result<FieldValue, ValueError> {
+        let parsed = match self {
+            ValueType::Int => parse_int(value).map(FieldValue::Int),
+            ValueType::DayOfMonth => parse_int(value).filter(|day| (1..=31).contains(day)).map(|day| FieldValue::DayOfMonth(day as u32)),
+            ValueType::Float | ValueType::Qty | ValueType::Price | ValueType::PriceOffset | ValueType::Amt => {
+                parse_decimal(value).map(FieldValue::Decimal)
+            },
+            ValueType::Char => {
+                let mut chars = value.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Some(FieldValue::Char(c)),
+                    _ => None
+                }
+            },
+            ValueType::Boolean => match value {
+                "Y" => Some(FieldValue::Boolean(true)),
+                "N" => Some(FieldValue::Boolean(false)),
+                _ => None
+            },
+            ValueType::String | ValueType::Data | ValueType::XmlData => Some(FieldValue::String(value.to_string())),
+            ValueType::MultipleValueString => {
+                let values: Vec<String> = value.split(' ').map(String::from).collect();
+                values.iter().all(|v| !v.is_empty()).then_some(FieldValue::MultipleValueString(values))
+            },
+            ValueType::Currency => iso_codes::is_currency(value).then(|| FieldValue::Currency(value.to_string())),
+            ValueType::Country => iso_codes::is_country(value).then(|| FieldValue::Country(value.to_string())),
+            ValueType::Exchange => iso_codes::is_exchange(value).then(|| FieldValue::Exchange(value.to_string())),
+            ValueType::UTCTimestamp => parse_time(value, 17).and_then(|(base, nanos, precision)| {
+                let timestamp = NaiveDateTime::parse_from_str(base, "%Y%m%d-%H:%M:%S").ok()?;
+                Some(FieldValue::UTCTimestamp(timestamp.with_nanosecond(nanos)?, precision))
+            }),
+            ValueType::UTCTimeOnly => parse_time(value, 8).and_then(|(base, nanos, precision)| {
+                let time = NaiveTime::parse_from_str(base, "%H:%M:%S").ok()?;
+                Some(FieldValue::UTCTimeOnly(time.with_nanosecond(nanos)?, precision))
+            }),
+            ValueType::LocalMktDate | ValueType::UTCDate => {
+                (value.len() == 8).then(|| NaiveDate::parse_from_str(value, "%Y%m%d").ok()).flatten().map(FieldValue::Date)
+            },
+            ValueType::MonthYear => MonthYear::parse(value).map(FieldValue::MonthYear),
+        };
+        parsed.ok_or_else(|| ValueError { datatype: *self, value: value.to_string() })
+    }
+}
+
+fn parse_int(value: &str) -> Option<i64> {
+    let digits = value.strip_prefix('-').unwrap_or(value);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    value.parse().ok()
+}
+
+// Digits with an optional minus sign and decimal point. FIX doesn't allow a plus sign or an
+// exponent, both of which Decimal would otherwise accept.
+pub(crate) fn parse_decimal(value: &str) -> Option<Decimal> {
+    let digits = value.strip_prefix('-').unwrap_or(value);
+    if !digits.bytes().any(|b| b.is_ascii_digit())
+        || !digits.bytes().all(|b| b.is_ascii_digit() || b == b'.')
+        || digits.bytes().filter(|b| *b == b'.').count() > 1 {
+        return None;
+    }
+    Decimal::from_str(value).ok()
+}
+
+// Splits HH:MM:SS.sss off its fraction, which may be 0, 3, 6 or 9 digits long.
+fn parse_time(value: &str, base_len: usize) -> Option<(&str, u32, TimePrecision)> {
+    let (base, fraction) = value.split_at_checked(base_len)?;
+    let precision = match fraction.len() {
+        0 => TimePrecision::Seconds,
+        4 => TimePrecision::Millis,
+        7 => TimePrecision::Micros,
+        10 => TimePrecision::Nanos,
+        _ => return None
+    };
+    let nanos = match fraction.strip_prefix('.') {
+        Some(digits) if digits.bytes().all(|b| b.is_ascii_digit()) => format!("{:0<9}", digits).parse().ok()?,
+        Some(_) => return None,
+        None => 0,
+    };
+    Some((base, nanos, precision))
+}
+
+// How many digits of a second a time was sent with, so that it's formatted back the same way.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimePrecision {
+    Seconds,
+    Millis,
+    Micros,
+    Nanos
+}
+
+impl TimePrecision {
+    fn time_format(&self) -> &'static str {
+        match self {
+            TimePrecision::Seconds => "%H:%M:%S",
+            TimePrecision::Millis => "%H:%M:%S%.3f",
+            TimePrecision::Micros => "%H:%M:%S%.6f",
+            TimePrecision::Nanos => "%H:%M:%S%.9f",
+        }
+    }
+}
+
+// YYYYMM, YYYYMMDD or YYYYMMwN for the Nth week of the month.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MonthYear {
+    pub year  : u32,
+    pub month : u32,
+    pub day   : Option<u32>,
+    pub week  : Option<u32>
+}
+
+impl MonthYear {
+    fn parse(value: &str) -> Option<MonthYear> {
+        let number = |from: usize, to: usize| value.get(from..to).filter(|s| s.bytes().all(|b| b.is_ascii_digit()))?.parse::<u32>().ok();
+        let year = number(0, 4)?;
+        let month = number(4, 6).filter(|month| (1..=12).contains(month))?;
+        let (day, week) = match value.len() {
+            6 => (None, None),
+            8 if value.as_bytes()[6] == b'w' => (None, Some(number(7, 8).filter(|week| (1..=5).contains(week))?)),
+            8 => (Some(number(6, 8).filter(|day| (1..=31).contains(day))?), None),
+            _ => return None
+        };
+        Some(MonthYear { year, month, day, week })
+    }
+}
+
+impl Display for MonthYear {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}{:02}", self.year, self.month)?;
+        match (self.day, self.week) {
+            (Some(day), _) => write!(f, "{:02}", day),
+            (None, Some(week)) => write!(f, "w{}", week),
+            (None, None) => Ok(())
+        }
+    }
+}
+
+// A field value parsed according to its ValueType. Formatting gives back exactly what was parsed,
+// so a price keeps its trailing zeros and a timestamp its precision.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldValue {
+    Int(i64),
+    DayOfMonth(u32),
+    // Float, Qty, Price, PriceOffset and Amt
+    Decimal(Decimal),
+    Char(char),
+    Boolean(bool),
+    // String, Data and XmlData
+    String(String),
+    MultipleValueString(Vec<String>),
+    Currency(String),
+    Country(String),
+    Exchange(String),
+    UTCTimestamp(NaiveDateTime, TimePrecision),
+    UTCTimeOnly(NaiveTime, TimePrecision),
+    // LocalMktDate and UTCDate
+    Date(NaiveDate),
+    MonthYear(MonthYear)
+}
+
+impl FieldValue {
+    pub fn timestamp_millis(timestamp: NaiveDateTime) -> FieldValue {
+        FieldValue::UTCTimestamp(timestamp, TimePrecision::Millis)
+    }
+
+    pub fn timestamp_micros(timestamp: NaiveDateTime) -> FieldValue {
+        FieldValue::UTCTimestamp(timestamp, TimePrecision::Micros)
+    }
+}
+
+impl Display for FieldValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldValue::Int(value) => write!(f, "{}", value),
+            FieldValue::DayOfMonth(value) => write!(f, "{}", value),
+            FieldValue::Decimal(value) => write!(f, "{}", value),
+            FieldValue::Char(value) => write!(f, "{}", value),
+            FieldValue::Boolean(value) => f.write_str(if *value { "Y" } else { "N" }),
+            FieldValue::String(value) | FieldValue::Currency(value) | FieldValue::Country(value) | FieldValue::Exchange(value) => {
+                f.write_str(value)
+            },
+            FieldValue::MultipleValueString(values) => f.write_str(&values.join(" ")),
+            FieldValue::UTCTimestamp(value, precision) => {
+                write!(f, "{}-{}", value.format("%Y%m%d"), value.format(precision.time_format()))
+            },
+            FieldValue::UTCTimeOnly(value, precision) => write!(f, "{}", value.format(precision.time_format())),
+            FieldValue::Date(value) => write!(f, "{}", value.format("%Y%m%d")),
+            FieldValue::MonthYear(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+// A value that isn't valid for its type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValueError {
+    pub datatype : ValueType,
+    pub value    : String
+}
+
+impl Display for ValueError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} is not a valid {:?}", self.value, self.datatype)
+    }
+}
+
 /*impl Clone for ValueType {
     fn clone(&self) -> Self { *self}
 }*/
@@ -54,7 +300,7 @@ pub const EXCHANGE:ValueType = ValueType::Exchange;
 pub const UTC_TIMESTAMP:ValueType = ValueType::UTCTimestamp;
 pub const TZ_TIMESTAMP:ValueType = ValueType::UTCTimestamp;
 pub const UTC_TIME_ONLY:ValueType = ValueType::UTCTimeOnly;
-pub const UTC_DATE_ONLY:ValueType = ValueType::UTCTimeOnly;
+pub const UTC_DATE_ONLY:ValueType = ValueType::UTCDate;
 pub const TZ_TIME_ONLY:ValueType = ValueType::UTCTimeOnly;
 pub const LOCAL_MKT_DATE:ValueType = ValueType::LocalMktDate;
 pub const LOCAL_MKT_TIME:ValueType = ValueType::LocalMktDate;
@@ -71,5 +317,90 @@ impl FixTag {
     }
     pub fn id(&self) -> &'static str { self.id }
 
+    // The id as a number, for matching against tags read off the wire.
+    pub const fn number(&self) -> u32 {
+        let digits = self.id.as_bytes();
+        let mut number = 0;
+        let mut i = 0;
+        while i < digits.len() {
+            number = number * 10 + (digits[i] - b'0') as u32;
+            i += 1;
+        }
+        number
+    }
+
     pub fn datatype(&self) -> ValueType { self.datatype }
-}
\ No newline at end of file
+
+    pub fn parse(&self, value: &str) -> Result<FieldValue, ValueError> {
+        self.datatype.parse(value)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_values_round_trip() {
+        let values = [
+            (ValueType::Int, vec!["0", "42", "-7"]),
+            (ValueType::DayOfMonth, vec!["1", "31"]),
+            (ValueType::Price, vec!["101.25", "101.50", "0.0000001", "-3", "100"]),
+            (ValueType::Qty, vec!["1000000000000", "0.5"]),
+            (ValueType::Char, vec!["1", "A"]),
+            (ValueType::Boolean, vec!["Y", "N"]),
+            (ValueType::String, vec!["IBM", "two words"]),
+            (ValueType::MultipleValueString, vec!["1", "1 G W"]),
+            (ValueType::Currency, vec!["USD", "EUR", "JPY"]),
+            (ValueType::Country, vec!["US", "GB", "JP"]),
+            (ValueType::Exchange, vec!["N", "XNYS", "XLON"]),
+            (ValueType::UTCTimestamp, vec!["20250119-16:13:08", "20250119-16:13:08.931", "20250119-16:13:08.931012", "20250119-16:13:08.000000001"]),
+            (ValueType::UTCTimeOnly, vec!["16:13:08", "16:13:08.900", "16:13:08.000100"]),
+            (ValueType::LocalMktDate, vec!["20250119"]),
+            (ValueType::UTCDate, vec!["20240229"]),
+            (ValueType::MonthYear, vec!["202503", "20250321", "202503w3"]),
+        ];
+        for (datatype, values) in values {
+            for value in values {
+                let parsed = datatype.parse(value).unwrap_or_else(|e| panic!("{}", e));
+                assert_eq!(parsed.to_string(), value, "{:?}", datatype);
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        let values = [
+            (ValueType::Int, vec!["", "+1", "1.0", "1e3"]),
+            (ValueType::DayOfMonth, vec!["0", "32"]),
+            (ValueType::Price, vec!["", "1e-7", "1E5", "+1", "1.2.3", ".", "-", "NaN", "inf"]),
+            (ValueType::Char, vec!["", "AB"]),
+            (ValueType::Boolean, vec!["y", "1", "true"]),
+            (ValueType::MultipleValueString, vec!["", "1  2", " 1"]),
+            (ValueType::Currency, vec!["usd", "US", "ABC"]),
+            (ValueType::Country, vec!["us", "USA", "XX"]),
+            (ValueType::Exchange, vec!["", "xnys", "XNYSE"]),
+            (ValueType::UTCTimestamp, vec!["20250119", "20250119-16:13", "20250119-16:13:08.93", "20250119-16:13:08.9311", "20250132-16:13:08"]),
+            (ValueType::UTCTimeOnly, vec!["16:13", "25:00:00", "16:13:08.1"]),
+            (ValueType::LocalMktDate, vec!["2025119", "20250230", "2025-01-19"]),
+            (ValueType::MonthYear, vec!["2025", "202513", "202503w6", "2025032"]),
+        ];
+        for (datatype, values) in values {
+            for value in values {
+                assert_eq!(datatype.parse(value), Err(ValueError { datatype, value: value.to_string() }));
+            }
+        }
+    }
+
+    #[test]
+    fn test_typed_values() {
+        let price = FixTag::new("44", ValueType::Price);
+        assert_eq!(price.parse("101.50"), Ok(FieldValue::Decimal(Decimal::new(10150, 2))));
+
+        let timestamp = NaiveDate::from_ymd_opt(2025, 1, 19).unwrap().and_hms_micro_opt(16, 13, 8, 931012).unwrap();
+        assert_eq!(FieldValue::timestamp_millis(timestamp).to_string(), "20250119-16:13:08.931");
+        assert_eq!(FieldValue::timestamp_micros(timestamp).to_string(), "20250119-16:13:08.931012");
+
+        assert_eq!(ValueType::MultipleValueString.parse("1 G"), Ok(FieldValue::MultipleValueString(vec![String::from("1"), String::from("G")])));
+        assert_eq!(ValueType::MonthYear.parse("202503w3"), Ok(FieldValue::MonthYear(MonthYear { year: 2025, month: 3, day: None, week: Some(3) })));
+    }
+}
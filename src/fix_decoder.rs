@@ -1,108 +1,376 @@
 use std::collections::HashMap;
-use std::io::Error;
-use std::io::ErrorKind::InvalidData;
-use bytes::{Buf, BytesMut};
+use std::fmt::{Display, Formatter};
+use std::io::{Error, Write};
+use bytes::{Buf, Bytes, BytesMut};
 use tokio_util::codec::{Decoder};
+use crate::fix_println;
+use crate::fix_msg_builder::SessionHeader;
 
 
 const FIX_SEPARATOR: u8        = b'';
-pub(crate) struct MyFIXDecoder {
-    header1 : Vec<u8>,
-    header2 : Vec<u8>,
-    trailer : Vec<u8>
+const MESSAGE_START: &[u8]     = b"8=FIX";
+// Used when "max_message_size" isn't configured.
+const DEFAULT_MAX_MESSAGE_SIZE: usize = 64 * 1024;
+// "FIXT.1.1" is the longest in use, this leaves room for anything that might follow it.
+const MAX_BEGIN_STRING_LEN: usize = 16;
+// "10=nnn<SOH>"
+const TRAILER_LEN: usize = 7;
+// Plenty for any BodyLength under a sane max_message_size, and stops the length overflowing.
+const MAX_BODY_LENGTH_DIGITS: usize = 9;
+
+// Why a frame couldn't be decoded. The bad frame has already been removed from the buffer, so
+// the caller can log it and carry on decoding whatever follows.
+#[derive(Debug)]
+pub enum FixDecodeError {
+    // The CheckSum(10) the sender declared doesn't match the one calculated over the frame.
+    ChecksumMismatch { declared: usize, calculated: usize },
+    // BodyLength(9) isn't a number or doesn't end the frame on a "10=nnn<SOH>" trailer.
+    InvalidBodyLength,
+    // Begins with "8=FIX" but not the BeginString we're expecting.
+    UnexpectedHeader,
+    // BodyLength(9) makes the frame bigger than "max_message_size".
+    MessageTooLarge { size: usize, max: usize },
+    Io(Error),
 }
 
-impl MyFIXDecoder {
-    pub(crate) fn new(config : &HashMap<String,String> ) -> Self {
+impl Display for FixDecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FixDecodeError::ChecksumMismatch { declared, calculated } =>
+                write!(f, "CheckSum mismatch, declared {:03} but calculated {:03}", declared, calculated),
+            FixDecodeError::InvalidBodyLength => write!(f, "BodyLength doesn't match the end of the message"),
+            FixDecodeError::UnexpectedHeader => write!(f, "Unexpected BeginString"),
+            FixDecodeError::MessageTooLarge { size, max } =>
+                write!(f, "Message of {} bytes is larger than the maximum of {}", size, max),
+            FixDecodeError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
 
-        let version = config.get("version").unwrap();
-        let mut hdr1 = String::new();
+// The Decoder trait needs this to report errors from the underlying stream.
+impl From<Error> for FixDecodeError {
+    fn from(e: Error) -> Self {
+        FixDecodeError::Io(e)
+    }
+}
 
-        if version == "4.2" {
-            hdr1.push_str("8=FIX.4.29=");
-        } else if version == "4.4" {
-            hdr1.push_str("8=FIX.4.49=");
-        } else if version == "5.0" {
-            hdr1.push_str("8=FIX.5.09=");
-        } else {
-            println!("Unsupported version {}, defaulting to 4.2", version);
-            hdr1.push_str("8=FIX.4.29=");
-        }
+// Sum of every byte up to and including the SOH before "10=", modulo 256.
+fn calculate_check_sum(frame: &[u8]) -> usize {
+    frame.iter().map(|b| *b as usize).sum::<usize>() % 256
+}
+pub struct MyFIXDecoder {
+    begin_string : Vec<u8>,
+    max_message_size : usize
+}
+
+impl MyFIXDecoder {
+    pub fn new(config : &HashMap<String,String> ) -> Self {
 
-        let sender_comp_id = config.get("sender_comp_id").unwrap();
-        let target_comp_id = config.get("target_comp_id").unwrap();
-        let now = chrono::offset::Utc::now().format("%Y%m%d%H%M%S%3f").to_string();
-        let hdr2= format!("35=_49={sender_comp_id}56={target_comp_id}52={now}34=");
+        let begin_string = String::from(SessionHeader::from_config(config).unwrap_or_else(|e| panic!("{}", e)).begin_string());
 
         Self {
-            header1: Vec::from(hdr1),
-            header2: Vec::from(hdr2),
-            trailer: Vec::from("10=000")
+            begin_string: Vec::from(begin_string),
+            max_message_size: config.get("max_message_size").and_then(|v| v.parse().ok()).unwrap_or(DEFAULT_MAX_MESSAGE_SIZE)
         }
     }
 }
 impl Decoder for MyFIXDecoder {
 
-    type Item = String;
+    type Item = Bytes;
 
-    type Error = std::io::Error;
+    type Error = FixDecodeError;
 
     fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
 
-        //Check in the first 12 bytes to see if tags 8= & 9= are present.
-        //63 bytes is the smallest valid FIX message
-        if src.len() >= 63  {
-            if src[0..12].eq_ignore_ascii_case(&self.header1) {
-                let mut length_sz: usize = 0;
-                let mut length: usize = 0;
-
-                //Now fetch the body length from tag 9= and see if that many bytes are available
-                let mut i = 12;
-
-                while src[i] != FIX_SEPARATOR && i < src.len() {
-                    // read the next byte as a digit
-                    if src[i].is_ascii_digit() {
-                        length = (length * 10) + (src[i] as usize - 0x30);
-                        length_sz += 1;
-                    } else {
-                        //todo: we found a non-digit in the length field throw exception.
-                        return Err(Error::new(InvalidData, format!("non-digit found in tag 9 <body_length>.")));
-                    }
-                    i += 1;
-                }
-
-                //so if x = (12 + length_sz + 1 + length[min:43] + 7) bytes are present we have a valid fix msg  ... maybe.
-                let msg_end = 20 + length_sz + length;
-
-                if src.len() >= msg_end {
-
-                    let msg = &src.iter().as_slice()[0..msg_end];
-
-                    // validate the checksum.
-                    let d1 = src[msg_end - 4];
-                    let d2 = src[msg_end - 3];
-                    let d3 = src[msg_end - 2];
-
-                    if d1.is_ascii_digit() &&  d1.is_ascii_digit() && d1.is_ascii_digit() {
-
-                        let n1 = d1 as usize - 0x30;
-                        let n2 = d2 as usize - 0x30;
-                        let n3 = d3 as usize - 0x30;
-                        let cksum = (n1 * 100) + (n2 * 10) + n3;
-
-                        // copy the data out of the buffer and into the heap
-
-                        //return the frame to the caller.
-                        let ret = String::from_utf8_lossy(msg).to_string();
-                        let _ = src.split_to(msg_end);
-                        return Ok(Some(ret));
-
-                    } else {
-                        return Err(Error::new(InvalidData, "Likely incorrect tag9 value"))
-                    }
-                } else { return Ok(None); }
-            } else { return Ok(None); }
+        // Anything in front of a message start marker is junk, e.g. the tail of a frame we've
+        // already given up on, so skip forward to the next one rather than waiting forever.
+        self.resync(src);
+
+        // Either too little to hold a start marker, or just the first part of one.
+        if !src.starts_with(MESSAGE_START) {
+            return Ok(None);
+        }
+
+        let (header_len, length) = match self.parse_header(src) {
+            Ok(Some(header)) => header,
+            Ok(None) => return Ok(None),
+            Err(e) => {
+                // Step over this start marker so the next call resyncs on the one after it.
+                src.advance(1);
+                return Err(e);
+            }
+        };
+
+        // The header, the body and then "10=nnn<SOH>".
+        let msg_end = header_len + length + TRAILER_LEN;
+
+        if msg_end > self.max_message_size {
+            src.advance(1);
+            return Err(FixDecodeError::MessageTooLarge { size: msg_end, max: self.max_message_size });
+        }
+
+        if src.len() < msg_end {
+            // Another message starting inside this one means BodyLength points past the real end
+            // of the frame, waiting for the rest would only hold up everything behind it.
+            if find(&src[1..], MESSAGE_START).is_some_and(|p| src[p] == FIX_SEPARATOR) {
+                src.advance(1);
+                return Err(FixDecodeError::InvalidBodyLength);
+            }
+            return Ok(None);
+        }
+
+        let msg = &src[0..msg_end];
+
+        // BodyLength has to land exactly on the "10=nnn<SOH>" trailer.
+        if &msg[msg_end - TRAILER_LEN..msg_end - 4] != b"10=" || msg[msg_end - TRAILER_LEN - 1] != FIX_SEPARATOR || msg[msg_end - 1] != FIX_SEPARATOR {
+            src.advance(1);
+            return Err(FixDecodeError::InvalidBodyLength);
+        }
+
+        // validate the checksum.
+        let d1 = src[msg_end - 4];
+        let d2 = src[msg_end - 3];
+        let d3 = src[msg_end - 2];
+
+        if d1.is_ascii_digit() &&  d2.is_ascii_digit() && d3.is_ascii_digit() {
+
+            let n1 = d1 as usize - 0x30;
+            let n2 = d2 as usize - 0x30;
+            let n3 = d3 as usize - 0x30;
+            let cksum = (n1 * 100) + (n2 * 10) + n3;
+
+            // "10=nnn<SOH>" is the last 7 bytes, everything before it is summed.
+            let calculated = calculate_check_sum(&msg[..msg_end - TRAILER_LEN]);
+
+            if cksum != calculated {
+                // A garbled frame is dropped without being processed, as if never received.
+                let _ = src.split_to(msg_end);
+                return Err(FixDecodeError::ChecksumMismatch { declared: cksum, calculated });
+            }
+
+            // Hand the frame over as it arrived, without copying it. Data fields needn't be UTF-8.
+            Ok(Some(src.split_to(msg_end).freeze()))
+
+        } else {
+            src.advance(1);
+            Err(FixDecodeError::InvalidBodyLength)
+        }
+    }
+}
+
+impl MyFIXDecoder {
+
+    // Reads "8=<BeginString><SOH>9=<BodyLength><SOH>" from the front of the buffer and returns the
+    // length of that header along with the BodyLength, or None if it hasn't all arrived yet.
+    fn parse_header(&self, src: &[u8]) -> Result<Option<(usize, usize)>, FixDecodeError> {
+
+        // BeginString(8), the buffer has already been resynced so it starts with "8=".
+        let search_to = src.len().min(2 + MAX_BEGIN_STRING_LEN + 1);
+        let Some(begin_string_end) = src[..search_to].iter().position(|b| *b == FIX_SEPARATOR) else {
+            return if search_to == src.len() && src.len() <= 2 + MAX_BEGIN_STRING_LEN { Ok(None) } else { Err(FixDecodeError::UnexpectedHeader) };
+        };
+
+        if src[2..begin_string_end] != self.begin_string[..] {
+            return Err(FixDecodeError::UnexpectedHeader);
+        }
+
+        // BodyLength(9) has to come straight after it.
+        let length_start = begin_string_end + 3;
+        if src.len() < length_start {
+            return Ok(None);
+        }
+        if &src[begin_string_end + 1..length_start] != b"9=" {
+            return Err(FixDecodeError::InvalidBodyLength);
+        }
+
+        let mut length: usize = 0;
+        let mut i = length_start;
+
+        loop {
+            if i == src.len() {
+                return Ok(None);
+            }
+            match src[i] {
+                FIX_SEPARATOR if i > length_start => return Ok(Some((i + 1, length))),
+                b if b.is_ascii_digit() && i - length_start < MAX_BODY_LENGTH_DIGITS => {
+                    length = (length * 10) + (b - b'0') as usize;
+                },
+                _ => return Err(FixDecodeError::InvalidBodyLength),
+            }
+            i += 1;
         }
-        Ok(None)
+    }
+
+    // Drops everything in front of the next "8=FIX". Without one we keep just enough of the tail
+    // to hold a marker that's been split across two reads.
+    fn resync(&self, src: &mut BytesMut) {
+
+        let junk = match find(src, MESSAGE_START) {
+            Some(pos) => pos,
+            None => src.len().saturating_sub(MESSAGE_START.len() - 1),
+        };
+
+        if junk > 0 {
+            fix_println!("Discarding {} bytes of junk ahead of the next message: {}", junk, String::from_utf8_lossy(&src[..junk]));
+            src.advance(junk);
+        }
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_decoder() -> MyFIXDecoder {
+        let mut config = HashMap::new();
+        config.insert(String::from("version"), String::from("4.2"));
+        config.insert(String::from("sender_comp_id"), String::from("TEST_SERVER"));
+        config.insert(String::from("target_comp_id"), String::from("TEST_CLIENT"));
+        MyFIXDecoder::new(&config)
+    }
+
+    fn build_message(body: &str) -> String {
+        let msg = format!("8=FIX.4.2\x019={}\x01{}", body.len(), body);
+        let cksum = calculate_check_sum(msg.as_bytes());
+        format!("{}10={:03}\x01", msg, cksum)
+    }
+
+    const LOGON_BODY: &str = "35=A\x0149=TEST_CLIENT\x0156=TEST_SERVER\x0134=1\x0152=20250119-16:13:08.931\x0198=0\x01108=30\x01";
+
+    #[test]
+    fn test_decode_valid_checksum() {
+
+        let msg = build_message(LOGON_BODY);
+        let mut buf = BytesMut::from(msg.as_str());
+
+        assert_eq!(create_decoder().decode(&mut buf).unwrap(), Some(Bytes::from(msg)));
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_decode_drops_frame_with_bad_checksum() {
+
+        let good = build_message(LOGON_BODY);
+        let bad = good.replace("108=30", "108=31");
+        let mut buf = BytesMut::from(format!("{}{}", bad, good).as_str());
+        let mut decoder = create_decoder();
+
+        match decoder.decode(&mut buf) {
+            Err(FixDecodeError::ChecksumMismatch { declared, calculated }) => {
+                assert_eq!(declared + 1, calculated);
+            },
+            other => panic!("Expected a checksum mismatch, got {:?}", other),
+        }
+        // Only the corrupt frame is gone, the next one still decodes.
+        assert_eq!(decoder.decode(&mut buf).unwrap(), Some(Bytes::from(good)));
+    }
+
+    #[test]
+    fn test_decode_skips_junk_before_message() {
+
+        let msg = build_message(LOGON_BODY);
+        let mut buf = BytesMut::from(format!("x\x01garbage{}", msg).as_str());
+
+        assert_eq!(create_decoder().decode(&mut buf).unwrap(), Some(Bytes::from(msg)));
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_decode_recovers_from_body_length_past_end_of_frame() {
+
+        let good = build_message(LOGON_BODY);
+        let bad = good.replacen(&format!("9={}", LOGON_BODY.len()), "9=500", 1);
+        let mut buf = BytesMut::from(format!("{}{}", bad, good).as_str());
+        let mut decoder = create_decoder();
+
+        assert!(matches!(decoder.decode(&mut buf), Err(FixDecodeError::InvalidBodyLength)));
+        assert_eq!(decoder.decode(&mut buf).unwrap(), Some(Bytes::from(good)));
+    }
+
+    #[test]
+    fn test_decode_rejects_message_larger_than_max_size() {
+
+        let mut config = HashMap::new();
+        config.insert(String::from("version"), String::from("4.2"));
+        config.insert(String::from("sender_comp_id"), String::from("TEST_SERVER"));
+        config.insert(String::from("target_comp_id"), String::from("TEST_CLIENT"));
+        config.insert(String::from("max_message_size"), String::from("64"));
+        let mut decoder = MyFIXDecoder::new(&config);
+
+        let mut buf = BytesMut::from(build_message(LOGON_BODY).as_str());
+        assert!(matches!(decoder.decode(&mut buf), Err(FixDecodeError::MessageTooLarge { max: 64, .. })));
+        // What's left has no message start, so it all goes as junk.
+        assert_eq!(decoder.decode(&mut buf).unwrap(), None);
+        assert!(buf.len() < 5);
+    }
+
+    #[test]
+    fn test_decode_message_shorter_than_63_bytes() {
+
+        let msg = build_message("35=0\x0134=2\x01");
+        let mut buf = BytesMut::from(msg.as_str());
+
+        assert!(msg.len() < 63);
+        assert_eq!(create_decoder().decode(&mut buf).unwrap(), Some(Bytes::from(msg)));
+    }
+
+    #[test]
+    fn test_decode_fixt_begin_string() {
+
+        let mut config = HashMap::new();
+        config.insert(String::from("version"), String::from("5.0SP2"));
+        config.insert(String::from("sender_comp_id"), String::from("TEST_SERVER"));
+        config.insert(String::from("target_comp_id"), String::from("TEST_CLIENT"));
+        let mut decoder = MyFIXDecoder::new(&config);
+
+        let msg = format!("8=FIXT.1.1\x019={}\x01{}", LOGON_BODY.len(), LOGON_BODY);
+        let msg = format!("{}10={:03}\x01", msg, calculate_check_sum(msg.as_bytes()));
+        let mut buf = BytesMut::from(msg.as_str());
+
+        assert_eq!(decoder.decode(&mut buf).unwrap(), Some(Bytes::from(msg)));
+    }
+
+    #[test]
+    fn test_decode_waits_for_the_rest_of_a_frame() {
+
+        let msg = build_message(LOGON_BODY);
+        let mut decoder = create_decoder();
+        let mut buf = BytesMut::new();
+
+        // However the frame is split the decoder waits until it's complete, never erroring.
+        for b in msg.bytes().take(msg.len() - 1) {
+            buf.extend_from_slice(&[b]);
+            assert_eq!(decoder.decode(&mut buf).unwrap(), None);
+        }
+        buf.extend_from_slice(&msg.as_bytes()[msg.len() - 1..]);
+        assert_eq!(decoder.decode(&mut buf).unwrap(), Some(Bytes::from(msg)));
+    }
+
+    #[test]
+    fn test_decode_rejects_non_numeric_body_length() {
+
+        let good = build_message(LOGON_BODY);
+        let mut buf = BytesMut::from(format!("8=FIX.4.2\x019=1x\x0135=0\x0110=000\x01{}", good).as_str());
+        let mut decoder = create_decoder();
+
+        assert!(matches!(decoder.decode(&mut buf), Err(FixDecodeError::InvalidBodyLength)));
+        assert_eq!(decoder.decode(&mut buf).unwrap(), Some(Bytes::from(good)));
+    }
+
+    #[test]
+    fn test_decode_keeps_binary_data_fields_intact() {
+
+        let body: &[u8] = b"35=B\x0134=2\x0148=XYZ\x0195=4\x0196=\xff\x00\xfe\x01\x0133=0\x01";
+        let mut msg = format!("8=FIX.4.2\x019={}\x01", body.len()).into_bytes();
+        msg.extend_from_slice(body);
+        let cksum = calculate_check_sum(&msg);
+        msg.extend_from_slice(format!("10={:03}\x01", cksum).as_bytes());
+        let mut buf = BytesMut::from(&msg[..]);
+
+        assert_eq!(create_decoder().decode(&mut buf).unwrap(), Some(Bytes::from(msg)));
     }
 }
@@ -1,57 +1,411 @@
-pub struct FixMessage {
-    header   : String,
-    body     : String,
-    trailer  : String,
-    msg_type : char
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+use std::str::FromStr;
+use bytes::Bytes;
+use chrono::NaiveDateTime;
+use rust_decimal::Decimal;
+use crate::fix_42::attribute_enums::MsgType;
+use crate::spec::fix42::fields;
+use crate::fix_42::value_types::{parse_decimal, FieldValue, FixTag};
+
+const FIX_SEPARATOR: u8 = b'\x01';
+
+// Why a typed getter couldn't return a value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldError {
+    // The tag isn't in the message at all.
+    Missing(u32),
+    // The tag is there but its value isn't of the type asked for.
+    Invalid(u32),
+    // A NumInGroup tag that doesn't match the number of instances that follow it.
+    IncorrectNumInGroupCount(u32),
 }
 
-impl FixMessage {
-    pub fn get_msg_type(&self) -> char {
-        self.msg_type
-    }
-    pub fn get_body(&self) -> String {
-        self.body.clone()
+impl Display for FieldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldError::Missing(tag) => write!(f, "Tag {} missing", tag),
+            FieldError::Invalid(tag) => write!(f, "Incorrect data format for value of tag {}", tag),
+            FieldError::IncorrectNumInGroupCount(tag) => write!(f, "Incorrect NumInGroup count for tag {}", tag),
+        }
     }
 }
 
+// A received message. The frame is kept exactly as it arrived and is indexed once, up front, so a
+// field lookup is a scan of the index and a slice of the frame rather than a copy or a re-parse.
+//
+// 8=FIX.4.2^9=77^35=A^34=1^49=TEST_CLIENT^56=TEST_SERVER^52=20250119-16:13:08.931^98=0^108=30^10=217^
+#[derive(Clone)]
+pub struct FixMessage {
+    frame    : Bytes,
+    // Every field in the order it appears, as (tag, range of its value within the frame). A tag
+    // that isn't a number is indexed as 0.
+    fields   : Vec<(u32, Range<usize>)>,
+    msg_type : MsgType
+}
+
 impl FixMessage {
 
-    pub fn dummy(message: &String, msg_type: char) -> Self {
+    pub fn dummy(message: &str, msg_type: MsgType) -> Self {
         Self {
-            header   : String::from(""),
-            body     : message.to_string(),
-            trailer  : String::from(""),
-            msg_type
+            msg_type,
+            ..Self::new(Bytes::copy_from_slice(message.as_bytes()))
         }
     }
-    // |-----header1------|-----------------header2-----------------------------------|---body----|-trlr-|
-    // |
-    // 8=FIX.4.2^9=77^35=A^34=0^49=TEST_CLIENT^56=TEST_SERVER^52=20250119-16:13:08.931^98=0^108=30^10=217^
-    pub fn new(message: &String) -> Self {
 
-        let mut indices = Vec::new();
+    pub fn new(frame: Bytes) -> Self {
+
+        let mut fields = Vec::with_capacity(32);
+        let mut start = 0;
+        // The data field the last length field was for, and how long its value is.
+        let mut data_len: Option<(u32, usize)> = None;
+
+        while start < frame.len() {
+
+            let mut end = frame[start..].iter().position(|b| *b == FIX_SEPARATOR).map_or(frame.len(), |p| start + p);
+            let field = &frame[start..end];
 
-        for i in 0..message.len() {
-            if message.chars().nth(i).unwrap()== '' {
-                indices.push(i);
+            // A field without an '=' is indexed with an empty value, it's up to the session to reject it.
+            match field.iter().position(|b| *b == b'=') {
+                Some(eq) => {
+                    let tag = parse_tag(&field[..eq]);
+                    let value_start = start + eq + 1;
+                    // A data field's value may hold SOH itself, so it ends where its length field says.
+                    // If that doesn't land on a field boundary the length is wrong and is ignored.
+                    if let Some((data_tag, len)) = data_len.take() {
+                        let data_end = value_start + len;
+                        if tag == data_tag && (data_end == frame.len() || frame.get(data_end) == Some(&FIX_SEPARATOR)) {
+                            end = data_end;
+                        }
+                    }
+                    data_len = fields::data_field_of(tag).zip(parse_len(&frame[value_start..end]));
+                    fields.push((tag, value_start..end));
+                },
+                None => fields.push((parse_tag(field), end..end)),
             }
+            start = end + 1;
         }
 
-        let header_end_idx    = *indices.get(2).unwrap();
-        let trailer_start_idx = *indices.get(indices.len()-2).unwrap();
-        let trailer_end_idx   = *indices.get(indices.len()-1).unwrap();
+        let mut msg = Self {
+            frame,
+            fields,
+            msg_type : MsgType::Custom(String::new())
+        };
+        // Found by tag number, so it doesn't matter where in the header it appears.
+        if let Ok(msg_type) = msg.get_str(&fields::MSG_TYPE) {
+            msg.msg_type = MsgType::from(msg_type);
+        }
+        msg
+    }
 
-        let hd = &message[0..header_end_idx];
-        let bd = &message[header_end_idx..trailer_start_idx];
-        let tr = &message[trailer_start_idx..trailer_end_idx];
+    pub fn get_msg_type(&self) -> &MsgType {
+        &self.msg_type
+    }
 
-        let mt = message.as_bytes()[header_end_idx-1] as char;
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.frame
+    }
 
-        Self {
-            header   : hd.to_string(),
-            body     : bd.to_string(),
-            trailer  : tr.to_string(),
-            msg_type : mt
+    // The whole message as one set of fields, which is where the getters below look.
+    pub fn as_group(&self) -> Group<'_> {
+        Group {
+            frame  : &self.frame,
+            fields : &self.fields
+        }
+    }
+
+    // Every (tag, value) pair, in the order they appear in the message.
+    pub fn fields(&self) -> impl Iterator<Item = (u32, &[u8])> {
+        self.as_group().fields()
+    }
+
+    pub fn contains(&self, tag: &FixTag) -> bool {
+        self.as_group().contains(tag)
+    }
+
+    pub fn get_raw(&self, tag: &FixTag) -> Option<&[u8]> {
+        self.as_group().get_raw(tag)
+    }
+
+    pub fn get_str(&self, tag: &FixTag) -> Result<&str, FieldError> {
+        self.as_group().get_str(tag)
+    }
+
+    pub fn get_int(&self, tag: &FixTag) -> Result<i64, FieldError> {
+        self.as_group().get_int(tag)
+    }
+
+    pub fn get_char(&self, tag: &FixTag) -> Result<char, FieldError> {
+        self.as_group().get_char(tag)
+    }
+
+    pub fn get_bool(&self, tag: &FixTag) -> Result<bool, FieldError> {
+        self.as_group().get_bool(tag)
+    }
+
+    pub fn get_timestamp(&self, tag: &FixTag) -> Result<NaiveDateTime, FieldError> {
+        self.as_group().get_timestamp(tag)
+    }
+
+    pub fn get_decimal(&self, tag: &FixTag) -> Result<Decimal, FieldError> {
+        self.as_group().get_decimal(tag)
+    }
+
+    pub fn get_value(&self, tag: &FixTag) -> Result<FieldValue, FieldError> {
+        self.as_group().get_value(tag)
+    }
+
+    // The instances of a repeating group in the body of the message.
+    pub fn get_group(&self, def: &GroupDef) -> Result<Vec<Group<'_>>, FieldError> {
+        self.as_group().get_group(def)
+    }
+}
+
+// A repeating group: the NumInGroup tag that gives the number of instances, the tag each instance
+// must start with, and every other tag that may appear in an instance. Groups nested inside an
+// instance are listed separately, their NumInGroup tag and members belong to the instance too.
+//
+// 73=2^11=ORD1^67=1^55=IBM^54=1^38=100^40=1^11=ORD2^67=2^55=MSFT^54=2^38=200^40=1^
+//      |-- first instance ------------|-- second instance ------------|
+pub struct GroupDef {
+    pub count_tag : &'static FixTag,
+    pub delimiter : &'static FixTag,
+    pub members   : &'static [&'static FixTag],
+    pub groups    : &'static [&'static GroupDef],
+}
+
+impl GroupDef {
+
+    // Whether the tag can appear in an instance after the delimiter, including within a nested group.
+    pub fn contains(&self, tag: u32) -> bool {
+        self.members.iter().any(|member| member.number() == tag)
+            || self.groups.iter().any(|group| group.count_tag.number() == tag || group.delimiter.number() == tag || group.contains(tag))
+    }
+}
+
+// A run of fields within a message, either the whole message or one instance of a repeating group.
+// It borrows the message's frame and index, so nothing is copied.
+#[derive(Clone, Copy)]
+pub struct Group<'a> {
+    frame  : &'a Bytes,
+    fields : &'a [(u32, Range<usize>)]
+}
+
+impl<'a> Group<'a> {
+
+    // Every (tag, value) pair, in the order they appear.
+    pub fn fields(&self) -> impl Iterator<Item = (u32, &'a [u8])> + 'a {
+        let frame = self.frame;
+        self.fields.iter().map(move |(tag, value)| (*tag, &frame[value.clone()]))
+    }
+
+    pub fn contains(&self, tag: &FixTag) -> bool {
+        self.get_raw(tag).is_some()
+    }
+
+    // The first occurrence of the tag, as it appears on the wire.
+    pub fn get_raw(&self, tag: &FixTag) -> Option<&'a [u8]> {
+        let number = tag.number();
+        self.fields.iter()
+            .find(|(t, _)| *t == number)
+            .map(|(_, value)| &self.frame[value.clone()])
+    }
+
+    pub fn get_str(&self, tag: &FixTag) -> Result<&'a str, FieldError> {
+        let value = self.get_raw(tag).ok_or(FieldError::Missing(tag.number()))?;
+        std::str::from_utf8(value).map_err(|_| FieldError::Invalid(tag.number()))
+    }
+
+    pub fn get_int(&self, tag: &FixTag) -> Result<i64, FieldError> {
+        self.parse(tag)
+    }
+
+    pub fn get_char(&self, tag: &FixTag) -> Result<char, FieldError> {
+        match self.get_raw(tag) {
+            Some([c]) if c.is_ascii() => Ok(*c as char),
+            Some(_) => Err(FieldError::Invalid(tag.number())),
+            None => Err(FieldError::Missing(tag.number())),
         }
     }
-}
\ No newline at end of file
+
+    // Y or N
+    pub fn get_bool(&self, tag: &FixTag) -> Result<bool, FieldError> {
+        match self.get_char(tag)? {
+            'Y' => Ok(true),
+            'N' => Ok(false),
+            _ => Err(FieldError::Invalid(tag.number())),
+        }
+    }
+
+    // UTCTimestamp, YYYYMMDD-HH:MM:SS with optional milliseconds.
+    pub fn get_timestamp(&self, tag: &FixTag) -> Result<NaiveDateTime, FieldError> {
+        let value = self.get_str(tag)?;
+        NaiveDateTime::parse_from_str(value, "%Y%m%d-%H:%M:%S%.f")
+            .map_err(|_| FieldError::Invalid(tag.number()))
+    }
+
+    // Prices, quantities and amounts, held exactly rather than as floating point.
+    pub fn get_decimal(&self, tag: &FixTag) -> Result<Decimal, FieldError> {
+        let value = self.get_str(tag)?;
+        parse_decimal(value).ok_or(FieldError::Invalid(tag.number()))
+    }
+
+    // The value parsed according to the tag's datatype.
+    pub fn get_value(&self, tag: &FixTag) -> Result<FieldValue, FieldError> {
+        let value = self.get_str(tag)?;
+        tag.parse(value).map_err(|_| FieldError::Invalid(tag.number()))
+    }
+
+    // The instances of a repeating group within these fields. Each instance runs from its delimiter
+    // up to the next delimiter or the first tag that isn't a member, and the number found has to
+    // match the NumInGroup tag. A group that isn't there at all has no instances.
+    pub fn get_group(&self, def: &GroupDef) -> Result<Vec<Group<'a>>, FieldError> {
+
+        let count_tag = def.count_tag.number();
+        let Some(start) = self.fields.iter().position(|(t, _)| *t == count_tag) else {
+            return Ok(Vec::new());
+        };
+        let count = self.get_int(def.count_tag)?;
+
+        let delimiter = def.delimiter.number();
+        let mut instances = Vec::new();
+        let mut i = start + 1;
+
+        while i < self.fields.len() && self.fields[i].0 == delimiter {
+            let instance_start = i;
+            i += 1;
+            while i < self.fields.len() && self.fields[i].0 != delimiter && def.contains(self.fields[i].0) {
+                i += 1;
+            }
+            instances.push(Group {
+                frame  : self.frame,
+                fields : &self.fields[instance_start..i]
+            });
+        }
+
+        if instances.len() as i64 != count {
+            return Err(FieldError::IncorrectNumInGroupCount(count_tag));
+        }
+        Ok(instances)
+    }
+
+    fn parse<T: FromStr>(&self, tag: &FixTag) -> Result<T, FieldError> {
+        self.get_str(tag)?.parse().map_err(|_| FieldError::Invalid(tag.number()))
+    }
+}
+
+// Tag numbers are positive integers, anything else comes back as 0.
+fn parse_tag(tag: &[u8]) -> u32 {
+    if tag.is_empty() || tag.len() > 9 || !tag.iter().all(u8::is_ascii_digit) {
+        return 0;
+    }
+    tag.iter().fold(0, |n, d| n * 10 + (d - b'0') as u32)
+}
+
+fn parse_len(value: &[u8]) -> Option<usize> {
+    std::str::from_utf8(value).ok()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::fix42::messages;
+
+    const LOGON: &str = "8=FIX.4.2\x019=77\x0135=A\x0134=1\x0149=TEST_CLIENT\x0156=TEST_SERVER\x0152=20250119-16:13:08.931\x0198=0\x01108=30\x01141=Y\x0110=217\x01";
+
+    #[test]
+    fn test_typed_getters() {
+
+        let msg = FixMessage::new(Bytes::from_static(LOGON.as_bytes()));
+
+        assert_eq!(msg.get_msg_type(), &MsgType::Logon);
+        assert_eq!(msg.get_str(&fields::SENDER_COMP_ID), Ok("TEST_CLIENT"));
+        assert_eq!(msg.get_int(&fields::MSG_SEQ_NUM), Ok(1));
+        assert_eq!(msg.get_int(&fields::HEART_BT_INT), Ok(30));
+        assert_eq!(msg.get_char(&fields::MSG_TYPE), Ok('A'));
+        assert_eq!(msg.get_bool(&fields::RESET_SEQ_NUM_FLAG), Ok(true));
+        assert_eq!(msg.get_timestamp(&fields::SENDING_TIME).unwrap().to_string(), "2025-01-19 16:13:08.931");
+        assert_eq!(msg.get_int(&fields::TEST_REQ_ID), Err(FieldError::Missing(112)));
+        assert_eq!(msg.get_char(&fields::SENDER_COMP_ID), Err(FieldError::Invalid(49)));
+        assert_eq!(msg.fields().count(), 11);
+    }
+
+    #[test]
+    fn test_get_decimal_is_exact() {
+
+        let msg = FixMessage::new(Bytes::from_static(b"35=D\x0144=0.1\x0138=1e3\x0110=000\x01"));
+
+        assert_eq!(msg.get_decimal(&fields::PRICE).unwrap().to_string(), "0.1");
+        assert_eq!(msg.get_decimal(&fields::ORDER_QTY), Err(FieldError::Invalid(38)));
+    }
+
+    #[test]
+    fn test_badly_formed_fields_are_indexed() {
+
+        let msg = FixMessage::new(Bytes::from_static(b"35=0\x01abc=1\x01112\x0110=000\x01"));
+        let fields: Vec<(u32, &[u8])> = msg.fields().collect();
+
+        assert_eq!(fields[1], (0, &b"1"[..]));
+        assert_eq!(fields[2], (112, &b""[..]));
+    }
+
+    #[test]
+    fn test_data_field_may_hold_soh() {
+
+        let msg = FixMessage::new(Bytes::from_static(b"35=B\x01148=NEWS\x0195=8\x0196=a\x0134=9\x01b\x0158=TEXT\x0110=000\x01"));
+
+        assert_eq!(msg.get_raw(&fields::RAW_DATA), Some(&b"a\x0134=9\x01b"[..]));
+        assert_eq!(msg.get_str(&fields::TEXT), Ok("TEXT"));
+        assert!(!msg.contains(&fields::MSG_SEQ_NUM));
+    }
+
+    #[test]
+    fn test_data_field_with_wrong_length_ends_at_soh() {
+
+        let msg = FixMessage::new(Bytes::from_static(b"35=B\x0195=3\x0196=abcdef\x0158=TEXT\x0110=000\x01"));
+
+        assert_eq!(msg.get_raw(&fields::RAW_DATA), Some(&b"abcdef"[..]));
+        assert_eq!(msg.get_str(&fields::TEXT), Ok("TEXT"));
+    }
+
+    #[test]
+    fn test_msg_type_found_by_tag_number() {
+
+        let msg = FixMessage::new(Bytes::from_static(b"8=FIXT.1.1\x019=20\x0149=A\x0135=AE\x0134=1\x0110=000\x01"));
+        assert_eq!(msg.get_msg_type(), &MsgType::Custom(String::from("AE")));
+    }
+
+    #[test]
+    fn test_nested_groups() {
+
+        let list = "35=E\x0166=LIST1\x0168=2\x0173=2\
+\x0111=ORD1\x0167=1\x0155=IBM\x0154=1\x0178=2\x0179=ACC1\x0180=60\x0179=ACC2\x0180=40\x0138=100\x0140=1\
+\x0111=ORD2\x0167=2\x0155=MSFT\x0154=2\x0138=200\x0140=2\x0144=10.5\
+\x01394=3\x0110=000\x01";
+        let msg = FixMessage::new(Bytes::from(list));
+
+        let orders = msg.get_group(&messages::NEW_ORDER_LIST_NO_ORDERS).unwrap();
+        assert_eq!(orders.len(), 2);
+        assert_eq!(orders[0].get_str(&fields::CL_ORD_ID), Ok("ORD1"));
+        assert_eq!(orders[0].get_int(&fields::ORDER_QTY), Ok(100));
+        assert_eq!(orders[1].get_str(&fields::SYMBOL), Ok("MSFT"));
+        assert_eq!(orders[1].get_decimal(&fields::PRICE).unwrap().to_string(), "10.5");
+        // Anything after the last instance that isn't a member belongs to the message.
+        assert_eq!(orders[1].fields().count(), 7);
+
+        let allocs = orders[0].get_group(&messages::NEW_ORDER_LIST_NO_ORDERS_NO_ALLOCS).unwrap();
+        let accounts: Vec<&str> = allocs.iter().map(|alloc| alloc.get_str(&fields::ALLOC_ACCOUNT).unwrap()).collect();
+        assert_eq!(accounts, ["ACC1", "ACC2"]);
+        assert!(orders[1].get_group(&messages::NEW_ORDER_LIST_NO_ORDERS_NO_ALLOCS).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_group_count_must_match() {
+
+        let msg = FixMessage::new(Bytes::from_static(b"35=B\x01148=Headline\x0133=3\x0158=one\x0158=two\x0110=000\x01"));
+        assert_eq!(msg.get_group(&messages::NEWS_LINES_OF_TEXT).err(), Some(FieldError::IncorrectNumInGroupCount(33)));
+
+        let msg = FixMessage::new(Bytes::from_static(b"35=B\x01148=Headline\x0133=2\x0158=one\x0110=000\x0158=two\x01"));
+        assert_eq!(msg.get_group(&messages::NEWS_LINES_OF_TEXT).err(), Some(FieldError::IncorrectNumInGroupCount(33)));
+    }
+}
@@ -1,231 +1,324 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Write};
+use bytes::{Bytes, BytesMut};
 use chrono::DateTime;
-use crate::fix_42;
-use crate::fix_42::*;
+use rust_decimal::Decimal;
 use crate::fix_42::attribute_enums::*;
-use crate::fix_42::value_types::FixTag;
+use crate::fix_42::value_types::{FieldValue, FixTag};
+use crate::fix_message::{FixMessage, GroupDef};
+use crate::spec::fix42::fields;
 
 const FIX_FIELD_SEPARATOR: u8 = 0x01;
 
-struct SessionHeader {
-    header_one       : String,
-    header_one_len   : usize,
-    header_two       : String,
-    header_two_len   : usize,
+// A version in the session's config that we don't have a BeginString for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedVersion(pub String);
+
+impl Display for UnsupportedVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unsupported FIX version {}", self.0)
+    }
+}
+
+// The parts of the standard header that stay the same for every message sent on a session.
+#[derive(Clone)]
+pub struct SessionHeader {
+    begin_string       : String,
+    sender_comp_id     : String,
+    target_comp_id     : String,
+    // Only sent when configured, some venues need them to route within the firm.
+    sender_sub_id      : Option<String>,
+    target_sub_id      : Option<String>,
+    sender_location_id : Option<String>,
 }
 
 impl SessionHeader {
 
-    fn new(fix_version: &str, sender_comp_id: &str, target_comp_id: &str) -> SessionHeader {
+    pub fn new(begin_string: &str, sender_comp_id: &str, target_comp_id: &str) -> SessionHeader {
+        SessionHeader {
+            begin_string       : String::from(begin_string),
+            sender_comp_id     : String::from(sender_comp_id),
+            target_comp_id     : String::from(target_comp_id),
+            sender_sub_id      : None,
+            target_sub_id      : None,
+            sender_location_id : None,
+        }
+    }
 
-        let mut new = SessionHeader {
-            header_one : String::new(),
-            header_one_len : 0,
-            header_two : String::new(),
-            header_two_len : 0
-        };
+    // BeginString comes from begin_string if it's set, otherwise from version.
+    pub fn from_config(config: &HashMap<String, String>) -> Result<SessionHeader, UnsupportedVersion> {
 
-        new.header_one.push_str(fix_version);
-        new.header_one.push_str("9=");
-        new.header_one_len = new.header_one.len();
+        let optional = |key: &str| config.get(key).filter(|v| !v.is_empty()).cloned();
 
-        new.header_two.push_str("49=");
-        new.header_two.push_str(sender_comp_id);
-        new.header_two.push_str("56=");
-        new.header_two.push_str(target_comp_id);
-        new.header_two_len = new.header_two.len();
+        let begin_string = match optional("begin_string") {
+            Some(begin_string) => begin_string,
+            None => begin_string_for_version(config.get("version").map(String::as_str).unwrap_or("4.2"))?,
+        };
 
-        new
+        Ok(SessionHeader {
+            begin_string,
+            sender_comp_id     : config.get("sender_comp_id").cloned().unwrap_or_default(),
+            target_comp_id     : config.get("target_comp_id").cloned().unwrap_or_default(),
+            sender_sub_id      : optional("sender_sub_id"),
+            target_sub_id      : optional("target_sub_id"),
+            sender_location_id : optional("sender_location_id"),
+        })
     }
-}
-
 
-fn new_create_fix_pre_body(buf :&mut String, header_two :&str, seq_no :&i32, msg_type :MsgType) {
-
-    //Pre-Body
-    buf.push_str("35=");
-    buf.push(msg_type.value());
-    buf.push_str(header_two);
-    buf.push_str("34=");
-    buf.push_str(seq_no.to_string().as_str());
-    buf.push_str("52=");
-    buf.push_str(chrono::offset::Utc::now().format("%Y%m%d-%H:%M:%S%.3f").to_string().as_str());
+    pub fn begin_string(&self) -> &str { &self.begin_string }
+    pub fn sender_comp_id(&self) -> &str { &self.sender_comp_id }
+    pub fn target_comp_id(&self) -> &str { &self.target_comp_id }
 }
 
-fn new_create_fix_header(buf:&mut String, header_one: &String, pre_body:&str, seq_no:&i32, msg_type: MsgType) {
-
-    //Total length will be 13 + lengthOf(BodyLengthValue)
-    buf.push_str(header_one);
-    buf.push_str(pre_body.len().to_string().as_str());
-    buf.push('');
-    buf.push_str(pre_body);
+// FIX 5.0 and later are carried over the FIXT.1.1 session protocol.
+pub fn begin_string_for_version(version: &str) -> Result<String, UnsupportedVersion> {
+    match version {
+        "4.2" | "4.4" => Ok(format!("FIX.{}", version)),
+        "5.0" | "5.0SP1" | "5.0SP2" => Ok(String::from("FIXT.1.1")),
+        _ => Err(UnsupportedVersion(String::from(version)))
+    }
 }
-fn create_fix_header(buf:&mut String, length:usize, seq_no:&i32, msg_type: fix_42::attribute_enums::MsgType) {
-
 
-    //"8=FIX.4.2|9=74|35=0"
-    let mut tmp:String = String::from("");
+// Builds outbound messages for a session. A message is started with its MsgType, the body is
+// added field by field, and finish() wraps it in the header and trailer, i.e. BeginString,
+// BodyLength, MsgType, the CompIDs, MsgSeqNum, SendingTime and CheckSum are never added by hand.
+//
+// let msg = builder.start(MsgType::TestRequest)
+//     .add_string_field(fields::TEST_REQ_ID, "TEST")
+//     .finish(seq_no);
+//
+// The buffers are kept and reused from one message to the next, so once they've grown to the
+// size of the largest message sent, building a message doesn't allocate.
+pub struct FixMessageBuilder {
+    header   : SessionHeader,
+    msg_type : MsgType,
+    body     : BytesMut,
+    out      : BytesMut,
+}
 
-    add_char_field(&mut tmp, tags::MSG_TYPE, msg_type.value());
-    add_string_field(&mut tmp, tags::SENDER_COMP_ID, "TEST_SERVER");
-    add_string_field(&mut tmp, tags::TARGET_COMP_ID, "TEST_CLIENT");
-    add_seqnum_field(&mut tmp, tags::MSG_SEQ_NO,*seq_no);
-    add_timestamp_field(&mut tmp, tags::SENDING_TIME, chrono::offset::Utc::now());
+impl FixMessageBuilder {
 
-    add_string_field(buf, tags::BEGIN_STRING, "FIX.4.2");
-    add_unsigned_field(buf, tags::BODY_LENGTH, length + tmp.len());
+    pub fn new(header: SessionHeader) -> FixMessageBuilder {
+        FixMessageBuilder {
+            header,
+            msg_type : MsgType::HeartBeat,
+            body     : BytesMut::with_capacity(256),
+            out      : BytesMut::with_capacity(512),
+        }
+    }
 
-    buf.push_str(&tmp);
-}
+    pub fn header(&self) -> &SessionHeader { &self.header }
 
-fn create_fix_trailer(buf:&mut String)  {
+    // Throws away anything left over from a message that was never finished.
+    pub fn start(&mut self, msg_type: MsgType) -> &mut Self {
+        self.msg_type = msg_type;
+        self.body.clear();
+        self
+    }
 
-    add_checksum_field(buf, tags::CHECK_SUM, generate_check_sum(buf));
-}
-pub fn new_create_fix_heartbeat(buf:&mut String, hdr: &SessionHeader, seq_no:i32, test_request_id: &str){
+    pub fn add_string_field(&mut self, tag: FixTag, value: &str) -> &mut Self {
+        add_string_field(&mut self.body, tag, value);
+        self
+    }
 
-    let mut pre_body:String = String::new();
+    pub fn add_char_field(&mut self, tag: FixTag, value: char) -> &mut Self {
+        add_char_field(&mut self.body, tag, value);
+        self
+    }
 
-    new_create_fix_pre_body(&mut pre_body, &hdr.header_two, &seq_no, MsgType::HeartBeat );
+    pub fn add_bool_field(&mut self, tag: FixTag, value: bool) -> &mut Self {
+        add_char_field(&mut self.body, tag, if value { 'Y' } else { 'N' });
+        self
+    }
 
-    if test_request_id.len() > 0 {
-        add_string_field(&mut pre_body, tags::TEST_REQ_ID, test_request_id);
+    pub fn add_int_field(&mut self, tag: FixTag, value: i64) -> &mut Self {
+        add_int_field(&mut self.body, tag, value);
+        self
     }
-    // Then once complete calculate the overall length using the body length as input
-    // and prepend to the start of the msg.
-    new_create_fix_header(buf, &hdr.header_one, &pre_body, &seq_no, MsgType::HeartBeat);
 
-    // Finally calculate the checksum as append it the end
-    create_fix_trailer(buf);
-}
-pub fn create_fix_heartbeat(buf:&mut String, seq_no:i32, test_request_id: &str){
+    pub fn add_seqnum_field(&mut self, tag: FixTag, value: i32) -> &mut Self {
+        add_seqnum_field(&mut self.body, tag, value);
+        self
+    }
 
-    let mut tmp:String = String::new();
+    pub fn add_u64_field(&mut self, tag: FixTag, value: u64) -> &mut Self {
+        add_u64_field(&mut self.body, tag, value);
+        self
+    }
 
-    if test_request_id.len() > 0 {
-        add_string_field(&mut tmp, tags::TEST_REQ_ID, test_request_id);
+    // Prices, quantities and amounts.
+    pub fn add_decimal_field(&mut self, tag: FixTag, value: Decimal) -> &mut Self {
+        add_decimal_field(&mut self.body, tag, value);
+        self
     }
-    // Then once complete calculate the overall length using the body length as input
-    // and prepend to the start of the msg.
-    create_fix_header(buf, tmp.len(), &seq_no, attribute_enums::MsgType::HeartBeat);
 
-    buf.push_str(&tmp);
-    // Finally calculate the checksum as append it the end
-    create_fix_trailer(buf);
-}
+    pub fn add_timestamp_field(&mut self, tag: FixTag, value: DateTime<chrono::offset::Utc>) -> &mut Self {
+        add_timestamp_field(&mut self.body, tag, value);
+        self
+    }
 
-pub fn create_fix_logon(buf:&mut String, seq_no:i32, hb_interval: u64, encryption_method :attribute_enums::EncryptMethod) {
-    let mut tmp: String = String::from("");
+    // Any value, formatted as its type requires.
+    pub fn add_value_field(&mut self, tag: FixTag, value: &FieldValue) -> &mut Self {
+        add_field(&mut self.body, tag, value);
+        self
+    }
 
-    add_char_field(&mut tmp, tags::ENCRYPT_METHOD, encryption_method.value());
-    add_u64_field(&mut tmp, tags::HEARTBT_INT, hb_interval);
+    // A field whose value is already encoded, e.g. copied from another message.
+    pub fn add_raw_field(&mut self, tag: &str, value: &[u8]) -> &mut Self {
+        add_raw_field(&mut self.body, tag, value);
+        self
+    }
 
-    // Then once complete calculate the overall length using the body length as input
-    // and prepend to the start of the msg.
-    create_fix_header(buf, tmp.len(), &seq_no, attribute_enums::MsgType::Logon);
+    // A repeating group: its NumInGroup tag with the number of entries, then add_entry is called to
+    // write the fields of each one, starting with the group's delimiter. Nested groups are added the
+    // same way from inside add_entry.
+    //
+    // builder.add_group(&messages::NEW_ORDER_LIST_NO_ORDERS, &orders, |builder, order| {
+    //     builder.add_string_field(fields::CL_ORD_ID, &order.cl_ord_id)
+    //         .add_group(&messages::NEW_ORDER_LIST_NO_ORDERS_NO_ALLOCS, &order.allocs, |builder, alloc| { ... });
+    // });
+    pub fn add_group<T>(&mut self, def: &GroupDef, entries: &[T], mut add_entry: impl FnMut(&mut Self, &T)) -> &mut Self {
+
+        let _ = write!(self.body, "{}={}\x01", def.count_tag.id(), entries.len());
+
+        for entry in entries {
+            let entry_start = self.body.len();
+            add_entry(self, entry);
+            debug_assert!(self.body[entry_start..].starts_with(format!("{}=", def.delimiter.id()).as_bytes()),
+                "each entry of group {} must start with tag {}", def.count_tag.id(), def.delimiter.id());
+        }
+        self
+    }
 
-    buf.push_str(&tmp);
-    // Finally calculate the checksum as append it the end
-    create_fix_trailer(buf);
+    // Adds the header and trailer to the body built up so far and hands back the finished message.
+    pub fn finish(&mut self, seq_no: i32) -> Bytes {
+
+        // The header fields after BodyLength are written straight after the body so BodyLength is
+        // known, then moved in front of it.
+        let body_len = self.body.len();
+        add_string_field(&mut self.body, fields::MSG_TYPE, self.msg_type.as_str());
+        add_string_field(&mut self.body, fields::SENDER_COMP_ID, &self.header.sender_comp_id);
+        add_string_field(&mut self.body, fields::TARGET_COMP_ID, &self.header.target_comp_id);
+        if let Some(sender_sub_id) = &self.header.sender_sub_id {
+            add_string_field(&mut self.body, fields::SENDER_SUB_ID, sender_sub_id);
+        }
+        if let Some(target_sub_id) = &self.header.target_sub_id {
+            add_string_field(&mut self.body, fields::TARGET_SUB_ID, target_sub_id);
+        }
+        if let Some(sender_location_id) = &self.header.sender_location_id {
+            add_string_field(&mut self.body, fields::SENDER_LOCATION_ID, sender_location_id);
+        }
+        add_seqnum_field(&mut self.body, fields::MSG_SEQ_NUM, seq_no);
+        add_timestamp_field(&mut self.body, fields::SENDING_TIME, chrono::offset::Utc::now());
+
+        self.out.clear();
+        add_string_field(&mut self.out, fields::BEGIN_STRING, &self.header.begin_string);
+        add_unsigned_field(&mut self.out, fields::BODY_LENGTH, self.body.len());
+        self.out.extend_from_slice(&self.body[body_len..]);
+        self.out.extend_from_slice(&self.body[..body_len]);
+        self.body.clear();
+
+        add_checksum_field(&mut self.out);
+        self.out.split().freeze()
+    }
 }
 
-fn create_fix_test_request(buf:&mut String, seq_no:i32) {
+// Rebuilds a previously sent message for replay in response to a ResendRequest.
+// The original SendingTime is moved into OrigSendingTime (122), SendingTime is refreshed,
+// PossDupFlag=Y is added and BodyLength/CheckSum are recomputed. Everything else is untouched.
+pub fn create_fix_poss_dup(original:&[u8]) -> Bytes {
+
+    let mut begin_string: &[u8] = b"";
+    let mut tmp = BytesMut::with_capacity(original.len() + 64);
+
+    // Indexed rather than split on SOH, so a data field holding SOH is copied whole.
+    let original = FixMessage::new(Bytes::copy_from_slice(original));
+
+    for (tag, value) in original.fields() {
+
+        if tag == fields::BEGIN_STRING.number() {
+            begin_string = value;
+        } else if tag == 0 || tag == fields::BODY_LENGTH.number() || tag == fields::CHECK_SUM.number() || tag == fields::POSS_DUP_FLAG.number() || tag == fields::ORIG_SENDING_TIME.number() {
+            // recomputed or re-added below, or not a field at all
+        } else if tag == fields::SENDING_TIME.number() {
+            add_timestamp_field(&mut tmp, fields::SENDING_TIME, chrono::offset::Utc::now());
+            add_char_field(&mut tmp, fields::POSS_DUP_FLAG, 'Y');
+            add_raw_field(&mut tmp, fields::ORIG_SENDING_TIME.id(), value);
+        } else {
+            let _ = write!(tmp, "{}=", tag);
+            tmp.extend_from_slice(value);
+            tmp.extend_from_slice(&[FIX_FIELD_SEPARATOR]);
+        }
+    }
 
-    let mut tmp: String = String::from("");
-    // Then once complete calculate the overall length using the body length as input
-    // and prepend to the start of the msg.
-    create_fix_header(buf, tmp.len(), &seq_no, attribute_enums::MsgType::TestRequest);
-    add_string_field(&mut tmp, tags::TEST_REQ_ID, chrono::offset::Utc::now().format("%Y%m%d%H%M%S%3f").to_string().as_str());
-    buf.push_str(&tmp);
-    // Finally calculate the checksum as append it the end
-    create_fix_trailer(buf);
+    let mut buf = BytesMut::with_capacity(tmp.len() + 32);
+    add_raw_field(&mut buf, fields::BEGIN_STRING.id(), begin_string);
+    add_unsigned_field(&mut buf, fields::BODY_LENGTH, tmp.len());
+    buf.extend_from_slice(&tmp);
+    add_checksum_field(&mut buf);
+    buf.freeze()
 }
 
+// Writing to a BytesMut can't fail, it just grows.
+fn add_field(buf:&mut BytesMut, tag :FixTag, value :impl Display) {
+    let _ = write!(buf, "{}={}\x01", tag.id(), value);
+}
 
-fn add_checksum_field(buf:&mut String, tag :FixTag, cksum:usize){
-    buf.push_str(tag.id());
-    buf.push('=');
-    buf.push_str(format!("{:03}",cksum).as_str());
-    buf.push('');
+fn add_raw_field(buf:&mut BytesMut, tag :&str, value :&[u8]) {
+    buf.extend_from_slice(tag.as_bytes());
+    buf.extend_from_slice(b"=");
+    buf.extend_from_slice(value);
+    buf.extend_from_slice(&[FIX_FIELD_SEPARATOR]);
 }
 
-fn add_timestamp_field(buf:&mut String, tag :FixTag, timestamp:DateTime<chrono::offset::Utc>){
-    buf.push_str(tag.id());
-    buf.push('=');
-    buf.push_str(timestamp.format("%Y%m%d-%H:%M:%S%.3f").to_string().as_str());
-    buf.push('');
+// Covers everything already in the buffer.
+fn add_checksum_field(buf:&mut BytesMut){
+    let cksum = generate_check_sum_bytes(buf);
+    let _ = write!(buf, "{}={:03}\x01", fields::CHECK_SUM.id(), cksum);
 }
-fn add_seqnum_field(buf:&mut String, tag: FixTag, seq_num: i32) {
-    buf.push_str(tag.id());
-    buf.push('=');
-    buf.push_str(seq_num.to_string().as_str());
-    buf.push('');
+
+fn add_timestamp_field(buf:&mut BytesMut, tag :FixTag, timestamp:DateTime<chrono::offset::Utc>){
+    add_field(buf, tag, timestamp.format("%Y%m%d-%H:%M:%S%.3f"));
 }
 
-fn add_char_field(buf:&mut String, tag : FixTag, value : char) {
-    buf.push_str(tag.id());
-    buf.push('=');
-    buf.push(value);
-    buf.push('');
+fn add_seqnum_field(buf:&mut BytesMut, tag: FixTag, seq_num: i32) {
+    add_field(buf, tag, seq_num);
 }
 
-fn add_int_field(buf:&mut String, tag :FixTag, value : i32) {
-    buf.push_str(tag.id());
-    buf.push('=');
-    buf.push_str(value.to_string().as_str());
-    buf.push('');
+fn add_char_field(buf:&mut BytesMut, tag : FixTag, value : char) {
+    add_field(buf, tag, value);
 }
 
-/*fn add_enum_field(buf:&mut String, tag :FixTag, value : Fix42::FixEnum) {
-    buf.push_str(tag.id());
-    buf.push('=');
-    buf.push(value.value());
-    buf.push('');
-}*/
-
-fn add_price_field(buf:&mut String, tag :FixTag, value :f64) {
-    buf.push_str(tag.id());
-    buf.push('=');
-    buf.push_str(value.to_string().as_str());
-    buf.push('');
+fn add_int_field(buf:&mut BytesMut, tag :FixTag, value : i64) {
+    add_field(buf, tag, value);
 }
 
-fn add_unsigned_field(buf:&mut String, tag :FixTag, value :usize) {
-    buf.push_str(tag.id());
-    buf.push('=');
-    buf.push_str(value.to_string().as_str());
-    buf.push('');
+fn add_decimal_field(buf:&mut BytesMut, tag :FixTag, value :Decimal) {
+    add_field(buf, tag, value);
 }
 
-fn add_u64_field(buf:&mut String, tag :FixTag, value :u64) {
-    buf.push_str(tag.id());
-    buf.push('=');
-    buf.push_str(value.to_string().as_str());
-    buf.push('');
+fn add_unsigned_field(buf:&mut BytesMut, tag :FixTag, value :usize) {
+    add_field(buf, tag, value);
 }
 
-fn add_qty_field(buf:&mut String, tag :FixTag, value :f64) {
-    buf.push_str(tag.id());
-    buf.push('=');
-    buf.push_str(value.to_string().as_str());
-    buf.push('');
+fn add_u64_field(buf:&mut BytesMut, tag :FixTag, value :u64) {
+    add_field(buf, tag, value);
 }
 
-fn add_string_field(buf:&mut String, tag :FixTag, value :&str) {
-    buf.push_str(tag.id());
-    buf.push('=');
-    buf.push_str(value);
-    buf.push('');
+fn add_string_field(buf:&mut BytesMut, tag :FixTag, value :&str) {
+    add_raw_field(buf, tag.id(), value.as_bytes());
 }
 
 pub fn generate_check_sum(buf:&str) -> usize {
+    generate_check_sum_bytes(buf.as_bytes())
+}
 
-    let b = buf.as_bytes();
+fn generate_check_sum_bytes(b:&[u8]) -> usize {
 
     let mut cks :usize = 0;
 
-    for i in 0..buf.len() {
-        let y = b[i] as usize;
-        cks = cks + y;
+    for y in b {
+        cks += *y as usize;
     }
 
     cks % 256
@@ -235,6 +328,8 @@ pub fn generate_check_sum(buf:&str) -> usize {
 mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
     use super::*;
+    use crate::spec::fix42::messages;
+    use crate::fix_message::FixMessage;
 
     #[test]
     fn test_generate_check_sum_0() {
@@ -260,86 +355,283 @@ mod tests {
         assert_eq!(generate_check_sum("8=FIX.4.29=535=0"), 161);
     }
 
+    fn builder() -> FixMessageBuilder {
+        FixMessageBuilder::new(SessionHeader::new("FIX.4.2", "TEST_SERVER", "TEST_CLIENT"))
+    }
+
+    // BodyLength and CheckSum must agree with the message they were computed for.
+    fn assert_well_formed(msg: &str) {
+        let body_start = msg.find("\x0135=").unwrap() + 1;
+        let trailer_start = msg.rfind("\x0110=").unwrap() + 1;
+        let body_length: usize = msg[msg.find("\x019=").unwrap() + 3..body_start - 1].parse().unwrap();
+        assert_eq!(body_length, trailer_start - body_start);
+        assert_eq!(format!("{:03}", generate_check_sum(&msg[..trailer_start])), &msg[trailer_start + 3..msg.len() - 1]);
+    }
+
     #[test]
     fn test_add_enum_field() {
 
-        let mut msg = String::from("8=FIX.4.49=58");
-        add_char_field(&mut msg, tags::MSG_TYPE, attribute_enums::MsgType::HeartBeat.value());
-        assert_eq!(msg,"8=FIX.4.49=5835=0");
+        let mut msg = BytesMut::new();
+        add_string_field(&mut msg, fields::ENCRYPT_METHOD, EncryptMethod::None.value());
+        add_char_field(&mut msg, fields::SIDE, Side::Sell.value().chars().next().unwrap());
+        assert_eq!(&msg[..], b"98=0\x0154=2\x01");
     }
 
     #[test]
     fn test_add_string_field() {
 
-        let mut msg = String::from("8=FIX.4.49=58");
-        add_char_field(&mut msg, tags::MSG_TYPE, attribute_enums::MsgType::HeartBeat.value());
-        assert_eq!(msg,"8=FIX.4.49=5835=0");
+        let mut msg = BytesMut::from("8=FIX.4.4\x019=58\x01");
+        add_string_field(&mut msg, fields::MSG_TYPE, MsgType::HeartBeat.as_str());
+        assert_eq!(&msg[..], b"8=FIX.4.4\x019=58\x0135=0\x01");
+    }
+
+    #[test]
+    fn test_add_typed_fields() {
+
+        let mut msg = BytesMut::new();
+        add_int_field(&mut msg, fields::SESSION_REJECT_REASON, 5);
+        add_char_field(&mut msg, fields::GAP_FILL_FLAG, 'Y');
+        add_decimal_field(&mut msg, fields::PRICE, Decimal::new(4938, 2));
+        add_timestamp_field(&mut msg, fields::SENDING_TIME, DateTime::from_timestamp(1737303188, 931_000_000).unwrap());
+        assert_eq!(&msg[..], b"373=5\x01123=Y\x0144=49.38\x0152=20250119-16:13:08.931\x01");
+    }
+
+    #[test]
+    fn test_add_value_fields() {
+
+        let mut builder = builder();
+        let values = [
+            (fields::PRICE, "0.0000001"),
+            (fields::ORDER_QTY, "100.50"),
+            (fields::CURRENCY, "EUR"),
+            (fields::EXEC_INST, "1 G"),
+            (fields::TRANSACT_TIME, "20250119-16:13:08.931012"),
+        ];
+        builder.start(MsgType::NewOrderSingle);
+        for (tag, value) in &values {
+            builder.add_value_field(FixTag::new(tag.id(), tag.datatype()), &tag.parse(value).unwrap());
+        }
+        let msg = FixMessage::new(builder.finish(1));
+        for (tag, value) in &values {
+            assert_eq!(msg.get_value(tag).unwrap().to_string(), *value);
+        }
+        assert!(std::str::from_utf8(msg.as_bytes()).unwrap().contains("\x0144=0.0000001\x01"));
+    }
+
+    #[test]
+    fn test_finish_adds_header_and_trailer() {
+
+        let mut builder = builder();
+        let msg = builder.start(MsgType::HeartBeat).add_string_field(fields::TEST_REQ_ID, "test").finish(1);
+        let msg = std::str::from_utf8(&msg).unwrap();
+
+        assert!(msg.starts_with("8=FIX.4.2\x019="));
+        assert!(msg.contains("\x0135=0\x0149=TEST_SERVER\x0156=TEST_CLIENT\x0134=1\x0152="));
+        assert!(msg.contains("\x01112=test\x0110="));
+        assert_well_formed(msg);
     }
 
     #[test]
     fn test_create_fix_heartbeat() {
 
-        let hdr = SessionHeader::new("8=FIX.4.2","TEST_SERVER","TEST_CLIENT");
-
-        let mut msg = String::from("");
-        let s = chrono::offset::Utc::now();
-        create_fix_heartbeat(&mut msg, 0, "test");
-        let e = chrono::offset::Utc::now();
-        println!("Duration:{}",e-s);
-        println!("{msg}");
-
-        let mut msg = String::from("");
-        let s = chrono::offset::Utc::now();
-        new_create_fix_heartbeat(&mut msg, &hdr, 0, "test");
-        let e = chrono::offset::Utc::now();
-        println!("Duration:{}",e-s);
-        println!("{msg}");
-
-        let mut msg = String::from("");
-        let s = chrono::offset::Utc::now();
-        create_fix_heartbeat(&mut msg, 0, "test");
-        let e = chrono::offset::Utc::now();
-        println!("Duration:{}",e-s);
-        println!("{msg}");
-
-        let mut msg = String::from("");
-        let s = chrono::offset::Utc::now();
-        new_create_fix_heartbeat(&mut msg, &hdr, 0, "test");
-        let e = chrono::offset::Utc::now();
-        println!("Duration:{}",e-s);
-        println!("{msg}");
+        let mut builder = builder();
+        let msg = builder.start(MsgType::HeartBeat).finish(1);
+        let msg = std::str::from_utf8(&msg).unwrap();
+
+        assert!(msg.contains("\x0135=0\x0149=TEST_SERVER\x0156=TEST_CLIENT\x0134=1\x0152="));
+        assert!(!msg.contains("\x01112="));
+        assert_well_formed(msg);
     }
+
     #[test]
     fn test_new_create_fix_heartbeat() {
 
-        let hdr = SessionHeader::new("8=FIX.4.2","TEST_SERVER","TEST_CLIENT");
+        // The reply to a TestRequest, with the header taken from the session rather than hard coded.
+        let mut builder = FixMessageBuilder::new(SessionHeader::new("FIX.4.4", "BuySide", "SellSide"));
+        let msg = builder.start(MsgType::HeartBeat).add_string_field(fields::TEST_REQ_ID, "TestReqID").finish(2);
+        let msg = std::str::from_utf8(&msg).unwrap();
 
-        let mut msg = String::from("");
-        let s = chrono::offset::Utc::now();
-        new_create_fix_heartbeat(&mut msg, &hdr,0, "test");
-        let e = chrono::offset::Utc::now();
-        println!("Duration:{}",e-s);
-        println!("{msg}");
+        assert!(msg.starts_with("8=FIX.4.4\x019="));
+        assert!(msg.contains("\x0135=0\x0149=BuySide\x0156=SellSide\x0134=2\x0152="));
+        assert!(msg.contains("\x01112=TestReqID\x0110="));
+        assert_well_formed(msg);
     }
 
     #[test]
     fn test_create_fix_logon() {
-        let mut msg = String::from("");
 
-        let s = chrono::offset::Utc::now();
-        create_fix_logon(&mut msg, 0, 10, attribute_enums::EncryptMethod::NONE);
-        let e = chrono::offset::Utc::now();
-        println!("Duration:{}",e-s);
-        println!("{msg}");
+        let mut builder = builder();
+        let msg = builder.start(MsgType::Logon)
+            .add_string_field(fields::ENCRYPT_METHOD, EncryptMethod::None.value())
+            .add_u64_field(fields::HEART_BT_INT, 10)
+            .finish(1);
+        let msg = std::str::from_utf8(&msg).unwrap();
+        assert!(msg.contains("\x0135=A\x01"));
+        assert!(msg.contains("\x0134=1\x01"));
+        assert!(msg.contains("\x0198=0\x01108=10\x0110="));
+        assert_well_formed(msg);
+
+        let msg = builder.start(MsgType::Logon)
+            .add_string_field(fields::ENCRYPT_METHOD, EncryptMethod::None.value())
+            .add_u64_field(fields::HEART_BT_INT, 10)
+            .add_bool_field(fields::RESET_SEQ_NUM_FLAG, true)
+            .finish(1);
+        let msg = std::str::from_utf8(&msg).unwrap();
+        assert!(msg.contains("\x0198=0\x01108=10\x01141=Y\x0110="));
+        assert_well_formed(msg);
     }
+
     #[test]
     fn test_create_fix_test_request() {
-        let mut msg = String::from("");
 
-        let s = chrono::offset::Utc::now();
-        create_fix_test_request(&mut msg, 0);
-        let e = chrono::offset::Utc::now();
-        println!("Duration:{}",e-s);
-        println!("{msg}");
+        let mut builder = builder();
+        let msg = builder.start(MsgType::TestRequest).add_string_field(fields::TEST_REQ_ID, "TEST").finish(4);
+        let msg = std::str::from_utf8(&msg).unwrap();
+
+        assert!(msg.contains("\x0135=1\x01"));
+        assert!(msg.contains("\x0134=4\x01"));
+        assert!(msg.contains("\x01112=TEST\x0110="));
+        assert_well_formed(msg);
+    }
+
+    #[test]
+    fn test_create_fix_sequence_reset_gap_fill() {
+
+        let mut builder = builder();
+        let msg = builder.start(MsgType::SequenceReset)
+            .add_bool_field(fields::POSS_DUP_FLAG, true)
+            .add_timestamp_field(fields::ORIG_SENDING_TIME, chrono::offset::Utc::now())
+            .add_bool_field(fields::GAP_FILL_FLAG, true)
+            .add_seqnum_field(fields::NEW_SEQ_NO, 9)
+            .finish(5);
+        let msg = std::str::from_utf8(&msg).unwrap();
+
+        assert!(msg.contains("\x0135=4\x01"));
+        assert!(msg.contains("\x0134=5\x01"));
+        assert!(msg.contains("\x0143=Y\x01"));
+        assert!(msg.contains("\x01123=Y\x01"));
+        assert!(msg.contains("\x0136=9\x01"));
+        assert_well_formed(msg);
+    }
+
+    #[test]
+    fn test_create_fix_reject() {
+
+        let mut builder = builder();
+        let msg = builder.start(MsgType::Reject)
+            .add_seqnum_field(fields::REF_SEQ_NUM, 12)
+            .add_string_field(fields::REF_TAG_ID, fields::TEST_REQ_ID.id())
+            .add_string_field(fields::REF_MSG_TYPE, MsgType::TestRequest.as_str())
+            .add_int_field(fields::SESSION_REJECT_REASON, SessionRejectReason::RequiredTagMissing.value() as i64)
+            .add_string_field(fields::TEXT, "Required tag missing")
+            .finish(3);
+        let msg = std::str::from_utf8(&msg).unwrap();
+
+        assert!(msg.contains("\x0135=3\x01"));
+        assert!(msg.contains("\x0145=12\x01371=112\x01372=1\x01373=1\x0158=Required tag missing\x0110="));
+        assert_well_formed(msg);
+    }
+
+    #[test]
+    fn test_header_from_config() {
+
+        let mut config = HashMap::new();
+        config.insert(String::from("version"), String::from("5.0SP2"));
+        config.insert(String::from("sender_comp_id"), String::from("CLIENT"));
+        config.insert(String::from("target_comp_id"), String::from("SERVER"));
+        config.insert(String::from("sender_sub_id"), String::from("DESK1"));
+        config.insert(String::from("sender_location_id"), String::from("LDN"));
+        config.insert(String::from("target_sub_id"), String::new());
+
+        let mut builder = FixMessageBuilder::new(SessionHeader::from_config(&config).unwrap());
+        let msg = builder.start(MsgType::HeartBeat).finish(3);
+        let msg = std::str::from_utf8(&msg).unwrap();
+
+        assert!(msg.starts_with("8=FIXT.1.1\x019="));
+        assert!(msg.contains("\x0135=0\x0149=CLIENT\x0156=SERVER\x0150=DESK1\x01142=LDN\x0134=3\x01"));
+        assert!(!msg.contains("\x0157="));
+        assert_well_formed(msg);
+    }
+
+    #[test]
+    fn test_header_from_config_with_unsupported_version() {
+
+        let mut config = HashMap::new();
+        config.insert(String::from("version"), String::from("4.3"));
+        assert_eq!(SessionHeader::from_config(&config).err(), Some(UnsupportedVersion(String::from("4.3"))));
+
+        // An explicit begin_string doesn't need a version we know.
+        config.insert(String::from("begin_string"), String::from("FIX.4.3"));
+        assert_eq!(SessionHeader::from_config(&config).unwrap().begin_string(), "FIX.4.3");
+    }
+
+    #[test]
+    fn test_add_nested_groups() {
+
+        let orders = [("ORD1", vec![("ACC1", 60), ("ACC2", 40)]), ("ORD2", vec![])];
+
+        let mut builder = builder();
+        let msg = builder.start(MsgType::Custom(String::from("E")))
+            .add_string_field(fields::LIST_ID, "LIST1")
+            .add_group(&messages::NEW_ORDER_LIST_NO_ORDERS, &orders, |builder, (cl_ord_id, allocs)| {
+                builder.add_string_field(fields::CL_ORD_ID, cl_ord_id)
+                    .add_group(&messages::NEW_ORDER_LIST_NO_ORDERS_NO_ALLOCS, allocs, |builder, (account, qty)| {
+                        builder.add_string_field(fields::ALLOC_ACCOUNT, account).add_int_field(fields::ALLOC_SHARES, *qty);
+                    })
+                    .add_char_field(fields::SIDE, '1');
+            })
+            .finish(1);
+
+        let text = std::str::from_utf8(&msg).unwrap();
+        assert!(text.contains("\x0173=2\x0111=ORD1\x0178=2\x0179=ACC1\x0180=60\x0179=ACC2\x0180=40\x0154=1\x0111=ORD2\x0178=0\x0154=1\x01"));
+        assert_well_formed(text);
+
+        let msg = FixMessage::new(msg);
+        let parsed = msg.get_group(&messages::NEW_ORDER_LIST_NO_ORDERS).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].get_group(&messages::NEW_ORDER_LIST_NO_ORDERS_NO_ALLOCS).unwrap()[1].get_int(&fields::ALLOC_SHARES), Ok(40));
+    }
+
+    #[test]
+    fn test_builder_is_reused() {
+
+        let mut builder = builder();
+        let first = builder.start(MsgType::TestRequest).add_string_field(fields::TEST_REQ_ID, "TEST").finish(1);
+        // An unfinished message is dropped by the next start().
+        builder.start(MsgType::Logout).add_string_field(fields::TEXT, "unused");
+        let second = builder.start(MsgType::HeartBeat).finish(2);
+
+        assert!(first.windows(9).any(|w| w == b"\x01112=TEST"));
+        assert!(!second.windows(6).any(|w| w == b"\x0158=un"));
+        assert_well_formed(std::str::from_utf8(&first).unwrap());
+        assert_well_formed(std::str::from_utf8(&second).unwrap());
+    }
+
+    #[test]
+    fn test_create_fix_poss_dup() {
+        let original = "8=FIX.4.2\x019=61\x0135=D\x0149=TEST_SERVER\x0156=TEST_CLIENT\x0134=7\x0152=20250119-16:13:08.931\x0111=ORD1\x0110=000\x01";
+
+        let msg = create_fix_poss_dup(original.as_bytes());
+        let msg = std::str::from_utf8(&msg).unwrap();
+
+        assert!(msg.starts_with("8=FIX.4.2\x019="));
+        assert!(msg.contains("\x0134=7\x01"));
+        assert!(msg.contains("\x0143=Y\x01122=20250119-16:13:08.931\x01"));
+        assert!(msg.contains("\x0111=ORD1\x01"));
+        assert!(!msg.contains("52=20250119-16:13:08.931"));
+        assert_well_formed(msg);
+    }
+
+    #[test]
+    fn test_create_fix_poss_dup_keeps_data_field_with_soh() {
+        let original = "8=FIX.4.2\x019=73\x0135=D\x0149=TEST_SERVER\x0156=TEST_CLIENT\x0134=7\x0152=20250119-16:13:08.931\x0195=6\x0196=a\x0152=b\x0111=ORD1\x0110=000\x01";
+
+        let msg = create_fix_poss_dup(original.as_bytes());
+        let msg = std::str::from_utf8(&msg).unwrap();
+
+        assert!(msg.contains("\x0195=6\x0196=a\x0152=b\x0111=ORD1\x01"));
+        assert!(msg.contains("\x0143=Y\x01122=20250119-16:13:08.931\x01"));
+        assert_eq!(msg.matches("\x0143=Y\x01").count(), 1);
+        assert_well_formed(msg);
     }
 }
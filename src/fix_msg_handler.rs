@@ -1,162 +1,500 @@
-use std::collections::HashMap;
-use crate::{fix_msg_builder, fix_println};
-use crate::fix_message::FixMessage;
+use std::collections::{BTreeMap, HashMap};
+use crate::fix_println;
+use crate::fix_msg_builder::{self, FixMessageBuilder, SessionHeader};
+use crate::fix_message::{FieldError, FixMessage};
 use std::io::Write;
-use std::iter::Skip;
-use std::slice::Iter;
-use tokio::sync::mpsc;
+use tokio::sync::{broadcast, mpsc};
 use tokio::sync::mpsc::{Sender, Receiver};
 use tokio::sync::mpsc::error::TryRecvError;
 use tokio::task::yield_now;
+use tokio::time::{Duration, Instant};
 use crate::countdown_actor::{AlarmMessage, ResetMessage};
-use crate::fix_42::attribute_enums::{EncryptMethod, FixEnum, MsgType};
-use crate::fix_42::{attribute_enums, tags};
+use crate::fix_42::attribute_enums::{BusinessRejectReason, CxlRejResponseTo, EncryptMethod, ExecType, FixEnum, MsgType, SessionRejectReason};
+use crate::fix_42::value_types::FixTag;
 use crate::socket_actor::ApplicationMessage;
+use crate::fix_msg_store;
+use crate::fix_msg_store::FixMsgStore;
+use crate::fix_session_state::{SessionEvent, SessionState, SessionStateMachine, StateChange};
+use crate::data_dictionary::{DataDictionary, ValidationSettings};
+use crate::spec::Message;
+use crate::spec::fix42::fields;
+use crate::spec::fix42::messages::{BusinessMessageReject, ExecutionReport, NewOrderSingle, OrderCancelReject, OrderCancelReplaceRequest, OrderCancelRequest, OrderStatusRequest};
 
 
-struct FixMsgStore {
-
-    store : Vec<ApplicationMessage>
-}
-
-impl FixMsgStore {
-    fn new() -> FixMsgStore {
-       Self {
-           store : Vec::new()
-       }
-    }
-    fn push(&mut self, value : ApplicationMessage) {
-        self.store.push(value);
-    }
-    fn get_single(&self, sequence_number: i32) -> &ApplicationMessage {
-        // Probably want an iterator here
-        // as well send to send replays in batches, yielding once the buffer is full
-        // What is a sensible amount of data to send - do we send message at a time or just a mass
-        // also potentially want to throttle resend msgs/sec ( for extra credit )
-        return self.store.get(sequence_number as usize).unwrap();
-    }
-
-    fn iter(&self, n :usize) -> Skip<Iter<'_, ApplicationMessage>> {
-        return self.store.iter().skip(n);
-    }
-
-    fn get_slice(&self, begin :usize, end :usize) -> &[ApplicationMessage] {
-        return &self.store[begin..end];
-    }
-
-    fn len(&self) -> usize {
-        return self.store.len();
-    }
-}
 pub struct MyFixMsgHandler {
 
     interval_tx : Sender<u64>,
     app_msg_rx  : Receiver<ApplicationMessage>,
     app_msg_tx  : Sender<ApplicationMessage>,
     alarm_rx    : Receiver<AlarmMessage>,
+    logout_rx   : Receiver<String>,
+    // Holds the session's SessionHeader, i.e. who we are and who we expect to hear from.
+    builder     : FixMessageBuilder,
+    // Whether we connected out and so send the first Logon, rather than waiting for one.
+    initiator   : bool,
+    session_state : SessionStateMachine,
+    // Inbound messages are checked against this when the session is configured with one.
+    dictionary  : Option<DataDictionary>,
+    validation  : ValidationSettings,
     fix_status  : FixStatus,
-    msg_store   : FixMsgStore
+    msg_store   : Box<dyn FixMsgStore + Send + Sync>,
+    // Inbound messages that arrived ahead of a sequence gap, held until the gap is filled.
+    inbound_queue : BTreeMap<i32, FixMessage>,
+    // Application messages are passed on to this, it replies through the session handle.
+    application : Box<dyn FixMsgHandler + Send + Sync>,
+    session     : SessionHandle,
+    outbound_rx : mpsc::UnboundedReceiver<Box<dyn Message + Send>>
 }
 
-struct FixStatus {
-    logon_complete : bool,
-    next_seq_id_to_send : i32,
-    next_seq_id_ro_recv : i32,
-    hb_interval : u64,
-    status : FixSessionStatus
+// Given to the application callbacks for sending messages on the session. They're queued and sent
+// from the handler's loop once the session is logged on, with the header and MsgSeqNum added as for
+// any other message.
+#[derive(Clone)]
+pub struct SessionHandle {
+    outbound_tx : mpsc::UnboundedSender<Box<dyn Message + Send>>
 }
 
-enum FixServerStatus {
-    AWAITING_LOGON,
-    LOGON_RECEIVED,
-}
+impl SessionHandle {
 
-enum FixClientStatus {
-    UNSENT,
-    LOGON_SENT,
+    // False if the session has already ended.
+    pub fn send(&self, msg: impl Message + Send + 'static) -> bool {
+        self.outbound_tx.send(Box::new(msg)).is_ok()
+    }
 }
 
-#[derive(PartialEq, Eq)]
-enum FixSessionStatus {
-    DOWN,
-    UP,
+struct FixStatus {
+    next_seq_id_to_send : i32,
+    next_seq_id_to_recv : i32,
+    resend_requested_to : Option<i32>,
+    hb_interval : u64,
+    logout_sent_at : Option<Instant>,
+    logout_timeout : Duration,
+    logged_out : bool,
+    // Whether sequence numbers go back to 1 at each of these points in the session's life.
+    reset_on_logon : bool,
+    reset_on_logout : bool,
+    reset_on_disconnect : bool,
+    // Set while we're waiting for the other side to confirm a Logon we sent with ResetSeqNumFlag=Y.
+    reset_sent : bool,
+    // The TestReqID of a TestRequest we're still waiting on a Heartbeat for.
+    test_request_id : Option<String>
 }
 
 impl FixStatus {
-    fn new() -> FixStatus {
+    fn new(config : &HashMap<String, String>) -> FixStatus {
+
+        let heartbeat_interval = config.get("heartbeat_interval").and_then(|v| v.parse().ok()).unwrap_or(30);
+        let logout_timeout = config.get("logout_timeout").and_then(|v| v.parse().ok()).unwrap_or(10);
+        let flag = |key: &str| config.get(key).is_some_and(|v| v == "true" || v == "Y");
+
         FixStatus {
-            logon_complete      :  false,
-            next_seq_id_to_send : 0,
-            next_seq_id_ro_recv : 0,
-            hb_interval         : 10,
-            status              : FixSessionStatus::DOWN
+            next_seq_id_to_send : 1,
+            next_seq_id_to_recv : 1,
+            resend_requested_to : None,
+            hb_interval         : heartbeat_interval,
+            logout_sent_at      : None,
+            logout_timeout      : Duration::from_secs(logout_timeout),
+            logged_out          : false,
+            reset_on_logon      : flag("reset_on_logon"),
+            reset_on_logout     : flag("reset_on_logout"),
+            reset_on_disconnect : flag("reset_on_disconnect"),
+            reset_sent          : false,
+            test_request_id     : None
         }
     }
 }
 
 impl MyFixMsgHandler {
 
-    pub fn new(interval_sender : Sender<u64>, app_msg_rx: Receiver<ApplicationMessage>, app_msg_sender : Sender<ApplicationMessage>, alarm_rx :Receiver<AlarmMessage> ) -> Self {
+    pub fn new(config : &HashMap<String, String>, interval_sender : Sender<u64>, app_msg_rx: Receiver<ApplicationMessage>, app_msg_sender : Sender<ApplicationMessage>, alarm_rx :Receiver<AlarmMessage>, logout_rx :Receiver<String>, application : Box<dyn FixMsgHandler + Send + Sync> ) -> Self {
+
+        let header = SessionHeader::from_config(config).unwrap_or_else(|e| panic!("{}", e));
+        let dictionary = config.get("data_dictionary").map(|path| {
+            let dictionary = DataDictionary::load(path).unwrap_or_else(|e| panic!("Unable to load {}: {}", path, e));
+            // A spec for another FIX version would reject everything the other side sends.
+            if dictionary.begin_string() != header.begin_string() {
+                panic!("{} is for {}, but the session uses {}", path, dictionary.begin_string(), header.begin_string());
+            }
+            dictionary
+        });
+        let msg_store = fix_msg_store::create_msg_store(config);
+        let (outbound_tx, outbound_rx) = mpsc::unbounded_channel();
+        let mut fix_status = FixStatus::new(config);
+
+        // Pick up where we left off if the session has been running before.
+        if let Some((next_sender_seq_num, next_target_seq_num)) = msg_store.get_seq_nums() {
+            fix_println!("Resuming session, next sender seq num:{} next target seq num:{}", next_sender_seq_num, next_target_seq_num);
+            fix_status.next_seq_id_to_send = next_sender_seq_num;
+            fix_status.next_seq_id_to_recv = next_target_seq_num;
+        }
+
         Self {
             interval_tx: interval_sender,
             app_msg_rx,
             app_msg_tx: app_msg_sender,
-            fix_status: FixStatus::new(),
-            msg_store: FixMsgStore::new(),
+            logout_rx,
+            builder: FixMessageBuilder::new(header),
+            initiator: config.get("type").is_some_and(|v| v == "client"),
+            session_state: SessionStateMachine::new(),
+            dictionary,
+            validation: ValidationSettings::from_config(config),
+            fix_status,
+            msg_store,
+            inbound_queue: BTreeMap::new(),
+            application,
+            session: SessionHandle { outbound_tx },
+            outbound_rx,
             alarm_rx
         }
     }
 
+    pub fn subscribe_state_changes(&self) -> broadcast::Receiver<StateChange> {
+        self.session_state.subscribe()
+    }
+
+    // Anything that doesn't fit the session state machine is logged but otherwise let through, the
+    // checks that matter are made on the state itself before it gets this far.
+    fn transition(&mut self, event: SessionEvent) {
+        if let Err(e) = self.session_state.on_event(event) {
+            fix_println!("Unexpected session event: {}", e);
+        }
+    }
+
+    // Checks the inbound MsgSeqNum against the one we expect before the message is acted upon.
+    // Messages from the future are queued behind a ResendRequest, replays we've already seen are
+    // dropped and anything else that is too low is fatal to the session.
     async fn handle_fix_message(&mut self, msg: &FixMessage) {
 
-        if msg.get_msg_type() == MsgType::Logon.value() {
+        let Some(seq_no) = get_seq_no(msg) else {
+            fix_println!("Ignoring message without a valid MsgSeqNum.");
+            return;
+        };
+
+        // A Logon we won't accept isn't worth a Reject, the session never starts. Nor does it get to
+        // touch the session's sequence numbers.
+        if *msg.get_msg_type() == MsgType::Logon {
+            if let Err(text) = self.validate_logon(msg) {
+                fix_println!("Refusing Logon: {}", text);
+                self.create_and_send_logout(&text).await;
+                self.transition(SessionEvent::Disconnect);
+                return;
+            }
+        }
+
+        // A Logon with ResetSeqNumFlag=Y starts the inbound sequence again from its own MsgSeqNum.
+        if *msg.get_msg_type() == MsgType::Logon && msg.get_bool(&fields::RESET_SEQ_NUM_FLAG) == Ok(true) {
+            fix_println!("Logon with ResetSeqNumFlag received, expecting MsgSeqNum {} next.", seq_no);
+            self.inbound_queue.clear();
+            self.fix_status.resend_requested_to = None;
+            self.fix_status.next_seq_id_to_recv = seq_no;
+        }
+
+        // SequenceReset-Reset ignores MsgSeqNum altogether, whereas GapFill goes through the usual
+        // checks. Either way the header is validated like any other message's.
+        if *msg.get_msg_type() == MsgType::SequenceReset && msg.get_bool(&fields::GAP_FILL_FLAG) != Ok(true) {
+            self.dispatch_fix_message(msg).await;
+            self.process_inbound_queue().await;
+            return;
+        }
+
+        let expected = self.fix_status.next_seq_id_to_recv;
+
+        if seq_no > expected {
+
+            fix_println!("Sequence gap detected: expected {} but received {}.", expected, seq_no);
+
+            // A Logon or ResendRequest has to be acted on straight away, otherwise both sides could
+            // end up waiting on each other. They are still queued so the sequence accounting works out.
+            if *msg.get_msg_type() == MsgType::Logon || *msg.get_msg_type() == MsgType::ResendRequest {
+                self.dispatch_fix_message(msg).await;
+            }
+
+            self.inbound_queue.insert(seq_no, msg.clone());
+
+            if self.fix_status.resend_requested_to.is_none() {
+                self.create_and_send_resend_request(expected, seq_no - 1).await;
+            }
+            return;
+        }
+
+        if seq_no < expected {
+
+            if msg.get_bool(&fields::POSS_DUP_FLAG) == Ok(true) {
+                fix_println!("Ignoring PossDup message {} as it has already been processed.", seq_no);
+            } else {
+                let text = format!("MsgSeqNum too low, expecting {} but received {}", expected, seq_no);
+                fix_println!("{}", text);
+                self.initiate_logout(&text).await;
+            }
+            return;
+        }
+
+        self.fix_status.next_seq_id_to_recv += 1;
+        self.dispatch_fix_message(msg).await;
+
+        self.process_inbound_queue().await;
+    }
+
+    // Releases queued messages, in order, for as long as they are contiguous with what we've processed.
+    async fn process_inbound_queue(&mut self) {
+
+        while let Some(msg) = self.inbound_queue.remove(&self.fix_status.next_seq_id_to_recv) {
+
+            self.fix_status.next_seq_id_to_recv += 1;
+
+            if *msg.get_msg_type() != MsgType::Logon && *msg.get_msg_type() != MsgType::ResendRequest {
+                self.dispatch_fix_message(&msg).await;
+            }
+        }
+
+        let next = self.fix_status.next_seq_id_to_recv;
+        self.inbound_queue.retain(|seq_no, _| *seq_no >= next);
+
+        if self.fix_status.resend_requested_to.is_some_and(|end| next > end) {
+            fix_println!("Sequence gap filled, next expected MsgSeqNum is {}.", next);
+            self.fix_status.resend_requested_to = None;
+            self.transition(SessionEvent::GapFilled);
+        }
+
+        // Whatever is still queued arrived after a second gap while the first was being filled, so
+        // that one has to be asked for now or the queue would never drain.
+        if self.fix_status.resend_requested_to.is_none() {
+            if let Some(&first) = self.inbound_queue.keys().next() {
+                fix_println!("Sequence gap detected: expected {} but next queued is {}.", next, first);
+                self.create_and_send_resend_request(next, first - 1).await;
+            }
+        }
+    }
+
+    async fn dispatch_fix_message(&mut self, msg: &FixMessage) {
+
+        // By now the message has been accepted as far as sequencing goes, so anything wrong with its
+        // content is answered with a Reject and the message goes no further.
+        if let Some((reason, ref_tag_id, text)) = self.validate_fix_message(msg) {
+
+            let ref_seq_num = get_seq_no(msg).unwrap_or(0);
+            fix_println!("Rejecting message {}: {:?} {}", ref_seq_num, reason, text);
+            self.create_and_send_reject(ref_seq_num, ref_tag_id, Some(msg.get_msg_type()), reason, &text).await;
+
+            // A message from someone other than who we're talking to ends the session.
+            if reason == Some(SessionRejectReason::CompIdProblem) {
+                self.initiate_logout(&text).await;
+            }
+            return;
+        }
+
+        // Any valid message shows the other side is still there, not just the Heartbeat we asked for.
+        if let Some(test_request_id) = self.fix_status.test_request_id.take() {
+            fix_println!("Message received while waiting on TestRequest '{}', no longer waiting.", test_request_id);
+        }
+
+        if !is_admin_msg_type(msg.get_msg_type()) && *msg.get_msg_type() != MsgType::Reject && !self.session_state.state().accepts_application_messages() {
+            let ref_seq_num = get_seq_no(msg).unwrap_or(0);
+            let text = format!("Application message received in session state {}", self.session_state.state());
+            fix_println!("Rejecting message {}: {}", ref_seq_num, text);
+            self.create_and_send_reject(ref_seq_num, None, Some(msg.get_msg_type()), None, &text).await;
+            return;
+        }
+
+        if *msg.get_msg_type() == MsgType::Logon {
             fix_println!("Calling: on_logon");
-            self.on_logon_request(msg.get_body()).await;
-        } else if msg.get_msg_type() == MsgType::TestRequest.value() {
+            self.on_logon_request(msg).await;
+        } else if *msg.get_msg_type() == MsgType::TestRequest {
             fix_println!("Calling: on_test_request");
-            self.on_test_request(msg.get_body()).await;
+            self.on_test_request(msg).await;
 
-        } else if msg.get_msg_type() == MsgType::HeartBeat.value() {
+        } else if *msg.get_msg_type() == MsgType::HeartBeat {
             fix_println!("Calling: on_heartbeat");
-            self.on_heartbeat(msg.get_body());
+            self.on_heartbeat(msg);
 
-        } else if msg.get_msg_type() == MsgType::SequenceReset.value() {
-            fix_println!("Resetting Sequence Numbers.");
+        } else if *msg.get_msg_type() == MsgType::ResendRequest {
+            fix_println!("Calling: on_resend_request");
+            self.on_resend_request(msg).await;
+
+        } else if *msg.get_msg_type() == MsgType::SequenceReset {
+            fix_println!("Calling: on_sequence_reset");
+            self.on_sequence_reset(msg).await;
+
+        } else if *msg.get_msg_type() == MsgType::Logout {
+            fix_println!("Calling: on_logout");
+            self.on_logout(msg).await;
+
+        } else if *msg.get_msg_type() == MsgType::Reject {
+            fix_println!("Calling: on_session_level_reject");
+            self.on_session_level_reject(msg);
 
         } else {
-            fix_println!("Unknown message type:'{}'",msg.get_msg_type());
+            self.on_application_message(msg).await;
         }
     }
 
+    // Decodes the message and hands it to the application. A message that doesn't decode is
+    // rejected, as is any application message we have no callback for, with a BusinessMessageReject.
+    async fn on_application_message(&mut self, msg: &FixMessage) {
+
+        let application = &mut self.application;
+        let session = &self.session;
+
+        let decoded = match msg.get_msg_type() {
+            MsgType::NewOrderSingle => NewOrderSingle::decode(msg).map(|m| application.on_new_order_single(session, &m)),
+            MsgType::OrderCancelRequest => OrderCancelRequest::decode(msg).map(|m| application.on_cancel_request(session, &m)),
+            MsgType::OrderCancelReplaceRequest => OrderCancelReplaceRequest::decode(msg).map(|m| application.on_cxl_replace_request(session, &m)),
+            MsgType::OrderStatusRequest => OrderStatusRequest::decode(msg).map(|m| application.on_order_status_request(session, &m)),
+            MsgType::ExecutionReport => ExecutionReport::decode(msg).map(|m| application.on_execution_report(session, &m)),
+            MsgType::OrderCancelReject => OrderCancelReject::decode(msg).map(|m| application.on_order_cancel_reject(session, &m)),
+            MsgType::BusinessMessageReject => BusinessMessageReject::decode(msg).map(|m| application.on_business_reject(session, &m)),
+            msg_type => {
+                fix_println!("Unsupported message type:'{}'", msg_type);
+                let ref_seq_num = get_seq_no(msg).unwrap_or(0);
+                self.create_and_send_business_reject(ref_seq_num, msg_type, BusinessRejectReason::UnsupportedMessageType, "Unsupported Message Type").await;
+                return;
+            }
+        };
+
+        if let Err(e) = decoded {
+            let (reason, tag) = match e {
+                FieldError::Missing(tag) => (SessionRejectReason::RequiredTagMissing, tag),
+                FieldError::Invalid(tag) => (SessionRejectReason::IncorrectDataFormat, tag),
+                FieldError::IncorrectNumInGroupCount(tag) => (SessionRejectReason::IncorrectNumInGroupCount, tag),
+            };
+            let ref_seq_num = get_seq_no(msg).unwrap_or(0);
+            fix_println!("Rejecting message {}: {}", ref_seq_num, e);
+            self.create_and_send_reject(ref_seq_num, fields::find(tag), Some(msg.get_msg_type()), Some(reason), &e.to_string()).await;
+        }
+    }
+
+    fn validate_logon(&self, msg: &FixMessage) -> Result<(), String> {
+
+        let header = self.builder.header();
+
+        let begin_string = msg.get_str(&fields::BEGIN_STRING).unwrap_or("");
+        if begin_string != header.begin_string() {
+            return Err(format!("Incorrect BeginString {}, expected {}", begin_string, header.begin_string()));
+        }
+
+        let sender_comp_id = msg.get_str(&fields::SENDER_COMP_ID).unwrap_or("");
+        let target_comp_id = msg.get_str(&fields::TARGET_COMP_ID).unwrap_or("");
+        if sender_comp_id != header.target_comp_id() || target_comp_id != header.sender_comp_id() {
+            return Err(format!("Unknown session {}->{}", sender_comp_id, target_comp_id));
+        }
+
+        let encrypt_method = msg.get_str(&fields::ENCRYPT_METHOD).unwrap_or("");
+        if encrypt_method != EncryptMethod::None.value() {
+            return Err(format!("Unsupported EncryptMethod '{}'", encrypt_method));
+        }
+
+        match msg.get_int(&fields::HEART_BT_INT) {
+            Ok(interval) if (1..=MAX_HEARTBEAT_INTERVAL as i64).contains(&interval) => Ok(()),
+            Err(FieldError::Missing(_)) => Err(String::from("HeartBtInt missing")),
+            _ => Err(format!("Invalid HeartBtInt, must be between 1 and {} seconds", MAX_HEARTBEAT_INTERVAL)),
+        }
+    }
+
+    // Session level checks on the content of an inbound message. Returns the reason, offending tag
+    // and some explanatory text for the first problem found. With a data dictionary the content is
+    // checked against that, otherwise only against the tags we know about.
+    fn validate_fix_message(&self, msg: &FixMessage) -> Option<(Option<SessionRejectReason>, Option<&'static FixTag>, String)> {
+
+        if let Some(dictionary) = &self.dictionary {
+            if let Err(e) = dictionary.validate(msg, &self.validation) {
+                return Some((e.reason, e.tag.and_then(fields::find), e.text));
+            }
+        } else if let Some((reason, ref_tag_id, text)) = validate_known_tags(msg) {
+            return Some((Some(reason), ref_tag_id, text));
+        }
+
+        for required in HEADER_REQUIRED_TAGS.iter().chain(required_tags(msg.get_msg_type())) {
+            if !msg.contains(required) {
+                return Some((Some(SessionRejectReason::RequiredTagMissing), fields::find(required.number()), format!("Required tag {} missing", required.id())));
+            }
+        }
+
+        if msg.get_str(&fields::SENDER_COMP_ID) != Ok(self.builder.header().target_comp_id()) {
+            return Some((Some(SessionRejectReason::CompIdProblem), Some(&fields::SENDER_COMP_ID), String::from("CompID problem")));
+        }
+        if msg.get_str(&fields::TARGET_COMP_ID) != Ok(self.builder.header().sender_comp_id()) {
+            return Some((Some(SessionRejectReason::CompIdProblem), Some(&fields::TARGET_COMP_ID), String::from("CompID problem")));
+        }
+
+        None
+    }
+
     pub async fn run_with_try(&mut self) {
 
         fix_println!("Start Msg handler loop.");
 
-        loop {
+        self.transition(SessionEvent::Connect);
+
+        if self.initiator {
+            self.create_and_send_logon().await;
+        }
+
+        while self.session_state.state() != SessionState::Disconnected {
 
             let recvd = self.app_msg_rx.try_recv();
 
             match recvd {
                 Err(TryRecvError::Empty) => {},
-                Err(TryRecvError::Disconnected) => fix_println!("MH_RX: something went wrong."),
+                Err(TryRecvError::Disconnected) => {
+                    fix_println!("MH_RX: socket closed, stopping Msg handler.");
+                    self.transition(SessionEvent::Disconnect);
+                },
                 Ok(app_msg) => {
                     //fix_println!("Received from Socket: {}", app_msg.get_message());
-                    let fix_msg = FixMessage::new(&app_msg.get_message());
+                    let fix_msg = FixMessage::new(app_msg.into_bytes());
                     self.handle_fix_message(&fix_msg).await;
+                    self.save_seq_nums();
                 }
             };
 
             let x = self.alarm_rx.try_recv();
             match x {
-                Ok(_) => {
+                Ok(AlarmMessage::Alarm) => {
                     self.create_and_send_heartbeat("").await;
                 }
+                Ok(AlarmMessage::InboundTimeout) => {
+                    self.on_inbound_timeout().await;
+                }
                 Err(TryRecvError::Empty) => {},
                 Err(TryRecvError::Disconnected) => { },
             };
 
+            if let Ok(text) = self.logout_rx.try_recv() {
+                self.initiate_logout(&text).await;
+            }
+
+            self.send_application_messages().await;
+
+            // Give up waiting for the other side to confirm our Logout.
+            if self.fix_status.logout_sent_at.is_some_and(|sent| sent.elapsed() >= self.fix_status.logout_timeout) {
+                fix_println!("No Logout confirmation received within {:?}, disconnecting.", self.fix_status.logout_timeout);
+                self.fix_status.logged_out = true;
+                self.transition(SessionEvent::Disconnect);
+            }
+
             yield_now().await;
         }
+
+        if self.fix_status.reset_on_disconnect || (self.fix_status.reset_on_logout && self.fix_status.logged_out) {
+            self.reset_seq_nums();
+        }
+
+        // Dropping our end of the channels is what tells the SocketActor (and in turn the
+        // CountdownActor) to shut down once any pending messages have been written.
+        fix_println!("Msg handler stopped.");
+    }
+
+    // Whatever the application has sent waits until the session is ready for it.
+    async fn send_application_messages(&mut self) {
+
+        if !self.session_state.state().accepts_application_messages() {
+            return;
+        }
+
+        while let Ok(msg) = self.outbound_rx.try_recv() {
+            msg.encode_fields(self.builder.start(msg.msg_type()));
+            self.send_message().await;
+        }
     }
 
     async fn resend(&self, message : ApplicationMessage) {
@@ -169,87 +507,346 @@ impl MyFixMsgHandler {
         }
         fix_println!("There are {} messages in ths inbound store",self.msg_store.len());
     }
-    async fn send(&mut self, message : ApplicationMessage) {
+    // The one way a new message goes out. Whatever has been built is given the next MsgSeqNum,
+    // written to the socket and kept in the store in case the other side asks for it again.
+    async fn send_message(&mut self) {
+
+        let seq_no = self.fix_status.next_seq_id_to_send;
+        let message = ApplicationMessage::from_bytes(self.builder.finish(seq_no));
+        self.fix_status.next_seq_id_to_send += 1;
 
         let store_message = message.clone();
 
         let res = self.app_msg_tx.send(message).await;
 
         match res {
-            Ok(_) =>  { self.msg_store.push(store_message);},
+            Ok(_) =>  {
+                self.msg_store.push(seq_no, &store_message);
+                self.save_seq_nums();
+            },
             Err(e) => {fix_println!("Error sending FIX msg to socket handler {}",e);}
         }
         fix_println!("There are {} messages in ths inbound store",self.msg_store.len());
     }
+
+    fn reset_seq_nums(&mut self) {
+        self.msg_store.reset();
+        self.inbound_queue.clear();
+        self.fix_status.next_seq_id_to_send = 1;
+        self.fix_status.next_seq_id_to_recv = 1;
+        self.fix_status.resend_requested_to = None;
+        self.save_seq_nums();
+    }
+
+    fn save_seq_nums(&mut self) {
+        self.msg_store.set_seq_nums(self.fix_status.next_seq_id_to_send, self.fix_status.next_seq_id_to_recv);
+    }
+
     // e.g. "8=FIX.4.29=7435=034=049=TEST_SENDER56=TEST_TARGET52=20241228-17:10:29.938112=test";
     async fn create_and_send_heartbeat(&mut self, test_request_id: &str) {
 
-        let mut hb = String::new();
-        fix_msg_builder::create_fix_heartbeat(&mut hb, self.fix_status.next_seq_id_to_send, test_request_id);
-        self.fix_status.next_seq_id_to_send += 1;
-        let msg = ApplicationMessage::new(hb);
+        self.builder.start(MsgType::HeartBeat);
+        if !test_request_id.is_empty() {
+            self.builder.add_string_field(fields::TEST_REQ_ID, test_request_id);
+        }
+        self.transition(SessionEvent::HeartbeatSent);
+
+        self.send_message().await;
+    }
+
+    // First time round the other side gets a TestRequest to prove it's still there, if it stays quiet
+    // for another interval after that we give up on it.
+    async fn on_inbound_timeout(&mut self) {
+
+        if let Some(test_request_id) = self.fix_status.test_request_id.clone() {
+            fix_println!("No Heartbeat received in response to TestRequest '{}', disconnecting.", test_request_id);
+            self.initiate_logout("Heartbeat timeout").await;
+            self.transition(SessionEvent::Disconnect);
+            return;
+        }
+
+        fix_println!("Nothing received for more than a heartbeat interval, sending TestRequest.");
+        let test_request_id = format!("TEST-{}", chrono::offset::Utc::now().format("%Y%m%d-%H:%M:%S%.6f"));
+        self.create_and_send_test_request(&test_request_id).await;
+        self.fix_status.test_request_id = Some(test_request_id);
+    }
+
+    async fn create_and_send_test_request(&mut self, test_request_id: &str) {
+
+        self.builder.start(MsgType::TestRequest)
+            .add_string_field(fields::TEST_REQ_ID, test_request_id);
+        self.transition(SessionEvent::TestRequestSent);
+
+        self.send_message().await;
+    }
+
+    async fn create_and_send_resend_request(&mut self, begin_seq_no: i32, end_seq_no: i32) {
+
+        self.builder.start(MsgType::ResendRequest)
+            .add_seqnum_field(fields::BEGIN_SEQ_NO, begin_seq_no)
+            .add_seqnum_field(fields::END_SEQ_NO, end_seq_no);
+        self.fix_status.resend_requested_to = Some(end_seq_no);
+        self.transition(SessionEvent::ResendRequestSent);
+
+        fix_println!("Requesting resend of messages {} to {}.", begin_seq_no, end_seq_no);
+        self.send_message().await;
+    }
+
+    async fn create_and_send_reject(&mut self, ref_seq_num: i32, ref_tag_id: Option<&FixTag>, ref_msg_type: Option<&MsgType>, reason: Option<SessionRejectReason>, text: &str) {
 
-        self.send(msg).await;
+        // RefTagID and RefMsgType are optional as they aren't always known, e.g. a message so badly
+        // formed that its MsgType couldn't be read, and not every problem has a SessionRejectReason.
+        self.builder.start(MsgType::Reject).add_seqnum_field(fields::REF_SEQ_NUM, ref_seq_num);
+        if let Some(ref_tag_id) = ref_tag_id {
+            self.builder.add_string_field(fields::REF_TAG_ID, ref_tag_id.id());
+        }
+        if let Some(ref_msg_type) = ref_msg_type {
+            self.builder.add_string_field(fields::REF_MSG_TYPE, ref_msg_type.as_str());
+        }
+        if let Some(reason) = reason {
+            self.builder.add_int_field(fields::SESSION_REJECT_REASON, reason.value() as i64);
+        }
+        if !text.is_empty() {
+            self.builder.add_string_field(fields::TEXT, text);
+        }
+        self.transition(SessionEvent::RejectSent);
+
+        self.send_message().await;
+    }
+
+    async fn create_and_send_business_reject(&mut self, ref_seq_num: i32, ref_msg_type: &MsgType, reason: BusinessRejectReason, text: &str) {
+
+        let reject = BusinessMessageReject {
+            ref_seq_num            : Some(ref_seq_num as i64),
+            ref_msg_type           : ref_msg_type.to_string(),
+            business_reject_ref_id : None,
+            business_reject_reason : reason,
+            text                   : Some(String::from(text)),
+            encoded_text_len       : None,
+            encoded_text           : None,
+        };
+        reject.encode_fields(self.builder.start(reject.msg_type()));
+        self.send_message().await;
+    }
+
+    // Starts the Logout handshake: our Logout goes out and the session is torn down either when the
+    // other side confirms or once logout_timeout has passed, whichever comes first.
+    pub async fn initiate_logout(&mut self, text: &str) {
+
+        if self.fix_status.logout_sent_at.is_some() {
+            return;
+        }
+
+        fix_println!("Initiating Logout.");
+        self.create_and_send_logout(text).await;
+        self.fix_status.logout_sent_at = Some(Instant::now());
     }
 
+    async fn create_and_send_logout(&mut self, text: &str) {
+
+        self.builder.start(MsgType::Logout);
+        if !text.is_empty() {
+            self.builder.add_string_field(fields::TEXT, text);
+        }
+        self.transition(SessionEvent::LogoutSent);
+
+        self.send_message().await;
+    }
+
+    // Starts the session from the initiator's side. With reset_on_logon both sides go back to 1 and
+    // the Logon says so.
     pub async fn create_and_send_logon(&mut self) {
 
-        let mut logon = String::new();
+        let reset_seq_num = self.fix_status.reset_on_logon;
 
-        fix_msg_builder::create_fix_logon(&mut logon, self.fix_status.next_seq_id_to_send, self.fix_status.hb_interval, EncryptMethod::NONE);
+        if reset_seq_num {
+            fix_println!("Resetting sequence numbers on Logon.");
+            self.reset_seq_nums();
+            self.fix_status.reset_sent = true;
+        }
 
-        self.fix_status.next_seq_id_to_send += 1;
+        self.send_logon(reset_seq_num).await;
+    }
 
-        let msg = ApplicationMessage::new(logon);
-        let res = self.app_msg_tx.send(msg).await;
+    async fn send_logon(&mut self, reset_seq_num: bool) {
 
-        self.fix_status.status = FixSessionStatus::UP;
+        self.builder.start(MsgType::Logon)
+            .add_string_field(fields::ENCRYPT_METHOD, EncryptMethod::None.value())
+            .add_u64_field(fields::HEART_BT_INT, self.fix_status.hb_interval);
+        if reset_seq_num {
+            self.builder.add_bool_field(fields::RESET_SEQ_NUM_FLAG, true);
+        }
+        self.transition(SessionEvent::LogonSent);
+        self.send_message().await;
+    }
 
-        match res {
-            Ok(_) => {},
-            Err(e) => {fix_println!("Error sending FIX msg to socket handler {}",e);}
+    fn on_heartbeat(&mut self, msg: &FixMessage) {
+
+        self.transition(SessionEvent::HeartbeatReceived);
+
+        if let Ok(test_request_id) = msg.get_str(&fields::TEST_REQ_ID) {
+            fix_println!("Heartbeat received for TestRequest '{}'.", test_request_id);
         }
     }
+    async fn on_resend_request(&mut self, msg: &FixMessage) {
+
+        self.transition(SessionEvent::ResendRequestReceived);
+
+        let begin_sq_no = get_seq_num_field(msg, &fields::BEGIN_SEQ_NO);
+        let end_sq_no   = get_seq_num_field(msg, &fields::END_SEQ_NO);
+
+        let (Some(begin_sq_no), Some(end_sq_no)) = (begin_sq_no, end_sq_no) else {
+            fix_println!("Ignoring ResendRequest with missing or invalid BeginSeqNo/EndSeqNo.");
+            return;
+        };
+
+        // Sequence numbers start at 1, anything below that would have us send a GapFill with MsgSeqNum 0.
+        if begin_sq_no < 1 {
+            let ref_seq_num = get_seq_no(msg).unwrap_or(0);
+            self.create_and_send_reject(ref_seq_num, Some(&fields::BEGIN_SEQ_NO), Some(&MsgType::ResendRequest),
+                                        Some(SessionRejectReason::ValueIsIncorrect), "BeginSeqNo must be at least 1").await;
+            return;
+        }
 
-    fn on_heartbeat(&mut self, message:String) {
-        //parse message
-        //Update last ping time
-        //Update next expected sequence number
+        // EndSeqNo=0 means "infinity" (999999 is the pre FIX 4.2 convention), i.e. everything we've sent.
+        let last_sent = self.fix_status.next_seq_id_to_send - 1;
+        let end_sq_no = if end_sq_no == 0 || end_sq_no == 999999 || end_sq_no > last_sent { last_sent } else { end_sq_no };
+
+        fix_println!("Resending messages {} to {}.", begin_sq_no, end_sq_no);
+
+        // Application messages are replayed as PossDup, while runs of admin messages (or anything
+        // we no longer hold) are collapsed into a single SequenceReset-GapFill.
+        let mut gap_start: Option<i32> = None;
+
+        for seq_no in begin_sq_no..=end_sq_no {
+
+            let replay = match self.msg_store.get_single(seq_no) {
+                Some(stored) if !stored_msg_type(stored.as_bytes()).is_some_and(|msg_type| is_admin_msg_type(&msg_type)) => {
+                    Some(ApplicationMessage::from_bytes(fix_msg_builder::create_fix_poss_dup(stored.as_bytes())))
+                }
+                _ => None
+            };
+
+            match replay {
+                Some(msg) => {
+                    if let Some(gap_begin) = gap_start.take() {
+                        self.send_gap_fill(gap_begin, seq_no).await;
+                    }
+                    self.resend(msg).await;
+                }
+                None => {
+                    if gap_start.is_none() {
+                        gap_start = Some(seq_no);
+                    }
+                }
+            }
+        }
+
+        if let Some(gap_begin) = gap_start {
+            self.send_gap_fill(gap_begin, end_sq_no + 1).await;
+        }
     }
-    async fn on_resend_request(&mut self, message: String) {
 
-        let mut hmap = HashMap::new();
-        parse_fix_message(message.as_str(), &mut hmap);
+    async fn send_gap_fill(&mut self, seq_no: i32, new_seq_no: i32) {
 
-        let begin_sq_no:u64 = hmap.get(tags::BEGIN_SEQ_NO.id()).unwrap().parse().unwrap();
-        let end_sq_no:u64   = hmap.get(tags::END_SEQ_NO.id()).unwrap().parse().unwrap();
+        // Stands in for the messages from seq_no up to new_seq_no, so it goes out under the first of
+        // their sequence numbers and carries PossDupFlag like any other replay.
+        let buf = self.builder.start(MsgType::SequenceReset)
+            .add_bool_field(fields::POSS_DUP_FLAG, true)
+            .add_timestamp_field(fields::ORIG_SENDING_TIME, chrono::offset::Utc::now())
+            .add_bool_field(fields::GAP_FILL_FLAG, true)
+            .add_seqnum_field(fields::NEW_SEQ_NO, new_seq_no)
+            .finish(seq_no);
+        self.transition(SessionEvent::SequenceResetSent);
+
+        self.resend(ApplicationMessage::from_bytes(buf)).await;
+    }
 
-        for message in self.msg_store.get_slice(begin_sq_no as usize, end_sq_no as usize) {
+    // GapFill mode only reaches here once the message itself was found to be in sequence, so the
+    // expected inbound MsgSeqNum has already moved past it. Reset mode is applied unconditionally.
+    // Either way the sequence number may only ever go forwards.
+    async fn on_sequence_reset(&mut self, msg: &FixMessage) {
 
-            self.resend(message.clone()).await;
+        self.transition(SessionEvent::SequenceResetReceived);
+
+        let msg_seq_no = get_seq_no(msg).unwrap_or(0);
+        let gap_fill = msg.get_bool(&fields::GAP_FILL_FLAG) == Ok(true);
+
+        let Some(new_seq_no) = get_seq_num_field(msg, &fields::NEW_SEQ_NO) else {
+            self.create_and_send_reject(msg_seq_no, Some(&fields::NEW_SEQ_NO), Some(&MsgType::SequenceReset), Some(SessionRejectReason::RequiredTagMissing), "Missing or invalid NewSeqNo").await;
+            return;
+        };
+
+        let lowest_allowed = if gap_fill { msg_seq_no + 1 } else { self.fix_status.next_seq_id_to_recv };
+
+        if new_seq_no < lowest_allowed {
+            let text = format!("Attempt to lower sequence number, invalid value NewSeqNo={}", new_seq_no);
+            fix_println!("{}", text);
+            self.create_and_send_reject(msg_seq_no, Some(&fields::NEW_SEQ_NO), Some(&MsgType::SequenceReset), Some(SessionRejectReason::ValueIsIncorrect), &text).await;
+            return;
         }
+
+        fix_println!("Resetting inbound sequence number from {} to {} ({}).", self.fix_status.next_seq_id_to_recv, new_seq_no, if gap_fill { "GapFill" } else { "Reset" });
+        self.fix_status.next_seq_id_to_recv = new_seq_no;
     }
 
-    async fn on_test_request(&mut self, message: String) {
-        self.create_and_send_heartbeat(&*message).await;
+    async fn on_logout(&mut self, msg: &FixMessage) {
+
+        if let Ok(text) = msg.get_str(&fields::TEXT) {
+            fix_println!("Logout reason: {}", text);
+        }
+
+        self.transition(SessionEvent::LogoutReceived);
+        self.fix_status.logged_out = true;
+
+        if self.fix_status.logout_sent_at.is_some() {
+            fix_println!("Logout confirmed by remote side.");
+        } else {
+            fix_println!("Remote side initiated Logout, confirming.");
+            self.create_and_send_logout("").await;
+        }
+
+        // Whatever state we were in, once both Logouts have crossed the session is over.
+        self.transition(SessionEvent::Disconnect);
     }
 
-    fn on_session_level_reject(&mut self) {
-        todo!()
+    // The Heartbeat has to carry the TestReqID exactly as it was sent to us.
+    async fn on_test_request(&mut self, msg: &FixMessage) {
+
+        self.transition(SessionEvent::TestRequestReceived);
+
+        match msg.get_str(&fields::TEST_REQ_ID) {
+            Ok(test_request_id) if !test_request_id.is_empty() => {
+                self.create_and_send_heartbeat(test_request_id).await;
+            }
+            _ => {
+                let ref_seq_num = get_seq_no(msg).unwrap_or(0);
+                self.create_and_send_reject(ref_seq_num, Some(&fields::TEST_REQ_ID), Some(&MsgType::TestRequest), Some(SessionRejectReason::RequiredTagMissing), "Required tag missing").await;
+            }
+        }
     }
 
-    fn on_dont_know(&mut self) {
-        todo!()
+    // The other side has rejected one of our messages, there's nothing to do at the session level
+    // other than pass it on.
+    fn on_session_level_reject(&mut self, msg: &FixMessage) {
+
+        self.transition(SessionEvent::RejectReceived);
+
+        let ref_seq_num = get_seq_num_field(msg, &fields::REF_SEQ_NUM).unwrap_or(0);
+        let reason = msg.get_int(&fields::SESSION_REJECT_REASON).ok().and_then(|v| i32::try_from(v).ok()).and_then(SessionRejectReason::from_value);
+        let text = msg.get_str(&fields::TEXT).unwrap_or_default();
+
+        self.application.on_session_reject(&self.session, ref_seq_num, reason, text);
     }
 
-    async fn on_logon_request(&mut self, message: String) {
+    async fn on_logon_request(&mut self, msg: &FixMessage) {
 
         fix_println!("Received a logon Request");
 
-        let mut hmap = HashMap::new();
-        parse_fix_message(message.as_str(), &mut hmap);
+        self.transition(SessionEvent::LogonReceived);
 
-        let heartbeat_interval:u64 = hmap.get(tags::HEARTBT_INT.id()).unwrap().parse().unwrap();
+        // Already checked by validate_logon.
+        let heartbeat_interval:u64 = msg.get_int(&fields::HEART_BT_INT).ok().and_then(|v| u64::try_from(v).ok()).unwrap_or(self.fix_status.hb_interval);
 
         self.fix_status.hb_interval = heartbeat_interval;
         fix_println!("Remote side has requested an HB interval of {} seconds.", heartbeat_interval);
@@ -262,91 +859,962 @@ impl MyFixMsgHandler {
 
         tokio::spawn( async move { x.send(heartbeat_interval * 1000).await.expect("TODO: panic message")});
 
-        //This is the initial response to the logon request
+        let reset_seq_num = msg.get_bool(&fields::RESET_SEQ_NUM_FLAG) == Ok(true);
+
+        if self.fix_status.reset_sent {
+            // This is the reply to our own Logon, which asked for the reset.
+            self.fix_status.reset_sent = false;
+            if reset_seq_num {
+                fix_println!("Sequence number reset confirmed by remote side.");
+            } else {
+                fix_println!("Remote side did not confirm the sequence number reset.");
+            }
+        } else if reset_seq_num {
+            // The other side has reset, so our outbound sequence starts again too and the reply says
+            // so. The inbound sequence was restarted from the Logon's own MsgSeqNum before it got here.
+            fix_println!("Remote side requested a sequence number reset.");
+            self.msg_store.reset();
+            self.fix_status.next_seq_id_to_send = 1;
+            self.save_seq_nums();
+            self.send_logon(true).await;
+            return;
+        }
+
+        //This is the initial response to the logon request, the acceptor only resets when asked to.
         //
-        if( self.fix_status.status == FixSessionStatus::DOWN ) {
-            self.create_and_send_logon().await;
+        if self.session_state.state() == SessionState::LogonReceived {
+            self.send_logon(false).await;
         }
     }
 }
-pub fn parse_fix_message(buf:&str, hmap:&mut HashMap<String, String>)  {
-
-    // split the string by the SOH character
-    let x = buf.split('');
-    //todo: remove the last entry
-    for s in x {
-
-        if !s.is_empty() {
-            //println!("Processing attribute: {}",s);
-
-            //then split each of those by the  '=' character
-            let o = s.find('=');
-            match o {
-                Some(result) => {
-                    // use the lhs as the key and the rhs as the value
-                    hmap.insert(s[..result].to_string(), s[result + 1..].to_string());
-                }
-                None => {
-                    println!("Badly formed attribute found: {}", s);
-                }
-            }
+
+// Without a data dictionary all we can check is that each tag is one we know and that its value
+// fits the tag's type.
+fn validate_known_tags(msg: &FixMessage) -> Option<(SessionRejectReason, Option<&'static FixTag>, String)> {
+
+    for (tag, value) in msg.fields() {
+
+        // The framing fields have already been checked by the decoder.
+        if FRAMING_TAGS.iter().any(|t| t.number() == tag) {
+            continue;
+        }
+
+        if tag == 0 {
+            return Some((SessionRejectReason::InvalidTagNumber, None, String::from("Invalid tag number")));
+        }
+
+        let fix_tag = match fields::find(tag) {
+            Some(fix_tag) => Some(fix_tag),
+            // 5000 - 9999 is reserved for user defined fields, which we can't know about.
+            None if (5000..=9999).contains(&tag) => None,
+            None => return Some((SessionRejectReason::UndefinedTag, None, format!("Undefined tag {}", tag))),
+        };
+
+        if value.is_empty() {
+            return Some((SessionRejectReason::TagSpecifiedWithoutValue, fix_tag, format!("Tag {} specified without a value", tag)));
+        }
+
+        let valid = std::str::from_utf8(value).is_ok_and(|v| fix_tag.is_none_or(|t| t.datatype().is_valid(v)));
+        if !valid {
+            return Some((SessionRejectReason::IncorrectDataFormat, fix_tag, format!("Incorrect data format for value of tag {}", tag)));
         }
     }
+
+    None
 }
 
-impl FixMsgHandler for MyFixMsgHandler {
+// BeginString, BodyLength, MsgType and CheckSum, which the decoder has already dealt with.
+const FRAMING_TAGS: [FixTag; 4] = [fields::BEGIN_STRING, fields::BODY_LENGTH, fields::MSG_TYPE, fields::CHECK_SUM];
+
+// Anything longer than an hour is more likely a mistake than a heartbeat interval.
+const MAX_HEARTBEAT_INTERVAL: u64 = 3600;
+
+const HEADER_REQUIRED_TAGS: [FixTag; 4] = [fields::SENDER_COMP_ID, fields::TARGET_COMP_ID, fields::MSG_SEQ_NUM, fields::SENDING_TIME];
 
-    fn on_new_order_single(&mut self) {
-        todo!()
+// The body fields the session layer can't do without, per admin message type.
+fn required_tags(msg_type: &MsgType) -> &'static [FixTag] {
+    match msg_type {
+        MsgType::TestRequest   => &[fields::TEST_REQ_ID],
+        MsgType::ResendRequest => &[fields::BEGIN_SEQ_NO, fields::END_SEQ_NO],
+        MsgType::Reject        => &[fields::REF_SEQ_NUM],
+        MsgType::SequenceReset => &[fields::NEW_SEQ_NO],
+        MsgType::Logon         => &[fields::ENCRYPT_METHOD, fields::HEART_BT_INT],
+        _                      => &[]
     }
+}
+
+fn get_seq_no(msg: &FixMessage) -> Option<i32> {
+    get_seq_num_field(msg, &fields::MSG_SEQ_NUM)
+}
 
-    fn on_accepted(&mut self) {
-        todo!()
+// Sequence numbers are kept as i32 throughout the session.
+fn get_seq_num_field(msg: &FixMessage, tag: &FixTag) -> Option<i32> {
+    msg.get_int(tag).ok().and_then(|v| i32::try_from(v).ok())
+}
+
+// Session level messages are never replayed during a resend, they are gap filled instead.
+fn is_admin_msg_type(msg_type: &MsgType) -> bool {
+    matches!(msg_type, MsgType::HeartBeat | MsgType::TestRequest | MsgType::ResendRequest | MsgType::SequenceReset | MsgType::Logout | MsgType::Logon)
+}
+
+// Read straight from a frame we sent, without indexing the whole thing. MsgType is always the
+// third field, straight after BodyLength.
+fn stored_msg_type(frame: &[u8]) -> Option<MsgType> {
+    let start = frame.windows(4).position(|w| w == b"\x0135=")? + 4;
+    let len = frame[start..].iter().position(|b| *b == b'\x01')?;
+    std::str::from_utf8(&frame[start..start + len]).ok().map(MsgType::from)
+}
+
+// The application's side of a session. Each application message the session accepts is decoded and
+// passed to one of these along with a handle to reply on. They all just log by default, so an
+// application only implements the ones it's interested in.
+pub trait FixMsgHandler {
+
+    fn on_session_reject(&mut self, _session: &SessionHandle, ref_seq_num: i32, reason: Option<SessionRejectReason>, text: &str) {
+        fix_println!("Message {} was rejected by the remote side, reason:{:?} text:'{}'", ref_seq_num, reason, text);
     }
 
-    fn on_acknowledged(&mut self) {
-        todo!()
+    fn on_business_reject(&mut self, _session: &SessionHandle, reject: &BusinessMessageReject) {
+        fix_println!("Message {:?} was rejected by the remote application, reason:{:?} text:'{}'",
+            reject.ref_seq_num, reject.business_reject_reason, reject.text.as_deref().unwrap_or_default());
     }
 
-    fn on_cancel_request(&mut self) {
-        todo!()
+    fn on_new_order_single(&mut self, _session: &SessionHandle, order: &NewOrderSingle) {
+        fix_println!("NewOrderSingle {} {:?} {} {:?}", order.cl_ord_id, order.side, order.symbol, order.order_qty);
     }
 
-    fn on_cancel_accepted(&mut self) {
-        todo!()
+    fn on_cancel_request(&mut self, _session: &SessionHandle, request: &OrderCancelRequest) {
+        fix_println!("OrderCancelRequest {} for {}", request.cl_ord_id, request.orig_cl_ord_id);
     }
 
-    fn on_cancel_rejected(&mut self) {
-        todo!()
+    fn on_cxl_replace_request(&mut self, _session: &SessionHandle, request: &OrderCancelReplaceRequest) {
+        fix_println!("OrderCancelReplaceRequest {} for {}", request.cl_ord_id, request.orig_cl_ord_id);
     }
 
-    fn on_cxl_replace_request(&mut self) {
-        todo!()
+    fn on_order_status_request(&mut self, _session: &SessionHandle, request: &OrderStatusRequest) {
+        fix_println!("OrderStatusRequest for {}", request.cl_ord_id);
     }
 
-    fn on_cxl_replace_accepted(&mut self) {
-        todo!()
+    // Every ExecutionReport comes here first and is passed on by its ExecType.
+    fn on_execution_report(&mut self, session: &SessionHandle, report: &ExecutionReport) {
+        match report.exec_type {
+            ExecType::PendingNew => self.on_acknowledged(session, report),
+            ExecType::New => self.on_accepted(session, report),
+            ExecType::Canceled => self.on_cancel_accepted(session, report),
+            ExecType::Replace => self.on_cxl_replace_accepted(session, report),
+            exec_type => fix_println!("ExecutionReport {} {:?} {:?}", report.exec_id, exec_type, report.ord_status),
+        }
+    }
+
+    fn on_acknowledged(&mut self, _session: &SessionHandle, report: &ExecutionReport) {
+        fix_println!("Order {:?} acknowledged", report.cl_ord_id);
+    }
+
+    fn on_accepted(&mut self, _session: &SessionHandle, report: &ExecutionReport) {
+        fix_println!("Order {:?} accepted as {}", report.cl_ord_id, report.order_id);
+    }
+
+    fn on_cancel_accepted(&mut self, _session: &SessionHandle, report: &ExecutionReport) {
+        fix_println!("Order {:?} canceled", report.orig_cl_ord_id);
     }
 
-    fn on_cxl_replace_rejected(&mut self) {
-        todo!()
+    fn on_cxl_replace_accepted(&mut self, _session: &SessionHandle, report: &ExecutionReport) {
+        fix_println!("Order {:?} replaced by {:?}", report.orig_cl_ord_id, report.cl_ord_id);
     }
 
-    fn on_execution_report(&mut self) {
-        todo!()
+    // Every OrderCancelReject comes here first and is passed on by what it's in response to.
+    fn on_order_cancel_reject(&mut self, session: &SessionHandle, reject: &OrderCancelReject) {
+        match reject.cxl_rej_response_to {
+            CxlRejResponseTo::OrderCancelRequest => self.on_cancel_rejected(session, reject),
+            CxlRejResponseTo::OrderCancelReplaceRequest => self.on_cxl_replace_rejected(session, reject),
+        }
+    }
+
+    fn on_cancel_rejected(&mut self, _session: &SessionHandle, reject: &OrderCancelReject) {
+        fix_println!("Cancel {} rejected: {:?}", reject.cl_ord_id, reject.text);
+    }
+
+    fn on_cxl_replace_rejected(&mut self, _session: &SessionHandle, reject: &OrderCancelReject) {
+        fix_println!("Cancel/replace {} rejected: {:?}", reject.cl_ord_id, reject.text);
     }
 }
 
-pub trait FixMsgHandler {
+// For running the session on its own. Reports and rejects are logged, while anything asking for an
+// order to be worked is turned away as there's nothing behind the session to work it.
+pub struct DefaultApplication;
+
+impl DefaultApplication {
+    fn not_available(session: &SessionHandle, msg_type: MsgType, cl_ord_id: &str) {
+        fix_println!("No application to handle {} {}, rejecting.", msg_type, cl_ord_id);
+        session.send(BusinessMessageReject {
+            ref_seq_num            : None,
+            ref_msg_type           : msg_type.to_string(),
+            business_reject_ref_id : Some(String::from(cl_ord_id)),
+            business_reject_reason : BusinessRejectReason::ApplicationNotAvailable,
+            text                   : Some(String::from("Application not available")),
+            encoded_text_len       : None,
+            encoded_text           : None,
+        });
+    }
+}
+
+impl FixMsgHandler for DefaultApplication {
+
+    fn on_new_order_single(&mut self, session: &SessionHandle, order: &NewOrderSingle) {
+        DefaultApplication::not_available(session, MsgType::NewOrderSingle, &order.cl_ord_id);
+    }
+
+    fn on_cancel_request(&mut self, session: &SessionHandle, request: &OrderCancelRequest) {
+        DefaultApplication::not_available(session, MsgType::OrderCancelRequest, &request.cl_ord_id);
+    }
+
+    fn on_cxl_replace_request(&mut self, session: &SessionHandle, request: &OrderCancelReplaceRequest) {
+        DefaultApplication::not_available(session, MsgType::OrderCancelReplaceRequest, &request.cl_ord_id);
+    }
+
+    fn on_order_status_request(&mut self, session: &SessionHandle, request: &OrderStatusRequest) {
+        DefaultApplication::not_available(session, MsgType::OrderStatusRequest, &request.cl_ord_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use std::sync::{Arc, Mutex};
+
+    // Wraps a body in BeginString, BodyLength and CheckSum.
+    fn build_message(body: &str) -> String {
+        let mut msg = format!("8=FIX.4.2\x019={}\x01{}", body.len(), body);
+        let cksum = fix_msg_builder::generate_check_sum(&msg);
+        msg.push_str(&format!("10={:03}\x01", cksum));
+        msg
+    }
+
+    fn get_field<'a>(msg: &'a str, tag: &str) -> &'a str {
+        let prefix = format!("\x01{}=", tag);
+        let start = msg.find(&prefix).unwrap() + prefix.len();
+        let end = start + msg[start..].find('\x01').unwrap();
+        &msg[start..end]
+    }
+
+    // Passes a message from TEST_CLIENT to the handler, with the rest of the header filled in.
+    async fn receive(mh: &mut MyFixMsgHandler, msg_type: &str, seq_no: i32, body: &str) {
+        let msg = build_message(&format!("35={}\x0149=TEST_CLIENT\x0156=TEST_SERVER\x0134={}\x0152=20250119-16:13:08.931\x01{}", msg_type, seq_no, body));
+        mh.handle_fix_message(&FixMessage::new(Bytes::from(msg))).await;
+    }
+
+    // Everything the handler has sent to the socket so far.
+    fn sent(to_socket: &mut Receiver<ApplicationMessage>) -> Vec<String> {
+        let mut sent = Vec::new();
+        while let Ok(msg) = to_socket.try_recv() {
+            sent.push(String::from(msg.get_message()));
+        }
+        sent
+    }
+
+    // Returns the handler and the receiving end of everything it sends to the socket.
+    fn create_handler() -> (MyFixMsgHandler, Receiver<ApplicationMessage>) {
+
+        let mut config = HashMap::new();
+        config.insert(String::from("sender_comp_id"), String::from("TEST_SERVER"));
+        config.insert(String::from("target_comp_id"), String::from("TEST_CLIENT"));
+        create_handler_from(&config)
+    }
+
+    fn create_handler_from(config: &HashMap<String, String>) -> (MyFixMsgHandler, Receiver<ApplicationMessage>) {
+
+        let (interval_tx, _interval_rx) = mpsc::channel::<u64>(1);
+        let (_sc2mh_tx, sc2mh_rx)       = mpsc::channel::<ApplicationMessage>(1);
+        let (mh2sc_tx, mh2sc_rx)        = mpsc::channel::<ApplicationMessage>(10);
+        let (_alarm_tx, alarm_rx)       = mpsc::channel::<AlarmMessage>(1);
+        let (_logout_tx, logout_rx)     = mpsc::channel::<String>(1);
+
+        let mut mh = MyFixMsgHandler::new(config, interval_tx, sc2mh_rx, mh2sc_tx, alarm_rx, logout_rx, Box::new(DefaultApplication));
+        mh.transition(SessionEvent::Connect);
+        (mh, mh2sc_rx)
+    }
+
+    #[tokio::test]
+    async fn test_test_request_is_answered_with_its_test_req_id() {
+
+        let (mut mh, mut to_socket) = create_handler();
+
+        let test_request = build_message("35=1\x0149=TEST_CLIENT\x0156=TEST_SERVER\x0134=1\x0152=20250119-16:13:08.931\x01112=TR-0001 x=y\x01");
+        mh.handle_fix_message(&FixMessage::new(Bytes::from(test_request))).await;
+
+        let heartbeat = to_socket.try_recv().unwrap();
+        let heartbeat = heartbeat.get_message();
+        let sending_time = get_field(heartbeat, fields::SENDING_TIME.id());
+
+        let expected = build_message(&format!("35=0\x0149=TEST_SERVER\x0156=TEST_CLIENT\x0134=1\x0152={}\x01112=TR-0001 x=y\x01", sending_time));
+        assert_eq!(heartbeat, &expected);
+        assert!(to_socket.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_test_request_without_test_req_id_is_rejected() {
+
+        let (mut mh, mut to_socket) = create_handler();
+
+        let test_request = build_message("35=1\x0149=TEST_CLIENT\x0156=TEST_SERVER\x0134=1\x0152=20250119-16:13:08.931\x01");
+        mh.handle_fix_message(&FixMessage::new(Bytes::from(test_request))).await;
+
+        let reject = to_socket.try_recv().unwrap();
+        let reject = reject.get_message();
+        assert_eq!(get_field(reject, fields::MSG_TYPE.id()), "3");
+        assert_eq!(get_field(reject, fields::REF_SEQ_NUM.id()), "1");
+        assert_eq!(get_field(reject, fields::REF_TAG_ID.id()), "112");
+        assert_eq!(get_field(reject, fields::REF_MSG_TYPE.id()), "1");
+        assert_eq!(get_field(reject, fields::SESSION_REJECT_REASON.id()), "1");
+        assert!(to_socket.try_recv().is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "spec/FIX42.xml is for FIX.4.2, but the session uses FIX.4.4")]
+    fn test_data_dictionary_must_match_the_session_version() {
+
+        let mut config = HashMap::new();
+        config.insert(String::from("version"), String::from("4.4"));
+        config.insert(String::from("data_dictionary"), String::from("spec/FIX42.xml"));
+        create_handler_from(&config);
+    }
+
+    #[tokio::test]
+    async fn test_logon_uses_configured_header() {
+
+        let mut config = HashMap::new();
+        config.insert(String::from("type"), String::from("client"));
+        config.insert(String::from("version"), String::from("4.4"));
+        config.insert(String::from("sender_comp_id"), String::from("CLIENT"));
+        config.insert(String::from("target_comp_id"), String::from("SERVER"));
+        config.insert(String::from("sender_sub_id"), String::from("DESK1"));
+        config.insert(String::from("target_sub_id"), String::from("ROUTE2"));
+        let (mut mh, mut to_socket) = create_handler_from(&config);
+
+        mh.create_and_send_logon().await;
+
+        let logon = to_socket.try_recv().unwrap();
+        let logon = logon.get_message();
+        assert!(logon.starts_with("8=FIX.4.4\x01"));
+        assert_eq!(get_field(logon, fields::SENDER_COMP_ID.id()), "CLIENT");
+        assert_eq!(get_field(logon, fields::TARGET_COMP_ID.id()), "SERVER");
+        assert_eq!(get_field(logon, fields::SENDER_SUB_ID.id()), "DESK1");
+        assert_eq!(get_field(logon, fields::TARGET_SUB_ID.id()), "ROUTE2");
+        assert!(!logon.contains("\x01142="));
+    }
+
+    #[tokio::test]
+    async fn test_message_is_validated_against_data_dictionary() {
+
+        let mut config = HashMap::new();
+        config.insert(String::from("sender_comp_id"), String::from("TEST_SERVER"));
+        config.insert(String::from("target_comp_id"), String::from("TEST_CLIENT"));
+        config.insert(String::from("data_dictionary"), String::from("spec/FIX42.xml"));
+        let (mut mh, mut to_socket) = create_handler_from(&config);
+
+        let order = build_message("35=D\x0149=TEST_CLIENT\x0156=TEST_SERVER\x0134=1\x0152=20250119-16:13:08.931\x0111=ORD1\x0121=1\x0155=IBM\x0160=20250119-16:13:08.931\x0140=1\x01");
+        mh.handle_fix_message(&FixMessage::new(Bytes::from(order))).await;
+
+        let reject = to_socket.try_recv().unwrap();
+        let reject = reject.get_message();
+        assert_eq!(get_field(reject, fields::MSG_TYPE.id()), "3");
+        assert_eq!(get_field(reject, fields::REF_TAG_ID.id()), "54");
+        assert_eq!(get_field(reject, fields::SESSION_REJECT_REASON.id()), "1");
+        assert!(to_socket.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_logon_from_unknown_comp_id_is_logged_out() {
+
+        let (mut mh, mut to_socket) = create_handler();
+
+        let logon = build_message("35=A\x0149=SOMEONE_ELSE\x0156=TEST_SERVER\x0134=1\x0152=20250119-16:13:08.931\x0198=0\x01108=30\x01");
+        mh.handle_fix_message(&FixMessage::new(Bytes::from(logon))).await;
+
+        let logout = to_socket.try_recv().unwrap();
+        let logout = logout.get_message();
+        assert_eq!(get_field(logout, fields::MSG_TYPE.id()), "5");
+        assert_eq!(get_field(logout, fields::TEXT.id()), "Unknown session SOMEONE_ELSE->TEST_SERVER");
+        assert!(to_socket.try_recv().is_err());
+        assert_eq!(mh.session_state.state(), SessionState::Disconnected);
+    }
+
+    #[tokio::test]
+    async fn test_refused_logon_does_not_reset_sequence_numbers() {
+
+        let (mut mh, mut to_socket, _) = create_logged_on_handler().await;
+
+        receive(&mut mh, "D", 5, &new_order("ORD5")).await;
+        assert_eq!(get_field(&sent(&mut to_socket)[0], "35"), "2");
+
+        let logon = build_message("35=A\x0149=SOMEONE_ELSE\x0156=TEST_SERVER\x0134=1\x0152=20250119-16:13:08.931\x0198=0\x01108=30\x01141=Y\x01");
+        mh.handle_fix_message(&FixMessage::new(Bytes::from(logon))).await;
+
+        assert_eq!(get_field(&sent(&mut to_socket)[0], "35"), "5");
+        assert_eq!(mh.fix_status.next_seq_id_to_recv, 2);
+        assert_eq!(mh.fix_status.resend_requested_to, Some(4));
+        assert!(mh.inbound_queue.contains_key(&5));
+    }
+
+    #[tokio::test]
+    async fn test_logon_with_invalid_heartbeat_interval_is_logged_out() {
+
+        let (mut mh, mut to_socket) = create_handler();
+
+        let logon = build_message("35=A\x0149=TEST_CLIENT\x0156=TEST_SERVER\x0134=1\x0152=20250119-16:13:08.931\x0198=0\x01108=-5\x01");
+        mh.handle_fix_message(&FixMessage::new(Bytes::from(logon))).await;
+
+        let logout = to_socket.try_recv().unwrap();
+        let logout = logout.get_message();
+        assert_eq!(get_field(logout, fields::MSG_TYPE.id()), "5");
+        assert!(get_field(logout, fields::TEXT.id()).starts_with("Invalid HeartBtInt"));
+        assert_eq!(mh.session_state.state(), SessionState::Disconnected);
+    }
+
+    #[tokio::test]
+    async fn test_application_message_before_logon_is_rejected() {
+
+        let (mut mh, mut to_socket) = create_handler();
+
+        let order = build_message("35=D\x0149=TEST_CLIENT\x0156=TEST_SERVER\x0134=1\x0152=20250119-16:13:08.931\x0111=ORD1\x01");
+        mh.handle_fix_message(&FixMessage::new(Bytes::from(order))).await;
+
+        let reject = to_socket.try_recv().unwrap();
+        let reject = reject.get_message();
+        assert_eq!(get_field(reject, fields::MSG_TYPE.id()), "3");
+        assert_eq!(get_field(reject, fields::REF_SEQ_NUM.id()), "1");
+        assert_eq!(get_field(reject, fields::REF_MSG_TYPE.id()), "D");
+        assert!(!reject.contains("\x01373="));
+        assert_eq!(mh.session_state.state(), SessionState::Connected);
+    }
+
+    // Records each callback and acknowledges every order it's given.
+    struct TestApplication {
+        calls : Arc<Mutex<Vec<String>>>
+    }
+
+    impl FixMsgHandler for TestApplication {
+
+        fn on_new_order_single(&mut self, session: &SessionHandle, order: &NewOrderSingle) {
+            self.calls.lock().unwrap().push(format!("new_order_single {} {:?}", order.cl_ord_id, order.side));
+
+            let ack = build_message("35=8\x0134=1\x0137=O1\x0117=E1\x0120=0\x01150=0\x0139=0\x0155=IBM\x0154=1\x01151=100\x0114=0\x016=0\x01");
+            let mut ack = ExecutionReport::decode(&FixMessage::new(Bytes::from(ack))).unwrap();
+            ack.cl_ord_id = Some(order.cl_ord_id.clone());
+            assert!(session.send(ack));
+        }
+
+        fn on_accepted(&mut self, _session: &SessionHandle, report: &ExecutionReport) {
+            self.calls.lock().unwrap().push(format!("accepted {}", report.order_id));
+        }
+
+        fn on_cancel_rejected(&mut self, _session: &SessionHandle, reject: &OrderCancelReject) {
+            self.calls.lock().unwrap().push(format!("cancel_rejected {}", reject.cl_ord_id));
+        }
+    }
+
+    // A handler that has accepted a Logon and so takes application messages, from MsgSeqNum 2.
+    async fn create_logged_on_handler() -> (MyFixMsgHandler, Receiver<ApplicationMessage>, Arc<Mutex<Vec<String>>>) {
+
+        let (mut mh, to_socket) = create_handler();
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        mh.application = Box::new(TestApplication { calls: calls.clone() });
+
+        let (mh, to_socket) = log_on(mh, to_socket).await;
+        (mh, to_socket, calls)
+    }
+
+    async fn log_on(mut mh: MyFixMsgHandler, mut to_socket: Receiver<ApplicationMessage>) -> (MyFixMsgHandler, Receiver<ApplicationMessage>) {
+
+        let logon = build_message("35=A\x0149=TEST_CLIENT\x0156=TEST_SERVER\x0134=1\x0152=20250119-16:13:08.931\x0198=0\x01108=30\x01");
+        mh.handle_fix_message(&FixMessage::new(Bytes::from(logon))).await;
+        assert_eq!(get_field(to_socket.try_recv().unwrap().get_message(), fields::MSG_TYPE.id()), "A");
+        assert!(mh.session_state.state().accepts_application_messages());
+
+        (mh, to_socket)
+    }
+
+    #[tokio::test]
+    async fn test_orders_are_passed_to_the_application() {
+
+        let (mut mh, mut to_socket, calls) = create_logged_on_handler().await;
+
+        let order = build_message("35=D\x0149=TEST_CLIENT\x0156=TEST_SERVER\x0134=2\x0152=20250119-16:13:08.931\x0111=ORD1\x0121=1\x0155=IBM\x0154=2\x0160=20250119-16:13:08.931\x0140=1\x01");
+        mh.handle_fix_message(&FixMessage::new(Bytes::from(order))).await;
+        assert_eq!(*calls.lock().unwrap(), vec![String::from("new_order_single ORD1 Sell")]);
+
+        // The reply goes out from the handler's loop.
+        assert!(to_socket.try_recv().is_err());
+        mh.send_application_messages().await;
+
+        let ack = to_socket.try_recv().unwrap();
+        let ack = ack.get_message();
+        assert_eq!(get_field(ack, fields::MSG_TYPE.id()), "8");
+        assert_eq!(get_field(ack, fields::MSG_SEQ_NUM.id()), "2");
+        assert_eq!(get_field(ack, fields::SENDER_COMP_ID.id()), "TEST_SERVER");
+        assert_eq!(get_field(ack, fields::CL_ORD_ID.id()), "ORD1");
+        assert!(to_socket.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_reports_and_cancel_rejects_are_routed_by_type() {
+
+        let (mut mh, mut to_socket, calls) = create_logged_on_handler().await;
+
+        let report = build_message("35=8\x0149=TEST_CLIENT\x0156=TEST_SERVER\x0134=2\x0152=20250119-16:13:08.931\x0137=O1\x0111=ORD1\x0117=E1\x0120=0\x01150=0\x0139=0\x0155=IBM\x0154=1\x01151=100\x0114=0\x016=0\x01");
+        mh.handle_fix_message(&FixMessage::new(Bytes::from(report))).await;
+        let reject = build_message("35=9\x0149=TEST_CLIENT\x0156=TEST_SERVER\x0134=3\x0152=20250119-16:13:08.931\x0137=O1\x0111=CXL1\x0141=ORD1\x0139=0\x01434=1\x01");
+        mh.handle_fix_message(&FixMessage::new(Bytes::from(reject))).await;
+
+        assert_eq!(*calls.lock().unwrap(), vec![String::from("accepted O1"), String::from("cancel_rejected CXL1")]);
+        assert!(to_socket.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_application_message_gets_business_reject() {
+
+        let (mut mh, mut to_socket, calls) = create_logged_on_handler().await;
 
-    fn on_new_order_single(&mut self);
-    fn on_accepted(&mut self);
-    fn on_acknowledged(&mut self);
-    fn on_cancel_request(&mut self);
-    fn on_cancel_accepted(&mut self);
-    fn on_cancel_rejected(&mut self);
-    fn on_cxl_replace_request(&mut self);
-    fn on_cxl_replace_accepted(&mut self);
-    fn on_cxl_replace_rejected(&mut self);
-    fn on_execution_report(&mut self);
-}
\ No newline at end of file
+        for (seq_no, msg_type) in [(2, "B"), (3, "U1")] {
+            let msg = build_message(&format!("35={}\x0149=TEST_CLIENT\x0156=TEST_SERVER\x0134={}\x0152=20250119-16:13:08.931\x01", msg_type, seq_no));
+            mh.handle_fix_message(&FixMessage::new(Bytes::from(msg))).await;
+
+            let reject = to_socket.try_recv().unwrap();
+            let reject = reject.get_message();
+            assert_eq!(get_field(reject, fields::MSG_TYPE.id()), "j");
+            assert_eq!(get_field(reject, fields::REF_SEQ_NUM.id()), seq_no.to_string());
+            assert_eq!(get_field(reject, "372"), msg_type);
+            assert_eq!(get_field(reject, "380"), "3");
+        }
+        assert!(calls.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unhandled_message_gets_business_reject() {
+
+        let mut config = HashMap::new();
+        config.insert(String::from("sender_comp_id"), String::from("TEST_SERVER"));
+        config.insert(String::from("target_comp_id"), String::from("TEST_CLIENT"));
+        config.insert(String::from("data_dictionary"), String::from("spec/FIX42.xml"));
+        let (mh, to_socket) = create_handler_from(&config);
+        let (mut mh, mut to_socket) = log_on(mh, to_socket).await;
+
+        // IndicationOfInterest is a valid FIX 4.2 message that nothing here handles, U1 isn't one at all.
+        receive(&mut mh, "6", 2, "23=IOI1\x0128=N\x0155=IBM\x0154=1\x0127=100\x01").await;
+        let reject = sent(&mut to_socket);
+        assert_eq!(get_field(&reject[0], "35"), "j");
+        assert_eq!(get_field(&reject[0], "372"), "6");
+        assert_eq!(get_field(&reject[0], "380"), "3");
+
+        receive(&mut mh, "U1", 3, "").await;
+        let reject = sent(&mut to_socket);
+        assert_eq!(get_field(&reject[0], "35"), "3");
+        assert_eq!(get_field(&reject[0], "371"), "35");
+        assert_eq!(get_field(&reject[0], "373"), "11");
+    }
+
+    #[tokio::test]
+    async fn test_default_application_turns_orders_away() {
+
+        let (mh, to_socket) = create_handler();
+        let (mut mh, mut to_socket) = log_on(mh, to_socket).await;
+
+        receive(&mut mh, "D", 2, &new_order("ORD2")).await;
+        mh.send_application_messages().await;
+
+        let reject = sent(&mut to_socket);
+        assert_eq!(reject.len(), 1);
+        assert_eq!(get_field(&reject[0], "35"), "j");
+        assert_eq!(get_field(&reject[0], "372"), "D");
+        assert_eq!(get_field(&reject[0], "379"), "ORD2");
+        assert_eq!(get_field(&reject[0], "380"), "4");
+    }
+
+    #[tokio::test]
+    async fn test_order_that_does_not_decode_is_rejected() {
+
+        let (mut mh, mut to_socket, calls) = create_logged_on_handler().await;
+
+        let order = build_message("35=D\x0149=TEST_CLIENT\x0156=TEST_SERVER\x0134=2\x0152=20250119-16:13:08.931\x0111=ORD1\x0121=1\x0155=IBM\x0160=20250119-16:13:08.931\x0140=1\x01");
+        mh.handle_fix_message(&FixMessage::new(Bytes::from(order))).await;
+
+        let reject = to_socket.try_recv().unwrap();
+        let reject = reject.get_message();
+        assert_eq!(get_field(reject, fields::MSG_TYPE.id()), "3");
+        assert_eq!(get_field(reject, fields::REF_TAG_ID.id()), "54");
+        assert_eq!(get_field(reject, fields::SESSION_REJECT_REASON.id()), "1");
+        assert!(calls.lock().unwrap().is_empty());
+    }
+
+    fn execution_report(cl_ord_id: &str) -> ExecutionReport {
+        let report = build_message("35=8\x0134=1\x0137=O1\x0117=E1\x0120=0\x01150=0\x0139=0\x0155=IBM\x0154=1\x01151=100\x0114=0\x016=0\x01");
+        let mut report = ExecutionReport::decode(&FixMessage::new(Bytes::from(report))).unwrap();
+        report.cl_ord_id = Some(String::from(cl_ord_id));
+        report
+    }
+
+    // Logon(1) and a Heartbeat(2) are followed by an ExecutionReport(3), another Heartbeat(4) and a
+    // second ExecutionReport(5).
+    async fn create_handler_with_history() -> (MyFixMsgHandler, Receiver<ApplicationMessage>) {
+
+        let (mut mh, mut to_socket, _) = create_logged_on_handler().await;
+        mh.create_and_send_heartbeat("").await;
+        assert!(mh.session.send(execution_report("ORD1")));
+        mh.send_application_messages().await;
+        mh.create_and_send_heartbeat("").await;
+        assert!(mh.session.send(execution_report("ORD2")));
+        mh.send_application_messages().await;
+        assert_eq!(sent(&mut to_socket).len(), 4);
+        (mh, to_socket)
+    }
+
+    #[tokio::test]
+    async fn test_resend_request_replays_application_messages_and_gap_fills_the_rest() {
+
+        let (mut mh, mut to_socket) = create_handler_with_history().await;
+
+        receive(&mut mh, "2", 2, "7=1\x0116=0\x01").await;
+
+        let replayed = sent(&mut to_socket);
+        let fields = |msg: &str| (get_field(msg, "35").to_string(), get_field(msg, "34").to_string(), get_field(msg, "43").to_string());
+        assert_eq!(replayed.iter().map(|msg| fields(msg)).collect::<Vec<_>>(), vec![
+            (String::from("4"), String::from("1"), String::from("Y")),
+            (String::from("8"), String::from("3"), String::from("Y")),
+            (String::from("4"), String::from("4"), String::from("Y")),
+            (String::from("8"), String::from("5"), String::from("Y")),
+        ]);
+
+        // Logon and Heartbeat collapse into a single GapFill up to the ExecutionReport, as does the
+        // lone Heartbeat between the two reports.
+        assert_eq!(get_field(&replayed[0], "123"), "Y");
+        assert_eq!(get_field(&replayed[0], "36"), "3");
+        assert_eq!(get_field(&replayed[2], "36"), "5");
+
+        // The replays are the original messages with their SendingTime moved to OrigSendingTime.
+        assert_eq!(get_field(&replayed[1], "11"), "ORD1");
+        assert_eq!(get_field(&replayed[3], "11"), "ORD2");
+        assert!(replayed[1].contains("\x01122="));
+
+        // Nothing that was replayed took a new sequence number.
+        assert_eq!(mh.fix_status.next_seq_id_to_send, 6);
+    }
+
+    #[tokio::test]
+    async fn test_resend_request_end_seq_no_is_clamped_to_last_sent() {
+
+        let (mut mh, mut to_socket) = create_handler_with_history().await;
+
+        for (seq_no, end_seq_no) in [(2, "0"), (3, "999999"), (4, "50")] {
+            receive(&mut mh, "2", seq_no, &format!("7=4\x0116={}\x01", end_seq_no)).await;
+
+            let replayed = sent(&mut to_socket);
+            assert_eq!(replayed.len(), 2);
+            assert_eq!(get_field(&replayed[0], "35"), "4");
+            assert_eq!(get_field(&replayed[0], "34"), "4");
+            assert_eq!(get_field(&replayed[0], "36"), "5");
+            assert_eq!(get_field(&replayed[1], "34"), "5");
+            assert_eq!(get_field(&replayed[1], "11"), "ORD2");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resend_request_from_zero_is_rejected() {
+
+        let (mut mh, mut to_socket) = create_handler_with_history().await;
+
+        receive(&mut mh, "2", 2, "7=0\x0116=0\x01").await;
+
+        let reject = sent(&mut to_socket);
+        assert_eq!(reject.len(), 1);
+        assert_eq!(get_field(&reject[0], "35"), "3");
+        assert_eq!(get_field(&reject[0], "34"), "6");
+        assert_eq!(get_field(&reject[0], "45"), "2");
+        assert_eq!(get_field(&reject[0], "371"), "7");
+        assert_eq!(get_field(&reject[0], "373"), "5");
+    }
+
+    fn new_order(cl_ord_id: &str) -> String {
+        format!("11={}\x0121=1\x0155=IBM\x0154=2\x0160=20250119-16:13:08.931\x0140=1\x01", cl_ord_id)
+    }
+
+    #[tokio::test]
+    async fn test_gap_is_detected_and_later_messages_queued() {
+
+        let (mut mh, mut to_socket, calls) = create_logged_on_handler().await;
+
+        receive(&mut mh, "D", 4, &new_order("ORD4")).await;
+
+        let resend_request = sent(&mut to_socket);
+        assert_eq!(resend_request.len(), 1);
+        assert_eq!(get_field(&resend_request[0], "35"), "2");
+        assert_eq!(get_field(&resend_request[0], "7"), "2");
+        assert_eq!(get_field(&resend_request[0], "16"), "3");
+
+        // Held back until the gap is filled, and no second ResendRequest for the same gap.
+        receive(&mut mh, "D", 5, &new_order("ORD5")).await;
+        assert!(calls.lock().unwrap().is_empty());
+        assert!(sent(&mut to_socket).is_empty());
+        assert_eq!(mh.fix_status.next_seq_id_to_recv, 2);
+    }
+
+    #[tokio::test]
+    async fn test_queued_messages_are_processed_in_sequence_once_the_gap_is_filled() {
+
+        let (mut mh, mut to_socket, calls) = create_logged_on_handler().await;
+
+        receive(&mut mh, "D", 5, &new_order("ORD5")).await;
+        receive(&mut mh, "D", 4, &new_order("ORD4")).await;
+        receive(&mut mh, "D", 2, &new_order("ORD2")).await;
+        assert_eq!(calls.lock().unwrap().len(), 1);
+        receive(&mut mh, "D", 3, &new_order("ORD3")).await;
+
+        let cl_ord_ids: Vec<String> = calls.lock().unwrap().iter().map(|call| call.split(' ').nth(1).unwrap().to_string()).collect();
+        assert_eq!(cl_ord_ids, vec!["ORD2", "ORD3", "ORD4", "ORD5"]);
+        assert_eq!(mh.fix_status.next_seq_id_to_recv, 6);
+        assert_eq!(mh.fix_status.resend_requested_to, None);
+        assert!(mh.inbound_queue.is_empty());
+        assert_eq!(sent(&mut to_socket).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_second_gap_is_requested_once_the_first_is_filled() {
+
+        let (mut mh, mut to_socket, calls) = create_logged_on_handler().await;
+
+        receive(&mut mh, "D", 4, &new_order("ORD4")).await;
+        receive(&mut mh, "D", 7, &new_order("ORD7")).await;
+        assert_eq!(sent(&mut to_socket).len(), 1);
+
+        receive(&mut mh, "D", 2, &new_order("ORD2")).await;
+        receive(&mut mh, "D", 3, &new_order("ORD3")).await;
+
+        let resend_request = sent(&mut to_socket);
+        assert_eq!(resend_request.len(), 1);
+        assert_eq!(get_field(&resend_request[0], "35"), "2");
+        assert_eq!(get_field(&resend_request[0], "7"), "5");
+        assert_eq!(get_field(&resend_request[0], "16"), "6");
+        assert_eq!(calls.lock().unwrap().len(), 3);
+
+        receive(&mut mh, "D", 5, &new_order("ORD5")).await;
+        receive(&mut mh, "D", 6, &new_order("ORD6")).await;
+        assert_eq!(calls.lock().unwrap().len(), 6);
+        assert_eq!(calls.lock().unwrap()[5], "new_order_single ORD7 Sell");
+        assert_eq!(mh.fix_status.next_seq_id_to_recv, 8);
+        assert!(sent(&mut to_socket).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sequence_reset_gap_fill_moves_the_expected_seq_no_on() {
+
+        let (mut mh, mut to_socket, calls) = create_logged_on_handler().await;
+
+        receive(&mut mh, "4", 2, "43=Y\x01123=Y\x0136=5\x01").await;
+        assert_eq!(mh.fix_status.next_seq_id_to_recv, 5);
+
+        receive(&mut mh, "D", 5, &new_order("ORD5")).await;
+        assert_eq!(*calls.lock().unwrap(), vec![String::from("new_order_single ORD5 Sell")]);
+        assert!(sent(&mut to_socket).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sequence_reset_reset_ignores_msg_seq_num() {
+
+        let (mut mh, mut to_socket, _) = create_logged_on_handler().await;
+
+        receive(&mut mh, "4", 99, "123=N\x0136=10\x01").await;
+        assert_eq!(mh.fix_status.next_seq_id_to_recv, 10);
+
+        receive(&mut mh, "4", 1, "36=20\x01").await;
+        assert_eq!(mh.fix_status.next_seq_id_to_recv, 20);
+        assert!(sent(&mut to_socket).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sequence_reset_reset_from_wrong_comp_id_is_rejected() {
+
+        let (mut mh, mut to_socket, _) = create_logged_on_handler().await;
+
+        let reset = build_message("35=4\x0149=SOMEONE_ELSE\x0156=TEST_SERVER\x0134=2\x0152=20250119-16:13:08.931\x0136=10\x01");
+        mh.handle_fix_message(&FixMessage::new(Bytes::from(reset))).await;
+
+        let replies = sent(&mut to_socket);
+        assert_eq!(get_field(&replies[0], "35"), "3");
+        assert_eq!(get_field(&replies[0], "373"), "9");
+        assert_eq!(get_field(&replies[1], "35"), "5");
+        assert_eq!(mh.fix_status.next_seq_id_to_recv, 2);
+    }
+
+    #[tokio::test]
+    async fn test_sequence_reset_to_a_lower_seq_no_is_rejected() {
+
+        let (mut mh, mut to_socket, _) = create_logged_on_handler().await;
+
+        // Reset mode may not go below what we expect next, GapFill not below the message after itself.
+        for (seq_no, body) in [(7, "36=1\x01"), (2, "123=Y\x0136=2\x01")] {
+            receive(&mut mh, "4", seq_no, body).await;
+
+            let reject = sent(&mut to_socket);
+            assert_eq!(reject.len(), 1);
+            assert_eq!(get_field(&reject[0], "35"), "3");
+            assert_eq!(get_field(&reject[0], "45"), seq_no.to_string());
+            assert_eq!(get_field(&reject[0], "371"), "36");
+            assert_eq!(get_field(&reject[0], "373"), "5");
+        }
+        assert_eq!(mh.fix_status.next_seq_id_to_recv, 3);
+    }
+
+    #[tokio::test]
+    async fn test_second_inbound_timeout_without_a_reply_disconnects() {
+
+        let (mut mh, mut to_socket, _) = create_logged_on_handler().await;
+
+        mh.on_inbound_timeout().await;
+        let test_request = sent(&mut to_socket);
+        assert_eq!(get_field(&test_request[0], "35"), "1");
+        assert!(get_field(&test_request[0], "112").starts_with("TEST-"));
+
+        mh.on_inbound_timeout().await;
+        let logout = sent(&mut to_socket);
+        assert_eq!(get_field(&logout[0], "35"), "5");
+        assert_eq!(get_field(&logout[0], "58"), "Heartbeat timeout");
+        assert_eq!(mh.session_state.state(), SessionState::Disconnected);
+    }
+
+    #[tokio::test]
+    async fn test_any_inbound_message_answers_a_pending_test_request() {
+
+        let (mut mh, mut to_socket, _) = create_logged_on_handler().await;
+
+        mh.on_inbound_timeout().await;
+        assert_eq!(get_field(&sent(&mut to_socket)[0], "35"), "1");
+
+        // Not the Heartbeat we asked for, but proof enough the other side is there.
+        receive(&mut mh, "D", 2, &new_order("ORD2")).await;
+        assert_eq!(mh.fix_status.test_request_id, None);
+
+        mh.on_inbound_timeout().await;
+        assert_eq!(get_field(&sent(&mut to_socket)[0], "35"), "1");
+        assert_ne!(mh.session_state.state(), SessionState::Disconnected);
+    }
+
+    #[tokio::test]
+    async fn test_logout_we_initiate_ends_when_confirmed() {
+
+        let (mut mh, mut to_socket, calls) = create_logged_on_handler().await;
+
+        mh.initiate_logout("End of day").await;
+        let logout = sent(&mut to_socket);
+        assert_eq!(get_field(&logout[0], "35"), "5");
+        assert_eq!(get_field(&logout[0], "58"), "End of day");
+        assert_eq!(mh.session_state.state(), SessionState::LogoutSent);
+
+        // Asking again doesn't send a second Logout, and orders already in flight are still taken.
+        mh.initiate_logout("End of day").await;
+        receive(&mut mh, "D", 2, &new_order("ORD2")).await;
+        assert!(sent(&mut to_socket).is_empty());
+        assert_eq!(calls.lock().unwrap().len(), 1);
+
+        receive(&mut mh, "5", 3, "").await;
+        assert!(sent(&mut to_socket).is_empty());
+        assert!(mh.fix_status.logged_out);
+        assert_eq!(mh.session_state.state(), SessionState::Disconnected);
+    }
+
+    #[tokio::test]
+    async fn test_logout_from_the_other_side_is_confirmed() {
+
+        let (mut mh, mut to_socket, _) = create_logged_on_handler().await;
+
+        receive(&mut mh, "5", 2, "58=Going away\x01").await;
+
+        let logout = sent(&mut to_socket);
+        assert_eq!(logout.len(), 1);
+        assert_eq!(get_field(&logout[0], "35"), "5");
+        assert!(mh.fix_status.logged_out);
+        assert_eq!(mh.session_state.state(), SessionState::Disconnected);
+    }
+
+    fn reset_on_logon_config(client: bool) -> HashMap<String, String> {
+
+        let (sender, target) = if client { ("TEST_CLIENT", "TEST_SERVER") } else { ("TEST_SERVER", "TEST_CLIENT") };
+        let mut config = HashMap::new();
+        config.insert(String::from("type"), String::from(if client { "client" } else { "server" }));
+        config.insert(String::from("sender_comp_id"), String::from(sender));
+        config.insert(String::from("target_comp_id"), String::from(target));
+        config.insert(String::from("reset_on_logon"), String::from("true"));
+        config.insert(String::from("heartbeat_interval"), String::from("45"));
+        config
+    }
+
+    #[tokio::test]
+    async fn test_acceptor_does_not_reset_on_logon_without_reset_seq_num_flag() {
+
+        let (mut mh, mut to_socket) = create_handler_from(&reset_on_logon_config(false));
+        mh.fix_status.next_seq_id_to_send = 8;
+        mh.fix_status.next_seq_id_to_recv = 5;
+
+        receive(&mut mh, "A", 5, "98=0\x01108=30\x01").await;
+
+        let logon = sent(&mut to_socket);
+        assert_eq!(get_field(&logon[0], "35"), "A");
+        assert_eq!(get_field(&logon[0], "34"), "8");
+        assert!(!logon[0].contains("\x01141="));
+        assert_eq!(mh.fix_status.next_seq_id_to_recv, 6);
+        assert_eq!(mh.fix_status.next_seq_id_to_send, 9);
+    }
+
+    #[tokio::test]
+    async fn test_acceptor_resets_and_echoes_reset_seq_num_flag() {
+
+        let (mut mh, mut to_socket) = create_handler_from(&reset_on_logon_config(false));
+        mh.fix_status.next_seq_id_to_send = 8;
+        mh.fix_status.next_seq_id_to_recv = 5;
+
+        receive(&mut mh, "A", 1, "98=0\x01108=30\x01141=Y\x01").await;
+
+        let logon = sent(&mut to_socket);
+        assert_eq!(get_field(&logon[0], "35"), "A");
+        assert_eq!(get_field(&logon[0], "34"), "1");
+        assert_eq!(get_field(&logon[0], "141"), "Y");
+        assert_eq!(mh.fix_status.next_seq_id_to_recv, 2);
+        assert_eq!(mh.fix_status.next_seq_id_to_send, 2);
+    }
+
+    #[tokio::test]
+    async fn test_initiator_resets_on_its_own_logon() {
+
+        let (mut mh, mut to_socket) = create_handler_from(&reset_on_logon_config(true));
+        mh.fix_status.next_seq_id_to_send = 8;
+        mh.fix_status.next_seq_id_to_recv = 5;
+
+        mh.create_and_send_logon().await;
+        let logon = sent(&mut to_socket);
+        assert_eq!(get_field(&logon[0], "34"), "1");
+        assert_eq!(get_field(&logon[0], "108"), "45");
+        assert_eq!(get_field(&logon[0], "141"), "Y");
+        assert_eq!(mh.fix_status.next_seq_id_to_recv, 1);
+
+        let reply = build_message("35=A\x0149=TEST_SERVER\x0156=TEST_CLIENT\x0134=1\x0152=20250119-16:13:08.931\x0198=0\x01108=45\x01141=Y\x01");
+        mh.handle_fix_message(&FixMessage::new(Bytes::from(reply))).await;
+        assert!(sent(&mut to_socket).is_empty());
+        assert!(!mh.fix_status.reset_sent);
+        assert_eq!(mh.fix_status.next_seq_id_to_recv, 2);
+        assert_eq!(mh.session_state.state(), SessionState::Active);
+    }
+
+    #[tokio::test]
+    async fn test_initiator_logon_reply_without_reset_seq_num_flag() {
+
+        let (mut mh, mut to_socket) = create_handler_from(&reset_on_logon_config(true));
+
+        mh.create_and_send_logon().await;
+        assert_eq!(get_field(&sent(&mut to_socket)[0], "141"), "Y");
+
+        let reply = build_message("35=A\x0149=TEST_SERVER\x0156=TEST_CLIENT\x0134=1\x0152=20250119-16:13:08.931\x0198=0\x01108=45\x01");
+        mh.handle_fix_message(&FixMessage::new(Bytes::from(reply))).await;
+        assert!(sent(&mut to_socket).is_empty());
+        assert!(!mh.fix_status.reset_sent);
+        assert_eq!(mh.session_state.state(), SessionState::Active);
+    }
+}
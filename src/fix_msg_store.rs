@@ -0,0 +1,312 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use bytes::Bytes;
+use crate::fix_println;
+use crate::socket_actor::ApplicationMessage;
+
+// Everything the session needs to survive a restart: the messages we've sent, so they can be
+// replayed in response to a ResendRequest, and the next sequence numbers in each direction.
+pub trait FixMsgStore {
+
+    fn push(&mut self, sequence_number: i32, value: &ApplicationMessage);
+    fn get_single(&self, sequence_number: i32) -> Option<ApplicationMessage>;
+    fn len(&self) -> usize;
+
+    // (next sender seq num, next target seq num), or None if nothing has been stored yet.
+    fn get_seq_nums(&self) -> Option<(i32, i32)>;
+    fn set_seq_nums(&mut self, next_sender_seq_num: i32, next_target_seq_num: i32);
+
+    fn reset(&mut self);
+}
+
+// Picks the store from the session config, "file_store_path" switches on the FileMsgStore.
+pub fn create_msg_store(config: &HashMap<String, String>) -> Box<dyn FixMsgStore + Send + Sync> {
+
+    match config.get("file_store_path") {
+        Some(path) => {
+            let sender_comp_id = config.get("sender_comp_id").map(String::as_str).unwrap_or("SENDER");
+            let target_comp_id = config.get("target_comp_id").map(String::as_str).unwrap_or("TARGET");
+            let session_id = format!("{}-{}", sender_comp_id, target_comp_id);
+
+            match FileMsgStore::open(path, &session_id) {
+                Ok(store) => Box::new(store),
+                Err(e) => panic!("Unable to open message store in {}: {}", path, e)
+            }
+        }
+        None => Box::new(MemoryMsgStore::new())
+    }
+}
+
+pub struct MemoryMsgStore {
+
+    store    : BTreeMap<i32, ApplicationMessage>,
+    seq_nums : Option<(i32, i32)>
+}
+
+impl MemoryMsgStore {
+    pub fn new() -> MemoryMsgStore {
+       Self {
+           store    : BTreeMap::new(),
+           seq_nums : None
+       }
+    }
+}
+
+impl FixMsgStore for MemoryMsgStore {
+
+    fn push(&mut self, sequence_number: i32, value: &ApplicationMessage) {
+        self.store.insert(sequence_number, value.clone());
+    }
+
+    fn get_single(&self, sequence_number: i32) -> Option<ApplicationMessage> {
+        // Probably want an iterator here
+        // as well send to send replays in batches, yielding once the buffer is full
+        // What is a sensible amount of data to send - do we send message at a time or just a mass
+        // also potentially want to throttle resend msgs/sec ( for extra credit )
+        self.store.get(&sequence_number).cloned()
+    }
+
+    fn len(&self) -> usize {
+        self.store.len()
+    }
+
+    fn get_seq_nums(&self) -> Option<(i32, i32)> {
+        self.seq_nums
+    }
+
+    fn set_seq_nums(&mut self, next_sender_seq_num: i32, next_target_seq_num: i32) {
+        self.seq_nums = Some((next_sender_seq_num, next_target_seq_num));
+    }
+
+    fn reset(&mut self) {
+        self.store.clear();
+        self.seq_nums = None;
+    }
+}
+
+// Along the lines of QuickFIX's FileStore, for each session there are three files:
+//   <session>.body    - every message we've sent, appended one after the other.
+//   <session>.header  - an index into the body file, one "seq_no,offset,size" line per message.
+//   <session>.seqnums - the next sender and target sequence numbers, rewritten on every change.
+pub struct FileMsgStore {
+
+    body_path    : PathBuf,
+    header_path  : PathBuf,
+    seqnums_path : PathBuf,
+    body_file    : File,
+    header_file  : File,
+    index        : BTreeMap<i32, (u64, usize)>,
+    seq_nums     : Option<(i32, i32)>
+}
+
+impl FileMsgStore {
+
+    pub fn open(path: &str, session_id: &str) -> io::Result<FileMsgStore> {
+
+        fs::create_dir_all(path)?;
+
+        let base = PathBuf::from(path);
+        let body_path    = base.join(format!("{}.body", session_id));
+        let header_path  = base.join(format!("{}.header", session_id));
+        let seqnums_path = base.join(format!("{}.seqnums", session_id));
+
+        let body_file   = OpenOptions::new().create(true).append(true).read(true).open(&body_path)?;
+        let header_file = OpenOptions::new().create(true).append(true).read(true).open(&header_path)?;
+
+        let mut store = FileMsgStore {
+            body_path,
+            header_path,
+            seqnums_path,
+            body_file,
+            header_file,
+            index    : BTreeMap::new(),
+            seq_nums : None
+        };
+
+        store.load()?;
+
+        fix_println!("Opened message store for {} holding {} messages, sequence numbers:{:?}", session_id, store.index.len(), store.seq_nums);
+        Ok(store)
+    }
+
+    fn load(&mut self) -> io::Result<()> {
+
+        for line in BufReader::new(File::open(&self.header_path)?).lines() {
+            let line = line?;
+            let parts: Vec<&str> = line.split(',').collect();
+
+            if let [seq_no, offset, size] = parts[..] {
+                match (seq_no.parse(), offset.parse(), size.parse()) {
+                    (Ok(seq_no), Ok(offset), Ok(size)) => { self.index.insert(seq_no, (offset, size)); },
+                    _ => fix_println!("Ignoring corrupt message store index entry: {}", line)
+                }
+            }
+        }
+
+        // "0000000002 : 0000000005"
+        if let Ok(seqnums) = fs::read_to_string(&self.seqnums_path) {
+            if let Some((sender, target)) = seqnums.trim().split_once(':') {
+                if let (Ok(sender), Ok(target)) = (sender.trim().parse(), target.trim().parse()) {
+                    self.seq_nums = Some((sender, target));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn append(&mut self, sequence_number: i32, value: &ApplicationMessage) -> io::Result<()> {
+
+        let bytes = value.as_bytes();
+        let offset = self.body_file.seek(SeekFrom::End(0))?;
+
+        self.body_file.write_all(bytes)?;
+        self.body_file.flush()?;
+
+        writeln!(self.header_file, "{},{},{}", sequence_number, offset, bytes.len())?;
+        self.header_file.flush()?;
+
+        self.index.insert(sequence_number, (offset, bytes.len()));
+        Ok(())
+    }
+
+    fn read(&self, offset: u64, size: usize) -> io::Result<ApplicationMessage> {
+
+        let mut file = File::open(&self.body_path)?;
+        let mut buf = vec![0u8; size];
+
+        file.seek(SeekFrom::Start(offset))?;
+        file.read_exact(&mut buf)?;
+
+        Ok(ApplicationMessage::from_bytes(Bytes::from(buf)))
+    }
+}
+
+impl FixMsgStore for FileMsgStore {
+
+    fn push(&mut self, sequence_number: i32, value: &ApplicationMessage) {
+        if let Err(e) = self.append(sequence_number, value) {
+            fix_println!("Failed to store message {}: {}", sequence_number, e);
+        }
+    }
+
+    fn get_single(&self, sequence_number: i32) -> Option<ApplicationMessage> {
+
+        let (offset, size) = *self.index.get(&sequence_number)?;
+
+        match self.read(offset, size) {
+            Ok(msg) => Some(msg),
+            Err(e) => {
+                fix_println!("Failed to read message {} from store: {}", sequence_number, e);
+                None
+            }
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    fn get_seq_nums(&self) -> Option<(i32, i32)> {
+        self.seq_nums
+    }
+
+    fn set_seq_nums(&mut self, next_sender_seq_num: i32, next_target_seq_num: i32) {
+
+        if self.seq_nums == Some((next_sender_seq_num, next_target_seq_num)) {
+            return;
+        }
+        self.seq_nums = Some((next_sender_seq_num, next_target_seq_num));
+
+        // Write then rename, so a crash part way through can't leave us with a half written file. The
+        // new file is synced before the rename and the directory after it, otherwise a power cut can
+        // still lose the contents or the rename itself.
+        let tmp_path = self.seqnums_path.with_extension("seqnums.tmp");
+        let res = File::create(&tmp_path)
+            .and_then(|mut file| {
+                file.write_all(format!("{:010} : {:010}", next_sender_seq_num, next_target_seq_num).as_bytes())?;
+                file.sync_all()
+            })
+            .and_then(|_| fs::rename(&tmp_path, &self.seqnums_path))
+            .and_then(|_| sync_dir(&self.seqnums_path));
+
+        if let Err(e) = res {
+            fix_println!("Failed to store sequence numbers: {}", e);
+        }
+    }
+
+    fn reset(&mut self) {
+
+        let res = self.body_file.set_len(0)
+            .and_then(|_| self.header_file.set_len(0))
+            .and_then(|_| match fs::remove_file(&self.seqnums_path) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+                _ => Ok(())
+            });
+
+        if let Err(e) = res {
+            fix_println!("Failed to reset message store: {}", e);
+        }
+        self.index.clear();
+        self.seq_nums = None;
+    }
+}
+
+// Makes a rename in the directory holding path durable.
+fn sync_dir(path: &Path) -> io::Result<()> {
+    match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => File::open(dir)?.sync_all(),
+        _ => File::open(".")?.sync_all()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store_path(name: &str) -> String {
+        let path = std::env::temp_dir().join(format!("tokyofix-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        path.to_string_lossy().to_string()
+    }
+
+    #[test]
+    fn test_file_msg_store_survives_reopen() {
+
+        let path = store_path("reopen");
+
+        let mut store = FileMsgStore::open(&path, "SERVER-CLIENT").unwrap();
+        store.push(1, &ApplicationMessage::from_bytes(Bytes::from_static(b"8=FIX.4.2\x019=5\x0135=0\x0110=161\x01")));
+        store.push(2, &ApplicationMessage::from_bytes(Bytes::from_static(b"8=FIX.4.2\x019=11\x0135=D\x0111=A\x0110=000\x01")));
+        store.set_seq_nums(3, 7);
+        drop(store);
+
+        let store = FileMsgStore::open(&path, "SERVER-CLIENT").unwrap();
+        assert_eq!(store.len(), 2);
+        assert_eq!(store.get_seq_nums(), Some((3, 7)));
+        assert_eq!(store.get_single(2).unwrap().get_message(), "8=FIX.4.2\x019=11\x0135=D\x0111=A\x0110=000\x01");
+        assert_eq!(store.get_single(1).unwrap().get_message(), "8=FIX.4.2\x019=5\x0135=0\x0110=161\x01");
+        assert!(store.get_single(3).is_none());
+
+        let _ = fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn test_file_msg_store_reset() {
+
+        let path = store_path("reset");
+
+        let mut store = FileMsgStore::open(&path, "SERVER-CLIENT").unwrap();
+        store.push(1, &ApplicationMessage::from_bytes(Bytes::from_static(b"8=FIX.4.2\x019=5\x0135=0\x0110=161\x01")));
+        store.set_seq_nums(2, 2);
+        store.reset();
+        drop(store);
+
+        let store = FileMsgStore::open(&path, "SERVER-CLIENT").unwrap();
+        assert_eq!(store.len(), 0);
+        assert_eq!(store.get_seq_nums(), None);
+
+        let _ = fs::remove_dir_all(&path);
+    }
+}
@@ -9,6 +9,7 @@ use tokio::task::yield_now;
 use crate::countdown_actor::{AlarmMessage, ResetMessage};
 use crate::fix_msg_handler::MyFixMsgHandler;
 use crate::fix_message::FixMessage;
+use crate::fix_42::attribute_enums::MsgType;
 
 pub struct FixSessionHandler {
 
@@ -36,7 +37,7 @@ impl FixSessionHandler {
             match x {
                 Ok(app_msg) => {
 
-                    let fix_msg = FixMessage::new(&app_msg.get_message());
+                    let fix_msg = FixMessage::new(app_msg.into_bytes());
                     let x2 = self.to_msg_hdlr_tx.send(fix_msg).await;
 
                     match x2 {
@@ -52,8 +53,7 @@ impl FixSessionHandler {
             match x {
                 Ok(_) => {
 
-                    let hb = &String::from("35=1");
-                    let fix_hb_msg = FixMessage::dummy(hb, '1');
+                    let fix_hb_msg = FixMessage::dummy("35=1", MsgType::TestRequest);
                     let res = self.to_msg_hdlr_tx.send(fix_hb_msg).await;
 
                     match res {
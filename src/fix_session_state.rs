@@ -0,0 +1,213 @@
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+use tokio::sync::broadcast;
+use crate::fix_println;
+
+// The states a FIX session moves through, from the socket connecting to it closing again.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SessionState {
+    Disconnected,
+    Connected,
+    LogonSent,
+    LogonReceived,
+    Active,
+    ResendInProgress,
+    LogoutSent,
+    LogoutReceived,
+}
+
+impl SessionState {
+
+    // Whether the Logon handshake has completed and the session hasn't started to end.
+    pub fn is_logged_on(&self) -> bool {
+        matches!(self, SessionState::Active | SessionState::ResendInProgress)
+    }
+
+    // Application messages are only valid once logged on, although the other side may still
+    // have some in flight when we ask to log out.
+    pub fn accepts_application_messages(&self) -> bool {
+        self.is_logged_on() || *self == SessionState::LogoutSent
+    }
+}
+
+impl Display for SessionState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+// Everything that can move the session from one state to another. There is an event for every
+// admin message in each direction, even those that never change the state, so that sending or
+// receiving one at the wrong point in the session shows up as an invalid transition.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SessionEvent {
+    Connect,
+    Disconnect,
+    LogonSent,
+    LogonReceived,
+    LogoutSent,
+    LogoutReceived,
+    ResendRequestSent,
+    ResendRequestReceived,
+    GapFilled,
+    HeartbeatSent,
+    HeartbeatReceived,
+    TestRequestSent,
+    TestRequestReceived,
+    RejectSent,
+    RejectReceived,
+    SequenceResetSent,
+    SequenceResetReceived,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StateChange {
+    pub from  : SessionState,
+    pub to    : SessionState,
+    pub event : SessionEvent,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct InvalidTransition {
+    pub state : SessionState,
+    pub event : SessionEvent,
+}
+
+impl Display for InvalidTransition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} is not valid in state {}", self.event, self.state)
+    }
+}
+
+pub struct SessionStateMachine {
+    state     : SessionState,
+    events_tx : broadcast::Sender<StateChange>,
+}
+
+impl Default for SessionStateMachine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SessionStateMachine {
+
+    pub fn new() -> Self {
+        let (events_tx, _) = broadcast::channel(32);
+        Self {
+            state: SessionState::Disconnected,
+            events_tx,
+        }
+    }
+
+    pub fn state(&self) -> SessionState {
+        self.state
+    }
+
+    // Every change of state is published here, nothing is sent when an event leaves the state as it was.
+    pub fn subscribe(&self) -> broadcast::Receiver<StateChange> {
+        self.events_tx.subscribe()
+    }
+
+    pub fn on_event(&mut self, event: SessionEvent) -> Result<SessionState, InvalidTransition> {
+
+        let from = self.state;
+        let to = Self::next_state(from, event).ok_or(InvalidTransition { state: from, event })?;
+
+        if to != from {
+            fix_println!("Session state {} -> {} on {:?}", from, to, event);
+            self.state = to;
+            // No subscribers is fine, nobody has to be watching.
+            let _ = self.events_tx.send(StateChange { from, to, event });
+        }
+        Ok(to)
+    }
+
+    fn next_state(state: SessionState, event: SessionEvent) -> Option<SessionState> {
+
+        use SessionEvent as E;
+        use SessionState as S;
+
+        match (state, event) {
+            (_, E::Disconnect)                                  => Some(S::Disconnected),
+            (S::Disconnected, E::Connect)                       => Some(S::Connected),
+
+            (S::Connected, E::LogonSent)                        => Some(S::LogonSent),
+            (S::Connected, E::LogonReceived)                    => Some(S::LogonReceived),
+            (S::LogonSent, E::LogonReceived)                    => Some(S::Active),
+            (S::LogonReceived, E::LogonSent)                    => Some(S::Active),
+
+            (S::Active | S::ResendInProgress, E::ResendRequestSent) => Some(S::ResendInProgress),
+            (S::ResendInProgress, E::GapFilled)                 => Some(S::Active),
+
+            (S::Connected | S::LogonSent | S::LogonReceived | S::Active | S::ResendInProgress, E::LogoutSent)
+                                                                => Some(S::LogoutSent),
+            (S::LogonSent | S::LogonReceived | S::Active | S::ResendInProgress, E::LogoutReceived)
+                                                                => Some(S::LogoutReceived),
+            (S::LogoutSent, E::LogoutReceived)                  => Some(S::Disconnected),
+            (S::LogoutReceived, E::LogoutSent)                  => Some(S::Disconnected),
+
+            // A Reject can be the answer to anything the other side sends us.
+            (state, E::RejectSent) if state != S::Disconnected  => Some(state),
+
+            // The remaining admin messages only make sense once logged on and leave the state alone.
+            (S::Active | S::ResendInProgress | S::LogoutSent | S::LogoutReceived,
+             E::ResendRequestReceived | E::HeartbeatSent | E::HeartbeatReceived | E::TestRequestSent |
+             E::TestRequestReceived | E::RejectReceived | E::SequenceResetSent | E::SequenceResetReceived) => Some(state),
+
+            _ => None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_initiator_logon_and_logout() {
+
+        let mut sm = SessionStateMachine::new();
+        let mut changes = sm.subscribe();
+
+        assert_eq!(sm.on_event(SessionEvent::Connect), Ok(SessionState::Connected));
+        assert_eq!(sm.on_event(SessionEvent::LogonSent), Ok(SessionState::LogonSent));
+        assert_eq!(sm.on_event(SessionEvent::LogonReceived), Ok(SessionState::Active));
+        assert_eq!(sm.on_event(SessionEvent::HeartbeatSent), Ok(SessionState::Active));
+        assert_eq!(sm.on_event(SessionEvent::ResendRequestSent), Ok(SessionState::ResendInProgress));
+        assert_eq!(sm.on_event(SessionEvent::GapFilled), Ok(SessionState::Active));
+        assert_eq!(sm.on_event(SessionEvent::LogoutSent), Ok(SessionState::LogoutSent));
+        assert_eq!(sm.on_event(SessionEvent::LogoutReceived), Ok(SessionState::Disconnected));
+
+        let mut seen = Vec::new();
+        while let Ok(change) = changes.try_recv() {
+            seen.push(change.to);
+        }
+        assert_eq!(seen, vec![SessionState::Connected, SessionState::LogonSent, SessionState::Active, SessionState::ResendInProgress,
+                              SessionState::Active, SessionState::LogoutSent, SessionState::Disconnected]);
+    }
+
+    #[test]
+    fn test_acceptor_logon_and_remote_logout() {
+
+        let mut sm = SessionStateMachine::new();
+
+        assert_eq!(sm.on_event(SessionEvent::Connect), Ok(SessionState::Connected));
+        assert_eq!(sm.on_event(SessionEvent::LogonReceived), Ok(SessionState::LogonReceived));
+        assert_eq!(sm.on_event(SessionEvent::LogonSent), Ok(SessionState::Active));
+        assert_eq!(sm.on_event(SessionEvent::LogoutReceived), Ok(SessionState::LogoutReceived));
+        assert_eq!(sm.on_event(SessionEvent::LogoutSent), Ok(SessionState::Disconnected));
+    }
+
+    #[test]
+    fn test_admin_messages_before_logon_are_invalid() {
+
+        let mut sm = SessionStateMachine::new();
+        sm.on_event(SessionEvent::Connect).unwrap();
+
+        assert_eq!(sm.on_event(SessionEvent::HeartbeatReceived), Err(InvalidTransition { state: SessionState::Connected, event: SessionEvent::HeartbeatReceived }));
+        assert_eq!(sm.on_event(SessionEvent::GapFilled), Err(InvalidTransition { state: SessionState::Connected, event: SessionEvent::GapFilled }));
+        assert_eq!(sm.on_event(SessionEvent::RejectSent), Ok(SessionState::Connected));
+        assert_eq!(sm.state(), SessionState::Connected);
+    }
+}
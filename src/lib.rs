@@ -0,0 +1,25 @@
+pub mod countdown_actor;
+pub mod socket_actor;
+pub mod fix_decoder;
+mod fix_session_handler;
+pub mod fix_msg_handler;
+pub mod fix_42;
+pub mod fix_msg_builder;
+pub mod fix_message;
+mod fix_msg_store;
+pub mod fix_session_state;
+mod data_dictionary;
+pub mod spec;
+
+
+/// Custom `print!` macro that adds a timestamp to log messages
+#[macro_export]
+macro_rules! fix_println {
+    ($($arg:tt)*) => {{
+        let timestamp = chrono::Local::now().format("%H:%M:%S.%6f").to_string();
+        let filename = file!(); // Get the current file name
+        let formatted_msg = format!("[{}] [{}] {}", timestamp, filename, format_args!($($arg)*));
+        println!("{}", formatted_msg);
+        std::io::stdout().flush().unwrap();
+    }};
+}
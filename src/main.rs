@@ -1,41 +1,20 @@
-mod countdown_actor;
-mod socket_actor;
-mod fix_decoder;
-mod fix_session_handler;
-mod fix_msg_handler;
-mod fix_42;
-mod fix_msg_builder;
-mod fix_message;
-
-use crate::countdown_actor::{AlarmMessage, ResetMessage};
+use tokyo_fix::countdown_actor::{self, AlarmMessage, ResetMessage};
 use config::{Config, File};
 use std::collections::HashMap;
-use std::io::{self, Write};
+use std::io::Write;
 use std::sync::Arc;
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::{TcpListener,TcpStream};
 use tokio::runtime::Handle;
 use tokio::sync::{mpsc, Mutex};
-use tokio_util::codec::Decoder;
-use crate::fix_decoder::MyFIXDecoder;
-use crate::fix_session_handler::FixSessionHandler;
-use crate::fix_msg_handler::MyFixMsgHandler;
-use crate::socket_actor::ApplicationMessage;
+use tokyo_fix::fix_decoder::MyFIXDecoder;
+use tokyo_fix::fix_msg_builder::SessionHeader;
+use tokyo_fix::fix_msg_handler::{DefaultApplication, MyFixMsgHandler};
+use tokyo_fix::fix_println;
+use tokyo_fix::fix_session_state::SessionState;
+use tokyo_fix::socket_actor::{self, ApplicationMessage};
 use std::env;
 
 
-/// Custom `print!` macro that adds a timestamp to log messages
-#[macro_export]
-macro_rules! fix_println {
-    ($($arg:tt)*) => {{
-        let timestamp = chrono::Local::now().format("%H:%M:%S.%6f").to_string();
-        let filename = file!(); // Get the current file name
-        let formatted_msg = format!("[{}] [{}] {}", timestamp, filename, format_args!($($arg)*));
-        println!("{}", formatted_msg);
-        std::io::stdout().flush().unwrap();
-    }};
-}
-
 #[tokio::main]
 async fn main() {
     // Option 1
@@ -60,14 +39,22 @@ async fn main() {
     fix_println!("Runtime is using {} workers", n);
     fix_println!("\n{:?} \n\n-----------",settings_map);
 
+    // Don't go as far as listening or connecting with a version we can't speak.
+    if let Err(e) = SessionHeader::from_config(&settings_map) {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+
     let (interval_tx, interval_rx)  = mpsc::channel::<u64>(1);
     let (alarm_tx, alarm_rx)        = mpsc::channel::<AlarmMessage>(1);
     let (reset_tx, reset_rx)        = mpsc::channel::<ResetMessage>(1);
+    let (received_tx, received_rx)  = mpsc::channel::<ResetMessage>(1);
     let (mh2sc_tx, mh2sc_rx)        = mpsc::channel::<ApplicationMessage>(3);
     let (sc2mh_tx, sc2mh_rx)        = mpsc::channel::<ApplicationMessage>(1);
+    let (logout_tx, logout_rx)      = mpsc::channel::<String>(1);
 
     let hb_task = tokio::spawn(async move {
-        let mut hb = countdown_actor::CountdownActor::new(alarm_tx, interval_rx, reset_rx);
+        let mut hb = countdown_actor::CountdownActor::new(alarm_tx, interval_rx, reset_rx, received_rx);
         fix_println!("Starting CountdownActor.");
         hb.start().await;
     });
@@ -95,7 +82,7 @@ async fn main() {
         let decoder_clone = Arc::clone(&decoder_impl);
         let sa_interval_tx_clone = interval_tx.clone();
         tokio::spawn(async move {
-            let mut sa = socket_actor::SocketActor::new(socket, sa_interval_tx_clone, mh2sc_rx, reset_tx, decoder_clone, sc2mh_tx);
+            let mut sa = socket_actor::SocketActor::new(socket, sa_interval_tx_clone, mh2sc_rx, reset_tx, received_tx, decoder_clone, sc2mh_tx);
             fix_println!("Starting SocketActor.");
             sa.run_with_try().await;
         })
@@ -107,34 +94,53 @@ async fn main() {
         let host = settings_map.get("target_host").unwrap();
         let target_destination = format!("{}:{}", host, port);
         fix_println!("Attempting to connect to remote server on: {}", target_destination);
-        let mut socket = TcpStream::connect("localhost:8080").await.unwrap();
+        let socket = TcpStream::connect(&target_destination).await.unwrap();
 
         let decoder_impl = Arc::new(Mutex::new(MyFIXDecoder::new(&settings_map)));
         let decoder_clone = Arc::clone(&decoder_impl);
         let sa_interval_tx_clone = interval_tx.clone();
 
          tokio::spawn(async move {
-            let mut sa = socket_actor::SocketActor::new(socket, sa_interval_tx_clone, mh2sc_rx, reset_tx, decoder_clone, sc2mh_tx);
+            let mut sa = socket_actor::SocketActor::new(socket, sa_interval_tx_clone, mh2sc_rx, reset_tx, received_tx, decoder_clone, sc2mh_tx);
             fix_println!("Starting SocketActor.");
             sa.run_with_try().await;
         })
     };
 
-    let mh_interval_tx_clone = interval_tx.clone();
-    let mh_task = tokio::spawn(async move {
-        let mut mh: MyFixMsgHandler = MyFixMsgHandler::new(mh_interval_tx_clone, sc2mh_rx, mh2sc_tx, alarm_rx);
-        fix_println!("Starting MyFixMsgHandler.");
+    // Ctrl-C starts a graceful Logout rather than killing the process outright.
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            fix_println!("Ctrl-C received, logging out.");
+            let _ = logout_tx.send(String::from("Operator requested logout")).await;
+        }
+    });
 
-        if instance_type == "client" {
-            mh.create_and_send_logon().await;
+    let mut mh: MyFixMsgHandler = MyFixMsgHandler::new(&settings_map, interval_tx.clone(), sc2mh_rx, mh2sc_tx, alarm_rx, logout_rx, Box::new(DefaultApplication));
+
+    // The handler logs every transition itself, this is just the view from outside the session.
+    let mut state_changes = mh.subscribe_state_changes();
+    tokio::spawn(async move {
+        while let Ok(change) = state_changes.recv().await {
+            match change.to {
+                SessionState::Active if !change.from.is_logged_on() => fix_println!("Session logged on."),
+                SessionState::Disconnected => fix_println!("Session ended."),
+                _ => {}
+            }
         }
+    });
 
+    // The handler sends the first Logon itself when configured as the client.
+    let mh_task = tokio::spawn(async move {
+        fix_println!("Starting MyFixMsgHandler.");
         mh.run_with_try().await;
     });
 
     // Use the '?' as the top-level main returns a result - so it can deal
     // with any bad result created here.
 
+    // Only the actors should keep the CountdownActor's interval channel open.
+    drop(interval_tx);
+
     let metrics = Handle::current().metrics();
     let n = metrics.num_alive_tasks();
     println!("Runtime is using {} num_alive_tasks", n);
@@ -1,18 +1,18 @@
 
 //Telling the compiler I have other modules that are part of this create that need to be complied.
 
-use bytes::BytesMut;
+use bytes::{Bytes, BytesMut};
 use tokio::io::{AsyncWriteExt};
 use tokio::net::TcpStream;
 use tokio::sync::{mpsc, Mutex};
 use std::io::{self,Write};
 use std::sync::Arc;
-use tokio::sync::mpsc::error::{TryRecvError};
+use tokio::sync::mpsc::error::{TryRecvError, TrySendError};
 use tokio::task::yield_now;
 use tokio_util::codec::{Decoder};
 use crate::countdown_actor::AlarmMessage;
 use crate::countdown_actor::ResetMessage;
-use crate::fix_decoder::MyFIXDecoder;
+use crate::fix_decoder::FixDecodeError;
 use crate::fix_println;
 
 
@@ -21,33 +21,34 @@ pub struct SocketActor {
     interval_tx: mpsc::Sender<u64>,
     from_mh_rx:  mpsc::Receiver<ApplicationMessage>,
     reset_tx:    mpsc::Sender<ResetMessage>,
-    decoder:     Arc<Mutex<dyn Decoder<Item = String, Error = std::io::Error> + Send + Sync>>,
-    to_sh_tx:  mpsc::Sender<ApplicationMessage>
+    received_tx: mpsc::Sender<ResetMessage>,
+    decoder:     Arc<Mutex<dyn Decoder<Item = Bytes, Error = FixDecodeError> + Send + Sync>>,
+    to_sh_tx:  mpsc::Sender<ApplicationMessage>,
+    // Frames thrown away because they couldn't be decoded, e.g. a bad CheckSum.
+    decode_errors: u64
 }
 
+// A complete FIX message as it goes over the wire.
+#[derive(Clone)]
 pub struct ApplicationMessage {
-    message: String,
-    bmsg: Vec<u8>
-}
-
-impl Clone for ApplicationMessage {
-    fn clone(&self) -> Self {
-        ApplicationMessage {
-            message : self.message.clone(),
-            bmsg : self.bmsg.clone()
-        }
-    }
+    message: Bytes
 }
 
 impl ApplicationMessage {
 
-    pub fn new(message: String) -> ApplicationMessage {
+    pub fn from_bytes(message: Bytes) -> ApplicationMessage {
         ApplicationMessage {
-            message,
-            bmsg : Vec::new()
+            message
         }
     }
-    pub fn get_message(&self) -> &String { &self.message }
+
+    // Only for logging and tests, anything built here is ASCII.
+    pub fn get_message(&self) -> &str { std::str::from_utf8(&self.message).unwrap_or_default() }
+
+    pub fn as_bytes(&self) -> &[u8] { &self.message }
+
+    // Hands the message over without copying it.
+    pub fn into_bytes(self) -> Bytes { self.message }
 }
 
 // Try to avoid Socket Actor knowing anything about the message structure/protocol.
@@ -58,15 +59,18 @@ impl SocketActor {
                hb_channel:     mpsc::Sender<u64>,
                from_mh_rx:     mpsc::Receiver<ApplicationMessage>,
                reset_sender:   mpsc::Sender<ResetMessage>,
-               decoder:        Arc<Mutex<dyn Decoder<Item = String, Error = std::io::Error> + Send + Sync>>,
+               received_sender: mpsc::Sender<ResetMessage>,
+               decoder:        Arc<Mutex<dyn Decoder<Item = Bytes, Error = FixDecodeError> + Send + Sync>>,
                to_sh_tx:       mpsc::Sender<ApplicationMessage>) -> Self {
         Self {
             socket,
             interval_tx: hb_channel,
             from_mh_rx,
             reset_tx:    reset_sender,
+            received_tx: received_sender,
             decoder,
-            to_sh_tx
+            to_sh_tx,
+            decode_errors: 0
         }
     }
 
@@ -74,7 +78,8 @@ impl SocketActor {
 
         fix_println!("Running SocketActor");
         let mut buf = BytesMut::with_capacity(1024 * 128);
-        let mut decoder = self.decoder.lock().await; // Lock the decoder for mutable access
+        let decoder = Arc::clone(&self.decoder);
+        let mut decoder = decoder.lock().await; // Lock the decoder for mutable access
 
         loop {
 
@@ -88,26 +93,22 @@ impl SocketActor {
             };
 
             if num_bytes > 0 {
-                let z = buf.clone();
-                let Some(x) = decoder.decode(&mut buf).unwrap() else { todo!() };
-                let y = x.clone();
-                let am = ApplicationMessage::new(x);
-                fix_println!("Read {} bytes from socket:{}",num_bytes,y);
-                let res = self.to_sh_tx.send(am).await;
-                match res {
-                    Ok(_) => {},
-                    Err(e) => {eprintln!("failed to send to Session Handler.{}",e);}
-                }
+                fix_println!("Read {} bytes from socket.", num_bytes);
+                self.drain_frames(&mut *decoder, &mut buf).await;
             }
 
             let result =  self.from_mh_rx.try_recv();
             match result {
                 //Err(TryRecvError::Empty) => { if tried % 200000 == 0 { fix_println!("MH->SK: tried {} times so far",tried);}; },
                 Err(TryRecvError::Empty) => { },
-                Err(TryRecvError::Disconnected) => fix_println!("MH_RX: something went wrong."),
+                Err(TryRecvError::Disconnected) => {
+                    // The Msg handler has finished with the session and everything it queued has been written.
+                    fix_println!("MH_RX: Msg handler has stopped, closing socket.");
+                    break;
+                },
                 Ok(writable) => {
 
-                    let msg = writable.message.as_bytes();
+                    let msg = writable.as_bytes();
 
                     //todo: need some buffer, store, queue to append the created messages to
                     //      the try_write many write multiple messages or just a bit of one
@@ -126,12 +127,17 @@ impl SocketActor {
                         //todo: this tells the Countdown Timer to reset itself as a message has been /is being written
                         //      This is part of the FIX protocal and so should prob be moved up a layer.
                         //      Need to make sure that all the messages are sequence properly.
-                        //self.reset_tx.try_send(ResetMessage::Reset).unwrap();
+                        self.reset_countdown(ResetMessage::Reset);
                     }
                 }
             };
             yield_now().await;
         }
+
+        if let Err(e) = self.socket.shutdown().await {
+            eprintln!("failed to shutdown socket; err = {:?}", e);
+        }
+        fix_println!("SocketActor stopped.");
     }
     pub async fn run(&mut self) {
 
@@ -140,7 +146,8 @@ impl SocketActor {
 
         let mut buf = BytesMut::with_capacity(1024 * 128);
 
-        let mut decoder = self.decoder.lock().await; // Lock the decoder for mutable access
+        let decoder = Arc::clone(&self.decoder);
+        let mut decoder = decoder.lock().await; // Lock the decoder for mutable access
 
         fix_println!("Connection received from:{}", self.socket.peer_addr().unwrap());
 
@@ -157,17 +164,9 @@ impl SocketActor {
 
             if num_bytes > 0 {
                 //todo:call a onRead() callBack
-                let Some(x) = decoder.decode(&mut buf).unwrap() else { todo!() };
                 // a single callback for now but it could be a list of callbacks I guess.
                 // todo perhaps implement the timer reset as a callback rather than a channel ?
-                let am = ApplicationMessage::new(x);
-                fix_println!("MH->SK: waiting @ session send, pending={}", self.from_mh_rx.len());
-                let res = self.to_sh_tx.send(am).await;
-                fix_println!("MH->SK: session sent, pending={}", self.from_mh_rx.len());
-                match res {
-                    Ok(_) => {},
-                    Err(e) => {eprintln!("failed to send to Session Handler.{}",e);}
-                }
+                self.drain_frames(&mut *decoder, &mut buf).await;
             }
 
 
@@ -178,7 +177,7 @@ impl SocketActor {
 
             match result {
                 Some(writable) => {
-                    let msg = writable.message.as_bytes();
+                    let msg = writable.as_bytes();
 
                     //todo: need some buffer, store, queue to append the created messages to
                     //      the try_write many write multiple messages or just a bit of one
@@ -210,6 +209,47 @@ impl SocketActor {
         }
     }
 
+    // A single read can hold several messages, and the last of them may be incomplete. Pass on every
+    // complete frame and leave the remainder in the buffer to be finished off by the next read.
+    async fn drain_frames(&mut self, decoder: &mut (dyn Decoder<Item = Bytes, Error = FixDecodeError> + Send + Sync), buf: &mut BytesMut) {
+
+        loop {
+            let remaining = buf.len();
+            match decoder.decode(buf) {
+                Ok(Some(msg)) => {
+                    fix_println!("Decoded message:{}", String::from_utf8_lossy(&msg));
+                    self.reset_countdown(ResetMessage::Received);
+                    if let Err(e) = self.to_sh_tx.send(ApplicationMessage::from_bytes(msg)).await {
+                        eprintln!("failed to send to Session Handler.{}", e);
+                    }
+                },
+                Ok(None) => break,
+                Err(e) => {
+                    // The bad frame has been dropped from the buffer, so there may be good ones behind it.
+                    self.decode_errors += 1;
+                    fix_println!("Dropped inbound frame: {} ({} dropped so far)", e, self.decode_errors);
+                    // Nothing was consumed, so trying again would only fail the same way.
+                    if buf.len() == remaining {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    // Writes and reads reset different countdowns, each over its own channel. A full channel means
+    // a reset of that countdown is already pending, which is just as good.
+    fn reset_countdown(&self, reset: ResetMessage) {
+        let tx = match reset {
+            ResetMessage::Reset    => &self.reset_tx,
+            ResetMessage::Received => &self.received_tx,
+        };
+        match tx.try_send(reset) {
+            Ok(_) | Err(TrySendError::Full(_)) => {},
+            Err(TrySendError::Closed(_)) => {eprintln!("failed to reset countdown, CountdownActor has stopped.")}
+        }
+    }
+
     pub fn generate_check_sum(buf: &str) -> usize {
         let b = buf.as_bytes();
 
@@ -222,4 +262,59 @@ impl SocketActor {
 
         cks % 256
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fix_decoder::MyFIXDecoder;
+    use std::collections::HashMap;
+    use tokio::net::TcpListener;
+    use crate::fix_msg_builder;
+
+    fn build_message(body: &str) -> String {
+        let mut msg = format!("8=FIX.4.2\x019={}\x01{}", body.len(), body);
+        let cksum = fix_msg_builder::generate_check_sum(&msg);
+        msg.push_str(&format!("10={:03}\x01", cksum));
+        msg
+    }
+
+    #[tokio::test]
+    async fn test_every_frame_in_a_read_is_passed_on() {
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let mut peer = TcpStream::connect(listener.local_addr().unwrap()).await.unwrap();
+        let (socket, _) = listener.accept().await.unwrap();
+
+        let mut config = HashMap::new();
+        config.insert(String::from("version"), String::from("4.2"));
+        config.insert(String::from("sender_comp_id"), String::from("TEST_SERVER"));
+        config.insert(String::from("target_comp_id"), String::from("TEST_CLIENT"));
+
+        let (interval_tx, _interval_rx) = mpsc::channel::<u64>(1);
+        let (mh2sc_tx, mh2sc_rx)        = mpsc::channel::<ApplicationMessage>(1);
+        let (reset_tx, _reset_rx)       = mpsc::channel::<ResetMessage>(1);
+        let (received_tx, _received_rx) = mpsc::channel::<ResetMessage>(1);
+        let (sc2mh_tx, mut sc2mh_rx)    = mpsc::channel::<ApplicationMessage>(10);
+        let decoder = Arc::new(Mutex::new(MyFIXDecoder::new(&config)));
+
+        let mut sa = SocketActor::new(socket, interval_tx, mh2sc_rx, reset_tx, received_tx, decoder, sc2mh_tx);
+        let sa_task = tokio::spawn(async move { sa.run_with_try().await; });
+
+        let first  = build_message("35=0\x0149=TEST_CLIENT\x0156=TEST_SERVER\x0134=1\x0152=20250119-16:13:08.931\x01");
+        let second = build_message("35=0\x0149=TEST_CLIENT\x0156=TEST_SERVER\x0134=2\x0152=20250119-16:13:09.931\x01");
+        let third  = build_message("35=0\x0149=TEST_CLIENT\x0156=TEST_SERVER\x0134=3\x0152=20250119-16:13:10.931\x01");
+
+        // Two whole messages and the start of a third in one write, then the rest of the third.
+        let (head, tail) = third.split_at(20);
+        peer.write_all(format!("{}{}{}", first, second, head).as_bytes()).await.unwrap();
+        assert_eq!(sc2mh_rx.recv().await.unwrap().get_message(), first);
+        assert_eq!(sc2mh_rx.recv().await.unwrap().get_message(), second);
+
+        peer.write_all(tail.as_bytes()).await.unwrap();
+        assert_eq!(sc2mh_rx.recv().await.unwrap().get_message(), third);
+
+        drop(mh2sc_tx);
+        sa_task.await.unwrap();
+    }
+}
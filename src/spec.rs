@@ -0,0 +1,129 @@
+#![allow(dead_code)]
+// Code generated by build.rs from the specs in spec/, one module per spec, e.g. spec/FIX42.xml is
+// spec::fix42 with its fields, enums and messages. Each message gets a struct, and each repeating
+// group a struct and the GroupDef used to read and write it, named after the message and group,
+// e.g. messages::NEW_ORDER_LIST_NO_ORDERS.
+include!(concat!(env!("OUT_DIR"), "/specs.rs"));
+
+use crate::fix_42::attribute_enums::MsgType;
+use crate::fix_42::value_types::FixTag;
+use crate::fix_message::{FieldError, Group};
+use crate::fix_msg_builder::FixMessageBuilder;
+
+// Any generated message, so that one can be sent without knowing which it is.
+pub trait Message {
+    fn msg_type(&self) -> MsgType;
+    fn encode_fields(&self, builder: &mut FixMessageBuilder);
+}
+
+// An optional field that isn't there is None rather than an error.
+pub(crate) fn optional<T>(value: Result<T, FieldError>) -> Result<Option<T>, FieldError> {
+    match value {
+        Ok(value) => Ok(Some(value)),
+        Err(FieldError::Missing(_)) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+pub(crate) fn get_enum<T>(group: &Group, tag: &FixTag, from_bytes: fn(&[u8]) -> Option<T>) -> Result<T, FieldError> {
+    let value = group.get_raw(tag).ok_or(FieldError::Missing(tag.number()))?;
+    from_bytes(value).ok_or(FieldError::Invalid(tag.number()))
+}
+
+// MultipleValueString, values separated by spaces.
+pub(crate) fn get_enums<T>(group: &Group, tag: &FixTag, from_bytes: fn(&[u8]) -> Option<T>) -> Result<Vec<T>, FieldError> {
+    let value = group.get_raw(tag).ok_or(FieldError::Missing(tag.number()))?;
+    value.split(|b| *b == b' ')
+        .filter(|value| !value.is_empty())
+        .map(|value| from_bytes(value).ok_or(FieldError::Invalid(tag.number())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::fix42::enums::{ExecInst, OrdType, Side};
+    use crate::fix_42::attribute_enums::FixEnum;
+    use super::fix42::messages::{NewOrderList, NewOrderListNoOrders, NewOrderListNoOrdersNoAllocs, NewOrderSingle};
+    use crate::fix_message::FixMessage;
+    use crate::fix_msg_builder::{FixMessageBuilder, SessionHeader};
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
+
+    fn builder() -> FixMessageBuilder {
+        FixMessageBuilder::new(SessionHeader::new("FIX.4.2", "CLIENT", "SERVER"))
+    }
+
+    fn new_order_single() -> NewOrderSingle {
+        let mut order = NewOrderSingle::decode(&FixMessage::new(bytes::Bytes::from_static(
+            b"8=FIX.4.2\x019=0\x0135=D\x0134=1\x0149=CLIENT\x0156=SERVER\x0152=20250119-16:13:08.931\x01\
+              11=ORD1\x0121=1\x0155=IBM\x0154=1\x0160=20250119-16:13:08.931\x0140=2\x0144=101.25\x0138=100\x0110=000\x01"))).unwrap();
+        order.exec_inst = vec![ExecInst::NotHeld, ExecInst::AllOrNone];
+        order
+    }
+
+    #[test]
+    fn test_enums() {
+        assert_eq!(Side::from_bytes(b"2"), Some(Side::Sell));
+        assert_eq!(Side::from_bytes(b"Z"), None);
+        assert_eq!(OrdType::Limit.value(), "2");
+        assert_eq!(Side::Buy.to_string(), "1");
+        for side in Side::ALL {
+            assert_eq!(Side::from_bytes(side.value().as_bytes()), Some(*side));
+        }
+        assert_eq!(fix42::fields::CL_ORD_ID.number(), 11);
+        assert_eq!(fix42::fields::NO_MD_ENTRIES.number(), 268);
+        assert_eq!(fix42::fields::find(268).map(|tag| tag.id()), Some("268"));
+        assert!(fix42::fields::find(220).is_none());
+        assert_eq!(fix42::fields::ALL.len(), 405);
+    }
+
+    #[test]
+    fn test_decode_new_order_single() {
+        let order = new_order_single();
+        assert_eq!(order.cl_ord_id, "ORD1");
+        assert_eq!(order.side, Side::Buy);
+        assert_eq!(order.ord_type, OrdType::Limit);
+        assert_eq!(order.price, Some(Decimal::from_str("101.25").unwrap()));
+        assert_eq!(order.order_qty, Some(Decimal::from(100)));
+        assert_eq!(order.account, None);
+        assert_eq!(order.transact_time, NaiveDate::from_ymd_opt(2025, 1, 19).unwrap().and_hms_milli_opt(16, 13, 8, 931).unwrap());
+
+        let missing_side = FixMessage::new(bytes::Bytes::from_static(
+            b"8=FIX.4.2\x019=0\x0135=D\x0111=ORD1\x0121=1\x0155=IBM\x0160=20250119-16:13:08\x0140=1\x0110=000\x01"));
+        assert_eq!(NewOrderSingle::decode(&missing_side).err(), Some(FieldError::Missing(54)));
+        let bad_side = FixMessage::new(bytes::Bytes::from_static(
+            b"8=FIX.4.2\x019=0\x0135=D\x0111=ORD1\x0121=1\x0155=IBM\x0154=Z\x0160=20250119-16:13:08\x0140=1\x0110=000\x01"));
+        assert_eq!(NewOrderSingle::decode(&bad_side).err(), Some(FieldError::Invalid(54)));
+    }
+
+    #[test]
+    fn test_encode_decode_round_trip() {
+        let order = new_order_single();
+        let encoded = order.encode(&mut builder(), 2);
+        let text = String::from_utf8_lossy(&encoded);
+        assert!(text.contains("\x0111=ORD1\x01"));
+        assert!(text.contains("\x0118=1 G\x01"));
+        assert_eq!(NewOrderSingle::decode(&FixMessage::new(encoded)).unwrap(), order);
+    }
+
+    #[test]
+    fn test_groups_round_trip() {
+        let entry = |cl_ord_id: &str, allocs: Vec<NewOrderListNoOrdersNoAllocs>| {
+            let mut entry = NewOrderListNoOrders::decode_fields(&FixMessage::new(bytes::Bytes::from_static(
+                b"8=FIX.4.2\x019=0\x0135=E\x0111=X\x0167=1\x0155=IBM\x0154=2\x0110=000\x01")).as_group()).unwrap();
+            entry.cl_ord_id = cl_ord_id.to_string();
+            entry.no_allocs = allocs;
+            entry
+        };
+        let alloc = NewOrderListNoOrdersNoAllocs { alloc_account: Some(String::from("A1")), alloc_shares: Some(Decimal::from(50)) };
+        let mut list = NewOrderList::decode(&FixMessage::new(bytes::Bytes::from_static(
+            b"8=FIX.4.2\x019=0\x0135=E\x0166=LIST1\x01394=1\x0168=2\x0110=000\x01"))).unwrap();
+        assert_eq!(list.bid_type, fix42::enums::BidType::NonDisclosed);
+        list.no_orders = vec![entry("ORD1", vec![alloc.clone(), alloc]), entry("ORD2", vec![])];
+
+        let encoded = list.encode(&mut builder(), 3);
+        assert_eq!(NewOrderList::decode(&FixMessage::new(encoded)).unwrap(), list);
+    }
+}